@@ -35,6 +35,45 @@ CREATE TABLE login_failure_counts(
     PRIMARY KEY (user_uuid)
 );
 
+CREATE TABLE role_permissions(
+    role smallint NOT NULL,
+    permission smallint NOT NULL CHECK (permission >= 0 AND permission <= 7),
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (role, permission)
+);
+
+CREATE TABLE sessions(
+    uuid uuid NOT NULL,
+    session_id_hash text NOT NULL CHECK (session_id_hash <> ''),
+    user_uuid uuid NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    csrf_token text NOT NULL CHECK (csrf_token <> ''),
+    user_agent text,
+    created_at timestamptz NOT NULL,
+    last_accessed_at timestamptz NOT NULL,
+    expires_at timestamptz NOT NULL,
+    PRIMARY KEY (uuid),
+    UNIQUE (session_id_hash)
+);
+
+CREATE TABLE user_identities(
+    issuer text NOT NULL CHECK (issuer <> ''),
+    subject text NOT NULL CHECK (subject <> ''),
+    user_uuid uuid NOT NULL REFERENCES users(uuid) ON DELETE CASCADE,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (issuer, subject)
+);
+
+CREATE TABLE oidc_authorization_requests(
+    state text NOT NULL CHECK (state <> ''),
+    nonce text NOT NULL CHECK (nonce <> ''),
+    code_verifier text NOT NULL CHECK (code_verifier <> ''),
+    redirect_to text NOT NULL,
+    created_at timestamptz NOT NULL,
+    expires_at timestamptz NOT NULL,
+    PRIMARY KEY (state)
+);
+
 CREATE TABLE application_entities(
     uuid uuid NOT NULL DEFAULT uuidv7(),
     title varchar(16) NOT NULL CHECK (title <> ''),
@@ -93,6 +132,22 @@ CREATE TABLE studies(
     PRIMARY KEY (instance_uid)
 );
 
+CREATE TABLE studies_deleted(
+    patient_id varchar(16),
+    instance_uid varchar(64) NOT NULL,
+    id varchar(16) NOT NULL,
+    study_date date,
+    study_time time,
+    accession_number varchar(16) NOT NULL,
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL,
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL,
+    deleted_by uuid NOT NULL,
+    deleted_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (instance_uid, deleted_at)
+);
+
 CREATE TABLE series(
     study_instance_uid varchar(64) NOT NULL REFERENCES studies(instance_uid),
     instance_uid varchar(64) NOT NULL,
@@ -105,13 +160,161 @@ CREATE TABLE series(
     PRIMARY KEY (instance_uid)
 );
 
+CREATE TABLE series_deleted(
+    study_instance_uid varchar(64) NOT NULL,
+    instance_uid varchar(64) NOT NULL,
+    modality varchar(16) NOT NULL,
+    series_number integer,
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL,
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL,
+    deleted_by uuid NOT NULL,
+    deleted_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (instance_uid, deleted_at)
+);
+
 CREATE TABLE sop_instances(
     series_instance_uid varchar(64) NOT NULL REFERENCES series(instance_uid),
     class_uid varchar(64) NOT NULL,
     instance_uid varchar(64) NOT NULL,
     transfer_syntax_uid varchar(64) NOT NULL,
-    size integer NOT NULL CHECK (size >= 0 AND size <= 2147483647),
+    size bigint NOT NULL CHECK (size >= 0),
+    path text NOT NULL,
+    called_ae_title varchar(16) NOT NULL CHECK (called_ae_title <> ''),
+    content_hash char(64) NOT NULL,
+    file_hash char(64) NOT NULL,
+    file_hash_status smallint NOT NULL DEFAULT 0 CHECK (file_hash_status >= 0 AND file_hash_status <= 3),
+    file_hash_verified_at timestamptz,
+    version integer NOT NULL DEFAULT 1 CHECK (version >= 1),
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (instance_uid)
+);
+
+CREATE TABLE sop_instances_deleted(
+    series_instance_uid varchar(64) NOT NULL,
+    class_uid varchar(64) NOT NULL,
+    instance_uid varchar(64) NOT NULL,
+    transfer_syntax_uid varchar(64) NOT NULL,
+    size bigint NOT NULL CHECK (size >= 0),
+    path text NOT NULL,
+    called_ae_title varchar(16) NOT NULL CHECK (called_ae_title <> ''),
+    content_hash char(64) NOT NULL,
+    file_hash char(64) NOT NULL,
+    version integer NOT NULL CHECK (version >= 1),
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL,
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL,
+    deleted_by uuid NOT NULL,
+    deleted_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (instance_uid, deleted_at)
+);
+
+CREATE TABLE storage_journals(
+    uuid uuid NOT NULL DEFAULT uuidv7(),
+    sop_instance_uid varchar(64) NOT NULL,
+    called_ae_title varchar(16) NOT NULL CHECK (called_ae_title <> ''),
+    transfer_syntax_uid varchar(64) NOT NULL,
+    size bigint NOT NULL CHECK (size >= 0),
+    content_hash char(64) NOT NULL,
+    file_hash char(64) NOT NULL,
+    version integer NOT NULL CHECK (version >= 1),
+    policy smallint CHECK (policy = 2),
+    key text NOT NULL CHECK (key <> ''),
+    uri text,
+    operation smallint NOT NULL DEFAULT 0 CHECK (operation = 0 OR operation = 1),
+    status smallint NOT NULL DEFAULT 0 CHECK (status = 0 OR status = 1),
+    reason text NOT NULL DEFAULT '',
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (uuid)
+);
+
+CREATE TABLE sop_instance_histories(
+    instance_uid varchar(64) NOT NULL REFERENCES sop_instances(instance_uid) ON DELETE CASCADE,
+    version integer NOT NULL CHECK (version >= 1),
+    series_instance_uid varchar(64) NOT NULL,
+    class_uid varchar(64) NOT NULL,
+    transfer_syntax_uid varchar(64) NOT NULL,
+    size bigint NOT NULL CHECK (size >= 0),
     path text NOT NULL,
+    called_ae_title varchar(16) NOT NULL CHECK (called_ae_title <> ''),
+    content_hash char(64) NOT NULL,
+    file_hash char(64) NOT NULL,
+    policy smallint NOT NULL CHECK (policy = 2),
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL,
+    replaced_by uuid NOT NULL,
+    replaced_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (instance_uid, version, replaced_at)
+);
+
+CREATE TABLE patient_conflicts(
+    uuid uuid NOT NULL DEFAULT uuidv7(),
+    patient_id varchar(16) NOT NULL REFERENCES patients(id),
+    study_instance_uid varchar(64) NOT NULL,
+    sop_instance_uid varchar(64) NOT NULL,
+    name_alphabet varchar(64) NOT NULL,
+    name_kanji varchar(64) NOT NULL,
+    name_hiragana varchar(64) NOT NULL,
+    birth_date date,
+    sex smallint NOT NULL CHECK (sex = 0 OR sex = 1 OR sex = 2 OR sex = 9),
+    status smallint NOT NULL DEFAULT 0 CHECK (status = 0 OR status = 1 OR status = 2),
+    resolved_by uuid,
+    resolved_at timestamptz,
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (uuid)
+);
+
+CREATE TABLE patient_reconciliation_logs(
+    uuid uuid NOT NULL DEFAULT uuidv7(),
+    patient_conflict_uuid uuid NOT NULL REFERENCES patient_conflicts(uuid),
+    action smallint NOT NULL CHECK (action = 1 OR action = 2),
+    study_instance_uid varchar(64) NOT NULL,
+    old_patient_id varchar(16) NOT NULL,
+    new_patient_id varchar(16) NOT NULL,
+    rewrite_files boolean NOT NULL,
+    rewritten_file_count integer NOT NULL DEFAULT 0 CHECK (rewritten_file_count >= 0),
+    failed_file_paths text[] NOT NULL DEFAULT '{}',
+    performed_by uuid NOT NULL,
+    performed_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (uuid)
+);
+
+CREATE TABLE deidentification_jobs(
+    uuid uuid NOT NULL DEFAULT uuidv7(),
+    study_instance_uid varchar(64) NOT NULL,
+    retain_longitudinal_temporal_information boolean NOT NULL,
+    retain_patient_characteristics boolean NOT NULL,
+    clean_descriptors boolean NOT NULL,
+    status smallint NOT NULL DEFAULT 0 CHECK (status = 0 OR status = 1 OR status = 2),
+    exported_file_count integer NOT NULL DEFAULT 0 CHECK (exported_file_count >= 0),
+    failed_file_paths text[] NOT NULL DEFAULT '{}',
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    finished_at timestamptz,
+    PRIMARY KEY (uuid)
+);
+
+CREATE TABLE performed_procedure_steps(
+    instance_uid varchar(64) NOT NULL,
+    study_instance_uid varchar(64) NOT NULL,
+    patient_id varchar(64) NOT NULL,
+    accession_number varchar(16) NOT NULL,
+    id varchar(16) NOT NULL,
+    status smallint NOT NULL CHECK (status = 0 OR status = 1 OR status = 2),
+    modality varchar(16) NOT NULL,
+    description varchar(64) NOT NULL,
+    station_ae_title varchar(16) NOT NULL,
+    start_date date,
+    start_time time,
+    end_date date,
+    end_time time,
     created_by uuid NOT NULL,
     created_at timestamptz NOT NULL DEFAULT now(),
     updated_by uuid NOT NULL,
@@ -119,3 +322,84 @@ CREATE TABLE sop_instances(
     PRIMARY KEY (instance_uid)
 );
 
+CREATE TABLE performed_procedure_step_series(
+    performed_procedure_step_instance_uid varchar(64) NOT NULL REFERENCES performed_procedure_steps(instance_uid) ON DELETE CASCADE,
+    series_instance_uid varchar(64) NOT NULL,
+    description varchar(64) NOT NULL,
+    retrieve_ae_title varchar(16) NOT NULL,
+    number_of_instances integer NOT NULL CHECK (number_of_instances >= 0),
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (performed_procedure_step_instance_uid, series_instance_uid)
+);
+
+CREATE TABLE local_application_entities(
+    uuid uuid NOT NULL DEFAULT uuidv7(),
+    title varchar(16) NOT NULL CHECK (title <> ''),
+    storage_dir text NOT NULL CHECK (storage_dir <> ''),
+    duplicate_policy smallint NOT NULL DEFAULT 2 CHECK (duplicate_policy = 0 OR duplicate_policy = 1 OR duplicate_policy = 2),
+    comment text NOT NULL DEFAULT '',
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (uuid),
+    UNIQUE (title)
+);
+
+CREATE TABLE local_application_entity_allowed_callers(
+    local_application_entity_uuid uuid NOT NULL REFERENCES local_application_entities(uuid) ON DELETE CASCADE,
+    application_entity_uuid uuid NOT NULL REFERENCES application_entities(uuid) ON DELETE CASCADE,
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (local_application_entity_uuid, application_entity_uuid)
+);
+
+CREATE TABLE accepted_storage_sop_classes(
+    local_application_entity_uuid uuid REFERENCES local_application_entities(uuid) ON DELETE CASCADE,
+    application_entity_uuid uuid REFERENCES application_entities(uuid) ON DELETE CASCADE,
+    sop_class_uid varchar(64) NOT NULL CHECK (sop_class_uid <> ''),
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    UNIQUE NULLS NOT DISTINCT (local_application_entity_uuid, application_entity_uuid, sop_class_uid)
+);
+
+CREATE TABLE coercion_rules(
+    application_entity_uuid uuid NOT NULL REFERENCES application_entities(uuid) ON DELETE CASCADE,
+    application_order integer NOT NULL CHECK (application_order >= 1),
+    action smallint NOT NULL CHECK (action >= 1 AND action <= 5),
+    tag char(8) NOT NULL CHECK (tag ~ '^[0-9A-F]{8}$'),
+    source_tag char(8) CHECK (source_tag ~ '^[0-9A-F]{8}$'),
+    pattern text NOT NULL DEFAULT '',
+    value text NOT NULL DEFAULT '',
+    map_from text[] NOT NULL DEFAULT '{}',
+    map_to text[] NOT NULL DEFAULT '{}',
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (application_entity_uuid, application_order),
+    CHECK (tag NOT IN ('00080016', '00080018', '04000561')),
+    CHECK (action <> 2 OR source_tag IS NOT NULL),
+    CHECK (cardinality(map_from) = cardinality(map_to))
+);
+
+CREATE TABLE retention_rules(
+    uuid uuid NOT NULL DEFAULT uuidv7(),
+    name text NOT NULL CHECK (name <> ''),
+    called_ae_title varchar(16) CHECK (called_ae_title <> ''),
+    max_age_days integer CHECK (max_age_days >= 1),
+    max_total_size bigint CHECK (max_total_size >= 1),
+    created_by uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT now(),
+    updated_by uuid NOT NULL,
+    updated_at timestamptz NOT NULL DEFAULT now(),
+    PRIMARY KEY (uuid),
+    CHECK ((max_age_days IS NULL) <> (max_total_size IS NULL))
+);
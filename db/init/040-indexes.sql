@@ -0,0 +1,6 @@
+CREATE INDEX sessions_user_uuid_idx ON sessions(user_uuid);
+CREATE INDEX sessions_expires_at_idx ON sessions(expires_at);
+CREATE INDEX user_identities_user_uuid_idx ON user_identities(user_uuid);
+CREATE INDEX oidc_authorization_requests_expires_at_idx ON oidc_authorization_requests(expires_at);
+CREATE INDEX performed_procedure_steps_study_instance_uid_idx ON performed_procedure_steps(study_instance_uid);
+CREATE UNIQUE INDEX patient_conflicts_unresolved_idx ON patient_conflicts(patient_id, study_instance_uid) WHERE status = 0;
@@ -0,0 +1,28 @@
+-- ロールごとの権限の既定値
+-- ロール: 0=管理者, 1=情シス, 2=医師, 3=技師, 4=事務員
+-- 権限: 0=検査の閲覧, 1=検査のダウンロード, 2=検査の登録, 3=検査の削除, 4=AEの管理, 5=ユーザーの管理, 6=匿名化エクスポート, 7=患者属性の不一致の照合
+INSERT INTO role_permissions(role, permission, created_by)
+VALUES
+    (0, 0, '00000000-0000-7000-8000-000000000000'),
+    (0, 1, '00000000-0000-7000-8000-000000000000'),
+    (0, 2, '00000000-0000-7000-8000-000000000000'),
+    (0, 3, '00000000-0000-7000-8000-000000000000'),
+    (0, 4, '00000000-0000-7000-8000-000000000000'),
+    (0, 5, '00000000-0000-7000-8000-000000000000'),
+    (0, 6, '00000000-0000-7000-8000-000000000000'),
+    (0, 7, '00000000-0000-7000-8000-000000000000'),
+    (1, 0, '00000000-0000-7000-8000-000000000000'),
+    (1, 1, '00000000-0000-7000-8000-000000000000'),
+    (1, 2, '00000000-0000-7000-8000-000000000000'),
+    (1, 3, '00000000-0000-7000-8000-000000000000'),
+    (1, 4, '00000000-0000-7000-8000-000000000000'),
+    (1, 5, '00000000-0000-7000-8000-000000000000'),
+    (1, 6, '00000000-0000-7000-8000-000000000000'),
+    (1, 7, '00000000-0000-7000-8000-000000000000'),
+    (2, 0, '00000000-0000-7000-8000-000000000000'),
+    (2, 1, '00000000-0000-7000-8000-000000000000'),
+    (2, 2, '00000000-0000-7000-8000-000000000000'),
+    (3, 0, '00000000-0000-7000-8000-000000000000'),
+    (3, 1, '00000000-0000-7000-8000-000000000000'),
+    (3, 2, '00000000-0000-7000-8000-000000000000'),
+    (4, 0, '00000000-0000-7000-8000-000000000000');
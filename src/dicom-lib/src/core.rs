@@ -5,6 +5,6 @@ mod tag;
 pub mod value;
 
 pub use data_element::DataElement;
-pub use data_set::DataSet;
+pub use data_set::{ConvertEncodingError, DataSet};
 pub use encoding::Encoding;
-pub use tag::Tag;
+pub use tag::{Tag, TagParseError};
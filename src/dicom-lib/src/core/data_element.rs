@@ -4,6 +4,7 @@ pub use vr::Vr;
 
 use crate::core::tag::Tag;
 
+#[derive(Clone)]
 pub struct DataElement {
     pub(crate) tag: Tag,
     pub(crate) vr: Option<Vr>,
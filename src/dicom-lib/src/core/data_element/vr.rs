@@ -39,7 +39,7 @@ pub enum Vr {
 }
 
 impl Vr {
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'static str {
         match self {
             Vr::Ae => "AE",
             Vr::As => "AS",
@@ -77,6 +77,30 @@ impl Vr {
             Vr::Uv => "UV",
         }
     }
+
+    /// 文字列を値として持つVRであるかを返す。
+    pub fn is_string(&self) -> bool {
+        matches!(
+            self,
+            Vr::Ae
+                | Vr::As
+                | Vr::Cs
+                | Vr::Da
+                | Vr::Ds
+                | Vr::Dt
+                | Vr::Is
+                | Vr::Lo
+                | Vr::Lt
+                | Vr::Pn
+                | Vr::Sh
+                | Vr::St
+                | Vr::Tm
+                | Vr::Uc
+                | Vr::Ui
+                | Vr::Ur
+                | Vr::Ut
+        )
+    }
 }
 
 #[derive(Error, Debug)]
@@ -3,17 +3,23 @@ mod element_in_data_set;
 mod reader;
 
 use crate::core::{
-    DataElement,
-    data_element::vr::VrParseError,
-    data_set::{constants::ITEM_TAG, element_in_data_set::ElementInDataSet},
+    DataElement, Tag,
+    data_element::{Vr, vr::VrParseError},
+    data_set::{
+        constants::{ITEM_DELIMITATION_TAG, ITEM_TAG, PIXEL_DATA_TAG},
+        element_in_data_set::ElementInDataSet,
+    },
     encoding::Encoding,
 };
+use crate::dictionaries::tag_dictionary;
 use std::{io::Cursor, ops::Index, vec::IntoIter};
 
+#[derive(Clone)]
 pub struct DataSet {
     pub(super) encoding: Encoding,
     pub(crate) data_elements: Vec<ElementInDataSet>,
-    size: usize,
+    /// 2GiBを超える画像等も扱えるよう、32ビットでは表せないサイズを許容する
+    size: u64,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -25,12 +31,30 @@ pub enum ParseError {
     UnknownVr(#[from] VrParseError),
 }
 
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ConvertEncodingError {
+    #[error("Explicit VR Big Endianとの変換には対応していません")]
+    UnsupportedEncoding,
+}
+
+/// Bits Allocated (0028,0100)
+const BITS_ALLOCATED_TAG: Tag = Tag(0x0028, 0x0100);
+
 impl DataSet {
+    /// 空のデータセットを生成する。
+    pub fn new(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            data_elements: Vec::new(),
+            size: 0,
+        }
+    }
+
     pub fn encoding(&self) -> Encoding {
         self.encoding
     }
 
-    pub fn size(&self) -> usize {
+    pub fn size(&self) -> u64 {
         self.size
     }
 
@@ -80,6 +104,379 @@ impl DataSet {
         self.data_elements.is_empty()
     }
 
+    /// 指定したタグを持つ最上位のデータ要素のインデックスを返す。
+    pub fn find_index(&self, tag: Tag) -> Option<usize> {
+        self.data_elements
+            .iter()
+            .position(|e| e.parent_index.is_none() && e.tag() == tag)
+    }
+
+    /// 最上位のデータ要素の値フィールドを置き換える。
+    /// 置き換えた場合は`true`、該当するデータ要素が存在しない場合は`false`を返す。
+    ///
+    /// 後続のデータ要素の位置とデータセットのサイズも更新する。
+    /// 値フィールドは呼出側でVRに応じたパディングを行い、偶数長にしておく必要がある。
+    pub fn replace_value_field(&mut self, tag: Tag, value_field: Vec<u8>) -> bool {
+        let Some(index) = self.find_index(tag) else {
+            return false;
+        };
+
+        let old_size = self.data_elements[index].size();
+        let vr = self.data_elements[index].vr();
+        let value_length = value_field.len() as u32;
+        self.data_elements[index].element = DataElement::new(tag, vr, value_length, value_field);
+        let new_size = self.data_elements[index].size();
+
+        for e in self.data_elements.iter_mut().skip(index + 1) {
+            e.position = e.position + new_size as u64 - old_size as u64;
+        }
+        self.size = self.size + new_size as u64 - old_size as u64;
+
+        true
+    }
+
+    /// 最上位にデータ要素を設定する。
+    /// 同じタグのデータ要素が存在する場合は置き換え、存在しない場合はタグの順序に従って挿入する。
+    ///
+    /// 暗黙的VRのデータセットでは`vr`を記録しない。
+    /// 値フィールドは呼出側でVRに応じたパディングを行い、偶数長にしておく必要がある。
+    pub fn set_element(&mut self, tag: Tag, vr: Vr, value_field: Vec<u8>) {
+        self.remove(tag);
+
+        let vr = match self.encoding {
+            Encoding::ImplicitVrLittleEndian => None,
+            _ => Some(vr),
+        };
+        let value_length = value_field.len() as u32;
+        let element = ElementInDataSet {
+            element: DataElement::new(tag, vr, value_length, value_field),
+            position: 0, // splice内で設定する
+            parent_index: None,
+        };
+        self.splice(self.insertion_index(tag), 0, vec![element]);
+    }
+
+    /// 最上位のデータ要素を配下のデータ要素とあわせて削除する。
+    /// 削除した場合は`true`、該当するデータ要素が存在しない場合は`false`を返す。
+    pub fn remove(&mut self, tag: Tag) -> bool {
+        let Some(index) = self.find_index(tag) else {
+            return false;
+        };
+
+        let removed_count = 1 + self.get_descendants_count(index);
+        self.splice(index, removed_count, Vec::new());
+
+        true
+    }
+
+    /// 最上位のシーケンスの末尾にアイテムを追加する。
+    /// シーケンスが存在しない場合は、値長さを明示したシーケンスをタグの順序に従って挿入する。
+    /// 同じタグを持つシーケンスでないデータ要素が存在する場合は何もせず`false`を返す。
+    ///
+    /// アイテムはこのデータセットと同じエンコーディングである必要がある。
+    pub fn push_item(&mut self, tag: Tag, item: DataSet) -> bool {
+        assert!(
+            item.encoding == self.encoding,
+            "アイテムのエンコーディングがデータセットと一致しません"
+        );
+
+        let item_size = 8 // Item Tag + Item Length
+            + item.size;
+        match self.find_index(tag) {
+            Some(index) => {
+                let sequence = &self.data_elements[index];
+                if !sequence.value_field().is_empty()
+                    || sequence.vr().is_some_and(|vr| vr != Vr::Sq)
+                {
+                    return false;
+                }
+
+                let descendants_count = self.get_descendants_count(index);
+                let insertion_index = if sequence.value_length() == 0xffffffff {
+                    // 値長さが不定の場合はシーケンス区切り要素の直前に挿入する
+                    index + descendants_count
+                } else {
+                    self.data_elements[index].element.value_length += item_size as u32;
+                    index + 1 + descendants_count
+                };
+                let elements = Self::item_to_elements(item, insertion_index, index);
+                self.splice(insertion_index, 0, elements);
+            }
+            None => {
+                let index = self.insertion_index(tag);
+                let vr = match self.encoding {
+                    Encoding::ImplicitVrLittleEndian => None,
+                    _ => Some(Vr::Sq),
+                };
+                let mut elements = vec![ElementInDataSet {
+                    element: DataElement::new(tag, vr, item_size as u32, Vec::new()),
+                    position: 0, // splice内で設定する
+                    parent_index: None,
+                }];
+                elements.append(&mut Self::item_to_elements(item, index + 1, index));
+                self.splice(index, 0, elements);
+            }
+        }
+
+        true
+    }
+
+    /// 別のデータセットの最上位のデータ要素を、配下のデータ要素とあわせてこのデータセットに設定する。
+    /// 同じタグのデータ要素が存在する場合は置き換え、存在しない場合はタグの順序に従って挿入する。
+    ///
+    /// 別のデータセットはこのデータセットと同じエンコーディングである必要がある。
+    pub fn copy_element(&mut self, source: &DataSet, index: usize) {
+        assert!(
+            source.encoding == self.encoding,
+            "コピー元のエンコーディングがデータセットと一致しません"
+        );
+        assert!(
+            source.data_elements[index].parent_index.is_none(),
+            "最上位のデータ要素ではありません"
+        );
+
+        let tag = source.data_elements[index].tag();
+        self.remove(tag);
+
+        let insertion_index = self.insertion_index(tag);
+        let end = index + 1 + source.get_descendants_count(index);
+        let elements = source.data_elements[index..end]
+            .iter()
+            .cloned()
+            .map(|mut e| {
+                e.parent_index = e.parent_index.map(|i| i - index + insertion_index);
+                e
+            })
+            .collect();
+        self.splice(insertion_index, 0, elements);
+    }
+
+    /// 最上位のシーケンスに含まれるアイテムを、それぞれ独立したデータセットとして返す。
+    /// アイテム区切り要素は含めない。
+    pub fn items(&self, index: usize) -> Vec<DataSet> {
+        let end = index + 1 + self.get_descendants_count(index);
+        let mut items = Vec::new();
+
+        let mut item_index = index + 1;
+        while item_index < end {
+            let descendants_count = self.get_descendants_count(item_index);
+            let next_item_index = item_index + 1 + descendants_count;
+            if self.data_elements[item_index].tag() != ITEM_TAG {
+                // シーケンス区切り要素
+                item_index = next_item_index;
+                continue;
+            }
+
+            let mut content_end = next_item_index;
+            if content_end > item_index + 1
+                && self.data_elements[content_end - 1].tag() == ITEM_DELIMITATION_TAG
+                && self.data_elements[content_end - 1].parent_index == Some(item_index)
+            {
+                content_end -= 1;
+            }
+
+            let mut position = 0;
+            let data_elements: Vec<_> = self.data_elements[item_index + 1..content_end]
+                .iter()
+                .cloned()
+                .map(|mut e| {
+                    e.parent_index = e
+                        .parent_index
+                        .filter(|&i| i > item_index)
+                        .map(|i| i - item_index - 1);
+                    e.position = position;
+                    position += e.size() as u64;
+                    e
+                })
+                .collect();
+            items.push(DataSet {
+                encoding: self.encoding,
+                data_elements,
+                size: position,
+            });
+
+            item_index = next_item_index;
+        }
+
+        items
+    }
+
+    /// エンコーディングを変換したデータセットを返す。
+    ///
+    /// Implicit VR Little EndianとExplicit VR Little Endianの相互変換に対応する。
+    /// 暗黙的VRから変換する場合は標準DICOMタグ辞書からVRを求め、辞書に存在しないデータ要素や、
+    /// 値長さが2バイトで表せないデータ要素は"UN"とする。
+    /// 値長さを明示したシーケンスとアイテムの値長さは、変換後の子孫要素のサイズから求め直す。
+    pub fn convert_encoding(&self, encoding: Encoding) -> Result<Self, ConvertEncodingError> {
+        if self.encoding == encoding {
+            return Ok(self.clone());
+        }
+        if self.encoding == Encoding::ExplicitVrBigEndian
+            || encoding == Encoding::ExplicitVrBigEndian
+        {
+            return Err(ConvertEncodingError::UnsupportedEncoding);
+        }
+
+        let mut data_elements: Vec<_> = self
+            .data_elements
+            .iter()
+            .map(|e| {
+                let vr = match encoding {
+                    Encoding::ImplicitVrLittleEndian => None,
+                    _ => self.explicit_vr(e),
+                };
+                ElementInDataSet {
+                    element: DataElement::new(
+                        e.tag(),
+                        vr,
+                        e.value_length(),
+                        e.value_field().to_vec(),
+                    ),
+                    position: e.position,
+                    parent_index: e.parent_index,
+                }
+            })
+            .collect();
+
+        // 子孫要素は親要素の直後に符号化されるため、値長さは子孫要素のサイズの合計となる
+        for index in 0..data_elements.len() {
+            let e = &data_elements[index];
+            if !e.value_field().is_empty() || e.value_length() == 0xffffffff {
+                continue;
+            }
+
+            let descendants_count = self.get_descendants_count(index);
+            if descendants_count == 0 {
+                continue;
+            }
+            let value_length: usize = data_elements[index + 1..=index + descendants_count]
+                .iter()
+                .map(|e| e.size())
+                .sum();
+            data_elements[index].element =
+                DataElement::new(e.tag(), e.vr(), value_length as u32, Vec::new());
+        }
+
+        let mut position = self.data_elements.first().map_or(0, |e| e.position);
+        let start = position;
+        for e in data_elements.iter_mut() {
+            e.position = position;
+            position += e.size() as u64;
+        }
+
+        Ok(DataSet {
+            encoding,
+            data_elements,
+            size: position - start,
+        })
+    }
+
+    /// 明示的VRで符号化する際のデータ要素のVRを返す。
+    /// アイテム要素と区切り要素はVRを持たないため`None`を返す。
+    fn explicit_vr(&self, element: &ElementInDataSet) -> Option<Vr> {
+        let tag = element.tag();
+        if tag.group() == 0xfffe {
+            return None;
+        }
+        if let Some(vr) = element.vr() {
+            return Some(vr);
+        }
+
+        let vr = if tag == PIXEL_DATA_TAG {
+            // ネイティブ形式のPixel Dataは、Bits Allocatedが8以下の場合のみOBとなりうる
+            let bits_allocated = self
+                .data_elements
+                .iter()
+                .find(|e| e.parent_index == element.parent_index && e.tag() == BITS_ALLOCATED_TAG)
+                .and_then(|e| e.value_field().get(..2))
+                .map(|v| u16::from_le_bytes([v[0], v[1]]));
+            match bits_allocated {
+                Some(bits) if bits <= 8 => Vr::Ob,
+                _ => Vr::Ow,
+            }
+        } else if element.value_length() == 0xffffffff {
+            // 値長さが不定のデータ要素は、暗黙的VRではシーケンスとしてのみ符号化できる
+            Vr::Sq
+        } else {
+            tag_dictionary::search_vr(tag).unwrap_or(Vr::Un)
+        };
+
+        // 値長さが2バイトのVRで表せない値はUNとする
+        let is_short = DataElement::new(tag, Some(vr), 0, Vec::new()).size() == 8;
+        if is_short && element.value_length() > 0xffff {
+            Some(Vr::Un)
+        } else {
+            Some(vr)
+        }
+    }
+
+    /// タグの順序に従ってデータ要素を挿入する位置を返す。
+    fn insertion_index(&self, tag: Tag) -> usize {
+        self.data_elements
+            .iter()
+            .position(|e| e.parent_index.is_none() && e.tag() > tag)
+            .unwrap_or(self.data_elements.len())
+    }
+
+    /// アイテムを、アイテム要素とその子孫要素に変換する。
+    /// `item_index`はアイテム要素を配置するインデックス、`parent_index`は親となるシーケンス要素のインデックスを表す。
+    fn item_to_elements(
+        item: DataSet,
+        item_index: usize,
+        parent_index: usize,
+    ) -> Vec<ElementInDataSet> {
+        let mut elements = Vec::with_capacity(1 + item.data_elements.len());
+        elements.push(ElementInDataSet {
+            element: DataElement::new(ITEM_TAG, None, item.size as u32, Vec::new()),
+            position: 0, // splice内で設定する
+            parent_index: Some(parent_index),
+        });
+        elements.extend(item.data_elements.into_iter().map(|mut e| {
+            e.parent_index = Some(match e.parent_index {
+                Some(i) => item_index + 1 + i,
+                None => item_index,
+            });
+            e
+        }));
+        elements
+    }
+
+    /// `index`から`removed_count`個のデータ要素を`inserted`に置き換え、後続のデータ要素の位置と親のインデックス、およびデータセットのサイズを更新する。
+    fn splice(&mut self, index: usize, removed_count: usize, mut inserted: Vec<ElementInDataSet>) {
+        let end = index + removed_count;
+        let position = match self.data_elements.get(index) {
+            Some(e) => e.position,
+            None => self
+                .data_elements
+                .last()
+                .map_or(0, |e| e.position + e.size() as u64),
+        };
+        let removed_size: usize = self.data_elements[index..end]
+            .iter()
+            .map(|e| e.size())
+            .sum();
+        let inserted_size: usize = inserted.iter().map(|e| e.size()).sum();
+        let inserted_count = inserted.len();
+
+        let mut current_position = position;
+        for e in inserted.iter_mut() {
+            e.position = current_position;
+            current_position += e.size() as u64;
+        }
+        for e in self.data_elements.iter_mut().skip(end) {
+            e.position = e.position + inserted_size as u64 - removed_size as u64;
+            e.parent_index = e.parent_index.map(|i| {
+                if i >= end {
+                    i + inserted_count - removed_count
+                } else {
+                    i
+                }
+            });
+        }
+        self.data_elements.splice(index..end, inserted);
+        self.size = self.size + inserted_size as u64 - removed_size as u64;
+    }
+
     pub fn read_from_cur(cur: &mut Cursor<&[u8]>, encoding: Encoding) -> Result<Self, ParseError> {
         let len = cur.get_ref().len() as u64 - cur.position();
         let data_elements = match encoding {
@@ -97,11 +494,29 @@ impl DataSet {
         Ok(DataSet {
             encoding,
             data_elements,
-            size: len as usize,
+            size: len,
         })
     }
 }
 
+#[cfg(test)]
+impl DataSet {
+    /// 値フィールドを確保せずに、`value_length`の値を持つものとしてデータ要素を設定する。
+    /// 2GiBを超えるデータセットのサイズや位置の計算を、実際にメモリを確保せずに検証するために使用する。
+    pub(crate) fn set_element_without_value(&mut self, tag: Tag, vr: Vr, value_length: u32) {
+        self.remove(tag);
+
+        let mut element = DataElement::new(tag, Some(vr), value_length, Vec::new());
+        element.size += value_length as usize;
+        let element = ElementInDataSet {
+            element,
+            position: 0, // splice内で設定する
+            parent_index: None,
+        };
+        self.splice(self.insertion_index(tag), 0, vec![element]);
+    }
+}
+
 impl Index<usize> for DataSet {
     type Output = DataElement;
 
@@ -112,7 +527,7 @@ impl Index<usize> for DataSet {
 
 impl From<DataSet> for Vec<u8> {
     fn from(v: DataSet) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(v.size);
+        let mut bytes = Vec::with_capacity(v.size as usize);
 
         for element_in_data_set in v.data_elements {
             bytes.append(&mut element_in_data_set.into());
@@ -138,7 +553,7 @@ impl<'a> IntoIterator for &'a DataSet {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{Tag, data_element::Vr};
+    use crate::core::data_element::Vr;
     use std::io::SeekFrom;
     use tokio::{fs, io::AsyncSeekExt};
 
@@ -258,4 +673,213 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[tokio::test]
+    async fn test_replace_value_field() {
+        // Arrange
+        let mut data_set = {
+            let buf = fs::read("../../data/dicom/GENECG").await.unwrap();
+            let mut cur = Cursor::new(buf.as_ref());
+            cur.seek(SeekFrom::Current(0x00000160)).await.unwrap();
+            DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian).unwrap()
+        };
+        let old_size = data_set.size();
+
+        // Act
+        // Patient ID (18バイト) を8バイトの値に置き換える
+        let replaced = data_set.replace_value_field(Tag(0x0010, 0x0020), b"P000001 ".to_vec());
+        // 最上位に存在しないタグは置き換えられない
+        let not_replaced = data_set.replace_value_field(Tag(0x0008, 0x0100), b"CODE".to_vec());
+
+        // Assert
+        assert!(replaced);
+        assert!(!not_replaced);
+        assert_eq!(data_set[37].tag(), Tag(0x0010, 0x0020));
+        assert_eq!(data_set[37].vr(), Some(Vr::Lo));
+        assert_eq!(data_set[37].value_length(), 8);
+        assert_eq!(data_set[37].value_field(), b"P000001 ");
+        assert_eq!(data_set[37].size(), 16);
+        assert_eq!(data_set[33].value_field(), b"122400");
+        // 置き換えたデータ要素より前の位置は変わらず、後続のデータ要素は10バイト前にずれる
+        assert_eq!(data_set.get_position(37), 0x0000055a);
+        assert_eq!(data_set.get_position(38), 0x00000574 - 10);
+        assert_eq!(data_set.get_position(80), 0x000018e4 - 10);
+        assert_eq!(data_set.size(), old_size - 10);
+
+        let bytes: Vec<u8> = data_set.into();
+        assert_eq!(bytes.len() as u64, old_size - 10);
+    }
+
+    #[tokio::test]
+    async fn test_edit_top_level_elements() {
+        // Arrange
+        let mut data_set = {
+            let buf = fs::read("../../data/dicom/GENECG").await.unwrap();
+            let mut cur = Cursor::new(buf.as_ref());
+            cur.seek(SeekFrom::Current(0x00000160)).await.unwrap();
+            DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian).unwrap()
+        };
+        let old_size = data_set.size();
+        let old_len = data_set.len();
+        let item = |value: &[u8]| {
+            let mut item = DataSet::new(Encoding::ExplicitVrLittleEndian);
+            item.set_element(Tag(0x0400, 0x0565), Vr::Cs, value.to_vec());
+            item
+        };
+
+        // Act
+        // 配下に3つのデータ要素を持つシーケンス (122バイト) を削除する
+        let removed = data_set.remove(Tag(0x0008, 0x114a));
+        let not_removed = data_set.remove(Tag(0x0008, 0x114a));
+        // 既存のデータ要素 (36バイト) を置き換え、存在しないデータ要素 (14バイト) を挿入する
+        data_set.set_element(Tag(0x0008, 0x0080), Vr::Lo, b"OCEANUS ".to_vec());
+        data_set.set_element(Tag(0x0010, 0x0021), Vr::Lo, b"ISSUER".to_vec());
+        // 存在しないシーケンスにアイテムを追加した後、同じシーケンスにアイテムを追加する
+        let pushed = data_set.push_item(Tag(0x0400, 0x0561), item(b"COERCE"));
+        let pushed_again = data_set.push_item(Tag(0x0400, 0x0561), item(b"CORRECT "));
+        // シーケンスでないデータ要素にはアイテムを追加できない
+        let not_pushed = data_set.push_item(Tag(0x0010, 0x0020), item(b"COERCE"));
+
+        // Assert
+        assert!(removed);
+        assert!(!not_removed);
+        assert!(pushed);
+        assert!(pushed_again);
+        assert!(!not_pushed);
+        // 削除: -122バイト, 置き換え: 16-36バイト, 挿入: +14バイト, シーケンス: 12+(8+14)+(8+16)バイト
+        let expected_size = old_size - 122 + 16 - 36 + 14 + 12 + 22 + 24;
+        assert_eq!(data_set.size(), expected_size);
+        assert_eq!(data_set.len(), old_len - 4 + 1 + 5);
+
+        // 書き出したバイト列を読み込み直しても同じ構造になる
+        let bytes: Vec<u8> = data_set.into();
+        assert_eq!(bytes.len() as u64, expected_size);
+        let actual = {
+            let mut cur = Cursor::new(bytes.as_ref());
+            DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian).unwrap()
+        };
+        assert_eq!(actual.find_index(Tag(0x0008, 0x114a)), None);
+        let index = actual.find_index(Tag(0x0008, 0x0080)).unwrap();
+        assert_eq!(actual[index].value_field(), b"OCEANUS ");
+        // 削除したシーケンスの後続のシーケンスの親子関係が維持される
+        let index = actual.find_index(Tag(0x0008, 0x1250)).unwrap();
+        assert_eq!(index, 23);
+        assert_eq!(actual.get_descendants_count(index), 8);
+        assert_eq!(actual.get_parent_index(index + 1), Some(index));
+        let index = actual.find_index(Tag(0x0010, 0x0021)).unwrap();
+        assert_eq!(actual[index - 1].tag(), Tag(0x0010, 0x0020));
+        assert_eq!(actual[index].value_field(), b"ISSUER");
+        let index = actual.find_index(Tag(0x0400, 0x0561)).unwrap();
+        assert_eq!(actual[index].value_length(), 46);
+        assert_eq!(actual.get_descendants_count(index), 4);
+        assert_eq!(actual[index + 2].value_field(), b"COERCE");
+        assert_eq!(actual[index + 4].value_field(), b"CORRECT ");
+        assert_eq!(actual.get_parent_index(index + 3), Some(index));
+        assert_eq!(actual.get_parent_index(index + 4), Some(index + 3));
+        assert_eq!(actual[index + 5].tag(), Tag(0x5400, 0x0100));
+    }
+
+    #[tokio::test]
+    async fn test_items_and_copy_element() {
+        // Arrange
+        let source = {
+            let buf = fs::read("../../data/dicom/GENECG").await.unwrap();
+            let mut cur = Cursor::new(buf.as_ref());
+            cur.seek(SeekFrom::Current(0x00000160)).await.unwrap();
+            DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian).unwrap()
+        };
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+
+        // Act
+        // 値長さが不定のアイテムを持つシーケンスと、入れ子のシーケンスを持つシーケンスからアイテムを取り出す
+        let waveform_items = source.items(58);
+        let related_series_items = source.items(27);
+        // 後ろのデータ要素から順にコピーしても、タグの順序に従って挿入される
+        data_set.copy_element(&source, 58);
+        data_set.copy_element(&source, 27);
+        data_set.copy_element(&source, 27);
+
+        // Assert
+        // アイテム区切り要素は含まれない
+        assert_eq!(waveform_items.len(), 1);
+        assert_eq!(waveform_items[0].len(), 20);
+        assert_eq!(waveform_items[0][0].tag(), Tag(0x0018, 0x1068));
+        assert_eq!(waveform_items[0][19].tag(), Tag(0x5400, 0x1010));
+        assert_eq!(waveform_items[0].size(), 4478 - 8);
+        assert_eq!(related_series_items.len(), 1);
+        assert_eq!(related_series_items[0].len(), 7);
+        assert_eq!(related_series_items[0].size(), 206);
+        assert_eq!(related_series_items[0].get_parent_index(0), None);
+        assert_eq!(related_series_items[0].get_parent_index(3), Some(2));
+        assert_eq!(related_series_items[0].get_descendants_count(2), 4);
+        assert_eq!(related_series_items[0].get_position(2), 128);
+
+        // コピーした要素は配下の要素とあわせて元の構造を維持する
+        assert_eq!(data_set.len(), 9 + 23);
+        assert_eq!(data_set.size(), 214 + 12 + 4486 + 12);
+        assert_eq!(data_set[0].tag(), Tag(0x0008, 0x1250));
+        assert_eq!(data_set.get_descendants_count(0), 8);
+        assert_eq!(data_set[9].tag(), Tag(0x5400, 0x0100));
+        assert_eq!(data_set.get_descendants_count(9), 22);
+        assert_eq!(data_set.get_parent_index(10), Some(9));
+        assert_eq!(data_set.get_parent_index(31), Some(10));
+        assert_eq!(data_set.get_position(9), 226);
+    }
+
+    #[tokio::test]
+    async fn test_convert_encoding() {
+        // Arrange
+        let buf = fs::read("../../data/dicom/GENECG").await.unwrap();
+        let data_set = {
+            let mut cur = Cursor::new(buf.as_ref());
+            cur.seek(SeekFrom::Current(0x00000160)).await.unwrap();
+            DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian).unwrap()
+        };
+
+        // Act
+        let implicit = data_set
+            .convert_encoding(Encoding::ImplicitVrLittleEndian)
+            .unwrap();
+        let implicit_bytes: Vec<u8> = implicit.clone().into();
+        let reread = DataSet::read_from_cur(
+            &mut Cursor::new(implicit_bytes.as_ref()),
+            Encoding::ImplicitVrLittleEndian,
+        )
+        .unwrap();
+        let explicit = reread
+            .convert_encoding(Encoding::ExplicitVrLittleEndian)
+            .unwrap();
+        let unsupported = data_set.convert_encoding(Encoding::ExplicitVrBigEndian);
+
+        // Assert
+        // 暗黙的VRではVRを持たず、値長さが4バイトのVRのヘッダーが4バイト短くなる
+        assert_eq!(implicit.encoding(), Encoding::ImplicitVrLittleEndian);
+        assert_eq!(implicit.len(), data_set.len());
+        assert!(implicit.into_iter().all(|e| e.vr().is_none()));
+        assert_eq!(implicit.get_position(0), 0x00000160);
+        assert_eq!(implicit.size(), data_set.size() - 4 * 8);
+        assert_eq!(implicit_bytes.len() as u64, implicit.size());
+        // 値長さを明示したシーケンスとアイテムの値長さは子孫要素のサイズから求め直す
+        assert_eq!(implicit[23].value_length(), 110);
+        assert_eq!(implicit[27].value_length(), 214 - 4);
+        assert_eq!(implicit[28].value_length(), 206 - 4);
+        assert_eq!(implicit[57].value_length(), 0);
+        assert_eq!(implicit[58].value_length(), 4486 - 12);
+        // 値長さが不定のアイテムはそのまま
+        assert_eq!(implicit[59].value_length(), 0xffffffff);
+        assert_eq!(implicit[79].value_field(), data_set[79].value_field());
+
+        // 暗黙的VRとして読み直した後、明示的VRに戻すと元のバイト列と一致する
+        assert_eq!(reread.len(), data_set.len());
+        assert_eq!(explicit.encoding(), Encoding::ExplicitVrLittleEndian);
+        assert_eq!(explicit[23].vr(), Some(Vr::Sq));
+        assert_eq!(explicit[79].vr(), Some(Vr::Ob));
+        let explicit_bytes: Vec<u8> = explicit.into();
+        assert_eq!(explicit_bytes, &buf[0x00000160..]);
+
+        assert_eq!(
+            unsupported.err(),
+            Some(ConvertEncodingError::UnsupportedEncoding)
+        );
+    }
 }
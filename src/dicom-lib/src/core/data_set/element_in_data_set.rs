@@ -1,5 +1,6 @@
 use crate::core::{DataElement, data_element::Vr, tag::Tag};
 
+#[derive(Clone)]
 pub(crate) struct ElementInDataSet {
     pub(crate) element: DataElement,
     pub(crate) position: u64,
@@ -327,11 +327,7 @@ fn read_value_field(
 
     let vr = match vr {
         Some(vr) => vr,
-        None => tag_dictionary::search(tag).map_or(Vr::Un, |item| {
-            let vr_bytes = item.vr.as_bytes();
-            Vr::try_from([vr_bytes[0], vr_bytes[1]])
-                .expect("標準DICOMタグ辞書は2バイト以上の長さで正しいタグを保有しているはず")
-        }),
+        None => tag_dictionary::search_vr(tag).unwrap_or(Vr::Un),
     };
 
     if vr == Vr::Sq || value_length == 0xffffffff {
@@ -371,6 +367,14 @@ fn update_parent_index_for_each_element(
     }
 }
 
+/// 暗黙的VRのシーケンス要素であるかを判定する。
+fn is_implicit_vr_sequence(element: &ElementInDataSet) -> bool {
+    // アイテム要素や区切り要素は標準DICOMタグ辞書にVRを持たないため除外する
+    element.vr().is_none()
+        && element.tag().group() != 0xfffe
+        && tag_dictionary::search_vr(element.tag()) == Some(Vr::Sq)
+}
+
 fn calculate_descendants_count(elements: &[ElementInDataSet], index: usize) -> usize {
     if !elements[index].value_field().is_empty() {
         // 子孫要素の有無に関わらず、親要素自身は値フィールドを持たない。
@@ -379,8 +383,10 @@ fn calculate_descendants_count(elements: &[ElementInDataSet], index: usize) -> u
     } else if elements[index].vr() != Some(Vr::Sq)
         && elements[index].tag() != ITEM_TAG
         && elements[index].value_length() != 0xffffffff
+        && !is_implicit_vr_sequence(&elements[index])
     {
         // シーケンス要素 / アイテム要素 / カプセル化されたPixel Data要素 / 不定な値長さを持つVRが"UN"のデータ要素 は子孫要素を持つ可能性がある。
+        // 暗黙的VRのシーケンス要素はVRを持たないため、標準DICOMタグ辞書のVRで判定する。
         // その条件に合致しない場合は、そのデータ要素の子孫が存在しないことになる。
         // なお、カプセル化されたPixel Data要素の値長さは0xffffffffであるため、不定な値長さを持つVRが"UN"のデータ要素と同じ条件で判定を行っている。
         return 0;
@@ -444,11 +450,11 @@ fn calculate_descendants_count(elements: &[ElementInDataSet], index: usize) -> u
         // 減算の結果値が0以下になった場合に処理を抜ける。
         // このとき、カウントされたデータ要素の個数が子孫要素の個数である。
         let mut descendants_count = 0;
-        let mut size = elements[index].value_length() as i32;
+        let mut size = i64::from(elements[index].value_length());
         for element in elements.iter().skip(index + 1) {
             descendants_count += 1;
 
-            size -= element.size() as i32;
+            size -= element.size() as i64;
             if size <= 0 {
                 break;
             }
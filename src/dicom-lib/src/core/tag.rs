@@ -1,6 +1,7 @@
 use std::{
     fmt::{Display, Formatter},
     io::Read,
+    str::FromStr,
 };
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -34,6 +35,27 @@ impl Tag {
     }
 }
 
+#[derive(thiserror::Error, Debug)]
+pub enum TagParseError {
+    #[error("タグは16進数8桁で表す必要があります (文字列=\"{0}\")")]
+    InvalidFormat(String),
+}
+
+/// `"00100020"`の形式 (DICOM JSONモデルにおけるタグの表記) の文字列からタグを生成する。
+impl FromStr for Tag {
+    type Err = TagParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 8 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(TagParseError::InvalidFormat(s.to_string()));
+        }
+
+        let group = u16::from_str_radix(&s[0..4], 16).unwrap();
+        let element = u16::from_str_radix(&s[4..8], 16).unwrap();
+        Ok(Tag(group, element))
+    }
+}
+
 impl std::fmt::Debug for Tag {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "Tag({:#06x?}, {:#06x?})", self.0, self.1)
@@ -46,6 +68,13 @@ impl Display for Tag {
     }
 }
 
+/// `"00100020"`の形式 (DICOM JSONモデルにおけるタグの表記) で出力する。
+impl std::fmt::UpperHex for Tag {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{:04X}{:04X}", self.0, self.1)
+    }
+}
+
 impl From<Tag> for Vec<u8> {
     fn from(tag: Tag) -> Self {
         let mut bytes = Vec::with_capacity(4);
@@ -65,3 +94,23 @@ impl From<Tag> for [u8; 4] {
         bytes
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("00100020".parse::<Tag>().unwrap(), Tag(0x0010, 0x0020));
+        assert_eq!("7fe0ffFF".parse::<Tag>().unwrap(), Tag(0x7fe0, 0xffff));
+        assert!("0010002".parse::<Tag>().is_err());
+        assert!("(0010,0020)".parse::<Tag>().is_err());
+        assert!("0010002G".parse::<Tag>().is_err());
+    }
+
+    #[test]
+    fn test_upper_hex() {
+        assert_eq!(format!("{:X}", Tag(0x0010, 0x0020)), "00100020");
+        assert_eq!(format!("{:X}", Tag(0x7fe0, 0xffff)), "7FE0FFFF");
+    }
+}
@@ -7,7 +7,9 @@ use specific_character_set::{
     iso_2022_ir_13_and_iso_2022_ir_87, iso_ir_13, iso_ir_192, none,
 };
 
-fn generate_string_lossy(bytes: &[u8], char_set: SpecificCharacterSet) -> String {
+/// 文字列の値表現の値フィールドを、文字セットに従って復号した文字列に変換する。
+/// 復号できないバイト列は置換文字に置き換える。
+pub fn generate_string_lossy(bytes: &[u8], char_set: SpecificCharacterSet) -> String {
     match char_set {
         SpecificCharacterSet::None => none::generate_string_lossy(bytes),
         SpecificCharacterSet::IsoIr13 => iso_ir_13::generate_string_lossy(bytes),
@@ -24,7 +26,12 @@ fn generate_string_lossy(bytes: &[u8], char_set: SpecificCharacterSet) -> String
     }
 }
 
-fn generate_person_name_strings_lossy(bytes: &[u8], char_set: SpecificCharacterSet) -> Vec<String> {
+/// 人名（PN）の値フィールドを、文字セットに従って値ごとに復号した文字列に変換する。
+/// 各文字列は`=`で区切られた構成要素グループを含む。
+pub fn generate_person_name_strings_lossy(
+    bytes: &[u8],
+    char_set: SpecificCharacterSet,
+) -> Vec<String> {
     if bytes.is_empty() {
         return vec![String::new()];
     }
@@ -0,0 +1,551 @@
+mod profile;
+
+use crate::{
+    core::{DataSet, Tag, data_element::Vr},
+    dictionaries::tag_dictionary,
+    uid::{self, UidScheme},
+};
+use std::collections::HashMap;
+
+/// 匿名化のオプション
+///
+/// 各オプションは PS3.15 Table E.1-1 のオプションに対応する。
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DeidentificationOptions {
+    /// Retain Longitudinal Temporal Information with Full Dates Option
+    pub retain_longitudinal_temporal_information: bool,
+    /// Retain Patient Characteristics Option
+    pub retain_patient_characteristics: bool,
+    /// Clean Descriptors Option
+    pub clean_descriptors: bool,
+}
+
+/// Basic Application Level Confidentiality Profileに従ってデータセットを匿名化する。
+///
+/// UIDの置き換え結果はインスタンスを跨いで保持するため、
+/// 同じ検査 (または関連する複数の検査) に属するインスタンスは同じ`Deidentifier`で匿名化する必要がある。
+pub struct Deidentifier {
+    options: DeidentificationOptions,
+    uid_map: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    /// ダミー値に置き換える
+    D,
+    /// 空の値に置き換える
+    Z,
+    /// 削除する
+    X,
+    /// そのまま保持する
+    K,
+    /// 識別情報を取り除いた値に置き換える
+    C,
+    /// 一貫性を保ったまま別のUIDに置き換える
+    U,
+}
+
+const PATIENT_IDENTITY_REMOVED: Tag = Tag(0x0012, 0x0062);
+const DEIDENTIFICATION_METHOD: Tag = Tag(0x0012, 0x0063);
+const DEIDENTIFICATION_METHOD_CODE_SEQUENCE: Tag = Tag(0x0012, 0x0064);
+const LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED: Tag = Tag(0x0028, 0x0303);
+const CODE_VALUE: Tag = Tag(0x0008, 0x0100);
+const CODING_SCHEME_DESIGNATOR: Tag = Tag(0x0008, 0x0102);
+const CODE_MEANING: Tag = Tag(0x0008, 0x0104);
+
+// DICOM PS3.16 - CID 7050 De-identification Method
+const BASIC_PROFILE_CODE: (&str, &str) = ("113100", "Basic Application Confidentiality Profile");
+const CLEAN_DESCRIPTORS_CODE: (&str, &str) = ("113105", "Clean Descriptors Option");
+const RETAIN_LONGITUDINAL_TEMPORAL_INFORMATION_CODE: (&str, &str) = (
+    "113106",
+    "Retain Longitudinal Temporal Information Full Dates Option",
+);
+const RETAIN_PATIENT_CHARACTERISTICS_CODE: (&str, &str) =
+    ("113108", "Retain Patient Characteristics Option");
+
+/// 記述子から取り除く識別情報の収集元となる属性
+const IDENTIFYING_TAGS: [Tag; 7] = [
+    Tag(0x0010, 0x0010), // Patient's Name
+    Tag(0x0010, 0x0020), // Patient ID
+    Tag(0x0010, 0x0030), // Patient's Birth Date
+    Tag(0x0010, 0x1000), // Other Patient IDs
+    Tag(0x0010, 0x1001), // Other Patient Names
+    Tag(0x0008, 0x0050), // Accession Number
+    Tag(0x0008, 0x0090), // Referring Physician's Name
+];
+
+impl Deidentifier {
+    pub fn new(options: DeidentificationOptions) -> Self {
+        Self {
+            options,
+            uid_map: HashMap::new(),
+        }
+    }
+
+    pub fn options(&self) -> DeidentificationOptions {
+        self.options
+    }
+
+    /// データセットを匿名化した新しいデータセットを返す。
+    ///
+    /// シーケンス内のデータ要素にも再帰的に処理を適用し、
+    /// 最後にPatient Identity Removed (0012,0062) とDe-identification Method (0012,0063) 等を記録する。
+    pub fn deidentify(&mut self, data_set: &DataSet) -> DataSet {
+        let identifiers = collect_identifiers(data_set);
+        let mut deidentified = self.process(data_set, &identifiers);
+        self.record_method(&mut deidentified);
+        deidentified
+    }
+
+    /// UIDを置き換える。
+    /// 同じUIDには常に同じUIDを返す。複数値の場合はそれぞれの値を置き換える。
+    pub fn remap_uid(&mut self, uid: &str) -> String {
+        uid.trim_end_matches(['\0', ' '])
+            .split('\\')
+            .map(|uid| {
+                self.uid_map
+                    .entry(uid.to_string())
+                    .or_insert_with(|| uid::generate(UidScheme::UuidDerived).to_string())
+                    .clone()
+            })
+            .collect::<Vec<_>>()
+            .join("\\")
+    }
+
+    fn process(&mut self, data_set: &DataSet, identifiers: &[Vec<u8>]) -> DataSet {
+        let mut ret = DataSet::new(data_set.encoding());
+
+        for index in 0..data_set.len() {
+            if data_set.get_parent_index(index).is_some() {
+                continue;
+            }
+
+            let element = &data_set[index];
+            let tag = element.tag();
+            let vr = match element.vr() {
+                None | Some(Vr::Un) => tag_dictionary::search_vr(tag).unwrap_or(Vr::Un),
+                Some(vr) => vr,
+            };
+            let action = self.action(tag, vr);
+
+            if vr == Vr::Sq {
+                match action {
+                    Action::X => {}
+                    // シーケンスのダミー値は作成できないため、空のシーケンスにする
+                    Action::Z | Action::D => ret.set_element(tag, Vr::Sq, Vec::new()),
+                    Action::K | Action::C | Action::U => {
+                        ret.set_element(tag, Vr::Sq, Vec::new());
+                        for item in data_set.items(index) {
+                            let item = self.process(&item, identifiers);
+                            ret.push_item(tag, item);
+                        }
+                    }
+                }
+                continue;
+            }
+
+            match action {
+                Action::X => {}
+                Action::Z => ret.set_element(tag, vr, Vec::new()),
+                Action::D => {
+                    let value_field = self.dummy_value(vr);
+                    ret.set_element(tag, vr, value_field);
+                }
+                Action::U if vr == Vr::Ui => {
+                    let uid = String::from_utf8_lossy(element.value_field()).to_string();
+                    let remapped = self.remap_uid(&uid);
+                    ret.set_element(tag, vr, pad(remapped.into_bytes(), b'\0'));
+                }
+                Action::C if is_descriptor(vr) => {
+                    let value_field = clean(element.value_field(), identifiers);
+                    ret.set_element(tag, vr, pad(value_field, b' '));
+                }
+                _ => ret.copy_element(data_set, index),
+            }
+        }
+
+        ret
+    }
+
+    /// 属性に適用する処理を決定する。
+    fn action(&self, tag: Tag, vr: Vr) -> Action {
+        if !tag.group().is_multiple_of(2) || tag.element() == 0x0000 {
+            // プライベート属性 / グループ長
+            return Action::X;
+        }
+
+        let Some(item) = profile::search(tag) else {
+            // 表にない標準の属性は保持する。
+            // ただし、人名は識別情報である可能性が高いため空にする。
+            return if vr == Vr::Pn { Action::Z } else { Action::K };
+        };
+
+        let mut code = item.basic;
+        if self.options.retain_patient_characteristics && !item.rpc.is_empty() {
+            code = item.rpc;
+        }
+        if self.options.retain_longitudinal_temporal_information && !item.rlt.is_empty() {
+            code = item.rlt;
+        }
+        if self.options.clean_descriptors && !item.cd.is_empty() {
+            code = item.cd;
+        }
+
+        // "X/Z/D"のように複数の処理を取りうる場合は、IODの要件を満たせるよう最も右の処理を採用する。
+        // "U*"等の"*"はシーケンスの場合に配下の属性へ処理を適用することを表すが、シーケンスは常に再帰的に処理する。
+        let code = code
+            .rsplit('/')
+            .next()
+            .unwrap_or(code)
+            .trim_end_matches('*');
+        match code {
+            "D" => Action::D,
+            "Z" => Action::Z,
+            "K" => Action::K,
+            "C" => Action::C,
+            "U" => Action::U,
+            _ => Action::X,
+        }
+    }
+
+    /// VRに応じたダミー値を返す。
+    fn dummy_value(&mut self, vr: Vr) -> Vec<u8> {
+        match vr {
+            Vr::Ae
+            | Vr::Cs
+            | Vr::Lo
+            | Vr::Lt
+            | Vr::Pn
+            | Vr::Sh
+            | Vr::St
+            | Vr::Uc
+            | Vr::Ur
+            | Vr::Ut => pad(b"ANONYMOUS".to_vec(), b' '),
+            Vr::As => b"000D".to_vec(),
+            Vr::Da => b"19000101".to_vec(),
+            Vr::Dt => b"19000101000000".to_vec(),
+            Vr::Tm => b"000000".to_vec(),
+            Vr::Ds | Vr::Is => pad(b"0".to_vec(), b' '),
+            Vr::Ui => uid::generate(UidScheme::UuidDerived).to_bytes(),
+            Vr::Us | Vr::Ss => vec![0; 2],
+            Vr::At | Vr::Fl | Vr::Sl | Vr::Ul => vec![0; 4],
+            Vr::Fd | Vr::Sv | Vr::Uv => vec![0; 8],
+            _ => Vec::new(),
+        }
+    }
+
+    /// 匿名化の方法を記録する。
+    fn record_method(&self, data_set: &mut DataSet) {
+        let mut codes = vec![BASIC_PROFILE_CODE];
+        if self.options.retain_longitudinal_temporal_information {
+            codes.push(RETAIN_LONGITUDINAL_TEMPORAL_INFORMATION_CODE);
+        }
+        if self.options.retain_patient_characteristics {
+            codes.push(RETAIN_PATIENT_CHARACTERISTICS_CODE);
+        }
+        if self.options.clean_descriptors {
+            codes.push(CLEAN_DESCRIPTORS_CODE);
+        }
+
+        data_set.set_element(PATIENT_IDENTITY_REMOVED, Vr::Cs, b"YES ".to_vec());
+
+        let method = codes
+            .iter()
+            .map(|(_, meaning)| *meaning)
+            .collect::<Vec<_>>()
+            .join("\\");
+        data_set.set_element(
+            DEIDENTIFICATION_METHOD,
+            Vr::Lo,
+            pad(method.into_bytes(), b' '),
+        );
+
+        data_set.set_element(DEIDENTIFICATION_METHOD_CODE_SEQUENCE, Vr::Sq, Vec::new());
+        for (value, meaning) in codes {
+            let mut item = DataSet::new(data_set.encoding());
+            item.set_element(CODE_VALUE, Vr::Sh, pad(value.as_bytes().to_vec(), b' '));
+            item.set_element(CODING_SCHEME_DESIGNATOR, Vr::Sh, b"DCM ".to_vec());
+            item.set_element(CODE_MEANING, Vr::Lo, pad(meaning.as_bytes().to_vec(), b' '));
+            data_set.push_item(DEIDENTIFICATION_METHOD_CODE_SEQUENCE, item);
+        }
+
+        let modified: &[u8] = if self.options.retain_longitudinal_temporal_information {
+            b"UNMODIFIED"
+        } else {
+            b"REMOVED "
+        };
+        data_set.set_element(
+            LONGITUDINAL_TEMPORAL_INFORMATION_MODIFIED,
+            Vr::Cs,
+            modified.to_vec(),
+        );
+    }
+}
+
+/// 記述子から取り除く識別情報を収集する。
+/// 長い識別情報から順に取り除けるよう、長さの降順に並べて返す。
+fn collect_identifiers(data_set: &DataSet) -> Vec<Vec<u8>> {
+    let mut identifiers: Vec<Vec<u8>> = IDENTIFYING_TAGS
+        .iter()
+        .filter_map(|&tag| data_set.find_index(tag))
+        .flat_map(|index| {
+            data_set[index]
+                .value_field()
+                .split(|b| matches!(b, b'\\' | b'^' | b'='))
+                .map(|s| s.trim_ascii().to_vec())
+                .collect::<Vec<_>>()
+        })
+        // 1文字の識別情報は記述子中の無関係な文字まで取り除いてしまうため対象外とする
+        .filter(|s| s.len() >= 2)
+        .collect();
+    identifiers.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    identifiers.dedup();
+    identifiers
+}
+
+/// 値から識別情報を大文字小文字を区別せずに取り除く。
+fn clean(value: &[u8], identifiers: &[Vec<u8>]) -> Vec<u8> {
+    let mut value = value.to_vec();
+    for identifier in identifiers {
+        let mut ret = Vec::with_capacity(value.len());
+        let mut i = 0;
+        while i < value.len() {
+            if value[i..].len() >= identifier.len()
+                && value[i..i + identifier.len()].eq_ignore_ascii_case(identifier)
+            {
+                i += identifier.len();
+            } else {
+                ret.push(value[i]);
+                i += 1;
+            }
+        }
+        value = ret;
+    }
+
+    let len = value.trim_ascii_end().len();
+    value.truncate(len);
+    value
+}
+
+/// 記述子 (自由記述のテキスト) のVRであるかを判定する。
+fn is_descriptor(vr: Vr) -> bool {
+    matches!(vr, Vr::Lo | Vr::Lt | Vr::Sh | Vr::St | Vr::Uc | Vr::Ut)
+}
+
+/// 値フィールドを偶数長にパディングする。
+fn pad(mut value_field: Vec<u8>, padding: u8) -> Vec<u8> {
+    if !value_field.len().is_multiple_of(2) {
+        value_field.push(padding);
+    }
+    value_field
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Encoding;
+    use std::io::{Cursor, SeekFrom};
+    use tokio::{fs, io::AsyncSeekExt};
+
+    async fn read_genecg() -> DataSet {
+        let buf = fs::read("../../data/dicom/GENECG").await.unwrap();
+        let mut cur = Cursor::new(buf.as_ref());
+        cur.seek(SeekFrom::Current(0x00000160)).await.unwrap();
+        DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian).unwrap()
+    }
+
+    fn value(data_set: &DataSet, tag: Tag) -> Option<&[u8]> {
+        data_set
+            .find_index(tag)
+            .map(|index| data_set[index].value_field())
+    }
+
+    fn uid(value: &[u8]) -> String {
+        String::from_utf8_lossy(value)
+            .trim_end_matches('\0')
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_deidentify() {
+        // Arrange
+        let mut data_set = read_genecg().await;
+        data_set.set_element(Tag(0x0009, 0x0010), Vr::Lo, b"OCEANUS ".to_vec());
+        data_set.set_element(Tag(0x0009, 0x1001), Vr::Lo, b"PRIVATE ".to_vec());
+        let mut deidentifier = Deidentifier::new(DeidentificationOptions::default());
+
+        // Act
+        let actual = deidentifier.deidentify(&data_set);
+
+        // Assert
+        // Z: 空の値に置き換える
+        assert_eq!(value(&actual, Tag(0x0010, 0x0010)), Some(&b""[..]));
+        assert_eq!(value(&actual, Tag(0x0010, 0x0020)), Some(&b""[..]));
+        assert_eq!(value(&actual, Tag(0x0010, 0x0030)), Some(&b""[..]));
+        assert_eq!(value(&actual, Tag(0x0010, 0x0040)), Some(&b""[..]));
+        assert_eq!(value(&actual, Tag(0x0008, 0x0020)), Some(&b""[..]));
+        assert_eq!(value(&actual, Tag(0x0008, 0x0050)), Some(&b""[..]));
+        // D: ダミー値に置き換える
+        assert_eq!(value(&actual, Tag(0x0008, 0x0021)), Some(&b"19000101"[..]));
+        assert_eq!(
+            value(&actual, Tag(0x0008, 0x0080)),
+            Some(&b"ANONYMOUS "[..])
+        );
+        assert_eq!(
+            value(&actual, Tag(0x0008, 0x1070)),
+            Some(&b"ANONYMOUS "[..])
+        );
+        // X: 削除する
+        assert_eq!(value(&actual, Tag(0x0008, 0x1050)), None);
+        assert_eq!(value(&actual, Tag(0x0010, 0x1020)), None);
+        assert_eq!(value(&actual, Tag(0x0010, 0x4000)), None);
+        assert_eq!(value(&actual, Tag(0x0008, 0x1030)), None);
+        // K: 保持する
+        assert_eq!(value(&actual, Tag(0x0008, 0x0060)), Some(&b"ECG "[..]));
+        assert_eq!(
+            value(&actual, Tag(0x5400, 0x0100)).is_some(),
+            value(&data_set, Tag(0x5400, 0x0100)).is_some()
+        );
+        // プライベート属性は削除する
+        assert_eq!(value(&actual, Tag(0x0009, 0x0010)), None);
+        assert_eq!(value(&actual, Tag(0x0009, 0x1001)), None);
+
+        // U: UIDを置き換え、シーケンス内のUIDも同じ対応で置き換える
+        let sop_instance_uid = uid(value(&actual, Tag(0x0008, 0x0018)).unwrap());
+        assert!(sop_instance_uid.starts_with("2.25."));
+        assert_eq!(
+            deidentifier.remap_uid("1.3.12.2.1107.5.4.5.999999.30000009060216012356200000013"),
+            sop_instance_uid
+        );
+        let study_instance_uid = uid(value(&actual, Tag(0x0020, 0x000d)).unwrap());
+        let related_series = actual.items(actual.find_index(Tag(0x0008, 0x1250)).unwrap());
+        assert_eq!(related_series.len(), 1);
+        assert_eq!(
+            uid(value(&related_series[0], Tag(0x0020, 0x000d)).unwrap()),
+            study_instance_uid
+        );
+        let referenced_instances = actual.items(actual.find_index(Tag(0x0008, 0x114a)).unwrap());
+        assert_eq!(
+            uid(value(&referenced_instances[0], Tag(0x0008, 0x1155)).unwrap()),
+            deidentifier.remap_uid("1.3.12.2.1107.5.4.5.999999.30000009060216012356200000003")
+        );
+        // SOP Class UIDは置き換えない
+        assert_eq!(
+            value(&actual, Tag(0x0008, 0x0016)),
+            value(&data_set, Tag(0x0008, 0x0016))
+        );
+
+        // 匿名化の方法を記録する
+        assert_eq!(value(&actual, Tag(0x0012, 0x0062)), Some(&b"YES "[..]));
+        assert_eq!(
+            value(&actual, Tag(0x0012, 0x0063)),
+            Some(&b"Basic Application Confidentiality Profile "[..])
+        );
+        let codes = actual.items(actual.find_index(Tag(0x0012, 0x0064)).unwrap());
+        assert_eq!(codes.len(), 1);
+        assert_eq!(value(&codes[0], Tag(0x0008, 0x0100)), Some(&b"113100"[..]));
+        assert_eq!(value(&codes[0], Tag(0x0008, 0x0102)), Some(&b"DCM "[..]));
+        assert_eq!(value(&actual, Tag(0x0028, 0x0303)), Some(&b"REMOVED "[..]));
+
+        // 書き出したバイト列を読み込み直しても同じ構造になる
+        let len = actual.len();
+        let size = actual.size();
+        let bytes: Vec<u8> = actual.into();
+        assert_eq!(bytes.len() as u64, size);
+        let reparsed = {
+            let mut cur = Cursor::new(bytes.as_ref());
+            DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian).unwrap()
+        };
+        assert_eq!(reparsed.len(), len);
+        assert_eq!(reparsed.size(), size);
+    }
+
+    #[tokio::test]
+    async fn test_deidentify_with_options() {
+        // Arrange
+        let data_set = read_genecg().await;
+        let mut deidentifier = Deidentifier::new(DeidentificationOptions {
+            retain_longitudinal_temporal_information: true,
+            retain_patient_characteristics: true,
+            clean_descriptors: true,
+        });
+
+        // Act
+        let actual = deidentifier.deidentify(&data_set);
+
+        // Assert
+        // Retain Longitudinal Temporal Information with Full Dates Option
+        assert_eq!(value(&actual, Tag(0x0008, 0x0020)), Some(&b"20090602"[..]));
+        assert_eq!(
+            value(&actual, Tag(0x0008, 0x002a)),
+            value(&data_set, Tag(0x0008, 0x002a))
+        );
+        // Retain Patient Characteristics Option
+        assert_eq!(value(&actual, Tag(0x0010, 0x0040)), Some(&b"M "[..]));
+        assert_eq!(
+            value(&actual, Tag(0x0010, 0x1020)),
+            value(&data_set, Tag(0x0010, 0x1020))
+        );
+        // Clean Descriptors Option: 患者名等の識別情報を取り除く
+        assert_eq!(
+            value(&actual, Tag(0x0010, 0x4000)),
+            Some(&b"Demonstration Patient for  ing activities!"[..])
+        );
+        assert_eq!(
+            value(&actual, Tag(0x0008, 0x1030)),
+            Some(&b"enhXA rotational Acq with ECG recording and derived enhXA3D "[..])
+        );
+        // オプションに関わらず識別情報は取り除く
+        assert_eq!(value(&actual, Tag(0x0010, 0x0010)), Some(&b""[..]));
+        assert_eq!(value(&actual, Tag(0x0010, 0x0030)), Some(&b""[..]));
+
+        assert_eq!(
+            value(&actual, Tag(0x0012, 0x0063)),
+            Some(
+                &b"Basic Application Confidentiality Profile\\Retain Longitudinal Temporal Information Full Dates Option\\Retain Patient Characteristics Option\\Clean Descriptors Option "[..]
+            )
+        );
+        let codes = actual
+            .items(actual.find_index(Tag(0x0012, 0x0064)).unwrap())
+            .iter()
+            .map(|item| value(item, Tag(0x0008, 0x0100)).unwrap().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                b"113100".to_vec(),
+                b"113106".to_vec(),
+                b"113108".to_vec(),
+                b"113105".to_vec()
+            ]
+        );
+        assert_eq!(
+            value(&actual, Tag(0x0028, 0x0303)),
+            Some(&b"UNMODIFIED"[..])
+        );
+    }
+
+    #[test]
+    fn test_remap_uid() {
+        // Arrange
+        let mut deidentifier = Deidentifier::new(DeidentificationOptions::default());
+        let mut another_deidentifier = Deidentifier::new(DeidentificationOptions::default());
+
+        // Act
+        let first = deidentifier.remap_uid("1.2.3.4");
+        let second = deidentifier.remap_uid("1.2.3.4\0");
+        let other = deidentifier.remap_uid("1.2.3.5");
+        let multiple = deidentifier.remap_uid("1.2.3.4\\1.2.3.5");
+        let another = another_deidentifier.remap_uid("1.2.3.4");
+
+        // Assert
+        // 同じDeidentifierでは同じUIDに置き換える
+        assert!(first.starts_with("2.25."));
+        assert!(first.len() <= 64);
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(multiple, format!("{first}\\{other}"));
+        // 異なるDeidentifierでは異なるUIDに置き換える
+        assert_ne!(first, another);
+    }
+}
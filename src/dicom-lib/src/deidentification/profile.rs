@@ -0,0 +1,362 @@
+use crate::core::Tag;
+
+/// 属性の匿名化に用いる処理の指定
+///
+/// 各処理は PS3.15 Table E.1-1 の表記（`"X/Z/D"`等）をそのまま保持する。
+/// オプションの処理が空文字列の場合は、そのオプションで処理が変わらないことを表す。
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProfileItem {
+    pub name: &'static str,
+    /// Basic Application Level Confidentiality Profileの処理
+    pub basic: &'static str,
+    /// Retain Patient Characteristics Optionでの処理
+    pub rpc: &'static str,
+    /// Retain Longitudinal Temporal Information with Full Dates Optionでの処理
+    pub rlt: &'static str,
+    /// Clean Descriptors Optionでの処理
+    pub cd: &'static str,
+}
+
+// DICOM PS3.15 2025b - Table E.1-1 Application Level Confidentiality Profile Attributes
+// https://dicom.nema.org/medical/dicom/2025b/output/chtml/part15/chapter_E.html
+//
+// 本システムが扱う画像・波形の標準IODに現れる属性を収録している。
+
+const PROFILE: phf::Map<u32, ProfileItem> = phf::phf_map! {
+    0x00080012u32 => ProfileItem { name: "Instance Creation Date", basic: "X/D", rpc: "", rlt: "K", cd: "" },
+    0x00080013u32 => ProfileItem { name: "Instance Creation Time", basic: "X/Z/D", rpc: "", rlt: "K", cd: "" },
+    0x00080014u32 => ProfileItem { name: "Instance Creator UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00080015u32 => ProfileItem { name: "Instance Coercion DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00080017u32 => ProfileItem { name: "Acquisition UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00080018u32 => ProfileItem { name: "SOP Instance UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00080019u32 => ProfileItem { name: "Pyramid UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00080020u32 => ProfileItem { name: "Study Date", basic: "Z", rpc: "", rlt: "K", cd: "" },
+    0x00080021u32 => ProfileItem { name: "Series Date", basic: "X/D", rpc: "", rlt: "K", cd: "" },
+    0x00080022u32 => ProfileItem { name: "Acquisition Date", basic: "X/Z", rpc: "", rlt: "K", cd: "" },
+    0x00080023u32 => ProfileItem { name: "Content Date", basic: "Z/D", rpc: "", rlt: "K", cd: "" },
+    0x00080024u32 => ProfileItem { name: "Overlay Date", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00080025u32 => ProfileItem { name: "Curve Date", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x0008002au32 => ProfileItem { name: "Acquisition DateTime", basic: "X/Z/D", rpc: "", rlt: "K", cd: "" },
+    0x00080030u32 => ProfileItem { name: "Study Time", basic: "Z", rpc: "", rlt: "K", cd: "" },
+    0x00080031u32 => ProfileItem { name: "Series Time", basic: "X/D", rpc: "", rlt: "K", cd: "" },
+    0x00080032u32 => ProfileItem { name: "Acquisition Time", basic: "X/Z", rpc: "", rlt: "K", cd: "" },
+    0x00080033u32 => ProfileItem { name: "Content Time", basic: "Z/D", rpc: "", rlt: "K", cd: "" },
+    0x00080034u32 => ProfileItem { name: "Overlay Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00080035u32 => ProfileItem { name: "Curve Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00080050u32 => ProfileItem { name: "Accession Number", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00080058u32 => ProfileItem { name: "Failed SOP Instance UID List", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00080080u32 => ProfileItem { name: "Institution Name", basic: "X/Z/D", rpc: "", rlt: "", cd: "" },
+    0x00080081u32 => ProfileItem { name: "Institution Address", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00080082u32 => ProfileItem { name: "Institution Code Sequence", basic: "X/Z/D", rpc: "", rlt: "", cd: "" },
+    0x00080090u32 => ProfileItem { name: "Referring Physician's Name", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00080092u32 => ProfileItem { name: "Referring Physician's Address", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00080094u32 => ProfileItem { name: "Referring Physician's Telephone Numbers", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00080096u32 => ProfileItem { name: "Referring Physician Identification Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x0008009cu32 => ProfileItem { name: "Consulting Physician's Name", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x0008009du32 => ProfileItem { name: "Consulting Physician Identification Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00080201u32 => ProfileItem { name: "Timezone Offset From UTC", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00081000u32 => ProfileItem { name: "Network ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081010u32 => ProfileItem { name: "Station Name", basic: "X/Z/D", rpc: "", rlt: "", cd: "" },
+    0x00081030u32 => ProfileItem { name: "Study Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x0008103eu32 => ProfileItem { name: "Series Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00081040u32 => ProfileItem { name: "Institutional Department Name", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081041u32 => ProfileItem { name: "Institutional Department Type Code Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081048u32 => ProfileItem { name: "Physician(s) of Record", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081049u32 => ProfileItem { name: "Physician(s) of Record Identification Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081050u32 => ProfileItem { name: "Performing Physician's Name", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081052u32 => ProfileItem { name: "Performing Physician Identification Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081060u32 => ProfileItem { name: "Name of Physician(s) Reading Study", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081062u32 => ProfileItem { name: "Physician(s) Reading Study Identification Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081070u32 => ProfileItem { name: "Operators' Name", basic: "X/Z/D", rpc: "", rlt: "", cd: "" },
+    0x00081072u32 => ProfileItem { name: "Operator Identification Sequence", basic: "X/D", rpc: "", rlt: "", cd: "" },
+    0x00081080u32 => ProfileItem { name: "Admitting Diagnoses Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00081084u32 => ProfileItem { name: "Admitting Diagnoses Code Sequence", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00081110u32 => ProfileItem { name: "Referenced Study Sequence", basic: "X/Z", rpc: "", rlt: "", cd: "" },
+    0x00081111u32 => ProfileItem { name: "Referenced Performed Procedure Step Sequence", basic: "X/Z/D", rpc: "", rlt: "", cd: "" },
+    0x00081120u32 => ProfileItem { name: "Referenced Patient Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00081140u32 => ProfileItem { name: "Referenced Image Sequence", basic: "X/Z/U*", rpc: "", rlt: "", cd: "" },
+    0x00081155u32 => ProfileItem { name: "Referenced SOP Instance UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00081195u32 => ProfileItem { name: "Transaction UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00082111u32 => ProfileItem { name: "Derivation Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00082112u32 => ProfileItem { name: "Source Image Sequence", basic: "X/Z/U*", rpc: "", rlt: "", cd: "" },
+    0x00083010u32 => ProfileItem { name: "Irradiation Event UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00084000u32 => ProfileItem { name: "Identifying Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00100010u32 => ProfileItem { name: "Patient's Name", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00100020u32 => ProfileItem { name: "Patient ID", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00100021u32 => ProfileItem { name: "Issuer of Patient ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100022u32 => ProfileItem { name: "Type of Patient ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100024u32 => ProfileItem { name: "Issuer of Patient ID Qualifiers Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100030u32 => ProfileItem { name: "Patient's Birth Date", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00100032u32 => ProfileItem { name: "Patient's Birth Time", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100033u32 => ProfileItem { name: "Patient's Birth Date in Alternative Calendar", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100034u32 => ProfileItem { name: "Patient's Death Date in Alternative Calendar", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100035u32 => ProfileItem { name: "Patient's Alternative Calendar", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100040u32 => ProfileItem { name: "Patient's Sex", basic: "Z", rpc: "K", rlt: "", cd: "" },
+    0x00100050u32 => ProfileItem { name: "Patient's Insurance Plan Code Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100101u32 => ProfileItem { name: "Patient's Primary Language Code Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00100102u32 => ProfileItem { name: "Patient's Primary Language Modifier Code Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101000u32 => ProfileItem { name: "Other Patient IDs", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101001u32 => ProfileItem { name: "Other Patient Names", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101002u32 => ProfileItem { name: "Other Patient IDs Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101005u32 => ProfileItem { name: "Patient's Birth Name", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101010u32 => ProfileItem { name: "Patient's Age", basic: "X", rpc: "K", rlt: "", cd: "" },
+    0x00101020u32 => ProfileItem { name: "Patient's Size", basic: "X", rpc: "K", rlt: "", cd: "" },
+    0x00101030u32 => ProfileItem { name: "Patient's Weight", basic: "X", rpc: "K", rlt: "", cd: "" },
+    0x00101040u32 => ProfileItem { name: "Patient's Address", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101050u32 => ProfileItem { name: "Insurance Plan Identification", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101060u32 => ProfileItem { name: "Patient's Mother's Birth Name", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101080u32 => ProfileItem { name: "Military Rank", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101081u32 => ProfileItem { name: "Branch of Service", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101090u32 => ProfileItem { name: "Medical Record Locator", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00101100u32 => ProfileItem { name: "Referenced Patient Photo Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00102000u32 => ProfileItem { name: "Medical Alerts", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00102110u32 => ProfileItem { name: "Allergies", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00102150u32 => ProfileItem { name: "Country of Residence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00102152u32 => ProfileItem { name: "Region of Residence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00102154u32 => ProfileItem { name: "Patient's Telephone Numbers", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00102155u32 => ProfileItem { name: "Patient's Telecom Information", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00102160u32 => ProfileItem { name: "Ethnic Group", basic: "X", rpc: "K", rlt: "", cd: "" },
+    0x00102180u32 => ProfileItem { name: "Occupation", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x001021a0u32 => ProfileItem { name: "Smoking Status", basic: "X", rpc: "K", rlt: "", cd: "" },
+    0x001021b0u32 => ProfileItem { name: "Additional Patient History", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x001021c0u32 => ProfileItem { name: "Pregnancy Status", basic: "X", rpc: "K", rlt: "", cd: "" },
+    0x001021d0u32 => ProfileItem { name: "Last Menstrual Date", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x001021f0u32 => ProfileItem { name: "Patient's Religious Preference", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00102203u32 => ProfileItem { name: "Patient's Sex Neutered", basic: "X/Z", rpc: "K", rlt: "", cd: "" },
+    0x00102297u32 => ProfileItem { name: "Responsible Person", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00102299u32 => ProfileItem { name: "Responsible Organization", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00104000u32 => ProfileItem { name: "Patient Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00180010u32 => ProfileItem { name: "Contrast/Bolus Agent", basic: "Z/D", rpc: "", rlt: "", cd: "C" },
+    0x00181000u32 => ProfileItem { name: "Device Serial Number", basic: "X/Z/D", rpc: "", rlt: "", cd: "" },
+    0x00181002u32 => ProfileItem { name: "Device UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00181004u32 => ProfileItem { name: "Plate ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00181005u32 => ProfileItem { name: "Generator ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00181007u32 => ProfileItem { name: "Cassette ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00181008u32 => ProfileItem { name: "Gantry ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00181012u32 => ProfileItem { name: "Date of Secondary Capture", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00181014u32 => ProfileItem { name: "Time of Secondary Capture", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00181030u32 => ProfileItem { name: "Protocol Name", basic: "X/D", rpc: "", rlt: "", cd: "C" },
+    0x00181072u32 => ProfileItem { name: "Radiopharmaceutical Start Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00181073u32 => ProfileItem { name: "Radiopharmaceutical Stop Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00181078u32 => ProfileItem { name: "Radiopharmaceutical Start DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00181079u32 => ProfileItem { name: "Radiopharmaceutical Stop DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00181200u32 => ProfileItem { name: "Date of Last Calibration", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00181201u32 => ProfileItem { name: "Time of Last Calibration", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00181400u32 => ProfileItem { name: "Acquisition Device Processing Description", basic: "X/D", rpc: "", rlt: "", cd: "C" },
+    0x00184000u32 => ProfileItem { name: "Acquisition Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x0018700au32 => ProfileItem { name: "Detector ID", basic: "X/D", rpc: "", rlt: "", cd: "" },
+    0x00189074u32 => ProfileItem { name: "Frame Acquisition DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00189151u32 => ProfileItem { name: "Frame Reference DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00189424u32 => ProfileItem { name: "Acquisition Protocol Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00189516u32 => ProfileItem { name: "Start Acquisition DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00189517u32 => ProfileItem { name: "End Acquisition DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00189701u32 => ProfileItem { name: "Decay Correction DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x0018a003u32 => ProfileItem { name: "Contribution Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x0020000du32 => ProfileItem { name: "Study Instance UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x0020000eu32 => ProfileItem { name: "Series Instance UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00200010u32 => ProfileItem { name: "Study ID", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00200052u32 => ProfileItem { name: "Frame of Reference UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00200200u32 => ProfileItem { name: "Synchronization Frame of Reference UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00203401u32 => ProfileItem { name: "Modifying Device ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00203406u32 => ProfileItem { name: "Modified Image Description", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00204000u32 => ProfileItem { name: "Image Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00209158u32 => ProfileItem { name: "Frame Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00209161u32 => ProfileItem { name: "Concatenation UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00209164u32 => ProfileItem { name: "Dimension Organization UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00281199u32 => ProfileItem { name: "Palette Color Lookup Table UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00281214u32 => ProfileItem { name: "Large Palette Color Lookup Table UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00284000u32 => ProfileItem { name: "Image Presentation Comments", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00320012u32 => ProfileItem { name: "Study ID Issuer", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00321020u32 => ProfileItem { name: "Scheduled Study Location", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00321021u32 => ProfileItem { name: "Scheduled Study Location AE Title", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00321030u32 => ProfileItem { name: "Reason for Study", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00321032u32 => ProfileItem { name: "Requesting Physician", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00321033u32 => ProfileItem { name: "Requesting Service", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00321060u32 => ProfileItem { name: "Requested Procedure Description", basic: "X/Z", rpc: "", rlt: "", cd: "C" },
+    0x00321070u32 => ProfileItem { name: "Requested Contrast Agent", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00324000u32 => ProfileItem { name: "Study Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00380010u32 => ProfileItem { name: "Admission ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00380011u32 => ProfileItem { name: "Issuer of Admission ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x0038001eu32 => ProfileItem { name: "Scheduled Patient Institution Residence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00380020u32 => ProfileItem { name: "Admitting Date", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00380021u32 => ProfileItem { name: "Admitting Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00380040u32 => ProfileItem { name: "Discharge Diagnosis Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00380050u32 => ProfileItem { name: "Special Needs", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00380060u32 => ProfileItem { name: "Service Episode ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00380061u32 => ProfileItem { name: "Issuer of Service Episode ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00380062u32 => ProfileItem { name: "Service Episode Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00380300u32 => ProfileItem { name: "Current Patient Location", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00380400u32 => ProfileItem { name: "Patient's Institution Residence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00380500u32 => ProfileItem { name: "Patient State", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00384000u32 => ProfileItem { name: "Visit Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00400001u32 => ProfileItem { name: "Scheduled Station AE Title", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400002u32 => ProfileItem { name: "Scheduled Procedure Step Start Date", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00400003u32 => ProfileItem { name: "Scheduled Procedure Step Start Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00400004u32 => ProfileItem { name: "Scheduled Procedure Step End Date", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00400005u32 => ProfileItem { name: "Scheduled Procedure Step End Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00400006u32 => ProfileItem { name: "Scheduled Performing Physician's Name", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400007u32 => ProfileItem { name: "Scheduled Procedure Step Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x0040000bu32 => ProfileItem { name: "Scheduled Performing Physician Identification Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400010u32 => ProfileItem { name: "Scheduled Station Name", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400011u32 => ProfileItem { name: "Scheduled Procedure Step Location", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400241u32 => ProfileItem { name: "Performed Station AE Title", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400242u32 => ProfileItem { name: "Performed Station Name", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400243u32 => ProfileItem { name: "Performed Location", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400244u32 => ProfileItem { name: "Performed Procedure Step Start Date", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00400245u32 => ProfileItem { name: "Performed Procedure Step Start Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00400250u32 => ProfileItem { name: "Performed Procedure Step End Date", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00400251u32 => ProfileItem { name: "Performed Procedure Step End Time", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x00400253u32 => ProfileItem { name: "Performed Procedure Step ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400254u32 => ProfileItem { name: "Performed Procedure Step Description", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00400275u32 => ProfileItem { name: "Request Attributes Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00400280u32 => ProfileItem { name: "Comments on the Performed Procedure Step", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00400555u32 => ProfileItem { name: "Acquisition Context Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401001u32 => ProfileItem { name: "Requested Procedure ID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401002u32 => ProfileItem { name: "Reason for the Requested Procedure", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00401004u32 => ProfileItem { name: "Patient Transport Arrangements", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401005u32 => ProfileItem { name: "Requested Procedure Location", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401010u32 => ProfileItem { name: "Names of Intended Recipients of Results", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401011u32 => ProfileItem { name: "Intended Recipients of Results Identification Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401101u32 => ProfileItem { name: "Person Identification Code Sequence", basic: "D", rpc: "", rlt: "", cd: "" },
+    0x00401102u32 => ProfileItem { name: "Person's Address", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401103u32 => ProfileItem { name: "Person's Telephone Numbers", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401104u32 => ProfileItem { name: "Person's Telecom Information", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00401400u32 => ProfileItem { name: "Requested Procedure Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00402001u32 => ProfileItem { name: "Reason for the Imaging Service Request", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00402008u32 => ProfileItem { name: "Order Entered By", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00402009u32 => ProfileItem { name: "Order Enterer's Location", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00402010u32 => ProfileItem { name: "Order Callback Phone Number", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00402011u32 => ProfileItem { name: "Order Callback Telecom Information", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00402016u32 => ProfileItem { name: "Placer Order Number / Imaging Service Request", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00402017u32 => ProfileItem { name: "Filler Order Number / Imaging Service Request", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00402400u32 => ProfileItem { name: "Imaging Service Request Comments", basic: "X", rpc: "", rlt: "", cd: "C" },
+    0x00403001u32 => ProfileItem { name: "Confidentiality Constraint on Patient Data Description", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x0040a027u32 => ProfileItem { name: "Verifying Organization", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x0040a030u32 => ProfileItem { name: "Verification DateTime", basic: "D", rpc: "", rlt: "K", cd: "" },
+    0x0040a032u32 => ProfileItem { name: "Observation DateTime", basic: "X/D", rpc: "", rlt: "K", cd: "" },
+    0x0040a073u32 => ProfileItem { name: "Verifying Observer Sequence", basic: "D", rpc: "", rlt: "", cd: "" },
+    0x0040a075u32 => ProfileItem { name: "Verifying Observer Name", basic: "D", rpc: "", rlt: "", cd: "" },
+    0x0040a078u32 => ProfileItem { name: "Author Observer Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x0040a07au32 => ProfileItem { name: "Participant Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x0040a07cu32 => ProfileItem { name: "Custodial Organization Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x0040a082u32 => ProfileItem { name: "Participation DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x0040a088u32 => ProfileItem { name: "Verifying Observer Identification Code Sequence", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x0040a120u32 => ProfileItem { name: "DateTime", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x0040a121u32 => ProfileItem { name: "Date", basic: "D", rpc: "", rlt: "K", cd: "" },
+    0x0040a122u32 => ProfileItem { name: "Time", basic: "D", rpc: "", rlt: "K", cd: "" },
+    0x0040a123u32 => ProfileItem { name: "Person Name", basic: "D", rpc: "", rlt: "", cd: "" },
+    0x0040a124u32 => ProfileItem { name: "UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x0040a171u32 => ProfileItem { name: "Observation UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x0040a192u32 => ProfileItem { name: "Observation Date (Trial)", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x0040a193u32 => ProfileItem { name: "Observation Time (Trial)", basic: "X", rpc: "", rlt: "K", cd: "" },
+    0x0040a730u32 => ProfileItem { name: "Content Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x0040db0cu32 => ProfileItem { name: "Template Extension Organization UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x0040db0du32 => ProfileItem { name: "Template Extension Creator UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x00700001u32 => ProfileItem { name: "Graphic Annotation Sequence", basic: "D", rpc: "", rlt: "", cd: "" },
+    0x00700084u32 => ProfileItem { name: "Content Creator's Name", basic: "Z", rpc: "", rlt: "", cd: "" },
+    0x00700086u32 => ProfileItem { name: "Content Creator's Identification Code Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x00880140u32 => ProfileItem { name: "Storage Media File-set UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x04000100u32 => ProfileItem { name: "Digital Signature UID", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x04000402u32 => ProfileItem { name: "Referenced Digital Signature Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x04000403u32 => ProfileItem { name: "Referenced SOP Instance MAC Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x04000404u32 => ProfileItem { name: "MAC", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x04000550u32 => ProfileItem { name: "Modified Attributes Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x04000561u32 => ProfileItem { name: "Original Attributes Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x30060024u32 => ProfileItem { name: "Referenced Frame of Reference UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x300600c2u32 => ProfileItem { name: "Related Frame of Reference UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x300a0013u32 => ProfileItem { name: "Dose Reference UID", basic: "U", rpc: "", rlt: "", cd: "" },
+    0x40000010u32 => ProfileItem { name: "Arbitrary", basic: "X", rpc: "", rlt: "", cd: "" },
+    0x40004000u32 => ProfileItem { name: "Text Comments", basic: "X", rpc: "", rlt: "", cd: "" },
+    0xfffafffau32 => ProfileItem { name: "Digital Signatures Sequence", basic: "X", rpc: "", rlt: "", cd: "" },
+    0xfffcfffcu32 => ProfileItem { name: "Data Set Trailing Padding", basic: "X", rpc: "", rlt: "", cd: "" },
+};
+
+// 繰り返しグループの属性
+const CURVE_DATA: ProfileItem = ProfileItem {
+    name: "Curve Data",
+    basic: "X",
+    rpc: "",
+    rlt: "",
+    cd: "",
+};
+const OVERLAY_DATA: ProfileItem = ProfileItem {
+    name: "Overlay Data",
+    basic: "X",
+    rpc: "",
+    rlt: "",
+    cd: "",
+};
+const OVERLAY_COMMENTS: ProfileItem = ProfileItem {
+    name: "Overlay Comments",
+    basic: "X",
+    rpc: "",
+    rlt: "",
+    cd: "",
+};
+
+/// 標準の属性の匿名化に用いる処理の指定を検索する。
+/// 表に存在しない属性の場合は`None`を返す。
+pub fn search(tag: Tag) -> Option<&'static ProfileItem> {
+    let Tag(group, element) = tag;
+    match group & 0xff00 {
+        0x5000 => Some(&CURVE_DATA),
+        0x6000 => match element {
+            0x3000 => Some(&OVERLAY_DATA),
+            0x4000 => Some(&OVERLAY_COMMENTS),
+            _ => None,
+        },
+        _ => PROFILE.get(&(((group as u32) << 16) | element as u32)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionaries::tag_dictionary;
+
+    #[test]
+    fn test_search() {
+        {
+            let actual = search(Tag(0x0010, 0x0040)).unwrap();
+
+            assert_eq!(
+                ProfileItem {
+                    name: "Patient's Sex",
+                    basic: "Z",
+                    rpc: "K",
+                    rlt: "",
+                    cd: "",
+                },
+                *actual
+            );
+        }
+
+        {
+            let actual = search(Tag(0x6002, 0x3000)).unwrap();
+
+            assert_eq!(OVERLAY_DATA, *actual);
+        }
+
+        {
+            let actual = search(Tag(0x6002, 0x0010));
+
+            assert_eq!(None, actual);
+        }
+
+        {
+            let actual = search(Tag(0x0008, 0x0016));
+
+            assert_eq!(None, actual);
+        }
+    }
+
+    #[test]
+    fn test_profile_names_match_tag_dictionary() {
+        for (key, item) in PROFILE.entries() {
+            let tag = Tag((key >> 16) as u16, (key & 0xffff) as u16);
+            let dictionary_item = tag_dictionary::search(tag)
+                .unwrap_or_else(|| panic!("タグ辞書に存在しない属性です: {tag}"));
+            assert_eq!(dictionary_item.name, item.name, "タグ={tag}");
+        }
+    }
+}
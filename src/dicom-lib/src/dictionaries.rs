@@ -1,4 +1,6 @@
 mod sop_class_dictionary;
 pub mod tag_dictionary;
 
-pub use sop_class_dictionary::SOP_CLASS_DICTIONARY;
+pub use sop_class_dictionary::{
+    SOP_CLASS_DICTIONARY, is_storage_sop_class, storage_sop_class_uids,
+};
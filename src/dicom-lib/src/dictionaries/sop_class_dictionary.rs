@@ -1,3 +1,11 @@
+use crate::constants::sop_class_uids::{
+    COLOR_PALETTE_STORAGE, GENERIC_IMPLANT_TEMPLATE_STORAGE, HANGING_PROTOCOL_STORAGE,
+    IMPLANT_ASSEMBLY_TEMPLATE_STORAGE, IMPLANT_TEMPLATE_GROUP_STORAGE, INVENTORY_CREATION,
+    INVENTORY_FIND, INVENTORY_GET, INVENTORY_MOVE, PROTOCOL_APPROVAL_INFORMATION_MODEL_FIND,
+    PROTOCOL_APPROVAL_INFORMATION_MODEL_GET, PROTOCOL_APPROVAL_INFORMATION_MODEL_MOVE,
+    REPOSITORY_QUERY, RT_BEAMS_DELIVERY_INSTRUCTION_STORAGE,
+    RT_BRACHY_APPLICATION_SETUP_DELIVERY_INSTRUCTION_STORAGE,
+};
 use phf::{Map, phf_map};
 
 pub const SOP_CLASS_DICTIONARY: Map<&'static str, &'static str> = phf_map! {
@@ -39,7 +47,7 @@ pub const SOP_CLASS_DICTIONARY: Map<&'static str, &'static str> = phf_map! {
     "1.2.840.10008.5.1.4.1.1.4.4" => "Legacy Converted Enhanced MR Image Storage",
     "1.2.840.10008.5.1.4.1.1.6.1" => "Ultrasound Image Storage",
     "1.2.840.10008.5.1.4.1.1.6.2" => "Enhanced US Volume Storage",
-    "1.2.840.10008.5.1.4.1.1.6.3" => "Photoacoustic Image Storage ",
+    "1.2.840.10008.5.1.4.1.1.6.3" => "Photoacoustic Image Storage",
     "1.2.840.10008.5.1.4.1.1.7" => "Secondary Capture Image Storage",
     "1.2.840.10008.5.1.4.1.1.7.1" => "Multi-frame Single Bit Secondary Capture Image Storage",
     "1.2.840.10008.5.1.4.1.1.7.2" => "Multi-frame Grayscale Byte Secondary Capture Image Storage",
@@ -266,3 +274,112 @@ pub const SOP_CLASS_DICTIONARY: Map<&'static str, &'static str> = phf_map! {
     "1.2.840.10008.10.3" => "Audio Waveform Real-Time Communication",
     "1.2.840.10008.10.4" => "Rendition Selection Document Real-Time Communication",
 };
+
+/// Storage SOPクラスのUIDの接頭辞
+const STORAGE_SOP_CLASS_UID_PREFIX: &str = "1.2.840.10008.5.1.4.1.1.";
+
+/// UIDが[`STORAGE_SOP_CLASS_UID_PREFIX`]で始まらないStorage SOPクラス
+const STORAGE_SOP_CLASS_UIDS_WITHOUT_PREFIX: &[&str] = &[
+    RT_BEAMS_DELIVERY_INSTRUCTION_STORAGE,
+    RT_BRACHY_APPLICATION_SETUP_DELIVERY_INSTRUCTION_STORAGE,
+    HANGING_PROTOCOL_STORAGE,
+    COLOR_PALETTE_STORAGE,
+    GENERIC_IMPLANT_TEMPLATE_STORAGE,
+    IMPLANT_ASSEMBLY_TEMPLATE_STORAGE,
+    IMPLANT_TEMPLATE_GROUP_STORAGE,
+];
+
+/// UIDが[`STORAGE_SOP_CLASS_UID_PREFIX`]で始まるが、Storage SOPクラスではないSOPクラス
+const NON_STORAGE_SOP_CLASS_UIDS_WITH_PREFIX: &[&str] = &[
+    PROTOCOL_APPROVAL_INFORMATION_MODEL_FIND,
+    PROTOCOL_APPROVAL_INFORMATION_MODEL_MOVE,
+    PROTOCOL_APPROVAL_INFORMATION_MODEL_GET,
+    INVENTORY_FIND,
+    INVENTORY_MOVE,
+    INVENTORY_GET,
+    INVENTORY_CREATION,
+    REPOSITORY_QUERY,
+];
+
+/// 指定されたSOPクラスUIDがStorage Service ClassのSOPクラス（C-STOREで送信されるSOPクラス）かどうかを判定する。
+///
+/// SOPクラス辞書に登録されているもののうち、UIDが`1.2.840.10008.5.1.4.1.1.`で始まるものをStorage SOPクラスとみなす。
+/// ただし、この接頭辞を持たないStorage SOPクラス（Hanging Protocol Storage等）およびこの接頭辞を持つStorage SOPクラス以外のSOPクラス
+/// （Inventory - FIND等）は、それぞれ個別に判定する。
+/// ネットワーク経由で送信されることのないMedia Storage Directory Storageは含まない。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part04/sect_B.5.html>
+pub fn is_storage_sop_class(uid: &str) -> bool {
+    if !SOP_CLASS_DICTIONARY.contains_key(uid) {
+        return false;
+    }
+
+    if uid.starts_with(STORAGE_SOP_CLASS_UID_PREFIX) {
+        !NON_STORAGE_SOP_CLASS_UIDS_WITH_PREFIX.contains(&uid)
+    } else {
+        STORAGE_SOP_CLASS_UIDS_WITHOUT_PREFIX.contains(&uid)
+    }
+}
+
+/// SOPクラス辞書に登録されているすべてのStorage SOPクラスのUIDを返す。
+pub fn storage_sop_class_uids() -> impl Iterator<Item = &'static str> {
+    SOP_CLASS_DICTIONARY
+        .keys()
+        .copied()
+        .filter(|uid| is_storage_sop_class(uid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::sop_class_uids::{
+        CT_IMAGE_STORAGE, ENCAPSULATED_PDF_STORAGE, ENHANCED_MR_IMAGE_STORAGE,
+        HANGING_PROTOCOL_STORAGE, MEDIA_STORAGE_DIRECTORY_STORAGE,
+        MODALITY_PERFORMED_PROCEDURE_STEP, STORAGE_COMMITMENT_PUSH_MODEL, ULTRASOUND_IMAGE_STORAGE,
+        VERIFICATION, VIDEO_ENDOSCOPIC_IMAGE_STORAGE,
+    };
+
+    #[test]
+    fn test_is_storage_sop_class() {
+        // Storage SOPクラス
+        assert!(is_storage_sop_class(CT_IMAGE_STORAGE));
+        assert!(is_storage_sop_class(ENHANCED_MR_IMAGE_STORAGE));
+        assert!(is_storage_sop_class(ULTRASOUND_IMAGE_STORAGE));
+        assert!(is_storage_sop_class(ENCAPSULATED_PDF_STORAGE));
+        assert!(is_storage_sop_class(HANGING_PROTOCOL_STORAGE));
+        assert!(is_storage_sop_class("1.2.840.10008.5.1.4.1.1.1.1")); // "- For Presentation"
+        assert!(is_storage_sop_class("1.2.840.10008.5.1.4.1.1.6.3")); // Photoacoustic Image Storage
+        assert!(is_storage_sop_class(RT_BEAMS_DELIVERY_INSTRUCTION_STORAGE));
+        assert!(is_storage_sop_class(IMPLANT_TEMPLATE_GROUP_STORAGE));
+
+        // Storage SOPクラスではないもの
+        assert!(!is_storage_sop_class(VERIFICATION));
+        assert!(!is_storage_sop_class(MODALITY_PERFORMED_PROCEDURE_STEP));
+        assert!(!is_storage_sop_class(STORAGE_COMMITMENT_PUSH_MODEL));
+        assert!(!is_storage_sop_class(MEDIA_STORAGE_DIRECTORY_STORAGE));
+        assert!(!is_storage_sop_class("1.2.840.10008.5.1.4.1.2.2.1")); // Study Root Q/R - FIND
+        assert!(!is_storage_sop_class("1.2.3.4"));
+        assert!(!is_storage_sop_class(INVENTORY_FIND));
+        assert!(!is_storage_sop_class(REPOSITORY_QUERY));
+        assert!(!is_storage_sop_class("1.2.840.10008.5.1.4.1.1.9999")); // 辞書に登録されていない
+    }
+
+    #[test]
+    fn test_storage_sop_class_uids() {
+        let uids = storage_sop_class_uids().collect::<Vec<_>>();
+
+        // 例外として個別に判定するSOPクラスは辞書に登録されている
+        for uid in STORAGE_SOP_CLASS_UIDS_WITHOUT_PREFIX
+            .iter()
+            .chain(NON_STORAGE_SOP_CLASS_UIDS_WITH_PREFIX)
+        {
+            assert!(SOP_CLASS_DICTIONARY.contains_key(uid), "{uid}");
+        }
+        assert!(uids.contains(&VIDEO_ENDOSCOPIC_IMAGE_STORAGE));
+
+        assert!(uids.contains(&CT_IMAGE_STORAGE));
+        assert!(!uids.contains(&VERIFICATION));
+        assert!(uids.iter().all(|uid| is_storage_sop_class(uid)));
+    }
+}
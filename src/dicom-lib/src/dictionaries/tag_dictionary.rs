@@ -1,4 +1,4 @@
-use crate::core::Tag;
+use crate::core::{Tag, data_element::Vr};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct DictionaryItem {
@@ -5748,6 +5748,16 @@ const VARIABLE_COEFFICIENTS_SDDN: DictionaryItem = DictionaryItem {
     remarks: "RET (2007)",
 };
 
+/// タグに対応するVRを返す。
+/// 複数のVRを取りうる場合 (例: "US or SS") は最初のVRを返す。
+pub fn search_vr(tag: Tag) -> Option<Vr> {
+    search(tag).map(|item| {
+        let vr_bytes = item.vr.as_bytes();
+        Vr::try_from([vr_bytes[0], vr_bytes[1]])
+            .expect("標準DICOMタグ辞書は2バイト以上の長さで正しいタグを保有しているはず")
+    })
+}
+
 pub fn search(tag: Tag) -> Option<&'static DictionaryItem> {
     if !tag.group().is_multiple_of(2) {
         // タググループが奇数である場合はプライベートデータ
@@ -32,17 +32,17 @@ impl File {
         }
     }
 
-    pub fn size(&self) -> usize {
+    pub fn size(&self) -> u64 {
         128 // Preamble
         + 4 // Prefix
-        + self.meta_information.size()
+        + self.meta_information.size() as u64
         + self.data_set.size()
     }
 }
 
 impl From<File> for Vec<u8> {
     fn from(val: File) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(val.size());
+        let mut buf = Vec::with_capacity(val.size() as usize);
 
         buf.extend_from_slice(&[0u8; 128]); // Preamble
         buf.extend_from_slice(b"DICM"); // Prefix
@@ -52,3 +52,56 @@ impl From<File> for Vec<u8> {
         buf
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Encoding, Tag, data_element::Vr, value::value_representations::ui::UiValue};
+
+    #[test]
+    fn test_size_over_2gib() {
+        // Arrange
+        // 値フィールドは確保せず、2.25GiBの値長さを持つものとしてサイズと位置を計算する
+        let pixel_data_length = 0x9000_0000; // 2.25GiB
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        data_set.set_element(Tag(0x0008, 0x0018), Vr::Ui, b"1.2.3.4\0".to_vec());
+        data_set.set_element_without_value(Tag(0x7fe0, 0x0010), Vr::Ob, pixel_data_length);
+        data_set.set_element(Tag(0xfffc, 0xfffc), Vr::Ob, vec![0; 8]);
+        let meta_information = FileMetaInformation::new(
+            UiValue::from_string("1.2.840.10008.5.1.4.1.1.77.1.6").unwrap(),
+            UiValue::from_string("1.2.3.4").unwrap(),
+            UiValue::from_string("1.2.840.10008.1.2.1").unwrap(),
+            UiValue::from_string("1.2.3").unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let meta_size = meta_information.size() as u64;
+
+        // Act
+        let file = File::new(meta_information, data_set);
+
+        // Assert
+        let data_set_size = 16 + 12 + pixel_data_length as u64 + 20;
+        assert_eq!(file.data_set().size(), data_set_size);
+        assert_eq!(file.size(), 128 + 4 + meta_size + data_set_size);
+        // 2GiBを超える位置のデータ要素の位置も64ビットで表す
+        assert_eq!(
+            file.data_set().get_position(2),
+            128 + 4 + meta_size + 16 + 12 + pixel_data_length as u64
+        );
+    }
+}
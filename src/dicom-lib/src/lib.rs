@@ -1,5 +1,9 @@
 pub mod constants;
 pub mod core;
+pub mod deidentification;
 pub mod dictionaries;
 pub mod file;
 pub mod network;
+pub mod pixel_data;
+pub mod rendering;
+pub mod uid;
@@ -1,3 +1,22 @@
+pub mod c_cancel;
 pub mod c_echo;
+pub mod c_find;
+pub mod c_get;
+pub mod c_move;
 pub mod c_store;
+mod command_set_builder;
+mod command_set_reader;
+mod dimse_message;
 pub mod enums;
+mod failure_response;
+pub mod n_action;
+pub mod n_create;
+pub mod n_delete;
+pub mod n_event_report;
+pub mod n_get;
+pub mod n_set;
+mod status;
+
+pub use dimse_message::DimseMessage;
+pub use failure_response::failure_response;
+pub use status::{Status, StatusType};
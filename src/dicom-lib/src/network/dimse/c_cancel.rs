@@ -0,0 +1,3 @@
+mod c_cancel_rq;
+
+pub use c_cancel_rq::CCancelRq;
@@ -0,0 +1,69 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// C-CANCEL-RQ
+///
+/// 実行中のC-FIND・C-GET・C-MOVE操作の取り消しを要求する。応答メッセージは存在しない。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.2.3.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct CCancelRq {
+    message_id_being_responded_to: u16,
+}
+
+impl CCancelRq {
+    /// 取り消し対象の操作のMessage ID
+    pub fn message_id_being_responded_to(&self) -> u16 {
+        self.message_id_being_responded_to
+    }
+
+    pub fn new(message_id_being_responded_to: u16) -> Self {
+        Self {
+            message_id_being_responded_to,
+        }
+    }
+}
+
+impl DimseMessage for CCancelRq {
+    const COMMAND_FIELD: CommandField = CommandField::CCancelRq;
+
+    fn has_data_set(&self) -> bool {
+        false
+    }
+}
+
+impl TryFrom<CommandSet> for CCancelRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id_being_responded_to =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        if reader.has_data_set()? {
+            return Err("Command Data Set Typeが不正です".to_string());
+        }
+
+        Ok(CCancelRq {
+            message_id_being_responded_to,
+        })
+    }
+}
+
+impl From<CCancelRq> for CommandSet {
+    fn from(val: CCancelRq) -> Self {
+        CommandSetBuilder::new(CCancelRq::COMMAND_FIELD, false)
+            .us(Tag(0x0000, 0x0120), val.message_id_being_responded_to)
+            .build()
+    }
+}
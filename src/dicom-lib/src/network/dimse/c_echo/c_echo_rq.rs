@@ -1,5 +1,20 @@
-use crate::{constants::sop_class_uids::VERIFICATION, network::CommandSet};
+use crate::{
+    constants::sop_class_uids::VERIFICATION,
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
 
+/// C-ECHO-RQ
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.5.html>
+#[derive(Debug, PartialEq, Eq)]
 pub struct CEchoRq {
     message_id: u16,
 }
@@ -8,75 +23,46 @@ impl CEchoRq {
     pub fn message_id(&self) -> u16 {
         self.message_id
     }
-}
-
-impl TryFrom<CommandSet> for CEchoRq {
-    type Error = &'static str;
 
-    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
-        let mut affected_sop_class_uid = None;
-        let mut command_field = None;
-        let mut message_id = None;
-        let mut command_data_set_type = None;
+    pub fn new(message_id: u16) -> Self {
+        Self { message_id }
+    }
+}
 
-        for command in val.iter() {
-            let tag = command.tag();
-            let value_length = command.value_length();
-            let value_field = command.value_field();
-            match (tag.group(), tag.element()) {
-                (0x0000, 0x0002) => {
-                    let str = std::str::from_utf8(value_field).map_err(|_| "Affected SOP Class UIDコマンドの値フィールドをUTF-8の文字列として解釈できません")?;
-                    affected_sop_class_uid = Some(str.trim_end_matches('\0'));
-                }
-                (0x0000, 0x0100) => {
-                    if value_length != 2 {
-                        return Err("Command Fieldコマンドの値長さが不正です");
-                    }
-                    command_field = Some(u16::from_le_bytes([value_field[0], value_field[1]]));
-                }
-                (0x0000, 0x0110) => {
-                    if value_length != 2 {
-                        return Err("Message IDコマンドの値長さが不正です");
-                    }
-                    message_id = Some(u16::from_le_bytes([value_field[0], value_field[1]]));
-                }
-                (0x0000, 0x0800) => {
-                    if value_length != 2 {
-                        return Err("Command Data Set Typeコマンドの値長さが不正です");
-                    }
-                    command_data_set_type =
-                        Some(u16::from_le_bytes([value_field[0], value_field[1]]));
-                }
-                _ => {}
-            }
-        }
+impl DimseMessage for CEchoRq {
+    const COMMAND_FIELD: CommandField = CommandField::CEchoRq;
 
-        if affected_sop_class_uid.is_none() {
-            return Err("Affected SOP Class UIDコマンドが存在しません");
-        }
-        if affected_sop_class_uid.unwrap() != VERIFICATION {
-            return Err("Affected SOP Class UIDが不正です");
-        }
+    fn has_data_set(&self) -> bool {
+        false
+    }
+}
 
-        if command_field.is_none() {
-            return Err("Command Fieldコマンドが存在しません");
-        }
-        if command_field.unwrap() != 0x30 {
-            return Err("Command Fieldが不正です");
-        }
+impl TryFrom<CommandSet> for CEchoRq {
+    type Error = String;
 
-        if message_id.is_none() {
-            return Err("Message IDコマンドが存在しません");
-        }
-        let message_id = message_id.unwrap();
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
 
-        if command_data_set_type.is_none() {
-            return Err("Command Data Set Typeコマンドが存在しません");
+        let affected_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?;
+        if affected_sop_class_uid != VERIFICATION {
+            return Err("Affected SOP Class UIDが不正です".to_string());
         }
-        if command_data_set_type.unwrap() != 0x0101 {
-            return Err("Command Data Set Typeが不正です");
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        if reader.has_data_set()? {
+            return Err("Command Data Set Typeが不正です".to_string());
         }
 
         Ok(CEchoRq { message_id })
     }
 }
+
+impl From<CEchoRq> for CommandSet {
+    fn from(val: CEchoRq) -> Self {
+        CommandSetBuilder::new(CEchoRq::COMMAND_FIELD, false)
+            .uid(Tag(0x0000, 0x0002), VERIFICATION)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .build()
+    }
+}
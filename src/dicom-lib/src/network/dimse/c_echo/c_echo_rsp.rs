@@ -1,10 +1,16 @@
 use crate::{
     constants::sop_class_uids::VERIFICATION,
     core::Tag,
-    network::{CommandSet, command_set::Command},
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
 };
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Status {
     /// 成功 ... 操作が成功したことを示す
     Success = 0x0000,
@@ -18,6 +24,34 @@ pub enum Status {
     UnrecognizedOperation = 0x0211,
 }
 
+impl TryFrom<u16> for Status {
+    type Error = String;
+
+    fn try_from(val: u16) -> Result<Self, Self::Error> {
+        match val {
+            0x0000 => Ok(Status::Success),
+            0x0122 => Ok(Status::Refused),
+            0x0210 => Ok(Status::DuplicateInvocation),
+            0x0212 => Ok(Status::MistypedArgument),
+            0x0211 => Ok(Status::UnrecognizedOperation),
+            _ => Err(format!(
+                "C-ECHOで定義されていないステータスコードです (コード={val:#06X})"
+            )),
+        }
+    }
+}
+
+impl From<Status> for crate::network::dimse::Status {
+    fn from(val: Status) -> Self {
+        Self::new(val as u16)
+    }
+}
+
+/// C-ECHO-RSP
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.5.2.html>
+#[derive(Debug, PartialEq, Eq)]
 pub struct CEchoRsp {
     message_id: u16,
     status: Status,
@@ -37,49 +71,35 @@ impl CEchoRsp {
     }
 }
 
+impl DimseMessage for CEchoRsp {
+    const COMMAND_FIELD: CommandField = CommandField::CEchoRsp;
+
+    fn has_data_set(&self) -> bool {
+        false
+    }
+}
+
+impl TryFrom<CommandSet> for CEchoRsp {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        let status = Status::try_from(reader.required_us(Tag(0x0000, 0x0900), "Status")?)?;
+
+        Ok(CEchoRsp { message_id, status })
+    }
+}
+
 impl From<CEchoRsp> for CommandSet {
     fn from(val: CEchoRsp) -> Self {
-        let affected_sop_class_uid = Command {
-            tag: Tag(0x0000, 0x0002),
-            value_field: format!("{}{}", VERIFICATION, '\0').into_bytes(),
-        };
-        let command_field = Command {
-            tag: Tag(0x0000, 0x0100),
-            value_field: 0x8030u16.to_le_bytes().to_vec(),
-        };
-        let message_id_being_responded_to = Command {
-            tag: Tag(0x0000, 0x0120),
-            value_field: val.message_id.to_le_bytes().to_vec(),
-        };
-        let command_data_set_type = Command {
-            tag: Tag(0x0000, 0x0800),
-            value_field: 0x0101u16.to_le_bytes().to_vec(),
-        };
-        let status = Command {
-            tag: Tag(0x0000, 0x0900),
-            value_field: (val.status as u16).to_le_bytes().to_vec(),
-        };
-        let group_length = affected_sop_class_uid.size()
-            + command_field.size()
-            + message_id_being_responded_to.size()
-            + command_data_set_type.size()
-            + status.size();
-        let command_group_length = Command {
-            tag: Tag(0x0000, 0x0000),
-            value_field: (group_length as u32).to_le_bytes().to_vec(),
-        };
-        let size = group_length + command_group_length.size();
-
-        CommandSet {
-            size,
-            commands: vec![
-                command_group_length,
-                affected_sop_class_uid,
-                command_field,
-                message_id_being_responded_to,
-                command_data_set_type,
-                status,
-            ],
-        }
+        CommandSetBuilder::new(CEchoRsp::COMMAND_FIELD, false)
+            .uid(Tag(0x0000, 0x0002), VERIFICATION)
+            .us(Tag(0x0000, 0x0120), val.message_id)
+            .us(Tag(0x0000, 0x0900), val.status as u16)
+            .build()
     }
 }
@@ -0,0 +1,5 @@
+mod c_find_rq;
+mod c_find_rsp;
+
+pub use c_find_rq::CFindRq;
+pub use c_find_rsp::CFindRsp;
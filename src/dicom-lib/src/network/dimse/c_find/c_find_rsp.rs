@@ -0,0 +1,125 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, Status, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// C-FIND-RSP
+///
+/// ステータスが保留中（Pending）の場合は、一致した結果をデータセットとして伴う。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.2.html#sect_9.3.2.2>
+#[derive(Debug, PartialEq, Eq)]
+pub struct CFindRsp {
+    message_id: u16,
+    status: Status,
+    affected_sop_class_uid: String,
+    has_data_set: bool,
+}
+
+impl CFindRsp {
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn new(message_id: u16, status: Status, affected_sop_class_uid: impl Into<String>) -> Self {
+        Self {
+            message_id,
+            status,
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            has_data_set: status.is_pending(),
+        }
+    }
+}
+
+impl DimseMessage for CFindRsp {
+    const COMMAND_FIELD: CommandField = CommandField::CFindRsp;
+
+    fn has_data_set(&self) -> bool {
+        self.has_data_set
+    }
+}
+
+impl TryFrom<CommandSet> for CFindRsp {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid = reader
+            .uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?
+            .unwrap_or_default();
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        let has_data_set = reader.has_data_set()?;
+        let status = Status::new(reader.required_us(Tag(0x0000, 0x0900), "Status")?);
+
+        Ok(CFindRsp {
+            message_id,
+            status,
+            affected_sop_class_uid,
+            has_data_set,
+        })
+    }
+}
+
+impl From<CFindRsp> for CommandSet {
+    fn from(val: CFindRsp) -> Self {
+        CommandSetBuilder::new(CFindRsp::COMMAND_FIELD, val.has_data_set)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0120), val.message_id)
+            .us(Tag(0x0000, 0x0900), val.status.code())
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_find_rsp_has_data_set() {
+        // 保留中の応答は一致した結果を伴う
+        {
+            // Arrange
+            let rsp = CFindRsp::new(1, Status::PENDING, "1.2.840.10008.5.1.4.1.2.2.1");
+
+            // Act
+            let command_set: CommandSet = rsp.into();
+
+            // Assert
+            let actual = CFindRsp::try_from(command_set).unwrap();
+            assert!(actual.has_data_set());
+            assert_eq!(actual.status(), Status::PENDING);
+        }
+
+        // 最終の応答はデータセットを伴わない
+        {
+            // Arrange
+            let rsp = CFindRsp::new(1, Status::SUCCESS, "1.2.840.10008.5.1.4.1.2.2.1");
+
+            // Act
+            let command_set: CommandSet = rsp.into();
+
+            // Assert
+            let actual = CFindRsp::try_from(command_set).unwrap();
+            assert!(!actual.has_data_set());
+            assert_eq!(actual.status(), Status::SUCCESS);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod c_get_rq;
+mod c_get_rsp;
+
+pub use c_get_rq::CGetRq;
+pub use c_get_rsp::CGetRsp;
@@ -0,0 +1,94 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage,
+            command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader,
+            enums::{CommandField, Priority},
+        },
+    },
+};
+
+/// C-GET-RQ
+///
+/// 取得するインスタンスを特定するキー（Identifier）をデータセットとして伴う。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.3.html#sect_9.3.3.1>
+#[derive(Debug, PartialEq, Eq)]
+pub struct CGetRq {
+    affected_sop_class_uid: String,
+    message_id: u16,
+    priority: Priority,
+}
+
+impl CGetRq {
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    pub fn new(
+        affected_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        priority: Priority,
+    ) -> Self {
+        Self {
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            message_id,
+            priority,
+        }
+    }
+}
+
+impl DimseMessage for CGetRq {
+    const COMMAND_FIELD: CommandField = CommandField::CGetRq;
+
+    fn has_data_set(&self) -> bool {
+        true
+    }
+}
+
+impl TryFrom<CommandSet> for CGetRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        let priority = Priority::try_from(reader.required_us(Tag(0x0000, 0x0700), "Priority")?)
+            .map_err(|e| format!("Priorityが不正です: {e}"))?;
+        if !reader.has_data_set()? {
+            // Identifierを含むデータセットが必須
+            return Err("Command Data Set Typeが不正です".to_string());
+        }
+
+        Ok(CGetRq {
+            affected_sop_class_uid,
+            message_id,
+            priority,
+        })
+    }
+}
+
+impl From<CGetRq> for CommandSet {
+    fn from(val: CGetRq) -> Self {
+        CommandSetBuilder::new(CGetRq::COMMAND_FIELD, true)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .us(Tag(0x0000, 0x0700), val.priority as u16)
+            .build()
+    }
+}
@@ -0,0 +1,5 @@
+mod c_move_rq;
+mod c_move_rsp;
+
+pub use c_move_rq::CMoveRq;
+pub use c_move_rsp::CMoveRsp;
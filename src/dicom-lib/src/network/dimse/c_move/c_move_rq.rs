@@ -0,0 +1,163 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage,
+            command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader,
+            enums::{CommandField, Priority},
+        },
+    },
+};
+
+/// C-MOVE-RQ
+///
+/// 移動するインスタンスを特定するキー（Identifier）をデータセットとして伴う。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.4.html#sect_9.3.4.1>
+#[derive(Debug, PartialEq, Eq)]
+pub struct CMoveRq {
+    affected_sop_class_uid: String,
+    message_id: u16,
+    move_destination: String,
+    priority: Priority,
+}
+
+impl CMoveRq {
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    /// C-STOREのサブ操作の送信先となるAEタイトル
+    pub fn move_destination(&self) -> &str {
+        &self.move_destination
+    }
+
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    pub fn new(
+        affected_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        move_destination: impl Into<String>,
+        priority: Priority,
+    ) -> Self {
+        Self {
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            message_id,
+            move_destination: move_destination.into(),
+            priority,
+        }
+    }
+}
+
+impl DimseMessage for CMoveRq {
+    const COMMAND_FIELD: CommandField = CommandField::CMoveRq;
+
+    fn has_data_set(&self) -> bool {
+        true
+    }
+}
+
+impl TryFrom<CommandSet> for CMoveRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        let move_destination = reader.required_ae(Tag(0x0000, 0x0600), "Move Destination")?;
+        let priority = Priority::try_from(reader.required_us(Tag(0x0000, 0x0700), "Priority")?)
+            .map_err(|e| format!("Priorityが不正です: {e}"))?;
+        if !reader.has_data_set()? {
+            // Identifierを含むデータセットが必須
+            return Err("Command Data Set Typeが不正です".to_string());
+        }
+
+        Ok(CMoveRq {
+            affected_sop_class_uid,
+            message_id,
+            move_destination,
+            priority,
+        })
+    }
+}
+
+impl From<CMoveRq> for CommandSet {
+    fn from(val: CMoveRq) -> Self {
+        CommandSetBuilder::new(CMoveRq::COMMAND_FIELD, true)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .ae(Tag(0x0000, 0x0600), &val.move_destination)
+            .us(Tag(0x0000, 0x0700), val.priority as u16)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::command_set::Command;
+
+    #[test]
+    fn test_c_move_rq_try_from() {
+        // 正常系
+        {
+            // Arrange
+            let expected = CMoveRq::new(
+                "1.2.840.10008.5.1.4.1.2.2.2",
+                4,
+                "STORE_SCP",
+                Priority::Medium,
+            );
+            let command_set: CommandSet = CMoveRq::new(
+                "1.2.840.10008.5.1.4.1.2.2.2",
+                4,
+                "STORE_SCP",
+                Priority::Medium,
+            )
+            .into();
+
+            // Act
+            let actual = CMoveRq::try_from(command_set).unwrap();
+
+            // Assert
+            assert_eq!(expected, actual);
+        }
+
+        // 準正常系: Move Destinationが存在しない
+        {
+            // Arrange
+            let command_set = CommandSet::new(vec![
+                Command::new(
+                    Tag(0x0000, 0x0002),
+                    "1.2.840.10008.5.1.4.1.2.2.2\0".as_bytes().to_vec(),
+                ),
+                Command::new(Tag(0x0000, 0x0100), 0x0021u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0110), 4u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0700), 0u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0800), 0x0001u16.to_le_bytes().to_vec()),
+            ])
+            .unwrap();
+
+            // Act
+            let result = CMoveRq::try_from(command_set);
+
+            // Assert
+            assert_eq!(
+                result.unwrap_err(),
+                "Move Destinationコマンドが存在しません"
+            );
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, Status, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// C-MOVE-RSP
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.4.html#sect_9.3.4.2>
+#[derive(Debug, PartialEq, Eq)]
+pub struct CMoveRsp {
+    message_id: u16,
+    status: Status,
+    affected_sop_class_uid: String,
+    number_of_remaining_sub_operations: Option<u16>,
+    number_of_completed_sub_operations: Option<u16>,
+    number_of_failed_sub_operations: Option<u16>,
+    number_of_warning_sub_operations: Option<u16>,
+    has_data_set: bool,
+}
+
+impl CMoveRsp {
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn number_of_remaining_sub_operations(&self) -> Option<u16> {
+        self.number_of_remaining_sub_operations
+    }
+
+    pub fn number_of_completed_sub_operations(&self) -> Option<u16> {
+        self.number_of_completed_sub_operations
+    }
+
+    pub fn number_of_failed_sub_operations(&self) -> Option<u16> {
+        self.number_of_failed_sub_operations
+    }
+
+    pub fn number_of_warning_sub_operations(&self) -> Option<u16> {
+        self.number_of_warning_sub_operations
+    }
+
+    pub fn new(message_id: u16, status: Status, affected_sop_class_uid: impl Into<String>) -> Self {
+        Self {
+            message_id,
+            status,
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            number_of_remaining_sub_operations: None,
+            number_of_completed_sub_operations: None,
+            number_of_failed_sub_operations: None,
+            number_of_warning_sub_operations: None,
+            has_data_set: false,
+        }
+    }
+
+    /// サブ操作の件数を設定する。残りの件数はステータスが保留中の場合のみ指定する。
+    pub fn with_sub_operations(
+        mut self,
+        remaining: Option<u16>,
+        completed: u16,
+        failed: u16,
+        warning: u16,
+    ) -> Self {
+        self.number_of_remaining_sub_operations = remaining;
+        self.number_of_completed_sub_operations = Some(completed);
+        self.number_of_failed_sub_operations = Some(failed);
+        self.number_of_warning_sub_operations = Some(warning);
+        self
+    }
+
+    /// 失敗したSOPインスタンスUIDのリストをデータセットとして送信する。
+    pub fn with_data_set(mut self) -> Self {
+        self.has_data_set = true;
+        self
+    }
+}
+
+impl DimseMessage for CMoveRsp {
+    const COMMAND_FIELD: CommandField = CommandField::CMoveRsp;
+
+    fn has_data_set(&self) -> bool {
+        self.has_data_set
+    }
+}
+
+impl TryFrom<CommandSet> for CMoveRsp {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid = reader
+            .uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?
+            .unwrap_or_default();
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        let has_data_set = reader.has_data_set()?;
+        let status = Status::new(reader.required_us(Tag(0x0000, 0x0900), "Status")?);
+        let number_of_remaining_sub_operations =
+            reader.us(Tag(0x0000, 0x1020), "Number of Remaining Sub-operations")?;
+        let number_of_completed_sub_operations =
+            reader.us(Tag(0x0000, 0x1021), "Number of Completed Sub-operations")?;
+        let number_of_failed_sub_operations =
+            reader.us(Tag(0x0000, 0x1022), "Number of Failed Sub-operations")?;
+        let number_of_warning_sub_operations =
+            reader.us(Tag(0x0000, 0x1023), "Number of Warning Sub-operations")?;
+
+        Ok(CMoveRsp {
+            message_id,
+            status,
+            affected_sop_class_uid,
+            number_of_remaining_sub_operations,
+            number_of_completed_sub_operations,
+            number_of_failed_sub_operations,
+            number_of_warning_sub_operations,
+            has_data_set,
+        })
+    }
+}
+
+impl From<CMoveRsp> for CommandSet {
+    fn from(val: CMoveRsp) -> Self {
+        let mut builder = CommandSetBuilder::new(CMoveRsp::COMMAND_FIELD, val.has_data_set)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0120), val.message_id)
+            .us(Tag(0x0000, 0x0900), val.status.code());
+        for (tag, value) in [
+            (Tag(0x0000, 0x1020), val.number_of_remaining_sub_operations),
+            (Tag(0x0000, 0x1021), val.number_of_completed_sub_operations),
+            (Tag(0x0000, 0x1022), val.number_of_failed_sub_operations),
+            (Tag(0x0000, 0x1023), val.number_of_warning_sub_operations),
+        ] {
+            if let Some(value) = value {
+                builder = builder.us(tag, value);
+            }
+        }
+        builder.build()
+    }
+}
@@ -1,6 +1,20 @@
-use crate::network::{CommandSet, dimse::enums::Priority};
-use std::str::from_utf8;
-
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage,
+            command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader,
+            enums::{CommandField, Priority},
+        },
+    },
+};
+
+/// C-STORE-RQ
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.html#sect_9.3.1.1>
 #[derive(Debug, PartialEq, Eq)]
 pub struct CStoreRq {
     affected_sop_class_uid: String,
@@ -35,138 +49,99 @@ impl CStoreRq {
     pub fn move_originator_message_id(&self) -> Option<u16> {
         self.move_originator_message_id
     }
+
+    pub fn new(
+        affected_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        priority: Priority,
+        affected_sop_instance_uid: impl Into<String>,
+    ) -> Self {
+        Self {
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            message_id,
+            priority,
+            affected_sop_instance_uid: affected_sop_instance_uid.into(),
+            move_originator_ae_title: None,
+            move_originator_message_id: None,
+        }
+    }
+
+    /// C-MOVEのサブ操作として送信する場合に、C-MOVEを要求したAEの情報を設定する。
+    pub fn with_move_originator(
+        mut self,
+        move_originator_ae_title: impl Into<String>,
+        move_originator_message_id: u16,
+    ) -> Self {
+        self.move_originator_ae_title = Some(move_originator_ae_title.into());
+        self.move_originator_message_id = Some(move_originator_message_id);
+        self
+    }
+}
+
+impl DimseMessage for CStoreRq {
+    const COMMAND_FIELD: CommandField = CommandField::CStoreRq;
+
+    fn has_data_set(&self) -> bool {
+        true
+    }
 }
 
 impl TryFrom<CommandSet> for CStoreRq {
     type Error = String;
 
     fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
-        let mut affected_sop_class_uid = None;
-        let mut command_field = None;
-        let mut message_id = None;
-        let mut priority = None;
-        let mut command_data_set_type = None;
-        let mut affected_sop_instance_uid = None;
-        let mut move_originator_ae_title = None;
-        let mut move_originator_message_id = None;
-
-        for command in val.iter() {
-            let tag = command.tag();
-            let value_length = command.value_length();
-            let value_field = command.value_field();
-            match (tag.group(), tag.element()) {
-                (0x0000, 0x0002) => {
-                    let uid = from_utf8(value_field).map_err(|_| "Affected SOP Class UIDコマンドの値フィールドをUTF-8の文字列として解釈できません")?.trim_end_matches('\0');
-                    if !uid.starts_with(
-                        "1.2.840.10008.5.1.4.1.1.", // Storage関連のSOP Class UIDは1.2.840.10008.5.1.4.1.1.*の形式
-                    ) {
-                        return Err("Affected SOP Class UIDが不正です".to_string());
-                    }
-                    affected_sop_class_uid = Some(uid.to_string());
-                }
-                (0x0000, 0x0100) => {
-                    if value_length != 2 {
-                        return Err("Command Fieldコマンドの値長さが不正です".to_string());
-                    }
-                    let f = u16::from_le_bytes([value_field[0], value_field[1]]);
-                    if f != 0x0001 {
-                        return Err("Command Fieldが不正です".to_string());
-                    }
-                    command_field = Some(f);
-                }
-                (0x0000, 0x0110) => {
-                    if value_length != 2 {
-                        return Err("Message IDコマンドの値長さが不正です".to_string());
-                    }
-                    message_id = Some(u16::from_le_bytes([value_field[0], value_field[1]]));
-                }
-                (0x0000, 0x0700) => {
-                    if value_length != 2 {
-                        return Err("Priorityコマンドの値長さが不正です".to_string());
-                    }
-                    priority = Some(
-                        Priority::try_from(u16::from_le_bytes([value_field[0], value_field[1]]))
-                            .map_err(|e| format!("Priorityが不正です: {e}"))?,
-                    );
-                }
-                (0x0000, 0x0800) => {
-                    if value_length != 2 {
-                        return Err("Command Data Set Typeコマンドの値長さが不正です".to_string());
-                    }
-                    let t = u16::from_le_bytes([value_field[0], value_field[1]]);
-                    if t == 0x0101 {
-                        return Err("Command Data Set Typeが不正です".to_string());
-                    }
-                    command_data_set_type = Some(t);
-                }
-                (0x0000, 0x1000) => {
-                    let uid = from_utf8(value_field).map_err(|_| "Affected SOP Instance UIDコマンドの値フィールドをUTF-8の文字列として解釈できません")?.trim_end_matches('\0');
-                    if uid.is_empty() {
-                        return Err("Affected SOP Instance UIDが空です".to_string());
-                    }
-                    affected_sop_instance_uid = Some(uid.to_string());
-                }
-                (0x0000, 0x1030) => {
-                    let title = from_utf8(value_field).map_err(|_| "Move Originator Application Entity Titleコマンドの値フィールドをUTF-8の文字列として解釈できません")?.trim_end_matches(' ').trim_start_matches(' ');
-                    if title.is_empty() || title.len() > 16 {
-                        return Err(
-                            "Move Originator Application Entity Titleは1文字以上16文字以下でなければなりません".to_string(),
-                        );
-                    }
-                    if !title.is_ascii() {
-                        return Err(
-                            "Move Originator Application Entity TitleはISO 646:1990 (basic G0 set)でエンコーディングされている必要があります".to_string(),
-                        );
-                    }
-                    move_originator_ae_title = Some(title.to_string());
-                }
-                (0x0000, 0x1031) => {
-                    if value_length != 2 {
-                        return Err(
-                            "Move Originator Message IDコマンドの値長さが不正です".to_string()
-                        );
-                    }
-                    move_originator_message_id =
-                        Some(u16::from_le_bytes([value_field[0], value_field[1]]));
-                }
-                _ => {}
-            }
-        }
-
-        if affected_sop_class_uid.is_none() {
-            return Err("Affected SOP Class UIDコマンドが存在しません".to_string());
-        }
-        if command_field.is_none() {
-            return Err("Command Fieldコマンドが存在しません".to_string());
-        }
-        if message_id.is_none() {
-            return Err("Message IDコマンドが存在しません".to_string());
-        }
-        if priority.is_none() {
-            return Err("Priorityコマンドが存在しません".to_string());
-        }
-        if command_data_set_type.is_none() {
-            return Err("Command Data Set Typeコマンドが存在しません".to_string());
-        }
-        if affected_sop_instance_uid.is_none() {
-            return Err("Affected SOP Instance UIDコマンドが存在しません".to_string());
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        let priority = Priority::try_from(reader.required_us(Tag(0x0000, 0x0700), "Priority")?)
+            .map_err(|e| format!("Priorityが不正です: {e}"))?;
+        if !reader.has_data_set()? {
+            return Err("Command Data Set Typeが不正です".to_string());
         }
+        let affected_sop_instance_uid =
+            reader.required_uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")?;
+        let move_originator_ae_title = reader.ae(
+            Tag(0x0000, 0x1030),
+            "Move Originator Application Entity Title",
+        )?;
+        let move_originator_message_id =
+            reader.us(Tag(0x0000, 0x1031), "Move Originator Message ID")?;
 
         Ok(CStoreRq {
-            affected_sop_class_uid: affected_sop_class_uid.unwrap(),
-            message_id: message_id.unwrap(),
-            priority: priority.unwrap(),
-            affected_sop_instance_uid: affected_sop_instance_uid.unwrap(),
+            affected_sop_class_uid,
+            message_id,
+            priority,
+            affected_sop_instance_uid,
             move_originator_ae_title,
             move_originator_message_id,
         })
     }
 }
 
+impl From<CStoreRq> for CommandSet {
+    fn from(val: CStoreRq) -> Self {
+        let mut builder = CommandSetBuilder::new(CStoreRq::COMMAND_FIELD, true)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .us(Tag(0x0000, 0x0700), val.priority as u16)
+            .uid(Tag(0x0000, 0x1000), &val.affected_sop_instance_uid);
+        if let Some(title) = &val.move_originator_ae_title {
+            builder = builder.ae(Tag(0x0000, 0x1030), title);
+        }
+        if let Some(message_id) = val.move_originator_message_id {
+            builder = builder.us(Tag(0x0000, 0x1031), message_id);
+        }
+        builder.build()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{core::Tag, network::command_set::Command};
+    use crate::network::command_set::Command;
 
     #[test]
     fn test_c_store_rq_try_from() {
@@ -205,4 +180,38 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_c_store_rq_into_command_set() {
+        // Arrange
+        let expected = CStoreRq::new(
+            "1.2.840.10008.5.1.4.1.1.2",
+            3,
+            Priority::High,
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+        )
+        .with_move_originator("MOVE_SCU", 7);
+
+        // Act
+        let command_set: CommandSet = CStoreRq::new(
+            "1.2.840.10008.5.1.4.1.1.2",
+            3,
+            Priority::High,
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+        )
+        .with_move_originator("MOVE_SCU", 7)
+        .into();
+
+        // Assert
+        // Command Group Lengthはそれ以降のコマンドの合計サイズと一致する
+        let group_length = u32::from_le_bytes(command_set[0].value_field().try_into().unwrap());
+        assert_eq!(
+            group_length as usize,
+            command_set.size() - command_set[0].size()
+        );
+        // タグの昇順で並んでいる
+        assert!(command_set.iter().is_sorted_by_key(|c| c.tag()));
+        // 解析すると元のメッセージに戻る
+        assert_eq!(CStoreRq::try_from(command_set).unwrap(), expected);
+    }
 }
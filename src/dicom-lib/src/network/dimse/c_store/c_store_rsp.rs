@@ -4,9 +4,20 @@ pub use status::Status;
 
 use crate::{
     core::Tag,
-    network::{CommandSet, command_set::Command},
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
 };
 
+/// C-STORE-RSP
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_9.3.html#sect_9.3.1.2>
+#[derive(Debug, PartialEq, Eq)]
 pub struct CStoreRsp {
     message_id: u16,
     status: Status,
@@ -46,66 +57,90 @@ impl CStoreRsp {
     }
 }
 
+impl DimseMessage for CStoreRsp {
+    const COMMAND_FIELD: CommandField = CommandField::CStoreRsp;
+
+    fn has_data_set(&self) -> bool {
+        false
+    }
+}
+
+impl TryFrom<CommandSet> for CStoreRsp {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid = reader
+            .uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?
+            .unwrap_or_default();
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        let status = Status::try_from(reader.required_us(Tag(0x0000, 0x0900), "Status")?)?;
+        let affected_sop_instance_uid = reader
+            .uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")?
+            .unwrap_or_default();
+
+        Ok(CStoreRsp {
+            message_id,
+            status,
+            affected_sop_class_uid,
+            affected_sop_instance_uid,
+        })
+    }
+}
+
 impl From<CStoreRsp> for CommandSet {
     fn from(val: CStoreRsp) -> Self {
-        let affected_sop_class_uid = {
-            let mut uid = val.affected_sop_class_uid;
-            if !uid.len().is_multiple_of(2) {
-                uid.push('\0');
-            };
-            Command {
-                tag: Tag(0x0000, 0x0002),
-                value_field: uid.into_bytes(),
-            }
-        };
-        let command_field = Command {
-            tag: Tag(0x0000, 0x0100),
-            value_field: 0x8001u16.to_le_bytes().to_vec(),
-        };
-        let message_id_being_responded_to = Command {
-            tag: Tag(0x0000, 0x0120),
-            value_field: val.message_id.to_le_bytes().to_vec(),
-        };
-        let command_data_set_type = Command {
-            tag: Tag(0x0000, 0x0800),
-            value_field: 0x0101u16.to_le_bytes().to_vec(),
-        };
-        let status = Command {
-            tag: Tag(0x0000, 0x0900),
-            value_field: Into::<u16>::into(val.status).to_le_bytes().to_vec(),
-        };
-        let affected_sop_instance_uid = {
-            let mut uid = val.affected_sop_instance_uid;
-            if !uid.len().is_multiple_of(2) {
-                uid.push('\0');
-            };
-            Command {
-                tag: Tag(0x0000, 0x1000),
-                value_field: uid.into_bytes(),
-            }
-        };
-        let group_length = affected_sop_class_uid.size()
-            + command_field.size()
-            + message_id_being_responded_to.size()
-            + command_data_set_type.size()
-            + status.size()
-            + affected_sop_instance_uid.size();
-        let command_group_length = Command {
-            tag: Tag(0x0000, 0x0000),
-            value_field: (group_length as u32).to_le_bytes().to_vec(),
-        };
-        let size = group_length + command_group_length.size();
-
-        CommandSet {
-            size,
-            commands: vec![
-                command_group_length,
-                affected_sop_class_uid,
-                command_field,
-                message_id_being_responded_to,
-                command_data_set_type,
-                status,
-            ],
-        }
+        CommandSetBuilder::new(CStoreRsp::COMMAND_FIELD, false)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0120), val.message_id)
+            .us(Tag(0x0000, 0x0900), val.status.into())
+            .uid(Tag(0x0000, 0x1000), &val.affected_sop_instance_uid)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_store_rsp_into_command_set() {
+        // Arrange
+        let rsp = CStoreRsp::new(
+            5,
+            Status::SopClassNotSupported,
+            "1.2.840.10008.5.1.4.1.1.4",
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+        );
+
+        // Act
+        let command_set: CommandSet = rsp.into();
+
+        // Assert
+        // Affected SOP Instance UIDもコマンドセットに含まれる
+        let tags = command_set.iter().map(|c| c.tag()).collect::<Vec<_>>();
+        assert_eq!(
+            tags,
+            vec![
+                Tag(0x0000, 0x0000),
+                Tag(0x0000, 0x0002),
+                Tag(0x0000, 0x0100),
+                Tag(0x0000, 0x0120),
+                Tag(0x0000, 0x0800),
+                Tag(0x0000, 0x0900),
+                Tag(0x0000, 0x1000),
+            ]
+        );
+        let actual = CStoreRsp::try_from(command_set).unwrap();
+        assert_eq!(actual.message_id(), 5);
+        assert_eq!(actual.status(), Status::SopClassNotSupported);
+        assert_eq!(actual.affected_sop_class_uid(), "1.2.840.10008.5.1.4.1.1.4");
+        assert_eq!(
+            actual.affected_sop_instance_uid(),
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1"
+        );
     }
 }
@@ -144,3 +144,9 @@ impl From<Status> for u16 {
         }
     }
 }
+
+impl From<Status> for crate::network::dimse::Status {
+    fn from(val: Status) -> Self {
+        Self::new(val.into())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    core::Tag,
+    network::{CommandSet, command_set::Command, dimse::enums::CommandField},
+};
+
+/// DIMSEメッセージのコマンドセットを組み立てるビルダー
+///
+/// `Command Group Length (0000,0000)`の計算とタグの昇順への並べ替えはビルド時に行う。
+pub(crate) struct CommandSetBuilder {
+    commands: Vec<Command>,
+}
+
+impl CommandSetBuilder {
+    pub(crate) fn new(command_field: CommandField, has_data_set: bool) -> Self {
+        // データセットが存在する場合の値は0x0101以外であれば何でもよい
+        let command_data_set_type: u16 = if has_data_set { 0x0001 } else { 0x0101 };
+        Self {
+            commands: vec![
+                Command {
+                    tag: Tag(0x0000, 0x0100),
+                    value_field: u16::from(command_field).to_le_bytes().to_vec(),
+                },
+                Command {
+                    tag: Tag(0x0000, 0x0800),
+                    value_field: command_data_set_type.to_le_bytes().to_vec(),
+                },
+            ],
+        }
+    }
+
+    /// UI (UID) 型のコマンドを追加する。奇数長の場合はNULL文字で埋める。
+    pub(crate) fn uid(mut self, tag: Tag, uid: &str) -> Self {
+        let mut value_field = uid.as_bytes().to_vec();
+        if !value_field.len().is_multiple_of(2) {
+            value_field.push(b'\0');
+        }
+        self.commands.push(Command { tag, value_field });
+        self
+    }
+
+    /// AE (Application Entity) 型のコマンドを追加する。16バイトになるまで空白で埋める。
+    pub(crate) fn ae(mut self, tag: Tag, title: &str) -> Self {
+        let value_field = format!("{title:<16}").into_bytes();
+        self.commands.push(Command { tag, value_field });
+        self
+    }
+
+    /// US (Unsigned Short) 型のコマンドを追加する。
+    pub(crate) fn us(mut self, tag: Tag, value: u16) -> Self {
+        self.commands.push(Command {
+            tag,
+            value_field: value.to_le_bytes().to_vec(),
+        });
+        self
+    }
+
+    /// AT (Attribute Tag) 型のコマンドを追加する。
+    pub(crate) fn at(mut self, tag: Tag, tags: &[Tag]) -> Self {
+        let value_field = tags
+            .iter()
+            .flat_map(|&t| <[u8; 4]>::from(t))
+            .collect::<Vec<_>>();
+        self.commands.push(Command { tag, value_field });
+        self
+    }
+
+    pub(crate) fn build(mut self) -> CommandSet {
+        self.commands.sort_by_key(|command| command.tag);
+
+        let group_length = self.commands.iter().map(|c| c.size()).sum::<usize>();
+        let command_group_length = Command {
+            tag: Tag(0x0000, 0x0000),
+            value_field: (group_length as u32).to_le_bytes().to_vec(),
+        };
+        let size = group_length + command_group_length.size();
+        self.commands.insert(0, command_group_length);
+
+        CommandSet {
+            size,
+            commands: self.commands,
+        }
+    }
+}
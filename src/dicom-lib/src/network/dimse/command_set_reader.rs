@@ -0,0 +1,126 @@
+use crate::{
+    core::Tag,
+    network::{CommandSet, command_set::Command, dimse::enums::CommandField},
+};
+use std::str::from_utf8;
+
+/// DIMSEメッセージのコマンドセットから各コマンドの値を読み取るリーダー
+///
+/// エラーメッセージにはコマンド名を含めるため、読み取り時にコマンド名を指定する。
+pub(crate) struct CommandSetReader<'a> {
+    command_set: &'a CommandSet,
+}
+
+impl<'a> CommandSetReader<'a> {
+    pub(crate) fn new(command_set: &'a CommandSet) -> Self {
+        Self { command_set }
+    }
+
+    fn find(&self, tag: Tag) -> Option<&'a Command> {
+        self.command_set.iter().find(|command| command.tag() == tag)
+    }
+
+    /// `Command Field (0000,0100)`が期待する値であることを確認する。
+    pub(crate) fn expect_command_field(&self, expected: CommandField) -> Result<(), String> {
+        let command_field = self.required_us(Tag(0x0000, 0x0100), "Command Field")?;
+        if command_field != u16::from(expected) {
+            return Err("Command Fieldが不正です".to_string());
+        }
+        Ok(())
+    }
+
+    /// `Command Data Set Type (0000,0800)`からデータセットが続くかどうかを判定する。
+    pub(crate) fn has_data_set(&self) -> Result<bool, String> {
+        let command_data_set_type =
+            self.required_us(Tag(0x0000, 0x0800), "Command Data Set Type")?;
+        Ok(command_data_set_type != 0x0101)
+    }
+
+    /// US (Unsigned Short) 型のコマンドを読み取る。
+    pub(crate) fn us(&self, tag: Tag, name: &str) -> Result<Option<u16>, String> {
+        let Some(command) = self.find(tag) else {
+            return Ok(None);
+        };
+        let value_field = command.value_field();
+        if value_field.len() != 2 {
+            return Err(format!("{name}コマンドの値長さが不正です"));
+        }
+        Ok(Some(u16::from_le_bytes([value_field[0], value_field[1]])))
+    }
+
+    pub(crate) fn required_us(&self, tag: Tag, name: &str) -> Result<u16, String> {
+        self.us(tag, name)?
+            .ok_or_else(|| format!("{name}コマンドが存在しません"))
+    }
+
+    /// UI (UID) 型のコマンドを読み取る。末尾のNULL文字は取り除く。
+    pub(crate) fn uid(&self, tag: Tag, name: &str) -> Result<Option<String>, String> {
+        let Some(command) = self.find(tag) else {
+            return Ok(None);
+        };
+        let uid = from_utf8(command.value_field())
+            .map_err(|_| {
+                format!("{name}コマンドの値フィールドをUTF-8の文字列として解釈できません")
+            })?
+            .trim_end_matches('\0');
+        Ok(Some(uid.to_string()))
+    }
+
+    /// 空であってはならないUI (UID) 型のコマンドを読み取る。
+    pub(crate) fn required_uid(&self, tag: Tag, name: &str) -> Result<String, String> {
+        let uid = self
+            .uid(tag, name)?
+            .ok_or_else(|| format!("{name}コマンドが存在しません"))?;
+        if uid.is_empty() {
+            return Err(format!("{name}が空です"));
+        }
+        Ok(uid)
+    }
+
+    /// AE (Application Entity) 型のコマンドを読み取る。前後の空白は取り除く。
+    pub(crate) fn ae(&self, tag: Tag, name: &str) -> Result<Option<String>, String> {
+        let Some(command) = self.find(tag) else {
+            return Ok(None);
+        };
+        let title = from_utf8(command.value_field())
+            .map_err(|_| {
+                format!("{name}コマンドの値フィールドをUTF-8の文字列として解釈できません")
+            })?
+            .trim_matches(' ');
+        if title.is_empty() || title.len() > 16 {
+            return Err(format!("{name}は1文字以上16文字以下でなければなりません"));
+        }
+        if !title.is_ascii() {
+            return Err(format!(
+                "{name}はISO 646:1990 (basic G0 set)でエンコーディングされている必要があります"
+            ));
+        }
+        Ok(Some(title.to_string()))
+    }
+
+    pub(crate) fn required_ae(&self, tag: Tag, name: &str) -> Result<String, String> {
+        self.ae(tag, name)?
+            .ok_or_else(|| format!("{name}コマンドが存在しません"))
+    }
+
+    /// AT (Attribute Tag) 型のコマンドを読み取る。
+    pub(crate) fn at(&self, tag: Tag, name: &str) -> Result<Option<Vec<Tag>>, String> {
+        let Some(command) = self.find(tag) else {
+            return Ok(None);
+        };
+        let value_field = command.value_field();
+        if !value_field.len().is_multiple_of(4) {
+            return Err(format!("{name}コマンドの値長さが不正です"));
+        }
+        let tags = value_field
+            .chunks_exact(4)
+            .map(|chunk| {
+                Tag(
+                    u16::from_le_bytes([chunk[0], chunk[1]]),
+                    u16::from_le_bytes([chunk[2], chunk[3]]),
+                )
+            })
+            .collect();
+        Ok(Some(tags))
+    }
+}
@@ -0,0 +1,12 @@
+use crate::network::{CommandSet, dimse::enums::CommandField};
+
+/// 全てのDIMSEメッセージ（C-*およびN-*の要求・応答）に共通のトレイト
+///
+/// コマンドセットからの解析（`TryFrom<CommandSet>`）とコマンドセットへの変換（`Into<CommandSet>`）を備える。
+pub trait DimseMessage: TryFrom<CommandSet, Error = String> + Into<CommandSet> {
+    /// メッセージの`Command Field (0000,0100)`
+    const COMMAND_FIELD: CommandField;
+
+    /// コマンドセットに続いてデータセットが送られるかどうか
+    fn has_data_set(&self) -> bool;
+}
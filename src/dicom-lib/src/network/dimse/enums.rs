@@ -1,3 +1,5 @@
+mod command_field;
 mod priority;
 
+pub use command_field::CommandField;
 pub use priority::Priority;
@@ -0,0 +1,209 @@
+use crate::{core::Tag, network::CommandSet};
+use std::fmt::{Display, Formatter};
+
+/// Command Field (0000,0100)
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/chapter_E.html>
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CommandField {
+    CStoreRq = 0x0001,
+    CStoreRsp = 0x8001,
+    CGetRq = 0x0010,
+    CGetRsp = 0x8010,
+    CFindRq = 0x0020,
+    CFindRsp = 0x8020,
+    CMoveRq = 0x0021,
+    CMoveRsp = 0x8021,
+    CEchoRq = 0x0030,
+    CEchoRsp = 0x8030,
+    NEventReportRq = 0x0100,
+    NEventReportRsp = 0x8100,
+    NGetRq = 0x0110,
+    NGetRsp = 0x8110,
+    NSetRq = 0x0120,
+    NSetRsp = 0x8120,
+    NActionRq = 0x0130,
+    NActionRsp = 0x8130,
+    NCreateRq = 0x0140,
+    NCreateRsp = 0x8140,
+    NDeleteRq = 0x0150,
+    NDeleteRsp = 0x8150,
+    CCancelRq = 0x0fff,
+}
+
+impl CommandField {
+    /// コマンドセットからCommand Fieldを取得する。
+    pub fn from_command_set(command_set: &CommandSet) -> Result<Self, String> {
+        let command = command_set
+            .iter()
+            .find(|command| command.tag() == Tag(0x0000, 0x0100))
+            .ok_or("Command Fieldコマンドが存在しません")?;
+        let value_field = command.value_field();
+        if value_field.len() != 2 {
+            return Err("Command Fieldコマンドの値長さが不正です".to_string());
+        }
+
+        Self::try_from(u16::from_le_bytes([value_field[0], value_field[1]]))
+    }
+
+    /// 要求メッセージかどうか
+    pub fn is_request(&self) -> bool {
+        (*self as u16) & 0x8000 == 0
+    }
+
+    /// 応答メッセージかどうか
+    pub fn is_response(&self) -> bool {
+        !self.is_request()
+    }
+
+    /// 要求に対応する応答のCommand Field
+    ///
+    /// 応答メッセージと、応答が存在しないC-CANCEL-RQの場合は`None`を返す。
+    pub fn response(&self) -> Option<Self> {
+        if !self.is_request() || *self == Self::CCancelRq {
+            return None;
+        }
+        Self::try_from((*self as u16) | 0x8000).ok()
+    }
+}
+
+impl TryFrom<u16> for CommandField {
+    type Error = String;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0x0001 => Ok(Self::CStoreRq),
+            0x8001 => Ok(Self::CStoreRsp),
+            0x0010 => Ok(Self::CGetRq),
+            0x8010 => Ok(Self::CGetRsp),
+            0x0020 => Ok(Self::CFindRq),
+            0x8020 => Ok(Self::CFindRsp),
+            0x0021 => Ok(Self::CMoveRq),
+            0x8021 => Ok(Self::CMoveRsp),
+            0x0030 => Ok(Self::CEchoRq),
+            0x8030 => Ok(Self::CEchoRsp),
+            0x0100 => Ok(Self::NEventReportRq),
+            0x8100 => Ok(Self::NEventReportRsp),
+            0x0110 => Ok(Self::NGetRq),
+            0x8110 => Ok(Self::NGetRsp),
+            0x0120 => Ok(Self::NSetRq),
+            0x8120 => Ok(Self::NSetRsp),
+            0x0130 => Ok(Self::NActionRq),
+            0x8130 => Ok(Self::NActionRsp),
+            0x0140 => Ok(Self::NCreateRq),
+            0x8140 => Ok(Self::NCreateRsp),
+            0x0150 => Ok(Self::NDeleteRq),
+            0x8150 => Ok(Self::NDeleteRsp),
+            0x0fff => Ok(Self::CCancelRq),
+            _ => Err(format!("Command Fieldが不正です (値={value:#06X})")),
+        }
+    }
+}
+
+impl From<CommandField> for u16 {
+    fn from(val: CommandField) -> Self {
+        val as u16
+    }
+}
+
+impl Display for CommandField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::CStoreRq => "C-STORE-RQ",
+            Self::CStoreRsp => "C-STORE-RSP",
+            Self::CGetRq => "C-GET-RQ",
+            Self::CGetRsp => "C-GET-RSP",
+            Self::CFindRq => "C-FIND-RQ",
+            Self::CFindRsp => "C-FIND-RSP",
+            Self::CMoveRq => "C-MOVE-RQ",
+            Self::CMoveRsp => "C-MOVE-RSP",
+            Self::CEchoRq => "C-ECHO-RQ",
+            Self::CEchoRsp => "C-ECHO-RSP",
+            Self::NEventReportRq => "N-EVENT-REPORT-RQ",
+            Self::NEventReportRsp => "N-EVENT-REPORT-RSP",
+            Self::NGetRq => "N-GET-RQ",
+            Self::NGetRsp => "N-GET-RSP",
+            Self::NSetRq => "N-SET-RQ",
+            Self::NSetRsp => "N-SET-RSP",
+            Self::NActionRq => "N-ACTION-RQ",
+            Self::NActionRsp => "N-ACTION-RSP",
+            Self::NCreateRq => "N-CREATE-RQ",
+            Self::NCreateRsp => "N-CREATE-RSP",
+            Self::NDeleteRq => "N-DELETE-RQ",
+            Self::NDeleteRsp => "N-DELETE-RSP",
+            Self::CCancelRq => "C-CANCEL-RQ",
+        };
+        write!(f, "{s}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::command_set::Command;
+
+    #[test]
+    fn test_command_field_try_from() {
+        assert_eq!(CommandField::try_from(0x0001), Ok(CommandField::CStoreRq));
+        assert_eq!(CommandField::try_from(0x8140), Ok(CommandField::NCreateRsp));
+        assert_eq!(CommandField::try_from(0x0fff), Ok(CommandField::CCancelRq));
+        assert_eq!(
+            CommandField::try_from(0x0002),
+            Err("Command Fieldが不正です (値=0x0002)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_command_field_is_request() {
+        assert!(CommandField::CFindRq.is_request());
+        assert!(CommandField::CCancelRq.is_request());
+        assert!(CommandField::NEventReportRsp.is_response());
+        assert!(!CommandField::CEchoRsp.is_request());
+    }
+
+    #[test]
+    fn test_command_field_response() {
+        assert_eq!(
+            CommandField::CStoreRq.response(),
+            Some(CommandField::CStoreRsp)
+        );
+        assert_eq!(
+            CommandField::NDeleteRq.response(),
+            Some(CommandField::NDeleteRsp)
+        );
+        assert_eq!(CommandField::CCancelRq.response(), None);
+        assert_eq!(CommandField::CEchoRsp.response(), None);
+    }
+
+    #[test]
+    fn test_command_field_from_command_set() {
+        // 正常系
+        {
+            let command_set = CommandSet::new(vec![
+                Command::new(Tag(0x0000, 0x0100), 0x0030u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0110), 1u16.to_le_bytes().to_vec()),
+            ])
+            .unwrap();
+
+            assert_eq!(
+                CommandField::from_command_set(&command_set),
+                Ok(CommandField::CEchoRq)
+            );
+        }
+
+        // 準正常系: Command Fieldが存在しない
+        {
+            let command_set = CommandSet::new(vec![Command::new(
+                Tag(0x0000, 0x0110),
+                1u16.to_le_bytes().to_vec(),
+            )])
+            .unwrap();
+
+            assert_eq!(
+                CommandField::from_command_set(&command_set),
+                Err("Command Fieldコマンドが存在しません".to_string())
+            );
+        }
+    }
+}
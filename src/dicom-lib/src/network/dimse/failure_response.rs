@@ -0,0 +1,142 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            Status, command_set_builder::CommandSetBuilder, command_set_reader::CommandSetReader,
+            enums::CommandField,
+        },
+    },
+};
+
+/// 要求に対して、ステータスのみを返す失敗応答のコマンドセットを生成する。
+///
+/// 応答のCommand Fieldは要求に対応するものとし、要求のSOPクラスUIDとSOPインスタンスUIDを
+/// `Affected SOP Class UID (0000,0002)`と`Affected SOP Instance UID (0000,1000)`として引き継ぐ。
+/// 応答が存在しないコマンド（応答メッセージやC-CANCEL-RQ）を指定した場合はエラーを返す。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/chapter_C.html>
+pub fn failure_response(request: &CommandSet, status: Status) -> Result<CommandSet, String> {
+    let command_field = CommandField::from_command_set(request)?;
+    let response_command_field = command_field
+        .response()
+        .ok_or_else(|| format!("{command_field}に対応する応答は存在しません"))?;
+
+    let reader = CommandSetReader::new(request);
+    let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+    let sop_class_uid = match reader.uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")? {
+        Some(uid) => Some(uid),
+        None => reader.uid(Tag(0x0000, 0x0003), "Requested SOP Class UID")?,
+    };
+    let sop_instance_uid = match reader.uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")? {
+        Some(uid) => Some(uid),
+        None => reader.uid(Tag(0x0000, 0x1001), "Requested SOP Instance UID")?,
+    };
+
+    let mut builder = CommandSetBuilder::new(response_command_field, false)
+        .us(Tag(0x0000, 0x0120), message_id)
+        .us(Tag(0x0000, 0x0900), status.code());
+    if let Some(uid) = sop_class_uid {
+        builder = builder.uid(Tag(0x0000, 0x0002), &uid);
+    }
+    if let Some(uid) = sop_instance_uid {
+        builder = builder.uid(Tag(0x0000, 0x1000), &uid);
+    }
+
+    Ok(builder.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constants::sop_class_uids::VERIFICATION,
+        network::dimse::{
+            c_echo::{CEchoRq, CEchoRsp, c_echo_rsp},
+            c_find::CFindRq,
+            enums::Priority,
+            n_set::NSetRq,
+        },
+    };
+
+    #[test]
+    fn test_failure_response() {
+        // 正常系: C-ECHO-RQに対するC-ECHO-RSP
+        {
+            // Arrange
+            let request: CommandSet = CEchoRq::new(1).into();
+
+            // Act
+            let actual = failure_response(&request, Status::UNRECOGNIZED_OPERATION).unwrap();
+
+            // Assert
+            assert_eq!(
+                CEchoRsp::try_from(actual),
+                Ok(CEchoRsp::new(1, c_echo_rsp::Status::UnrecognizedOperation))
+            );
+        }
+
+        // 正常系: Requested SOP Class UIDとRequested SOP Instance UIDをAffected *として引き継ぐ
+        {
+            // Arrange
+            let request: CommandSet = NSetRq::new("1.2.840.10008.3.1.2.3.3", 3, "1.2.3.4").into();
+
+            // Act
+            let actual = failure_response(&request, Status::UNRECOGNIZED_OPERATION).unwrap();
+
+            // Assert
+            let reader = CommandSetReader::new(&actual);
+            assert_eq!(
+                CommandField::from_command_set(&actual),
+                Ok(CommandField::NSetRsp)
+            );
+            assert_eq!(
+                reader.us(Tag(0x0000, 0x0120), "Message ID Being Responded To"),
+                Ok(Some(3))
+            );
+            assert_eq!(reader.us(Tag(0x0000, 0x0900), "Status"), Ok(Some(0x0211)));
+            assert_eq!(reader.has_data_set(), Ok(false));
+            assert_eq!(
+                reader.uid(Tag(0x0000, 0x0002), "Affected SOP Class UID"),
+                Ok(Some("1.2.840.10008.3.1.2.3.3".to_string()))
+            );
+            assert_eq!(
+                reader.uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID"),
+                Ok(Some("1.2.3.4".to_string()))
+            );
+        }
+
+        // 正常系: 要求がデータセットを伴っていても応答はデータセットを伴わない
+        {
+            // Arrange
+            let request: CommandSet = CFindRq::new(VERIFICATION, 5, Priority::Medium).into();
+
+            // Act
+            let actual = failure_response(&request, Status::UNRECOGNIZED_OPERATION).unwrap();
+
+            // Assert
+            let reader = CommandSetReader::new(&actual);
+            assert_eq!(
+                CommandField::from_command_set(&actual),
+                Ok(CommandField::CFindRsp)
+            );
+            assert_eq!(reader.has_data_set(), Ok(false));
+        }
+
+        // 準正常系: 応答メッセージには応答が存在しない
+        {
+            // Arrange
+            let request: CommandSet = CEchoRsp::new(1, c_echo_rsp::Status::Success).into();
+
+            // Act
+            let actual = failure_response(&request, Status::UNRECOGNIZED_OPERATION);
+
+            // Assert
+            assert_eq!(
+                actual.err(),
+                Some("C-ECHO-RSPに対応する応答は存在しません".to_string())
+            );
+        }
+    }
+}
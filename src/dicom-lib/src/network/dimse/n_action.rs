@@ -0,0 +1,5 @@
+mod n_action_rq;
+mod n_action_rsp;
+
+pub use n_action_rq::NActionRq;
+pub use n_action_rsp::NActionRsp;
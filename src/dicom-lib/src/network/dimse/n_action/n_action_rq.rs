@@ -0,0 +1,157 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-ACTION-RQ
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.4.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NActionRq {
+    requested_sop_class_uid: String,
+    message_id: u16,
+    requested_sop_instance_uid: String,
+    action_type_id: u16,
+    has_data_set: bool,
+}
+
+impl NActionRq {
+    pub fn requested_sop_class_uid(&self) -> &str {
+        &self.requested_sop_class_uid
+    }
+
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn requested_sop_instance_uid(&self) -> &str {
+        &self.requested_sop_instance_uid
+    }
+
+    pub fn action_type_id(&self) -> u16 {
+        self.action_type_id
+    }
+
+    pub fn new(
+        requested_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        requested_sop_instance_uid: impl Into<String>,
+        action_type_id: u16,
+    ) -> Self {
+        Self {
+            requested_sop_class_uid: requested_sop_class_uid.into(),
+            message_id,
+            requested_sop_instance_uid: requested_sop_instance_uid.into(),
+            action_type_id,
+            has_data_set: false,
+        }
+    }
+
+    /// アクション情報をデータセットとして送信する。
+    pub fn with_data_set(mut self) -> Self {
+        self.has_data_set = true;
+        self
+    }
+}
+
+impl DimseMessage for NActionRq {
+    const COMMAND_FIELD: CommandField = CommandField::NActionRq;
+
+    fn has_data_set(&self) -> bool {
+        self.has_data_set
+    }
+}
+
+impl TryFrom<CommandSet> for NActionRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let requested_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0003), "Requested SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        let has_data_set = reader.has_data_set()?;
+        let requested_sop_instance_uid =
+            reader.required_uid(Tag(0x0000, 0x1001), "Requested SOP Instance UID")?;
+        let action_type_id = reader.required_us(Tag(0x0000, 0x1008), "Action Type ID")?;
+
+        Ok(NActionRq {
+            requested_sop_class_uid,
+            message_id,
+            requested_sop_instance_uid,
+            action_type_id,
+            has_data_set,
+        })
+    }
+}
+
+impl From<NActionRq> for CommandSet {
+    fn from(val: NActionRq) -> Self {
+        CommandSetBuilder::new(NActionRq::COMMAND_FIELD, val.has_data_set)
+            .uid(Tag(0x0000, 0x0003), &val.requested_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .uid(Tag(0x0000, 0x1001), &val.requested_sop_instance_uid)
+            .us(Tag(0x0000, 0x1008), val.action_type_id)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::command_set::Command;
+
+    #[test]
+    fn test_n_action_rq_try_from() {
+        // 正常系
+        {
+            // Arrange
+            let expected = NActionRq::new("1.2.840.10008.1.20.1", 6, "1.2.840.10008.1.20.1.1", 1)
+                .with_data_set();
+            let command_set: CommandSet =
+                NActionRq::new("1.2.840.10008.1.20.1", 6, "1.2.840.10008.1.20.1.1", 1)
+                    .with_data_set()
+                    .into();
+
+            // Act
+            let actual = NActionRq::try_from(command_set).unwrap();
+
+            // Assert
+            assert_eq!(expected, actual);
+        }
+
+        // 準正常系: Action Type IDが存在しない
+        {
+            // Arrange
+            let command_set = CommandSet::new(vec![
+                Command::new(
+                    Tag(0x0000, 0x0003),
+                    "1.2.840.10008.1.20.1\0".as_bytes().to_vec(),
+                ),
+                Command::new(Tag(0x0000, 0x0100), 0x0130u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0110), 6u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0800), 0x0101u16.to_le_bytes().to_vec()),
+                Command::new(
+                    Tag(0x0000, 0x1001),
+                    "1.2.840.10008.1.20.1.1\0".as_bytes().to_vec(),
+                ),
+            ])
+            .unwrap();
+
+            // Act
+            let result = NActionRq::try_from(command_set);
+
+            // Assert
+            assert_eq!(result.unwrap_err(), "Action Type IDコマンドが存在しません");
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, Status, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-ACTION-RSP
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.4.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NActionRsp {
+    message_id: u16,
+    status: Status,
+    affected_sop_class_uid: String,
+    affected_sop_instance_uid: String,
+    action_type_id: Option<u16>,
+    has_data_set: bool,
+}
+
+impl NActionRsp {
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn affected_sop_instance_uid(&self) -> &str {
+        &self.affected_sop_instance_uid
+    }
+
+    pub fn action_type_id(&self) -> Option<u16> {
+        self.action_type_id
+    }
+
+    pub fn new(
+        message_id: u16,
+        status: Status,
+        affected_sop_class_uid: impl Into<String>,
+        affected_sop_instance_uid: impl Into<String>,
+        action_type_id: Option<u16>,
+    ) -> Self {
+        Self {
+            message_id,
+            status,
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            affected_sop_instance_uid: affected_sop_instance_uid.into(),
+            action_type_id,
+            has_data_set: false,
+        }
+    }
+
+    /// アクション応答をデータセットとして送信する。
+    pub fn with_data_set(mut self) -> Self {
+        self.has_data_set = true;
+        self
+    }
+}
+
+impl DimseMessage for NActionRsp {
+    const COMMAND_FIELD: CommandField = CommandField::NActionRsp;
+
+    fn has_data_set(&self) -> bool {
+        self.has_data_set
+    }
+}
+
+impl TryFrom<CommandSet> for NActionRsp {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid = reader
+            .uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?
+            .unwrap_or_default();
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        let has_data_set = reader.has_data_set()?;
+        let status = Status::new(reader.required_us(Tag(0x0000, 0x0900), "Status")?);
+        let affected_sop_instance_uid = reader
+            .uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")?
+            .unwrap_or_default();
+        let action_type_id = reader.us(Tag(0x0000, 0x1008), "Action Type ID")?;
+
+        Ok(NActionRsp {
+            message_id,
+            status,
+            affected_sop_class_uid,
+            affected_sop_instance_uid,
+            action_type_id,
+            has_data_set,
+        })
+    }
+}
+
+impl From<NActionRsp> for CommandSet {
+    fn from(val: NActionRsp) -> Self {
+        let mut builder = CommandSetBuilder::new(NActionRsp::COMMAND_FIELD, val.has_data_set)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0120), val.message_id)
+            .us(Tag(0x0000, 0x0900), val.status.code())
+            .uid(Tag(0x0000, 0x1000), &val.affected_sop_instance_uid);
+        if let Some(action_type_id) = val.action_type_id {
+            builder = builder.us(Tag(0x0000, 0x1008), action_type_id);
+        }
+        builder.build()
+    }
+}
@@ -0,0 +1,5 @@
+mod n_create_rq;
+pub mod n_create_rsp;
+
+pub use n_create_rq::NCreateRq;
+pub use n_create_rsp::NCreateRsp;
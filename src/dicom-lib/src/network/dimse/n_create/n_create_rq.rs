@@ -0,0 +1,163 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-CREATE-RQ
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.5.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NCreateRq {
+    affected_sop_class_uid: String,
+    message_id: u16,
+    affected_sop_instance_uid: Option<String>,
+    has_data_set: bool,
+}
+
+impl NCreateRq {
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    /// SCUがSOPインスタンスUIDを指定しなかった場合は`None`を返す。
+    pub fn affected_sop_instance_uid(&self) -> Option<&str> {
+        self.affected_sop_instance_uid.as_deref()
+    }
+
+    pub fn new(
+        affected_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        affected_sop_instance_uid: Option<String>,
+    ) -> Self {
+        Self {
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            message_id,
+            affected_sop_instance_uid,
+            has_data_set: false,
+        }
+    }
+
+    /// 属性リストをデータセットとして送信する。
+    pub fn with_data_set(mut self) -> Self {
+        self.has_data_set = true;
+        self
+    }
+}
+
+impl DimseMessage for NCreateRq {
+    const COMMAND_FIELD: CommandField = CommandField::NCreateRq;
+
+    fn has_data_set(&self) -> bool {
+        self.has_data_set
+    }
+}
+
+impl TryFrom<CommandSet> for NCreateRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        let has_data_set = reader.has_data_set()?;
+        // N-CREATEではSOPインスタンスUIDの指定は任意（SCP側で割り当てる場合がある）
+        let affected_sop_instance_uid = reader
+            .uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")?
+            .filter(|uid| !uid.is_empty());
+
+        Ok(NCreateRq {
+            affected_sop_class_uid,
+            message_id,
+            affected_sop_instance_uid,
+            has_data_set,
+        })
+    }
+}
+
+impl From<NCreateRq> for CommandSet {
+    fn from(val: NCreateRq) -> Self {
+        let mut builder = CommandSetBuilder::new(NCreateRq::COMMAND_FIELD, val.has_data_set)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id);
+        if let Some(uid) = &val.affected_sop_instance_uid {
+            builder = builder.uid(Tag(0x0000, 0x1000), uid);
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::command_set::Command;
+
+    #[test]
+    fn test_n_create_rq_try_from() {
+        // 正常系: Affected SOP Instance UIDあり
+        {
+            // Arrange
+            let expected = NCreateRq {
+                affected_sop_class_uid: "1.2.840.10008.3.1.2.3.3".to_string(),
+                message_id: 1,
+                affected_sop_instance_uid: Some("1.2.392.200036.9116.2.6.1.48".to_string()),
+                has_data_set: true,
+            };
+            let command_set = CommandSet::new(vec![
+                Command::new(Tag(0x0000, 0x0000), 96u32.to_le_bytes().to_vec()),
+                Command::new(
+                    Tag(0x0000, 0x0002),
+                    "1.2.840.10008.3.1.2.3.3\0".as_bytes().to_vec(),
+                ),
+                Command::new(Tag(0x0000, 0x0100), 0x0140u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0110), 1u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0800), 0x0001u16.to_le_bytes().to_vec()),
+                Command::new(
+                    Tag(0x0000, 0x1000),
+                    "1.2.392.200036.9116.2.6.1.48".as_bytes().to_vec(),
+                ),
+            ])
+            .unwrap();
+
+            // Act
+            let actual = NCreateRq::try_from(command_set).unwrap();
+
+            // Assert
+            assert_eq!(expected, actual);
+        }
+
+        // 準正常系: Command FieldがN-CREATE-RQではない
+        {
+            // Arrange
+            let command_set = CommandSet::new(vec![
+                Command::new(
+                    Tag(0x0000, 0x0002),
+                    "1.2.840.10008.3.1.2.3.3\0".as_bytes().to_vec(),
+                ),
+                Command::new(Tag(0x0000, 0x0100), 0x0120u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0110), 1u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0800), 0x0001u16.to_le_bytes().to_vec()),
+            ])
+            .unwrap();
+
+            // Act
+            let result = NCreateRq::try_from(command_set);
+
+            // Assert
+            assert_eq!(result.unwrap_err(), "Command Fieldが不正です");
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod n_delete_rq;
+mod n_delete_rsp;
+
+pub use n_delete_rq::NDeleteRq;
+pub use n_delete_rsp::NDeleteRsp;
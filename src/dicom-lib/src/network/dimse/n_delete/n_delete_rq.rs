@@ -0,0 +1,89 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-DELETE-RQ
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.6.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NDeleteRq {
+    requested_sop_class_uid: String,
+    message_id: u16,
+    requested_sop_instance_uid: String,
+}
+
+impl NDeleteRq {
+    pub fn requested_sop_class_uid(&self) -> &str {
+        &self.requested_sop_class_uid
+    }
+
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn requested_sop_instance_uid(&self) -> &str {
+        &self.requested_sop_instance_uid
+    }
+
+    pub fn new(
+        requested_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        requested_sop_instance_uid: impl Into<String>,
+    ) -> Self {
+        Self {
+            requested_sop_class_uid: requested_sop_class_uid.into(),
+            message_id,
+            requested_sop_instance_uid: requested_sop_instance_uid.into(),
+        }
+    }
+}
+
+impl DimseMessage for NDeleteRq {
+    const COMMAND_FIELD: CommandField = CommandField::NDeleteRq;
+
+    fn has_data_set(&self) -> bool {
+        false
+    }
+}
+
+impl TryFrom<CommandSet> for NDeleteRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let requested_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0003), "Requested SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        if reader.has_data_set()? {
+            return Err("Command Data Set Typeが不正です".to_string());
+        }
+        let requested_sop_instance_uid =
+            reader.required_uid(Tag(0x0000, 0x1001), "Requested SOP Instance UID")?;
+
+        Ok(NDeleteRq {
+            requested_sop_class_uid,
+            message_id,
+            requested_sop_instance_uid,
+        })
+    }
+}
+
+impl From<NDeleteRq> for CommandSet {
+    fn from(val: NDeleteRq) -> Self {
+        CommandSetBuilder::new(NDeleteRq::COMMAND_FIELD, false)
+            .uid(Tag(0x0000, 0x0003), &val.requested_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .uid(Tag(0x0000, 0x1001), &val.requested_sop_instance_uid)
+            .build()
+    }
+}
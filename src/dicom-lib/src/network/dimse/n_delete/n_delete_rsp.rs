@@ -0,0 +1,99 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, Status, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-DELETE-RSP
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.6.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NDeleteRsp {
+    message_id: u16,
+    status: Status,
+    affected_sop_class_uid: String,
+    affected_sop_instance_uid: String,
+}
+
+impl NDeleteRsp {
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn affected_sop_instance_uid(&self) -> &str {
+        &self.affected_sop_instance_uid
+    }
+
+    pub fn new(
+        message_id: u16,
+        status: Status,
+        affected_sop_class_uid: impl Into<String>,
+        affected_sop_instance_uid: impl Into<String>,
+    ) -> Self {
+        Self {
+            message_id,
+            status,
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            affected_sop_instance_uid: affected_sop_instance_uid.into(),
+        }
+    }
+}
+
+impl DimseMessage for NDeleteRsp {
+    const COMMAND_FIELD: CommandField = CommandField::NDeleteRsp;
+
+    fn has_data_set(&self) -> bool {
+        false
+    }
+}
+
+impl TryFrom<CommandSet> for NDeleteRsp {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid = reader
+            .uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?
+            .unwrap_or_default();
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        let status = Status::new(reader.required_us(Tag(0x0000, 0x0900), "Status")?);
+        let affected_sop_instance_uid = reader
+            .uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")?
+            .unwrap_or_default();
+
+        Ok(NDeleteRsp {
+            message_id,
+            status,
+            affected_sop_class_uid,
+            affected_sop_instance_uid,
+        })
+    }
+}
+
+impl From<NDeleteRsp> for CommandSet {
+    fn from(val: NDeleteRsp) -> Self {
+        CommandSetBuilder::new(NDeleteRsp::COMMAND_FIELD, false)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0120), val.message_id)
+            .us(Tag(0x0000, 0x0900), val.status.code())
+            .uid(Tag(0x0000, 0x1000), &val.affected_sop_instance_uid)
+            .build()
+    }
+}
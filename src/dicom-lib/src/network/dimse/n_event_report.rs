@@ -0,0 +1,5 @@
+mod n_event_report_rq;
+mod n_event_report_rsp;
+
+pub use n_event_report_rq::NEventReportRq;
+pub use n_event_report_rsp::NEventReportRsp;
@@ -0,0 +1,127 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-EVENT-REPORT-RQ
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.html#sect_10.3.1>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NEventReportRq {
+    affected_sop_class_uid: String,
+    message_id: u16,
+    affected_sop_instance_uid: String,
+    event_type_id: u16,
+    has_data_set: bool,
+}
+
+impl NEventReportRq {
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn affected_sop_instance_uid(&self) -> &str {
+        &self.affected_sop_instance_uid
+    }
+
+    pub fn event_type_id(&self) -> u16 {
+        self.event_type_id
+    }
+
+    pub fn new(
+        affected_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        affected_sop_instance_uid: impl Into<String>,
+        event_type_id: u16,
+    ) -> Self {
+        Self {
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            message_id,
+            affected_sop_instance_uid: affected_sop_instance_uid.into(),
+            event_type_id,
+            has_data_set: false,
+        }
+    }
+
+    /// イベント情報をデータセットとして送信する。
+    pub fn with_data_set(mut self) -> Self {
+        self.has_data_set = true;
+        self
+    }
+}
+
+impl DimseMessage for NEventReportRq {
+    const COMMAND_FIELD: CommandField = CommandField::NEventReportRq;
+
+    fn has_data_set(&self) -> bool {
+        self.has_data_set
+    }
+}
+
+impl TryFrom<CommandSet> for NEventReportRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        let has_data_set = reader.has_data_set()?;
+        let affected_sop_instance_uid =
+            reader.required_uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")?;
+        let event_type_id = reader.required_us(Tag(0x0000, 0x1002), "Event Type ID")?;
+
+        Ok(NEventReportRq {
+            affected_sop_class_uid,
+            message_id,
+            affected_sop_instance_uid,
+            event_type_id,
+            has_data_set,
+        })
+    }
+}
+
+impl From<NEventReportRq> for CommandSet {
+    fn from(val: NEventReportRq) -> Self {
+        CommandSetBuilder::new(NEventReportRq::COMMAND_FIELD, val.has_data_set)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .uid(Tag(0x0000, 0x1000), &val.affected_sop_instance_uid)
+            .us(Tag(0x0000, 0x1002), val.event_type_id)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_n_event_report_rq_into_command_set() {
+        // Arrange
+        let expected = NEventReportRq::new("1.2.840.10008.1.20.1", 11, "1.2.840.10008.1.20.1.1", 1)
+            .with_data_set();
+
+        // Act
+        let command_set: CommandSet =
+            NEventReportRq::new("1.2.840.10008.1.20.1", 11, "1.2.840.10008.1.20.1.1", 1)
+                .with_data_set()
+                .into();
+
+        // Assert
+        assert_eq!(NEventReportRq::try_from(command_set).unwrap(), expected);
+    }
+}
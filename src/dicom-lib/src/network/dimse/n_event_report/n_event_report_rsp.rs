@@ -0,0 +1,121 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, Status, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-EVENT-REPORT-RSP
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.html#sect_10.3.1>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NEventReportRsp {
+    message_id: u16,
+    status: Status,
+    affected_sop_class_uid: String,
+    affected_sop_instance_uid: String,
+    event_type_id: Option<u16>,
+    has_data_set: bool,
+}
+
+impl NEventReportRsp {
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn affected_sop_instance_uid(&self) -> &str {
+        &self.affected_sop_instance_uid
+    }
+
+    pub fn event_type_id(&self) -> Option<u16> {
+        self.event_type_id
+    }
+
+    pub fn new(
+        message_id: u16,
+        status: Status,
+        affected_sop_class_uid: impl Into<String>,
+        affected_sop_instance_uid: impl Into<String>,
+        event_type_id: Option<u16>,
+    ) -> Self {
+        Self {
+            message_id,
+            status,
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            affected_sop_instance_uid: affected_sop_instance_uid.into(),
+            event_type_id,
+            has_data_set: false,
+        }
+    }
+
+    /// イベント応答をデータセットとして送信する。
+    pub fn with_data_set(mut self) -> Self {
+        self.has_data_set = true;
+        self
+    }
+}
+
+impl DimseMessage for NEventReportRsp {
+    const COMMAND_FIELD: CommandField = CommandField::NEventReportRsp;
+
+    fn has_data_set(&self) -> bool {
+        self.has_data_set
+    }
+}
+
+impl TryFrom<CommandSet> for NEventReportRsp {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid = reader
+            .uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?
+            .unwrap_or_default();
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        let has_data_set = reader.has_data_set()?;
+        let status = Status::new(reader.required_us(Tag(0x0000, 0x0900), "Status")?);
+        let affected_sop_instance_uid = reader
+            .uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")?
+            .unwrap_or_default();
+        let event_type_id = reader.us(Tag(0x0000, 0x1002), "Event Type ID")?;
+
+        Ok(NEventReportRsp {
+            message_id,
+            status,
+            affected_sop_class_uid,
+            affected_sop_instance_uid,
+            event_type_id,
+            has_data_set,
+        })
+    }
+}
+
+impl From<NEventReportRsp> for CommandSet {
+    fn from(val: NEventReportRsp) -> Self {
+        let mut builder = CommandSetBuilder::new(NEventReportRsp::COMMAND_FIELD, val.has_data_set)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0120), val.message_id)
+            .us(Tag(0x0000, 0x0900), val.status.code())
+            .uid(Tag(0x0000, 0x1000), &val.affected_sop_instance_uid);
+        if let Some(event_type_id) = val.event_type_id {
+            builder = builder.us(Tag(0x0000, 0x1002), event_type_id);
+        }
+        builder.build()
+    }
+}
@@ -0,0 +1,5 @@
+mod n_get_rq;
+mod n_get_rsp;
+
+pub use n_get_rq::NGetRq;
+pub use n_get_rsp::NGetRsp;
@@ -0,0 +1,141 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-GET-RQ
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.2.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NGetRq {
+    requested_sop_class_uid: String,
+    message_id: u16,
+    requested_sop_instance_uid: String,
+    attribute_identifier_list: Vec<Tag>,
+}
+
+impl NGetRq {
+    pub fn requested_sop_class_uid(&self) -> &str {
+        &self.requested_sop_class_uid
+    }
+
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn requested_sop_instance_uid(&self) -> &str {
+        &self.requested_sop_instance_uid
+    }
+
+    /// 取得する属性のタグのリスト。空の場合は全ての属性を取得する。
+    pub fn attribute_identifier_list(&self) -> &[Tag] {
+        &self.attribute_identifier_list
+    }
+
+    pub fn new(
+        requested_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        requested_sop_instance_uid: impl Into<String>,
+        attribute_identifier_list: Vec<Tag>,
+    ) -> Self {
+        Self {
+            requested_sop_class_uid: requested_sop_class_uid.into(),
+            message_id,
+            requested_sop_instance_uid: requested_sop_instance_uid.into(),
+            attribute_identifier_list,
+        }
+    }
+}
+
+impl DimseMessage for NGetRq {
+    const COMMAND_FIELD: CommandField = CommandField::NGetRq;
+
+    fn has_data_set(&self) -> bool {
+        false
+    }
+}
+
+impl TryFrom<CommandSet> for NGetRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let requested_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0003), "Requested SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        if reader.has_data_set()? {
+            return Err("Command Data Set Typeが不正です".to_string());
+        }
+        let requested_sop_instance_uid =
+            reader.required_uid(Tag(0x0000, 0x1001), "Requested SOP Instance UID")?;
+        let attribute_identifier_list = reader
+            .at(Tag(0x0000, 0x1005), "Attribute Identifier List")?
+            .unwrap_or_default();
+
+        Ok(NGetRq {
+            requested_sop_class_uid,
+            message_id,
+            requested_sop_instance_uid,
+            attribute_identifier_list,
+        })
+    }
+}
+
+impl From<NGetRq> for CommandSet {
+    fn from(val: NGetRq) -> Self {
+        let mut builder = CommandSetBuilder::new(NGetRq::COMMAND_FIELD, false)
+            .uid(Tag(0x0000, 0x0003), &val.requested_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .uid(Tag(0x0000, 0x1001), &val.requested_sop_instance_uid);
+        if !val.attribute_identifier_list.is_empty() {
+            builder = builder.at(Tag(0x0000, 0x1005), &val.attribute_identifier_list);
+        }
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_n_get_rq_into_command_set() {
+        // Arrange
+        let expected = NGetRq::new(
+            "1.2.840.10008.5.1.1.16",
+            2,
+            "1.2.840.10008.5.1.1.17",
+            vec![Tag(0x2110, 0x0010), Tag(0x2110, 0x0020)],
+        );
+
+        // Act
+        let command_set: CommandSet = NGetRq::new(
+            "1.2.840.10008.5.1.1.16",
+            2,
+            "1.2.840.10008.5.1.1.17",
+            vec![Tag(0x2110, 0x0010), Tag(0x2110, 0x0020)],
+        )
+        .into();
+
+        // Assert
+        // Attribute Identifier Listは (グループ, 要素) のリトルエンディアンで並ぶ
+        let attribute_identifier_list = command_set
+            .iter()
+            .find(|c| c.tag() == Tag(0x0000, 0x1005))
+            .unwrap();
+        assert_eq!(
+            attribute_identifier_list.value_field(),
+            &[0x10, 0x21, 0x10, 0x00, 0x10, 0x21, 0x20, 0x00]
+        );
+        assert_eq!(NGetRq::try_from(command_set).unwrap(), expected);
+    }
+}
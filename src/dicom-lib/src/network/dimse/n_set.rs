@@ -0,0 +1,5 @@
+mod n_set_rq;
+pub mod n_set_rsp;
+
+pub use n_set_rq::NSetRq;
+pub use n_set_rsp::NSetRsp;
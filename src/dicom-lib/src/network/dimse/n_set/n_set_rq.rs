@@ -0,0 +1,155 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-SET-RQ
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.3.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NSetRq {
+    requested_sop_class_uid: String,
+    message_id: u16,
+    requested_sop_instance_uid: String,
+}
+
+impl NSetRq {
+    pub fn requested_sop_class_uid(&self) -> &str {
+        &self.requested_sop_class_uid
+    }
+
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn requested_sop_instance_uid(&self) -> &str {
+        &self.requested_sop_instance_uid
+    }
+
+    pub fn new(
+        requested_sop_class_uid: impl Into<String>,
+        message_id: u16,
+        requested_sop_instance_uid: impl Into<String>,
+    ) -> Self {
+        Self {
+            requested_sop_class_uid: requested_sop_class_uid.into(),
+            message_id,
+            requested_sop_instance_uid: requested_sop_instance_uid.into(),
+        }
+    }
+}
+
+impl DimseMessage for NSetRq {
+    const COMMAND_FIELD: CommandField = CommandField::NSetRq;
+
+    fn has_data_set(&self) -> bool {
+        true
+    }
+}
+
+impl TryFrom<CommandSet> for NSetRq {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let requested_sop_class_uid =
+            reader.required_uid(Tag(0x0000, 0x0003), "Requested SOP Class UID")?;
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id = reader.required_us(Tag(0x0000, 0x0110), "Message ID")?;
+        if !reader.has_data_set()? {
+            // N-SETでは変更する属性のリストが必須
+            return Err("Command Data Set Typeが不正です".to_string());
+        }
+        let requested_sop_instance_uid =
+            reader.required_uid(Tag(0x0000, 0x1001), "Requested SOP Instance UID")?;
+
+        Ok(NSetRq {
+            requested_sop_class_uid,
+            message_id,
+            requested_sop_instance_uid,
+        })
+    }
+}
+
+impl From<NSetRq> for CommandSet {
+    fn from(val: NSetRq) -> Self {
+        CommandSetBuilder::new(NSetRq::COMMAND_FIELD, true)
+            .uid(Tag(0x0000, 0x0003), &val.requested_sop_class_uid)
+            .us(Tag(0x0000, 0x0110), val.message_id)
+            .uid(Tag(0x0000, 0x1001), &val.requested_sop_instance_uid)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::command_set::Command;
+
+    #[test]
+    fn test_n_set_rq_try_from() {
+        // 正常系
+        {
+            // Arrange
+            let expected = NSetRq {
+                requested_sop_class_uid: "1.2.840.10008.3.1.2.3.3".to_string(),
+                message_id: 2,
+                requested_sop_instance_uid: "1.2.392.200036.9116.2.6.1.48".to_string(),
+            };
+            let command_set = CommandSet::new(vec![
+                Command::new(Tag(0x0000, 0x0000), 96u32.to_le_bytes().to_vec()),
+                Command::new(
+                    Tag(0x0000, 0x0003),
+                    "1.2.840.10008.3.1.2.3.3\0".as_bytes().to_vec(),
+                ),
+                Command::new(Tag(0x0000, 0x0100), 0x0120u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0110), 2u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0800), 0x0001u16.to_le_bytes().to_vec()),
+                Command::new(
+                    Tag(0x0000, 0x1001),
+                    "1.2.392.200036.9116.2.6.1.48".as_bytes().to_vec(),
+                ),
+            ])
+            .unwrap();
+
+            // Act
+            let actual = NSetRq::try_from(command_set).unwrap();
+
+            // Assert
+            assert_eq!(expected, actual);
+        }
+
+        // 準正常系: データセットが存在しない
+        {
+            // Arrange
+            let command_set = CommandSet::new(vec![
+                Command::new(
+                    Tag(0x0000, 0x0003),
+                    "1.2.840.10008.3.1.2.3.3\0".as_bytes().to_vec(),
+                ),
+                Command::new(Tag(0x0000, 0x0100), 0x0120u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0110), 2u16.to_le_bytes().to_vec()),
+                Command::new(Tag(0x0000, 0x0800), 0x0101u16.to_le_bytes().to_vec()),
+                Command::new(
+                    Tag(0x0000, 0x1001),
+                    "1.2.392.200036.9116.2.6.1.48".as_bytes().to_vec(),
+                ),
+            ])
+            .unwrap();
+
+            // Act
+            let result = NSetRq::try_from(command_set);
+
+            // Assert
+            assert_eq!(result.unwrap_err(), "Command Data Set Typeが不正です");
+        }
+    }
+}
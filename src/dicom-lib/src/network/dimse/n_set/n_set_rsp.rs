@@ -0,0 +1,184 @@
+use crate::{
+    core::Tag,
+    network::{
+        CommandSet,
+        dimse::{
+            DimseMessage, command_set_builder::CommandSetBuilder,
+            command_set_reader::CommandSetReader, enums::CommandField,
+        },
+    },
+};
+
+/// N-SETのステータスコード
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.1.3.html#sect_10.1.3.1.9>
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/chapter_C.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// 成功 ... SOPインスタンスが正常に更新されたことを示す
+    Success = 0x0000,
+    /// 属性リストエラー ... SOPインスタンスは更新されたが、一部の属性が処理されなかったことを示す
+    AttributeListError = 0x0107,
+    /// 属性値が範囲外 ... SOPインスタンスは更新されたが、一部の属性値が範囲外であったことを示す
+    AttributeValueOutOfRange = 0x0116,
+    /// 属性が存在しない ... SOPクラスで定義されていない属性が指定されたことを示す
+    NoSuchAttribute = 0x0105,
+    /// 属性値が不正 ... 指定された属性値が不正であることを示す
+    InvalidAttributeValue = 0x0106,
+    /// 処理失敗 ... 操作の処理中に一般的な失敗が発生したことを示す
+    ProcessingFailure = 0x0110,
+    /// SOPインスタンスが存在しない ... 指定されたSOPインスタンスが存在しないことを示す
+    NoSuchObjectInstance = 0x0112,
+    /// 不正なオブジェクトインスタンス ... 指定されたSOPインスタンスUIDがUID構築ルールに違反していることを示す
+    InvalidObjectInstance = 0x0117,
+    /// SOPクラスが存在しない ... 指定されたSOPクラスが認識されないことを示す
+    NoSuchSopClass = 0x0118,
+    /// クラスとインスタンスの不整合 ... 指定されたSOPインスタンスが指定されたSOPクラスのメンバーではないことを示す
+    ClassInstanceConflict = 0x0119,
+    /// 必須属性値の欠落 ... 必須の属性値が指定されなかったことを示す
+    MissingAttributeValue = 0x0121,
+    /// 拒否：認証されていない ... ピアDIMSEサービスユーザーが操作を許可されていないことを示す
+    NotAuthorized = 0x0124,
+    /// 重複呼び出し ... 指定されたメッセージIDが別の通知もしくは操作に割り当てられていることを示す
+    DuplicateInvocation = 0x0210,
+    /// 認識されていない操作 ... DIMSEサービスユーザー間で合意された操作のいずれでもないことを示す
+    UnrecognizedOperation = 0x0211,
+    /// 引数の型が不正 ... 指定されたパラメータの1つが、DIMSEサービスユーザ間のアソシエーションでの使用が合意されていないことを示す
+    MistypedArgument = 0x0212,
+    /// リソース制限 ... リソースの制限により操作が実行されなかったことを示す
+    ResourceLimitation = 0x0213,
+}
+
+impl TryFrom<u16> for Status {
+    type Error = String;
+
+    fn try_from(val: u16) -> Result<Self, Self::Error> {
+        match val {
+            0x0000 => Ok(Status::Success),
+            0x0107 => Ok(Status::AttributeListError),
+            0x0116 => Ok(Status::AttributeValueOutOfRange),
+            0x0105 => Ok(Status::NoSuchAttribute),
+            0x0106 => Ok(Status::InvalidAttributeValue),
+            0x0110 => Ok(Status::ProcessingFailure),
+            0x0112 => Ok(Status::NoSuchObjectInstance),
+            0x0117 => Ok(Status::InvalidObjectInstance),
+            0x0118 => Ok(Status::NoSuchSopClass),
+            0x0119 => Ok(Status::ClassInstanceConflict),
+            0x0121 => Ok(Status::MissingAttributeValue),
+            0x0124 => Ok(Status::NotAuthorized),
+            0x0210 => Ok(Status::DuplicateInvocation),
+            0x0211 => Ok(Status::UnrecognizedOperation),
+            0x0212 => Ok(Status::MistypedArgument),
+            0x0213 => Ok(Status::ResourceLimitation),
+            _ => Err(format!(
+                "N-SETで定義されていないステータスコードです (コード={val:#06X})"
+            )),
+        }
+    }
+}
+
+impl From<Status> for crate::network::dimse::Status {
+    fn from(val: Status) -> Self {
+        Self::new(val as u16)
+    }
+}
+
+/// N-SET-RSP
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/sect_10.3.3.html>
+#[derive(Debug, PartialEq, Eq)]
+pub struct NSetRsp {
+    message_id: u16,
+    status: Status,
+    affected_sop_class_uid: String,
+    affected_sop_instance_uid: String,
+    has_data_set: bool,
+}
+
+impl NSetRsp {
+    pub fn message_id(&self) -> u16 {
+        self.message_id
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    pub fn affected_sop_class_uid(&self) -> &str {
+        &self.affected_sop_class_uid
+    }
+
+    pub fn affected_sop_instance_uid(&self) -> &str {
+        &self.affected_sop_instance_uid
+    }
+
+    pub fn new(
+        message_id: u16,
+        status: Status,
+        affected_sop_class_uid: impl Into<String>,
+        affected_sop_instance_uid: impl Into<String>,
+    ) -> Self {
+        Self {
+            message_id,
+            status,
+            affected_sop_class_uid: affected_sop_class_uid.into(),
+            affected_sop_instance_uid: affected_sop_instance_uid.into(),
+            has_data_set: false,
+        }
+    }
+
+    /// 属性リストをデータセットとして送信する。
+    pub fn with_data_set(mut self) -> Self {
+        self.has_data_set = true;
+        self
+    }
+}
+
+impl DimseMessage for NSetRsp {
+    const COMMAND_FIELD: CommandField = CommandField::NSetRsp;
+
+    fn has_data_set(&self) -> bool {
+        self.has_data_set
+    }
+}
+
+impl TryFrom<CommandSet> for NSetRsp {
+    type Error = String;
+
+    fn try_from(val: CommandSet) -> Result<Self, Self::Error> {
+        let reader = CommandSetReader::new(&val);
+
+        let affected_sop_class_uid = reader
+            .uid(Tag(0x0000, 0x0002), "Affected SOP Class UID")?
+            .unwrap_or_default();
+        reader.expect_command_field(Self::COMMAND_FIELD)?;
+        let message_id =
+            reader.required_us(Tag(0x0000, 0x0120), "Message ID Being Responded To")?;
+        let has_data_set = reader.has_data_set()?;
+        let status = Status::try_from(reader.required_us(Tag(0x0000, 0x0900), "Status")?)?;
+        let affected_sop_instance_uid = reader
+            .uid(Tag(0x0000, 0x1000), "Affected SOP Instance UID")?
+            .unwrap_or_default();
+
+        Ok(NSetRsp {
+            message_id,
+            status,
+            affected_sop_class_uid,
+            affected_sop_instance_uid,
+            has_data_set,
+        })
+    }
+}
+
+impl From<NSetRsp> for CommandSet {
+    fn from(val: NSetRsp) -> Self {
+        CommandSetBuilder::new(NSetRsp::COMMAND_FIELD, val.has_data_set)
+            .uid(Tag(0x0000, 0x0002), &val.affected_sop_class_uid)
+            .us(Tag(0x0000, 0x0120), val.message_id)
+            .us(Tag(0x0000, 0x0900), val.status as u16)
+            .uid(Tag(0x0000, 0x1000), &val.affected_sop_instance_uid)
+            .build()
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::{Display, Formatter};
+
+/// ステータスの種別
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/chapter_C.html>
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StatusType {
+    /// 成功 (0000)
+    Success,
+    /// 警告 (0001 or Bxxx or 0107 or 0116)
+    Warning,
+    /// 失敗 (Axxx or Cxxx or 01xx (0107と0116を除く) or 02xx)
+    Failure,
+    /// キャンセル (FE00)
+    Cancel,
+    /// 保留中 (FF00 or FF01)
+    Pending,
+}
+
+/// 全てのDIMSEサービスで共通のステータス
+///
+/// サービスクラスごとに意味が異なるステータスコードも扱えるよう、値はステータスコードそのものを保持する。
+/// 一般的なステータスコードは関連定数として定義している。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part07/chapter_C.html>
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Status(u16);
+
+impl Status {
+    /// 成功
+    pub const SUCCESS: Status = Status(0x0000);
+    /// 警告: 属性リストエラー
+    pub const ATTRIBUTE_LIST_ERROR: Status = Status(0x0107);
+    /// 警告: 属性値が範囲外
+    pub const ATTRIBUTE_VALUE_OUT_OF_RANGE: Status = Status(0x0116);
+    /// 失敗: 属性が存在しない
+    pub const NO_SUCH_ATTRIBUTE: Status = Status(0x0105);
+    /// 失敗: 属性値が不正
+    pub const INVALID_ATTRIBUTE_VALUE: Status = Status(0x0106);
+    /// 失敗: 処理失敗
+    pub const PROCESSING_FAILURE: Status = Status(0x0110);
+    /// 失敗: SOPインスタンスの重複
+    pub const DUPLICATE_SOP_INSTANCE: Status = Status(0x0111);
+    /// 失敗: SOPインスタンスが存在しない
+    pub const NO_SUCH_OBJECT_INSTANCE: Status = Status(0x0112);
+    /// 失敗: イベントタイプが存在しない
+    pub const NO_SUCH_EVENT_TYPE: Status = Status(0x0113);
+    /// 失敗: 引数が存在しない
+    pub const NO_SUCH_ARGUMENT: Status = Status(0x0114);
+    /// 失敗: 引数の値が不正
+    pub const INVALID_ARGUMENT_VALUE: Status = Status(0x0115);
+    /// 失敗: 不正なオブジェクトインスタンス
+    pub const INVALID_OBJECT_INSTANCE: Status = Status(0x0117);
+    /// 失敗: SOPクラスが存在しない
+    pub const NO_SUCH_SOP_CLASS: Status = Status(0x0118);
+    /// 失敗: クラスとインスタンスの不整合
+    pub const CLASS_INSTANCE_CONFLICT: Status = Status(0x0119);
+    /// 失敗: 必須属性の欠落
+    pub const MISSING_ATTRIBUTE: Status = Status(0x0120);
+    /// 失敗: 必須属性値の欠落
+    pub const MISSING_ATTRIBUTE_VALUE: Status = Status(0x0121);
+    /// 拒否: 未対応のSOPクラス
+    pub const SOP_CLASS_NOT_SUPPORTED: Status = Status(0x0122);
+    /// 失敗: アクションタイプが存在しない
+    pub const NO_SUCH_ACTION_TYPE: Status = Status(0x0123);
+    /// 拒否: 認証されていない
+    pub const NOT_AUTHORIZED: Status = Status(0x0124);
+    /// 失敗: 重複呼び出し
+    pub const DUPLICATE_INVOCATION: Status = Status(0x0210);
+    /// 失敗: 認識されていない操作
+    pub const UNRECOGNIZED_OPERATION: Status = Status(0x0211);
+    /// 失敗: 引数の型が不正
+    pub const MISTYPED_ARGUMENT: Status = Status(0x0212);
+    /// 失敗: リソース制限
+    pub const RESOURCE_LIMITATION: Status = Status(0x0213);
+    /// キャンセル
+    pub const CANCEL: Status = Status(0xfe00);
+    /// 保留中
+    pub const PENDING: Status = Status(0xff00);
+    /// 保留中: 一部のオプションキーがサポートされていない (C-FIND)
+    pub const PENDING_WITH_WARNING: Status = Status(0xff01);
+
+    pub fn new(code: u16) -> Self {
+        Self(code)
+    }
+
+    pub fn code(&self) -> u16 {
+        self.0
+    }
+
+    pub fn status_type(&self) -> StatusType {
+        match self.0 {
+            0x0000 => StatusType::Success,
+            0x0001 | 0x0107 | 0x0116 | 0xb000..=0xbfff => StatusType::Warning,
+            0xfe00 => StatusType::Cancel,
+            0xff00 | 0xff01 => StatusType::Pending,
+            // 上記以外はサービスクラス固有のものも含めて失敗として扱う
+            _ => StatusType::Failure,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.status_type() == StatusType::Success
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.status_type() == StatusType::Warning
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.status_type() == StatusType::Failure
+    }
+
+    pub fn is_cancel(&self) -> bool {
+        self.status_type() == StatusType::Cancel
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status_type() == StatusType::Pending
+    }
+}
+
+impl From<u16> for Status {
+    fn from(code: u16) -> Self {
+        Self(code)
+    }
+}
+
+impl From<Status> for u16 {
+    fn from(val: Status) -> Self {
+        val.0
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#06X}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_type() {
+        assert_eq!(Status::SUCCESS.status_type(), StatusType::Success);
+        assert_eq!(Status::new(0x0001).status_type(), StatusType::Warning);
+        assert_eq!(Status::new(0xb007).status_type(), StatusType::Warning);
+        assert_eq!(
+            Status::ATTRIBUTE_LIST_ERROR.status_type(),
+            StatusType::Warning
+        );
+        assert_eq!(Status::new(0xa700).status_type(), StatusType::Failure);
+        assert_eq!(Status::new(0xc001).status_type(), StatusType::Failure);
+        assert_eq!(
+            Status::NO_SUCH_OBJECT_INSTANCE.status_type(),
+            StatusType::Failure
+        );
+        assert_eq!(Status::CANCEL.status_type(), StatusType::Cancel);
+        assert_eq!(Status::PENDING.status_type(), StatusType::Pending);
+        assert_eq!(
+            Status::PENDING_WITH_WARNING.status_type(),
+            StatusType::Pending
+        );
+    }
+}
@@ -0,0 +1,355 @@
+use crate::core::{DataSet, Tag};
+use thiserror::Error;
+
+const SAMPLES_PER_PIXEL: Tag = Tag(0x0028, 0x0002);
+const NUMBER_OF_FRAMES: Tag = Tag(0x0028, 0x0008);
+const ROWS: Tag = Tag(0x0028, 0x0010);
+const COLUMNS: Tag = Tag(0x0028, 0x0011);
+const BITS_ALLOCATED: Tag = Tag(0x0028, 0x0100);
+const PIXEL_DATA: Tag = Tag(0x7fe0, 0x0010);
+const ITEM: Tag = Tag(0xfffe, 0xe000);
+
+/// 値長さが不定であることを表す値
+const UNDEFINED_LENGTH: u32 = 0xffffffff;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PixelDataError {
+    #[error("Pixel Data (7FE0,0010) が存在しません")]
+    NotFound,
+
+    #[error("画像の属性 {0} が存在しないか、値が不正です")]
+    InvalidAttribute(&'static str),
+
+    #[error("Bits Allocated (0028,0100) が{0}のピクセルデータはフレームに分割できません")]
+    UnsupportedBitsAllocated(u16),
+
+    #[error("ピクセルデータの長さが不足しています (必要な長さ={expected}, 実際の長さ={actual})")]
+    InsufficientLength { expected: u64, actual: u64 },
+
+    #[error("カプセル化されたピクセルデータのフレームの境界を特定できません")]
+    UnknownFrameBoundary,
+}
+
+/// フレームごとに分割したピクセルデータ
+///
+/// ピクセルデータは転送構文の符号化のまま扱い、圧縮されたピクセルデータの復号は行わない。
+pub struct PixelData {
+    encapsulated: bool,
+    frames: Vec<Vec<u8>>,
+}
+
+impl PixelData {
+    /// データセットのPixel Data (7FE0,0010) をフレームごとに分割する。
+    ///
+    /// カプセル化されたピクセルデータは、フレームが1つの場合はすべてのフラグメントを連結し、
+    /// 複数の場合はBasic Offset Tableに従って（空の場合はフラグメントとフレームが1対1に対応するものとして）分割する。
+    pub fn from_data_set(data_set: &DataSet) -> Result<Self, PixelDataError> {
+        let index = data_set
+            .find_index(PIXEL_DATA)
+            .ok_or(PixelDataError::NotFound)?;
+        let number_of_frames = match data_set.find_index(NUMBER_OF_FRAMES) {
+            Some(i) => String::from_utf8_lossy(data_set[i].value_field())
+                .trim_matches(['\0', ' '])
+                .parse::<usize>()
+                .ok()
+                .filter(|&n| n > 0)
+                .ok_or(PixelDataError::InvalidAttribute(
+                    "Number of Frames (0028,0008)",
+                ))?,
+            None => 1,
+        };
+
+        if data_set[index].value_length() == UNDEFINED_LENGTH {
+            let items = (index + 1..=index + data_set.get_descendants_count(index))
+                .filter(|&i| data_set[i].tag() == ITEM)
+                .map(|i| data_set[i].value_field())
+                .collect::<Vec<_>>();
+            let frames = split_fragments(&items, number_of_frames)?;
+            return Ok(Self {
+                encapsulated: true,
+                frames,
+            });
+        }
+
+        let rows = read_us(data_set, ROWS, "Rows (0028,0010)")?;
+        let columns = read_us(data_set, COLUMNS, "Columns (0028,0011)")?;
+        let samples_per_pixel =
+            read_us(data_set, SAMPLES_PER_PIXEL, "Samples per Pixel (0028,0002)")?;
+        let bits_allocated = read_us(data_set, BITS_ALLOCATED, "Bits Allocated (0028,0100)")?;
+        if bits_allocated == 0 || !bits_allocated.is_multiple_of(8) {
+            // 1ビットのピクセルデータはフレームの境界がバイトの境界と一致しない場合がある
+            return Err(PixelDataError::UnsupportedBitsAllocated(bits_allocated));
+        }
+
+        let frame_length = rows as usize
+            * columns as usize
+            * samples_per_pixel as usize
+            * (bits_allocated / 8) as usize;
+        let value_field = data_set[index].value_field();
+        if value_field.len() < frame_length * number_of_frames {
+            return Err(PixelDataError::InsufficientLength {
+                expected: (frame_length * number_of_frames) as u64,
+                actual: value_field.len() as u64,
+            });
+        }
+
+        Ok(Self {
+            encapsulated: false,
+            frames: value_field
+                .chunks(frame_length.max(1))
+                .take(number_of_frames)
+                .map(<[u8]>::to_vec)
+                .collect(),
+        })
+    }
+
+    /// カプセル化された（圧縮された）ピクセルデータであるかを返す。
+    pub fn is_encapsulated(&self) -> bool {
+        self.encapsulated
+    }
+
+    pub fn number_of_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// 1から始まるフレーム番号を指定してフレームを返す。
+    pub fn frame(&self, number: usize) -> Option<&[u8]> {
+        number
+            .checked_sub(1)
+            .and_then(|i| self.frames.get(i))
+            .map(Vec::as_slice)
+    }
+}
+
+/// 値表現がUSである最上位のデータ要素の値を返す。
+fn read_us(data_set: &DataSet, tag: Tag, name: &'static str) -> Result<u16, PixelDataError> {
+    data_set
+        .find_index(tag)
+        .and_then(|i| data_set[i].value_field().get(..2))
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or(PixelDataError::InvalidAttribute(name))
+}
+
+/// カプセル化されたピクセルデータのアイテム（先頭はBasic Offset Table）をフレームごとに分割する。
+fn split_fragments(
+    items: &[&[u8]],
+    number_of_frames: usize,
+) -> Result<Vec<Vec<u8>>, PixelDataError> {
+    let Some((offset_table, fragments)) = items.split_first() else {
+        return Err(PixelDataError::UnknownFrameBoundary);
+    };
+
+    if number_of_frames == 1 {
+        return Ok(vec![fragments.concat()]);
+    }
+
+    if offset_table.is_empty() {
+        if fragments.len() != number_of_frames {
+            return Err(PixelDataError::UnknownFrameBoundary);
+        }
+        return Ok(fragments.iter().map(|f| f.to_vec()).collect());
+    }
+
+    // Basic Offset Tableの値は、最初のフラグメントのアイテムタグを起点とした各フレームの先頭のアイテムタグの位置を表す
+    let offsets = offset_table
+        .chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as u64)
+        .collect::<Vec<_>>();
+    if offsets.len() != number_of_frames || offsets.first() != Some(&0) {
+        return Err(PixelDataError::UnknownFrameBoundary);
+    }
+
+    let mut frames: Vec<Vec<u8>> = Vec::with_capacity(number_of_frames);
+    let mut position = 0;
+    for fragment in fragments {
+        if offsets.get(frames.len()) == Some(&position) {
+            frames.push(Vec::new());
+        }
+        match frames.last_mut() {
+            Some(frame) => frame.extend_from_slice(fragment),
+            None => return Err(PixelDataError::UnknownFrameBoundary),
+        }
+        position += 8 + fragment.len() as u64; // Item Tag + Item Length + フラグメント
+    }
+    if frames.len() != number_of_frames {
+        return Err(PixelDataError::UnknownFrameBoundary);
+    }
+
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Encoding, data_element::Vr};
+    use std::io::Cursor;
+
+    /// 明示的VRリトルエンディアンのデータ要素のバイト列を返す。
+    fn element(tag: Tag, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&tag.group().to_le_bytes());
+        buf.extend_from_slice(&tag.element().to_le_bytes());
+        buf.extend_from_slice(vr);
+        if matches!(vr, b"OB" | b"OW") {
+            buf.extend_from_slice(&[0, 0]);
+            buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        } else {
+            buf.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        }
+        buf.extend_from_slice(value);
+        buf
+    }
+
+    /// カプセル化されたPixel Dataのバイト列を返す。
+    fn encapsulated_pixel_data(offset_table: &[u32], fragments: &[&[u8]]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(&[0xe0, 0x7f, 0x10, 0x00]);
+        buf.extend_from_slice(b"OB\0\0");
+        buf.extend_from_slice(&UNDEFINED_LENGTH.to_le_bytes());
+        let offset_table = offset_table
+            .iter()
+            .flat_map(|o| o.to_le_bytes())
+            .collect::<Vec<_>>();
+        for item in std::iter::once(offset_table.as_slice()).chain(fragments.iter().copied()) {
+            buf.extend_from_slice(&[0xfe, 0xff, 0x00, 0xe0]);
+            buf.extend_from_slice(&(item.len() as u32).to_le_bytes());
+            buf.extend_from_slice(item);
+        }
+        buf.extend_from_slice(&[0xfe, 0xff, 0xdd, 0xe0, 0, 0, 0, 0]);
+        buf
+    }
+
+    fn read(buf: &[u8]) -> DataSet {
+        let mut cur = Cursor::new(buf);
+        DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian).unwrap()
+    }
+
+    #[test]
+    fn test_native_frames() {
+        // Arrange
+        // 2行×2列、16ビット、3フレーム
+        let pixels = (0..24).collect::<Vec<u8>>();
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        data_set.set_element(SAMPLES_PER_PIXEL, Vr::Us, 1u16.to_le_bytes().to_vec());
+        data_set.set_element(NUMBER_OF_FRAMES, Vr::Is, b"3 ".to_vec());
+        data_set.set_element(ROWS, Vr::Us, 2u16.to_le_bytes().to_vec());
+        data_set.set_element(COLUMNS, Vr::Us, 2u16.to_le_bytes().to_vec());
+        data_set.set_element(BITS_ALLOCATED, Vr::Us, 16u16.to_le_bytes().to_vec());
+        data_set.set_element(PIXEL_DATA, Vr::Ow, pixels.clone());
+
+        // Act
+        let actual = PixelData::from_data_set(&data_set).unwrap();
+
+        // Assert
+        assert!(!actual.is_encapsulated());
+        assert_eq!(actual.number_of_frames(), 3);
+        assert_eq!(actual.frame(1), Some(&pixels[0..8]));
+        assert_eq!(actual.frame(3), Some(&pixels[16..24]));
+        assert_eq!(actual.frame(0), None);
+        assert_eq!(actual.frame(4), None);
+    }
+
+    #[test]
+    fn test_native_frames_insufficient_length() {
+        // Arrange
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        data_set.set_element(SAMPLES_PER_PIXEL, Vr::Us, 1u16.to_le_bytes().to_vec());
+        data_set.set_element(NUMBER_OF_FRAMES, Vr::Is, b"2 ".to_vec());
+        data_set.set_element(ROWS, Vr::Us, 2u16.to_le_bytes().to_vec());
+        data_set.set_element(COLUMNS, Vr::Us, 2u16.to_le_bytes().to_vec());
+        data_set.set_element(BITS_ALLOCATED, Vr::Us, 8u16.to_le_bytes().to_vec());
+        data_set.set_element(PIXEL_DATA, Vr::Ob, vec![0; 6]);
+
+        // Act & Assert
+        assert_eq!(
+            PixelData::from_data_set(&data_set).err(),
+            Some(PixelDataError::InsufficientLength {
+                expected: 8,
+                actual: 6
+            })
+        );
+    }
+
+    #[test]
+    fn test_encapsulated_frames_with_offset_table() {
+        // Arrange
+        // 1フレーム目は2つのフラグメント、2フレーム目は1つのフラグメントで構成される
+        let mut buf = element(NUMBER_OF_FRAMES, b"IS", b"2 ");
+        buf.append(&mut encapsulated_pixel_data(
+            &[0, 8 + 4 + 8 + 2],
+            &[b"\x01\x02\x03\x04", b"\x05\x06", b"\x07\x08"],
+        ));
+        let data_set = read(&buf);
+
+        // Act
+        let actual = PixelData::from_data_set(&data_set).unwrap();
+
+        // Assert
+        assert!(actual.is_encapsulated());
+        assert_eq!(actual.number_of_frames(), 2);
+        assert_eq!(actual.frame(1), Some(&b"\x01\x02\x03\x04\x05\x06"[..]));
+        assert_eq!(actual.frame(2), Some(&b"\x07\x08"[..]));
+    }
+
+    #[test]
+    fn test_encapsulated_frames_without_offset_table() {
+        // Arrange
+        let mut buf = element(NUMBER_OF_FRAMES, b"IS", b"2 ");
+        buf.append(&mut encapsulated_pixel_data(
+            &[],
+            &[b"\x01\x02", b"\x03\x04"],
+        ));
+        let data_set = read(&buf);
+
+        // Act
+        let actual = PixelData::from_data_set(&data_set).unwrap();
+
+        // Assert
+        assert_eq!(actual.frame(1), Some(&b"\x01\x02"[..]));
+        assert_eq!(actual.frame(2), Some(&b"\x03\x04"[..]));
+    }
+
+    #[test]
+    fn test_encapsulated_single_frame() {
+        // Arrange
+        // フレームが1つの場合はすべてのフラグメントを連結する
+        let buf = encapsulated_pixel_data(&[], &[b"\x01\x02", b"\x03\x04"]);
+        let data_set = read(&buf);
+
+        // Act
+        let actual = PixelData::from_data_set(&data_set).unwrap();
+
+        // Assert
+        assert_eq!(actual.number_of_frames(), 1);
+        assert_eq!(actual.frame(1), Some(&b"\x01\x02\x03\x04"[..]));
+    }
+
+    #[test]
+    fn test_encapsulated_unknown_frame_boundary() {
+        // Arrange
+        let mut buf = element(NUMBER_OF_FRAMES, b"IS", b"2 ");
+        buf.append(&mut encapsulated_pixel_data(
+            &[],
+            &[b"\x01\x02", b"\x03\x04", b"\x05\x06"],
+        ));
+        let data_set = read(&buf);
+
+        // Act & Assert
+        assert_eq!(
+            PixelData::from_data_set(&data_set).err(),
+            Some(PixelDataError::UnknownFrameBoundary)
+        );
+    }
+
+    #[test]
+    fn test_pixel_data_not_found() {
+        // Arrange
+        let data_set = read(&element(ROWS, b"US", &2u16.to_le_bytes()));
+
+        // Act & Assert
+        assert_eq!(
+            PixelData::from_data_set(&data_set).err(),
+            Some(PixelDataError::NotFound)
+        );
+    }
+}
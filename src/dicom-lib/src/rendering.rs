@@ -0,0 +1,863 @@
+use crate::core::{DataSet, Encoding, Tag, data_element::Vr};
+use crate::pixel_data::{PixelData, PixelDataError};
+use thiserror::Error;
+
+const SAMPLES_PER_PIXEL: Tag = Tag(0x0028, 0x0002);
+const PHOTOMETRIC_INTERPRETATION: Tag = Tag(0x0028, 0x0004);
+const PLANAR_CONFIGURATION: Tag = Tag(0x0028, 0x0006);
+const ROWS: Tag = Tag(0x0028, 0x0010);
+const COLUMNS: Tag = Tag(0x0028, 0x0011);
+const BITS_ALLOCATED: Tag = Tag(0x0028, 0x0100);
+const BITS_STORED: Tag = Tag(0x0028, 0x0101);
+const HIGH_BIT: Tag = Tag(0x0028, 0x0102);
+const PIXEL_REPRESENTATION: Tag = Tag(0x0028, 0x0103);
+const WINDOW_CENTER: Tag = Tag(0x0028, 0x1050);
+const WINDOW_WIDTH: Tag = Tag(0x0028, 0x1051);
+const RESCALE_INTERCEPT: Tag = Tag(0x0028, 0x1052);
+const RESCALE_SLOPE: Tag = Tag(0x0028, 0x1053);
+const VOI_LUT_FUNCTION: Tag = Tag(0x0028, 0x1056);
+const RED_PALETTE_COLOR_LUT_DESCRIPTOR: Tag = Tag(0x0028, 0x1101);
+const GREEN_PALETTE_COLOR_LUT_DESCRIPTOR: Tag = Tag(0x0028, 0x1102);
+const BLUE_PALETTE_COLOR_LUT_DESCRIPTOR: Tag = Tag(0x0028, 0x1103);
+const RED_PALETTE_COLOR_LUT_DATA: Tag = Tag(0x0028, 0x1201);
+const GREEN_PALETTE_COLOR_LUT_DATA: Tag = Tag(0x0028, 0x1202);
+const BLUE_PALETTE_COLOR_LUT_DATA: Tag = Tag(0x0028, 0x1203);
+const LUT_DESCRIPTOR: Tag = Tag(0x0028, 0x3002);
+const LUT_DATA: Tag = Tag(0x0028, 0x3006);
+const VOI_LUT_SEQUENCE: Tag = Tag(0x0028, 0x3010);
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum RenderingError {
+    #[error(transparent)]
+    PixelData(#[from] PixelDataError),
+
+    #[error("カプセル化された（圧縮された）ピクセルデータはレンダリングできません")]
+    Encapsulated,
+
+    #[error("フレーム番号{0}のフレームが存在しません")]
+    FrameNotFound(usize),
+
+    #[error("画像の属性 {0} が存在しないか、値が不正です")]
+    InvalidAttribute(&'static str),
+
+    #[error("Photometric Interpretation (0028,0004) が{0}の画像はレンダリングできません")]
+    UnsupportedPhotometricInterpretation(String),
+
+    #[error("Bits Allocated (0028,0100) が{0}の画像はレンダリングできません")]
+    UnsupportedBitsAllocated(u16),
+
+    #[error("画像の符号化に失敗しました: {0}")]
+    Encode(String),
+}
+
+/// VOI LUT Function (0028,1056)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoiLutFunction {
+    Linear,
+    LinearExact,
+    Sigmoid,
+}
+
+impl VoiLutFunction {
+    /// VOI LUT Function (0028,1056) の定義語から変換する。
+    pub fn from_defined_term(term: &str) -> Option<Self> {
+        match term {
+            "LINEAR" => Some(Self::Linear),
+            "LINEAR_EXACT" => Some(Self::LinearExact),
+            "SIGMOID" => Some(Self::Sigmoid),
+            _ => None,
+        }
+    }
+}
+
+/// ウィンドウ（表示する値の範囲）
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Window {
+    pub center: f64,
+    pub width: f64,
+    pub function: VoiLutFunction,
+}
+
+impl Window {
+    /// ウィンドウを適用して8ビットの値に変換する（PS3.3 C.11.2.1.2）。
+    fn apply(&self, x: f64) -> u8 {
+        let (c, w) = (self.center, self.width);
+        let y = match self.function {
+            VoiLutFunction::Linear => {
+                if x <= c - 0.5 - (w - 1.0) / 2.0 {
+                    0.0
+                } else if x > c - 0.5 + (w - 1.0) / 2.0 {
+                    1.0
+                } else {
+                    (x - (c - 0.5)) / (w - 1.0) + 0.5
+                }
+            }
+            VoiLutFunction::LinearExact => ((x - c) / w + 0.5).clamp(0.0, 1.0),
+            VoiLutFunction::Sigmoid => 1.0 / (1.0 + (-4.0 * (x - c) / w).exp()),
+        };
+        (y * 255.0).round().clamp(0.0, 255.0) as u8
+    }
+
+    /// 値が適用可能な範囲であるかを返す。LINEARの場合、Window Widthは1以上である必要がある。
+    pub fn is_valid(&self) -> bool {
+        self.center.is_finite()
+            && self.width.is_finite()
+            && match self.function {
+                VoiLutFunction::Linear => self.width >= 1.0,
+                _ => self.width > 0.0,
+            }
+    }
+}
+
+/// 表示用にレンダリングした8ビットのグレースケールまたはRGBの画像
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedImage {
+    width: u32,
+    height: u32,
+    channels: usize,
+    pixels: Vec<u8>,
+}
+
+impl RenderedImage {
+    /// データセットの1から始まるフレーム番号のフレームをレンダリングする。
+    ///
+    /// モノクロの画像には、Rescale Slope/Interceptを適用した後、`window`、データセットの
+    /// Window Center/Width、VOI LUT Sequenceの順に最初に存在するものを適用する。
+    /// いずれも存在しない場合は、フレームの最小値から最大値までを階調の範囲とする。
+    /// 非カプセル化（非圧縮）のピクセルデータのみに対応する。
+    pub fn render(
+        data_set: &DataSet,
+        frame_number: usize,
+        window: Option<Window>,
+    ) -> Result<Self, RenderingError> {
+        let pixel_data = PixelData::from_data_set(data_set)?;
+        if pixel_data.is_encapsulated() {
+            return Err(RenderingError::Encapsulated);
+        }
+        let frame = pixel_data
+            .frame(frame_number)
+            .ok_or(RenderingError::FrameNotFound(frame_number))?;
+
+        let attributes = ImageAttributes::from_data_set(data_set)?;
+        let (channels, pixels) = match attributes.photometric_interpretation.as_str() {
+            "MONOCHROME1" | "MONOCHROME2" => {
+                attributes.expect_samples_per_pixel(1)?;
+                (1, render_monochrome(data_set, &attributes, frame, window)?)
+            }
+            "RGB" => {
+                attributes.expect_samples_per_pixel(3)?;
+                (3, render_rgb(&attributes, frame))
+            }
+            "PALETTE COLOR" => {
+                attributes.expect_samples_per_pixel(1)?;
+                (3, render_palette_color(data_set, &attributes, frame)?)
+            }
+            other => {
+                return Err(RenderingError::UnsupportedPhotometricInterpretation(
+                    other.to_string(),
+                ));
+            }
+        };
+
+        Ok(Self {
+            width: attributes.columns as u32,
+            height: attributes.rows as u32,
+            channels,
+            pixels,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// RGBの画像であるかを返す。
+    pub fn is_color(&self) -> bool {
+        self.channels == 3
+    }
+
+    /// 行優先で並べたピクセルの値（RGBの場合はR, G, Bの順）を返す。
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// 縦横比を保ったまま、指定した幅と高さに収まるように拡大・縮小した画像を返す。
+    ///
+    /// 縮小する場合は、出力の各ピクセルに対応する範囲の平均値とする。
+    pub fn fit_to(&self, max_width: u32, max_height: u32) -> Self {
+        let scale = f64::min(
+            max_width as f64 / self.width as f64,
+            max_height as f64 / self.height as f64,
+        );
+        let width = ((self.width as f64 * scale).round() as u32).clamp(1, u16::MAX as u32);
+        let height = ((self.height as f64 * scale).round() as u32).clamp(1, u16::MAX as u32);
+        if width == self.width && height == self.height {
+            return self.clone();
+        }
+
+        let range = |i: u32, source: u32, target: u32| {
+            let start = (i as u64 * source as u64 / target as u64) as usize;
+            let end = ((i as u64 + 1) * source as u64 / target as u64) as usize;
+            start..end.max(start + 1)
+        };
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * self.channels);
+        for y in 0..height {
+            let rows = range(y, self.height, height);
+            for x in 0..width {
+                let columns = range(x, self.width, width);
+                let count = (rows.len() * columns.len()) as u32;
+                for channel in 0..self.channels {
+                    let sum: u32 = rows
+                        .clone()
+                        .flat_map(|r| columns.clone().map(move |c| (r, c)))
+                        .map(|(r, c)| {
+                            let index = (r * self.width as usize + c) * self.channels + channel;
+                            self.pixels[index] as u32
+                        })
+                        .sum();
+                    pixels.push(((sum + count / 2) / count) as u8);
+                }
+            }
+        }
+
+        Self {
+            width,
+            height,
+            channels: self.channels,
+            pixels,
+        }
+    }
+
+    /// PNG形式に符号化する。
+    pub fn encode_png(&self) -> Result<Vec<u8>, RenderingError> {
+        let mut buf = Vec::new();
+        let mut encoder = png::Encoder::new(&mut buf, self.width, self.height);
+        encoder.set_color(if self.is_color() {
+            png::ColorType::Rgb
+        } else {
+            png::ColorType::Grayscale
+        });
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| RenderingError::Encode(e.to_string()))?;
+        writer
+            .write_image_data(&self.pixels)
+            .map_err(|e| RenderingError::Encode(e.to_string()))?;
+        writer
+            .finish()
+            .map_err(|e| RenderingError::Encode(e.to_string()))?;
+        Ok(buf)
+    }
+
+    /// 品質（1〜100）を指定してJPEG形式に符号化する。
+    pub fn encode_jpeg(&self, quality: u8) -> Result<Vec<u8>, RenderingError> {
+        let (Ok(width), Ok(height)) = (u16::try_from(self.width), u16::try_from(self.height))
+        else {
+            return Err(RenderingError::Encode(format!(
+                "JPEGで符号化できない画像サイズです (幅={}, 高さ={})",
+                self.width, self.height
+            )));
+        };
+        let color_type = if self.is_color() {
+            jpeg_encoder::ColorType::Rgb
+        } else {
+            jpeg_encoder::ColorType::Luma
+        };
+
+        let mut buf = Vec::new();
+        jpeg_encoder::Encoder::new(&mut buf, quality.clamp(1, 100))
+            .encode(&self.pixels, width, height, color_type)
+            .map_err(|e| RenderingError::Encode(e.to_string()))?;
+        Ok(buf)
+    }
+}
+
+/// レンダリングに用いる画像ピクセルモジュールの属性
+struct ImageAttributes {
+    photometric_interpretation: String,
+    samples_per_pixel: u16,
+    planar_configuration: u16,
+    rows: u16,
+    columns: u16,
+    bits_allocated: u16,
+    bits_stored: u16,
+    high_bit: u16,
+    signed: bool,
+    big_endian: bool,
+}
+
+impl ImageAttributes {
+    fn from_data_set(data_set: &DataSet) -> Result<Self, RenderingError> {
+        let photometric_interpretation = read_strings(data_set, PHOTOMETRIC_INTERPRETATION)
+            .and_then(|values| values.into_iter().next())
+            .ok_or(RenderingError::InvalidAttribute(
+                "Photometric Interpretation (0028,0004)",
+            ))?;
+        let required =
+            |tag, name| read_u16(data_set, tag).ok_or(RenderingError::InvalidAttribute(name));
+        let samples_per_pixel = required(SAMPLES_PER_PIXEL, "Samples per Pixel (0028,0002)")?;
+        let rows = required(ROWS, "Rows (0028,0010)")?;
+        let columns = required(COLUMNS, "Columns (0028,0011)")?;
+        let bits_allocated = required(BITS_ALLOCATED, "Bits Allocated (0028,0100)")?;
+        if !matches!(bits_allocated, 8 | 16 | 32) {
+            return Err(RenderingError::UnsupportedBitsAllocated(bits_allocated));
+        }
+        let bits_stored = read_u16(data_set, BITS_STORED).unwrap_or(bits_allocated);
+        if bits_stored == 0 || bits_stored > bits_allocated {
+            return Err(RenderingError::InvalidAttribute("Bits Stored (0028,0101)"));
+        }
+        let high_bit = read_u16(data_set, HIGH_BIT).unwrap_or(bits_stored - 1);
+        if high_bit >= bits_allocated || high_bit + 1 < bits_stored {
+            return Err(RenderingError::InvalidAttribute("High Bit (0028,0102)"));
+        }
+
+        Ok(Self {
+            photometric_interpretation,
+            samples_per_pixel,
+            planar_configuration: read_u16(data_set, PLANAR_CONFIGURATION).unwrap_or(0),
+            rows,
+            columns,
+            bits_allocated,
+            bits_stored,
+            high_bit,
+            signed: read_u16(data_set, PIXEL_REPRESENTATION) == Some(1),
+            big_endian: data_set.encoding() == Encoding::ExplicitVrBigEndian,
+        })
+    }
+
+    fn expect_samples_per_pixel(&self, expected: u16) -> Result<(), RenderingError> {
+        if self.samples_per_pixel != expected {
+            return Err(RenderingError::InvalidAttribute(
+                "Samples per Pixel (0028,0002)",
+            ));
+        }
+        Ok(())
+    }
+
+    /// フレームのバイト列から、Bits Stored/High Bit/Pixel Representationに従って各サンプルの値を取り出す。
+    fn samples(&self, frame: &[u8]) -> Vec<i64> {
+        let bytes_per_sample = (self.bits_allocated / 8) as usize;
+        let shift = self.high_bit + 1 - self.bits_stored;
+        let mask = (1u64 << self.bits_stored) - 1;
+        let sign_bit = 1u64 << (self.bits_stored - 1);
+        frame
+            .chunks_exact(bytes_per_sample)
+            .map(|bytes| {
+                let raw = bytes.iter().enumerate().fold(0u64, |acc, (i, &b)| {
+                    let i = if self.big_endian {
+                        bytes_per_sample - 1 - i
+                    } else {
+                        i
+                    };
+                    acc | (b as u64) << (8 * i)
+                });
+                let value = (raw >> shift) & mask;
+                if self.signed && value & sign_bit != 0 {
+                    value as i64 - (1i64 << self.bits_stored)
+                } else {
+                    value as i64
+                }
+            })
+            .collect()
+    }
+}
+
+/// ルックアップテーブル（VOI LUT、Palette Color Lookup Table）
+struct Lut {
+    first_mapped_value: i64,
+    bits: u16,
+    entries: Vec<u16>,
+}
+
+impl Lut {
+    /// LUT DescriptorとLUT Dataからルックアップテーブルを読み込む。
+    /// LUT Descriptorが存在しない場合は`None`を返す。
+    fn from_data_set(
+        data_set: &DataSet,
+        descriptor_tag: Tag,
+        data_tag: Tag,
+        signed: bool,
+        name: &'static str,
+    ) -> Result<Option<Self>, RenderingError> {
+        let Some(index) = data_set.find_index(descriptor_tag) else {
+            return Ok(None);
+        };
+        let descriptor = read_u16s(data_set, descriptor_tag)
+            .filter(|d| d.len() == 3 && (1..=16).contains(&d[2]))
+            .ok_or(RenderingError::InvalidAttribute(name))?;
+        let number_of_entries = if descriptor[0] == 0 {
+            65536
+        } else {
+            descriptor[0] as usize
+        };
+        // 最初にマッピングされる値のVRは、ピクセルデータが符号付きの場合にSSとなる
+        let first_mapped_value = match data_set[index].vr() {
+            Some(Vr::Ss) => descriptor[1] as i16 as i64,
+            None if signed => descriptor[1] as i16 as i64,
+            _ => descriptor[1] as i64,
+        };
+
+        let data = data_set
+            .find_index(data_tag)
+            .map(|i| data_set[i].value_field())
+            .ok_or(RenderingError::InvalidAttribute(name))?;
+        // 8ビットのエントリが1バイトずつ格納されている場合と、2バイトずつ格納されている場合がある
+        let entries = if descriptor[2] <= 8 && data.len() == number_of_entries {
+            data.iter().map(|&b| b as u16).collect::<Vec<_>>()
+        } else {
+            data.chunks_exact(2)
+                .map(|b| match data_set.encoding() {
+                    Encoding::ExplicitVrBigEndian => u16::from_be_bytes([b[0], b[1]]),
+                    _ => u16::from_le_bytes([b[0], b[1]]),
+                })
+                .collect::<Vec<_>>()
+        };
+        if entries.len() < number_of_entries {
+            return Err(RenderingError::InvalidAttribute(name));
+        }
+
+        Ok(Some(Self {
+            first_mapped_value,
+            bits: descriptor[2],
+            entries: entries[..number_of_entries].to_vec(),
+        }))
+    }
+
+    /// 値に対応するエントリを8ビットの値に変換して返す。
+    /// 範囲外の値は最初または最後のエントリに対応させる。
+    fn lookup(&self, x: i64) -> u8 {
+        let index = (x - self.first_mapped_value).clamp(0, self.entries.len() as i64 - 1);
+        let entry = self.entries[index as usize] as u32 & ((1u32 << self.bits) - 1);
+        if self.bits >= 8 {
+            (entry >> (self.bits - 8)) as u8
+        } else {
+            (entry * 255 / ((1 << self.bits) - 1)) as u8
+        }
+    }
+}
+
+fn render_monochrome(
+    data_set: &DataSet,
+    attributes: &ImageAttributes,
+    frame: &[u8],
+    window: Option<Window>,
+) -> Result<Vec<u8>, RenderingError> {
+    let slope = read_decimals(data_set, RESCALE_SLOPE)
+        .and_then(|v| v.first().copied())
+        .unwrap_or(1.0);
+    let intercept = read_decimals(data_set, RESCALE_INTERCEPT)
+        .and_then(|v| v.first().copied())
+        .unwrap_or(0.0);
+    let values = attributes
+        .samples(frame)
+        .into_iter()
+        .map(|v| v as f64 * slope + intercept)
+        .collect::<Vec<_>>();
+
+    let mut pixels = match window
+        .filter(Window::is_valid)
+        .or_else(|| window_from_data_set(data_set))
+    {
+        Some(window) => values.iter().map(|&v| window.apply(v)).collect::<Vec<_>>(),
+        None => match voi_lut_from_data_set(data_set, attributes.signed)? {
+            Some(lut) => values
+                .iter()
+                .map(|&v| lut.lookup(v.round() as i64))
+                .collect(),
+            None => {
+                let (min, max) = values
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                        (min.min(v), max.max(v))
+                    });
+                values
+                    .iter()
+                    .map(|&v| {
+                        if max > min {
+                            ((v - min) / (max - min) * 255.0).round() as u8
+                        } else {
+                            0
+                        }
+                    })
+                    .collect()
+            }
+        },
+    };
+
+    if attributes.photometric_interpretation == "MONOCHROME1" {
+        // MONOCHROME1は値が小さいほど明るく表示する
+        pixels.iter_mut().for_each(|p| *p = 255 - *p);
+    }
+
+    Ok(pixels)
+}
+
+/// データセットの最初のWindow Center/WidthとVOI LUT Functionからウィンドウを返す。
+fn window_from_data_set(data_set: &DataSet) -> Option<Window> {
+    let center = *read_decimals(data_set, WINDOW_CENTER)?.first()?;
+    let width = *read_decimals(data_set, WINDOW_WIDTH)?.first()?;
+    let function = read_strings(data_set, VOI_LUT_FUNCTION)
+        .and_then(|v| v.first().and_then(|f| VoiLutFunction::from_defined_term(f)))
+        .unwrap_or(VoiLutFunction::Linear);
+    Some(Window {
+        center,
+        width,
+        function,
+    })
+    .filter(Window::is_valid)
+}
+
+/// VOI LUT Sequenceの最初のアイテムのルックアップテーブルを返す。
+fn voi_lut_from_data_set(data_set: &DataSet, signed: bool) -> Result<Option<Lut>, RenderingError> {
+    let Some(item) = data_set
+        .find_index(VOI_LUT_SEQUENCE)
+        .and_then(|i| data_set.items(i).into_iter().next())
+    else {
+        return Ok(None);
+    };
+    Lut::from_data_set(
+        &item,
+        LUT_DESCRIPTOR,
+        LUT_DATA,
+        signed,
+        "VOI LUT Sequence (0028,3010)",
+    )
+}
+
+fn render_rgb(attributes: &ImageAttributes, frame: &[u8]) -> Vec<u8> {
+    let samples = attributes.samples(frame);
+    let max = ((1u64 << attributes.bits_stored) - 1) as f64;
+    let to_u8 = |v: i64| (v.max(0) as f64 * 255.0 / max).round() as u8;
+
+    if attributes.planar_configuration == 1 {
+        // 色平面ごとに格納されている（RRR...GGG...BBB...）
+        let n = samples.len() / 3;
+        (0..n)
+            .flat_map(|i| [samples[i], samples[n + i], samples[2 * n + i]])
+            .map(to_u8)
+            .collect()
+    } else {
+        samples.into_iter().map(to_u8).collect()
+    }
+}
+
+fn render_palette_color(
+    data_set: &DataSet,
+    attributes: &ImageAttributes,
+    frame: &[u8],
+) -> Result<Vec<u8>, RenderingError> {
+    let luts = [
+        (
+            RED_PALETTE_COLOR_LUT_DESCRIPTOR,
+            RED_PALETTE_COLOR_LUT_DATA,
+            "Red Palette Color Lookup Table (0028,1101)",
+        ),
+        (
+            GREEN_PALETTE_COLOR_LUT_DESCRIPTOR,
+            GREEN_PALETTE_COLOR_LUT_DATA,
+            "Green Palette Color Lookup Table (0028,1102)",
+        ),
+        (
+            BLUE_PALETTE_COLOR_LUT_DESCRIPTOR,
+            BLUE_PALETTE_COLOR_LUT_DATA,
+            "Blue Palette Color Lookup Table (0028,1103)",
+        ),
+    ]
+    .into_iter()
+    .map(|(descriptor, data, name)| {
+        // Segmented Palette Color Lookup Table Dataには対応しない
+        Lut::from_data_set(data_set, descriptor, data, attributes.signed, name)?
+            .ok_or(RenderingError::InvalidAttribute(name))
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(attributes
+        .samples(frame)
+        .into_iter()
+        .flat_map(|index| luts.iter().map(move |lut| lut.lookup(index)))
+        .collect())
+}
+
+/// 値表現がUS（またはSS）である最上位のデータ要素の値をすべて返す。
+fn read_u16s(data_set: &DataSet, tag: Tag) -> Option<Vec<u16>> {
+    let value_field = data_set[data_set.find_index(tag)?].value_field();
+    Some(
+        value_field
+            .chunks_exact(2)
+            .map(|b| match data_set.encoding() {
+                Encoding::ExplicitVrBigEndian => u16::from_be_bytes([b[0], b[1]]),
+                _ => u16::from_le_bytes([b[0], b[1]]),
+            })
+            .collect(),
+    )
+}
+
+/// 値表現がUSである最上位のデータ要素の最初の値を返す。
+fn read_u16(data_set: &DataSet, tag: Tag) -> Option<u16> {
+    read_u16s(data_set, tag)?.first().copied()
+}
+
+/// 文字列の最上位のデータ要素の値を、値多重度に従って分割して返す。
+fn read_strings(data_set: &DataSet, tag: Tag) -> Option<Vec<String>> {
+    let value_field = data_set[data_set.find_index(tag)?].value_field();
+    Some(
+        String::from_utf8_lossy(value_field)
+            .split('\\')
+            .map(|v| v.trim_matches(['\0', ' ']).to_string())
+            .collect(),
+    )
+}
+
+/// 値表現がDSまたはISである最上位のデータ要素の値を返す。
+fn read_decimals(data_set: &DataSet, tag: Tag) -> Option<Vec<f64>> {
+    read_strings(data_set, tag)?
+        .iter()
+        .map(|v| v.parse::<f64>().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2行×2列、モノクロの画像のデータセットを返す。
+    fn monochrome_data_set(photometric_interpretation: &[u8], pixels: &[u16]) -> DataSet {
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        data_set.set_element(SAMPLES_PER_PIXEL, Vr::Us, 1u16.to_le_bytes().to_vec());
+        data_set.set_element(
+            PHOTOMETRIC_INTERPRETATION,
+            Vr::Cs,
+            photometric_interpretation.to_vec(),
+        );
+        data_set.set_element(ROWS, Vr::Us, 2u16.to_le_bytes().to_vec());
+        data_set.set_element(COLUMNS, Vr::Us, 2u16.to_le_bytes().to_vec());
+        data_set.set_element(BITS_ALLOCATED, Vr::Us, 16u16.to_le_bytes().to_vec());
+        data_set.set_element(BITS_STORED, Vr::Us, 12u16.to_le_bytes().to_vec());
+        data_set.set_element(HIGH_BIT, Vr::Us, 11u16.to_le_bytes().to_vec());
+        data_set.set_element(PIXEL_REPRESENTATION, Vr::Us, 0u16.to_le_bytes().to_vec());
+        data_set.set_element(
+            Tag(0x7fe0, 0x0010),
+            Vr::Ow,
+            pixels.iter().flat_map(|p| p.to_le_bytes()).collect(),
+        );
+        data_set
+    }
+
+    fn us(values: &[u16]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+
+    #[test]
+    fn test_render_monochrome2_with_window() {
+        // Arrange
+        let mut data_set = monochrome_data_set(b"MONOCHROME2 ", &[0, 100, 200, 1000]);
+        data_set.set_element(RESCALE_INTERCEPT, Vr::Ds, b"-100".to_vec());
+        data_set.set_element(RESCALE_SLOPE, Vr::Ds, b"1 ".to_vec());
+        data_set.set_element(WINDOW_CENTER, Vr::Ds, b"50\\0".to_vec());
+        data_set.set_element(WINDOW_WIDTH, Vr::Ds, b"101\\1".to_vec());
+
+        // Act
+        let actual = RenderedImage::render(&data_set, 1, None).unwrap();
+
+        // Assert
+        // 階調の範囲は-0.5〜99.5（Rescale Interceptを加算する前のピクセル値では99.5〜199.5）
+        assert_eq!(actual.width(), 2);
+        assert_eq!(actual.height(), 2);
+        assert!(!actual.is_color());
+        assert_eq!(actual.pixels(), &[0, 1, 255, 255]);
+    }
+
+    #[test]
+    fn test_render_window_override() {
+        // Arrange
+        let mut data_set = monochrome_data_set(b"MONOCHROME2 ", &[0, 100, 200, 1000]);
+        data_set.set_element(WINDOW_CENTER, Vr::Ds, b"50".to_vec());
+        data_set.set_element(WINDOW_WIDTH, Vr::Ds, b"100 ".to_vec());
+        let window = Window {
+            center: 100.0,
+            width: 200.0,
+            function: VoiLutFunction::LinearExact,
+        };
+
+        // Act
+        let actual = RenderedImage::render(&data_set, 1, Some(window)).unwrap();
+
+        // Assert
+        assert_eq!(actual.pixels(), &[0, 128, 255, 255]);
+    }
+
+    #[test]
+    fn test_render_monochrome1_min_max() {
+        // Arrange
+        // ウィンドウがない場合は最小値から最大値までを階調の範囲とする
+        let data_set = monochrome_data_set(b"MONOCHROME1 ", &[10, 20, 30, 40]);
+
+        // Act
+        let actual = RenderedImage::render(&data_set, 1, None).unwrap();
+
+        // Assert
+        assert_eq!(actual.pixels(), &[255, 170, 85, 0]);
+    }
+
+    #[test]
+    fn test_render_signed_with_high_bit() {
+        // Arrange
+        // 12ビットの符号付きの値が上位ビットに詰められている（High Bit=15）
+        let pixels = [-2048i16, -1, 0, 2047].map(|v| ((v as u16) << 4) | 0x000f);
+        let mut data_set = monochrome_data_set(b"MONOCHROME2 ", &pixels);
+        data_set.set_element(HIGH_BIT, Vr::Us, 15u16.to_le_bytes().to_vec());
+        data_set.set_element(PIXEL_REPRESENTATION, Vr::Us, 1u16.to_le_bytes().to_vec());
+        data_set.set_element(WINDOW_CENTER, Vr::Ds, b"0 ".to_vec());
+        data_set.set_element(WINDOW_WIDTH, Vr::Ds, b"4096".to_vec());
+        data_set.set_element(VOI_LUT_FUNCTION, Vr::Cs, b"LINEAR_EXACT".to_vec());
+
+        // Act
+        let actual = RenderedImage::render(&data_set, 1, None).unwrap();
+
+        // Assert
+        assert_eq!(actual.pixels(), &[0, 127, 128, 255]);
+    }
+
+    #[test]
+    fn test_render_voi_lut_sequence() {
+        // Arrange
+        let mut data_set = monochrome_data_set(b"MONOCHROME2 ", &[0, 100, 101, 103]);
+        let mut item = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        item.set_element(LUT_DESCRIPTOR, Vr::Us, us(&[3, 100, 12]));
+        item.set_element(LUT_DATA, Vr::Us, us(&[0x000, 0x800, 0xfff, 0x000]));
+        data_set.push_item(VOI_LUT_SEQUENCE, item);
+
+        // Act
+        let actual = RenderedImage::render(&data_set, 1, None).unwrap();
+
+        // Assert
+        // LUT Dataの4番目の値はエントリ数（3）を超えるため使用しない
+        assert_eq!(actual.pixels(), &[0, 0, 128, 255]);
+    }
+
+    #[test]
+    fn test_render_rgb_planar() {
+        // Arrange
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        data_set.set_element(SAMPLES_PER_PIXEL, Vr::Us, 3u16.to_le_bytes().to_vec());
+        data_set.set_element(PHOTOMETRIC_INTERPRETATION, Vr::Cs, b"RGB ".to_vec());
+        data_set.set_element(PLANAR_CONFIGURATION, Vr::Us, 1u16.to_le_bytes().to_vec());
+        data_set.set_element(ROWS, Vr::Us, 1u16.to_le_bytes().to_vec());
+        data_set.set_element(COLUMNS, Vr::Us, 2u16.to_le_bytes().to_vec());
+        data_set.set_element(BITS_ALLOCATED, Vr::Us, 8u16.to_le_bytes().to_vec());
+        data_set.set_element(Tag(0x7fe0, 0x0010), Vr::Ob, vec![1, 2, 3, 4, 5, 6]);
+
+        // Act
+        let actual = RenderedImage::render(&data_set, 1, None).unwrap();
+
+        // Assert
+        assert!(actual.is_color());
+        assert_eq!(actual.pixels(), &[1, 3, 5, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_render_palette_color() {
+        // Arrange
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        data_set.set_element(SAMPLES_PER_PIXEL, Vr::Us, 1u16.to_le_bytes().to_vec());
+        data_set.set_element(
+            PHOTOMETRIC_INTERPRETATION,
+            Vr::Cs,
+            b"PALETTE COLOR ".to_vec(),
+        );
+        data_set.set_element(ROWS, Vr::Us, 1u16.to_le_bytes().to_vec());
+        data_set.set_element(COLUMNS, Vr::Us, 2u16.to_le_bytes().to_vec());
+        data_set.set_element(BITS_ALLOCATED, Vr::Us, 8u16.to_le_bytes().to_vec());
+        for (descriptor, data, entries) in [
+            (
+                RED_PALETTE_COLOR_LUT_DESCRIPTOR,
+                RED_PALETTE_COLOR_LUT_DATA,
+                [0xff00, 0x0000],
+            ),
+            (
+                GREEN_PALETTE_COLOR_LUT_DESCRIPTOR,
+                GREEN_PALETTE_COLOR_LUT_DATA,
+                [0x0000, 0xff00],
+            ),
+            (
+                BLUE_PALETTE_COLOR_LUT_DESCRIPTOR,
+                BLUE_PALETTE_COLOR_LUT_DATA,
+                [0x8000, 0x8000],
+            ),
+        ] {
+            data_set.set_element(descriptor, Vr::Us, us(&[2, 0, 16]));
+            data_set.set_element(data, Vr::Ow, us(&entries));
+        }
+        data_set.set_element(Tag(0x7fe0, 0x0010), Vr::Ob, vec![0, 1]);
+
+        // Act
+        let actual = RenderedImage::render(&data_set, 1, None).unwrap();
+
+        // Assert
+        assert_eq!(actual.pixels(), &[255, 0, 128, 0, 255, 128]);
+    }
+
+    #[test]
+    fn test_render_unsupported_photometric_interpretation() {
+        // Arrange
+        let data_set = monochrome_data_set(b"YBR_FULL", &[0, 0, 0, 0]);
+
+        // Act & Assert
+        assert_eq!(
+            RenderedImage::render(&data_set, 1, None).err(),
+            Some(RenderingError::UnsupportedPhotometricInterpretation(
+                "YBR_FULL".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_render_frame_not_found() {
+        // Arrange
+        let data_set = monochrome_data_set(b"MONOCHROME2 ", &[0, 0, 0, 0]);
+
+        // Act & Assert
+        assert_eq!(
+            RenderedImage::render(&data_set, 2, None).err(),
+            Some(RenderingError::FrameNotFound(2))
+        );
+    }
+
+    #[test]
+    fn test_fit_to() {
+        // Arrange
+        let data_set = monochrome_data_set(b"MONOCHROME2 ", &[0, 30, 60, 90]);
+        let image = RenderedImage::render(&data_set, 1, None).unwrap();
+
+        // Act
+        let reduced = image.fit_to(1, 10);
+        let enlarged = image.fit_to(8, 4);
+
+        // Assert
+        assert_eq!((reduced.width(), reduced.height()), (1, 1));
+        assert_eq!(reduced.pixels(), &[128]);
+        assert_eq!((enlarged.width(), enlarged.height()), (4, 4));
+        assert_eq!(&enlarged.pixels()[..4], &[0, 0, 85, 85]);
+    }
+
+    #[test]
+    fn test_encode() {
+        // Arrange
+        let data_set = monochrome_data_set(b"MONOCHROME2 ", &[0, 30, 60, 90]);
+        let image = RenderedImage::render(&data_set, 1, None).unwrap();
+
+        // Act
+        let png = image.encode_png().unwrap();
+        let jpeg = image.encode_jpeg(90).unwrap();
+
+        // Assert
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert!(jpeg.starts_with(&[0xff, 0xd8]));
+        assert!(jpeg.ends_with(&[0xff, 0xd9]));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::core::value::value_representations::ui::UiValue;
+use uuid::Uuid;
+
+// <root>.<app>.<type>.<value>
+// root: 1.3.6.1.4.1.64183 (https://www.iana.org/assignments/enterprise-numbers/)
+// app: 1 (Oceanus)
+// type: 0 (生成したUID) ※ 1以降は実装クラスUIDで使用
+// value: UUIDv7を10進数で表した値
+const GENERATED_UID_PREFIX: &str = "1.3.6.1.4.1.64183.1.0.";
+
+// https://dicom.nema.org/medical/dicom/2025c/output/chtml/part05/sect_B.2.html
+const UUID_DERIVED_UID_PREFIX: &str = "2.25.";
+
+/// UIDの生成方式
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UidScheme {
+    /// OceanusのルートUID配下に生成する
+    #[default]
+    Oceanus,
+    /// UUIDから導出したUID（2.25）を生成する
+    UuidDerived,
+}
+
+/// 新しいUIDを生成する。
+///
+/// UUIDの乱数部により複数のプロセスで同時に生成しても衝突せず、
+/// UIDの長さは常に64バイト以下となる。
+pub fn generate(scheme: UidScheme) -> UiValue {
+    let uid = match scheme {
+        // UUIDv7は時刻順に並び、同一プロセス内ではカウンターにより単調増加する
+        UidScheme::Oceanus => format!("{GENERATED_UID_PREFIX}{}", Uuid::now_v7().as_u128()),
+        UidScheme::UuidDerived => {
+            format!("{UUID_DERIVED_UID_PREFIX}{}", Uuid::new_v4().as_u128())
+        }
+    };
+
+    UiValue::from_string(&uid).unwrap_or_else(|e| panic!("生成したUIDが不正です: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::HashSet, thread};
+
+    #[test]
+    fn test_generate() {
+        for scheme in [UidScheme::Oceanus, UidScheme::UuidDerived] {
+            // Act
+            let uids = (0..10_000)
+                .map(|_| generate(scheme).uid().to_string())
+                .collect::<Vec<_>>();
+
+            // Assert
+            let prefix = match scheme {
+                UidScheme::Oceanus => "1.3.6.1.4.1.64183.1.0.",
+                UidScheme::UuidDerived => "2.25.",
+            };
+            for uid in &uids {
+                assert!(uid.starts_with(prefix), "{uid}");
+                assert!(uid.len() <= 64, "{uid}");
+            }
+            assert_eq!(uids.iter().collect::<HashSet<_>>().len(), uids.len());
+        }
+    }
+
+    #[test]
+    fn test_generate_in_parallel() {
+        // Act
+        let handles = (0..8)
+            .map(|_| {
+                thread::spawn(|| {
+                    (0..1_000)
+                        .map(|_| generate(UidScheme::Oceanus).uid().to_string())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let uids = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(uids.iter().collect::<HashSet<_>>().len(), 8_000);
+    }
+}
@@ -1,4 +1,7 @@
-use clap::{Parser, ValueEnum};
+use clap::{Parser, Subcommand, ValueEnum};
+use ingest::DuplicatePolicy;
+use std::path::PathBuf;
+use storage::{FileSystemStorage, S3Config, StorageConfig};
 use tracing::level_filters::LevelFilter;
 
 #[derive(Parser, Debug)]
@@ -23,6 +26,142 @@ pub struct Args {
     /// データディレクトリ
     #[arg(long = "data-dir", env = "DATA_DIR")]
     pub data_dir: String,
+
+    /// 既定のAEタイトルにおける重複したSOPインスタンスの処理方針
+    #[arg(long = "duplicate-policy", env = "DUPLICATE_POLICY", value_enum, default_value_t = DuplicatePolicy::Overwrite)]
+    pub duplicate_policy: DuplicatePolicy,
+
+    /// 保存先のストレージ
+    #[arg(long = "storage-backend", env = "STORAGE_BACKEND", value_enum, default_value_t = StorageBackendKind::FileSystem)]
+    pub storage_backend: StorageBackendKind,
+
+    /// S3互換のオブジェクトストレージのエンドポイント
+    #[arg(long = "s3-endpoint", env = "S3_ENDPOINT")]
+    pub s3_endpoint: Option<String>,
+
+    /// S3互換のオブジェクトストレージのリージョン
+    #[arg(long = "s3-region", env = "S3_REGION", default_value = "us-east-1")]
+    pub s3_region: String,
+
+    /// S3互換のオブジェクトストレージのバケット
+    #[arg(long = "s3-bucket", env = "S3_BUCKET")]
+    pub s3_bucket: Option<String>,
+
+    /// S3互換のオブジェクトストレージのアクセスキーID
+    #[arg(long = "s3-access-key-id", env = "S3_ACCESS_KEY_ID")]
+    pub s3_access_key_id: Option<String>,
+
+    /// S3互換のオブジェクトストレージのシークレットアクセスキー
+    #[arg(long = "s3-secret-access-key", env = "S3_SECRET_ACCESS_KEY")]
+    pub s3_secret_access_key: Option<String>,
+
+    /// ファイルのハッシュ値の定期検証の間隔（分）。0の場合は定期検証を行わない
+    #[arg(
+        long = "scrub-interval-minutes",
+        env = "SCRUB_INTERVAL_MINUTES",
+        default_value_t = 60
+    )]
+    pub scrub_interval_minutes: u64,
+
+    /// ファイルのハッシュ値の定期検証で1回に検証するファイル数
+    #[arg(
+        long = "scrub-batch-size",
+        env = "SCRUB_BATCH_SIZE",
+        default_value_t = 1000
+    )]
+    pub scrub_batch_size: u32,
+
+    /// 保存期間の規則による削除の間隔（分）。0の場合は定期削除を行わない
+    #[arg(
+        long = "retention-interval-minutes",
+        env = "RETENTION_INTERVAL_MINUTES",
+        default_value_t = 60
+    )]
+    pub retention_interval_minutes: u64,
+
+    /// 削除したSOPインスタンスのファイルの移動先ディレクトリ（省略した場合はファイルを削除する）
+    #[arg(long = "trash-dir", env = "TRASH_DIR")]
+    pub trash_dir: Option<String>,
+
+    /// 保守用のコマンド（省略した場合はDICOMサーバーとして起動する）
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// 保存済みのファイルとDBの整合性を検査する
+    Check {
+        /// 検査結果を出力するレポートファイルのパス
+        #[arg(long = "report")]
+        report: PathBuf,
+
+        /// DBに登録されていないファイルを解析し、患者・検査・シリーズ・SOPインスタンスをDBに登録する
+        #[arg(long = "reindex")]
+        reindex: bool,
+    },
+    /// 保存期間の規則を評価し、対象となった検査を削除する
+    Retention {
+        /// 対象となった検査の一覧を出力するレポートファイルのパス
+        #[arg(long = "report")]
+        report: PathBuf,
+
+        /// 削除せずに、対象となる検査の一覧のみを出力する
+        #[arg(long = "dry-run")]
+        dry_run: bool,
+    },
+}
+
+impl Args {
+    /// 保存先のストレージの設定を返す。
+    /// S3互換のオブジェクトストレージを選択した場合に接続設定が不足している場合はエラーを返す。
+    pub fn storage_config(&self) -> Result<StorageConfig, String> {
+        let data_dir = PathBuf::from(&self.data_dir);
+        match self.storage_backend {
+            StorageBackendKind::FileSystem => Ok(StorageConfig::FileSystem { data_dir }),
+            StorageBackendKind::ContentAddressed => {
+                Ok(StorageConfig::ContentAddressed { data_dir })
+            }
+            StorageBackendKind::S3 => {
+                let (Some(endpoint), Some(bucket), Some(access_key_id), Some(secret_access_key)) = (
+                    non_empty(&self.s3_endpoint),
+                    non_empty(&self.s3_bucket),
+                    non_empty(&self.s3_access_key_id),
+                    non_empty(&self.s3_secret_access_key),
+                ) else {
+                    return Err("S3互換のオブジェクトストレージを使用するには、S3_ENDPOINT、S3_BUCKET、S3_ACCESS_KEY_IDおよびS3_SECRET_ACCESS_KEYを指定する必要があります".to_string());
+                };
+                Ok(StorageConfig::S3(S3Config {
+                    endpoint: endpoint.clone(),
+                    region: self.s3_region.clone(),
+                    bucket: bucket.clone(),
+                    access_key_id: access_key_id.clone(),
+                    secret_access_key: secret_access_key.clone(),
+                }))
+            }
+        }
+    }
+
+    /// 削除したSOPインスタンスのファイルの移動先（ゴミ箱）のストレージを返す。
+    pub fn trash(&self) -> Option<FileSystemStorage> {
+        non_empty(&self.trash_dir).map(FileSystemStorage::new)
+    }
+}
+
+/// 環境変数に空文字列が指定された場合は未指定として扱う。
+fn non_empty(value: &Option<String>) -> Option<&String> {
+    value.as_ref().filter(|value| !value.is_empty())
+}
+
+/// 保存先のストレージの種類
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum StorageBackendKind {
+    /// データディレクトリ配下に検査日・検査・シリーズごとのディレクトリを作成して保存する
+    FileSystem,
+    /// データディレクトリ配下に内容のハッシュ値をパスとして保存する
+    ContentAddressed,
+    /// S3互換のオブジェクトストレージに保存する
+    S3,
 }
 
 #[derive(ValueEnum, Clone, Copy, Debug)]
@@ -1,13 +1,16 @@
 use dicom_lib::constants::{
     sop_class_uids::{
-        COMPUTED_RADIOGRAPHY_IMAGE_STORAGE, CT_IMAGE_STORAGE,
-        DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION,
-        DIGITAL_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION, MR_IMAGE_STORAGE,
-        SECONDARY_CAPTURE_IMAGE_STORAGE, VERIFICATION, X_RAY_ANGIOGRAPHIC_IMAGE_STORAGE,
-        X_RAY_RADIOFLUOROSCOPIC_IMAGE_STORAGE,
+        VIDEO_ENDOSCOPIC_IMAGE_STORAGE, VIDEO_MICROSCOPIC_IMAGE_STORAGE,
+        VIDEO_PHOTOGRAPHIC_IMAGE_STORAGE,
+    },
+    transfer_syntax_uids::{
+        EXPLICIT_VR_LITTLE_ENDIAN, HEVC_M10P51, HEVC_MP51, IMPLICIT_VR_LITTLE_ENDIAN, MPEG2_MPHL,
+        MPEG2_MPHL_F, MPEG2_MPML, MPEG2_MPML_F, MPEG4_HP41, MPEG4_HP41_BD, MPEG4_HP41_BD_F,
+        MPEG4_HP41_F, MPEG4_HP42_2D, MPEG4_HP42_2D_F, MPEG4_HP42_3D, MPEG4_HP42_3D_F,
+        MPEG4_HP42_STEREO, MPEG4_HP42_STEREO_F,
     },
-    transfer_syntax_uids::{EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN},
 };
+use std::time::Duration;
 
 // <root>.<app>.<type>.<version>
 // root: 1.3.6.1.4.1.64183 (https://www.iana.org/assignments/enterprise-numbers/)
@@ -20,18 +23,38 @@ pub const IMPLEMENTATION_VERSION_NAME: &str = concat!("OCEANUS_", env!("CARGO_PK
 
 pub const MAXIMUM_LENGTH: u32 = 0; // 制限なし
 
-pub const SUPPORTED_ABSTRACT_SYNTAX_UIDS: &[&str] = &[
-    // Verification
-    VERIFICATION,
-    // Storage
-    COMPUTED_RADIOGRAPHY_IMAGE_STORAGE,
-    DIGITAL_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION,
-    DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION,
-    CT_IMAGE_STORAGE,
-    MR_IMAGE_STORAGE,
-    SECONDARY_CAPTURE_IMAGE_STORAGE,
-    X_RAY_ANGIOGRAPHIC_IMAGE_STORAGE,
-    X_RAY_RADIOFLUOROSCOPIC_IMAGE_STORAGE,
-];
+// 作成からこの時間が経過しても残っているジャーナルの記録および一時ファイルを、中断した処理とみなして復旧する
+// NOTE: 実行中のDICOMサーバーおよびWeb APIが保存中の処理を妨げないよう、最も時間のかかる保存よりも十分に長くする
+pub const STALE_STORE_THRESHOLD: Duration = Duration::from_secs(60 * 60);
+
+// NOTE: 受諾する抽象構文はサービスハンドラーのレジストリで決定する
 pub const SUPPORTED_TRANSFER_SYNTAX_UIDS: &[&str] = // NOTE: 順序は優先度順
     &[EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN];
+
+// 動画のStorage SOPクラスは、ピクセルデータをMPEG-2/MPEG-4/HEVCで圧縮した転送構文でも受諾する
+pub const VIDEO_STORAGE_SOP_CLASS_UIDS: &[&str] = &[
+    VIDEO_ENDOSCOPIC_IMAGE_STORAGE,
+    VIDEO_MICROSCOPIC_IMAGE_STORAGE,
+    VIDEO_PHOTOGRAPHIC_IMAGE_STORAGE,
+];
+
+// NOTE: 受信したデータセットは変換せず、受諾した転送構文のまま保存する
+pub const VIDEO_TRANSFER_SYNTAX_UIDS: &[&str] = // NOTE: 順序は優先度順
+    &[
+        MPEG2_MPML,
+        MPEG2_MPML_F,
+        MPEG2_MPHL,
+        MPEG2_MPHL_F,
+        MPEG4_HP41,
+        MPEG4_HP41_F,
+        MPEG4_HP41_BD,
+        MPEG4_HP41_BD_F,
+        MPEG4_HP42_2D,
+        MPEG4_HP42_2D_F,
+        MPEG4_HP42_3D,
+        MPEG4_HP42_3D_F,
+        MPEG4_HP42_STEREO,
+        MPEG4_HP42_STEREO_F,
+        HEVC_MP51,
+        HEVC_M10P51,
+    ];
@@ -0,0 +1,40 @@
+use crate::dimse::ServiceRegistry;
+use ingest::{CoercionRule, DuplicatePolicy};
+use sqlx::{Pool, Postgres, types::Uuid};
+use storage::{FileSystemStorage, StorageBackend, StorageConfig};
+
+/// サーバー全体で共有する情報
+///
+/// 起動時に生成し、各アソシエーションおよびサービスハンドラーへ明示的に渡す。
+pub struct ServerContext {
+    /// 既定のAEタイトル
+    ///
+    /// `local_application_entities`テーブルに存在しない場合も、宛先AEタイトルとして受け付ける。
+    pub default_ae_title: String,
+    /// 既定のAEタイトルにおける重複したSOPインスタンスの処理方針
+    pub default_duplicate_policy: DuplicatePolicy,
+    /// 保存先のストレージの設定
+    pub storage_config: StorageConfig,
+    pub db_pool: Pool<Postgres>,
+    /// ダンプファイル等、宛先AEに依存しないファイルの保存先
+    pub storage: Box<dyn StorageBackend>,
+    /// 削除したSOPインスタンスのファイルの移動先（`None`の場合はファイルを削除する）
+    pub trash: Option<FileSystemStorage>,
+    pub service_registry: ServiceRegistry,
+}
+
+/// 確立したアソシエーションの情報
+pub struct AssociationContext {
+    /// 宛先AEタイトル
+    pub called_ae_title: String,
+    /// 呼出元AEタイトル
+    pub calling_ae_title: String,
+    /// 呼出元AEの`application_entities`テーブル上のUUID
+    pub calling_ae_uuid: Uuid,
+    /// 宛先AEごとのDICOMファイルの保存先
+    pub storage: Box<dyn StorageBackend>,
+    /// 宛先AEごとの重複したSOPインスタンスの処理方針
+    pub duplicate_policy: DuplicatePolicy,
+    /// 呼出元AEごとの受信時の属性の書き換え規則
+    pub coercion_rules: Vec<CoercionRule>,
+}
@@ -1,26 +1,25 @@
 pub mod c_echo;
 pub mod c_store;
+pub mod mpps;
+mod service_handler;
+mod service_registry;
 
-use crate::STORAGE_DIR;
+pub use self::{
+    service_handler::{ServiceHandler, ServiceRequest},
+    service_registry::ServiceRegistry,
+};
+
+use crate::context::{AssociationContext, ServerContext};
 use dicom_lib::{
-    constants::{
-        sop_class_uids::{
-            COMPUTED_RADIOGRAPHY_IMAGE_STORAGE, CT_IMAGE_STORAGE,
-            DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION,
-            DIGITAL_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION, MR_IMAGE_STORAGE,
-            SECONDARY_CAPTURE_IMAGE_STORAGE, VERIFICATION, X_RAY_ANGIOGRAPHIC_IMAGE_STORAGE,
-            X_RAY_RADIOFLUOROSCOPIC_IMAGE_STORAGE,
-        },
-        transfer_syntax_uids::{EXPLICIT_VR_BIG_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN},
+    constants::transfer_syntax_uids::{EXPLICIT_VR_BIG_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN},
+    core::{DataSet, Encoding, Tag},
+    network::{
+        CommandSet,
+        dimse::{Status, enums::CommandField, failure_response},
+        upper_layer_protocol::pdu::a_abort::Reason,
     },
-    core::{DataSet, Encoding},
-    network::{CommandSet, upper_layer_protocol::pdu::a_abort::Reason},
-};
-use std::{
-    io::Cursor,
-    path::{Path, PathBuf},
 };
-use tokio::fs;
+use std::io::Cursor;
 use tracing::{error, info};
 
 pub struct DimseMessage {
@@ -55,137 +54,147 @@ fn parse_data_set(buf: &[u8], encoding: Encoding) -> Result<DataSet, Reason> {
     }
 }
 
+/// 標準で提供するサービスハンドラーを登録したレジストリを生成する。
+pub fn default_service_registry() -> ServiceRegistry {
+    let mut registry = ServiceRegistry::new();
+    registry.register(c_echo::VerificationHandler);
+    registry.register(c_store::StorageHandler);
+    registry.register(mpps::MppsHandler);
+    registry
+}
+
 pub async fn handle_dimse_message(
     dimse_message: DimseMessage,
-    ae_title: &str,
+    server: &ServerContext,
+    association: &AssociationContext,
 ) -> Result<(Vec<u8>, Vec<u8>), Reason> {
+    let calling_ae_title = association.calling_ae_title.as_str();
     let command_set = match parse_command_set(&dimse_message.command_set_buf) {
         Ok(val) => val,
         Err(e) => {
-            match dump(
+            dump(
+                server,
                 dimse_message.command_set_buf,
-                ae_title,
+                calling_ae_title,
                 DumpType::CommandSet,
             )
-            .await
-            {
-                Ok(path) => {
-                    info!(
-                        "パースに失敗したコマンドセットをダンプファイルとして保存しました (パス=\"{}\")",
-                        path.display()
-                    );
-                }
-                Err(e) => {
-                    error!(
-                        "パースに失敗したコマンドセットをダンプファイルとして保存できませんでした: {}",
-                        e
-                    );
-                }
-            }
+            .await;
             return Err(e);
         }
     };
 
-    match dimse_message.abstract_syntax_uid.as_str() {
-        VERIFICATION => c_echo::handle_c_echo(command_set, dimse_message.context_id),
-        COMPUTED_RADIOGRAPHY_IMAGE_STORAGE
-        | DIGITAL_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION
-        | DIGITAL_MAMMOGRAPHY_X_RAY_IMAGE_STORAGE_FOR_PRESENTATION
-        | CT_IMAGE_STORAGE
-        | MR_IMAGE_STORAGE
-        | SECONDARY_CAPTURE_IMAGE_STORAGE
-        | X_RAY_ANGIOGRAPHIC_IMAGE_STORAGE
-        | X_RAY_RADIOFLUOROSCOPIC_IMAGE_STORAGE => {
-            let data_set = {
-                let encoding = match dimse_message.transfer_syntax_uid {
-                    IMPLICIT_VR_LITTLE_ENDIAN => Encoding::ImplicitVrLittleEndian,
-                    EXPLICIT_VR_BIG_ENDIAN => {
-                        unimplemented!("Explicit VR Big Endianのサポートは未実装です")
-                    }
-                    _ => {
-                        // 暗黙的VRリトルエンディアンと明示的VRビッグエンディアン以外の転送構文に対応するエンコーディングは明示的VRリトルエンディアン
-                        Encoding::ExplicitVrLittleEndian
-                    }
-                };
-
-                match parse_data_set(dimse_message.data_set_buf.as_ref(), encoding) {
-                    Ok(val) => val,
-                    Err(e) => {
-                        match dump(dimse_message.data_set_buf, ae_title, DumpType::DataSet).await {
-                            Ok(path) => {
-                                info!(
-                                    "パースに失敗したデータセットをダンプファイルとして保存しました (パス=\"{}\")",
-                                    path.display()
-                                );
-                            }
-                            Err(e) => {
-                                error!(
-                                    "パースに失敗したデータセットをダンプファイルとして保存できませんでした: {}",
-                                    e
-                                );
-                            }
-                        }
-                        return Err(e);
-                    }
-                }
-            };
-            c_store::handle_c_store(command_set, data_set, dimse_message, ae_title).await
+    let command_field = match CommandField::from_command_set(&command_set) {
+        Ok(val) => val,
+        Err(e) => {
+            error!("Command Fieldの取得に失敗しました: {e}");
+            return Err(Reason::InvalidPduParameterValue);
         }
-        _ => unreachable!(),
-    }
-}
+    };
 
-enum DumpType {
-    CommandSet,
-    DataSet,
-}
+    // プレゼンテーションコンテキストの抽象構文に対応するハンドラーが、そのコマンドを扱えるかを確認する
+    let abstract_syntax_uid = dimse_message.abstract_syntax_uid.as_str();
+    let Some(handler) = server
+        .service_registry
+        .get(abstract_syntax_uid)
+        .filter(|handler| handler.command_fields().contains(&command_field))
+    else {
+        error!(
+            "抽象構文に対してサポートされていないコマンドです (CommandField={command_field}, 抽象構文=\"{abstract_syntax_uid}\")"
+        );
+        // アソシエーションは維持し、認識されていない操作として応答する
+        return match failure_response(&command_set, Status::UNRECOGNIZED_OPERATION) {
+            Ok(command_set) => Ok((command_set.into(), vec![])),
+            Err(e) => {
+                error!("応答の生成に失敗しました: {e}");
+                Err(Reason::UnrecognizedPdu)
+            }
+        };
+    };
 
-async fn dump(buf: Vec<u8>, ae_title: &str, dump_type: DumpType) -> Result<PathBuf, SaveFileError> {
-    let now = chrono::Utc::now().format("%Y%m%d%H%M%S%6f").to_string();
-    let dump_type = match dump_type {
-        DumpType::CommandSet => "commandset",
-        DumpType::DataSet => "dataset",
+    let data_set = if command_set_has_data_set(&command_set) {
+        Some(receive_data_set(&dimse_message, server, calling_ae_title).await?)
+    } else {
+        None
+    };
+    let request = ServiceRequest {
+        context_id: dimse_message.context_id,
+        transfer_syntax_uid: dimse_message.transfer_syntax_uid,
+        command_field,
+        command_set,
+        data_set,
     };
 
-    let path_buf = Path::new(STORAGE_DIR.get().unwrap())
-        .join("dump")
-        .join(format!("{now}_{ae_title}_{dump_type}.dump"));
+    handler.handle(request, server, association).await
+}
 
-    save_file(buf, &path_buf).await?;
-    Ok(path_buf)
+/// 受信したコマンドセットの`Command Data Set Type (0000,0800)`から、データセットが続くかどうかを判定する。
+/// コマンドセットが不正な場合はデータセットが続かないものとみなし、後続のDIMSEメッセージの処理でエラーとする。
+pub fn has_data_set(command_set_buf: &[u8]) -> bool {
+    let mut cur = Cursor::new(command_set_buf);
+    CommandSet::read_from_cur(&mut cur)
+        .is_ok_and(|command_set| command_set_has_data_set(&command_set))
 }
 
-#[derive(Debug, thiserror::Error)]
-enum SaveFileError {
-    #[error("ディレクトリの作成に失敗しました (パス=\"{}\"): {io_error}", path_buf.display())]
-    CreateDirError {
-        path_buf: PathBuf,
-        io_error: std::io::Error,
-    },
-    #[error("ファイルの書き込みに失敗しました (パス=\"{}\"): {io_error}", path_buf.display())]
-    WriteFileError {
-        path_buf: PathBuf,
-        io_error: std::io::Error,
-    },
+fn command_set_has_data_set(command_set: &CommandSet) -> bool {
+    command_set
+        .iter()
+        .find(|command| command.tag() == Tag(0x0000, 0x0800))
+        .is_some_and(|command| command.value_field() != 0x0101u16.to_le_bytes())
 }
 
-async fn save_file(buf: Vec<u8>, path: &Path) -> Result<(), SaveFileError> {
-    // ディレクトリが存在しない場合は作成
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)
-            .await
-            .map_err(|e| SaveFileError::CreateDirError {
-                path_buf: parent.to_path_buf(),
-                io_error: e,
-            })?;
+/// 受信したデータセットをパースする。
+/// パースに失敗した場合、受信したデータセットをダンプファイルとして保存する。
+async fn receive_data_set(
+    dimse_message: &DimseMessage,
+    server: &ServerContext,
+    calling_ae_title: &str,
+) -> Result<DataSet, Reason> {
+    let encoding = match dimse_message.transfer_syntax_uid {
+        IMPLICIT_VR_LITTLE_ENDIAN => Encoding::ImplicitVrLittleEndian,
+        EXPLICIT_VR_BIG_ENDIAN => {
+            unimplemented!("Explicit VR Big Endianのサポートは未実装です")
+        }
+        _ => {
+            // 暗黙的VRリトルエンディアンと明示的VRビッグエンディアン以外の転送構文に対応するエンコーディングは明示的VRリトルエンディアン
+            Encoding::ExplicitVrLittleEndian
+        }
+    };
+
+    match parse_data_set(dimse_message.data_set_buf.as_ref(), encoding) {
+        Ok(val) => Ok(val),
+        Err(e) => {
+            dump(
+                server,
+                dimse_message.data_set_buf.clone(),
+                calling_ae_title,
+                DumpType::DataSet,
+            )
+            .await;
+            Err(e)
+        }
     }
+}
 
-    fs::write(path, buf)
-        .await
-        .map_err(|e| SaveFileError::WriteFileError {
-            path_buf: path.to_path_buf(),
-            io_error: e,
-        })?;
+enum DumpType {
+    CommandSet,
+    DataSet,
+}
+
+/// パースに失敗したデータをダンプファイルとして保存する。
+async fn dump(server: &ServerContext, buf: Vec<u8>, ae_title: &str, dump_type: DumpType) {
+    let now = chrono::Utc::now().format("%Y%m%d%H%M%S%6f").to_string();
+    let (dump_type, name) = match dump_type {
+        DumpType::CommandSet => ("commandset", "コマンドセット"),
+        DumpType::DataSet => ("dataset", "データセット"),
+    };
+    let key = format!("dump/{now}_{ae_title}_{dump_type}.dump");
 
-    Ok(())
+    match server.storage.put(&key, buf).await {
+        Ok(uri) => {
+            info!("パースに失敗した{name}をダンプファイルとして保存しました (URI=\"{uri}\")");
+        }
+        Err(e) => {
+            error!("パースに失敗した{name}をダンプファイルとして保存できませんでした: {e}");
+        }
+    }
 }
@@ -1,14 +1,44 @@
-use dicom_lib::network::{
-    CommandSet,
-    dimse::c_echo::{CEchoRq, CEchoRsp, c_echo_rsp::Status},
-    upper_layer_protocol::pdu::a_abort::Reason,
+use crate::{
+    context::{AssociationContext, ServerContext},
+    dimse::{ServiceHandler, ServiceRequest},
+};
+use dicom_lib::{
+    constants::sop_class_uids::VERIFICATION,
+    network::{
+        CommandSet,
+        dimse::{
+            c_echo::{CEchoRq, CEchoRsp, c_echo_rsp::Status},
+            enums::CommandField,
+        },
+        upper_layer_protocol::pdu::a_abort::Reason,
+    },
 };
 use tracing::{error, info};
 
-pub fn handle_c_echo(
-    command_set: CommandSet,
-    context_id: u8,
-) -> Result<(Vec<u8>, Vec<u8>), Reason> {
+/// Verification SOP Classに対するC-ECHOを処理する。
+pub struct VerificationHandler;
+
+#[async_trait::async_trait]
+impl ServiceHandler for VerificationHandler {
+    fn sop_class_uids(&self) -> Vec<&'static str> {
+        vec![VERIFICATION]
+    }
+
+    fn command_fields(&self) -> &'static [CommandField] {
+        &[CommandField::CEchoRq]
+    }
+
+    async fn handle(
+        &self,
+        request: ServiceRequest,
+        _server: &ServerContext,
+        _association: &AssociationContext,
+    ) -> Result<(Vec<u8>, Vec<u8>), Reason> {
+        handle_c_echo(request.command_set, request.context_id)
+    }
+}
+
+fn handle_c_echo(command_set: CommandSet, context_id: u8) -> Result<(Vec<u8>, Vec<u8>), Reason> {
     let c_echo_rq = match CEchoRq::try_from(command_set) {
         Ok(val) => val,
         Err(e) => {
@@ -0,0 +1,1052 @@
+mod performed_procedure_step_info;
+
+use crate::{
+    context::{AssociationContext, ServerContext},
+    dimse::{
+        ServiceHandler, ServiceRequest,
+        mpps::performed_procedure_step_info::{
+            PerformedProcedureStepInfo, PerformedSeries, ProcedureStepStatus,
+        },
+    },
+};
+use dicom_lib::{
+    constants::sop_class_uids::MODALITY_PERFORMED_PROCEDURE_STEP,
+    core::DataSet,
+    network::{
+        CommandSet,
+        dimse::{
+            enums::CommandField,
+            n_create::{NCreateRq, NCreateRsp, n_create_rsp},
+            n_set::{NSetRq, NSetRsp, n_set_rsp},
+        },
+        upper_layer_protocol::pdu::a_abort::Reason,
+    },
+};
+use sqlx::{Pool, Postgres, Transaction, query, types::Uuid};
+use tracing::{error, info, warn};
+
+const SOP_CLASS_NAME: &str = "Modality Performed Procedure Step SOP Class";
+
+/// Modality Performed Procedure Step SOP Classに対するN-CREATE/N-SETを処理する。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part04/sect_F.7.html>
+pub struct MppsHandler;
+
+#[async_trait::async_trait]
+impl ServiceHandler for MppsHandler {
+    fn sop_class_uids(&self) -> Vec<&'static str> {
+        vec![MODALITY_PERFORMED_PROCEDURE_STEP]
+    }
+
+    fn command_fields(&self) -> &'static [CommandField] {
+        &[CommandField::NCreateRq, CommandField::NSetRq]
+    }
+
+    async fn handle(
+        &self,
+        request: ServiceRequest,
+        server: &ServerContext,
+        association: &AssociationContext,
+    ) -> Result<(Vec<u8>, Vec<u8>), Reason> {
+        let Some(data_set) = request.data_set else {
+            error!("{}にデータセットが存在しません", request.command_field);
+            return Err(Reason::InvalidPduParameterValue);
+        };
+        let db_pool = &server.db_pool;
+        let ae_uuid = association.calling_ae_uuid;
+        let context_id = request.context_id;
+
+        let command_set_to_be_sent: CommandSet = match request.command_field {
+            CommandField::NCreateRq => {
+                let n_create_rq = match NCreateRq::try_from(request.command_set) {
+                    Ok(val) => val,
+                    Err(e) => {
+                        error!("N-CREATE-RQのパースに失敗しました: {e}");
+                        return Err(Reason::InvalidPduParameterValue);
+                    }
+                };
+                handle_n_create_rq(n_create_rq, data_set, db_pool, ae_uuid, context_id)
+                    .await?
+                    .into()
+            }
+            CommandField::NSetRq => {
+                let n_set_rq = match NSetRq::try_from(request.command_set) {
+                    Ok(val) => val,
+                    Err(e) => {
+                        error!("N-SET-RQのパースに失敗しました: {e}");
+                        return Err(Reason::InvalidPduParameterValue);
+                    }
+                };
+                handle_n_set_rq(n_set_rq, data_set, db_pool, ae_uuid, context_id)
+                    .await?
+                    .into()
+            }
+            _ => unreachable!(), // command_fields()で宣言したコマンドのみが渡される
+        };
+
+        Ok((command_set_to_be_sent.into(), Vec::new()))
+    }
+}
+
+/// Performed Procedure Stepの保存先
+#[async_trait::async_trait]
+trait PerformedProcedureStepStore: Sync {
+    /// Performed Procedure Stepを登録する。
+    ///
+    /// # Returns
+    /// 登録した場合は`true`、同じSOPインスタンスUIDのものがすでに登録されていた場合は`false`を返す。
+    async fn insert(
+        &self,
+        sop_instance_uid: &str,
+        info: &PerformedProcedureStepInfo,
+        ae_uuid: Uuid,
+    ) -> Result<bool, String>;
+
+    /// Performed Procedure Stepを更新する。
+    /// 属性が存在しない項目は更新せず、Performed Series Sequenceが存在する場合はシリーズの一覧を置き換える。
+    async fn update(
+        &self,
+        sop_instance_uid: &str,
+        info: &PerformedProcedureStepInfo,
+        ae_uuid: Uuid,
+    ) -> Result<UpdateResult, String>;
+}
+
+#[async_trait::async_trait]
+impl PerformedProcedureStepStore for Pool<Postgres> {
+    async fn insert(
+        &self,
+        sop_instance_uid: &str,
+        info: &PerformedProcedureStepInfo,
+        ae_uuid: Uuid,
+    ) -> Result<bool, String> {
+        insert_performed_procedure_step(self, sop_instance_uid, info, ae_uuid).await
+    }
+
+    async fn update(
+        &self,
+        sop_instance_uid: &str,
+        info: &PerformedProcedureStepInfo,
+        ae_uuid: Uuid,
+    ) -> Result<UpdateResult, String> {
+        update_performed_procedure_step(self, sop_instance_uid, info, ae_uuid).await
+    }
+}
+
+/// N-CREATE-RQを処理し、N-CREATE-RSPを生成する。
+/// SCUが送信したデータが原因で作成に失敗した場合、適切なステータスを持つN-CREATE-RSPを返す。
+/// SCPの内部エラーが発生した場合、Reasonを返す。
+async fn handle_n_create_rq(
+    n_create_rq: NCreateRq,
+    data_set: DataSet,
+    store: &impl PerformedProcedureStepStore,
+    ae_uuid: Uuid,
+    context_id: u8,
+) -> Result<NCreateRsp, Reason> {
+    let message_id = n_create_rq.message_id();
+    let affected_sop_class_uid = n_create_rq.affected_sop_class_uid();
+    let rsp = |status, sop_instance_uid: &str| {
+        NCreateRsp::new(message_id, status, affected_sop_class_uid, sop_instance_uid)
+    };
+
+    // MPPSではSCUがSOPインスタンスUIDを指定する
+    // https://dicom.nema.org/medical/dicom/2025c/output/chtml/part04/sect_F.7.2.html#sect_F.7.2.1.1
+    let Some(sop_instance_uid) = n_create_rq.affected_sop_instance_uid() else {
+        warn!("N-CREATE-RQにAffected SOP Instance UIDが指定されていません");
+        return Ok(rsp(n_create_rsp::Status::InvalidObjectInstance, ""));
+    };
+
+    let info = match PerformedProcedureStepInfo::from_data_set(&data_set) {
+        Ok(val) => val,
+        Err(e) => {
+            warn!("Performed Procedure Stepの属性の抽出に失敗しました: {e}");
+            return Ok(rsp(
+                n_create_rsp::Status::InvalidAttributeValue,
+                sop_instance_uid,
+            ));
+        }
+    };
+
+    // N-CREATE時のステータスは"IN PROGRESS"でなければならない
+    match info.status() {
+        Some(ProcedureStepStatus::InProgress) => {}
+        Some(status) => {
+            warn!(
+                "N-CREATE時のPerformed Procedure Step Statusが\"IN PROGRESS\"ではありません (ステータス=\"{}\")",
+                status.code()
+            );
+            return Ok(rsp(
+                n_create_rsp::Status::InvalidAttributeValue,
+                sop_instance_uid,
+            ));
+        }
+        None => {
+            warn!("Performed Procedure Step Statusが見つかりませんでした");
+            return Ok(rsp(
+                n_create_rsp::Status::MissingAttribute,
+                sop_instance_uid,
+            ));
+        }
+    }
+    let Some(study_instance_uid) = info.study_instance_uid() else {
+        warn!("Scheduled Step Attributes SequenceにStudy Instance UIDが見つかりませんでした");
+        return Ok(rsp(
+            n_create_rsp::Status::MissingAttribute,
+            sop_instance_uid,
+        ));
+    };
+
+    match store.insert(sop_instance_uid, &info, ae_uuid).await {
+        Ok(true) => {}
+        Ok(false) => {
+            warn!(
+                "Performed Procedure Stepがすでに登録されています (SOPインスタンスUID=\"{sop_instance_uid}\")"
+            );
+            return Ok(rsp(
+                n_create_rsp::Status::DuplicateSopInstance,
+                sop_instance_uid,
+            ));
+        }
+        Err(e) => {
+            error!("データベースへの情報の保存に失敗しました: {e}");
+            return Err(Reason::ReasonNotSpecified);
+        }
+    }
+
+    info!(
+        "[{context_id}] N-CREATE - {SOP_CLASS_NAME} (SOPインスタンスUID=\"{sop_instance_uid}\", 検査インスタンスUID=\"{study_instance_uid}\", 患者ID=\"{}\", ステータス=\"{}\", シリーズ数={})",
+        info.patient_id().unwrap_or_default(),
+        ProcedureStepStatus::InProgress.code(),
+        info.performed_series().map_or(0, |s| s.len())
+    );
+
+    Ok(rsp(n_create_rsp::Status::Success, sop_instance_uid))
+}
+
+/// N-SET-RQを処理し、N-SET-RSPを生成する。
+/// SCUが送信したデータが原因で更新に失敗した場合、適切なステータスを持つN-SET-RSPを返す。
+/// SCPの内部エラーが発生した場合、Reasonを返す。
+async fn handle_n_set_rq(
+    n_set_rq: NSetRq,
+    data_set: DataSet,
+    store: &impl PerformedProcedureStepStore,
+    ae_uuid: Uuid,
+    context_id: u8,
+) -> Result<NSetRsp, Reason> {
+    let message_id = n_set_rq.message_id();
+    let sop_class_uid = n_set_rq.requested_sop_class_uid();
+    let sop_instance_uid = n_set_rq.requested_sop_instance_uid();
+    let rsp = |status| NSetRsp::new(message_id, status, sop_class_uid, sop_instance_uid);
+
+    let info = match PerformedProcedureStepInfo::from_data_set(&data_set) {
+        Ok(val) => val,
+        Err(e) => {
+            warn!("Performed Procedure Stepの属性の抽出に失敗しました: {e}");
+            return Ok(rsp(n_set_rsp::Status::InvalidAttributeValue));
+        }
+    };
+
+    let status = match store.update(sop_instance_uid, &info, ae_uuid).await {
+        Ok(UpdateResult::Updated(status)) => status,
+        Ok(UpdateResult::NotFound) => {
+            warn!(
+                "Performed Procedure Stepが見つかりませんでした (SOPインスタンスUID=\"{sop_instance_uid}\")"
+            );
+            return Ok(rsp(n_set_rsp::Status::NoSuchObjectInstance));
+        }
+        Ok(UpdateResult::AlreadyFinal(status)) => {
+            // 最終状態となったPerformed Procedure Stepは更新できない
+            // https://dicom.nema.org/medical/dicom/2025c/output/chtml/part04/sect_F.7.2.2.html#sect_F.7.2.2.4.2
+            warn!(
+                "Performed Procedure Stepはすでに\"{}\"のため更新できません (SOPインスタンスUID=\"{sop_instance_uid}\")",
+                status.code()
+            );
+            return Ok(rsp(n_set_rsp::Status::ProcessingFailure));
+        }
+        Ok(UpdateResult::MissingEndDateTime) => {
+            warn!(
+                "Performed Procedure Stepを終了するにはPerformed Procedure Step End Date/Timeが必要です (SOPインスタンスUID=\"{sop_instance_uid}\")"
+            );
+            return Ok(rsp(n_set_rsp::Status::MissingAttributeValue));
+        }
+        Err(e) => {
+            error!("データベースへの情報の保存に失敗しました: {e}");
+            return Err(Reason::ReasonNotSpecified);
+        }
+    };
+
+    info!(
+        "[{context_id}] N-SET - {SOP_CLASS_NAME} (SOPインスタンスUID=\"{sop_instance_uid}\", ステータス=\"{}\", シリーズ数={})",
+        status.code(),
+        info.performed_series()
+            .map_or("変更なし".to_string(), |s| s.len().to_string())
+    );
+
+    Ok(rsp(n_set_rsp::Status::Success))
+}
+
+/// Performed Procedure StepをDBへ登録する。
+async fn insert_performed_procedure_step(
+    db_pool: &Pool<Postgres>,
+    sop_instance_uid: &str,
+    info: &PerformedProcedureStepInfo,
+    ae_uuid: Uuid,
+) -> Result<bool, String> {
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .map_err(|e| format!("トランザクションの開始に失敗しました: {e}"))?;
+
+    let rows_affected = query!(
+        r#"
+        INSERT INTO performed_procedure_steps (instance_uid, study_instance_uid, patient_id, accession_number, id, status, modality, description, station_ae_title, start_date, start_time, end_date, end_time, created_by, created_at, updated_by, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, now(), $14, now())
+        ON CONFLICT (instance_uid) DO NOTHING
+        "#,
+        sop_instance_uid,
+        info.study_instance_uid().unwrap_or_default(),
+        info.patient_id().unwrap_or_default(),
+        info.accession_number().unwrap_or_default(),
+        info.id().unwrap_or_default(),
+        ProcedureStepStatus::InProgress.as_i16(),
+        info.modality().unwrap_or_default(),
+        info.description().unwrap_or_default(),
+        info.station_ae_title().unwrap_or_default(),
+        info.start_date(),
+        info.start_time(),
+        info.end_date(),
+        info.end_time(),
+        ae_uuid,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("Performed Procedure Stepの保存に失敗しました: {e}"))?
+    .rows_affected();
+    if rows_affected == 0 {
+        return Ok(false);
+    }
+
+    if let Some(performed_series) = info.performed_series() {
+        insert_performed_series(
+            &mut transaction,
+            sop_instance_uid,
+            performed_series,
+            ae_uuid,
+        )
+        .await?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("トランザクションのコミットに失敗しました: {e}"))?;
+
+    Ok(true)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum UpdateResult {
+    /// 更新に成功した（値は更新後のステータス）
+    Updated(ProcedureStepStatus),
+    /// 対象のPerformed Procedure Stepが存在しない
+    NotFound,
+    /// 対象のPerformed Procedure Stepがすでに最終状態となっている
+    AlreadyFinal(ProcedureStepStatus),
+    /// 最終状態へ更新しようとしたが、終了日時が存在しない
+    MissingEndDateTime,
+}
+
+/// DBのPerformed Procedure Stepを更新する。
+async fn update_performed_procedure_step(
+    db_pool: &Pool<Postgres>,
+    sop_instance_uid: &str,
+    info: &PerformedProcedureStepInfo,
+    ae_uuid: Uuid,
+) -> Result<UpdateResult, String> {
+    let mut transaction = db_pool
+        .begin()
+        .await
+        .map_err(|e| format!("トランザクションの開始に失敗しました: {e}"))?;
+
+    let Some(current) = query!(
+        "SELECT status, end_date, end_time FROM performed_procedure_steps WHERE instance_uid = $1 FOR UPDATE",
+        sop_instance_uid
+    )
+    .fetch_optional(&mut *transaction)
+    .await
+    .map_err(|e| format!("Performed Procedure Stepの取得に失敗しました: {e}"))?
+    else {
+        return Ok(UpdateResult::NotFound);
+    };
+
+    let current_status = ProcedureStepStatus::from_i16(current.status)?;
+    let status = match decide_status(
+        current_status,
+        current.end_date.is_some(),
+        current.end_time.is_some(),
+        info,
+    ) {
+        UpdateResult::Updated(status) => status,
+        result => return Ok(result),
+    };
+
+    query!(
+        r#"
+        UPDATE performed_procedure_steps SET
+            id = COALESCE($2, id),
+            status = $3,
+            modality = COALESCE($4, modality),
+            description = COALESCE($5, description),
+            station_ae_title = COALESCE($6, station_ae_title),
+            start_date = COALESCE($7, start_date),
+            start_time = COALESCE($8, start_time),
+            end_date = COALESCE($9, end_date),
+            end_time = COALESCE($10, end_time),
+            updated_by = $11,
+            updated_at = now()
+        WHERE instance_uid = $1
+        "#,
+        sop_instance_uid,
+        info.id(),
+        status.as_i16(),
+        info.modality(),
+        info.description(),
+        info.station_ae_title(),
+        info.start_date(),
+        info.start_time(),
+        info.end_date(),
+        info.end_time(),
+        ae_uuid,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("Performed Procedure Stepの更新に失敗しました: {e}"))?;
+
+    if let Some(performed_series) = info.performed_series() {
+        query!(
+            "DELETE FROM performed_procedure_step_series WHERE performed_procedure_step_instance_uid = $1",
+            sop_instance_uid
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| format!("Performed Series Sequenceの削除に失敗しました: {e}"))?;
+
+        insert_performed_series(
+            &mut transaction,
+            sop_instance_uid,
+            performed_series,
+            ae_uuid,
+        )
+        .await?;
+    }
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("トランザクションのコミットに失敗しました: {e}"))?;
+
+    Ok(UpdateResult::Updated(status))
+}
+
+/// 現在のステータスとN-SETで受信した属性から、更新後のステータスを決定する。
+/// 更新できない場合は、その理由を返す。
+fn decide_status(
+    current_status: ProcedureStepStatus,
+    has_end_date: bool,
+    has_end_time: bool,
+    info: &PerformedProcedureStepInfo,
+) -> UpdateResult {
+    if current_status.is_final() {
+        return UpdateResult::AlreadyFinal(current_status);
+    }
+
+    let status = info.status().unwrap_or(current_status);
+    if status.is_final()
+        && (info.end_date().is_none() && !has_end_date
+            || info.end_time().is_none() && !has_end_time)
+    {
+        return UpdateResult::MissingEndDateTime;
+    }
+
+    UpdateResult::Updated(status)
+}
+
+async fn insert_performed_series(
+    transaction: &mut Transaction<'_, Postgres>,
+    sop_instance_uid: &str,
+    performed_series: &[PerformedSeries],
+    ae_uuid: Uuid,
+) -> Result<(), String> {
+    for series in performed_series {
+        query!(
+            r#"
+            INSERT INTO performed_procedure_step_series (performed_procedure_step_instance_uid, series_instance_uid, description, retrieve_ae_title, number_of_instances, created_by, created_at, updated_by, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, now(), $6, now())
+            ON CONFLICT (performed_procedure_step_instance_uid, series_instance_uid) DO UPDATE SET
+                description = EXCLUDED.description,
+                retrieve_ae_title = EXCLUDED.retrieve_ae_title,
+                number_of_instances = EXCLUDED.number_of_instances,
+                updated_by = EXCLUDED.updated_by,
+                updated_at = EXCLUDED.updated_at
+            "#, // 同一シリーズが複数の項目に含まれる場合は後の項目を優先する
+            sop_instance_uid,
+            series.instance_uid(),
+            series.description(),
+            series.retrieve_ae_title(),
+            series.number_of_instances(),
+            ae_uuid,
+        )
+        .execute(&mut **transaction)
+        .await
+        .map_err(|e| format!("Performed Series Sequenceの保存に失敗しました: {e}"))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_lib::core::{Encoding, Tag, data_element::Vr};
+    use std::{collections::HashMap, sync::Mutex};
+
+    const SOP_INSTANCE_UID: &str = "1.2.392.200036.9116.2.6.1.48.2000";
+
+    /// 登録したPerformed Procedure Step
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct TestStep {
+        status: ProcedureStepStatus,
+        has_end_date: bool,
+        has_end_time: bool,
+        /// シリーズインスタンスUIDと参照するインスタンス数
+        series: Vec<(String, i32)>,
+    }
+
+    /// Performed Procedure Stepをメモリ上に保存する
+    #[derive(Default)]
+    struct TestStore {
+        steps: Mutex<HashMap<String, TestStep>>,
+    }
+
+    impl TestStore {
+        fn with_step(sop_instance_uid: &str, step: TestStep) -> Self {
+            let store = Self::default();
+            store
+                .steps
+                .lock()
+                .unwrap()
+                .insert(sop_instance_uid.to_string(), step);
+            store
+        }
+
+        fn step(&self, sop_instance_uid: &str) -> Option<TestStep> {
+            self.steps.lock().unwrap().get(sop_instance_uid).cloned()
+        }
+    }
+
+    fn series(info: &PerformedProcedureStepInfo) -> Option<Vec<(String, i32)>> {
+        info.performed_series().map(|performed_series| {
+            performed_series
+                .iter()
+                .map(|s| (s.instance_uid().to_string(), s.number_of_instances()))
+                .collect()
+        })
+    }
+
+    #[async_trait::async_trait]
+    impl PerformedProcedureStepStore for TestStore {
+        async fn insert(
+            &self,
+            sop_instance_uid: &str,
+            info: &PerformedProcedureStepInfo,
+            _ae_uuid: Uuid,
+        ) -> Result<bool, String> {
+            let mut steps = self.steps.lock().unwrap();
+            if steps.contains_key(sop_instance_uid) {
+                return Ok(false);
+            }
+            steps.insert(
+                sop_instance_uid.to_string(),
+                TestStep {
+                    status: ProcedureStepStatus::InProgress,
+                    has_end_date: info.end_date().is_some(),
+                    has_end_time: info.end_time().is_some(),
+                    series: series(info).unwrap_or_default(),
+                },
+            );
+            Ok(true)
+        }
+
+        async fn update(
+            &self,
+            sop_instance_uid: &str,
+            info: &PerformedProcedureStepInfo,
+            _ae_uuid: Uuid,
+        ) -> Result<UpdateResult, String> {
+            let mut steps = self.steps.lock().unwrap();
+            let Some(step) = steps.get_mut(sop_instance_uid) else {
+                return Ok(UpdateResult::NotFound);
+            };
+            let status =
+                match decide_status(step.status, step.has_end_date, step.has_end_time, info) {
+                    UpdateResult::Updated(status) => status,
+                    result => return Ok(result),
+                };
+            step.status = status;
+            step.has_end_date |= info.end_date().is_some();
+            step.has_end_time |= info.end_time().is_some();
+            if let Some(series) = series(info) {
+                step.series = series;
+            }
+            Ok(UpdateResult::Updated(status))
+        }
+    }
+
+    /// 値フィールドを偶数長になるようパディングする。
+    fn padded(value: &str, padding: u8) -> Vec<u8> {
+        let mut value_field = value.as_bytes().to_vec();
+        if !value_field.len().is_multiple_of(2) {
+            value_field.push(padding);
+        }
+        value_field
+    }
+
+    /// N-CREATE/N-SETで送信するデータセットを生成する。
+    /// `performed_series`はシリーズインスタンスUIDと参照するインスタンス数を表す。
+    fn mpps_data_set(
+        status: Option<&str>,
+        study_instance_uid: Option<&str>,
+        end_date_time: Option<(&str, &str)>,
+        performed_series: Option<&[(&str, usize)]>,
+    ) -> DataSet {
+        let encoding = Encoding::ExplicitVrLittleEndian;
+        let mut data_set = DataSet::new(encoding);
+        if let Some(study_instance_uid) = study_instance_uid {
+            let mut item = DataSet::new(encoding);
+            item.set_element(Tag(0x0020, 0x000d), Vr::Ui, padded(study_instance_uid, 0));
+            data_set.push_item(Tag(0x0040, 0x0270), item);
+        }
+        if let Some((end_date, end_time)) = end_date_time {
+            data_set.set_element(Tag(0x0040, 0x0250), Vr::Da, padded(end_date, b' '));
+            data_set.set_element(Tag(0x0040, 0x0251), Vr::Tm, padded(end_time, b' '));
+        }
+        if let Some(status) = status {
+            data_set.set_element(Tag(0x0040, 0x0252), Vr::Cs, padded(status, b' '));
+        }
+        if let Some(performed_series) = performed_series {
+            // 空のシーケンスも送信できるよう、アイテムを追加する前にシーケンスを設定する
+            data_set.set_element(Tag(0x0040, 0x0340), Vr::Sq, vec![]);
+            for (series_instance_uid, number_of_instances) in performed_series {
+                let mut item = DataSet::new(encoding);
+                item.set_element(Tag(0x0020, 0x000e), Vr::Ui, padded(series_instance_uid, 0));
+                for i in 0..*number_of_instances {
+                    let mut image = DataSet::new(encoding);
+                    image.set_element(
+                        Tag(0x0008, 0x1155),
+                        Vr::Ui,
+                        padded(&format!("{series_instance_uid}.{}", i + 1), 0),
+                    );
+                    item.push_item(Tag(0x0008, 0x1140), image);
+                }
+                data_set.push_item(Tag(0x0040, 0x0340), item);
+            }
+        }
+        data_set
+    }
+
+    fn in_progress_step() -> TestStep {
+        TestStep {
+            status: ProcedureStepStatus::InProgress,
+            has_end_date: false,
+            has_end_time: false,
+            series: vec![],
+        }
+    }
+
+    async fn n_create(
+        store: &TestStore,
+        sop_instance_uid: Option<&str>,
+        data_set: DataSet,
+    ) -> Result<NCreateRsp, String> {
+        let n_create_rq = NCreateRq::new(
+            MODALITY_PERFORMED_PROCEDURE_STEP,
+            1,
+            sop_instance_uid.map(str::to_string),
+        )
+        .with_data_set();
+        let rsp = handle_n_create_rq(n_create_rq, data_set, store, Uuid::nil(), 1)
+            .await
+            .unwrap_or_else(|_| panic!("SCPの内部エラーは発生しないはず"));
+        // 送信するコマンドセットとして読み直す
+        NCreateRsp::try_from(CommandSet::from(rsp))
+    }
+
+    async fn n_set(store: &TestStore, data_set: DataSet) -> Result<NSetRsp, String> {
+        let n_set_rq = NSetRq::new(MODALITY_PERFORMED_PROCEDURE_STEP, 2, SOP_INSTANCE_UID);
+        let rsp = handle_n_set_rq(n_set_rq, data_set, store, Uuid::nil(), 1)
+            .await
+            .unwrap_or_else(|_| panic!("SCPの内部エラーは発生しないはず"));
+        NSetRsp::try_from(CommandSet::from(rsp))
+    }
+
+    #[tokio::test]
+    async fn test_handle_n_create_rq() {
+        // Arrange
+        let store = TestStore::default();
+        let data_set = mpps_data_set(
+            Some("IN PROGRESS"),
+            Some("1.2.392.200036.9116.2.6.1.48.1000"),
+            None,
+            Some(&[("1.2.392.200036.9116.2.6.1.48.1000.1", 2)]),
+        );
+
+        // Act
+        let actual = n_create(&store, Some(SOP_INSTANCE_UID), data_set).await;
+
+        // Assert
+        assert_eq!(
+            actual,
+            Ok(NCreateRsp::new(
+                1,
+                n_create_rsp::Status::Success,
+                MODALITY_PERFORMED_PROCEDURE_STEP,
+                SOP_INSTANCE_UID
+            ))
+        );
+        // 参照するシリーズを登録する
+        assert_eq!(
+            store.step(SOP_INSTANCE_UID),
+            Some(TestStep {
+                series: vec![("1.2.392.200036.9116.2.6.1.48.1000.1".to_string(), 2)],
+                ..in_progress_step()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_n_create_rq_failure() {
+        let study_instance_uid = Some("1.2.392.200036.9116.2.6.1.48.1000");
+
+        // 準正常系: すでに登録されているSOPインスタンスUID
+        {
+            // Arrange
+            let store = TestStore::with_step(SOP_INSTANCE_UID, in_progress_step());
+            let data_set = mpps_data_set(Some("IN PROGRESS"), study_instance_uid, None, None);
+
+            // Act
+            let actual = n_create(&store, Some(SOP_INSTANCE_UID), data_set).await;
+
+            // Assert
+            assert_eq!(
+                actual.map(|rsp| (rsp.status(), rsp.affected_sop_instance_uid().to_string())),
+                Ok((
+                    n_create_rsp::Status::DuplicateSopInstance,
+                    SOP_INSTANCE_UID.to_string()
+                ))
+            );
+            assert_eq!(store.step(SOP_INSTANCE_UID), Some(in_progress_step()));
+        }
+
+        // 準正常系: SOPインスタンスUIDが指定されていない
+        {
+            // Arrange
+            let store = TestStore::default();
+            let data_set = mpps_data_set(Some("IN PROGRESS"), study_instance_uid, None, None);
+
+            // Act
+            let actual = n_create(&store, None, data_set).await;
+
+            // Assert
+            assert_eq!(
+                actual.map(|rsp| rsp.status()),
+                Ok(n_create_rsp::Status::InvalidObjectInstance)
+            );
+            assert!(store.steps.lock().unwrap().is_empty());
+        }
+
+        // 準正常系: ステータスが"IN PROGRESS"でない、またはステータス・検査インスタンスUIDが存在しない
+        for (data_set, expected) in [
+            (
+                mpps_data_set(
+                    Some("COMPLETED"),
+                    study_instance_uid,
+                    Some(("20260201", "103000")),
+                    None,
+                ),
+                n_create_rsp::Status::InvalidAttributeValue,
+            ),
+            (
+                mpps_data_set(None, study_instance_uid, None, None),
+                n_create_rsp::Status::MissingAttribute,
+            ),
+            (
+                mpps_data_set(Some("IN PROGRESS"), None, None, None),
+                n_create_rsp::Status::MissingAttribute,
+            ),
+        ] {
+            // Arrange
+            let store = TestStore::default();
+
+            // Act
+            let actual = n_create(&store, Some(SOP_INSTANCE_UID), data_set).await;
+
+            // Assert
+            assert_eq!(actual.map(|rsp| rsp.status()), Ok(expected));
+            assert_eq!(store.step(SOP_INSTANCE_UID), None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_n_set_rq() {
+        // 正常系: 参照するシリーズを置き換える
+        {
+            // Arrange
+            let store = TestStore::with_step(
+                SOP_INSTANCE_UID,
+                TestStep {
+                    series: vec![("1.2.392.200036.9116.2.6.1.48.1000.1".to_string(), 2)],
+                    ..in_progress_step()
+                },
+            );
+            let data_set = mpps_data_set(
+                None,
+                None,
+                None,
+                Some(&[
+                    ("1.2.392.200036.9116.2.6.1.48.1000.2", 1),
+                    ("1.2.392.200036.9116.2.6.1.48.1000.3", 3),
+                ]),
+            );
+
+            // Act
+            let actual = n_set(&store, data_set).await;
+
+            // Assert
+            assert_eq!(
+                actual,
+                Ok(NSetRsp::new(
+                    2,
+                    n_set_rsp::Status::Success,
+                    MODALITY_PERFORMED_PROCEDURE_STEP,
+                    SOP_INSTANCE_UID
+                ))
+            );
+            assert_eq!(
+                store.step(SOP_INSTANCE_UID),
+                Some(TestStep {
+                    series: vec![
+                        ("1.2.392.200036.9116.2.6.1.48.1000.2".to_string(), 1),
+                        ("1.2.392.200036.9116.2.6.1.48.1000.3".to_string(), 3),
+                    ],
+                    ..in_progress_step()
+                })
+            );
+        }
+
+        // 正常系: 終了日時とともに"COMPLETED"に更新する
+        {
+            // Arrange
+            let store = TestStore::with_step(SOP_INSTANCE_UID, in_progress_step());
+            let data_set =
+                mpps_data_set(Some("COMPLETED"), None, Some(("20260201", "103000")), None);
+
+            // Act
+            let actual = n_set(&store, data_set).await;
+
+            // Assert
+            assert_eq!(
+                actual.map(|rsp| rsp.status()),
+                Ok(n_set_rsp::Status::Success)
+            );
+            assert_eq!(
+                store.step(SOP_INSTANCE_UID),
+                Some(TestStep {
+                    status: ProcedureStepStatus::Completed,
+                    has_end_date: true,
+                    has_end_time: true,
+                    series: vec![],
+                })
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_n_set_rq_failure() {
+        // 準正常系: 最終状態となったPerformed Procedure Stepは更新できない
+        for status in [
+            ProcedureStepStatus::Completed,
+            ProcedureStepStatus::Discontinued,
+        ] {
+            // Arrange
+            let step = TestStep {
+                status,
+                has_end_date: true,
+                has_end_time: true,
+                series: vec![],
+            };
+            let store = TestStore::with_step(SOP_INSTANCE_UID, step.clone());
+            let data_set = mpps_data_set(
+                Some("IN PROGRESS"),
+                None,
+                None,
+                Some(&[("1.2.392.200036.9116.2.6.1.48.1000.1", 1)]),
+            );
+
+            // Act
+            let actual = n_set(&store, data_set).await;
+
+            // Assert
+            assert_eq!(
+                actual,
+                Ok(NSetRsp::new(
+                    2,
+                    n_set_rsp::Status::ProcessingFailure,
+                    MODALITY_PERFORMED_PROCEDURE_STEP,
+                    SOP_INSTANCE_UID
+                )),
+                "{}",
+                status.code()
+            );
+            assert_eq!(
+                store.step(SOP_INSTANCE_UID),
+                Some(step),
+                "{}",
+                status.code()
+            );
+        }
+
+        // 準正常系: 存在しないSOPインスタンス
+        {
+            // Arrange
+            let store = TestStore::default();
+            let data_set = mpps_data_set(Some("IN PROGRESS"), None, None, None);
+
+            // Act
+            let actual = n_set(&store, data_set).await;
+
+            // Assert
+            assert_eq!(
+                actual.map(|rsp| rsp.status()),
+                Ok(n_set_rsp::Status::NoSuchObjectInstance)
+            );
+        }
+
+        // 準正常系: 終了日時なしで最終状態に更新する
+        {
+            // Arrange
+            let store = TestStore::with_step(SOP_INSTANCE_UID, in_progress_step());
+            let data_set = mpps_data_set(Some("DISCONTINUED"), None, None, None);
+
+            // Act
+            let actual = n_set(&store, data_set).await;
+
+            // Assert
+            assert_eq!(
+                actual.map(|rsp| rsp.status()),
+                Ok(n_set_rsp::Status::MissingAttributeValue)
+            );
+            assert_eq!(store.step(SOP_INSTANCE_UID), Some(in_progress_step()));
+        }
+    }
+
+    /// N-SETで受信するデータセットからPerformed Procedure Stepの属性を抽出する。
+    fn n_set_info(
+        status: Option<&str>,
+        end_date_time: Option<(&str, &str)>,
+    ) -> PerformedProcedureStepInfo {
+        let data_set = mpps_data_set(status, None, end_date_time, None);
+        PerformedProcedureStepInfo::from_data_set(&data_set).unwrap()
+    }
+
+    #[test]
+    fn test_decide_status_in_progress() {
+        // Arrange
+        let info = n_set_info(None, None);
+
+        // Act
+        let actual = decide_status(ProcedureStepStatus::InProgress, false, false, &info);
+
+        // Assert
+        // ステータスが送信されない場合は、現在のステータスを維持する
+        assert_eq!(
+            actual,
+            UpdateResult::Updated(ProcedureStepStatus::InProgress)
+        );
+    }
+
+    #[test]
+    fn test_decide_status_final() {
+        for (code, expected) in [
+            ("COMPLETED", ProcedureStepStatus::Completed),
+            ("DISCONTINUED", ProcedureStepStatus::Discontinued),
+        ] {
+            // Arrange
+            let info = n_set_info(Some(code), Some(("20260201", "103000")));
+
+            // Act
+            let actual = decide_status(ProcedureStepStatus::InProgress, false, false, &info);
+
+            // Assert
+            assert_eq!(actual, UpdateResult::Updated(expected), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_decide_status_final_with_stored_end_date_time() {
+        // Arrange
+        // 終了日時は以前のN-SETで送信済み
+        let info = n_set_info(Some("COMPLETED"), None);
+
+        // Act
+        let actual = decide_status(ProcedureStepStatus::InProgress, true, true, &info);
+
+        // Assert
+        assert_eq!(
+            actual,
+            UpdateResult::Updated(ProcedureStepStatus::Completed)
+        );
+    }
+
+    #[test]
+    fn test_decide_status_missing_end_date_time() {
+        // Arrange
+        let info = n_set_info(Some("DISCONTINUED"), None);
+
+        // Act & Assert
+        assert_eq!(
+            decide_status(ProcedureStepStatus::InProgress, false, false, &info),
+            UpdateResult::MissingEndDateTime
+        );
+        assert_eq!(
+            decide_status(ProcedureStepStatus::InProgress, true, false, &info),
+            UpdateResult::MissingEndDateTime
+        );
+    }
+
+    #[test]
+    fn test_decide_status_already_final() {
+        for current_status in [
+            ProcedureStepStatus::Completed,
+            ProcedureStepStatus::Discontinued,
+        ] {
+            for info in [
+                n_set_info(None, None),
+                n_set_info(Some("IN PROGRESS"), None),
+                n_set_info(Some("COMPLETED"), Some(("20260201", "103000"))),
+            ] {
+                // Act
+                let actual = decide_status(current_status, true, true, &info);
+
+                // Assert
+                // 最終状態となったPerformed Procedure Stepは更新できない
+                assert_eq!(
+                    actual,
+                    UpdateResult::AlreadyFinal(current_status),
+                    "{}",
+                    current_status.code()
+                );
+            }
+        }
+    }
+}
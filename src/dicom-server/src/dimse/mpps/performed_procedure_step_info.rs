@@ -0,0 +1,376 @@
+use chrono::{NaiveDate, NaiveTime};
+use dicom_lib::core::{
+    DataSet, Tag,
+    value::{
+        SpecificCharacterSet,
+        value_representations::{
+            ae::AeValue, cs::CsValue, da::DaValue, lo::LoValue, sh::ShValue, tm::TmValue,
+            ui::UiValue,
+        },
+    },
+};
+
+const ITEM_TAG: Tag = Tag(0xfffe, 0xe000);
+
+/// Performed Procedure Step Status (0040,0252)
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part03/sect_C.4.14.html>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcedureStepStatus {
+    InProgress = 0,
+    Completed = 1,
+    Discontinued = 2,
+}
+
+impl ProcedureStepStatus {
+    pub fn from_code(code: &str) -> Result<Self, String> {
+        match code {
+            "IN PROGRESS" => Ok(Self::InProgress),
+            "COMPLETED" => Ok(Self::Completed),
+            "DISCONTINUED" => Ok(Self::Discontinued),
+            _ => Err(format!("不正なステータスです: {code}")),
+        }
+    }
+
+    pub fn from_i16(value: i16) -> Result<Self, String> {
+        match value {
+            0 => Ok(Self::InProgress),
+            1 => Ok(Self::Completed),
+            2 => Ok(Self::Discontinued),
+            _ => Err(format!("不正なステータスです: {value}")),
+        }
+    }
+
+    pub fn as_i16(&self) -> i16 {
+        *self as i16
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InProgress => "IN PROGRESS",
+            Self::Completed => "COMPLETED",
+            Self::Discontinued => "DISCONTINUED",
+        }
+    }
+
+    /// 最終状態（これ以上更新できない状態）かどうか
+    pub fn is_final(&self) -> bool {
+        !matches!(self, Self::InProgress)
+    }
+}
+
+/// Performed Series Sequence (0040,0340) の項目
+pub struct PerformedSeries {
+    instance_uid: UiValue,
+    description: String,
+    retrieve_ae_title: String,
+    number_of_instances: i32,
+}
+
+impl PerformedSeries {
+    pub fn instance_uid(&self) -> &str {
+        self.instance_uid.uid()
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn retrieve_ae_title(&self) -> &str {
+        &self.retrieve_ae_title
+    }
+
+    pub fn number_of_instances(&self) -> i32 {
+        self.number_of_instances
+    }
+}
+
+/// N-CREATE/N-SETで受信したModality Performed Procedure Stepの属性
+///
+/// N-SETでは変更対象の属性のみが送信されるため、各属性は存在しない可能性がある。
+/// なお、値が空の属性は存在しないものとして扱う。
+///
+/// # 参考リンク
+/// - <https://dicom.nema.org/medical/dicom/2025c/output/chtml/part04/sect_F.7.2.html>
+#[derive(Default)]
+pub struct PerformedProcedureStepInfo {
+    study_instance_uid: Option<UiValue>,
+    accession_number: Option<ShValue>,
+    patient_id: Option<LoValue>,
+    id: Option<ShValue>,
+    status: Option<ProcedureStepStatus>,
+    modality: Option<CsValue>,
+    description: Option<LoValue>,
+    station_ae_title: Option<AeValue>,
+    start_date: Option<DaValue>,
+    start_time: Option<TmValue>,
+    end_date: Option<DaValue>,
+    end_time: Option<TmValue>,
+    performed_series: Option<Vec<PerformedSeries>>,
+}
+
+impl PerformedProcedureStepInfo {
+    pub fn study_instance_uid(&self) -> Option<&str> {
+        self.study_instance_uid.as_ref().map(|v| v.uid())
+    }
+
+    pub fn accession_number(&self) -> Option<&str> {
+        self.accession_number.as_ref().map(|v| v.string())
+    }
+
+    pub fn patient_id(&self) -> Option<&str> {
+        self.patient_id.as_ref().map(|v| v.string())
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_ref().map(|v| v.string())
+    }
+
+    pub fn status(&self) -> Option<ProcedureStepStatus> {
+        self.status
+    }
+
+    pub fn modality(&self) -> Option<&str> {
+        self.modality.as_ref().map(|v| v.code())
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_ref().map(|v| v.string())
+    }
+
+    pub fn station_ae_title(&self) -> Option<&str> {
+        self.station_ae_title.as_ref().map(|v| v.value())
+    }
+
+    pub fn start_date(&self) -> Option<&NaiveDate> {
+        self.start_date.as_ref().map(|v| v.date())
+    }
+
+    pub fn start_time(&self) -> Option<&NaiveTime> {
+        self.start_time.as_ref().map(|v| v.time())
+    }
+
+    pub fn end_date(&self) -> Option<&NaiveDate> {
+        self.end_date.as_ref().map(|v| v.date())
+    }
+
+    pub fn end_time(&self) -> Option<&NaiveTime> {
+        self.end_time.as_ref().map(|v| v.time())
+    }
+
+    pub fn performed_series(&self) -> Option<&[PerformedSeries]> {
+        self.performed_series.as_deref()
+    }
+
+    pub fn from_data_set(data_set: &DataSet) -> Result<Self, String> {
+        let mut char_set = SpecificCharacterSet::None;
+        let mut info = Self::default();
+
+        for i in child_indices(data_set, None) {
+            let element = &data_set[i];
+            let value_field = element.value_field();
+
+            match element.tag() {
+                Tag(0x0008, 0x0005) if !value_field.is_empty() => {
+                    char_set = SpecificCharacterSet::try_from(value_field).map_err(|e| {
+                        format!("Specific Character Setのパースに失敗しました: {e}")
+                    })?;
+                }
+
+                Tag(0x0008, 0x0060) if !value_field.is_empty() => {
+                    info.modality = Some(
+                        CsValue::from_bytes(value_field)
+                            .map_err(|e| format!("Modalityのパースに失敗しました: {e}"))?,
+                    );
+                }
+
+                Tag(0x0010, 0x0020) if !value_field.is_empty() => {
+                    info.patient_id = Some(
+                        LoValue::from_bytes_lossy(value_field, char_set)
+                            .map_err(|e| format!("Patient IDのパースに失敗しました: {e}"))?,
+                    );
+                }
+
+                // Scheduled Step Attributes Sequence
+                Tag(0x0040, 0x0270) => {
+                    // 複数の項目が含まれる場合、検査の紐付けには最初の項目を使用する
+                    let Some(item_index) = item_indices(data_set, i).into_iter().next() else {
+                        continue;
+                    };
+                    for j in child_indices(data_set, Some(item_index)) {
+                        let element = &data_set[j];
+                        let value_field = element.value_field();
+                        if value_field.is_empty() {
+                            continue;
+                        }
+                        match element.tag() {
+                            Tag(0x0020, 0x000d) => {
+                                info.study_instance_uid =
+                                    Some(UiValue::from_bytes(value_field).map_err(|e| {
+                                        format!("Study Instance UIDのパースに失敗しました: {e}")
+                                    })?);
+                            }
+                            Tag(0x0008, 0x0050) => {
+                                info.accession_number = Some(
+                                    ShValue::from_bytes_lossy(value_field, char_set).map_err(
+                                        |e| format!("Accession Numberのパースに失敗しました: {e}"),
+                                    )?,
+                                );
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                Tag(0x0040, 0x0241) if !value_field.is_empty() => {
+                    info.station_ae_title =
+                        Some(AeValue::from_bytes(value_field).map_err(|e| {
+                            format!("Performed Station AE Titleのパースに失敗しました: {e}")
+                        })?);
+                }
+
+                Tag(0x0040, 0x0244) if !value_field.is_empty() => {
+                    info.start_date = Some(DaValue::from_bytes(value_field).map_err(|e| {
+                        format!("Performed Procedure Step Start Dateのパースに失敗しました: {e}")
+                    })?);
+                }
+
+                Tag(0x0040, 0x0245) if !value_field.is_empty() => {
+                    info.start_time = Some(TmValue::from_bytes(value_field).map_err(|e| {
+                        format!("Performed Procedure Step Start Timeのパースに失敗しました: {e}")
+                    })?);
+                }
+
+                Tag(0x0040, 0x0250) if !value_field.is_empty() => {
+                    info.end_date = Some(DaValue::from_bytes(value_field).map_err(|e| {
+                        format!("Performed Procedure Step End Dateのパースに失敗しました: {e}")
+                    })?);
+                }
+
+                Tag(0x0040, 0x0251) if !value_field.is_empty() => {
+                    info.end_time = Some(TmValue::from_bytes(value_field).map_err(|e| {
+                        format!("Performed Procedure Step End Timeのパースに失敗しました: {e}")
+                    })?);
+                }
+
+                Tag(0x0040, 0x0252) if !value_field.is_empty() => {
+                    let code = CsValue::from_bytes(value_field).map_err(|e| {
+                        format!("Performed Procedure Step Statusのパースに失敗しました: {e}")
+                    })?;
+                    info.status =
+                        Some(ProcedureStepStatus::from_code(code.code()).map_err(|e| {
+                            format!("Performed Procedure Step Statusのパースに失敗しました: {e}")
+                        })?);
+                }
+
+                Tag(0x0040, 0x0253) if !value_field.is_empty() => {
+                    info.id = Some(ShValue::from_bytes_lossy(value_field, char_set).map_err(
+                        |e| format!("Performed Procedure Step IDのパースに失敗しました: {e}"),
+                    )?);
+                }
+
+                Tag(0x0040, 0x0254) if !value_field.is_empty() => {
+                    info.description = Some(
+                        LoValue::from_bytes_lossy(value_field, char_set).map_err(|e| {
+                            format!(
+                                "Performed Procedure Step Descriptionのパースに失敗しました: {e}"
+                            )
+                        })?,
+                    );
+                }
+
+                // Performed Series Sequence
+                Tag(0x0040, 0x0340) => {
+                    let mut performed_series = vec![];
+                    for item_index in item_indices(data_set, i) {
+                        performed_series
+                            .push(parse_performed_series(data_set, item_index, char_set)?);
+                    }
+                    info.performed_series = Some(performed_series);
+                }
+
+                _ => {}
+            }
+        }
+
+        Ok(info)
+    }
+}
+
+fn parse_performed_series(
+    data_set: &DataSet,
+    item_index: usize,
+    char_set: SpecificCharacterSet,
+) -> Result<PerformedSeries, String> {
+    let mut instance_uid = None;
+    let mut description = None;
+    let mut retrieve_ae_title = None;
+    let mut number_of_instances = 0;
+
+    for j in child_indices(data_set, Some(item_index)) {
+        let element = &data_set[j];
+        let value_field = element.value_field();
+
+        match element.tag() {
+            Tag(0x0020, 0x000e) if !value_field.is_empty() => {
+                instance_uid = Some(
+                    UiValue::from_bytes(value_field)
+                        .map_err(|e| format!("Series Instance UIDのパースに失敗しました: {e}"))?,
+                );
+            }
+            Tag(0x0008, 0x103e) if !value_field.is_empty() => {
+                description = Some(
+                    LoValue::from_bytes_lossy(value_field, char_set)
+                        .map_err(|e| format!("Series Descriptionのパースに失敗しました: {e}"))?,
+                );
+            }
+            Tag(0x0008, 0x0054) if !value_field.is_empty() => {
+                retrieve_ae_title = Some(
+                    AeValue::from_bytes(value_field)
+                        .map_err(|e| format!("Retrieve AE Titleのパースに失敗しました: {e}"))?,
+                );
+            }
+            // Referenced Image Sequence / Referenced Non-Image Composite SOP Instance Sequence
+            Tag(0x0008, 0x1140) | Tag(0x0040, 0x0220) => {
+                number_of_instances += item_indices(data_set, j).len() as i32;
+            }
+            _ => {}
+        }
+    }
+
+    let instance_uid = instance_uid.ok_or(
+        "Performed Series SequenceにSeries Instance UIDが見つかりませんでした".to_string(),
+    )?;
+
+    Ok(PerformedSeries {
+        instance_uid,
+        description: description
+            .map(|v| v.string().to_string())
+            .unwrap_or_default(),
+        retrieve_ae_title: retrieve_ae_title
+            .map(|v| v.value().to_string())
+            .unwrap_or_default(),
+        number_of_instances,
+    })
+}
+
+/// 指定したデータ要素（`None`の場合はデータセットのトップレベル）の直下にあるデータ要素のインデックスを返す
+fn child_indices(data_set: &DataSet, parent_index: Option<usize>) -> Vec<usize> {
+    let range = match parent_index {
+        Some(index) => index + 1..index + 1 + data_set.get_descendants_count(index),
+        None => 0..data_set.len(),
+    };
+
+    range
+        .filter(|&i| data_set.get_parent_index(i) == parent_index)
+        .collect()
+}
+
+/// 指定したシーケンスに含まれる項目のインデックスを返す
+fn item_indices(data_set: &DataSet, sequence_index: usize) -> Vec<usize> {
+    child_indices(data_set, Some(sequence_index))
+        .into_iter()
+        .filter(|&i| data_set[i].tag() == ITEM_TAG)
+        .collect()
+}
@@ -0,0 +1,36 @@
+use crate::context::{AssociationContext, ServerContext};
+use dicom_lib::{
+    core::DataSet,
+    network::{CommandSet, dimse::enums::CommandField, upper_layer_protocol::pdu::a_abort::Reason},
+};
+
+/// サービスハンドラーへ渡すDIMSEメッセージ
+pub struct ServiceRequest {
+    pub context_id: u8,
+    pub transfer_syntax_uid: &'static str,
+    pub command_field: CommandField,
+    pub command_set: CommandSet,
+    /// コマンドがデータセットを伴わない場合は`None`
+    pub data_set: Option<DataSet>,
+}
+
+/// SOPクラスごとのサービスを処理するハンドラー
+///
+/// ハンドラーは`ServiceRegistry`に登録し、登録したSOPクラスのプレゼンテーションコンテキストを受諾する。
+#[async_trait::async_trait]
+pub trait ServiceHandler: Send + Sync {
+    /// 処理するSOPクラスのUID
+    fn sop_class_uids(&self) -> Vec<&'static str>;
+
+    /// 処理する要求のCommand Field
+    fn command_fields(&self) -> &'static [CommandField];
+
+    /// 要求を処理し、応答として送信するコマンドセットとデータセットを返す。
+    /// アソシエーションを中断すべきエラーが発生した場合はReasonを返す。
+    async fn handle(
+        &self,
+        request: ServiceRequest,
+        server: &ServerContext,
+        association: &AssociationContext,
+    ) -> Result<(Vec<u8>, Vec<u8>), Reason>;
+}
@@ -0,0 +1,141 @@
+use crate::dimse::ServiceHandler;
+use std::{collections::HashMap, sync::Arc};
+use tracing::warn;
+
+/// SOPクラスUIDをキーとしたサービスハンドラーの一覧
+#[derive(Default)]
+pub struct ServiceRegistry {
+    handlers: HashMap<&'static str, Arc<dyn ServiceHandler>>,
+}
+
+impl ServiceRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ハンドラーを登録する。同じSOPクラスのハンドラーがすでに登録されている場合は置き換える。
+    pub fn register(&mut self, handler: impl ServiceHandler + 'static) {
+        let handler: Arc<dyn ServiceHandler> = Arc::new(handler);
+        for sop_class_uid in handler.sop_class_uids() {
+            if self
+                .handlers
+                .insert(sop_class_uid, Arc::clone(&handler))
+                .is_some()
+            {
+                warn!(
+                    "SOPクラスのサービスハンドラーを置き換えました (SOPクラスUID=\"{sop_class_uid}\")"
+                );
+            }
+        }
+    }
+
+    pub fn get(&self, sop_class_uid: &str) -> Option<Arc<dyn ServiceHandler>> {
+        self.handlers.get(sop_class_uid).cloned()
+    }
+
+    pub fn contains(&self, sop_class_uid: &str) -> bool {
+        self.handlers.contains_key(sop_class_uid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        context::{AssociationContext, ServerContext},
+        dimse::{ServiceRequest, default_service_registry},
+    };
+    use dicom_lib::{
+        constants::sop_class_uids::{
+            CT_IMAGE_STORAGE, MODALITY_PERFORMED_PROCEDURE_STEP,
+            MODALITY_WORKLIST_INFORMATION_MODEL_FIND, VERIFICATION,
+        },
+        network::{dimse::enums::CommandField, upper_layer_protocol::pdu::a_abort::Reason},
+    };
+
+    struct TestHandler {
+        command_fields: &'static [CommandField],
+    }
+
+    #[async_trait::async_trait]
+    impl ServiceHandler for TestHandler {
+        fn sop_class_uids(&self) -> Vec<&'static str> {
+            vec![VERIFICATION, "1.2.3.4"]
+        }
+
+        fn command_fields(&self) -> &'static [CommandField] {
+            self.command_fields
+        }
+
+        async fn handle(
+            &self,
+            _request: ServiceRequest,
+            _server: &ServerContext,
+            _association: &AssociationContext,
+        ) -> Result<(Vec<u8>, Vec<u8>), Reason> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn test_default_service_registry() {
+        // Arrange
+        let registry = default_service_registry();
+
+        // Act & Assert
+        for (sop_class_uid, command_fields) in [
+            (VERIFICATION, &[CommandField::CEchoRq][..]),
+            (CT_IMAGE_STORAGE, &[CommandField::CStoreRq][..]),
+            (
+                MODALITY_PERFORMED_PROCEDURE_STEP,
+                &[CommandField::NCreateRq, CommandField::NSetRq][..],
+            ),
+        ] {
+            assert!(registry.contains(sop_class_uid), "{sop_class_uid}");
+            assert_eq!(
+                registry.get(sop_class_uid).unwrap().command_fields(),
+                command_fields,
+                "{sop_class_uid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_sop_class() {
+        // Arrange
+        let registry = default_service_registry();
+
+        // Act & Assert
+        // ハンドラーが登録されていないSOPクラスは受諾しない
+        for sop_class_uid in [MODALITY_WORKLIST_INFORMATION_MODEL_FIND, "1.2.3.4", ""] {
+            assert!(!registry.contains(sop_class_uid), "{sop_class_uid}");
+            assert!(registry.get(sop_class_uid).is_none(), "{sop_class_uid}");
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        // Arrange
+        let mut registry = default_service_registry();
+
+        // Act
+        registry.register(TestHandler {
+            command_fields: &[CommandField::NEventReportRq],
+        });
+
+        // Assert
+        // 同じSOPクラスのハンドラーは後から登録したものに置き換わる
+        assert_eq!(
+            registry.get(VERIFICATION).unwrap().command_fields(),
+            &[CommandField::NEventReportRq]
+        );
+        assert_eq!(
+            registry.get("1.2.3.4").unwrap().command_fields(),
+            &[CommandField::NEventReportRq]
+        );
+        assert_eq!(
+            registry.get(CT_IMAGE_STORAGE).unwrap().command_fields(),
+            &[CommandField::CStoreRq]
+        );
+    }
+}
@@ -0,0 +1,463 @@
+use crate::{
+    context::ServerContext,
+    scrub::{FileHashStatus, record_file_hash_status},
+};
+use ingest::{
+    InstanceInfo, SavedFile, StorageJournal, calculate_content_hash, dicom_file::DicomFile,
+    save_instance_to_db,
+};
+use sqlx::{query, types::Uuid};
+use std::{collections::HashSet, fmt, path::Path};
+use storage::{StorageError, StorageUri, calculate_file_hash};
+use tokio::fs;
+use tracing::{info, warn};
+
+/// 検査で検出した事項の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FindingKind {
+    /// DBに登録されたファイルが存在しない
+    Missing,
+    /// DBに登録されたファイルを読み込めない
+    Unreadable,
+    /// DBに登録されたサイズとファイルのサイズが一致しない
+    SizeMismatch,
+    /// DBに登録されたハッシュ値とファイルのハッシュ値が一致しない
+    ChecksumMismatch,
+    /// ファイルをDICOMファイルとして解析できない、またはインスタンス情報を取得できない
+    Unparsable,
+    /// ストレージに存在するファイルがDBに登録されていない
+    Orphaned,
+    /// DBに登録されていないファイルをDBに登録した
+    Reindexed,
+    /// DBに登録されていないファイルのSOPインスタンスUIDが、別のファイルとして登録済みのため登録しなかった
+    Duplicated,
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Self::Missing => "missing",
+            Self::Unreadable => "unreadable",
+            Self::SizeMismatch => "size-mismatch",
+            Self::ChecksumMismatch => "checksum-mismatch",
+            Self::Unparsable => "unparsable",
+            Self::Orphaned => "orphaned",
+            Self::Reindexed => "reindexed",
+            Self::Duplicated => "duplicated",
+        };
+        write!(f, "{kind}")
+    }
+}
+
+struct Finding {
+    kind: FindingKind,
+    uri: String,
+    detail: String,
+}
+
+/// 検査の結果
+#[derive(Default)]
+pub struct Report {
+    checked_instance_count: usize,
+    scanned_file_count: usize,
+    findings: Vec<Finding>,
+}
+
+impl Report {
+    fn add(&mut self, kind: FindingKind, uri: &str, detail: impl Into<String>) {
+        self.findings.push(Finding {
+            kind,
+            uri: uri.to_string(),
+            detail: detail.into(),
+        });
+    }
+
+    fn count(&self, kind: FindingKind) -> usize {
+        self.findings
+            .iter()
+            .filter(|finding| finding.kind == kind)
+            .count()
+    }
+
+    /// 再登録以外の不整合を検出したかを返す。
+    pub fn has_inconsistencies(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|finding| finding.kind != FindingKind::Reindexed)
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "SOPインスタンス={}, ファイル={}, 欠損={}, 読み込み不可={}, サイズ不一致={}, ハッシュ値不一致={}, 解析不可={}, 未登録={}, 再登録={}, 重複={}",
+            self.checked_instance_count,
+            self.scanned_file_count,
+            self.count(FindingKind::Missing),
+            self.count(FindingKind::Unreadable),
+            self.count(FindingKind::SizeMismatch),
+            self.count(FindingKind::ChecksumMismatch),
+            self.count(FindingKind::Unparsable),
+            self.count(FindingKind::Orphaned),
+            self.count(FindingKind::Reindexed),
+            self.count(FindingKind::Duplicated),
+        )
+    }
+
+    /// レポートファイルの内容を返す。
+    /// 1行目に件数の概要を出力し、以降は検出した事項ごとに種類、URI、詳細をタブ区切りで出力する。
+    fn to_tsv(&self) -> String {
+        let mut tsv = format!("# {}\n", self.summary());
+        for finding in &self.findings {
+            tsv.push_str(&format!(
+                "{}\t{}\t{}\n",
+                finding.kind,
+                finding.uri,
+                finding.detail.replace(['\t', '\n'], " ")
+            ));
+        }
+        tsv
+    }
+}
+
+/// 保存済みのファイルとDBの整合性を検査し、結果をレポートファイルに出力する。
+///
+/// 1. `sop_instances`テーブルに登録されたファイルを読み込み、存在しないファイル、サイズまたはハッシュ値が一致しないファイルおよび解析できないファイルを検出する。
+///    ハッシュ値の検証結果は定期検証と同様にDBに記録する。
+/// 2. 既定のAEタイトルおよび`local_application_entities`テーブルに登録された宛先AEの保存先を走査し、
+///    `sop_instances`テーブルおよび`sop_instance_histories`テーブルに登録されていないDICOMファイル（拡張子が`.dcm`）を検出する。
+///
+/// `reindex`が`true`の場合、登録されていないファイルのうち解析できるファイルについて、
+/// 受信時と同様にインスタンス情報を取得して患者、検査、シリーズおよびSOPインスタンスをDBに登録する。
+pub async fn check_integrity(
+    server: &ServerContext,
+    report_path: &Path,
+    reindex: bool,
+) -> Result<Report, String> {
+    let mut report = Report::default();
+    let resolver = server.storage_config.resolver();
+
+    // DBに登録されたファイルを検査する
+    let records =
+        query!("SELECT instance_uid, path, size, file_hash FROM sop_instances ORDER BY path")
+            .fetch_all(&server.db_pool)
+            .await
+            .map_err(|e| format!("SOPインスタンスの取得に失敗しました: {e}"))?;
+    let history_paths = query!("SELECT path FROM sop_instance_histories")
+        .fetch_all(&server.db_pool)
+        .await
+        .map_err(|e| format!("SOPインスタンスの履歴の取得に失敗しました: {e}"))?
+        .into_iter()
+        .map(|record| record.path);
+    // URIの導入前に記録されたパスと比較できるよう、URIの形式に揃える
+    let registered_uris = records
+        .iter()
+        .map(|record| record.path.clone())
+        .chain(history_paths)
+        .map(|path| normalize_uri(&path))
+        .collect::<HashSet<_>>();
+
+    for record in &records {
+        report.checked_instance_count += 1;
+        let uri = &record.path;
+        let storage = match resolver.resolve(uri) {
+            Ok(val) => val,
+            Err(e) => {
+                report.add(FindingKind::Unreadable, uri, e.to_string());
+                continue;
+            }
+        };
+        let buf = match storage.get(uri).await {
+            Ok(val) => val,
+            Err(StorageError::NotFound { .. }) => {
+                report.add(
+                    FindingKind::Missing,
+                    uri,
+                    format!("SOPインスタンスUID={}", record.instance_uid),
+                );
+                record_file_hash_status(
+                    &server.db_pool,
+                    &record.instance_uid,
+                    uri,
+                    &record.file_hash,
+                    FileHashStatus::Missing,
+                )
+                .await
+                .map_err(|e| format!("ハッシュ値の検証結果の記録に失敗しました: {e}"))?;
+                continue;
+            }
+            Err(e) => {
+                report.add(FindingKind::Unreadable, uri, e.to_string());
+                continue;
+            }
+        };
+
+        if buf.len() as i64 != record.size {
+            report.add(
+                FindingKind::SizeMismatch,
+                uri,
+                format!(
+                    "SOPインスタンスUID={}, 登録されたサイズ={}, ファイルのサイズ={}",
+                    record.instance_uid,
+                    record.size,
+                    buf.len()
+                ),
+            );
+        }
+        let file_hash = calculate_file_hash(&buf);
+        let status = if file_hash == record.file_hash {
+            FileHashStatus::Matched
+        } else {
+            report.add(
+                FindingKind::ChecksumMismatch,
+                uri,
+                format!(
+                    "SOPインスタンスUID={}, 登録されたハッシュ値={}, ファイルのハッシュ値={file_hash}",
+                    record.instance_uid, record.file_hash
+                ),
+            );
+            FileHashStatus::Mismatched
+        };
+        record_file_hash_status(
+            &server.db_pool,
+            &record.instance_uid,
+            uri,
+            &record.file_hash,
+            status,
+        )
+        .await
+        .map_err(|e| format!("ハッシュ値の検証結果の記録に失敗しました: {e}"))?;
+        if let Err(message) = read_instance_info(&buf) {
+            report.add(FindingKind::Unparsable, uri, message);
+        }
+    }
+
+    // ストレージを走査し、DBに登録されていないファイルを検査する
+    let storage_dirs = query!("SELECT DISTINCT storage_dir FROM local_application_entities")
+        .fetch_all(&server.db_pool)
+        .await
+        .map_err(|e| format!("宛先AEの保存先ディレクトリの取得に失敗しました: {e}"))?
+        .into_iter()
+        .map(|record| record.storage_dir);
+    let mut scanned_uris = HashSet::new();
+    for storage_dir in std::iter::once("dicom".to_string()).chain(storage_dirs) {
+        let storage = server.storage_config.open(&storage_dir);
+        let uris = storage.list().await.map_err(|e| {
+            format!(
+                "保存先のファイルの列挙に失敗しました (保存先ディレクトリ=\"{storage_dir}\"): {e}"
+            )
+        })?;
+
+        for uri in uris {
+            if !uri.ends_with(".dcm") || !scanned_uris.insert(uri.clone()) {
+                continue;
+            }
+            report.scanned_file_count += 1;
+            if registered_uris.contains(&uri) {
+                continue;
+            }
+
+            report.add(FindingKind::Orphaned, &uri, "");
+            let buf = match storage.get(&uri).await {
+                Ok(val) => val,
+                Err(e) => {
+                    report.add(FindingKind::Unreadable, &uri, e.to_string());
+                    continue;
+                }
+            };
+            let (dicom_file, instance_info) = match read_instance_info(&buf) {
+                Ok(val) => val,
+                Err(message) => {
+                    report.add(FindingKind::Unparsable, &uri, message);
+                    continue;
+                }
+            };
+
+            if reindex {
+                reindex_file(server, &mut report, &uri, &buf, &dicom_file, &instance_info).await?;
+            }
+        }
+    }
+
+    fs::write(report_path, report.to_tsv()).await.map_err(|e| {
+        format!(
+            "レポートファイルの書き込みに失敗しました (パス=\"{}\"): {e}",
+            report_path.display()
+        )
+    })?;
+
+    Ok(report)
+}
+
+/// URIの導入前に記録されたパスをURIの形式に変換する。
+fn normalize_uri(path: &str) -> String {
+    StorageUri::parse(path)
+        .map(|uri| uri.to_string())
+        .unwrap_or_else(|_| path.to_string())
+}
+
+fn read_instance_info(buf: &[u8]) -> Result<(DicomFile, InstanceInfo), String> {
+    let dicom_file = DicomFile::read(buf)?;
+    let instance_info =
+        InstanceInfo::from_data_set(&dicom_file.data_set).map_err(|(message, _)| message)?;
+    Ok((dicom_file, instance_info))
+}
+
+/// DBに登録されていないファイルを、受信時と同様にDBに登録する。
+///
+/// 宛先AEタイトルおよび呼出元AEはファイルメタ情報から取得する。
+/// 呼出元AEが`application_entities`テーブルに存在しない場合、登録者は不明（nil UUID）とする。
+async fn reindex_file(
+    server: &ServerContext,
+    report: &mut Report,
+    uri: &str,
+    buf: &[u8],
+    dicom_file: &DicomFile,
+    instance_info: &InstanceInfo,
+) -> Result<(), String> {
+    let sop_instance_uid = instance_info.sop_instance.instance_uid();
+    let registered_path = query!(
+        "SELECT path FROM sop_instances WHERE instance_uid = $1",
+        sop_instance_uid
+    )
+    .fetch_optional(&server.db_pool)
+    .await
+    .map_err(|e| format!("SOPインスタンスの取得に失敗しました: {e}"))?;
+    if let Some(record) = registered_path {
+        report.add(
+            FindingKind::Duplicated,
+            uri,
+            format!(
+                "SOPインスタンスUID={sop_instance_uid}, 登録済みのパス={}",
+                record.path
+            ),
+        );
+        return Ok(());
+    }
+
+    let called_ae_title = dicom_file
+        .receiving_ae_title
+        .clone()
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| server.default_ae_title.clone());
+    let ae_uuid = match &dicom_file.source_ae_title {
+        Some(source_ae_title) => query!(
+            "SELECT uuid FROM application_entities WHERE title = $1",
+            source_ae_title
+        )
+        .fetch_optional(&server.db_pool)
+        .await
+        .map_err(|e| format!("呼出元AEの取得に失敗しました: {e}"))?
+        .map(|record| record.uuid),
+        None => None,
+    }
+    .unwrap_or(Uuid::nil());
+
+    let content_hash = calculate_content_hash(&buf[dicom_file.meta_end..]);
+    let file_hash = calculate_file_hash(buf);
+    let saved_file = SavedFile {
+        transfer_syntax_uid: &dicom_file.transfer_syntax_uid,
+        size: buf.len() as u64,
+        content_hash: &content_hash,
+        file_hash: &file_hash,
+        version: 1,
+    };
+    // 保存済みのファイルを登録するため、保存先のキーの代わりにURIを記録する
+    // NOTE: URIの記録前に中断した場合は、キーからファイルが見つからず記録は削除されるが、次回の検査で再び登録される
+    let mut journal = StorageJournal::begin(
+        &server.db_pool,
+        uri,
+        sop_instance_uid,
+        &called_ae_title,
+        ae_uuid,
+        &saved_file,
+        None,
+    )
+    .await
+    .map_err(|e| format!("ジャーナルへの記録に失敗しました: {e}"))?;
+    journal
+        .record_uri(&server.db_pool, uri.to_string())
+        .await
+        .map_err(|e| format!("ジャーナルへの保存先の記録に失敗しました: {e}"))?;
+
+    match save_instance_to_db(
+        &server.db_pool,
+        instance_info,
+        ae_uuid,
+        &called_ae_title,
+        &saved_file,
+        None,
+        &journal,
+    )
+    .await
+    {
+        Ok(_) => {
+            info!(
+                "未登録のファイルをDBに登録しました (SOPインスタンスUID=\"{sop_instance_uid}\", URI=\"{uri}\")"
+            );
+            report.add(
+                FindingKind::Reindexed,
+                uri,
+                format!("SOPインスタンスUID={sop_instance_uid}"),
+            );
+        }
+        Err(e) => {
+            warn!("未登録のファイルをDBに登録できませんでした (URI=\"{uri}\"): {e}");
+            if let Err(e) = journal.discard(&server.db_pool).await {
+                warn!("ジャーナルの削除に失敗しました: {e}");
+            }
+            report.add(FindingKind::Unparsable, uri, e);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        // Arrange
+        let mut report = Report {
+            checked_instance_count: 3,
+            scanned_file_count: 4,
+            ..Default::default()
+        };
+        report.add(
+            FindingKind::Reindexed,
+            "file:///dicom/1.2.3.dcm",
+            "SOPインスタンスUID=1.2.3",
+        );
+
+        // Act & Assert
+        // 再登録のみの場合は不整合としない
+        assert!(!report.has_inconsistencies());
+
+        report.add(
+            FindingKind::ChecksumMismatch,
+            "file:///dicom/1.2.4.dcm",
+            "記録=abc\tファイル=def",
+        );
+        assert!(report.has_inconsistencies());
+        assert_eq!(
+            report.to_tsv(),
+            "# SOPインスタンス=3, ファイル=4, 欠損=0, 読み込み不可=0, サイズ不一致=0, ハッシュ値不一致=1, 解析不可=0, 未登録=0, 再登録=1, 重複=0\n\
+             reindexed\tfile:///dicom/1.2.3.dcm\tSOPインスタンスUID=1.2.3\n\
+             checksum-mismatch\tfile:///dicom/1.2.4.dcm\t記録=abc ファイル=def\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_uri() {
+        // Act & Assert
+        // URIの導入前に記録されたパスはファイルのURIに変換する
+        assert_eq!(
+            normalize_uri("/var/lib/oceanus/dicom/1.2.3.dcm"),
+            "file:///var/lib/oceanus/dicom/1.2.3.dcm"
+        );
+        assert_eq!(
+            normalize_uri("s3://oceanus/dicom/1.2.3.dcm"),
+            "s3://oceanus/dicom/1.2.3.dcm"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::context::ServerContext;
+use ingest::DuplicatePolicy;
+use sqlx::{Pool, Postgres, query, types::Uuid};
+
+/// 宛先AEとして応答する自身のAE
+///
+/// `local_application_entities`テーブルで設定する。
+/// 起動時に指定したAEタイトルがテーブルに存在しない場合は、既定の設定を持つAEとして扱う。
+pub struct LocalApplicationEntity {
+    /// `local_application_entities`テーブル上のUUID（既定の設定の場合は`None`）
+    uuid: Option<Uuid>,
+    title: String,
+    /// DICOMファイルの保存先ディレクトリ
+    storage_dir: String,
+    /// 重複したSOPインスタンスの処理方針
+    duplicate_policy: DuplicatePolicy,
+}
+
+impl LocalApplicationEntity {
+    pub fn uuid(&self) -> Option<Uuid> {
+        self.uuid
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn storage_dir(&self) -> &str {
+        &self.storage_dir
+    }
+
+    pub fn duplicate_policy(&self) -> DuplicatePolicy {
+        self.duplicate_policy
+    }
+
+    /// 宛先AEタイトルに対応するAEを取得する。
+    /// 対応するAEが存在しない場合は`None`を返す。
+    ///
+    /// 保存先ディレクトリはストレージの設定に従って開く（[`storage::StorageConfig::open`]を参照）。
+    /// 既定の設定の保存先ディレクトリはデータディレクトリの直下の`dicom`ディレクトリとする。
+    pub async fn find(
+        server: &ServerContext,
+        called_ae_title: &str,
+    ) -> Result<Option<Self>, String> {
+        let record = query!(
+            "SELECT uuid, title, storage_dir, duplicate_policy FROM local_application_entities WHERE title = $1",
+            called_ae_title
+        )
+        .fetch_optional(&server.db_pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let local_application_entity = match record {
+            Some(record) => Some(Self {
+                uuid: Some(record.uuid),
+                title: record.title,
+                storage_dir: record.storage_dir,
+                duplicate_policy: DuplicatePolicy::try_from(record.duplicate_policy)?,
+            }),
+            None if called_ae_title == server.default_ae_title => Some(Self {
+                uuid: None,
+                title: server.default_ae_title.clone(),
+                storage_dir: "dicom".to_string(),
+                duplicate_policy: server.default_duplicate_policy,
+            }),
+            None => None,
+        };
+
+        Ok(local_application_entity)
+    }
+
+    /// 呼出元AEからの接続を許可するかを判定する。
+    ///
+    /// `local_application_entity_allowed_callers`テーブルに自身に対する設定が存在しない場合は、すべての呼出元AEからの接続を許可する。
+    pub async fn is_calling_ae_allowed(
+        &self,
+        db_pool: &Pool<Postgres>,
+        application_entity_uuid: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let Some(uuid) = self.uuid else {
+            return Ok(true);
+        };
+
+        let record = query!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!",
+                COUNT(*) FILTER (WHERE application_entity_uuid = $2) AS "matched_count!"
+            FROM local_application_entity_allowed_callers
+            WHERE local_application_entity_uuid = $1
+            "#,
+            uuid,
+            application_entity_uuid
+        )
+        .fetch_one(db_pool)
+        .await?;
+
+        Ok(record.count == 0 || record.matched_count > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::postgres::PgPoolOptions;
+
+    #[tokio::test]
+    async fn test_is_calling_ae_allowed_for_default_ae() {
+        // Arrange
+        // 既定の設定を持つAEはDBを参照しないため、接続しないプールを用いる
+        let db_pool = PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/oceanus")
+            .unwrap();
+        let local_application_entity = LocalApplicationEntity {
+            uuid: None,
+            title: "OCEANUS".to_string(),
+            storage_dir: "dicom".to_string(),
+            duplicate_policy: DuplicatePolicy::Reject,
+        };
+
+        // Act
+        let actual = local_application_entity
+            .is_calling_ae_allowed(&db_pool, Uuid::nil())
+            .await;
+
+        // Assert
+        // 既定の設定を持つAEは、すべての呼出元AEからの接続を許可する
+        assert!(actual.unwrap());
+    }
+}
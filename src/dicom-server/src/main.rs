@@ -1,15 +1,24 @@
 mod args;
 mod constants;
+mod context;
 mod dimse;
+mod integrity_check;
+mod local_application_entity;
+mod recovery;
+mod retention_job;
+mod scrub;
 
 use crate::{
-    args::Args,
+    args::{Args, Command},
     constants::*,
-    dimse::{DimseMessage, handle_dimse_message},
+    context::{AssociationContext, ServerContext},
+    dimse::{DimseMessage, ServiceRegistry, handle_dimse_message, has_data_set},
+    local_application_entity::LocalApplicationEntity,
 };
 use clap::Parser;
 use dicom_lib::{
-    constants::{sop_class_uids::VERIFICATION, transfer_syntax_uids::IMPLICIT_VR_LITTLE_ENDIAN},
+    constants::transfer_syntax_uids::IMPLICIT_VR_LITTLE_ENDIAN,
+    dictionaries::{is_storage_sop_class, storage_sop_class_uids},
     network::{
         command_set::utils::generate_p_data_tf_pdus,
         upper_layer_protocol::{
@@ -37,15 +46,15 @@ use dicom_lib::{
     },
 };
 use dotenvy::dotenv;
-use sqlx::{Pool, Postgres, postgres::PgPoolOptions, query};
+use ingest::CoercionRule;
+use sqlx::{Pool, Postgres, postgres::PgPoolOptions, query, types::Uuid};
 use std::{
     collections::{HashMap, HashSet},
     io::{ErrorKind, IsTerminal},
     net::Ipv4Addr,
-    path::{Path, PathBuf},
     process::exit,
     sync::{
-        OnceLock,
+        Arc,
         atomic::{AtomicU64, Ordering},
     },
     time::Duration,
@@ -59,9 +68,6 @@ use tracing::{Instrument, Level, debug, error, info, level_filters::LevelFilter,
 use tracing_subscriber::fmt::time::LocalTime;
 
 static CONNECTION_COUNTER: AtomicU64 = AtomicU64::new(1);
-static SERVER_AE_TITLE: OnceLock<String> = OnceLock::new();
-static DB_POOL: OnceLock<Pool<Postgres>> = OnceLock::new();
-static STORAGE_DIR: OnceLock<PathBuf> = OnceLock::new();
 
 #[tokio::main]
 async fn main() {
@@ -69,10 +75,6 @@ async fn main() {
     let _ = dotenv();
     // コマンドライン引数の解析
     let args = Args::parse();
-    SERVER_AE_TITLE.set(args.ae_title).unwrap();
-    STORAGE_DIR // ストレージ先ディレクトリはデータディレクトリの直下の`dicom`ディレクトリとする
-        .set(Path::new(&args.data_dir).join("dicom"))
-        .unwrap();
 
     print!(
         r"
@@ -102,20 +104,93 @@ async fn main() {
     }
 
     // DB 接続
-    match PgPoolOptions::new()
+    let db_pool = match PgPoolOptions::new()
         .max_connections(5)
         .acquire_timeout(Duration::from_secs(5))
         .connect(&args.database_url)
         .await
     {
         Ok(pool) => {
-            DB_POOL.set(pool).unwrap();
             debug!("データベースに接続しました");
+            pool
         }
         Err(e) => {
             error!("データベースへの接続に失敗しました: {e}");
             exit(1);
         }
+    };
+
+    let storage_config = match args.storage_config() {
+        Ok(val) => val,
+        Err(e) => {
+            error!("{e}");
+            exit(1);
+        }
+    };
+    let trash = args.trash();
+    let server = Arc::new(ServerContext {
+        default_ae_title: args.ae_title,
+        default_duplicate_policy: args.duplicate_policy,
+        db_pool,
+        // ストレージ先ディレクトリはデータディレクトリの直下の`dicom`ディレクトリとする
+        storage: storage_config.open("dicom"),
+        trash,
+        storage_config,
+        service_registry: dimse::default_service_registry(),
+    });
+
+    // 保守用のコマンドを実行して終了する
+    match &args.command {
+        Some(Command::Check { report, reindex }) => {
+            match integrity_check::check_integrity(&server, report, *reindex).await {
+                Ok(result) if result.has_inconsistencies() => {
+                    warn!("整合性の検査で不整合を検出しました ({})", result.summary());
+                    exit(2);
+                }
+                Ok(result) => {
+                    info!("整合性の検査が完了しました ({})", result.summary());
+                    exit(0);
+                }
+                Err(e) => {
+                    error!("整合性の検査に失敗しました: {e}");
+                    exit(1);
+                }
+            }
+        }
+        Some(Command::Retention { report, dry_run }) => {
+            match retention_job::run_retention(&server, report, *dry_run).await {
+                Ok(result) if result.has_failures() => {
+                    warn!(
+                        "保存期間の規則による削除で一部の検査の削除に失敗しました ({})",
+                        result.summary()
+                    );
+                    exit(2);
+                }
+                Ok(result) if *dry_run => {
+                    info!("保存期間の規則の評価が完了しました ({})", result.summary());
+                    exit(0);
+                }
+                Ok(result) => {
+                    info!(
+                        "保存期間の規則による削除が完了しました ({})",
+                        result.summary()
+                    );
+                    exit(0);
+                }
+                Err(e) => {
+                    error!("保存期間の規則による削除に失敗しました: {e}");
+                    exit(1);
+                }
+            }
+        }
+        None => {}
+    }
+
+    // 前回の実行で中断したSOPインスタンスの保存を復旧する
+    // NOTE: 保守用のコマンドは実行中のDICOMサーバーと並行して実行されうるため、復旧はDICOMサーバーの起動時と起動後の定期的な復旧でのみ行う
+    if let Err(e) = recovery::recover_interrupted_stores(&server, STALE_STORE_THRESHOLD).await {
+        error!("中断したSOPインスタンスの保存の復旧に失敗しました: {e}");
+        exit(1);
     }
 
     let listener = {
@@ -131,11 +206,30 @@ async fn main() {
         }
     };
     info!(
-        "サーバーが起動しました (AEタイトル=\"{}\" ポート番号={})",
-        SERVER_AE_TITLE.get().unwrap(),
-        args.port
+        "サーバーが起動しました (既定のAEタイトル=\"{}\" ポート番号={})",
+        server.default_ae_title, args.port
     );
 
+    // 中断したSOPインスタンスの保存の定期的な復旧
+    recovery::spawn_recovery_job(Arc::clone(&server), STALE_STORE_THRESHOLD);
+
+    // ファイルのハッシュ値の定期検証
+    if args.scrub_interval_minutes > 0 {
+        scrub::spawn_scrub_job(
+            Arc::clone(&server),
+            Duration::from_secs(args.scrub_interval_minutes * 60),
+            args.scrub_batch_size,
+        );
+    }
+
+    // 保存期間の規則による定期削除
+    if args.retention_interval_minutes > 0 {
+        retention_job::spawn_retention_job(
+            Arc::clone(&server),
+            Duration::from_secs(args.retention_interval_minutes * 60),
+        );
+    }
+
     let shutdown = shutdown_signal();
     tokio::pin!(shutdown);
 
@@ -155,10 +249,11 @@ async fn main() {
             }
         };
         let connection_id = CONNECTION_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let server = Arc::clone(&server);
 
         spawn(async move {
             use Instrument;
-            handle_association(socket)
+            handle_association(socket, &server)
                 .instrument(span!(
                     Level::INFO,
                     "connection",
@@ -171,11 +266,11 @@ async fn main() {
     }
 }
 
-async fn handle_association(mut socket: TcpStream) {
+async fn handle_association(mut socket: TcpStream, server: &ServerContext) {
     let mut buf_reader = BufReader::new(&mut socket);
 
-    let (a_associate_rq, mut context_id_to_dimse_message) =
-        match handle_association_establishment(&mut buf_reader).await {
+    let (a_associate_rq, association, mut context_id_to_dimse_message) =
+        match handle_association_establishment(&mut buf_reader, server).await {
             Some(val) => val,
             None => return,
         };
@@ -248,6 +343,10 @@ async fn handle_association(mut socket: TcpStream) {
             if is_command {
                 dimse_message.command_set_buf.append(fragment);
                 dimse_message.is_command_received = is_last;
+                if is_last && !has_data_set(&dimse_message.command_set_buf) {
+                    // データセットを伴わないコマンドの場合、すでにデータセットを受信したものとみなす
+                    dimse_message.is_data_received = true;
+                }
             } else {
                 dimse_message.data_set_buf.append(fragment);
                 dimse_message.is_data_received = is_last;
@@ -269,18 +368,14 @@ async fn handle_association(mut socket: TcpStream) {
                 ),
             );
 
-            let (command_set_buf, data_set_buf) = match handle_dimse_message(
-                dimse_message,
-                a_associate_rq.calling_ae_title(),
-            )
-            .await
-            {
-                Ok(val) => val,
-                Err(reason) => {
-                    abort(&mut buf_reader, reason).await;
-                    return;
-                }
-            };
+            let (command_set_buf, data_set_buf) =
+                match handle_dimse_message(dimse_message, server, &association).await {
+                    Ok(val) => val,
+                    Err(reason) => {
+                        abort(&mut buf_reader, reason).await;
+                        return;
+                    }
+                };
 
             // P-DATA-TFの送信
             {
@@ -314,7 +409,8 @@ async fn handle_association(mut socket: TcpStream) {
 
 async fn handle_association_establishment(
     buf_reader: &mut BufReader<&mut TcpStream>,
-) -> Option<(AAssociateRq, HashMap<u8, DimseMessage>)> {
+    server: &ServerContext,
+) -> Option<(AAssociateRq, AssociationContext, HashMap<u8, DimseMessage>)> {
     // A-ASSOCIATE-RQの受信
     let a_associate_rq = match receive_a_associate_rq(buf_reader).await {
         Ok(val) => val,
@@ -338,24 +434,40 @@ async fn handle_association_establishment(
     );
 
     // アソシエーション要求を受諾するか判定し、拒否する場合はA-ASSOCIATE-RJを送信して終了する
-    if called_ae_title != SERVER_AE_TITLE.get().unwrap() {
-        warn!(
-            "アソシエーション要求を拒否しました (呼出元=\"{calling_ae_title}\" 宛先=\"{called_ae_title}\" 理由=宛先AEタイトル不一致)",
-        );
-        reject_association(
-            buf_reader,
-            a_associate_rj::Result::RejectedPermanent,
-            SourceAndReason::ServiceUser(service_user::Reason::CalledAeTitleNotRecognized),
-        )
-        .await;
-        return None;
-    }
+    let local_application_entity = match LocalApplicationEntity::find(server, called_ae_title).await
+    {
+        Ok(Some(val)) => val,
+        Ok(None) => {
+            warn!(
+                "アソシエーション要求を拒否しました (呼出元=\"{calling_ae_title}\" 宛先=\"{called_ae_title}\" 理由=宛先AEタイトル不一致)",
+            );
+            reject_association(
+                buf_reader,
+                a_associate_rj::Result::RejectedPermanent,
+                SourceAndReason::ServiceUser(service_user::Reason::CalledAeTitleNotRecognized),
+            )
+            .await;
+            return None;
+        }
+        Err(e) => {
+            error!(
+                "アソシエーション要求を拒否しました (呼出元=\"{calling_ae_title}\" 宛先=\"{called_ae_title}\" 理由=宛先AEの取得に失敗): {e}",
+            );
+            reject_association(
+                buf_reader,
+                a_associate_rj::Result::RejectedTransient,
+                SourceAndReason::ServiceProviderAcse(service_provider_acse::Reason::NoReasonGiven),
+            )
+            .await;
+            return None;
+        }
+    };
 
-    match query!(
-        "SELECT host FROM application_entities WHERE title = $1",
+    let application_entity_uuid = match query!(
+        "SELECT uuid, host FROM application_entities WHERE title = $1",
         calling_ae_title
     )
-    .fetch_one(DB_POOL.get().unwrap())
+    .fetch_one(&server.db_pool)
     .await
     {
         Ok(application_entity) => {
@@ -393,6 +505,8 @@ async fn handle_association_establishment(
                 .await;
                 return None;
             }
+
+            application_entity.uuid
         }
         Err(_) => {
             warn!(
@@ -406,8 +520,79 @@ async fn handle_association_establishment(
             .await;
             return None;
         }
+    };
+
+    match local_application_entity
+        .is_calling_ae_allowed(&server.db_pool, application_entity_uuid)
+        .await
+    {
+        Ok(true) => {}
+        Ok(false) => {
+            warn!(
+                "アソシエーション要求を拒否しました (呼出元=\"{calling_ae_title}\" 宛先=\"{called_ae_title}\" 理由=宛先AEへの接続が許可されていない呼出元AE)",
+            );
+            reject_association(
+                buf_reader,
+                a_associate_rj::Result::RejectedPermanent,
+                SourceAndReason::ServiceUser(service_user::Reason::CallingAeTitleNotRecognized),
+            )
+            .await;
+            return None;
+        }
+        Err(e) => {
+            error!(
+                "アソシエーション要求を拒否しました (呼出元=\"{calling_ae_title}\" 宛先=\"{called_ae_title}\" 理由=接続を許可する呼出元AEの取得に失敗): {e}",
+            );
+            reject_association(
+                buf_reader,
+                a_associate_rj::Result::RejectedTransient,
+                SourceAndReason::ServiceProviderAcse(service_provider_acse::Reason::NoReasonGiven),
+            )
+            .await;
+            return None;
+        }
     }
 
+    let accepted_storage_sop_class_uids = match fetch_accepted_storage_sop_class_uids(
+        &server.db_pool,
+        local_application_entity.uuid(),
+        application_entity_uuid,
+    )
+    .await
+    {
+        Ok(val) => val,
+        Err(e) => {
+            error!(
+                "アソシエーション要求を拒否しました (呼出元=\"{calling_ae_title}\" 宛先=\"{called_ae_title}\" 理由=受諾するSOPクラスの取得に失敗): {e}",
+            );
+            reject_association(
+                buf_reader,
+                a_associate_rj::Result::RejectedTransient,
+                SourceAndReason::ServiceProviderAcse(service_provider_acse::Reason::NoReasonGiven),
+            )
+            .await;
+            return None;
+        }
+    };
+
+    let coercion_rules = match CoercionRule::fetch_all(&server.db_pool, application_entity_uuid)
+        .await
+    {
+        Ok(val) => val,
+        Err(e) => {
+            error!(
+                "アソシエーション要求を拒否しました (呼出元=\"{calling_ae_title}\" 宛先=\"{called_ae_title}\" 理由=書き換え規則の取得に失敗): {e}",
+            );
+            reject_association(
+                buf_reader,
+                a_associate_rj::Result::RejectedTransient,
+                SourceAndReason::ServiceProviderAcse(service_provider_acse::Reason::NoReasonGiven),
+            )
+            .await;
+            return None;
+        }
+    };
+
     // A-ASSOCIATE-ACの送信
     let mut context_id_to_dimse_message = HashMap::new();
     {
@@ -416,7 +601,11 @@ async fn handle_association_establishment(
             .presentation_contexts()
             .iter()
             .map(|presentation_context| {
-                if !is_abstract_syntax_supported(presentation_context) {
+                if !is_abstract_syntax_supported(
+                    presentation_context,
+                    &server.service_registry,
+                    &accepted_storage_sop_class_uids,
+                ) {
                     a_associate_ac::PresentationContext::new(
                         presentation_context.context_id(),
                         ResultReason::AbstractSyntaxNotSupported,
@@ -482,9 +671,22 @@ async fn handle_association_establishment(
         );
         return None;
     }
-    info!("アソシエーション要求を受諾しました (呼出元=\"{calling_ae_title}\")");
+    info!(
+        "アソシエーション要求を受諾しました (呼出元=\"{calling_ae_title}\" 宛先=\"{called_ae_title}\")"
+    );
+
+    let association = AssociationContext {
+        called_ae_title: local_application_entity.title().to_string(),
+        calling_ae_title: calling_ae_title.to_string(),
+        calling_ae_uuid: application_entity_uuid,
+        storage: server
+            .storage_config
+            .open(local_application_entity.storage_dir()),
+        duplicate_policy: local_application_entity.duplicate_policy(),
+        coercion_rules,
+    };
 
-    Some((a_associate_rq, context_id_to_dimse_message))
+    Some((a_associate_rq, association, context_id_to_dimse_message))
 }
 
 fn generate_empty_dimse_message(
@@ -492,11 +694,6 @@ fn generate_empty_dimse_message(
     abstract_syntax_uid: &str,
     transfer_syntax_uid: &'static str,
 ) -> DimseMessage {
-    let is_data_received = match abstract_syntax_uid {
-        VERIFICATION => true, // C-ECHOはデータセットを使用しないため、すでにデータセットを受信したものとみなす
-        _ => false,
-    };
-
     DimseMessage {
         context_id,
         abstract_syntax_uid: abstract_syntax_uid.to_string(),
@@ -504,27 +701,122 @@ fn generate_empty_dimse_message(
         command_set_buf: Vec::new(),
         data_set_buf: Vec::new(),
         is_command_received: false,
-        is_data_received,
+        is_data_received: false,
     }
 }
 
+/// 抽象構文を受諾するかを判定する。
+///
+/// サービスハンドラーが登録されているSOPクラスを受諾する。
+/// ただし、Storage SOPクラスは呼出元AEに対して受諾するよう設定されているもののみを受諾する。
 fn is_abstract_syntax_supported(
     presentation_context: &a_associate_rq::PresentationContext,
+    service_registry: &ServiceRegistry,
+    accepted_storage_sop_class_uids: &HashSet<String>,
 ) -> bool {
-    SUPPORTED_ABSTRACT_SYNTAX_UIDS.contains(&presentation_context.abstract_syntax().name())
+    let abstract_syntax_uid = presentation_context.abstract_syntax().name();
+    service_registry.contains(abstract_syntax_uid)
+        && (!is_storage_sop_class(abstract_syntax_uid)
+            || accepted_storage_sop_class_uids.contains(abstract_syntax_uid))
+}
+
+/// 宛先AEと呼出元AEの組に対して受諾するStorage SOPクラスのUIDを取得する。
+///
+/// 受諾するStorage SOPクラスは`accepted_storage_sop_classes`テーブルで設定する。
+/// 宛先AEと呼出元AEの組、宛先AE、呼出元AE、全体の順に設定を探し、最初に見つかった設定を使用する。
+/// いずれも存在しない場合は、SOPクラス辞書に登録されているすべてのStorage SOPクラスを受諾する。
+async fn fetch_accepted_storage_sop_class_uids(
+    db_pool: &Pool<Postgres>,
+    local_application_entity_uuid: Option<Uuid>,
+    application_entity_uuid: Uuid,
+) -> Result<HashSet<String>, sqlx::Error> {
+    let records = query!(
+        r#"
+        SELECT local_application_entity_uuid, application_entity_uuid, sop_class_uid
+        FROM accepted_storage_sop_classes
+        WHERE (local_application_entity_uuid = $1 OR local_application_entity_uuid IS NULL)
+          AND (application_entity_uuid = $2 OR application_entity_uuid IS NULL)
+        "#,
+        local_application_entity_uuid,
+        application_entity_uuid
+    )
+    .fetch_all(db_pool)
+    .await?;
+
+    // 設定の優先度（小さいほど優先）
+    let priority = |local_application_entity_uuid: &Option<Uuid>,
+                    application_entity_uuid: &Option<Uuid>| {
+        match (local_application_entity_uuid, application_entity_uuid) {
+            (Some(_), Some(_)) => 0,
+            (Some(_), None) => 1,
+            (None, Some(_)) => 2,
+            (None, None) => 3,
+        }
+    };
+    let Some(highest_priority) = records
+        .iter()
+        .map(|record| {
+            priority(
+                &record.local_application_entity_uuid,
+                &record.application_entity_uuid,
+            )
+        })
+        .min()
+    else {
+        return Ok(storage_sop_class_uids().map(str::to_string).collect());
+    };
+
+    Ok(records
+        .into_iter()
+        .filter(|record| {
+            priority(
+                &record.local_application_entity_uuid,
+                &record.application_entity_uuid,
+            ) == highest_priority
+        })
+        .filter_map(|record| {
+            if is_storage_sop_class(&record.sop_class_uid) {
+                Some(record.sop_class_uid)
+            } else {
+                warn!(
+                    "Storage SOPクラスではないSOPクラスが受諾するStorage SOPクラスとして設定されています (SOPクラスUID=\"{}\")",
+                    record.sop_class_uid
+                );
+                None
+            }
+        })
+        .collect())
+}
+
+/// 抽象構文に対して受諾する転送構文のUIDを優先度順に返す。
+///
+/// 動画のStorage SOPクラスは、非圧縮の転送構文に加えて動画の転送構文を受諾する。
+/// 動画を非圧縮で送信させないよう、動画の転送構文を優先する。
+fn supported_transfer_syntax_uids(abstract_syntax_uid: &str) -> impl Iterator<Item = &'static str> {
+    let video_transfer_syntax_uids = if VIDEO_STORAGE_SOP_CLASS_UIDS.contains(&abstract_syntax_uid)
+    {
+        VIDEO_TRANSFER_SYNTAX_UIDS
+    } else {
+        &[]
+    };
+
+    video_transfer_syntax_uids
+        .iter()
+        .chain(SUPPORTED_TRANSFER_SYNTAX_UIDS)
+        .copied()
 }
 
 fn is_transfer_syntax_supported(
     presentation_context: &a_associate_rq::PresentationContext,
 ) -> bool {
-    SUPPORTED_TRANSFER_SYNTAX_UIDS
-        .iter()
-        .any(|transfer_syntax| {
+    supported_transfer_syntax_uids(presentation_context.abstract_syntax().name()).any(
+        |transfer_syntax| {
             presentation_context
                 .transfer_syntaxes()
                 .iter()
-                .any(|ts| ts.name() == *transfer_syntax)
-        })
+                .any(|ts| ts.name() == transfer_syntax)
+        },
+    )
 }
 
 fn choose_transfer_syntax_uid(
@@ -537,13 +829,12 @@ fn choose_transfer_syntax_uid(
         .collect::<Vec<_>>();
 
     // サポートされている転送構文UIDの中から最初にマッチしたもの（優先度が高いもの）を取り出す
-    let uid = SUPPORTED_TRANSFER_SYNTAX_UIDS
-        .iter()
+    let uid = supported_transfer_syntax_uids(presentation_context.abstract_syntax().name())
         .find(|uid| transfer_syntax_uids.contains(uid));
 
     // 取り出した転送構文UIDを返す
     // 見つからなかった場合はImplicit VR Little Endian（デフォルトの転送構文UID）を返す
-    uid.unwrap_or(&IMPLICIT_VR_LITTLE_ENDIAN)
+    uid.unwrap_or(IMPLICIT_VR_LITTLE_ENDIAN)
 }
 
 async fn reject_association(
@@ -621,3 +912,74 @@ async fn shutdown_signal() {
 
     info!("サーバーを停止します");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_lib::{
+        constants::{
+            sop_class_uids::{CT_IMAGE_STORAGE, VIDEO_ENDOSCOPIC_IMAGE_STORAGE},
+            transfer_syntax_uids::{
+                EXPLICIT_VR_LITTLE_ENDIAN, HEVC_MP51, JPEG_BASELINE_8_BIT, MPEG4_HP41,
+            },
+        },
+        network::upper_layer_protocol::pdu::a_associate_rq::presentation_context::{
+            AbstractSyntax, TransferSyntax,
+        },
+    };
+
+    fn presentation_context(
+        abstract_syntax_uid: &str,
+        transfer_syntax_uids: &[&str],
+    ) -> a_associate_rq::PresentationContext {
+        a_associate_rq::PresentationContext::new(
+            1,
+            AbstractSyntax::new(abstract_syntax_uid).unwrap(),
+            transfer_syntax_uids
+                .iter()
+                .map(|uid| TransferSyntax::new(*uid).unwrap())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_choose_transfer_syntax_uid() {
+        // Arrange
+        #[rustfmt::skip]
+        let cases = [
+            (CT_IMAGE_STORAGE, vec![IMPLICIT_VR_LITTLE_ENDIAN, EXPLICIT_VR_LITTLE_ENDIAN], EXPLICIT_VR_LITTLE_ENDIAN),
+            (CT_IMAGE_STORAGE, vec![MPEG4_HP41, IMPLICIT_VR_LITTLE_ENDIAN], IMPLICIT_VR_LITTLE_ENDIAN),
+            // 動画のStorage SOPクラスは動画の転送構文を優先する
+            (VIDEO_ENDOSCOPIC_IMAGE_STORAGE, vec![EXPLICIT_VR_LITTLE_ENDIAN, MPEG4_HP41], MPEG4_HP41),
+            (VIDEO_ENDOSCOPIC_IMAGE_STORAGE, vec![HEVC_MP51], HEVC_MP51),
+            (VIDEO_ENDOSCOPIC_IMAGE_STORAGE, vec![EXPLICIT_VR_LITTLE_ENDIAN], EXPLICIT_VR_LITTLE_ENDIAN),
+        ];
+
+        for (abstract_syntax_uid, transfer_syntax_uids, expected) in cases {
+            let presentation_context =
+                presentation_context(abstract_syntax_uid, &transfer_syntax_uids);
+
+            // Act & Assert
+            assert!(is_transfer_syntax_supported(&presentation_context));
+            assert_eq!(
+                choose_transfer_syntax_uid(&presentation_context),
+                expected,
+                "{abstract_syntax_uid}, {transfer_syntax_uids:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_transfer_syntax_not_supported() {
+        // Act & Assert
+        // 動画の転送構文は動画のStorage SOPクラスに対してのみ受諾する
+        assert!(!is_transfer_syntax_supported(&presentation_context(
+            CT_IMAGE_STORAGE,
+            &[MPEG4_HP41]
+        )));
+        assert!(!is_transfer_syntax_supported(&presentation_context(
+            VIDEO_ENDOSCOPIC_IMAGE_STORAGE,
+            &[JPEG_BASELINE_8_BIT]
+        )));
+    }
+}
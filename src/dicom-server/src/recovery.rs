@@ -0,0 +1,508 @@
+use crate::context::ServerContext;
+use ingest::{
+    DuplicatePolicy, JournalOperation, RewrittenFile, SavedFile, SopInstanceLock, StorageJournal,
+    dicom_file::DicomFile, instance_info::InstanceInfo, save_instance_to_db,
+    save_rewritten_file_to_db,
+};
+use sqlx::{query, query_scalar};
+use std::{sync::Arc, time::Duration};
+use storage::{StorageBackend, calculate_file_hash, size_from_db, verify_file_hash};
+use tracing::{error, info, warn};
+
+/// 中断したSOPインスタンスの保存の定期的な復旧を開始する。
+///
+/// 起動直前に中断した処理は[`recover_interrupted_stores`]の対象とならないため、`stale_after`ごとに復旧をやり直す。
+pub fn spawn_recovery_job(server: Arc<ServerContext>, stale_after: Duration) {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(stale_after).await;
+            if let Err(e) = recover_interrupted_stores(&server, stale_after).await {
+                error!("中断したSOPインスタンスの保存の復旧に失敗しました: {e}");
+            }
+        }
+    });
+}
+
+/// 中断したSOPインスタンスの保存を復旧する。
+///
+/// 実行中のDICOMサーバーおよびWeb API（STOW-RS）が保存中の処理を妨げないよう、
+/// 作成から`stale_after`以上経過した一時ファイルおよび記録のみを対象とする。
+/// 記録は保存と同じSOPインスタンスUIDのロックを取得して復旧し、ロックを取得できない場合は保存中とみなして次回に見送る。
+///
+/// - 書き込み途中で中断した一時ファイルを削除する。
+/// - ファイルの保存前に中断した記録は、保存先に書き込み途中のファイルが残らないため、記録のみを削除する。
+/// - ファイルの保存後にDBへの登録前に中断した記録は、保存したファイルからDBへの登録をやり直す。
+///   保存先のURIの記録前に中断した場合は、記録したキーから保存したファイルを探す。
+///   ファイルが存在しない、ファイルのハッシュ値が記録した値と一致しない、またはファイルからインスタンス情報を取得できない場合は、
+///   記録を隔離（`status`=1）して理由を残す。
+/// - Patient IDの書き換えの記録は、置き換えたファイルが書き換え後の内容であればDBへの記録をやり直し、書き換え前の内容であれば記録のみを削除する。
+pub async fn recover_interrupted_stores(
+    server: &ServerContext,
+    stale_after: Duration,
+) -> Result<(), String> {
+    remove_temporary_files(server, stale_after).await?;
+
+    let records = query!(
+        r#"
+        SELECT uuid, sop_instance_uid, called_ae_title, transfer_syntax_uid, size, content_hash, file_hash, version, policy, key, uri, operation, created_by
+        FROM storage_journals
+        WHERE status = 0 AND created_at < now() - make_interval(secs => $1)
+        ORDER BY created_at, uuid
+        "#,
+        stale_after.as_secs_f64()
+    )
+    .fetch_all(&server.db_pool)
+    .await
+    .map_err(|e| format!("ジャーナルの取得に失敗しました: {e}"))?;
+
+    for record in records {
+        let sop_instance_uid = record.sop_instance_uid.as_str();
+
+        // 保存中の処理と同時にDBへ登録しないよう、保存と同じロックを取得できた記録のみを復旧する
+        let Some(mut lock) = SopInstanceLock::try_acquire(&server.db_pool, sop_instance_uid)
+            .await
+            .map_err(|e| format!("SOPインスタンスのロックの取得に失敗しました: {e}"))?
+        else {
+            info!(
+                "保存中のSOPインスタンスのため復旧を見送りました (SOPインスタンスUID=\"{sop_instance_uid}\")"
+            );
+            continue;
+        };
+        let connection = lock.connection();
+        let result = async {
+            // 記録の取得後にロックを取得するまでの間に、保存中の処理が完了した場合は復旧しない
+            let is_pending = query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM storage_journals WHERE uuid = $1 AND status = 0) AS "is_pending!""#,
+                record.uuid
+            )
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(|e| format!("ジャーナルの取得に失敗しました: {e}"))?;
+            if !is_pending {
+                return Ok(());
+            }
+
+            let mut journal =
+                StorageJournal::restore(record.uuid, record.key.clone(), record.uri.clone());
+
+            let storage = server.storage.as_ref();
+            let recovery = match JournalOperation::try_from(record.operation)? {
+                JournalOperation::Store => plan_recovery(storage, &journal, &record.file_hash).await,
+                JournalOperation::Rewrite => {
+                    plan_rewrite_recovery(storage, &journal, &record.file_hash).await
+                }
+            };
+            let instance_info = match recovery {
+                Recovery::Discard => {
+                    journal
+                        .discard(&mut *connection)
+                        .await
+                        .map_err(|e| format!("ジャーナルの削除に失敗しました: {e}"))?;
+                    info!(
+                        "ファイルの保存前または置き換え前に中断したSOPインスタンスの記録を削除しました (SOPインスタンスUID=\"{sop_instance_uid}\")"
+                    );
+                    return Ok(());
+                }
+                Recovery::Quarantine { uri, reason } => {
+                    let uri = uri.unwrap_or_default();
+                    query!(
+                        "UPDATE storage_journals SET status = 1, reason = $2, uri = COALESCE(uri, NULLIF($3, '')) WHERE uuid = $1",
+                        journal.uuid(),
+                        reason,
+                        uri
+                    )
+                    .execute(&mut *connection)
+                    .await
+                    .map_err(|e| format!("ジャーナルの隔離に失敗しました: {e}"))?;
+                    error!(
+                        "DBへの登録前に中断したSOPインスタンスを復旧できないため隔離しました (SOPインスタンスUID=\"{sop_instance_uid}\", URI=\"{uri}\"): {reason}"
+                    );
+                    return Ok(());
+                }
+                Recovery::Rewritten => {
+                    let rewritten_file = RewrittenFile {
+                        size: size_from_db(record.size)?,
+                        content_hash: &record.content_hash,
+                        file_hash: &record.file_hash,
+                    };
+                    let uri = journal.uri().unwrap_or_default();
+                    match save_rewritten_file_to_db(&mut *connection, &rewritten_file, &journal)
+                        .await
+                    {
+                        Ok(()) => warn!(
+                            "DBへの記録前に中断したPatient IDの書き換えを、置き換えたファイルから記録しました (SOPインスタンスUID=\"{sop_instance_uid}\", URI=\"{uri}\")"
+                        ),
+                        // 記録が残るため次回の復旧で再度記録する
+                        Err(e) => error!(
+                            "DBへの記録前に中断したPatient IDの書き換えの記録に失敗しました (SOPインスタンスUID=\"{sop_instance_uid}\", URI=\"{uri}\"): {e}"
+                        ),
+                    }
+                    return Ok(());
+                }
+                Recovery::Register { uri, instance_info } => {
+                    if journal.uri().is_none() {
+                        journal
+                            .record_uri(&mut *connection, uri)
+                            .await
+                            .map_err(|e| format!("ジャーナルへの保存先の記録に失敗しました: {e}"))?;
+                    }
+                    instance_info
+                }
+            };
+            let uri = journal.uri().unwrap_or_default();
+
+            let saved_file = SavedFile {
+                transfer_syntax_uid: &record.transfer_syntax_uid,
+                size: size_from_db(record.size)?,
+                content_hash: &record.content_hash,
+                file_hash: &record.file_hash,
+                version: record.version,
+            };
+            let replaced_policy = record.policy.map(DuplicatePolicy::try_from).transpose()?;
+            match save_instance_to_db(
+                &mut *connection,
+                &instance_info,
+                record.created_by,
+                &record.called_ae_title,
+                &saved_file,
+                replaced_policy,
+                &journal,
+            )
+            .await
+            {
+                Ok(_) => warn!(
+                    "DBへの登録前に中断したSOPインスタンスを保存したファイルから登録しました (SOPインスタンスUID=\"{sop_instance_uid}\", URI=\"{uri}\")"
+                ),
+                // DBへの接続の問題等で登録できない場合は、記録が残るため次回の復旧で再度登録する
+                Err(e) => error!(
+                    "DBへの登録前に中断したSOPインスタンスの登録に失敗しました (SOPインスタンスUID=\"{sop_instance_uid}\", URI=\"{uri}\"): {e}"
+                ),
+            }
+
+            Ok::<(), String>(())
+        }
+        .await;
+        if let Err(e) = lock.release().await {
+            error!("SOPインスタンスのロックの解放に失敗しました: {e}");
+        }
+        result?;
+    }
+
+    Ok(())
+}
+
+/// 既定のAEタイトルおよび`local_application_entities`テーブルに登録された宛先AEの保存先から、
+/// 書き込み途中で中断した一時ファイルのうち、最終更新から`stale_after`以上経過したものを削除する。
+async fn remove_temporary_files(
+    server: &ServerContext,
+    stale_after: Duration,
+) -> Result<(), String> {
+    let storage_dirs = query!("SELECT DISTINCT storage_dir FROM local_application_entities")
+        .fetch_all(&server.db_pool)
+        .await
+        .map_err(|e| format!("宛先AEの保存先ディレクトリの取得に失敗しました: {e}"))?
+        .into_iter()
+        .map(|record| record.storage_dir);
+
+    for storage_dir in std::iter::once("dicom".to_string()).chain(storage_dirs) {
+        match server
+            .storage_config
+            .open(&storage_dir)
+            .remove_temporary_files(stale_after)
+            .await
+        {
+            Ok(0) => {}
+            Ok(count) => warn!(
+                "書き込み途中で中断した一時ファイルを削除しました (保存先ディレクトリ=\"{storage_dir}\", ファイル数={count})"
+            ),
+            Err(e) => error!(
+                "書き込み途中で中断した一時ファイルの削除に失敗しました (保存先ディレクトリ=\"{storage_dir}\"): {e}"
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// ジャーナルの記録に対して行う復旧処理
+enum Recovery {
+    /// ファイルの保存前または置き換え前に中断したため、記録のみを削除する
+    Discard,
+    /// 保存したファイルから復旧できないため、理由を添えて記録を隔離する
+    Quarantine { uri: Option<String>, reason: String },
+    /// 保存したファイルから取得したインスタンス情報でDBへの登録をやり直す
+    Register {
+        uri: String,
+        instance_info: Box<InstanceInfo>,
+    },
+    /// 置き換えたファイルのサイズとハッシュ値をDBへ記録し直す
+    Rewritten,
+}
+
+/// ジャーナルの記録と保存先のファイルから、行う復旧処理を決定する。
+async fn plan_recovery(
+    storage: &dyn StorageBackend,
+    journal: &StorageJournal,
+    file_hash: &str,
+) -> Recovery {
+    let uri = match journal.uri() {
+        Some(uri) => uri.to_string(),
+        // 保存先のURIの記録前に中断した場合は、記録したキーから保存したファイルを探す
+        None => match storage.locate(journal.key(), file_hash).await {
+            Ok(Some(uri)) => uri,
+            Ok(None) => return Recovery::Discard,
+            Err(e) => {
+                return Recovery::Quarantine {
+                    uri: None,
+                    reason: format!("保存したファイルの検索に失敗しました: {e}"),
+                };
+            }
+        },
+    };
+
+    match read_instance_info(storage, &uri, file_hash).await {
+        Ok(instance_info) => Recovery::Register {
+            uri,
+            instance_info: Box::new(instance_info),
+        },
+        Err(reason) => Recovery::Quarantine {
+            uri: Some(uri),
+            reason,
+        },
+    }
+}
+
+/// Patient IDの書き換えの記録と保存先のファイルから、行う復旧処理を決定する。
+///
+/// ファイルは不可分に置き換えるため、保存先のファイルは書き換え前または書き換え後のいずれかの内容となる。
+/// 書き換え後のハッシュ値と一致する場合はDBへの記録をやり直し、一致しない場合は置き換え前に中断したものとして記録のみを削除する。
+async fn plan_rewrite_recovery(
+    storage: &dyn StorageBackend,
+    journal: &StorageJournal,
+    file_hash: &str,
+) -> Recovery {
+    let uri = journal.uri().unwrap_or(journal.key());
+    match storage.get(uri).await {
+        Ok(buf) if calculate_file_hash(&buf) == file_hash => Recovery::Rewritten,
+        Ok(_) => Recovery::Discard,
+        Err(e) => Recovery::Quarantine {
+            uri: Some(uri.to_string()),
+            reason: format!("ファイルの読み込みに失敗しました: {e}"),
+        },
+    }
+}
+
+/// 保存したファイルを読み込み、ハッシュ値を検証してインスタンス情報を取得する。
+async fn read_instance_info(
+    storage: &dyn StorageBackend,
+    uri: &str,
+    file_hash: &str,
+) -> Result<InstanceInfo, String> {
+    let buf = storage
+        .get(uri)
+        .await
+        .map_err(|e| format!("ファイルの読み込みに失敗しました: {e}"))?;
+    verify_file_hash(uri, &buf, file_hash).map_err(|e| e.to_string())?;
+    let dicom_file = DicomFile::read(&buf)?;
+
+    InstanceInfo::from_data_set(&dicom_file.data_set).map_err(|(message, _)| message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_lib::{
+        constants::{
+            sop_class_uids::CT_IMAGE_STORAGE, transfer_syntax_uids::EXPLICIT_VR_LITTLE_ENDIAN,
+        },
+        core::{
+            DataSet, Encoding, Tag, data_element::Vr, value::value_representations::ui::UiValue,
+        },
+        file::{File, file_meta_information::FileMetaInformation},
+    };
+    use sqlx::types::Uuid;
+    use storage::FileSystemStorage;
+
+    const SOP_INSTANCE_UID: &str = "1.2.3.4.5.6";
+    const KEY: &str = "success/2026/02/03/1.2.3.4/1.2.3.4.5/1.2.3.4.5.6.dcm";
+
+    fn dicom_file() -> Vec<u8> {
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        for (tag, vr, value) in [
+            (Tag(0x0008, 0x0016), Vr::Ui, "1.2.840.10008.5.1.4.1.1.2\0"),
+            (Tag(0x0008, 0x0018), Vr::Ui, "1.2.3.4.5.6\0"),
+            (Tag(0x0008, 0x0020), Vr::Da, "20260203"),
+            (Tag(0x0008, 0x0060), Vr::Cs, "CT"),
+            (Tag(0x0010, 0x0010), Vr::Pn, "YAMADA^TARO "),
+            (Tag(0x0010, 0x0020), Vr::Lo, "P001"),
+            (Tag(0x0020, 0x000d), Vr::Ui, "1.2.3.4\0"),
+            (Tag(0x0020, 0x000e), Vr::Ui, "1.2.3.4.5\0"),
+        ] {
+            data_set.set_element(tag, vr, value.as_bytes().to_vec());
+        }
+        let file_meta_info = FileMetaInformation::new(
+            UiValue::from_string(CT_IMAGE_STORAGE).unwrap(),
+            UiValue::from_string(SOP_INSTANCE_UID).unwrap(),
+            UiValue::from_string(EXPLICIT_VR_LITTLE_ENDIAN).unwrap(),
+            UiValue::from_string("1.2.3").unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        File::new(file_meta_info, data_set).into()
+    }
+
+    /// テストごとに異なるディレクトリをルートとするストレージを作成する。
+    fn storage(name: &str) -> (std::path::PathBuf, FileSystemStorage) {
+        let root_dir = std::env::temp_dir().join(format!(
+            "oceanus-dicom-server-test-recovery-{name}-{}",
+            std::process::id()
+        ));
+        let storage = FileSystemStorage::new(&root_dir);
+        (root_dir, storage)
+    }
+
+    #[tokio::test]
+    async fn test_plan_recovery_before_write() {
+        // Arrange
+        let (_, storage) = storage("before-write");
+        // ファイルの保存前に中断した記録には保存先のURIがなく、キーにもファイルがない
+        let journal = StorageJournal::restore(Uuid::nil(), KEY.to_string(), None);
+
+        // Act
+        let actual = plan_recovery(&storage, &journal, &calculate_file_hash(&dicom_file())).await;
+
+        // Assert
+        assert!(matches!(actual, Recovery::Discard));
+    }
+
+    #[tokio::test]
+    async fn test_plan_recovery_after_write() {
+        // Arrange
+        let (root_dir, storage) = storage("after-write");
+        let buf = dicom_file();
+        let file_hash = calculate_file_hash(&buf);
+        let uri = storage.put(KEY, buf).await.unwrap();
+        let journal = StorageJournal::restore(Uuid::nil(), KEY.to_string(), Some(uri));
+
+        // Act
+        let actual = plan_recovery(&storage, &journal, &file_hash).await;
+
+        // Assert
+        // 保存したファイルからDBへの登録をやり直す
+        let Recovery::Register { instance_info, .. } = actual else {
+            panic!("DBへの登録をやり直す必要があります");
+        };
+        assert_eq!(instance_info.sop_instance.instance_uid(), SOP_INSTANCE_UID);
+
+        tokio::fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_plan_recovery_before_recording_uri() {
+        // Arrange
+        let (root_dir, storage) = storage("before-recording-uri");
+        let buf = dicom_file();
+        let file_hash = calculate_file_hash(&buf);
+        let uri = storage.put(KEY, buf).await.unwrap();
+        // ファイルの保存後、保存先のURIの記録前に中断した記録
+        let journal = StorageJournal::restore(Uuid::nil(), KEY.to_string(), None);
+
+        // Act
+        let actual = plan_recovery(&storage, &journal, &file_hash).await;
+
+        // Assert
+        // 記録したキーから保存したファイルを探し、DBへの登録をやり直す
+        let Recovery::Register {
+            uri: actual_uri,
+            instance_info,
+        } = actual
+        else {
+            panic!("DBへの登録をやり直す必要があります");
+        };
+        assert_eq!(actual_uri, uri);
+        assert_eq!(instance_info.sop_instance.instance_uid(), SOP_INSTANCE_UID);
+
+        tokio::fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_plan_rewrite_recovery() {
+        // Arrange
+        let (root_dir, storage) = storage("rewrite");
+        let buf = dicom_file();
+        let uri = storage.put(KEY, buf.clone()).await.unwrap();
+        let mut rewritten_buf = buf.clone();
+        rewritten_buf.extend_from_slice(b"\x10\x00\x21\x00LO\x02\x00X ");
+        let rewritten_file_hash = calculate_file_hash(&rewritten_buf);
+        let journal = StorageJournal::restore(Uuid::nil(), uri.clone(), Some(uri.clone()));
+
+        // Act
+        // ファイルの置き換え前に中断した場合
+        let before_replace = plan_rewrite_recovery(&storage, &journal, &rewritten_file_hash).await;
+        // ファイルの置き換え後、DBへの記録前に中断した場合
+        storage.replace(&uri, rewritten_buf).await.unwrap();
+        let after_replace = plan_rewrite_recovery(&storage, &journal, &rewritten_file_hash).await;
+
+        // Assert
+        assert!(matches!(before_replace, Recovery::Discard));
+        assert!(matches!(after_replace, Recovery::Rewritten));
+
+        tokio::fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_plan_recovery_partial_write() {
+        // Arrange
+        let (root_dir, storage) = storage("partial-write");
+        let buf = dicom_file();
+        let file_hash = calculate_file_hash(&buf);
+        // 途中までしか書き込まれていないファイル
+        let uri = storage
+            .put(KEY, buf[..buf.len() / 2].to_vec())
+            .await
+            .unwrap();
+        let journal = StorageJournal::restore(Uuid::nil(), KEY.to_string(), Some(uri));
+
+        // Act
+        let actual = plan_recovery(&storage, &journal, &file_hash).await;
+
+        // Assert
+        // ハッシュ値が一致しないファイルからは登録せず、記録を隔離する
+        assert!(matches!(actual, Recovery::Quarantine { .. }));
+
+        tokio::fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_plan_recovery_missing_file() {
+        // Arrange
+        let (root_dir, storage) = storage("missing-file");
+        let buf = dicom_file();
+        let file_hash = calculate_file_hash(&buf);
+        let uri = storage.put(KEY, buf).await.unwrap();
+        storage.delete(&uri).await.unwrap();
+        let journal = StorageJournal::restore(Uuid::nil(), KEY.to_string(), Some(uri));
+
+        // Act
+        let actual = plan_recovery(&storage, &journal, &file_hash).await;
+
+        // Assert
+        assert!(matches!(actual, Recovery::Quarantine { .. }));
+
+        tokio::fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+}
@@ -0,0 +1,215 @@
+use crate::context::ServerContext;
+use chrono::{SecondsFormat, Utc};
+use retention::{DeletionContext, RetentionCandidate};
+use std::{fmt, path::Path, sync::Arc, time::Duration};
+use storage::StorageBackend;
+use tokio::fs;
+use tracing::{error, info, warn};
+
+/// 保存期間の規則による削除のログの接頭辞
+const LOG_PREFIX: &str = "[保存期間]";
+
+/// 対象となった検査の処理結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutcomeKind {
+    /// 削除の対象（dry-runのため削除していない）
+    Candidate,
+    /// 削除した
+    Deleted,
+    /// 削除したが、一部のファイルの削除に失敗した
+    FileRemovalFailed,
+    /// 評価後に削除または変更されていたため、削除しなかった
+    Skipped,
+    /// 削除に失敗した
+    Failed,
+}
+
+impl fmt::Display for OutcomeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Self::Candidate => "candidate",
+            Self::Deleted => "deleted",
+            Self::FileRemovalFailed => "file-removal-failed",
+            Self::Skipped => "skipped",
+            Self::Failed => "failed",
+        };
+        write!(f, "{kind}")
+    }
+}
+
+struct Outcome {
+    kind: OutcomeKind,
+    candidate: RetentionCandidate,
+    detail: String,
+}
+
+/// 保存期間の規則による削除の結果
+#[derive(Default)]
+pub struct Report {
+    outcomes: Vec<Outcome>,
+}
+
+impl Report {
+    fn add(&mut self, kind: OutcomeKind, candidate: RetentionCandidate, detail: impl Into<String>) {
+        self.outcomes.push(Outcome {
+            kind,
+            candidate,
+            detail: detail.into(),
+        });
+    }
+
+    fn count(&self, kind: OutcomeKind) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.kind == kind)
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// 削除またはファイルの削除に失敗した検査があるかを返す。
+    pub fn has_failures(&self) -> bool {
+        self.outcomes.iter().any(|outcome| {
+            matches!(
+                outcome.kind,
+                OutcomeKind::FileRemovalFailed | OutcomeKind::Failed
+            )
+        })
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "対象={}, 削除={}, ファイルの削除に失敗={}, 変更または削除済み={}, 失敗={}",
+            self.outcomes.len(),
+            self.count(OutcomeKind::Deleted),
+            self.count(OutcomeKind::FileRemovalFailed),
+            self.count(OutcomeKind::Skipped),
+            self.count(OutcomeKind::Failed),
+        )
+    }
+
+    /// レポートファイルの内容を返す。
+    /// 1行目に件数の概要を出力し、以降は検査ごとに処理結果、規則の名前、検査インスタンスUID、患者ID、
+    /// 最後に受信した日時、サイズ、SOPインスタンス数、詳細をタブ区切りで出力する。
+    fn to_tsv(&self) -> String {
+        let mut tsv = format!("# {}\n", self.summary());
+        for outcome in &self.outcomes {
+            let study = &outcome.candidate.study;
+            tsv.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                outcome.kind,
+                outcome.candidate.rule_name.replace(['\t', '\n'], " "),
+                study.study_instance_uid,
+                study.patient_id.as_deref().unwrap_or_default(),
+                study
+                    .last_received_at
+                    .to_rfc3339_opts(SecondsFormat::Secs, true),
+                study.size,
+                study.sop_instance_count,
+                outcome.detail.replace(['\t', '\n'], " ")
+            ));
+        }
+        tsv
+    }
+}
+
+/// 保存期間の規則による削除を定期的に実行する。
+pub fn spawn_retention_job(server: Arc<ServerContext>, interval: Duration) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+            match apply_retention_rules(&server, false).await {
+                Ok(report) if report.is_empty() => {}
+                Ok(report) if report.has_failures() => {
+                    warn!(
+                        "{LOG_PREFIX} 保存期間の規則による削除で一部の検査の削除に失敗しました ({})",
+                        report.summary()
+                    );
+                }
+                Ok(report) => {
+                    info!(
+                        "{LOG_PREFIX} 保存期間の規則による削除が完了しました ({})",
+                        report.summary()
+                    );
+                }
+                Err(e) => error!("{LOG_PREFIX} 保存期間の規則による削除に失敗しました: {e}"),
+            }
+        }
+    });
+}
+
+/// 保存期間の規則を評価し、対象となった検査の一覧をレポートファイルに出力する。
+/// `dry_run`が`false`の場合は、対象となった検査を削除する。
+pub async fn run_retention(
+    server: &ServerContext,
+    report_path: &Path,
+    dry_run: bool,
+) -> Result<Report, String> {
+    let report = apply_retention_rules(server, dry_run).await?;
+
+    fs::write(report_path, report.to_tsv()).await.map_err(|e| {
+        format!(
+            "レポートファイルの書き込みに失敗しました (パス=\"{}\"): {e}",
+            report_path.display()
+        )
+    })?;
+
+    Ok(report)
+}
+
+/// 保存期間の規則を評価し、対象となった検査を削除する（`dry_run`が`true`の場合は削除しない）。
+///
+/// 削除済みテーブルの`deleted_by`には、検査が対象となった規則のUUIDを記録する。
+/// 評価後にSOPインスタンスを受信する等により変更された検査は削除しない。
+/// 1件の検査の削除に失敗しても、残りの検査の削除は継続する。
+async fn apply_retention_rules(server: &ServerContext, dry_run: bool) -> Result<Report, String> {
+    let candidates = retention::find_candidates(&server.db_pool, Utc::now()).await?;
+
+    let mut report = Report::default();
+    if dry_run {
+        for candidate in candidates {
+            report.add(OutcomeKind::Candidate, candidate, "");
+        }
+        return Ok(report);
+    }
+
+    let resolver = server.storage_config.resolver();
+    for candidate in candidates {
+        let context = DeletionContext {
+            db_pool: &server.db_pool,
+            resolver: &resolver,
+            trash: server
+                .trash
+                .as_ref()
+                .map(|trash| trash as &dyn StorageBackend),
+            deleted_by: candidate.rule_uuid,
+            deleted_at: Utc::now(),
+            log_prefix: LOG_PREFIX,
+        };
+        // 評価後に受信したSOPインスタンスを削除しないよう、評価時から変更されていない場合のみ削除する
+        match retention::delete_if_unchanged(&context, &candidate.study).await {
+            Ok(Some(result)) if result.failed_file_uris.is_empty() => {
+                report.add(OutcomeKind::Deleted, candidate, "");
+            }
+            Ok(Some(result)) => {
+                let detail = result.failed_file_uris.join(" ");
+                report.add(OutcomeKind::FileRemovalFailed, candidate, detail);
+            }
+            Ok(None) => {
+                report.add(OutcomeKind::Skipped, candidate, "");
+            }
+            Err(e) => {
+                error!(
+                    "{LOG_PREFIX} 検査の削除に失敗しました (検査インスタンスUID=\"{}\"): {e}",
+                    candidate.study.study_instance_uid
+                );
+                report.add(OutcomeKind::Failed, candidate, e);
+            }
+        }
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,198 @@
+use crate::context::ServerContext;
+use sqlx::{Pool, Postgres, query};
+use std::{sync::Arc, time::Duration};
+use storage::{StorageBackend, StorageError, calculate_stream_hash};
+use tracing::{error, info, warn};
+
+/// ファイルのハッシュ値の検証結果（`sop_instances`テーブルの`file_hash_status`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileHashStatus {
+    /// 記録したハッシュ値とファイルのハッシュ値が一致する
+    Matched,
+    /// 記録したハッシュ値とファイルのハッシュ値が一致しない
+    Mismatched,
+    /// ファイルが存在しない
+    Missing,
+}
+
+impl From<FileHashStatus> for i16 {
+    fn from(status: FileHashStatus) -> Self {
+        match status {
+            FileHashStatus::Matched => 1,
+            FileHashStatus::Mismatched => 2,
+            FileHashStatus::Missing => 3,
+        }
+    }
+}
+
+/// SOPインスタンスのファイルのハッシュ値の検証結果を記録する。
+///
+/// 検証中に同じSOPインスタンスが置き換えられた場合に誤った結果を記録しないよう、
+/// 検証したファイルのパスおよびハッシュ値が登録内容と一致する場合のみ記録する。
+pub async fn record_file_hash_status(
+    db_pool: &Pool<Postgres>,
+    instance_uid: &str,
+    path: &str,
+    file_hash: &str,
+    status: FileHashStatus,
+) -> Result<(), sqlx::Error> {
+    query!(
+        r#"
+        UPDATE sop_instances
+        SET file_hash_status = $4, file_hash_verified_at = now()
+        WHERE instance_uid = $1 AND path = $2 AND file_hash = $3
+        "#,
+        instance_uid,
+        path,
+        file_hash,
+        i16::from(status),
+    )
+    .execute(db_pool)
+    .await?;
+
+    Ok(())
+}
+
+/// ファイルのハッシュ値の定期検証を開始する。
+///
+/// `interval`ごとに、最後に検証した日時が古い（未検証を含む）SOPインスタンスから順に`batch_size`件のファイルを検証する。
+pub fn spawn_scrub_job(server: Arc<ServerContext>, interval: Duration, batch_size: u32) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+            if let Err(e) = scrub(&server, batch_size).await {
+                error!("ファイルのハッシュ値の定期検証に失敗しました: {e}");
+            }
+        }
+    });
+}
+
+/// ファイルを読み込んでハッシュ値を再計算し、記録したハッシュ値と比較した結果をDBに記録する。
+/// 2GiBを超えるファイルも扱えるよう、ファイル全体をメモリに読み込まずに順に読み出して計算する。
+///
+/// ファイルの読み込みに失敗した場合は、ストレージに接続できない等の一時的な問題の可能性があるため、今回の検証を中断する。
+async fn scrub(server: &ServerContext, batch_size: u32) -> Result<(), String> {
+    let resolver = server.storage_config.resolver();
+    let records = query!(
+        r#"
+        SELECT instance_uid, path, file_hash
+        FROM sop_instances
+        ORDER BY file_hash_verified_at NULLS FIRST, instance_uid
+        LIMIT $1
+        "#,
+        i64::from(batch_size)
+    )
+    .fetch_all(&server.db_pool)
+    .await
+    .map_err(|e| format!("SOPインスタンスの取得に失敗しました: {e}"))?;
+
+    let mut mismatched_count = 0;
+    let mut missing_count = 0;
+    for record in &records {
+        let uri = &record.path;
+        let storage = resolver.resolve(uri).map_err(|e| e.to_string())?;
+        let status = verify_file(storage, uri, &record.file_hash).await?;
+        match status {
+            FileHashStatus::Matched => {}
+            FileHashStatus::Mismatched => {
+                error!(
+                    "ファイルのハッシュ値が記録された値と一致しません (SOPインスタンスUID=\"{}\", URI=\"{uri}\")",
+                    record.instance_uid
+                );
+                mismatched_count += 1;
+            }
+            FileHashStatus::Missing => {
+                error!(
+                    "SOPインスタンスのファイルが存在しません (SOPインスタンスUID=\"{}\", URI=\"{uri}\")",
+                    record.instance_uid
+                );
+                missing_count += 1;
+            }
+        }
+
+        record_file_hash_status(
+            &server.db_pool,
+            &record.instance_uid,
+            uri,
+            &record.file_hash,
+            status,
+        )
+        .await
+        .map_err(|e| format!("ハッシュ値の検証結果の記録に失敗しました: {e}"))?;
+    }
+
+    if mismatched_count > 0 || missing_count > 0 {
+        warn!(
+            "ファイルのハッシュ値の定期検証で不整合を検出しました (ファイル数={}, 不一致={mismatched_count}, 欠損={missing_count})",
+            records.len()
+        );
+    } else if !records.is_empty() {
+        info!(
+            "ファイルのハッシュ値の定期検証が完了しました (ファイル数={})",
+            records.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// ファイルを読み込み、記録したハッシュ値と比較した結果を返す。
+async fn verify_file(
+    storage: &dyn StorageBackend,
+    uri: &str,
+    file_hash: &str,
+) -> Result<FileHashStatus, String> {
+    match storage.stream(uri).await {
+        Ok(stream) => {
+            let (_, actual) = calculate_stream_hash(stream)
+                .await
+                .map_err(|e| format!("ファイルの読み込みに失敗しました (URI=\"{uri}\"): {e}"))?;
+            if actual == file_hash {
+                Ok(FileHashStatus::Matched)
+            } else {
+                Ok(FileHashStatus::Mismatched)
+            }
+        }
+        Err(StorageError::NotFound { .. }) => Ok(FileHashStatus::Missing),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use storage::{FileSystemStorage, calculate_file_hash};
+
+    #[tokio::test]
+    async fn test_verify_file() {
+        // Arrange
+        let root_dir = std::env::temp_dir().join(format!(
+            "oceanus-dicom-server-test-scrub-{}",
+            std::process::id()
+        ));
+        let storage = FileSystemStorage::new(&root_dir);
+        let file_hash = calculate_file_hash(b"DICM");
+        let matched_uri = storage.put("1.2.3.dcm", b"DICM".to_vec()).await.unwrap();
+        // 保存後に内容が変化したファイル
+        let mismatched_uri = storage.put("1.2.4.dcm", b"DICN".to_vec()).await.unwrap();
+        let missing_uri = storage.put("1.2.5.dcm", b"DICM".to_vec()).await.unwrap();
+        storage.delete(&missing_uri).await.unwrap();
+
+        // Act & Assert
+        assert_eq!(
+            verify_file(&storage, &matched_uri, &file_hash).await,
+            Ok(FileHashStatus::Matched)
+        );
+        assert_eq!(
+            verify_file(&storage, &mismatched_uri, &file_hash).await,
+            Ok(FileHashStatus::Mismatched)
+        );
+        assert_eq!(
+            verify_file(&storage, &missing_uri, &file_hash).await,
+            Ok(FileHashStatus::Missing)
+        );
+
+        tokio::fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+}
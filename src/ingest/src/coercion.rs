@@ -0,0 +1,470 @@
+use chrono::Local;
+use dicom_lib::{
+    core::{DataSet, Tag, data_element::Vr},
+    dictionaries::tag_dictionary,
+};
+use regex::Regex;
+use sqlx::{Pool, Postgres, query, types::Uuid};
+use std::collections::{BTreeMap, HashMap};
+use tracing::warn;
+
+const ORIGINAL_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0400, 0x0561);
+const MODIFIED_ATTRIBUTES_SEQUENCE: Tag = Tag(0x0400, 0x0550);
+const ATTRIBUTE_MODIFICATION_DATE_TIME: Tag = Tag(0x0400, 0x0562);
+const MODIFYING_SYSTEM: Tag = Tag(0x0400, 0x0563);
+const SOURCE_OF_PREVIOUS_VALUES: Tag = Tag(0x0400, 0x0564);
+const REASON_FOR_THE_ATTRIBUTE_MODIFICATION: Tag = Tag(0x0400, 0x0565);
+
+/// 受信時の属性の書き換え規則
+///
+/// `coercion_rules`テーブルで呼出元AEごとに設定する。
+/// `action`は1=設定、2=複写、3=削除、4=置換、5=対応付けを表し、`tag`および`source_tag`は`"00100020"`の形式で指定する。
+/// `source_tag`は複写、`pattern`は置換、`value`は設定および置換、`map_from`および`map_to`は対応付けで使用する。
+pub struct CoercionRule {
+    tag: Tag,
+    /// 標準DICOMタグ辞書上の書き換え対象の属性のVR
+    vr: Vr,
+    operation: Operation,
+}
+
+enum Operation {
+    /// 値を設定する。属性が存在しない場合は追加する。
+    Set(String),
+    /// 他の属性の値を複写する。複写元の属性が存在しない場合は何もしない。
+    Copy(Tag),
+    /// 属性を削除する。
+    Delete,
+    /// 正規表現に一致する部分を置換する。属性が存在しない場合は何もしない。
+    Replace { pattern: Regex, replacement: String },
+    /// 値が一致する場合に対応する値へ置き換える。
+    Map(HashMap<String, String>),
+}
+
+impl CoercionRule {
+    /// 呼出元AEに対する書き換え規則を適用順に取得する。
+    /// 不正な規則は警告を出力して読み飛ばす。
+    pub async fn fetch_all(
+        db_pool: &Pool<Postgres>,
+        application_entity_uuid: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        let records = query!(
+            r#"
+            SELECT application_order, action, tag, source_tag, pattern, value, map_from, map_to
+            FROM coercion_rules
+            WHERE application_entity_uuid = $1
+            ORDER BY application_order
+            "#,
+            application_entity_uuid
+        )
+        .fetch_all(db_pool)
+        .await?;
+
+        let rules = records
+            .into_iter()
+            .filter_map(|record| {
+                let application_order = record.application_order;
+                Self::new(
+                    record.action,
+                    &record.tag,
+                    record.source_tag.as_deref(),
+                    &record.pattern,
+                    record.value,
+                    record.map_from,
+                    record.map_to,
+                )
+                .inspect_err(|e| {
+                    warn!("不正な書き換え規則を無視しました (適用順={application_order}): {e}")
+                })
+                .ok()
+            })
+            .collect();
+
+        Ok(rules)
+    }
+
+    fn new(
+        action: i16,
+        tag: &str,
+        source_tag: Option<&str>,
+        pattern: &str,
+        value: String,
+        map_from: Vec<String>,
+        map_to: Vec<String>,
+    ) -> Result<Self, String> {
+        let (tag, vr) = parse_string_tag(tag)?;
+        let operation = match action {
+            1 => Operation::Set(value),
+            2 => {
+                let source_tag = source_tag.ok_or("複写元の属性が指定されていません")?;
+                Operation::Copy(parse_string_tag(source_tag)?.0)
+            }
+            3 => Operation::Delete,
+            4 => Operation::Replace {
+                pattern: Regex::new(pattern)
+                    .map_err(|e| format!("正規表現が不正です (正規表現=\"{pattern}\"): {e}"))?,
+                replacement: value,
+            },
+            5 => Operation::Map(map_from.into_iter().zip(map_to).collect()),
+            _ => return Err(format!("不明な処理です (action={action})")),
+        };
+
+        Ok(Self { tag, vr, operation })
+    }
+}
+
+/// 文字列を値として持つ属性のタグと、そのVRを返す。
+fn parse_string_tag(tag: &str) -> Result<(Tag, Vr), String> {
+    let tag = tag.parse::<Tag>().map_err(|e| e.to_string())?;
+    match tag_dictionary::search_vr(tag) {
+        Some(vr) if vr.is_string() => Ok((tag, vr)),
+        _ => Err(format!(
+            "文字列を値として持つ属性ではありません (タグ={tag})"
+        )),
+    }
+}
+
+/// データセットに書き換え規則を順に適用し、値を変更した属性のタグを返す。
+///
+/// 値は値フィールドのバイト列をUTF-8として解釈し、末尾のパディングを除いた文字列として扱う。
+/// 文字集合の変換は行わない。
+///
+/// 変更した属性の元の値は、Original Attributes Sequence (0400,0561) のアイテムとして記録する。
+/// 元の値が存在しなかった属性は、値長さが0の属性として記録する。
+pub fn apply_coercion_rules(
+    data_set: &mut DataSet,
+    rules: &[CoercionRule],
+    modifying_system: &str,
+    source_of_previous_values: &str,
+) -> Vec<Tag> {
+    // 変更前の属性のVRと値フィールド（属性が存在しなかった場合は`None`）
+    let mut originals: BTreeMap<Tag, (Vr, Option<Vec<u8>>)> = BTreeMap::new();
+
+    for rule in rules {
+        let current_value = get_value(data_set, rule.tag);
+        let new_value = match &rule.operation {
+            Operation::Set(value) => Some(value.clone()),
+            Operation::Copy(source_tag) => match get_value(data_set, *source_tag) {
+                Some(value) => Some(value),
+                None => continue,
+            },
+            Operation::Delete => None,
+            Operation::Replace {
+                pattern,
+                replacement,
+            } => match &current_value {
+                Some(value) => Some(
+                    pattern
+                        .replace_all(value, replacement.as_str())
+                        .into_owned(),
+                ),
+                None => continue,
+            },
+            Operation::Map(map) => match current_value.as_ref().and_then(|value| map.get(value)) {
+                Some(value) => Some(value.clone()),
+                None => continue,
+            },
+        };
+        if new_value == current_value {
+            continue;
+        }
+
+        let value_field = new_value.map(|value| encode_value(value, rule.vr));
+        if let Some(value_field) = &value_field
+            && !matches!(rule.vr, Vr::Uc | Vr::Ur | Vr::Ut)
+            && value_field.len() > 0xfffe
+        {
+            warn!(
+                "値が長すぎるため書き換えませんでした (タグ={}, 値の長さ={})",
+                rule.tag,
+                value_field.len()
+            );
+            continue;
+        }
+
+        originals.entry(rule.tag).or_insert_with(|| {
+            match data_set.find_index(rule.tag).map(|i| &data_set[i]) {
+                Some(e) => (e.vr().unwrap_or(rule.vr), Some(e.value_field().to_vec())),
+                None => (rule.vr, None),
+            }
+        });
+        match value_field {
+            Some(value_field) => data_set.set_element(rule.tag, rule.vr, value_field),
+            None => {
+                data_set.remove(rule.tag);
+            }
+        }
+    }
+
+    // 複数の規則の適用により元の値に戻った属性は記録しない
+    originals.retain(|tag, (_, original_value_field)| {
+        let value_field = data_set
+            .find_index(*tag)
+            .map(|i| data_set[i].value_field().to_vec());
+        value_field != *original_value_field
+    });
+    if originals.is_empty() {
+        return Vec::new();
+    }
+
+    let encoding = data_set.encoding();
+    let mut modified_attributes = DataSet::new(encoding);
+    for (tag, (vr, original_value_field)) in &originals {
+        modified_attributes.set_element(
+            *tag,
+            *vr,
+            original_value_field.clone().unwrap_or_default(),
+        );
+    }
+    let mut item = DataSet::new(encoding);
+    item.push_item(MODIFIED_ATTRIBUTES_SEQUENCE, modified_attributes);
+    item.set_element(
+        ATTRIBUTE_MODIFICATION_DATE_TIME,
+        Vr::Dt,
+        encode_value(
+            Local::now().format("%Y%m%d%H%M%S%.6f%z").to_string(),
+            Vr::Dt,
+        ),
+    );
+    item.set_element(
+        MODIFYING_SYSTEM,
+        Vr::Lo,
+        encode_value(modifying_system.to_string(), Vr::Lo),
+    );
+    item.set_element(
+        SOURCE_OF_PREVIOUS_VALUES,
+        Vr::Lo,
+        encode_value(source_of_previous_values.to_string(), Vr::Lo),
+    );
+    item.set_element(
+        REASON_FOR_THE_ATTRIBUTE_MODIFICATION,
+        Vr::Cs,
+        encode_value("COERCE".to_string(), Vr::Cs),
+    );
+    if !data_set.push_item(ORIGINAL_ATTRIBUTES_SEQUENCE, item) {
+        warn!(
+            "Original Attributes Sequenceがシーケンスでないため、変更前の値を記録できませんでした"
+        );
+    }
+
+    originals.into_keys().collect()
+}
+
+/// 最上位の属性の値を、末尾のパディングを除いた文字列として取得する。
+fn get_value(data_set: &DataSet, tag: Tag) -> Option<String> {
+    data_set.find_index(tag).map(|i| {
+        String::from_utf8_lossy(data_set[i].value_field())
+            .trim_end_matches([' ', '\0'])
+            .to_string()
+    })
+}
+
+/// 文字列を、VRに応じたパディングを行った値フィールドに変換する。
+fn encode_value(value: String, vr: Vr) -> Vec<u8> {
+    let mut value_field = value.into_bytes();
+    if !value_field.len().is_multiple_of(2) {
+        value_field.push(if vr == Vr::Ui { b'\0' } else { b' ' });
+    }
+    value_field
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_lib::core::Encoding;
+
+    const INSTITUTION_NAME: Tag = Tag(0x0008, 0x0080);
+    const STUDY_DESCRIPTION: Tag = Tag(0x0008, 0x1030);
+    const PATIENT_ID: Tag = Tag(0x0010, 0x0020);
+    const PATIENT_SEX: Tag = Tag(0x0010, 0x0040);
+    const STUDY_ID: Tag = Tag(0x0020, 0x0010);
+
+    fn data_set() -> DataSet {
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        data_set.set_element(Tag(0x0008, 0x0060), Vr::Cs, b"CT".to_vec());
+        data_set.set_element(STUDY_DESCRIPTION, Vr::Lo, b"CHEST".to_vec());
+        data_set.set_element(PATIENT_ID, Vr::Lo, b"id-001".to_vec());
+        data_set.set_element(PATIENT_SEX, Vr::Cs, b"MALE".to_vec());
+        data_set
+    }
+
+    fn rule(action: i16, tag: &str, value: &str) -> CoercionRule {
+        CoercionRule::new(
+            action,
+            tag,
+            None,
+            "",
+            value.to_string(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .unwrap()
+    }
+
+    /// Original Attributes Sequenceの最初の項目に記録された変更前の属性の一覧を返す。
+    fn original_attributes(data_set: &DataSet) -> Vec<(Tag, String)> {
+        let index = data_set.find_index(ORIGINAL_ATTRIBUTES_SEQUENCE).unwrap();
+        let item = data_set.items(index).remove(0);
+        assert_eq!(get_value(&item, MODIFYING_SYSTEM).unwrap(), "OCEANUS");
+        assert_eq!(
+            get_value(&item, SOURCE_OF_PREVIOUS_VALUES).unwrap(),
+            "MODALITY"
+        );
+        assert_eq!(
+            get_value(&item, REASON_FOR_THE_ATTRIBUTE_MODIFICATION).unwrap(),
+            "COERCE"
+        );
+
+        let index = item.find_index(MODIFIED_ATTRIBUTES_SEQUENCE).unwrap();
+        let modified_attributes = item.items(index).remove(0);
+        modified_attributes
+            .into_iter()
+            .map(|e| (e.tag(), get_value(&modified_attributes, e.tag()).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_coercion_rules() {
+        // Arrange
+        let mut data_set = data_set();
+        let rules = [
+            // 値の設定（属性が存在しない場合は追加する）
+            rule(1, "00080080", "OCEANUS HOSPITAL"),
+            // 正規表現による置換
+            CoercionRule::new(
+                4,
+                "00100020",
+                None,
+                "^id-",
+                "ID".to_string(),
+                Vec::new(),
+                Vec::new(),
+            )
+            .unwrap(),
+            // 対応表による置換
+            CoercionRule::new(
+                5,
+                "00100040",
+                None,
+                "",
+                String::new(),
+                vec!["FEMALE".to_string(), "MALE".to_string()],
+                vec!["F".to_string(), "M".to_string()],
+            )
+            .unwrap(),
+            // 属性の複写
+            CoercionRule::new(
+                2,
+                "00200010",
+                Some("00100020"),
+                "",
+                String::new(),
+                Vec::new(),
+                Vec::new(),
+            )
+            .unwrap(),
+            // 属性の削除
+            rule(3, "00081030", ""),
+        ];
+
+        // Act
+        let modified_tags = apply_coercion_rules(&mut data_set, &rules, "OCEANUS", "MODALITY");
+
+        // Assert
+        assert_eq!(
+            modified_tags,
+            vec![
+                INSTITUTION_NAME,
+                STUDY_DESCRIPTION,
+                PATIENT_ID,
+                PATIENT_SEX,
+                STUDY_ID
+            ]
+        );
+        assert_eq!(
+            get_value(&data_set, INSTITUTION_NAME).unwrap(),
+            "OCEANUS HOSPITAL"
+        );
+        assert_eq!(get_value(&data_set, STUDY_DESCRIPTION), None);
+        assert_eq!(get_value(&data_set, PATIENT_ID).unwrap(), "ID001");
+        assert_eq!(get_value(&data_set, PATIENT_SEX).unwrap(), "M");
+        assert_eq!(get_value(&data_set, STUDY_ID).unwrap(), "ID001");
+        assert_eq!(get_value(&data_set, Tag(0x0008, 0x0060)).unwrap(), "CT");
+
+        // 元の値が存在しなかった属性は、値が空の属性として記録する
+        assert_eq!(
+            original_attributes(&data_set),
+            vec![
+                (INSTITUTION_NAME, String::new()),
+                (STUDY_DESCRIPTION, "CHEST".to_string()),
+                (PATIENT_ID, "id-001".to_string()),
+                (PATIENT_SEX, "MALE".to_string()),
+                (STUDY_ID, String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_coercion_rules_without_rules() {
+        // Arrange
+        let mut data_set = data_set();
+        let expected: Vec<u8> = data_set.clone().into();
+
+        // Act
+        let modified_tags = apply_coercion_rules(&mut data_set, &[], "OCEANUS", "MODALITY");
+
+        // Assert
+        assert!(modified_tags.is_empty());
+        assert_eq!(Vec::<u8>::from(data_set), expected);
+    }
+
+    #[test]
+    fn test_apply_coercion_rules_without_changes() {
+        // Arrange
+        let mut data_set = data_set();
+        let expected: Vec<u8> = data_set.clone().into();
+        let rules = [
+            // 値が変わらない規則
+            rule(1, "00100040", "MALE"),
+            // 複数の規則の適用により元の値に戻る規則
+            rule(1, "00100020", "ID001"),
+            rule(1, "00100020", "id-001"),
+            // 属性が存在しないため何もしない規則
+            rule(3, "00080080", ""),
+        ];
+
+        // Act
+        let modified_tags = apply_coercion_rules(&mut data_set, &rules, "OCEANUS", "MODALITY");
+
+        // Assert
+        // 値が変わらない場合は変更前の値を記録しない
+        assert!(modified_tags.is_empty());
+        assert_eq!(Vec::<u8>::from(data_set), expected);
+    }
+
+    #[test]
+    fn test_invalid_coercion_rule() {
+        let new = |action, tag, source_tag, pattern: &str| {
+            CoercionRule::new(
+                action,
+                tag,
+                source_tag,
+                pattern,
+                String::new(),
+                Vec::new(),
+                Vec::new(),
+            )
+        };
+
+        // Act & Assert
+        // 文字列を値として持たない属性
+        assert!(new(1, "7FE00010", None, "").is_err());
+        // タグの形式が不正
+        assert!(new(1, "0010002", None, "").is_err());
+        // 複写元の属性が指定されていない
+        assert!(new(2, "00100020", None, "").is_err());
+        // 正規表現が不正
+        assert!(new(4, "00100020", None, "(").is_err());
+        // 不明な処理
+        assert!(new(6, "00100020", None, "").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use dicom_lib::{
+    constants::transfer_syntax_uids::{
+        DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN, EXPLICIT_VR_BIG_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN,
+    },
+    core::{DataSet, Encoding, Tag},
+};
+use std::io::Cursor;
+
+const PREAMBLE_LENGTH: usize = 128;
+const PREFIX: &[u8; 4] = b"DICM";
+/// File Meta Information Group Length (0002,0000) の値フィールドの位置
+const GROUP_LENGTH_VALUE_POSITION: usize = PREAMBLE_LENGTH + 4 + 8;
+
+/// 保存済みのDICOMファイル（PS3.10形式）を読み込んだ結果
+pub struct DicomFile {
+    /// ファイルメタ情報の末尾の位置（データセットの先頭の位置）
+    pub meta_end: usize,
+    /// Media Storage SOP Class UID (0002,0002)
+    pub sop_class_uid: Option<String>,
+    /// Media Storage SOP Instance UID (0002,0003)
+    pub sop_instance_uid: Option<String>,
+    pub transfer_syntax_uid: String,
+    /// Source Application Entity Title (0002,0016)
+    pub source_ae_title: Option<String>,
+    /// Receiving Application Entity Title (0002,0018)
+    pub receiving_ae_title: Option<String>,
+    pub data_set: DataSet,
+}
+
+impl DicomFile {
+    /// DICOMファイルを読み込む。
+    pub fn read(buf: &[u8]) -> Result<Self, String> {
+        if buf.len() < GROUP_LENGTH_VALUE_POSITION + 4
+            || &buf[PREAMBLE_LENGTH..PREAMBLE_LENGTH + 4] != PREFIX
+        {
+            return Err("DICOMファイルの形式ではありません".to_string());
+        }
+
+        let group_length = u32::from_le_bytes(
+            buf[GROUP_LENGTH_VALUE_POSITION..GROUP_LENGTH_VALUE_POSITION + 4]
+                .try_into()
+                .unwrap(),
+        ) as usize;
+        let meta_end = GROUP_LENGTH_VALUE_POSITION + 4 + group_length;
+        if buf.len() < meta_end {
+            return Err("ファイルメタ情報が途中で終わっています".to_string());
+        }
+        let meta_data_set = {
+            let mut cur = Cursor::new(&buf[..meta_end]);
+            cur.set_position((PREAMBLE_LENGTH + 4) as u64);
+            DataSet::read_from_cur(&mut cur, Encoding::ExplicitVrLittleEndian)
+                .map_err(|e| format!("ファイルメタ情報の読み込みに失敗しました: {e}"))?
+        };
+
+        let transfer_syntax_uid = find_string(&meta_data_set, Tag(0x0002, 0x0010))
+            .ok_or_else(|| "転送構文UIDが存在しません".to_string())?;
+        let encoding = match transfer_syntax_uid.as_str() {
+            IMPLICIT_VR_LITTLE_ENDIAN => Encoding::ImplicitVrLittleEndian,
+            EXPLICIT_VR_BIG_ENDIAN | DEFLATED_EXPLICIT_VR_LITTLE_ENDIAN => {
+                return Err(format!(
+                    "読み込みに対応していない転送構文です (転送構文UID=\"{transfer_syntax_uid}\")"
+                ));
+            }
+            _ => Encoding::ExplicitVrLittleEndian,
+        };
+
+        let data_set = {
+            let mut cur = Cursor::new(buf);
+            cur.set_position(meta_end as u64);
+            DataSet::read_from_cur(&mut cur, encoding)
+                .map_err(|e| format!("データセットの読み込みに失敗しました: {e}"))?
+        };
+
+        Ok(Self {
+            meta_end,
+            sop_class_uid: find_string(&meta_data_set, Tag(0x0002, 0x0002)),
+            sop_instance_uid: find_string(&meta_data_set, Tag(0x0002, 0x0003)),
+            transfer_syntax_uid,
+            source_ae_title: find_string(&meta_data_set, Tag(0x0002, 0x0016)),
+            receiving_ae_title: find_string(&meta_data_set, Tag(0x0002, 0x0018)),
+            data_set,
+        })
+    }
+}
+
+/// 最上位のデータ要素の値を文字列として返す。
+pub fn find_string(data_set: &DataSet, tag: Tag) -> Option<String> {
+    data_set.into_iter().find(|e| e.tag() == tag).map(|e| {
+        String::from_utf8_lossy(e.value_field())
+            .trim_end_matches(['\0', ' '])
+            .to_string()
+    })
+}
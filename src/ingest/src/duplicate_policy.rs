@@ -0,0 +1,153 @@
+use clap::ValueEnum;
+use dicom_lib::network::dimse::c_store::c_store_rsp::{
+    Status,
+    status::code::{DataSetDoesNotMatchSopClass, Warning},
+};
+
+/// 保存済みのSOPインスタンスと同じSOPインスタンスUIDを持つSOPインスタンスを受信した場合の処理方針
+///
+/// 宛先AEごとに`local_application_entities`テーブルの`duplicate_policy`で設定する。
+#[derive(ValueEnum, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DuplicatePolicy {
+    /// 受信したSOPインスタンスを拒否する
+    Reject = 0,
+    /// 保存済みのSOPインスタンスを維持し、受信したSOPインスタンスを破棄する
+    KeepFirst = 1,
+    /// 保存済みのSOPインスタンスを受信したSOPインスタンスで上書きする
+    ///
+    /// 受信したSOPインスタンスは新しい版として別のファイルに保存し、置き換え前の版は`sop_instance_histories`テーブルに記録する。
+    /// 置き換え前の版のファイルは削除しないため、履歴として残る。
+    Overwrite = 2,
+}
+
+impl TryFrom<i16> for DuplicatePolicy {
+    type Error = String;
+
+    fn try_from(val: i16) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(DuplicatePolicy::Reject),
+            1 => Ok(DuplicatePolicy::KeepFirst),
+            2 => Ok(DuplicatePolicy::Overwrite),
+            _ => Err(format!("重複時の処理方針の値が不正です (値={val})")),
+        }
+    }
+}
+
+impl From<DuplicatePolicy> for i16 {
+    fn from(val: DuplicatePolicy) -> Self {
+        val as i16
+    }
+}
+
+/// 重複したSOPインスタンスに対して行う処理
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DuplicateAction {
+    /// 保存済みのSOPインスタンスと内容が同一のため、保存しない
+    Identical,
+    /// 受信したSOPインスタンスを拒否する
+    Reject,
+    /// 受信したSOPインスタンスを破棄する
+    KeepFirst,
+    /// 保存済みのSOPインスタンスを上書きする
+    Overwrite,
+}
+
+impl DuplicateAction {
+    /// 処理方針と内容が同一かどうかから、行う処理を決定する。
+    ///
+    /// 内容が同一の場合は、処理方針によらず再送とみなして保存しない。
+    pub fn decide(policy: DuplicatePolicy, is_identical: bool) -> Self {
+        if is_identical {
+            return DuplicateAction::Identical;
+        }
+
+        match policy {
+            DuplicatePolicy::Reject => DuplicateAction::Reject,
+            DuplicatePolicy::KeepFirst => DuplicateAction::KeepFirst,
+            DuplicatePolicy::Overwrite => DuplicateAction::Overwrite,
+        }
+    }
+
+    /// 受信したSOPインスタンスを保存するかどうか
+    pub fn should_store(&self) -> bool {
+        matches!(self, DuplicateAction::Overwrite)
+    }
+
+    /// C-STORE-RSPで返すステータス
+    ///
+    /// ステータスコードはStorage Service Classで実装固有の値として定義できる範囲から割り当てる。
+    /// - 0xb010: 内容が同一のSOPインスタンスを保存済み
+    /// - 0xb011: 内容が異なるSOPインスタンスを保存済みのため、受信したSOPインスタンスを破棄した
+    /// - 0xb012: 保存済みのSOPインスタンスを上書きした
+    /// - 0xa910: 内容が異なるSOPインスタンスを保存済みのため、受信したSOPインスタンスを拒否した
+    pub fn status(&self) -> Status {
+        match self {
+            DuplicateAction::Identical => Status::Warning(Warning::new(0xb010).unwrap()),
+            DuplicateAction::KeepFirst => Status::Warning(Warning::new(0xb011).unwrap()),
+            DuplicateAction::Overwrite => Status::Warning(Warning::new(0xb012).unwrap()),
+            DuplicateAction::Reject => Status::DataSetDoesNotMatchSopClass(
+                DataSetDoesNotMatchSopClass::new(0xa910).unwrap(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_policy_try_from() {
+        for policy in [
+            DuplicatePolicy::Reject,
+            DuplicatePolicy::KeepFirst,
+            DuplicatePolicy::Overwrite,
+        ] {
+            // Act
+            let actual = DuplicatePolicy::try_from(i16::from(policy));
+
+            // Assert
+            assert_eq!(actual, Ok(policy));
+        }
+        assert!(DuplicatePolicy::try_from(3).is_err());
+        assert!(DuplicatePolicy::try_from(-1).is_err());
+    }
+
+    #[test]
+    fn test_decide() {
+        #[rustfmt::skip]
+        let cases = [
+            (DuplicatePolicy::Reject, DuplicateAction::Reject, false, 0xa910),
+            (DuplicatePolicy::KeepFirst, DuplicateAction::KeepFirst, false, 0xb011),
+            (DuplicatePolicy::Overwrite, DuplicateAction::Overwrite, true, 0xb012),
+        ];
+
+        for (policy, expected_action, expected_should_store, expected_status) in cases {
+            // Act
+            let actual = DuplicateAction::decide(policy, false);
+
+            // Assert
+            assert_eq!(actual, expected_action, "{policy:?}");
+            assert_eq!(actual.should_store(), expected_should_store, "{policy:?}");
+            assert_eq!(u16::from(actual.status()), expected_status, "{policy:?}");
+        }
+    }
+
+    #[test]
+    fn test_decide_identical() {
+        for policy in [
+            DuplicatePolicy::Reject,
+            DuplicatePolicy::KeepFirst,
+            DuplicatePolicy::Overwrite,
+        ] {
+            // Act
+            let actual = DuplicateAction::decide(policy, true);
+
+            // Assert
+            // 内容が同一の場合は、処理方針によらず再送とみなして保存しない
+            assert_eq!(actual, DuplicateAction::Identical, "{policy:?}");
+            assert!(!actual.should_store(), "{policy:?}");
+            assert_eq!(u16::from(actual.status()), 0xb010, "{policy:?}");
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{DuplicatePolicy, RewrittenFile, SavedFile};
+use sqlx::{PgExecutor, query, types::Uuid};
+
+/// ジャーナルに記録する処理
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum JournalOperation {
+    /// 受信したSOPインスタンスの保存
+    Store = 0,
+    /// 保存済みのファイルのPatient IDの書き換え
+    Rewrite = 1,
+}
+
+impl TryFrom<i16> for JournalOperation {
+    type Error = String;
+
+    fn try_from(val: i16) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(JournalOperation::Store),
+            1 => Ok(JournalOperation::Rewrite),
+            _ => Err(format!("ジャーナルに記録した処理の値が不正です (値={val})")),
+        }
+    }
+}
+
+/// 受信したSOPインスタンスのファイルの保存からDBへの登録までを記録するジャーナル
+///
+/// `storage_journals`テーブルに記録する。
+/// ファイルの保存前に[`StorageJournal::begin`]で保存先のキーとともに記録し、保存後に[`StorageJournal::record_uri`]で保存先のURIを記録する。
+/// 保存先のURIを記録する前に中断した場合は、キーから保存したファイルを探す。
+/// 記録はDBへの登録と同じトランザクションで削除するため、起動時に残っている記録は中断した処理を表す。
+///
+/// 保存済みのファイルのPatient IDを書き換える場合も、ファイルの置き換え前に[`StorageJournal::begin_rewrite`]で書き換え後のハッシュ値を記録する。
+pub struct StorageJournal {
+    uuid: Uuid,
+    /// 保存先のキー
+    key: String,
+    /// 保存先のURI（ファイルの保存前は`None`）
+    uri: Option<String>,
+}
+
+impl StorageJournal {
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn uri(&self) -> Option<&str> {
+        self.uri.as_deref()
+    }
+
+    /// `storage_journals`テーブルに残っている記録から復元する。
+    pub fn restore(uuid: Uuid, key: String, uri: Option<String>) -> Self {
+        Self { uuid, key, uri }
+    }
+
+    /// ファイルの保存前に、DBへの登録に必要な情報と保存先のキーを記録する。
+    pub async fn begin(
+        executor: impl PgExecutor<'_>,
+        key: &str,
+        sop_instance_uid: &str,
+        called_ae_title: &str,
+        ae_uuid: Uuid,
+        saved_file: &SavedFile<'_>,
+        replaced_policy: Option<DuplicatePolicy>,
+    ) -> Result<Self, sqlx::Error> {
+        let record = query!(
+            r#"
+            INSERT INTO storage_journals (sop_instance_uid, called_ae_title, transfer_syntax_uid, size, content_hash, file_hash, version, policy, key, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING uuid
+            "#,
+            sop_instance_uid,
+            called_ae_title,
+            saved_file.transfer_syntax_uid,
+            saved_file
+                .size_for_db()
+                .map_err(|e| sqlx::Error::Encode(e.into()))?,
+            saved_file.content_hash,
+            saved_file.file_hash,
+            saved_file.version,
+            replaced_policy.map(i16::from),
+            key,
+            ae_uuid,
+        )
+        .fetch_one(executor)
+        .await?;
+
+        Ok(Self {
+            uuid: record.uuid,
+            key: key.to_string(),
+            uri: None,
+        })
+    }
+
+    /// 保存済みのファイルの置き換え前に、書き換え後のファイルのサイズとハッシュ値を記録する。
+    ///
+    /// SOPインスタンスUID等は、ファイルを参照するSOPインスタンスまたは置き換え前の版の記録から引き継ぐ。
+    /// ファイルを参照する記録が存在しない場合は`None`を返す。
+    pub async fn begin_rewrite(
+        executor: impl PgExecutor<'_>,
+        uri: &str,
+        rewritten_file: &RewrittenFile<'_>,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        let record = query!(
+            r#"
+            INSERT INTO storage_journals (sop_instance_uid, called_ae_title, transfer_syntax_uid, size, content_hash, file_hash, version, key, uri, operation, created_by)
+            SELECT instance_uid, called_ae_title, transfer_syntax_uid, $2, $3, $4, version, $1, $1, $5, created_by
+            FROM (
+                SELECT instance_uid, called_ae_title, transfer_syntax_uid, version, updated_by AS created_by FROM sop_instances WHERE path = $1
+                UNION ALL
+                SELECT instance_uid, called_ae_title, transfer_syntax_uid, version, created_by FROM sop_instance_histories WHERE path = $1
+                LIMIT 1
+            ) AS instance
+            RETURNING uuid
+            "#,
+            uri,
+            rewritten_file
+                .size_for_db()
+                .map_err(|e| sqlx::Error::Encode(e.into()))?,
+            rewritten_file.content_hash,
+            rewritten_file.file_hash,
+            JournalOperation::Rewrite as i16,
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(record.map(|record| Self {
+            uuid: record.uuid,
+            key: uri.to_string(),
+            uri: Some(uri.to_string()),
+        }))
+    }
+
+    /// ファイルの保存後に、保存先のURIを記録する。
+    pub async fn record_uri(
+        &mut self,
+        executor: impl PgExecutor<'_>,
+        uri: String,
+    ) -> Result<(), sqlx::Error> {
+        query!(
+            "UPDATE storage_journals SET uri = $2 WHERE uuid = $1",
+            self.uuid,
+            uri
+        )
+        .execute(executor)
+        .await?;
+        self.uri = Some(uri);
+
+        Ok(())
+    }
+
+    /// ファイルの保存または置き換えに失敗した場合に記録を削除する。
+    pub async fn discard(&self, executor: impl PgExecutor<'_>) -> Result<(), sqlx::Error> {
+        query!("DELETE FROM storage_journals WHERE uuid = $1", self.uuid)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,814 @@
+//! DICOMサーバー（C-STORE）とWeb API（STOW-RS）で共通の、SOPインスタンスの保存処理
+//!
+//! 受信したデータセットからのインスタンス情報の抽出、ストレージ上のファイルの配置、
+//! DBへの登録を同じ手順で行い、受信経路によらず同じ結果となるようにする。
+
+mod coercion;
+pub mod dicom_file;
+mod duplicate_policy;
+pub mod instance_info;
+mod journal;
+
+pub use self::{
+    coercion::CoercionRule,
+    duplicate_policy::{DuplicateAction, DuplicatePolicy},
+    instance_info::InstanceInfo,
+    journal::{JournalOperation, StorageJournal},
+};
+
+use chrono::Datelike;
+use dicom_lib::{
+    core::DataSet,
+    dictionaries::SOP_CLASS_DICTIONARY,
+    file::{File, file_meta_information::FileMetaInformation},
+    network::dimse::c_store::c_store_rsp::{Status, status::code::OutOfResources},
+};
+use sha2::{Digest, Sha256};
+use sqlx::{
+    Acquire, PgConnection, PgExecutor, Pool, Postgres, pool::PoolConnection, query, query_scalar,
+    types::Uuid,
+};
+use std::io::ErrorKind;
+use storage::{StorageBackend, calculate_file_hash, size_for_db};
+use tracing::{error, info, warn};
+
+/// SOPインスタンスを受け付けた宛先AEおよび登録者の情報
+pub struct StoreContext<'a> {
+    pub db_pool: &'a Pool<Postgres>,
+    /// 宛先AEごとのDICOMファイルの保存先
+    pub storage: &'a dyn StorageBackend,
+    /// 宛先AEタイトル
+    pub called_ae_title: &'a str,
+    /// 呼出元AEタイトル（書き換え規則の変更前の値の記録に使用する）
+    pub calling_ae_title: &'a str,
+    /// 登録者のUUID（C-STOREの場合は呼出元AE、STOW-RSの場合はユーザー）
+    pub created_by: Uuid,
+    /// 宛先AEごとの重複したSOPインスタンスの処理方針
+    pub duplicate_policy: DuplicatePolicy,
+    /// 呼出元AEごとの受信時の属性の書き換え規則
+    pub coercion_rules: &'a [CoercionRule],
+    /// ログの接頭辞（例: `[1] C-STORE`）
+    pub log_prefix: &'a str,
+}
+
+/// 受信したSOPインスタンスを保存し、保存結果をStorage Service Classのステータスとして返す。
+///
+/// 書き換え規則の適用、インスタンス情報の抽出、重複の判定、ジャーナルへの記録、ファイルの保存、DBへの登録を順に行う。
+/// 送信されたデータが原因で保存できない場合は、失敗を表すステータスを返す。
+/// 内部エラー（DBへの接続の問題等）が発生した場合は、エラーの内容を返す。
+pub async fn store(
+    context: &StoreContext<'_>,
+    file_meta_info: FileMetaInformation,
+    data_set: DataSet,
+    transfer_syntax_uid: &str,
+    affected_sop_instance_uid: &str,
+) -> Result<Status, String> {
+    let log_prefix = context.log_prefix;
+
+    // 呼出元AEごとの書き換え規則を適用する
+    // 変更前の値の記録には書き換えた日時が含まれるため、重複の判定には書き換え前のデータセットのハッシュ値を用いる
+    let mut data_set = data_set;
+    let mut received_content_hash = None;
+    if !context.coercion_rules.is_empty() {
+        let received_data_set_buf: Vec<u8> = data_set.clone().into();
+        let modified_tags = coercion::apply_coercion_rules(
+            &mut data_set,
+            context.coercion_rules,
+            context.called_ae_title,
+            context.calling_ae_title,
+        );
+        if !modified_tags.is_empty() {
+            received_content_hash = Some(calculate_content_hash(&received_data_set_buf));
+            info!(
+                "{log_prefix} - 書き換え規則により属性を変更しました (SOPインスタンスUID=\"{affected_sop_instance_uid}\", タグ={})",
+                modified_tags
+                    .iter()
+                    .map(|tag| tag.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+        }
+    }
+
+    let instance_info = {
+        match InstanceInfo::from_data_set(&data_set) {
+            Ok(val) => val,
+            Err((message, error_status)) => {
+                error!("データセットからのインスタンス情報の抽出に失敗しました: {message}");
+
+                // データセットをファイルとして保存
+                let file = File::new(file_meta_info, data_set);
+                let key = generate_error_key(affected_sop_instance_uid);
+                if let Err(e) = context.storage.put(&key, file.into()).await {
+                    error!(
+                        "インスタンス情報の抽出に失敗したデータセットをファイルとして保存できませんでした: {e}"
+                    );
+                }
+
+                return Ok(error_status.into());
+            }
+        }
+    };
+
+    let sop_class = SOP_CLASS_DICTIONARY
+        .get(instance_info.sop_instance.class_uid())
+        .unwrap_or(&"Unknown SOP Class");
+
+    let patient_id = instance_info.patient.id();
+    let patient_name = {
+        let name_alphabet = instance_info.patient.name_alphabet();
+        let name_kanji = instance_info.patient.name_kanji();
+        let name_hiragana = instance_info.patient.name_hiragana();
+        format!("{name_alphabet}={name_kanji}={name_hiragana}")
+    };
+    let study_instance_uid = instance_info.study.instance_uid();
+    let study_id = instance_info.study.id();
+    let study_date_time = {
+        let study_date = match instance_info.study.date() {
+            Some(date) => date.to_string(),
+            None => String::new(),
+        };
+        let study_time = match instance_info.study.time() {
+            Some(time) => time.to_string(),
+            None => String::new(),
+        };
+        match (!study_date.is_empty(), !study_time.is_empty()) {
+            (true, true) => format!("{}T{}", study_date, study_time),
+            (true, false) => study_date,
+            (false, true) => study_time,
+            (false, false) => String::new(),
+        }
+    };
+    let accession_number = instance_info.study.accession_number();
+    let series_instance_uid = instance_info.series.instance_uid();
+    let modality = instance_info.series.modality();
+    let series_number = match instance_info.series.number() {
+        Some(number) => number.to_string(),
+        None => String::new(),
+    };
+    let sop_instance_uid = instance_info.sop_instance.instance_uid();
+    let instance_number = match instance_info.sop_instance.number() {
+        Some(number) => number.to_string(),
+        None => String::new(),
+    };
+
+    let data_set_size = data_set.size();
+    let file_buf: Vec<u8> = File::new(file_meta_info, data_set).into();
+    let file_size = file_buf.len() as u64;
+    // 内容の同一性はファイルメタ情報を除いたデータセット部分のハッシュ値で判定する
+    let content_hash = received_content_hash.unwrap_or_else(|| {
+        calculate_content_hash(&file_buf[(file_size - data_set_size) as usize..])
+    });
+    // 読み出し時の検証に用いるため、保存するファイル全体のハッシュ値を記録する
+    let file_hash = calculate_file_hash(&file_buf);
+
+    // 重複の判定からDBへの登録までの間、同じSOPインスタンスUIDのSOPインスタンスの保存を直列化する
+    let mut lock = SopInstanceLock::acquire(context.db_pool, sop_instance_uid)
+        .await
+        .map_err(|e| format!("SOPインスタンスのロックの取得に失敗しました: {e}"))?;
+    let connection = &mut *lock.connection;
+    let result = async {
+        // 同じSOPインスタンスUIDのSOPインスタンスが保存済みの場合、処理方針に従って処理を決定する
+        let stored_instance = fetch_stored_instance(&mut *connection, sop_instance_uid)
+            .await
+            .map_err(|e| format!("保存済みのSOPインスタンスの取得に失敗しました: {e}"))?;
+        let duplicate_action = stored_instance.as_ref().map(|stored_instance| {
+            DuplicateAction::decide(
+                context.duplicate_policy,
+                stored_instance.content_hash == content_hash,
+            )
+        });
+        if let (Some(stored_instance), Some(duplicate_action)) = (&stored_instance, duplicate_action)
+            && !duplicate_action.should_store()
+        {
+            match duplicate_action {
+                DuplicateAction::Identical => info!(
+                    "{log_prefix} - 内容が同一のSOPインスタンスを保存済みのため保存しませんでした (SOPインスタンスUID=\"{sop_instance_uid}\", パス=\"{}\")",
+                    stored_instance.path
+                ),
+                DuplicateAction::Reject => warn!(
+                    "{log_prefix} - 内容が異なるSOPインスタンスを保存済みのため拒否しました (SOPインスタンスUID=\"{sop_instance_uid}\", パス=\"{}\")",
+                    stored_instance.path
+                ),
+                _ => warn!(
+                    "{log_prefix} - 内容が異なるSOPインスタンスを保存済みのため破棄しました (SOPインスタンスUID=\"{sop_instance_uid}\", パス=\"{}\")",
+                    stored_instance.path
+                ),
+            }
+
+            return Ok(duplicate_action.status());
+        }
+        // 上書きする場合も、置き換え前の版の記録が参照するファイルを残すため、新しい版として別のファイルに保存する
+        let version = match &stored_instance {
+            Some(stored_instance) => stored_instance.version + 1,
+            None => 1,
+        };
+
+        // ファイルの保存とDBへの登録の間で中断しても復旧できるよう、保存前にジャーナルに記録する
+        let saved_file = SavedFile {
+            transfer_syntax_uid,
+            size: file_size,
+            content_hash: &content_hash,
+            file_hash: &file_hash,
+            version,
+        };
+        let replaced_policy = duplicate_action.map(|_| context.duplicate_policy);
+        let key = generate_success_key(&instance_info, version);
+        let mut journal = StorageJournal::begin(
+            &mut *connection,
+            &key,
+            sop_instance_uid,
+            context.called_ae_title,
+            context.created_by,
+            &saved_file,
+            replaced_policy,
+        )
+        .await
+        .map_err(|e| format!("ジャーナルへの記録に失敗しました: {e}"))?;
+
+        // データセットをファイルとして保存
+        let uri = match context.storage.put(&key, file_buf).await {
+            Ok(val) => val,
+            Err(e) => {
+                if let Err(e) = journal.discard(&mut *connection).await {
+                    error!("ジャーナルの削除に失敗しました: {e}");
+                }
+                return match e.io_error().map(|io_error| io_error.kind()) {
+                    Some(
+                        ErrorKind::StorageFull
+                        | ErrorKind::FileTooLarge
+                        | ErrorKind::OutOfMemory
+                        | ErrorKind::WriteZero,
+                    ) => {
+                        // リソース不足
+                        error!("データセットをファイルとして保存できませんでした: {e}");
+                        Ok(Status::OutOfResources(OutOfResources::new(0xa700).unwrap()))
+                    }
+                    _ => Err(format!(
+                        "データセットをファイルとして保存できませんでした: {e}"
+                    )),
+                };
+            }
+        };
+
+        journal
+            .record_uri(&mut *connection, uri)
+            .await
+            .map_err(|e| format!("ジャーナルへの保存先の記録に失敗しました: {e}"))?;
+
+        // DBへ情報を保存
+        let is_patient_conflicted = save_instance_to_db(
+            &mut *connection,
+            &instance_info,
+            context.created_by,
+            context.called_ae_title,
+            &saved_file,
+            replaced_policy,
+            &journal,
+        )
+        .await
+        .map_err(|e| format!("データベースへの情報の保存に失敗しました: {e}"))?;
+        if is_patient_conflicted {
+            warn!(
+                "{log_prefix} - 登録済みの患者と患者属性が一致しないため、照合キューに記録しました (患者ID=\"{patient_id}\", 患者氏名=\"{patient_name}\", 検査インスタンスUID=\"{study_instance_uid}\")"
+            );
+        }
+
+        info!(
+            "{log_prefix} - {sop_class} (患者ID=\"{patient_id}\", 患者氏名=\"{patient_name}\", 検査インスタンスUID=\"{study_instance_uid}\", 検査ID=\"{study_id}\" 検査日時=\"{study_date_time}\", 受付番号=\"{accession_number}\", シリーズインスタンスUID=\"{series_instance_uid}\", モダリティ=\"{modality}\", シリーズ番号={series_number}, SOPインスタンスUID=\"{sop_instance_uid}\", インスタンス番号={instance_number})"
+        );
+
+        let status = match (&stored_instance, duplicate_action) {
+            (Some(stored_instance), Some(duplicate_action)) => {
+                warn!(
+                    "{log_prefix} - 内容が異なるSOPインスタンスを保存済みのため上書きしました (SOPインスタンスUID=\"{sop_instance_uid}\", 置き換え前のパス=\"{}\", 版={version})",
+                    stored_instance.path
+                );
+                duplicate_action.status()
+            }
+            _ => Status::Success,
+        };
+
+        Ok(status)
+    }
+    .await;
+    if let Err(e) = lock.release().await {
+        error!("SOPインスタンスのロックの解放に失敗しました: {e}");
+    }
+
+    result
+}
+
+/// データセットのSHA-256ハッシュ値を16進数の文字列として返す。
+pub fn calculate_content_hash(data_set_buf: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data_set_buf))
+}
+
+/// 保存に成功したファイルのストレージ上のキーを生成する。
+/// 2版以降のファイルは、ファイル名に版を付与する。
+fn generate_success_key(info: &InstanceInfo, version: i32) -> String {
+    let date = match info.study.date() {
+        Some(date) => format!("{:04}/{:02}/{:02}", date.year(), date.month(), date.day()),
+        None => "unknown_date".to_string(),
+    };
+    let file_name = if version == 1 {
+        format!("{}.dcm", info.sop_instance.instance_uid())
+    } else {
+        format!("{}_v{version}.dcm", info.sop_instance.instance_uid())
+    };
+
+    format!(
+        "success/{date}/{}/{}/{file_name}",
+        info.study.instance_uid(),
+        info.series.instance_uid()
+    )
+}
+
+/// 保存に失敗したファイルのストレージ上のキーを生成する。
+fn generate_error_key(affected_sop_instance_uid: &str) -> String {
+    format!("error/{affected_sop_instance_uid}.dcm")
+}
+
+/// 同じSOPインスタンスUIDのSOPインスタンスの保存を直列化するロック
+///
+/// PostgreSQLのセッションレベルのアドバイザリーロックを、ロックを取得した接続で保持する。
+/// ロックの保持中に別の接続を待たないよう、保存に必要なDBの操作はロックを取得した接続で行う。
+/// 解放せずに破棄した場合は、ロックが残らないよう接続を閉じる。
+pub struct SopInstanceLock {
+    connection: PoolConnection<Postgres>,
+    sop_instance_uid: String,
+    is_released: bool,
+}
+
+impl SopInstanceLock {
+    /// ロックを取得する。他の接続がロックを保持している場合は解放されるまで待つ。
+    pub async fn acquire(
+        db_pool: &Pool<Postgres>,
+        sop_instance_uid: &str,
+    ) -> Result<Self, sqlx::Error> {
+        let mut connection = db_pool.acquire().await?;
+        query!(
+            "SELECT pg_advisory_lock('sop_instances'::regclass::oid::int, hashtext($1))",
+            sop_instance_uid
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(Self {
+            connection,
+            sop_instance_uid: sop_instance_uid.to_string(),
+            is_released: false,
+        })
+    }
+
+    /// ロックの取得を試みる。他の接続がロックを保持している場合は待たずに`None`を返す。
+    pub async fn try_acquire(
+        db_pool: &Pool<Postgres>,
+        sop_instance_uid: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        let mut connection = db_pool.acquire().await?;
+        let is_acquired = query_scalar!(
+            r#"SELECT pg_try_advisory_lock('sop_instances'::regclass::oid::int, hashtext($1)) AS "is_acquired!""#,
+            sop_instance_uid
+        )
+        .fetch_one(&mut *connection)
+        .await?;
+        if !is_acquired {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            connection,
+            sop_instance_uid: sop_instance_uid.to_string(),
+            is_released: false,
+        }))
+    }
+
+    /// ロックを取得した接続
+    pub fn connection(&mut self) -> &mut PgConnection {
+        &mut self.connection
+    }
+
+    pub async fn release(mut self) -> Result<(), sqlx::Error> {
+        query!(
+            "SELECT pg_advisory_unlock('sop_instances'::regclass::oid::int, hashtext($1))",
+            self.sop_instance_uid
+        )
+        .fetch_one(&mut *self.connection)
+        .await?;
+        self.is_released = true;
+
+        Ok(())
+    }
+}
+
+impl Drop for SopInstanceLock {
+    fn drop(&mut self) {
+        if !self.is_released {
+            self.connection.close_on_drop();
+        }
+    }
+}
+
+/// 保存済みのSOPインスタンス
+struct StoredInstance {
+    path: String,
+    content_hash: String,
+    version: i32,
+}
+
+async fn fetch_stored_instance(
+    executor: impl PgExecutor<'_>,
+    sop_instance_uid: &str,
+) -> Result<Option<StoredInstance>, sqlx::Error> {
+    let record = query!(
+        "SELECT path, content_hash, version FROM sop_instances WHERE instance_uid = $1",
+        sop_instance_uid
+    )
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(record.map(|record| StoredInstance {
+        path: record.path,
+        content_hash: record.content_hash,
+        version: record.version,
+    }))
+}
+
+/// 保存するファイル
+///
+/// 保存先のキーはファイルの保存前に、保存先のURIはファイルの保存後に[`StorageJournal`]に記録する。
+pub struct SavedFile<'a> {
+    pub transfer_syntax_uid: &'a str,
+    pub size: u64,
+    /// データセット部分のSHA-256ハッシュ値
+    ///
+    /// 書き換え規則により属性を変更した場合は、書き換え前のデータセットのハッシュ値とする。
+    pub content_hash: &'a str,
+    /// ファイル全体のSHA-256ハッシュ値
+    pub file_hash: &'a str,
+    pub version: i32,
+}
+
+impl SavedFile<'_> {
+    /// DBに記録するサイズ
+    pub fn size_for_db(&self) -> Result<i64, String> {
+        size_for_db(self.size)
+    }
+}
+
+/// Patient IDを書き換えたファイル
+///
+/// 書き換え後のサイズとハッシュ値はファイルの置き換え前に[`StorageJournal`]に記録する。
+pub struct RewrittenFile<'a> {
+    pub size: u64,
+    /// データセット部分のSHA-256ハッシュ値
+    pub content_hash: &'a str,
+    /// ファイル全体のSHA-256ハッシュ値
+    pub file_hash: &'a str,
+}
+
+impl RewrittenFile<'_> {
+    /// DBに記録するサイズ
+    pub fn size_for_db(&self) -> Result<i64, String> {
+        size_for_db(self.size)
+    }
+}
+
+/// インスタンス情報をDBへ保存する。
+///
+/// 保存済みのSOPインスタンスを置き換える場合は`replaced_policy`に処理方針を指定する。
+/// 置き換え前のSOPインスタンスの情報は`sop_instance_histories`テーブルに記録する。
+///
+/// 登録済みの患者と患者属性が一致しない場合は`patient_conflicts`テーブルに記録し、`true`を返す。
+///
+/// ファイルの保存先はジャーナルに記録したURIとし、登録と同じトランザクションでジャーナルの記録を削除する。
+pub async fn save_instance_to_db(
+    db: impl Acquire<'_, Database = Postgres>,
+    instance_info: &InstanceInfo,
+    ae_uuid: Uuid,
+    called_ae_title: &str,
+    saved_file: &SavedFile<'_>,
+    replaced_policy: Option<DuplicatePolicy>,
+    journal: &StorageJournal,
+) -> Result<bool, String> {
+    let Some(path) = journal.uri() else {
+        return Err("ジャーナルに保存先のURIが記録されていません".to_string());
+    };
+    let size = saved_file.size_for_db()?;
+
+    let mut transaction = db
+        .begin()
+        .await
+        .map_err(|e| format!("トランザクションの開始に失敗しました: {e}"))?;
+
+    let sex = instance_info
+        .patient
+        .sex()
+        .as_ref()
+        .map(|s| s.to_iso_5218())
+        .unwrap_or(0); // not known
+    query!(
+        r#"
+        INSERT INTO patients (id, name_alphabet, name_kanji, name_hiragana, birth_date, sex, created_by, created_at, updated_by, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, now(), $7, now())
+        ON CONFLICT (id) DO NOTHING
+        "#, // 患者についてはもともと登録されていた情報を真とみなす
+        instance_info.patient.id(),
+        instance_info.patient.name_alphabet(),
+        instance_info.patient.name_kanji(),
+        instance_info.patient.name_hiragana(),
+        instance_info.patient.birth_date(),
+        sex,
+        ae_uuid,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("患者情報の保存に失敗しました: {e}"))?;
+
+    query!(
+        r#"
+        INSERT INTO studies (patient_id, instance_uid, id, study_date, study_time, accession_number, created_by, created_at, updated_by, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, now(), $7, now())
+        ON CONFLICT (instance_uid) DO UPDATE SET
+            patient_id = EXCLUDED.patient_id,
+            id = EXCLUDED.id,
+            study_date = EXCLUDED.study_date,
+            study_time = EXCLUDED.study_time,
+            accession_number = EXCLUDED.accession_number,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = EXCLUDED.updated_at
+        WHERE studies.patient_id IS DISTINCT FROM EXCLUDED.patient_id
+           OR studies.id IS DISTINCT FROM EXCLUDED.id
+           OR studies.study_date IS DISTINCT FROM EXCLUDED.study_date
+           OR studies.study_time IS DISTINCT FROM EXCLUDED.study_time
+           OR studies.accession_number IS DISTINCT FROM EXCLUDED.accession_number
+        "#,
+        instance_info.patient.id(),
+        instance_info.study.instance_uid(),
+        instance_info.study.id(),
+        instance_info.study.date(),
+        instance_info.study.time(),
+        instance_info.study.accession_number(),
+        ae_uuid,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("検査情報の保存に失敗しました: {e}"))?;
+
+    // 受信した患者属性が登録済みの患者と一致しない場合は照合キューに記録する
+    // 受信した患者属性と登録済みの患者属性のどちらかが空の項目は比較しない
+    let is_patient_conflicted = query!(
+        r#"
+        INSERT INTO patient_conflicts (patient_id, study_instance_uid, sop_instance_uid, name_alphabet, name_kanji, name_hiragana, birth_date, sex, created_by, created_at)
+        SELECT id, $2, $3, $4::varchar, $5::varchar, $6::varchar, $7::date, $8::smallint, $9, now()
+        FROM patients
+        WHERE id = $1
+          AND (($4 <> '' AND name_alphabet <> '' AND name_alphabet <> $4)
+            OR ($5 <> '' AND name_kanji <> '' AND name_kanji <> $5)
+            OR ($6 <> '' AND name_hiragana <> '' AND name_hiragana <> $6)
+            OR birth_date <> $7
+            OR ($8 <> 0 AND sex <> 0 AND sex <> $8))
+        ON CONFLICT (patient_id, study_instance_uid) WHERE status = 0 DO NOTHING
+        "#,
+        instance_info.patient.id(),
+        instance_info.study.instance_uid(),
+        instance_info.sop_instance.instance_uid(),
+        instance_info.patient.name_alphabet(),
+        instance_info.patient.name_kanji(),
+        instance_info.patient.name_hiragana(),
+        instance_info.patient.birth_date(),
+        sex,
+        ae_uuid,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("患者属性の不一致の記録に失敗しました: {e}"))?
+    .rows_affected()
+        > 0;
+
+    query!(
+        r#"
+        INSERT INTO series (study_instance_uid, instance_uid, modality, series_number, created_by, created_at, updated_by, updated_at)
+        VALUES ($1, $2, $3, $4, $5, now(), $5, now())
+        ON CONFLICT (instance_uid) DO UPDATE SET
+            study_instance_uid = EXCLUDED.study_instance_uid,
+            modality = EXCLUDED.modality,
+            series_number = EXCLUDED.series_number,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = EXCLUDED.updated_at
+        WHERE series.study_instance_uid IS DISTINCT FROM EXCLUDED.study_instance_uid
+           OR series.modality IS DISTINCT FROM EXCLUDED.modality
+           OR series.series_number IS DISTINCT FROM EXCLUDED.series_number
+        "#,
+        instance_info.study.instance_uid(),
+        instance_info.series.instance_uid(),
+        instance_info.series.modality(),
+        instance_info.series.number(),
+        ae_uuid,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("シリーズ情報の保存に失敗しました: {e}"))?;
+
+    if let Some(replaced_policy) = replaced_policy {
+        query!(
+            r#"
+            INSERT INTO sop_instance_histories (instance_uid, version, series_instance_uid, class_uid, transfer_syntax_uid, size, path, called_ae_title, content_hash, file_hash, policy, created_by, created_at, replaced_by, replaced_at)
+            SELECT instance_uid, version, series_instance_uid, class_uid, transfer_syntax_uid, size, path, called_ae_title, content_hash, file_hash, $2, updated_by, updated_at, $3, now()
+            FROM sop_instances
+            WHERE instance_uid = $1
+            "#,
+            instance_info.sop_instance.instance_uid(),
+            i16::from(replaced_policy),
+            ae_uuid,
+        )
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| format!("置き換え前のSOPインスタンス情報の記録に失敗しました: {e}"))?;
+    }
+
+    query!(
+        r#"
+        INSERT INTO sop_instances (series_instance_uid, class_uid, instance_uid, transfer_syntax_uid, size, path, called_ae_title, content_hash, file_hash, version, created_by, created_at, updated_by, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, now(), $11, now())
+        ON CONFLICT (instance_uid) DO UPDATE SET
+            series_instance_uid = EXCLUDED.series_instance_uid,
+            class_uid = EXCLUDED.class_uid,
+            transfer_syntax_uid = EXCLUDED.transfer_syntax_uid,
+            size = EXCLUDED.size,
+            path = EXCLUDED.path,
+            called_ae_title = EXCLUDED.called_ae_title,
+            content_hash = EXCLUDED.content_hash,
+            file_hash = EXCLUDED.file_hash,
+            file_hash_status = 0,
+            file_hash_verified_at = NULL,
+            version = EXCLUDED.version,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = EXCLUDED.updated_at
+        WHERE sop_instances.series_instance_uid IS DISTINCT FROM EXCLUDED.series_instance_uid
+           OR sop_instances.class_uid IS DISTINCT FROM EXCLUDED.class_uid
+           OR sop_instances.transfer_syntax_uid IS DISTINCT FROM EXCLUDED.transfer_syntax_uid
+           OR sop_instances.size IS DISTINCT FROM EXCLUDED.size
+           OR sop_instances.path IS DISTINCT FROM EXCLUDED.path
+           OR sop_instances.called_ae_title IS DISTINCT FROM EXCLUDED.called_ae_title
+           OR sop_instances.content_hash IS DISTINCT FROM EXCLUDED.content_hash
+           OR sop_instances.file_hash IS DISTINCT FROM EXCLUDED.file_hash
+           OR sop_instances.version IS DISTINCT FROM EXCLUDED.version
+        "#,
+        instance_info.series.instance_uid(),
+        instance_info.sop_instance.class_uid(),
+        instance_info.sop_instance.instance_uid(),
+        saved_file.transfer_syntax_uid,
+        size,
+        path,
+        called_ae_title,
+        saved_file.content_hash,
+        saved_file.file_hash,
+        saved_file.version,
+        ae_uuid,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("SOPインスタンス情報の保存に失敗しました: {e}"))?;
+
+    query!(
+        "DELETE FROM storage_journals WHERE uuid = $1",
+        journal.uuid()
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("ジャーナルの削除に失敗しました: {e}"))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("トランザクションのコミットに失敗しました: {e}"))?;
+
+    Ok(is_patient_conflicted)
+}
+
+/// Patient IDを書き換えたファイルのサイズとハッシュ値をDBへ保存する。
+///
+/// 同じファイルを参照する置き換え前の版の記録も更新し、同じトランザクションでジャーナルの記録を削除する。
+/// 書き換えたファイルのハッシュ値は未検証とする。
+pub async fn save_rewritten_file_to_db(
+    db: impl Acquire<'_, Database = Postgres>,
+    rewritten_file: &RewrittenFile<'_>,
+    journal: &StorageJournal,
+) -> Result<(), String> {
+    let Some(path) = journal.uri() else {
+        return Err("ジャーナルに保存先のURIが記録されていません".to_string());
+    };
+    let size = rewritten_file.size_for_db()?;
+
+    let mut transaction = db
+        .begin()
+        .await
+        .map_err(|e| format!("トランザクションの開始に失敗しました: {e}"))?;
+
+    query!(
+        "UPDATE sop_instances SET size = $2, content_hash = $3, file_hash = $4, file_hash_status = 0, file_hash_verified_at = NULL WHERE path = $1",
+        path,
+        size,
+        rewritten_file.content_hash,
+        rewritten_file.file_hash,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("SOPインスタンス情報の更新に失敗しました: {e}"))?;
+
+    query!(
+        "UPDATE sop_instance_histories SET size = $2, content_hash = $3, file_hash = $4 WHERE path = $1",
+        path,
+        size,
+        rewritten_file.content_hash,
+        rewritten_file.file_hash,
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("SOPインスタンスの履歴の更新に失敗しました: {e}"))?;
+
+    query!(
+        "DELETE FROM storage_journals WHERE uuid = $1",
+        journal.uuid()
+    )
+    .execute(&mut *transaction)
+    .await
+    .map_err(|e| format!("ジャーナルの削除に失敗しました: {e}"))?;
+
+    transaction
+        .commit()
+        .await
+        .map_err(|e| format!("トランザクションのコミットに失敗しました: {e}"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dicom_lib::core::{Encoding, Tag, data_element::Vr};
+
+    fn data_set() -> DataSet {
+        let mut data_set = DataSet::new(Encoding::ExplicitVrLittleEndian);
+        for (tag, vr, value) in [
+            (Tag(0x0008, 0x0016), Vr::Ui, "1.2.840.10008.5.1.4.1.1.2\0"),
+            (Tag(0x0008, 0x0018), Vr::Ui, "1.2.3.4.5.6\0"),
+            (Tag(0x0008, 0x0020), Vr::Da, "20260203"),
+            (Tag(0x0008, 0x0060), Vr::Cs, "CT"),
+            (Tag(0x0010, 0x0010), Vr::Pn, "YAMADA^TARO "),
+            (Tag(0x0010, 0x0020), Vr::Lo, "P001"),
+            (Tag(0x0020, 0x000d), Vr::Ui, "1.2.3.4\0"),
+            (Tag(0x0020, 0x000e), Vr::Ui, "1.2.3.4.5\0"),
+        ] {
+            data_set.set_element(tag, vr, value.as_bytes().to_vec());
+        }
+        data_set
+    }
+
+    #[test]
+    fn test_generate_success_key() {
+        // Arrange
+        let info = InstanceInfo::from_data_set(&data_set()).unwrap();
+
+        // Act & Assert
+        assert_eq!(
+            generate_success_key(&info, 1),
+            "success/2026/02/03/1.2.3.4/1.2.3.4.5/1.2.3.4.5.6.dcm"
+        );
+        // 新しい版として保存するファイルは、保存済みのファイルを上書きしないようファイル名に版を付与する
+        assert_eq!(
+            generate_success_key(&info, 2),
+            "success/2026/02/03/1.2.3.4/1.2.3.4.5/1.2.3.4.5.6_v2.dcm"
+        );
+    }
+
+    #[test]
+    fn test_saved_file_size_for_db() {
+        let saved_file = |size| SavedFile {
+            transfer_syntax_uid: "1.2.840.10008.1.2.1",
+            size,
+            content_hash: "",
+            file_hash: "",
+            version: 1,
+        };
+
+        // 正常系: 2GiBを超えるサイズもそのまま記録する
+        assert_eq!(
+            saved_file(i32::MAX as u64 + 1).size_for_db(),
+            Ok(i32::MAX as i64 + 1)
+        );
+        assert_eq!(
+            saved_file(5 * 1024 * 1024 * 1024).size_for_db(),
+            Ok(5 * 1024 * 1024 * 1024)
+        );
+        assert_eq!(saved_file(i64::MAX as u64).size_for_db(), Ok(i64::MAX));
+
+        // 準正常系: bigint型で表せないサイズ
+        assert_eq!(
+            saved_file(i64::MAX as u64 + 1).size_for_db(),
+            Err("ファイルのサイズが大きすぎます (サイズ=9223372036854775808)".to_string())
+        );
+    }
+}
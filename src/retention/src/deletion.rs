@@ -0,0 +1,421 @@
+use crate::rule::{StudyUsage, load_study_usage};
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres, query, types::Uuid};
+use std::{collections::HashSet, fmt};
+use storage::{StorageBackend, StorageError, StorageResolver, size_from_db};
+use tracing::{info, warn};
+
+/// 削除の対象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeletionTarget {
+    /// 検査（配下のシリーズおよびSOPインスタンスを含む）
+    Study { study_instance_uid: String },
+    /// シリーズ（配下のSOPインスタンスを含む）
+    Series {
+        study_instance_uid: String,
+        series_instance_uid: String,
+    },
+    /// SOPインスタンス
+    SopInstance {
+        study_instance_uid: String,
+        series_instance_uid: String,
+        sop_instance_uid: String,
+    },
+}
+
+impl DeletionTarget {
+    fn study_instance_uid(&self) -> &str {
+        match self {
+            Self::Study { study_instance_uid }
+            | Self::Series {
+                study_instance_uid, ..
+            }
+            | Self::SopInstance {
+                study_instance_uid, ..
+            } => study_instance_uid,
+        }
+    }
+
+    fn series_instance_uid(&self) -> Option<&str> {
+        match self {
+            Self::Study { .. } => None,
+            Self::Series {
+                series_instance_uid,
+                ..
+            }
+            | Self::SopInstance {
+                series_instance_uid,
+                ..
+            } => Some(series_instance_uid),
+        }
+    }
+
+    fn sop_instance_uid(&self) -> Option<&str> {
+        match self {
+            Self::SopInstance {
+                sop_instance_uid, ..
+            } => Some(sop_instance_uid),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DeletionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "検査インスタンスUID=\"{}\"", self.study_instance_uid())?;
+        if let Some(series_instance_uid) = self.series_instance_uid() {
+            write!(f, ", シリーズインスタンスUID=\"{series_instance_uid}\"")?;
+        }
+        if let Some(sop_instance_uid) = self.sop_instance_uid() {
+            write!(f, ", SOPインスタンスUID=\"{sop_instance_uid}\"")?;
+        }
+        Ok(())
+    }
+}
+
+/// 削除を行う利用者および削除したファイルの扱い
+pub struct DeletionContext<'a> {
+    pub db_pool: &'a Pool<Postgres>,
+    /// 保存済みのファイルのURIに対応するストレージを選択するリゾルバー
+    pub resolver: &'a StorageResolver,
+    /// ゴミ箱ディレクトリのストレージ（`None`の場合はファイルを削除する）
+    pub trash: Option<&'a dyn StorageBackend>,
+    /// 削除者のUUID（管理者による削除の場合はユーザー、保存期間の規則による削除の場合は規則）
+    pub deleted_by: Uuid,
+    pub deleted_at: DateTime<Utc>,
+    /// ログの接頭辞（例: `[保存期間]`）
+    pub log_prefix: &'a str,
+}
+
+/// 削除の結果
+#[derive(Debug, Default)]
+pub struct DeletionResult {
+    pub deleted_study_count: u64,
+    pub deleted_series_count: u64,
+    pub deleted_sop_instance_count: u64,
+    /// 削除したSOPインスタンスの合計サイズ（バイト）
+    pub deleted_size: u64,
+    /// 削除またはゴミ箱への移動に失敗したファイルのURI
+    pub failed_file_uris: Vec<String>,
+}
+
+/// 削除するファイルのURIと、ゴミ箱ディレクトリに移動する場合のキー
+struct RemovedFile {
+    uri: String,
+    trash_key: String,
+}
+
+/// 検査・シリーズ・SOPインスタンスを削除する。対象が存在しない場合は`None`を返す。
+///
+/// 1つのトランザクションで、対象のSOPインスタンスを削除済みテーブルに記録してから削除し、
+/// SOPインスタンスがなくなったシリーズおよびシリーズがなくなった検査も同様に削除する。
+/// 検査を削除した場合は、その検査に対する未解決の患者属性の不一致も削除する。
+///
+/// コミット後に、削除したSOPインスタンスおよびその履歴のファイルをストレージから削除する（ゴミ箱ディレクトリを指定した場合は移動する）。
+/// 内容のハッシュ値をパスとするストレージでは他のSOPインスタンスが同じファイルを参照している場合があるため、参照が残っているファイルは削除しない。
+/// ファイルの削除に失敗しても処理は継続し、失敗したファイルのURIを結果に含める。
+pub async fn delete(
+    context: &DeletionContext<'_>,
+    target: &DeletionTarget,
+) -> Result<Option<DeletionResult>, String> {
+    delete_target(context, target, None).await
+}
+
+/// 保存期間の規則で評価した検査を、評価時から変更されていない場合のみ削除する。
+/// 検査が存在しない、または評価後にSOPインスタンスの受信等により変更された場合は`None`を返す。
+///
+/// 評価から削除までの間に受信したSOPインスタンスを削除しないよう、検査の行をロックしたうえで、
+/// 受信した宛先AE、最後に受信した日時、合計サイズおよびSOPインスタンス数が評価時と一致することを確認する。
+pub async fn delete_if_unchanged(
+    context: &DeletionContext<'_>,
+    study: &StudyUsage,
+) -> Result<Option<DeletionResult>, String> {
+    let target = DeletionTarget::Study {
+        study_instance_uid: study.study_instance_uid.clone(),
+    };
+    delete_target(context, &target, Some(study)).await
+}
+
+/// `expected`を指定した場合は、検査の情報が一致する場合のみ削除する。
+async fn delete_target(
+    context: &DeletionContext<'_>,
+    target: &DeletionTarget,
+    expected: Option<&StudyUsage>,
+) -> Result<Option<DeletionResult>, String> {
+    let log_prefix = context.log_prefix;
+    let study_instance_uid = target.study_instance_uid();
+    let db_error = |e: sqlx::Error| format!("データベース処理でエラーが発生しました: {e}");
+
+    let mut tx = context
+        .db_pool
+        .begin()
+        .await
+        .map_err(|e| format!("トランザクションの開始に失敗しました: {e}"))?;
+
+    // 同じ検査に対する受信や削除と競合しないよう、検査の行をロックする
+    let study = query!(
+        "SELECT instance_uid FROM studies WHERE instance_uid = $1 FOR UPDATE",
+        study_instance_uid
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(db_error)?;
+    if study.is_none() {
+        return Ok(None);
+    }
+    if let Some(expected) = expected
+        && load_study_usage(&mut *tx, study_instance_uid)
+            .await?
+            .as_ref()
+            != Some(expected)
+    {
+        info!("{log_prefix} - 評価後に検査が変更されたため削除しませんでした ({target})");
+        return Ok(None);
+    }
+
+    let series_instance_uids = query!(
+        r#"
+        SELECT instance_uid
+        FROM series
+        WHERE study_instance_uid = $1 AND ($2::text IS NULL OR instance_uid = $2)
+        "#,
+        study_instance_uid,
+        target.series_instance_uid(),
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(db_error)?
+    .into_iter()
+    .map(|record| record.instance_uid)
+    .collect::<Vec<_>>();
+    if target.series_instance_uid().is_some() && series_instance_uids.is_empty() {
+        return Ok(None);
+    }
+
+    let instances = query!(
+        r#"
+        SELECT instance_uid, series_instance_uid, size, path
+        FROM sop_instances
+        WHERE series_instance_uid = ANY($1) AND ($2::text IS NULL OR instance_uid = $2)
+        "#,
+        &series_instance_uids,
+        target.sop_instance_uid(),
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(db_error)?;
+    if target.sop_instance_uid().is_some() && instances.is_empty() {
+        return Ok(None);
+    }
+    let sop_instance_uids = instances
+        .iter()
+        .map(|instance| instance.instance_uid.clone())
+        .collect::<Vec<_>>();
+
+    // 置き換えられた版のファイルも削除する（履歴はSOPインスタンスの削除に伴い削除される）
+    let histories = query!(
+        r#"
+        SELECT instance_uid, series_instance_uid, version, path
+        FROM sop_instance_histories
+        WHERE instance_uid = ANY($1)
+        "#,
+        &sop_instance_uids,
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    let date = context.deleted_at.format("%Y%m%d");
+    let mut files = Vec::new();
+    for instance in &instances {
+        files.push(RemovedFile {
+            uri: instance.path.clone(),
+            trash_key: format!(
+                "{date}/{study_instance_uid}/{}/{}.dcm",
+                instance.series_instance_uid, instance.instance_uid
+            ),
+        });
+    }
+    for history in &histories {
+        files.push(RemovedFile {
+            uri: history.path.clone(),
+            trash_key: format!(
+                "{date}/{study_instance_uid}/{}/{}.v{}.dcm",
+                history.series_instance_uid, history.instance_uid, history.version
+            ),
+        });
+    }
+
+    let mut result = DeletionResult {
+        deleted_size: instances
+            .iter()
+            .map(|instance| size_from_db(instance.size))
+            .sum::<Result<_, _>>()?,
+        ..Default::default()
+    };
+
+    query!(
+        r#"
+        INSERT INTO sop_instances_deleted (series_instance_uid, class_uid, instance_uid, transfer_syntax_uid, size, path, called_ae_title, content_hash, file_hash, version, created_by, created_at, updated_by, updated_at, deleted_by, deleted_at)
+        SELECT series_instance_uid, class_uid, instance_uid, transfer_syntax_uid, size, path, called_ae_title, content_hash, file_hash, version, created_by, created_at, updated_by, updated_at, $2, $3
+        FROM sop_instances
+        WHERE instance_uid = ANY($1)
+        "#,
+        &sop_instance_uids,
+        context.deleted_by,
+        context.deleted_at,
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+    result.deleted_sop_instance_count = query!(
+        "DELETE FROM sop_instances WHERE instance_uid = ANY($1)",
+        &sop_instance_uids,
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?
+    .rows_affected();
+
+    // SOPインスタンスがなくなったシリーズを削除する
+    result.deleted_series_count = query!(
+        r#"
+        WITH deleted AS (
+            DELETE FROM series s
+            WHERE s.instance_uid = ANY($1)
+              AND NOT EXISTS (SELECT 1 FROM sop_instances i WHERE i.series_instance_uid = s.instance_uid)
+            RETURNING s.*
+        )
+        INSERT INTO series_deleted (study_instance_uid, instance_uid, modality, series_number, created_by, created_at, updated_by, updated_at, deleted_by, deleted_at)
+        SELECT study_instance_uid, instance_uid, modality, series_number, created_by, created_at, updated_by, updated_at, $2, $3
+        FROM deleted
+        "#,
+        &series_instance_uids,
+        context.deleted_by,
+        context.deleted_at,
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?
+    .rows_affected();
+
+    // シリーズがなくなった検査を削除する
+    result.deleted_study_count = query!(
+        r#"
+        WITH deleted AS (
+            DELETE FROM studies st
+            WHERE st.instance_uid = $1
+              AND NOT EXISTS (SELECT 1 FROM series s WHERE s.study_instance_uid = st.instance_uid)
+            RETURNING st.*
+        )
+        INSERT INTO studies_deleted (patient_id, instance_uid, id, study_date, study_time, accession_number, created_by, created_at, updated_by, updated_at, deleted_by, deleted_at)
+        SELECT patient_id, instance_uid, id, study_date, study_time, accession_number, created_by, created_at, updated_by, updated_at, $2, $3
+        FROM deleted
+        "#,
+        study_instance_uid,
+        context.deleted_by,
+        context.deleted_at,
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?
+    .rows_affected();
+    if result.deleted_study_count > 0 {
+        query!(
+            "DELETE FROM patient_conflicts WHERE study_instance_uid = $1 AND status = 0",
+            study_instance_uid
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("トランザクションのコミットに失敗しました: {e}"))?;
+
+    info!(
+        "{log_prefix} - 削除しました ({target}, 検査={}, シリーズ={}, SOPインスタンス={}, サイズ={})",
+        result.deleted_study_count,
+        result.deleted_series_count,
+        result.deleted_sop_instance_count,
+        result.deleted_size
+    );
+
+    result.failed_file_uris = remove_files(context, files).await;
+
+    Ok(Some(result))
+}
+
+/// 削除したSOPインスタンスのファイルを削除し、失敗したファイルのURIを返す。
+async fn remove_files(context: &DeletionContext<'_>, files: Vec<RemovedFile>) -> Vec<String> {
+    let log_prefix = context.log_prefix;
+
+    // 同じファイルを参照する版がある場合に重複して削除しないようにする
+    let mut seen = HashSet::new();
+    let files = files
+        .into_iter()
+        .filter(|file| seen.insert(file.uri.clone()))
+        .collect::<Vec<_>>();
+    let uris = files
+        .iter()
+        .map(|file| file.uri.clone())
+        .collect::<Vec<_>>();
+
+    let referenced = match query!(
+        r#"
+        SELECT path AS "path!" FROM sop_instances WHERE path = ANY($1)
+        UNION
+        SELECT path AS "path!" FROM sop_instance_histories WHERE path = ANY($1)
+        "#,
+        &uris,
+    )
+    .fetch_all(context.db_pool)
+    .await
+    {
+        Ok(records) => records
+            .into_iter()
+            .map(|record| record.path)
+            .collect::<HashSet<_>>(),
+        Err(e) => {
+            warn!(
+                "{log_prefix} - ファイルの参照の確認に失敗したため、ファイルを削除しませんでした: {e}"
+            );
+            return uris;
+        }
+    };
+
+    let mut failed_file_uris = Vec::new();
+    for file in files {
+        if referenced.contains(&file.uri) {
+            continue;
+        }
+        match remove_file(context, &file).await {
+            Ok(()) => {}
+            Err(StorageError::NotFound { .. }) => {
+                warn!(
+                    "{log_prefix} - 削除したSOPインスタンスのファイルが存在しません (URI=\"{}\")",
+                    file.uri
+                );
+            }
+            Err(e) => {
+                warn!("{log_prefix} - ファイルの削除に失敗しました: {e}");
+                failed_file_uris.push(file.uri);
+            }
+        }
+    }
+
+    failed_file_uris
+}
+
+async fn remove_file(
+    context: &DeletionContext<'_>,
+    file: &RemovedFile,
+) -> Result<(), StorageError> {
+    let storage = context.resolver.resolve(&file.uri)?;
+    if let Some(trash) = context.trash {
+        let buf = storage.get(&file.uri).await?;
+        trash.put(&file.trash_key, buf).await?;
+    }
+    storage.delete(&file.uri).await
+}
@@ -0,0 +1,16 @@
+//! Web API（管理者による削除）とDICOMサーバー（保存期間の規則による定期削除）で共通の、アーカイブからの削除処理
+//!
+//! 検査・シリーズ・SOPインスタンスを削除済みテーブル（`studies_deleted`等）に記録してから削除し、
+//! ファイルをストレージから削除する（ゴミ箱ディレクトリを指定した場合は移動する）。
+//! また、保存期間の規則（`retention_rules`テーブル）を評価して削除の対象となる検査を選択する。
+
+mod deletion;
+mod rule;
+
+pub use self::{
+    deletion::{DeletionContext, DeletionResult, DeletionTarget, delete, delete_if_unchanged},
+    rule::{
+        RetentionCandidate, RetentionLimit, RetentionRule, StudyUsage, find_candidates, load_rules,
+        load_study_usages, select_candidates,
+    },
+};
@@ -0,0 +1,358 @@
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use sqlx::{PgExecutor, Pool, Postgres, query, types::Uuid};
+use std::collections::HashSet;
+
+/// 保存期間の規則の上限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetentionLimit {
+    /// 最後にSOPインスタンスを受信してから保存する日数
+    MaxAgeDays(i32),
+    /// 対象の検査の合計サイズの上限（バイト）
+    MaxTotalSize(i64),
+}
+
+/// 保存期間の規則
+#[derive(Debug, Clone)]
+pub struct RetentionRule {
+    pub uuid: Uuid,
+    pub name: String,
+    /// 対象の宛先AEタイトル（`None`の場合はすべての検査が対象）
+    pub called_ae_title: Option<String>,
+    pub limit: RetentionLimit,
+}
+
+impl RetentionRule {
+    /// 検査が規則の対象であるかを返す。
+    ///
+    /// 宛先AEを指定した規則は、すべてのSOPインスタンスをその宛先AEで受信した検査のみを対象とする。
+    fn applies_to(&self, study: &StudyUsage) -> bool {
+        match &self.called_ae_title {
+            Some(title) => {
+                !study.called_ae_titles.is_empty()
+                    && study.called_ae_titles.iter().all(|t| t == title)
+            }
+            None => true,
+        }
+    }
+}
+
+/// 保存期間の規則の評価に用いる検査の情報
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StudyUsage {
+    pub study_instance_uid: String,
+    pub patient_id: Option<String>,
+    pub study_date: Option<NaiveDate>,
+    /// SOPインスタンスを受信した宛先AEタイトル
+    pub called_ae_titles: Vec<String>,
+    /// 最後にSOPインスタンスを受信した日時（SOPインスタンスがない場合は検査の登録日時）
+    pub last_received_at: DateTime<Utc>,
+    /// SOPインスタンスの合計サイズ（バイト）
+    pub size: i64,
+    pub sop_instance_count: i64,
+}
+
+/// 保存期間の規則により削除の対象となった検査
+#[derive(Debug, Clone)]
+pub struct RetentionCandidate {
+    pub rule_uuid: Uuid,
+    pub rule_name: String,
+    pub study: StudyUsage,
+}
+
+/// 保存期間の規則を登録順に取得する。
+pub async fn load_rules(db_pool: &Pool<Postgres>) -> Result<Vec<RetentionRule>, String> {
+    let records = query!(
+        r#"
+        SELECT uuid, name, called_ae_title, max_age_days, max_total_size
+        FROM retention_rules
+        ORDER BY created_at, uuid
+        "#
+    )
+    .fetch_all(db_pool)
+    .await
+    .map_err(|e| format!("保存期間の規則の取得に失敗しました: {e}"))?;
+
+    records
+        .into_iter()
+        .map(|record| {
+            let limit = match (record.max_age_days, record.max_total_size) {
+                (Some(days), None) => RetentionLimit::MaxAgeDays(days),
+                (None, Some(size)) => RetentionLimit::MaxTotalSize(size),
+                _ => {
+                    return Err(format!(
+                        "保存期間の規則の上限が不正です (UUID={})",
+                        record.uuid
+                    ));
+                }
+            };
+            Ok(RetentionRule {
+                uuid: record.uuid,
+                name: record.name,
+                called_ae_title: record.called_ae_title,
+                limit,
+            })
+        })
+        .collect()
+}
+
+/// すべての検査について、受信した宛先AE、最後に受信した日時および合計サイズを取得する。
+pub async fn load_study_usages(db_pool: &Pool<Postgres>) -> Result<Vec<StudyUsage>, String> {
+    fetch_study_usages(db_pool, None).await
+}
+
+/// 検査の受信した宛先AE、最後に受信した日時および合計サイズを取得する。検査が存在しない場合は`None`を返す。
+pub(crate) async fn load_study_usage(
+    executor: impl PgExecutor<'_>,
+    study_instance_uid: &str,
+) -> Result<Option<StudyUsage>, String> {
+    Ok(fetch_study_usages(executor, Some(study_instance_uid))
+        .await?
+        .pop())
+}
+
+/// `study_instance_uid`が`None`の場合はすべての検査の情報を取得する。
+async fn fetch_study_usages(
+    executor: impl PgExecutor<'_>,
+    study_instance_uid: Option<&str>,
+) -> Result<Vec<StudyUsage>, String> {
+    let records = query!(
+        r#"
+        SELECT
+            st.instance_uid,
+            st.patient_id,
+            st.study_date,
+            COALESCE(array_agg(DISTINCT i.called_ae_title) FILTER (WHERE i.called_ae_title IS NOT NULL), '{}') AS "called_ae_titles!: Vec<String>",
+            COALESCE(max(i.created_at), st.created_at) AS "last_received_at!",
+            COALESCE(sum(i.size), 0)::bigint AS "size!",
+            count(i.instance_uid) AS "sop_instance_count!"
+        FROM studies st
+        LEFT JOIN series se ON se.study_instance_uid = st.instance_uid
+        LEFT JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+        WHERE $1::text IS NULL OR st.instance_uid = $1
+        GROUP BY st.instance_uid
+        "#,
+        study_instance_uid,
+    )
+    .fetch_all(executor)
+    .await
+    .map_err(|e| format!("検査の使用量の取得に失敗しました: {e}"))?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| StudyUsage {
+            study_instance_uid: record.instance_uid,
+            patient_id: record.patient_id,
+            study_date: record.study_date,
+            called_ae_titles: record.called_ae_titles,
+            last_received_at: record.last_received_at,
+            size: record.size,
+            sop_instance_count: record.sop_instance_count,
+        })
+        .collect())
+}
+
+/// DBに登録された保存期間の規則を評価し、削除の対象となる検査を返す。
+pub async fn find_candidates(
+    db_pool: &Pool<Postgres>,
+    now: DateTime<Utc>,
+) -> Result<Vec<RetentionCandidate>, String> {
+    let rules = load_rules(db_pool).await?;
+    if rules.is_empty() {
+        return Ok(Vec::new());
+    }
+    let studies = load_study_usages(db_pool).await?;
+
+    Ok(select_candidates(&rules, &studies, now))
+}
+
+/// 保存期間の規則を評価し、削除の対象となる検査を規則ごとに古い順に返す。
+///
+/// 日数の規則を先に評価し、合計サイズの規則は日数の規則で対象となった検査を除いて合計サイズを計算する。
+/// 合計サイズの規則は、最後に受信した日時が新しい検査から順に上限まで残し、上限を超えた検査を対象とする。
+/// 複数の規則の対象となる検査は、最初に評価した規則の対象とする。
+pub fn select_candidates(
+    rules: &[RetentionRule],
+    studies: &[StudyUsage],
+    now: DateTime<Utc>,
+) -> Vec<RetentionCandidate> {
+    let mut selected = HashSet::new();
+    let mut candidates = Vec::new();
+
+    let age_rules = rules
+        .iter()
+        .filter(|rule| matches!(rule.limit, RetentionLimit::MaxAgeDays(_)));
+    let size_rules = rules
+        .iter()
+        .filter(|rule| matches!(rule.limit, RetentionLimit::MaxTotalSize(_)));
+
+    for rule in age_rules.chain(size_rules) {
+        let mut targets = studies
+            .iter()
+            .filter(|study| {
+                !selected.contains(study.study_instance_uid.as_str()) && rule.applies_to(study)
+            })
+            .collect::<Vec<_>>();
+        // 最後に受信した日時が新しい順
+        targets.sort_by(|a, b| {
+            b.last_received_at
+                .cmp(&a.last_received_at)
+                .then_with(|| b.study_instance_uid.cmp(&a.study_instance_uid))
+        });
+
+        let mut rule_candidates = match rule.limit {
+            RetentionLimit::MaxAgeDays(days) => {
+                let threshold = now - TimeDelta::days(days.into());
+                targets
+                    .into_iter()
+                    .filter(|study| study.last_received_at <= threshold)
+                    .collect::<Vec<_>>()
+            }
+            RetentionLimit::MaxTotalSize(max_total_size) => {
+                let mut total_size = 0i64;
+                targets
+                    .into_iter()
+                    .skip_while(|study| {
+                        total_size = total_size.saturating_add(study.size);
+                        total_size <= max_total_size
+                    })
+                    .collect::<Vec<_>>()
+            }
+        };
+        rule_candidates.reverse();
+
+        for study in rule_candidates {
+            selected.insert(study.study_instance_uid.as_str());
+            candidates.push(RetentionCandidate {
+                rule_uuid: rule.uuid,
+                rule_name: rule.name.clone(),
+                study: study.clone(),
+            });
+        }
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn study(
+        uid: &str,
+        called_ae_titles: &[&str],
+        last_received_at: &str,
+        size: i64,
+    ) -> StudyUsage {
+        StudyUsage {
+            study_instance_uid: uid.to_string(),
+            patient_id: Some("P001".to_string()),
+            study_date: None,
+            called_ae_titles: called_ae_titles.iter().map(|t| t.to_string()).collect(),
+            last_received_at: last_received_at.parse().unwrap(),
+            size,
+            sop_instance_count: 1,
+        }
+    }
+
+    fn rule(uuid: u128, called_ae_title: Option<&str>, limit: RetentionLimit) -> RetentionRule {
+        RetentionRule {
+            uuid: Uuid::from_u128(uuid),
+            name: format!("rule{uuid}"),
+            called_ae_title: called_ae_title.map(|t| t.to_string()),
+            limit,
+        }
+    }
+
+    fn uids(candidates: &[RetentionCandidate]) -> Vec<&str> {
+        candidates
+            .iter()
+            .map(|candidate| candidate.study.study_instance_uid.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_select_candidates_by_age() {
+        // Arrange
+        let rules = [rule(1, None, RetentionLimit::MaxAgeDays(90))];
+        let studies = [
+            study("1.1", &["OCEANUS"], "2026-01-01T00:00:00Z", 100),
+            study("1.2", &["OCEANUS"], "2026-07-20T00:00:00Z", 100),
+            study("1.3", &["OCEANUS"], "2026-07-21T00:00:00Z", 100),
+        ];
+        let now = "2026-10-18T00:00:00Z".parse().unwrap();
+
+        // Act
+        let candidates = select_candidates(&rules, &studies, now);
+
+        // Assert
+        assert_eq!(uids(&candidates), ["1.1", "1.2"]);
+        assert!(
+            candidates
+                .iter()
+                .all(|candidate| candidate.rule_uuid == Uuid::from_u128(1))
+        );
+    }
+
+    #[test]
+    fn test_select_candidates_by_called_ae_title() {
+        // Arrange
+        let rules = [rule(1, Some("RESEARCH"), RetentionLimit::MaxAgeDays(90))];
+        let studies = [
+            study("1.1", &["RESEARCH"], "2026-01-01T00:00:00Z", 100),
+            study("1.2", &["RESEARCH", "OCEANUS"], "2026-01-01T00:00:00Z", 100),
+            study("1.3", &["OCEANUS"], "2026-01-01T00:00:00Z", 100),
+            study("1.4", &[], "2026-01-01T00:00:00Z", 0),
+        ];
+        let now = "2026-10-18T00:00:00Z".parse().unwrap();
+
+        // Act
+        let candidates = select_candidates(&rules, &studies, now);
+
+        // Assert
+        assert_eq!(uids(&candidates), ["1.1"]);
+    }
+
+    #[test]
+    fn test_select_candidates_by_total_size() {
+        // Arrange
+        let rules = [rule(1, None, RetentionLimit::MaxTotalSize(250))];
+        let studies = [
+            study("1.1", &["OCEANUS"], "2026-01-01T00:00:00Z", 100),
+            study("1.2", &["OCEANUS"], "2026-02-01T00:00:00Z", 100),
+            study("1.3", &["OCEANUS"], "2026-03-01T00:00:00Z", 100),
+            study("1.4", &["OCEANUS"], "2026-04-01T00:00:00Z", 100),
+        ];
+        let now = "2026-10-18T00:00:00Z".parse().unwrap();
+
+        // Act
+        let candidates = select_candidates(&rules, &studies, now);
+
+        // Assert
+        assert_eq!(uids(&candidates), ["1.1", "1.2"]);
+    }
+
+    #[test]
+    fn test_select_candidates_evaluates_age_rules_before_size_rules() {
+        // Arrange
+        let rules = [
+            rule(1, None, RetentionLimit::MaxTotalSize(200)),
+            rule(2, Some("RESEARCH"), RetentionLimit::MaxAgeDays(90)),
+        ];
+        let studies = [
+            study("1.1", &["OCEANUS"], "2026-01-01T00:00:00Z", 100),
+            study("1.2", &["RESEARCH"], "2026-02-01T00:00:00Z", 100),
+            study("1.3", &["OCEANUS"], "2026-09-01T00:00:00Z", 100),
+            study("1.4", &["OCEANUS"], "2026-10-01T00:00:00Z", 100),
+        ];
+        let now = "2026-10-18T00:00:00Z".parse().unwrap();
+
+        // Act
+        let candidates = select_candidates(&rules, &studies, now);
+
+        // Assert
+        // 日数の規則で対象となった検査は合計サイズに含めない
+        assert_eq!(uids(&candidates), ["1.2", "1.1"]);
+        assert_eq!(candidates[0].rule_uuid, Uuid::from_u128(2));
+        assert_eq!(candidates[1].rule_uuid, Uuid::from_u128(1));
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    ByteStream, StorageBackend, StorageError, StorageUri, calculate_file_hash,
+    file_system::{
+        TEMPORARY_DIR, absolute_path, file_exists, list_files, open_file, parse_file_uri,
+        read_file, remove_file, remove_temporary_files, replace_file, write_file,
+    },
+};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// ローカルファイルシステム上のディレクトリに、内容のSHA-256ハッシュ値をパスとしてファイルを保存する。
+///
+/// ファイルは`{ルートディレクトリ}/{ハッシュ値の先頭2文字}/{続く2文字}/{ハッシュ値}{キーの拡張子}`に配置する。
+/// 内容が同一のファイルは同じパスとなるため、重複して保存しない。
+pub struct ContentAddressedStorage {
+    root_dir: PathBuf,
+}
+
+impl ContentAddressedStorage {
+    pub fn new(root_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            root_dir: root_dir.into(),
+        }
+    }
+
+    /// 内容のハッシュ値から保存先のパスを生成する。
+    /// `index`が1以上の場合は、ファイル名に番号を付与する。
+    fn path(&self, key: &str, hash: &str, index: usize) -> PathBuf {
+        let extension = Path::new(key)
+            .extension()
+            .map(|extension| format!(".{}", extension.to_string_lossy()))
+            .unwrap_or_default();
+        self.root_dir
+            .join(&hash[..2])
+            .join(&hash[2..4])
+            .join(if index == 0 {
+                format!("{hash}{extension}")
+            } else {
+                format!("{hash}_{index}{extension}")
+            })
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageBackend for ContentAddressedStorage {
+    async fn put(&self, key: &str, buf: Vec<u8>) -> Result<String, StorageError> {
+        let hash = calculate_file_hash(&buf);
+
+        // 置き換えにより内容が変わったファイルは他から参照されているため上書きせず、番号を付与したパスに保存する
+        let mut index = 0;
+        loop {
+            let path = absolute_path(&self.path(key, &hash, index))?;
+            let uri = StorageUri::from_path(&path).to_string();
+            if !file_exists(&path).await? {
+                write_file(&path, &self.root_dir.join(TEMPORARY_DIR), buf).await?;
+                return Ok(uri);
+            }
+            if read_file(&uri, &path).await? == buf {
+                return Ok(uri);
+            }
+            index += 1;
+        }
+    }
+
+    /// 番号を付与したパスも含め、内容のハッシュ値が`file_hash`と一致するファイルを探す。
+    async fn locate(&self, key: &str, file_hash: &str) -> Result<Option<String>, StorageError> {
+        let mut index = 0;
+        loop {
+            let path = absolute_path(&self.path(key, file_hash, index))?;
+            if !file_exists(&path).await? {
+                return Ok(None);
+            }
+            let uri = StorageUri::from_path(&path).to_string();
+            if calculate_file_hash(&read_file(&uri, &path).await?) == file_hash {
+                return Ok(Some(uri));
+            }
+            index += 1;
+        }
+    }
+
+    /// 置き換え後の内容とパスのハッシュ値は一致しなくなるが、
+    /// 保存時に既存のファイルの内容を確認するため、重複の排除には影響しない。
+    async fn replace(&self, uri: &str, buf: Vec<u8>) -> Result<(), StorageError> {
+        replace_file(&parse_file_uri(uri)?, buf).await
+    }
+
+    async fn get(&self, uri: &str) -> Result<Vec<u8>, StorageError> {
+        read_file(uri, &parse_file_uri(uri)?).await
+    }
+
+    async fn delete(&self, uri: &str) -> Result<(), StorageError> {
+        remove_file(uri, &parse_file_uri(uri)?).await
+    }
+
+    async fn exists(&self, uri: &str) -> Result<bool, StorageError> {
+        file_exists(&parse_file_uri(uri)?).await
+    }
+
+    async fn stream(&self, uri: &str) -> Result<ByteStream, StorageError> {
+        open_file(uri, &parse_file_uri(uri)?).await
+    }
+
+    async fn list(&self) -> Result<Vec<String>, StorageError> {
+        list_files(&self.root_dir).await
+    }
+
+    async fn remove_temporary_files(&self, older_than: Duration) -> Result<usize, StorageError> {
+        remove_temporary_files(&self.root_dir.join(TEMPORARY_DIR), older_than).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+    use tokio::fs;
+
+    #[tokio::test]
+    async fn test_content_addressed_storage() {
+        // Arrange
+        let root_dir = std::env::temp_dir().join(format!(
+            "oceanus-storage-test-content-addressed-{}",
+            std::process::id()
+        ));
+        let storage = ContentAddressedStorage::new(&root_dir);
+
+        // Act
+        let uri1 = storage
+            .put("success/1.2.3.dcm", b"DICM".to_vec())
+            .await
+            .unwrap();
+        let uri2 = storage
+            .put("success/1.2.4.dcm", b"DICM".to_vec())
+            .await
+            .unwrap();
+        let uri3 = storage
+            .put("success/1.2.5.dcm", b"DICM2".to_vec())
+            .await
+            .unwrap();
+
+        // Assert
+        // 内容が同一のファイルは同じパスに保存される
+        let hash = format!("{:x}", Sha256::digest(b"DICM"));
+        assert_eq!(uri1, uri2);
+        assert_ne!(uri1, uri3);
+        assert_eq!(
+            StorageUri::parse(&uri1).unwrap(),
+            StorageUri::File(
+                root_dir
+                    .join(&hash[..2])
+                    .join(&hash[2..4])
+                    .join(format!("{hash}.dcm"))
+            )
+        );
+        assert_eq!(storage.get(&uri1).await.unwrap(), b"DICM");
+
+        // 置き換えたファイルは上書きせず、番号を付与したパスに保存される
+        storage.replace(&uri1, b"DICM3".to_vec()).await.unwrap();
+        let uri4 = storage
+            .put("success/1.2.3.dcm", b"DICM".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            StorageUri::parse(&uri4).unwrap(),
+            StorageUri::File(
+                root_dir
+                    .join(&hash[..2])
+                    .join(&hash[2..4])
+                    .join(format!("{hash}_1.dcm"))
+            )
+        );
+        assert_eq!(storage.get(&uri1).await.unwrap(), b"DICM3");
+        assert_eq!(storage.get(&uri4).await.unwrap(), b"DICM");
+        // 番号を付与したパスも含め、内容のハッシュ値から保存したファイルを探せる
+        assert_eq!(
+            storage.locate("success/1.2.3.dcm", &hash).await.unwrap(),
+            Some(uri4.clone())
+        );
+        assert_eq!(
+            storage
+                .locate(
+                    "success/1.2.3.dcm",
+                    &format!("{:x}", Sha256::digest(b"DICM4"))
+                )
+                .await
+                .unwrap(),
+            None
+        );
+        let mut uris = vec![uri1, uri3, uri4];
+        uris.sort();
+        assert_eq!(storage.list().await.unwrap(), uris);
+
+        fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+}
@@ -0,0 +1,439 @@
+use crate::{ByteStream, StorageBackend, StorageError, StorageUri};
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime},
+};
+use tokio::{fs, io::AsyncWriteExt};
+
+/// 書き込み途中のファイルを配置するディレクトリの、ルートディレクトリからの相対パス
+pub(crate) const TEMPORARY_DIR: &str = ".tmp";
+
+static TEMPORARY_FILE_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+/// ローカルファイルシステム上のディレクトリをストレージとして使用する。
+///
+/// キーをルートディレクトリからの相対パスとしてファイルを保存する。
+/// ファイルは`{ルートディレクトリ}/.tmp`に書き込んでから所定のパスに移動するため、書き込み途中のファイルが所定のパスに残ることはない。
+pub struct FileSystemStorage {
+    root_dir: PathBuf,
+}
+
+impl FileSystemStorage {
+    pub fn new(root_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            root_dir: root_dir.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageBackend for FileSystemStorage {
+    async fn put(&self, key: &str, buf: Vec<u8>) -> Result<String, StorageError> {
+        let path = absolute_path(&self.root_dir.join(key))?;
+        write_file(&path, &self.root_dir.join(TEMPORARY_DIR), buf).await?;
+        Ok(StorageUri::from_path(&path).to_string())
+    }
+
+    async fn locate(&self, key: &str, _file_hash: &str) -> Result<Option<String>, StorageError> {
+        let path = absolute_path(&self.root_dir.join(key))?;
+        Ok(file_exists(&path)
+            .await?
+            .then(|| StorageUri::from_path(&path).to_string()))
+    }
+
+    async fn replace(&self, uri: &str, buf: Vec<u8>) -> Result<(), StorageError> {
+        replace_file(&parse_file_uri(uri)?, buf).await
+    }
+
+    async fn get(&self, uri: &str) -> Result<Vec<u8>, StorageError> {
+        read_file(uri, &parse_file_uri(uri)?).await
+    }
+
+    async fn delete(&self, uri: &str) -> Result<(), StorageError> {
+        remove_file(uri, &parse_file_uri(uri)?).await
+    }
+
+    async fn exists(&self, uri: &str) -> Result<bool, StorageError> {
+        file_exists(&parse_file_uri(uri)?).await
+    }
+
+    async fn stream(&self, uri: &str) -> Result<ByteStream, StorageError> {
+        open_file(uri, &parse_file_uri(uri)?).await
+    }
+
+    async fn list(&self) -> Result<Vec<String>, StorageError> {
+        list_files(&self.root_dir).await
+    }
+
+    async fn remove_temporary_files(&self, older_than: Duration) -> Result<usize, StorageError> {
+        remove_temporary_files(&self.root_dir.join(TEMPORARY_DIR), older_than).await
+    }
+}
+
+/// 記録したURIが作業ディレクトリに依存しないよう、絶対パスに変換する。
+pub(crate) fn absolute_path(path: &Path) -> Result<PathBuf, StorageError> {
+    std::path::absolute(path).map_err(|e| StorageError::WriteFileError {
+        path_buf: path.to_path_buf(),
+        io_error: e,
+    })
+}
+
+pub(crate) fn parse_file_uri(uri: &str) -> Result<PathBuf, StorageError> {
+    match StorageUri::parse(uri)? {
+        StorageUri::File(path) => Ok(path),
+        _ => Err(StorageError::UnsupportedUri {
+            uri: uri.to_string(),
+        }),
+    }
+}
+
+/// ファイルを不可分に書き込む。ディレクトリが存在しない場合は作成する。
+///
+/// `tmp_dir`に作成した一時ファイルに書き込んでディスクに同期してから、所定のパスに名前を変更する。
+/// 書き込み途中で中断した場合も、所定のパスには書き込み前のファイルまたは書き込み後のファイルのいずれかが存在する。
+/// 名前の変更が不可分となるよう、`tmp_dir`は所定のパスと同じファイルシステム上に配置する必要がある。
+pub(crate) async fn write_file(
+    path: &Path,
+    tmp_dir: &Path,
+    buf: Vec<u8>,
+) -> Result<(), StorageError> {
+    let parent = path.parent().unwrap_or(Path::new("/"));
+    for dir in [parent, tmp_dir] {
+        fs::create_dir_all(dir)
+            .await
+            .map_err(|e| StorageError::CreateDirError {
+                path_buf: dir.to_path_buf(),
+                io_error: e,
+            })?;
+    }
+
+    let tmp_path = tmp_dir.join(format!(
+        "{}.{}.{}.tmp",
+        path.file_name().unwrap_or_default().to_string_lossy(),
+        std::process::id(),
+        TEMPORARY_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let result = async {
+        let mut file = fs::File::create(&tmp_path).await?;
+        file.write_all(&buf).await?;
+        file.sync_all().await?;
+        fs::rename(&tmp_path, path).await?;
+        // 名前の変更をディスクに反映するため、ディレクトリを同期する
+        fs::File::open(parent).await?.sync_all().await
+    }
+    .await;
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp_path).await;
+        return Err(StorageError::WriteFileError {
+            path_buf: path.to_path_buf(),
+            io_error: e,
+        });
+    }
+
+    Ok(())
+}
+
+/// 書き込み途中で中断しても元のファイルが壊れないよう、同じディレクトリに作成した一時ファイルに書き込んでから置き換える。
+pub(crate) async fn replace_file(path: &Path, buf: Vec<u8>) -> Result<(), StorageError> {
+    let parent = path.parent().unwrap_or(Path::new("/"));
+    write_file(path, parent, buf).await
+}
+
+/// ルートディレクトリ配下のファイルのURIを、一時ファイルのディレクトリを除いてパスの昇順に返す。
+pub(crate) async fn list_files(root_dir: &Path) -> Result<Vec<String>, StorageError> {
+    let root_dir = absolute_path(root_dir)?;
+    let tmp_dir = root_dir.join(TEMPORARY_DIR);
+    let read_dir_error = |path: &Path, e| StorageError::ReadFileError {
+        path_buf: path.to_path_buf(),
+        io_error: e,
+    };
+
+    let mut paths = Vec::new();
+    let mut dirs = vec![root_dir];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(val) => val,
+            Err(e) if e.kind() == ErrorKind::NotFound => continue,
+            Err(e) => return Err(read_dir_error(&dir, e)),
+        };
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| read_dir_error(&dir, e))?
+        {
+            let path = entry.path();
+            let file_type = entry
+                .file_type()
+                .await
+                .map_err(|e| read_dir_error(&path, e))?;
+            if file_type.is_dir() {
+                if path != tmp_dir {
+                    dirs.push(path);
+                }
+            } else if file_type.is_file() {
+                paths.push(path);
+            }
+        }
+    }
+    paths.sort();
+
+    Ok(paths
+        .iter()
+        .map(|path| StorageUri::from_path(path).to_string())
+        .collect())
+}
+
+/// 書き込み途中で中断した一時ファイルのうち、最終更新から`older_than`以上経過したものを削除し、削除したファイルの数を返す。
+pub(crate) async fn remove_temporary_files(
+    tmp_dir: &Path,
+    older_than: Duration,
+) -> Result<usize, StorageError> {
+    let mut entries = match fs::read_dir(tmp_dir).await {
+        Ok(val) => val,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+        Err(e) => {
+            return Err(StorageError::ReadFileError {
+                path_buf: tmp_dir.to_path_buf(),
+                io_error: e,
+            });
+        }
+    };
+
+    let mut count = 0;
+    loop {
+        let entry = entries
+            .next_entry()
+            .await
+            .map_err(|e| StorageError::ReadFileError {
+                path_buf: tmp_dir.to_path_buf(),
+                io_error: e,
+            })?;
+        let Some(entry) = entry else {
+            break;
+        };
+        let path = entry.path();
+        let modified = entry
+            .metadata()
+            .await
+            .and_then(|metadata| metadata.modified())
+            .map_err(|e| StorageError::ReadFileError {
+                path_buf: path.clone(),
+                io_error: e,
+            })?;
+        // 書き込み中の可能性がある一時ファイルは削除しない
+        if SystemTime::now()
+            .duration_since(modified)
+            .is_ok_and(|elapsed| elapsed < older_than)
+        {
+            continue;
+        }
+        fs::remove_file(&path)
+            .await
+            .map_err(|e| StorageError::RemoveFileError {
+                path_buf: path.clone(),
+                io_error: e,
+            })?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+pub(crate) async fn read_file(uri: &str, path: &Path) -> Result<Vec<u8>, StorageError> {
+    fs::read(path).await.map_err(|e| match e.kind() {
+        ErrorKind::NotFound => StorageError::NotFound {
+            uri: uri.to_string(),
+        },
+        _ => StorageError::ReadFileError {
+            path_buf: path.to_path_buf(),
+            io_error: e,
+        },
+    })
+}
+
+pub(crate) async fn remove_file(uri: &str, path: &Path) -> Result<(), StorageError> {
+    fs::remove_file(path).await.map_err(|e| match e.kind() {
+        ErrorKind::NotFound => StorageError::NotFound {
+            uri: uri.to_string(),
+        },
+        _ => StorageError::RemoveFileError {
+            path_buf: path.to_path_buf(),
+            io_error: e,
+        },
+    })
+}
+
+pub(crate) async fn file_exists(path: &Path) -> Result<bool, StorageError> {
+    fs::try_exists(path)
+        .await
+        .map_err(|e| StorageError::ReadFileError {
+            path_buf: path.to_path_buf(),
+            io_error: e,
+        })
+}
+
+pub(crate) async fn open_file(uri: &str, path: &Path) -> Result<ByteStream, StorageError> {
+    let file = fs::File::open(path).await.map_err(|e| match e.kind() {
+        ErrorKind::NotFound => StorageError::NotFound {
+            uri: uri.to_string(),
+        },
+        _ => StorageError::ReadFileError {
+            path_buf: path.to_path_buf(),
+            io_error: e,
+        },
+    })?;
+    Ok(Box::pin(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn test_file_system_storage() {
+        // Arrange
+        let root_dir = std::env::temp_dir().join(format!(
+            "oceanus-storage-test-file-system-{}",
+            std::process::id()
+        ));
+        let storage = FileSystemStorage::new(&root_dir);
+
+        // Act & Assert
+        // 保存したファイルはルートディレクトリからの相対パスに配置される
+        let uri = storage
+            .put("success/2026/02/03/1.2.3.dcm", b"DICM".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            StorageUri::parse(&uri).unwrap(),
+            StorageUri::File(root_dir.join("success/2026/02/03/1.2.3.dcm"))
+        );
+        assert!(storage.exists(&uri).await.unwrap());
+        assert_eq!(storage.get(&uri).await.unwrap(), b"DICM");
+        assert_eq!(storage.list().await.unwrap(), vec![uri.clone()]);
+        // 保存したファイルはキーから探せる
+        assert_eq!(
+            storage
+                .locate("success/2026/02/03/1.2.3.dcm", "")
+                .await
+                .unwrap(),
+            Some(uri.clone())
+        );
+        assert_eq!(
+            storage
+                .locate("success/2026/02/03/1.2.4.dcm", "")
+                .await
+                .unwrap(),
+            None
+        );
+
+        storage.replace(&uri, b"DICM2".to_vec()).await.unwrap();
+        let mut buf = Vec::new();
+        storage
+            .stream(&uri)
+            .await
+            .unwrap()
+            .read_to_end(&mut buf)
+            .await
+            .unwrap();
+        assert_eq!(buf, b"DICM2");
+
+        storage.delete(&uri).await.unwrap();
+        assert!(!storage.exists(&uri).await.unwrap());
+        assert!(matches!(
+            storage.get(&uri).await,
+            Err(StorageError::NotFound { .. })
+        ));
+        assert!(matches!(
+            storage.get("s3://oceanus/1.2.3.dcm").await,
+            Err(StorageError::UnsupportedUri { .. })
+        ));
+
+        fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_large_sparse_file() {
+        // Arrange
+        // 2GiBを超えるファイルを、ディスクを消費しないスパースファイルとして作成する
+        let root_dir = std::env::temp_dir().join(format!(
+            "oceanus-storage-test-large-file-{}",
+            std::process::id()
+        ));
+        let storage = FileSystemStorage::new(&root_dir);
+        let uri = storage.put("1.2.3.dcm", b"DICM".to_vec()).await.unwrap();
+        let size = i32::MAX as u64 + 5;
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(parse_file_uri(&uri).unwrap())
+            .unwrap()
+            .set_len(size)
+            .unwrap();
+
+        // Act
+        let (actual_size, actual_hash) =
+            crate::calculate_stream_hash(storage.stream(&uri).await.unwrap())
+                .await
+                .unwrap();
+
+        // Assert
+        assert_eq!(actual_size, size);
+        // 先頭4バイトが"DICM"、以降が0のバイト列のSHA-256ハッシュ値
+        assert_eq!(
+            actual_hash,
+            "74dfe8be006861360406f6b72593439415cc5c0336f4ec0a17cf131432365188"
+        );
+        assert_eq!(storage.list().await.unwrap(), vec![uri.clone()]);
+
+        fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_remove_temporary_files() {
+        // Arrange
+        let root_dir = std::env::temp_dir().join(format!(
+            "oceanus-storage-test-temporary-files-{}",
+            std::process::id()
+        ));
+        let storage = FileSystemStorage::new(&root_dir);
+        storage.put("1.2.3.dcm", b"DICM".to_vec()).await.unwrap();
+        // 書き込み途中で中断した一時ファイル
+        fs::write(
+            root_dir.join(TEMPORARY_DIR).join("1.2.4.dcm.1.1.tmp"),
+            b"DI",
+        )
+        .await
+        .unwrap();
+
+        // Act
+        let recent_count = storage
+            .remove_temporary_files(Duration::from_secs(60 * 60))
+            .await
+            .unwrap();
+        let count = storage
+            .remove_temporary_files(Duration::ZERO)
+            .await
+            .unwrap();
+
+        // Assert
+        // 他のプロセスが書き込み中の可能性がある、最近更新された一時ファイルは削除しない
+        assert_eq!(recent_count, 0);
+        // 書き込みが完了したファイルの一時ファイルは残らない
+        assert_eq!(count, 1);
+        assert!(
+            fs::read_dir(root_dir.join(TEMPORARY_DIR))
+                .await
+                .unwrap()
+                .next_entry()
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(fs::read(root_dir.join("1.2.3.dcm")).await.unwrap(), b"DICM");
+
+        fs::remove_dir_all(&root_dir).await.unwrap();
+    }
+}
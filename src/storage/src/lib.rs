@@ -0,0 +1,291 @@
+mod content_addressed;
+mod file_system;
+mod s3;
+mod uri;
+
+pub use self::{
+    content_addressed::ContentAddressedStorage,
+    file_system::FileSystemStorage,
+    s3::{S3Config, S3Storage},
+    uri::StorageUri,
+};
+
+use sha2::{Digest, Sha256};
+use std::{io, path::PathBuf, pin::Pin, time::Duration};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// ファイルの内容を順に読み出すストリーム
+pub type ByteStream = Pin<Box<dyn AsyncRead + Send>>;
+
+/// ファイルの保存先
+///
+/// 保存したファイルはストレージの種類を表すスキームを持つURIで識別する。
+/// 利用側はURIをそのまま記録し、読み出し等の際は同じURIを指定する。
+#[async_trait::async_trait]
+pub trait StorageBackend: Send + Sync {
+    /// ストレージのルートからの相対パス（区切り文字は`/`）をキーとしてファイルを保存し、保存先のURIを返す。
+    /// キーが同じファイルが存在する場合は上書きする。
+    ///
+    /// 書き込みは不可分に行い、書き込み途中のファイルを保存先のURIから読み出せる状態にしない。
+    async fn put(&self, key: &str, buf: Vec<u8>) -> Result<String, StorageError>;
+
+    /// [`put`](Self::put)で`key`に保存した、ハッシュ値が`file_hash`のファイルのURIを返す。
+    /// 該当するファイルが存在しない場合は`None`を返す。
+    ///
+    /// 保存先のURIを記録する前に中断した場合に、保存したファイルを探すために使用する。
+    /// 内容のハッシュ値をパスとしないストレージでは、ハッシュ値によらずキーに対応するファイルのURIを返す。
+    async fn locate(&self, key: &str, file_hash: &str) -> Result<Option<String>, StorageError>;
+
+    /// 保存済みのファイルを同じURIのまま置き換える。
+    async fn replace(&self, uri: &str, buf: Vec<u8>) -> Result<(), StorageError>;
+
+    /// ファイルの内容を読み出す。
+    async fn get(&self, uri: &str) -> Result<Vec<u8>, StorageError>;
+
+    /// ファイルを削除する。
+    async fn delete(&self, uri: &str) -> Result<(), StorageError>;
+
+    /// ファイルが存在するかを判定する。
+    async fn exists(&self, uri: &str) -> Result<bool, StorageError>;
+
+    /// ファイルの内容を読み出すストリームを返す。
+    async fn stream(&self, uri: &str) -> Result<ByteStream, StorageError>;
+
+    /// ストレージのルート配下に保存されたすべてのファイルのURIを返す。
+    /// 書き込み途中の一時ファイルは含まない。
+    async fn list(&self) -> Result<Vec<String>, StorageError>;
+
+    /// 書き込み途中で中断したファイルのうち、最終更新から`older_than`以上経過したものを削除し、削除したファイルの数を返す。
+    /// 他のプロセスが書き込み中のファイルを削除しないよう、最近更新されたファイルは残す。
+    /// 書き込みが不可分に行われ、途中のファイルが残らないストレージでは何もしない。
+    async fn remove_temporary_files(&self, _older_than: Duration) -> Result<usize, StorageError> {
+        Ok(0)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("URIの形式が不正です (URI=\"{uri}\")")]
+    InvalidUri { uri: String },
+    #[error("ストレージが対応していないURIです (URI=\"{uri}\")")]
+    UnsupportedUri { uri: String },
+    #[error("ファイルが存在しません (URI=\"{uri}\")")]
+    NotFound { uri: String },
+    #[error("ディレクトリの作成に失敗しました (パス=\"{}\"): {io_error}", path_buf.display())]
+    CreateDirError {
+        path_buf: PathBuf,
+        io_error: io::Error,
+    },
+    #[error("ファイルの書き込みに失敗しました (パス=\"{}\"): {io_error}", path_buf.display())]
+    WriteFileError {
+        path_buf: PathBuf,
+        io_error: io::Error,
+    },
+    #[error("ファイルの読み込みに失敗しました (パス=\"{}\"): {io_error}", path_buf.display())]
+    ReadFileError {
+        path_buf: PathBuf,
+        io_error: io::Error,
+    },
+    #[error("ファイルの削除に失敗しました (パス=\"{}\"): {io_error}", path_buf.display())]
+    RemoveFileError {
+        path_buf: PathBuf,
+        io_error: io::Error,
+    },
+    #[error("オブジェクトストレージへのリクエストに失敗しました (URI=\"{uri}\"): {message}")]
+    RequestError { uri: String, message: String },
+    #[error(
+        "ファイルのハッシュ値が記録された値と一致しません (URI=\"{uri}\", 記録された値={expected}, ファイルの値={actual})"
+    )]
+    ChecksumMismatch {
+        uri: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl StorageError {
+    /// ローカルファイルシステムの入出力で発生したエラーの場合、その原因を返す。
+    pub fn io_error(&self) -> Option<&io::Error> {
+        match self {
+            Self::CreateDirError { io_error, .. }
+            | Self::WriteFileError { io_error, .. }
+            | Self::ReadFileError { io_error, .. }
+            | Self::RemoveFileError { io_error, .. } => Some(io_error),
+            _ => None,
+        }
+    }
+}
+
+/// ファイル全体のSHA-256ハッシュ値を16進数の文字列として返す。
+pub fn calculate_file_hash(buf: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(buf))
+}
+
+/// ファイルの内容を順に読み出しながらSHA-256ハッシュ値を計算し、ファイルのサイズとハッシュ値を返す。
+///
+/// 2GiBを超えるファイルも、全体をメモリに読み込まずに計算できる。
+pub async fn calculate_stream_hash(mut stream: ByteStream) -> io::Result<(u64, String)> {
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0; 1024 * 1024];
+    let mut size = 0;
+    loop {
+        let len = stream.read(&mut buf).await?;
+        if len == 0 {
+            break;
+        }
+        hasher.update(&buf[..len]);
+        size += len as u64;
+    }
+
+    Ok((size, format!("{:x}", hasher.finalize())))
+}
+
+/// 読み出したファイルのハッシュ値が、保存時に記録したハッシュ値と一致するかを検証する。
+pub fn verify_file_hash(uri: &str, buf: &[u8], expected: &str) -> Result<(), StorageError> {
+    let actual = calculate_file_hash(buf);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(StorageError::ChecksumMismatch {
+            uri: uri.to_string(),
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+}
+
+/// ファイルのサイズをDBに記録する値に変換する。
+///
+/// DBのサイズはbigint型のため、`i64`で表せないサイズはエラーとする。
+pub fn size_for_db(size: u64) -> Result<i64, String> {
+    i64::try_from(size).map_err(|_| format!("ファイルのサイズが大きすぎます (サイズ={size})"))
+}
+
+/// DBに記録したサイズをファイルのサイズに変換する。
+pub fn size_from_db(size: i64) -> Result<u64, String> {
+    u64::try_from(size).map_err(|_| format!("DBに記録したサイズが負の値です (サイズ={size})"))
+}
+
+/// 保存先のストレージの設定
+///
+/// DICOMサーバーは起動時の設定に従ってストレージを選択し、宛先AEごとの保存先ディレクトリに対してストレージを開く。
+#[derive(Debug, Clone)]
+pub enum StorageConfig {
+    /// データディレクトリ配下に相対パスのままファイルを保存する
+    FileSystem { data_dir: PathBuf },
+    /// データディレクトリ配下に内容のハッシュ値をパスとしてファイルを保存する
+    ContentAddressed { data_dir: PathBuf },
+    /// S3互換のオブジェクトストレージに保存する
+    S3(S3Config),
+}
+
+impl StorageConfig {
+    /// 保存先ディレクトリを指定してストレージを開く。
+    ///
+    /// 保存先ディレクトリが相対パスの場合はデータディレクトリからの相対パスとする。
+    /// S3互換のオブジェクトストレージの場合は保存先ディレクトリをキーの接頭辞とする。
+    pub fn open(&self, dir: &str) -> Box<dyn StorageBackend> {
+        match self {
+            Self::FileSystem { data_dir } => Box::new(FileSystemStorage::new(data_dir.join(dir))),
+            Self::ContentAddressed { data_dir } => {
+                Box::new(ContentAddressedStorage::new(data_dir.join(dir)))
+            }
+            Self::S3(config) => Box::new(S3Storage::new(config.clone(), dir)),
+        }
+    }
+
+    /// 保存済みのファイルのURIに対応するストレージを選択するリゾルバーを返す。
+    pub fn resolver(&self) -> StorageResolver {
+        StorageResolver::new(match self {
+            Self::S3(config) => Some(config.clone()),
+            _ => None,
+        })
+    }
+}
+
+/// 保存済みのファイルのURIに対応するストレージを選択する。
+///
+/// `file`スキームのURIおよびスキームを持たないパス（URIの導入前に記録されたパス）はローカルファイルシステム、
+/// `s3`スキームのURIはS3互換のオブジェクトストレージのファイルとして扱う。
+pub struct StorageResolver {
+    file_system: FileSystemStorage,
+    s3: Option<S3Storage>,
+}
+
+impl StorageResolver {
+    /// S3互換のオブジェクトストレージを使用しない場合は`s3_config`に`None`を指定する。
+    pub fn new(s3_config: Option<S3Config>) -> Self {
+        Self {
+            file_system: FileSystemStorage::new("/"),
+            s3: s3_config.map(|config| S3Storage::new(config, "")),
+        }
+    }
+
+    pub fn resolve(&self, uri: &str) -> Result<&dyn StorageBackend, StorageError> {
+        match StorageUri::parse(uri)? {
+            StorageUri::File(_) => Ok(&self.file_system),
+            StorageUri::S3 { .. } => match &self.s3 {
+                Some(s3) => Ok(s3),
+                None => Err(StorageError::UnsupportedUri {
+                    uri: uri.to_string(),
+                }),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        // Arrange
+        let resolver = StorageResolver::new(None);
+
+        // Act & Assert
+        assert!(
+            resolver
+                .resolve("file:///var/lib/oceanus/dicom/1.dcm")
+                .is_ok()
+        );
+        assert!(resolver.resolve("/var/lib/oceanus/dicom/1.dcm").is_ok());
+        assert!(matches!(
+            resolver.resolve("s3://oceanus/dicom/1.dcm"),
+            Err(StorageError::UnsupportedUri { .. })
+        ));
+        assert!(matches!(
+            resolver.resolve("http://localhost/dicom/1.dcm"),
+            Err(StorageError::InvalidUri { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_file_hash() {
+        // Arrange
+        let buf = b"DICM";
+        let hash = calculate_file_hash(buf);
+
+        // Act & Assert
+        assert_eq!(hash.len(), 64);
+        assert!(verify_file_hash("file:///dicom/1.dcm", buf, &hash).is_ok());
+        assert!(matches!(
+            verify_file_hash("file:///dicom/1.dcm", b"DICN", &hash),
+            Err(StorageError::ChecksumMismatch { expected, .. }) if expected == hash
+        ));
+    }
+
+    #[test]
+    fn test_size_from_db() {
+        // 正常系: 2GiBを超えるサイズもそのまま変換する
+        assert_eq!(size_from_db(i32::MAX as i64 + 1), Ok(i32::MAX as u64 + 1));
+        assert_eq!(size_from_db(i64::MAX), Ok(i64::MAX as u64));
+        assert_eq!(size_from_db(i64::MAX).and_then(size_for_db), Ok(i64::MAX));
+
+        // 準正常系: 負の値
+        assert_eq!(
+            size_from_db(-1),
+            Err("DBに記録したサイズが負の値です (サイズ=-1)".to_string())
+        );
+    }
+}
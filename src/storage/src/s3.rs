@@ -0,0 +1,519 @@
+mod signature;
+
+use self::signature::Credentials;
+use crate::{ByteStream, StorageBackend, StorageError, StorageUri};
+use chrono::Utc;
+use futures_util::TryStreamExt;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::{Client, Method, Response, StatusCode};
+use std::{fmt, io};
+use tokio_util::io::StreamReader;
+
+/// オブジェクトのキーでエスケープしない文字（RFC 3986の非予約文字および区切り文字の`/`）
+const KEY: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b'/');
+
+/// クエリ文字列のパラメーターでエスケープしない文字（RFC 3986の非予約文字）
+const QUERY: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// S3互換のオブジェクトストレージへの接続設定
+#[derive(Clone)]
+pub struct S3Config {
+    /// エンドポイントのURL（例: `http://localhost:9000`）
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+impl fmt::Debug for S3Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // シークレットアクセスキーはログに出力しない
+        f.debug_struct("S3Config")
+            .field("endpoint", &self.endpoint)
+            .field("region", &self.region)
+            .field("bucket", &self.bucket)
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// S3互換のオブジェクトストレージをストレージとして使用する。
+///
+/// キーに接頭辞を付与したものをオブジェクトのキーとし、パス形式のURL（`{エンドポイント}/{バケット}/{キー}`）でアクセスする。
+pub struct S3Storage {
+    client: Client,
+    config: S3Config,
+    prefix: String,
+}
+
+impl S3Storage {
+    pub fn new(config: S3Config, prefix: &str) -> Self {
+        Self {
+            client: Client::new(),
+            config,
+            prefix: prefix.trim_matches('/').to_string(),
+        }
+    }
+
+    fn object_key(&self, key: &str) -> String {
+        let key = key.trim_start_matches('/');
+        if self.prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}/{key}", self.prefix)
+        }
+    }
+
+    fn uri(&self, key: &str) -> String {
+        StorageUri::S3 {
+            bucket: self.config.bucket.clone(),
+            key: self.object_key(key),
+        }
+        .to_string()
+    }
+
+    fn parse_s3_uri(uri: &str) -> Result<(String, String), StorageError> {
+        match StorageUri::parse(uri)? {
+            StorageUri::S3 { bucket, key } => Ok((bucket, key)),
+            _ => Err(StorageError::UnsupportedUri {
+                uri: uri.to_string(),
+            }),
+        }
+    }
+
+    /// 署名したリクエストを送信する。
+    ///
+    /// `key`が空の場合はバケットに対するリクエストとする。
+    /// `query`はパラメーターを名前の昇順に並べてエンコードしたクエリ文字列とする。
+    async fn send(
+        &self,
+        method: Method,
+        uri: &str,
+        bucket: &str,
+        key: &str,
+        query: &str,
+        body: Vec<u8>,
+    ) -> Result<Response, StorageError> {
+        let request_error = |message: String| StorageError::RequestError {
+            uri: uri.to_string(),
+            message,
+        };
+
+        let mut path = if key.is_empty() {
+            format!("/{bucket}")
+        } else {
+            format!("/{bucket}/{}", utf8_percent_encode(key, KEY))
+        };
+        if !query.is_empty() {
+            path = format!("{path}?{query}");
+        }
+        let url = reqwest::Url::parse(&format!(
+            "{}{path}",
+            self.config.endpoint.trim_end_matches('/')
+        ))
+        .map_err(|e| request_error(format!("URLの形式が不正です: {e}")))?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(request_error("URLにホストが存在しません".to_string())),
+        };
+
+        let date_time = Utc::now();
+        let amz_date = signature::format_date_time(date_time);
+        let payload_hash = signature::hash(&body);
+        let authorization = signature::sign(
+            method.as_str(),
+            &path,
+            &[
+                ("host", &host),
+                ("x-amz-content-sha256", &payload_hash),
+                ("x-amz-date", &amz_date),
+            ],
+            &payload_hash,
+            date_time,
+            &Credentials {
+                access_key_id: &self.config.access_key_id,
+                secret_access_key: &self.config.secret_access_key,
+                region: &self.config.region,
+            },
+        );
+
+        self.client
+            .request(method, url)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("x-amz-date", amz_date)
+            .header("authorization", authorization)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| request_error(e.to_string()))
+    }
+
+    /// 応答のステータスコードが成功を表さない場合はエラーを返す。
+    async fn check_status(uri: &str, response: Response) -> Result<Response, StorageError> {
+        match response.status() {
+            status if status.is_success() => Ok(response),
+            StatusCode::NOT_FOUND => Err(StorageError::NotFound {
+                uri: uri.to_string(),
+            }),
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                Err(StorageError::RequestError {
+                    uri: uri.to_string(),
+                    message: format!("ステータスコード={status} 応答={body}"),
+                })
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageBackend for S3Storage {
+    async fn put(&self, key: &str, buf: Vec<u8>) -> Result<String, StorageError> {
+        let uri = self.uri(key);
+        self.replace(&uri, buf).await?;
+        Ok(uri)
+    }
+
+    async fn locate(&self, key: &str, _file_hash: &str) -> Result<Option<String>, StorageError> {
+        let uri = self.uri(key);
+        Ok(self.exists(&uri).await?.then_some(uri))
+    }
+
+    /// オブジェクトの書き込みは不可分に行われるため、そのまま上書きする。
+    async fn replace(&self, uri: &str, buf: Vec<u8>) -> Result<(), StorageError> {
+        let (bucket, key) = Self::parse_s3_uri(uri)?;
+        let response = self.send(Method::PUT, uri, &bucket, &key, "", buf).await?;
+        Self::check_status(uri, response).await?;
+        Ok(())
+    }
+
+    async fn get(&self, uri: &str) -> Result<Vec<u8>, StorageError> {
+        let (bucket, key) = Self::parse_s3_uri(uri)?;
+        let response = self
+            .send(Method::GET, uri, &bucket, &key, "", Vec::new())
+            .await?;
+        let bytes = Self::check_status(uri, response)
+            .await?
+            .bytes()
+            .await
+            .map_err(|e| StorageError::RequestError {
+                uri: uri.to_string(),
+                message: e.to_string(),
+            })?;
+        Ok(bytes.to_vec())
+    }
+
+    async fn delete(&self, uri: &str) -> Result<(), StorageError> {
+        let (bucket, key) = Self::parse_s3_uri(uri)?;
+        let response = self
+            .send(Method::DELETE, uri, &bucket, &key, "", Vec::new())
+            .await?;
+        Self::check_status(uri, response).await?;
+        Ok(())
+    }
+
+    async fn exists(&self, uri: &str) -> Result<bool, StorageError> {
+        let (bucket, key) = Self::parse_s3_uri(uri)?;
+        let response = self
+            .send(Method::HEAD, uri, &bucket, &key, "", Vec::new())
+            .await?;
+        match Self::check_status(uri, response).await {
+            Ok(_) => Ok(true),
+            Err(StorageError::NotFound { .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn stream(&self, uri: &str) -> Result<ByteStream, StorageError> {
+        let (bucket, key) = Self::parse_s3_uri(uri)?;
+        let response = self
+            .send(Method::GET, uri, &bucket, &key, "", Vec::new())
+            .await?;
+        let stream = Self::check_status(uri, response)
+            .await?
+            .bytes_stream()
+            .map_err(io::Error::other);
+        Ok(Box::pin(StreamReader::new(stream)))
+    }
+
+    /// ListObjectsV2でキーの接頭辞に一致するオブジェクトを列挙する。
+    async fn list(&self) -> Result<Vec<String>, StorageError> {
+        let bucket = &self.config.bucket;
+        let prefix = if self.prefix.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", self.prefix)
+        };
+        let uri = format!("s3://{bucket}/{prefix}");
+
+        let mut uris = Vec::new();
+        let mut continuation_token: Option<String> = None;
+        loop {
+            let mut query = String::new();
+            if let Some(continuation_token) = &continuation_token {
+                query.push_str(&format!(
+                    "continuation-token={}&",
+                    utf8_percent_encode(continuation_token, QUERY)
+                ));
+            }
+            query.push_str("list-type=2");
+            if !prefix.is_empty() {
+                query.push_str(&format!("&prefix={}", utf8_percent_encode(&prefix, QUERY)));
+            }
+
+            let response = self
+                .send(Method::GET, &uri, bucket, "", &query, Vec::new())
+                .await?;
+            let body = Self::check_status(&uri, response)
+                .await?
+                .text()
+                .await
+                .map_err(|e| StorageError::RequestError {
+                    uri: uri.clone(),
+                    message: e.to_string(),
+                })?;
+
+            uris.extend(xml_values(&body, "Key").into_iter().map(|key| {
+                StorageUri::S3 {
+                    bucket: bucket.clone(),
+                    key,
+                }
+                .to_string()
+            }));
+
+            let is_truncated =
+                xml_values(&body, "IsTruncated").first().map(String::as_str) == Some("true");
+            continuation_token = xml_values(&body, "NextContinuationToken")
+                .into_iter()
+                .next();
+            if !is_truncated || continuation_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(uris)
+    }
+}
+
+/// XML文書から指定した名前の要素の値をすべて返す。
+/// 属性を持つ要素およびCDATAセクションには対応しない。
+fn xml_values(xml: &str, name: &str) -> Vec<String> {
+    let (start_tag, end_tag) = (format!("<{name}>"), format!("</{name}>"));
+
+    let mut values = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&start_tag) {
+        rest = &rest[start + start_tag.len()..];
+        let Some(end) = rest.find(&end_tag) else {
+            break;
+        };
+        values.push(
+            rest[..end]
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&apos;", "'")
+                .replace("&amp;", "&"),
+        );
+        rest = &rest[end + end_tag.len()..];
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{signature::sign, *};
+    use axum::{
+        Router,
+        body::Bytes,
+        extract::State,
+        http::{HeaderMap, Method, StatusCode, Uri},
+        routing::any,
+    };
+    use chrono::NaiveDateTime;
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+    use tokio::{io::AsyncReadExt, net::TcpListener};
+
+    type Objects = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+    /// S3互換のオブジェクトストレージの代わりに、署名を検証してオブジェクトをメモリ上に保持するサーバーを起動する。
+    async fn start_stand_in_server(objects: Objects) -> String {
+        async fn handle(
+            State(objects): State<Objects>,
+            method: Method,
+            uri: Uri,
+            headers: HeaderMap,
+            body: Bytes,
+        ) -> (StatusCode, Vec<u8>) {
+            let header = |name: &str| headers.get(name).unwrap().to_str().unwrap();
+            let date_time = NaiveDateTime::parse_from_str(header("x-amz-date"), "%Y%m%dT%H%M%SZ")
+                .unwrap()
+                .and_utc();
+            let expected = sign(
+                method.as_str(),
+                uri.path_and_query().unwrap().as_str(),
+                &[
+                    ("host", header("host")),
+                    ("x-amz-content-sha256", header("x-amz-content-sha256")),
+                    ("x-amz-date", header("x-amz-date")),
+                ],
+                &signature::hash(&body),
+                date_time,
+                &Credentials {
+                    access_key_id: "oceanus",
+                    secret_access_key: "oceanus-secret",
+                    region: "us-east-1",
+                },
+            );
+            if header("authorization") != expected {
+                return (StatusCode::FORBIDDEN, Vec::new());
+            }
+
+            let mut objects = objects.lock().unwrap();
+            let path = uri.path().to_string();
+            if let Some(query) = uri.query() {
+                // ListObjectsV2（接頭辞のみに対応し、一度にすべてのオブジェクトを返す）
+                let prefix = query
+                    .split('&')
+                    .find_map(|param| param.strip_prefix("prefix="))
+                    .unwrap_or_default()
+                    .replace("%2F", "/");
+                let mut keys = objects
+                    .keys()
+                    .filter_map(|object_path| object_path.strip_prefix(&format!("{path}/")))
+                    .filter(|key| key.starts_with(&prefix))
+                    .map(|key| format!("<Contents><Key>{key}</Key></Contents>"))
+                    .collect::<Vec<_>>();
+                keys.sort();
+                let body = format!(
+                    "<ListBucketResult><IsTruncated>false</IsTruncated>{}</ListBucketResult>",
+                    keys.concat()
+                );
+                return (StatusCode::OK, body.into_bytes());
+            }
+            match method {
+                Method::PUT => {
+                    objects.insert(path, body.to_vec());
+                    (StatusCode::OK, Vec::new())
+                }
+                Method::GET | Method::HEAD => match objects.get(&path) {
+                    Some(object) => (StatusCode::OK, object.clone()),
+                    None => (StatusCode::NOT_FOUND, Vec::new()),
+                },
+                Method::DELETE => {
+                    objects.remove(&path);
+                    (StatusCode::NO_CONTENT, Vec::new())
+                }
+                _ => (StatusCode::METHOD_NOT_ALLOWED, Vec::new()),
+            }
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let router = Router::new()
+            .route("/{*path}", any(handle))
+            .with_state(objects);
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        format!("http://{addr}")
+    }
+
+    fn config(endpoint: String, secret_access_key: &str) -> S3Config {
+        S3Config {
+            endpoint,
+            region: "us-east-1".to_string(),
+            bucket: "oceanus".to_string(),
+            access_key_id: "oceanus".to_string(),
+            secret_access_key: secret_access_key.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_s3_storage() {
+        // Arrange
+        let objects = Objects::default();
+        let endpoint = start_stand_in_server(objects.clone()).await;
+        let storage = S3Storage::new(config(endpoint.clone(), "oceanus-secret"), "/dicom/");
+
+        // Act & Assert
+        // キーに接頭辞を付与したオブジェクトとして保存される
+        let uri = storage
+            .put("success/2026/02/03/1.2.3.dcm", b"DICM".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(uri, "s3://oceanus/dicom/success/2026/02/03/1.2.3.dcm");
+        assert_eq!(
+            objects
+                .lock()
+                .unwrap()
+                .get("/oceanus/dicom/success/2026/02/03/1.2.3.dcm"),
+            Some(&b"DICM".to_vec())
+        );
+        assert!(storage.exists(&uri).await.unwrap());
+        assert_eq!(storage.get(&uri).await.unwrap(), b"DICM");
+        assert_eq!(
+            storage
+                .locate("success/2026/02/03/1.2.3.dcm", "")
+                .await
+                .unwrap(),
+            Some(uri.clone())
+        );
+        assert_eq!(storage.locate("1.2.4.dcm", "").await.unwrap(), None);
+        // 接頭辞に一致しないオブジェクトは列挙しない
+        S3Storage::new(config(endpoint.clone(), "oceanus-secret"), "other")
+            .put("1.2.4.dcm", b"DICM".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(storage.list().await.unwrap(), vec![uri.clone()]);
+
+        storage.replace(&uri, b"DICM2".to_vec()).await.unwrap();
+        let mut buf = Vec::new();
+        storage
+            .stream(&uri)
+            .await
+            .unwrap()
+            .read_to_end(&mut buf)
+            .await
+            .unwrap();
+        assert_eq!(buf, b"DICM2");
+
+        storage.delete(&uri).await.unwrap();
+        assert!(!storage.exists(&uri).await.unwrap());
+        assert!(matches!(
+            storage.get(&uri).await,
+            Err(StorageError::NotFound { .. })
+        ));
+        assert!(matches!(
+            storage.get("file:///var/lib/oceanus/1.2.3.dcm").await,
+            Err(StorageError::UnsupportedUri { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_s3_storage_with_invalid_credentials() {
+        // Arrange
+        let endpoint = start_stand_in_server(Objects::default()).await;
+        let storage = S3Storage::new(config(endpoint, "invalid-secret"), "");
+
+        // Act
+        let result = storage.put("1.2.3.dcm", b"DICM".to_vec()).await;
+
+        // Assert
+        assert!(matches!(result, Err(StorageError::RequestError { .. })));
+    }
+}
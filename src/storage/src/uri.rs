@@ -0,0 +1,175 @@
+use crate::StorageError;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use std::path::{Path, PathBuf};
+
+const FILE_SCHEME: &str = "file://";
+const S3_SCHEME: &str = "s3://";
+
+/// URIのパスでエスケープしない文字（RFC 3986の非予約文字および区切り文字の`/`）
+const PATH: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b'/');
+
+/// 保存したファイルを識別するURI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageUri {
+    /// ローカルファイルシステム上のファイル（`file:///path/to/file`）
+    File(PathBuf),
+    /// S3互換のオブジェクトストレージ上のオブジェクト（`s3://bucket/key`）
+    S3 { bucket: String, key: String },
+}
+
+impl StorageUri {
+    /// URIを解析する。
+    /// スキームを持たないパスはURIの導入前に記録されたローカルファイルシステム上のパスとして扱う。
+    pub fn parse(uri: &str) -> Result<Self, StorageError> {
+        let invalid_uri = || StorageError::InvalidUri {
+            uri: uri.to_string(),
+        };
+        let decode = |str: &str| {
+            percent_decode_str(str)
+                .decode_utf8()
+                .map(|str| str.to_string())
+                .map_err(|_| invalid_uri())
+        };
+
+        if let Some(path) = uri.strip_prefix(FILE_SCHEME) {
+            // ホスト名を持つURIには対応しない
+            if !path.starts_with('/') {
+                return Err(invalid_uri());
+            }
+            Ok(Self::File(PathBuf::from(decode(path)?)))
+        } else if let Some(rest) = uri.strip_prefix(S3_SCHEME) {
+            let Some((bucket, key)) = rest.split_once('/') else {
+                return Err(invalid_uri());
+            };
+            if bucket.is_empty() || key.is_empty() {
+                return Err(invalid_uri());
+            }
+            Ok(Self::S3 {
+                bucket: bucket.to_string(),
+                key: decode(key)?,
+            })
+        } else if uri.contains("://") || uri.is_empty() {
+            Err(invalid_uri())
+        } else {
+            Ok(Self::File(PathBuf::from(uri)))
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Self {
+        Self::File(path.to_path_buf())
+    }
+}
+
+impl std::fmt::Display for StorageUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(
+                f,
+                "{FILE_SCHEME}{}",
+                utf8_percent_encode(&path.to_string_lossy(), PATH)
+            ),
+            Self::S3 { bucket, key } => {
+                write!(f, "{S3_SCHEME}{bucket}/{}", utf8_percent_encode(key, PATH))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        // 正常系: fileスキーム
+        {
+            // Act
+            let uri = StorageUri::parse("file:///var/lib/oceanus/dicom/a%20b.dcm").unwrap();
+
+            // Assert
+            assert_eq!(
+                uri,
+                StorageUri::File(PathBuf::from("/var/lib/oceanus/dicom/a b.dcm"))
+            );
+        }
+
+        // 正常系: s3スキーム
+        {
+            // Act
+            let uri = StorageUri::parse("s3://oceanus/dicom/success/1.2.3.dcm").unwrap();
+
+            // Assert
+            assert_eq!(
+                uri,
+                StorageUri::S3 {
+                    bucket: "oceanus".to_string(),
+                    key: "dicom/success/1.2.3.dcm".to_string()
+                }
+            );
+        }
+
+        // 正常系: スキームを持たないパス
+        {
+            // Act
+            let uri = StorageUri::parse("/var/lib/oceanus/dicom/1.dcm").unwrap();
+
+            // Assert
+            assert_eq!(
+                uri,
+                StorageUri::File(PathBuf::from("/var/lib/oceanus/dicom/1.dcm"))
+            );
+        }
+
+        // 準正常系: 不正なURI
+        {
+            for uri in [
+                "",
+                "file://localhost/var/lib/oceanus/dicom/1.dcm",
+                "s3://oceanus",
+                "s3:///dicom/1.dcm",
+                "http://localhost/dicom/1.dcm",
+            ] {
+                // Act
+                let result = StorageUri::parse(uri);
+
+                // Assert
+                assert!(
+                    matches!(result, Err(StorageError::InvalidUri { .. })),
+                    "{uri}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_string() {
+        // Arrange
+        let uris = [
+            StorageUri::File(PathBuf::from("/var/lib/oceanus/dicom/a b.dcm")),
+            StorageUri::S3 {
+                bucket: "oceanus".to_string(),
+                key: "dicom/success/1.2.3.dcm".to_string(),
+            },
+        ];
+
+        // Act
+        let strings = uris.iter().map(|uri| uri.to_string()).collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(
+            strings,
+            [
+                "file:///var/lib/oceanus/dicom/a%20b.dcm",
+                "s3://oceanus/dicom/success/1.2.3.dcm"
+            ]
+        );
+        for (uri, string) in uris.iter().zip(&strings) {
+            assert_eq!(&StorageUri::parse(string).unwrap(), uri);
+        }
+    }
+}
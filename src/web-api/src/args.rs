@@ -1,4 +1,11 @@
+use crate::internal::{
+    domain::value_object::{Id, LocalLoginPolicy, Role, RoleMapping},
+    infrastructure::repository::OidcConfig,
+};
 use clap::{Parser, ValueEnum};
+use ingest::DuplicatePolicy;
+use std::path::PathBuf;
+use storage::{S3Config, StorageConfig};
 use tracing::level_filters::LevelFilter;
 
 #[derive(Parser, Debug)]
@@ -12,9 +19,252 @@ pub struct Args {
     #[arg(short = 'p', long = "port", env = "PORT", default_value_t = 8080)]
     pub port: u16,
 
+    /// 匿名化したDICOMファイルのエクスポート先ディレクトリ
+    #[arg(
+        long = "export-directory",
+        env = "EXPORT_DIRECTORY",
+        default_value = "/var/lib/oceanus/export"
+    )]
+    pub export_directory: String,
+
+    /// STOW-RSで受け付けたSOPインスタンスの宛先AEタイトル
+    #[arg(long = "ae-title", env = "AE_TITLE", default_value = "OCEANUS")]
+    pub ae_title: String,
+
+    /// データディレクトリ（STOW-RSで受け付けたSOPインスタンスの保存先）
+    #[arg(
+        long = "data-dir",
+        env = "DATA_DIR",
+        default_value = "/var/lib/oceanus"
+    )]
+    pub data_dir: String,
+
+    /// 既定のAEタイトルにおける重複したSOPインスタンスの処理方針
+    #[arg(long = "duplicate-policy", env = "DUPLICATE_POLICY", value_enum, default_value_t = DuplicatePolicy::Overwrite)]
+    pub duplicate_policy: DuplicatePolicy,
+
+    /// STOW-RSで受け付けたSOPインスタンスの保存先のストレージ
+    #[arg(long = "storage-backend", env = "STORAGE_BACKEND", value_enum, default_value_t = StorageBackendKind::FileSystem)]
+    pub storage_backend: StorageBackendKind,
+
+    /// WADO-URIの署名付きURLの署名に使用する秘密鍵
+    ///
+    /// 指定しない場合は起動ごとに生成するため、再起動すると発行済みの署名付きURLは無効になる。
+    #[arg(long = "wado-uri-secret", env = "WADO_URI_SECRET")]
+    pub wado_uri_secret: Option<String>,
+
+    /// 削除したSOPインスタンスのファイルの移動先ディレクトリ（省略した場合はファイルを削除する）
+    #[arg(long = "trash-dir", env = "TRASH_DIR")]
+    pub trash_dir: Option<String>,
+
     /// ログレベル
     #[arg(long = "log-level", env = "LOG_LEVEL", value_enum, default_value_t = LogLevel::Info)]
     pub log_level: LogLevel,
+
+    /// S3互換のオブジェクトストレージのエンドポイント
+    #[arg(long = "s3-endpoint", env = "S3_ENDPOINT")]
+    pub s3_endpoint: Option<String>,
+
+    /// S3互換のオブジェクトストレージのリージョン
+    #[arg(long = "s3-region", env = "S3_REGION", default_value = "us-east-1")]
+    pub s3_region: String,
+
+    /// S3互換のオブジェクトストレージのバケット（STOW-RSで受け付けたSOPインスタンスの保存先）
+    #[arg(long = "s3-bucket", env = "S3_BUCKET")]
+    pub s3_bucket: Option<String>,
+
+    /// S3互換のオブジェクトストレージのアクセスキーID
+    #[arg(long = "s3-access-key-id", env = "S3_ACCESS_KEY_ID")]
+    pub s3_access_key_id: Option<String>,
+
+    /// S3互換のオブジェクトストレージのシークレットアクセスキー
+    #[arg(long = "s3-secret-access-key", env = "S3_SECRET_ACCESS_KEY")]
+    pub s3_secret_access_key: Option<String>,
+
+    /// シングルサインオン (OpenID Connect) のIdPの発行者URL（省略した場合はシングルサインオンを無効にする）
+    #[arg(long = "oidc-issuer-url", env = "OIDC_ISSUER_URL")]
+    pub oidc_issuer_url: Option<String>,
+
+    /// IdPに登録したクライアントID
+    #[arg(long = "oidc-client-id", env = "OIDC_CLIENT_ID")]
+    pub oidc_client_id: Option<String>,
+
+    /// IdPに登録したクライアントシークレット（パブリッククライアントの場合は省略する）
+    #[arg(long = "oidc-client-secret", env = "OIDC_CLIENT_SECRET")]
+    pub oidc_client_secret: Option<String>,
+
+    /// IdPに登録したリダイレクトURI（`https://{ホスト名}/api/oidc/callback`など）
+    #[arg(long = "oidc-redirect-uri", env = "OIDC_REDIRECT_URI")]
+    pub oidc_redirect_uri: Option<String>,
+
+    /// IdPに要求するスコープ（空白区切り）
+    #[arg(
+        long = "oidc-scopes",
+        env = "OIDC_SCOPES",
+        default_value = "openid profile"
+    )]
+    pub oidc_scopes: String,
+
+    /// ユーザーIDを含むIDトークンのクレーム
+    #[arg(
+        long = "oidc-user-id-claim",
+        env = "OIDC_USER_ID_CLAIM",
+        default_value = "preferred_username"
+    )]
+    pub oidc_user_id_claim: String,
+
+    /// 名前を含むIDトークンのクレーム
+    #[arg(
+        long = "oidc-name-claim",
+        env = "OIDC_NAME_CLAIM",
+        default_value = "name"
+    )]
+    pub oidc_name_claim: String,
+
+    /// 所属するグループを含むIDトークンのクレーム
+    #[arg(
+        long = "oidc-groups-claim",
+        env = "OIDC_GROUPS_CLAIM",
+        default_value = "groups"
+    )]
+    pub oidc_groups_claim: String,
+
+    /// IdPのグループとロールの対応（`グループ=ロール`のカンマ区切り。例: `pacs-admins=0,radiologists=2`）
+    #[arg(long = "oidc-role-mappings", env = "OIDC_ROLE_MAPPINGS")]
+    pub oidc_role_mappings: Option<String>,
+
+    /// 対応するグループに所属しないIdPのユーザーのロール（省略した場合はログインを拒否する）
+    #[arg(long = "oidc-default-role", env = "OIDC_DEFAULT_ROLE")]
+    pub oidc_default_role: Option<i16>,
+
+    /// パスワードによるログインを許可するユーザーID（カンマ区切り。省略した場合は全ユーザーに許可する）
+    ///
+    /// シングルサインオンを使用する場合は、IdPの障害時に使用する緊急用のアカウントのみを指定する。
+    #[arg(long = "local-login-users", env = "LOCAL_LOGIN_USERS")]
+    pub local_login_users: Option<String>,
+}
+
+impl Args {
+    /// DICOMサーバーがS3互換のオブジェクトストレージに保存したファイルを読み書きするための接続設定を返す。
+    /// 接続設定が指定されていない場合は`None`を返す。
+    pub fn s3_config(&self) -> Option<S3Config> {
+        match (
+            non_empty(&self.s3_endpoint),
+            non_empty(&self.s3_access_key_id),
+            non_empty(&self.s3_secret_access_key),
+        ) {
+            (Some(endpoint), Some(access_key_id), Some(secret_access_key)) => Some(S3Config {
+                endpoint: endpoint.clone(),
+                region: self.s3_region.clone(),
+                // 読み書きするオブジェクトのバケットはURIから取得する
+                bucket: String::new(),
+                access_key_id: access_key_id.clone(),
+                secret_access_key: secret_access_key.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// STOW-RSで受け付けたSOPインスタンスを保存するストレージの設定を返す。
+    /// DICOMサーバーと同じ設定を指定することで、C-STOREで受信したSOPインスタンスと同じ場所に保存する。
+    pub fn storage_config(&self) -> Result<StorageConfig, String> {
+        let data_dir = PathBuf::from(&self.data_dir);
+        match self.storage_backend {
+            StorageBackendKind::FileSystem => Ok(StorageConfig::FileSystem { data_dir }),
+            StorageBackendKind::ContentAddressed => {
+                Ok(StorageConfig::ContentAddressed { data_dir })
+            }
+            StorageBackendKind::S3 => {
+                let (Some(mut config), Some(bucket)) =
+                    (self.s3_config(), non_empty(&self.s3_bucket))
+                else {
+                    return Err("S3互換のオブジェクトストレージを使用するには、S3_ENDPOINT、S3_BUCKET、S3_ACCESS_KEY_IDおよびS3_SECRET_ACCESS_KEYを指定する必要があります".to_string());
+                };
+                config.bucket = bucket.clone();
+                Ok(StorageConfig::S3(config))
+            }
+        }
+    }
+
+    /// WADO-URIの署名付きURLの署名に使用する秘密鍵を返す。
+    /// 指定されていない場合は`None`を返す。
+    pub fn wado_uri_secret(&self) -> Option<&[u8]> {
+        non_empty(&self.wado_uri_secret).map(|secret| secret.as_bytes())
+    }
+
+    /// 削除したSOPインスタンスのファイルの移動先ディレクトリを返す。
+    /// 指定されていない場合は`None`を返す。
+    pub fn trash_dir(&self) -> Option<&str> {
+        non_empty(&self.trash_dir).map(String::as_str)
+    }
+
+    /// シングルサインオン (OpenID Connect) の設定を返す。
+    /// 発行者URLが指定されていない場合は`None`を返す。
+    pub fn oidc_config(&self) -> Result<Option<OidcConfig>, String> {
+        let Some(issuer_url) = non_empty(&self.oidc_issuer_url) else {
+            return Ok(None);
+        };
+        let (Some(client_id), Some(redirect_uri)) = (
+            non_empty(&self.oidc_client_id),
+            non_empty(&self.oidc_redirect_uri),
+        ) else {
+            return Err("シングルサインオンを使用するには、OIDC_ISSUER_URL、OIDC_CLIENT_IDおよびOIDC_REDIRECT_URIを指定する必要があります".to_string());
+        };
+        Ok(Some(OidcConfig {
+            issuer_url: issuer_url.clone(),
+            client_id: client_id.clone(),
+            client_secret: non_empty(&self.oidc_client_secret).cloned(),
+            redirect_uri: redirect_uri.clone(),
+            scopes: self.oidc_scopes.clone(),
+            user_id_claim: self.oidc_user_id_claim.clone(),
+            name_claim: self.oidc_name_claim.clone(),
+            groups_claim: self.oidc_groups_claim.clone(),
+        }))
+    }
+
+    /// IdPのグループとロールの対応を返す。
+    pub fn role_mapping(&self) -> Result<RoleMapping, String> {
+        let default_role = self
+            .oidc_default_role
+            .map(Role::from_i16)
+            .transpose()
+            .map_err(|e| format!("OIDC_DEFAULT_ROLEが不正です: {e}"))?;
+        RoleMapping::parse(
+            non_empty(&self.oidc_role_mappings).map_or("", String::as_str),
+            default_role,
+        )
+        .map_err(|e| format!("OIDC_ROLE_MAPPINGSが不正です: {e}"))
+    }
+
+    /// パスワードによるログインを許可するユーザーを返す。
+    pub fn local_login_policy(&self) -> Result<LocalLoginPolicy, String> {
+        let Some(users) = non_empty(&self.local_login_users) else {
+            return Ok(LocalLoginPolicy::AllUsers);
+        };
+        users
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(|id| Id::new(id).map_err(|e| format!("LOCAL_LOGIN_USERSが不正です: {e}")))
+            .collect::<Result<Vec<_>, _>>()
+            .map(LocalLoginPolicy::Only)
+    }
+}
+
+/// 環境変数に空文字列が指定された場合は未指定として扱う。
+fn non_empty(value: &Option<String>) -> Option<&String> {
+    value.as_ref().filter(|value| !value.is_empty())
+}
+
+/// 保存先のストレージの種類
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum StorageBackendKind {
+    /// データディレクトリ配下に検査日・検査・シリーズごとのディレクトリを作成して保存する
+    FileSystem,
+    /// データディレクトリ配下に内容のハッシュ値をパスとして保存する
+    ContentAddressed,
+    /// S3互換のオブジェクトストレージに保存する
+    S3,
 }
 
 #[derive(ValueEnum, Clone, Copy, Debug)]
@@ -0,0 +1,19 @@
+mod delete_archived_object_use_case;
+mod get_archived_study_use_case;
+mod list_archived_patients_use_case;
+mod list_archived_sop_instances_use_case;
+mod list_archived_studies_use_case;
+
+pub use delete_archived_object_use_case::{
+    DeleteArchivedObjectCommand, DeleteArchivedObjectUseCase,
+};
+pub use get_archived_study_use_case::{
+    ArchivedStudyDetail, GetArchivedStudyCommand, GetArchivedStudyUseCase,
+};
+pub use list_archived_patients_use_case::{
+    ListArchivedPatientsCommand, ListArchivedPatientsUseCase,
+};
+pub use list_archived_sop_instances_use_case::{
+    ListArchivedSopInstancesCommand, ListArchivedSopInstancesUseCase,
+};
+pub use list_archived_studies_use_case::{ListArchivedStudiesCommand, ListArchivedStudiesUseCase};
@@ -0,0 +1,32 @@
+use crate::internal::domain::{
+    error::RepositoryError,
+    repository::{ArchiveDeletionRepository, ArchiveDeletionTarget},
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct DeleteArchivedObjectUseCase {
+    repository: Arc<dyn ArchiveDeletionRepository>,
+}
+
+pub struct DeleteArchivedObjectCommand {
+    pub target: ArchiveDeletionTarget,
+    pub deleted_by: Uuid,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl DeleteArchivedObjectUseCase {
+    pub fn new(repository: Arc<dyn ArchiveDeletionRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: DeleteArchivedObjectCommand,
+    ) -> Result<(), RepositoryError> {
+        self.repository
+            .delete(&command.target, &command.deleted_by, &command.deleted_at)
+            .await
+    }
+}
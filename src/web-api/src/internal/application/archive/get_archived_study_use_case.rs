@@ -0,0 +1,45 @@
+use crate::internal::domain::{
+    entity::{ArchivedSeries, ArchivedStudy},
+    error::RepositoryError,
+    repository::ArchiveRepository,
+};
+use std::sync::Arc;
+
+pub struct GetArchivedStudyUseCase {
+    repository: Arc<dyn ArchiveRepository>,
+}
+
+pub struct GetArchivedStudyCommand {
+    pub study_instance_uid: String,
+}
+
+/// 検査と、その検査に含まれるシリーズ
+pub struct ArchivedStudyDetail {
+    pub study: ArchivedStudy,
+    pub series: Vec<ArchivedSeries>,
+}
+
+impl GetArchivedStudyUseCase {
+    pub fn new(repository: Arc<dyn ArchiveRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: GetArchivedStudyCommand,
+    ) -> Result<ArchivedStudyDetail, RepositoryError> {
+        let study = self
+            .repository
+            .find_study(&command.study_instance_uid)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound {
+                resource: "検査".to_string(),
+                key: command.study_instance_uid.clone(),
+            })?;
+        let series = self
+            .repository
+            .find_series(&command.study_instance_uid)
+            .await?;
+        Ok(ArchivedStudyDetail { study, series })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::internal::domain::{
+    entity::ArchivedPatient,
+    error::RepositoryError,
+    repository::{ArchiveRepository, ArchivedPatientFilter, ArchivedPatientSortKey, Page},
+    value_object::SortOrder,
+};
+use std::sync::Arc;
+
+pub struct ListArchivedPatientsUseCase {
+    repository: Arc<dyn ArchiveRepository>,
+}
+
+pub struct ListArchivedPatientsCommand {
+    pub filter: ArchivedPatientFilter,
+    pub sort_key: ArchivedPatientSortKey,
+    pub sort_order: SortOrder,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+impl ListArchivedPatientsUseCase {
+    pub fn new(repository: Arc<dyn ArchiveRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: ListArchivedPatientsCommand,
+    ) -> Result<Page<ArchivedPatient>, RepositoryError> {
+        self.repository
+            .find_patients(
+                &command.filter,
+                command.sort_key,
+                command.sort_order,
+                command.offset,
+                command.limit,
+            )
+            .await
+    }
+}
@@ -0,0 +1,43 @@
+use crate::internal::domain::{
+    entity::ArchivedSopInstance, error::RepositoryError, repository::ArchiveRepository,
+};
+use std::sync::Arc;
+
+pub struct ListArchivedSopInstancesUseCase {
+    repository: Arc<dyn ArchiveRepository>,
+}
+
+pub struct ListArchivedSopInstancesCommand {
+    pub study_instance_uid: String,
+    pub series_instance_uid: String,
+}
+
+impl ListArchivedSopInstancesUseCase {
+    pub fn new(repository: Arc<dyn ArchiveRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// シリーズに含まれるSOPインスタンスの一覧を取得する。
+    /// シリーズが指定した検査に含まれない場合は、シリーズが見つからないものとして扱う。
+    pub async fn execute(
+        &self,
+        command: ListArchivedSopInstancesCommand,
+    ) -> Result<Vec<ArchivedSopInstance>, RepositoryError> {
+        let series = self
+            .repository
+            .find_series(&command.study_instance_uid)
+            .await?;
+        if !series
+            .iter()
+            .any(|s| s.series().instance_uid() == command.series_instance_uid)
+        {
+            return Err(RepositoryError::NotFound {
+                resource: "シリーズ".to_string(),
+                key: command.series_instance_uid,
+            });
+        }
+        self.repository
+            .find_sop_instances(&command.series_instance_uid)
+            .await
+    }
+}
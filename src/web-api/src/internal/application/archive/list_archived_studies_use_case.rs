@@ -0,0 +1,40 @@
+use crate::internal::domain::{
+    entity::ArchivedStudy,
+    error::RepositoryError,
+    repository::{ArchiveRepository, ArchivedStudyFilter, ArchivedStudySortKey, Page},
+    value_object::SortOrder,
+};
+use std::sync::Arc;
+
+pub struct ListArchivedStudiesUseCase {
+    repository: Arc<dyn ArchiveRepository>,
+}
+
+pub struct ListArchivedStudiesCommand {
+    pub filter: ArchivedStudyFilter,
+    pub sort_key: ArchivedStudySortKey,
+    pub sort_order: SortOrder,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+impl ListArchivedStudiesUseCase {
+    pub fn new(repository: Arc<dyn ArchiveRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: ListArchivedStudiesCommand,
+    ) -> Result<Page<ArchivedStudy>, RepositoryError> {
+        self.repository
+            .find_studies(
+                &command.filter,
+                command.sort_key,
+                command.sort_order,
+                command.offset,
+                command.limit,
+            )
+            .await
+    }
+}
@@ -1,7 +1,13 @@
 mod authenticate_user_use_case;
+mod complete_oidc_login_use_case;
 mod login_use_case;
 mod logout_use_case;
+mod start_oidc_login_use_case;
 
 pub use authenticate_user_use_case::{AuthenticateUserUseCase, AuthenticationError};
+pub use complete_oidc_login_use_case::{
+    CompleteOidcLoginCommand, CompleteOidcLoginUseCase, OidcLoginError,
+};
 pub use login_use_case::{LoginCommand, LoginUseCase};
 pub use logout_use_case::LogoutUseCase;
+pub use start_oidc_login_use_case::StartOidcLoginUseCase;
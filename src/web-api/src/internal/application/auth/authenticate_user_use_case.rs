@@ -18,6 +18,11 @@ pub enum AuthenticationError {
     #[error("ロックされています。管理者にお問い合わせください。")]
     Locked,
 
+    #[error(
+        "パスワードによるログインは許可されていません。シングルサインオンでログインしてください。"
+    )]
+    LocalLoginNotAllowed,
+
     #[error("認証に失敗しました: {message}")]
     Other { message: String },
 }
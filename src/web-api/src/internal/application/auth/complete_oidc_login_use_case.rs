@@ -0,0 +1,252 @@
+use crate::internal::{
+    application::session::CreateSessionUseCase,
+    domain::{
+        entity::{ExternalIdentity, User, UserIdentity},
+        error::{IdentityProviderError, RepositoryError},
+        repository::{
+            IdentityProviderRepository, OidcAuthorizationRequestRepository, UserIdentityRepository,
+            UserRepository,
+        },
+        value_object::{Id, Role, RoleMapping, UserName},
+    },
+};
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHasher, SaltString, rand_core::OsRng},
+};
+use chrono::Utc;
+use rand::Rng;
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::info;
+use uuid::Uuid;
+
+/// IdPのユーザーの作成・ロールの変更を行ったユーザーとして記録するUUID（初期データと同じくシステムを表す）
+const SYSTEM_USER_UUID: Uuid = Uuid::from_u128(0x0000_0000_0000_7000_8000_0000_0000_0000);
+
+#[derive(Debug, Error)]
+pub enum OidcLoginError {
+    #[error("認可リクエストが見つからないか期限が切れています。もう一度ログインしてください")]
+    InvalidState,
+
+    #[error(transparent)]
+    IdentityProvider(#[from] IdentityProviderError),
+
+    #[error("{0}")]
+    InvalidClaim(String),
+
+    #[error("IdPのユーザーに割り当てるロールがありません。管理者にお問い合わせください")]
+    RoleNotAssigned,
+
+    #[error(
+        "ユーザーID '{0}' のユーザーが既に存在します。IdPのユーザーとの紐付けを管理者に依頼してください"
+    )]
+    UserIdAlreadyExists(String),
+
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+pub struct CompleteOidcLoginUseCase {
+    identity_provider_repository: Arc<dyn IdentityProviderRepository>,
+    authorization_request_repository: Arc<dyn OidcAuthorizationRequestRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    user_identity_repository: Arc<dyn UserIdentityRepository>,
+    create_session_use_case: Arc<CreateSessionUseCase>,
+    role_mapping: RoleMapping,
+}
+
+pub struct CompleteOidcLoginCommand {
+    pub state: String,
+    pub code: String,
+    pub user_agent: Option<String>,
+}
+
+/// OpenID Connectによるログインの結果
+pub struct CompleteOidcLoginResult {
+    /// セッションID（CSRFトークンはログイン後に`/me`で取得する）
+    pub session_id: String,
+    /// ログイン開始時に指定された、ログイン後にリダイレクトするパス
+    pub redirect_to: String,
+}
+
+impl CompleteOidcLoginUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        identity_provider_repository: Arc<dyn IdentityProviderRepository>,
+        authorization_request_repository: Arc<dyn OidcAuthorizationRequestRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        user_identity_repository: Arc<dyn UserIdentityRepository>,
+        create_session_use_case: Arc<CreateSessionUseCase>,
+        role_mapping: RoleMapping,
+    ) -> Self {
+        Self {
+            identity_provider_repository,
+            authorization_request_repository,
+            user_repository,
+            user_identity_repository,
+            create_session_use_case,
+            role_mapping,
+        }
+    }
+
+    /// 認可コードでIdPのユーザーを認証し、対応するユーザーのセッションを確立する。
+    ///
+    /// IdPのユーザーは発行者とサブジェクトでユーザーに紐付け、紐付くユーザーがいない場合はユーザーを作成して紐付ける。
+    /// ユーザーのロールはログインのたびにIdPのグループに合わせて更新する。
+    pub async fn execute(
+        &self,
+        command: CompleteOidcLoginCommand,
+    ) -> Result<CompleteOidcLoginResult, OidcLoginError> {
+        // 認可リクエストを取り出す（同じ認可リクエストは一度しか使用できない）
+        let request = self
+            .authorization_request_repository
+            .take(&command.state)
+            .await?
+            .ok_or(OidcLoginError::InvalidState)?;
+
+        // 認可コードをIDトークンと交換し、IdPのユーザーを取得
+        let identity = self
+            .identity_provider_repository
+            .exchange_code(&command.code, &request)
+            .await?;
+
+        // IdPのグループからロールを決定
+        let role = self
+            .role_mapping
+            .resolve(identity.groups())
+            .ok_or(OidcLoginError::RoleNotAssigned)?;
+
+        // IdPのユーザーに紐付くユーザーを取得（初めての場合はユーザーを作成して紐付ける）
+        let mut user = match self
+            .user_identity_repository
+            .find_by_subject(identity.issuer(), identity.subject())
+            .await?
+        {
+            Some(user_identity) => self
+                .user_repository
+                .find_by_uuid(user_identity.user_uuid())
+                .await?
+                .ok_or_else(|| RepositoryError::NotFound {
+                    resource: "ユーザー".to_string(),
+                    key: user_identity.user_uuid().to_string(),
+                })?,
+            None => self.provision_user(&identity, role).await?,
+        };
+
+        // ロールをIdPのグループに合わせる
+        if user.role() != role {
+            let old_id = user.id().clone();
+            user.update(
+                user.id().clone(),
+                user.name().clone(),
+                role,
+                user.password_hash().to_string(),
+                SYSTEM_USER_UUID,
+                Utc::now(),
+            );
+            user = self.user_repository.update(&old_id, &user).await?;
+            info!(
+                "IdPのグループに合わせてユーザーのロールを変更しました (ユーザーID={}, ロール={})",
+                user.id().value(),
+                role.as_i16()
+            );
+        }
+
+        // セッション確立
+        let (session_id, _csrf_token) = self
+            .create_session_use_case
+            .execute(*user.uuid(), command.user_agent)
+            .await?;
+
+        Ok(CompleteOidcLoginResult {
+            session_id,
+            redirect_to: request.redirect_to().to_string(),
+        })
+    }
+
+    /// IdPのユーザーに対応するユーザーを作成して紐付ける。
+    ///
+    /// IDトークンのユーザーIDはIdPのユーザーが変更できる場合があるため、同じIDのユーザーがいても紐付けない。
+    /// 既存のユーザーとの紐付けは管理者が行う。
+    async fn provision_user(
+        &self,
+        identity: &ExternalIdentity,
+        role: Role,
+    ) -> Result<User, OidcLoginError> {
+        let user_id = identity
+            .user_id()
+            .ok_or_else(|| {
+                OidcLoginError::InvalidClaim("IDトークンにユーザーIDが含まれていません".to_string())
+            })
+            .and_then(|user_id| {
+                Id::new(user_id).map_err(|e| {
+                    OidcLoginError::InvalidClaim(format!("IDトークンのユーザーIDが不正です: {e}"))
+                })
+            })?;
+        if self.user_repository.find_by_id(&user_id).await?.is_some() {
+            return Err(OidcLoginError::UserIdAlreadyExists(
+                user_id.value().to_string(),
+            ));
+        }
+
+        // 名前がない場合はユーザーIDを名前とする
+        let name = identity
+            .name()
+            .and_then(|name| UserName::new(name).ok())
+            .unwrap_or_else(|| {
+                UserName::new(user_id.value()).expect("ユーザーIDは名前としても有効なはず")
+            });
+
+        // パスワードでログインできないよう、推測できないパスワードのハッシュ値を設定する
+        let password = rand::rng().random::<[u8; 32]>();
+        let password_hash = Argon2::default()
+            .hash_password(&password, &SaltString::generate(&mut OsRng))
+            .map_err(|e| RepositoryError::Other {
+                message: format!("パスワードのハッシュ化に失敗しました: {e}"),
+            })?
+            .to_string();
+
+        let now = Utc::now();
+        let user = User::create(
+            user_id.clone(),
+            name.clone(),
+            role,
+            password_hash.clone(),
+            SYSTEM_USER_UUID,
+            now,
+        );
+        let user = match self.user_repository.add(&user).await {
+            // 同姓同名のユーザーがいる場合は、名前にユーザーIDを付記する
+            Err(RepositoryError::Conflict { field, .. }) if field == "名前" => {
+                let name = UserName::new(format!("{} ({})", name.value(), user_id.value()))
+                    .expect("空でない名前にユーザーIDを付記した名前は有効なはず");
+                let user = User::create(user_id, name, role, password_hash, SYSTEM_USER_UUID, now);
+                self.user_repository.add(&user).await?
+            }
+            // 同時にログインした別のIdPのユーザーが同じIDのユーザーを作成した場合
+            Err(RepositoryError::Conflict { field, .. }) if field == "ID" => {
+                return Err(OidcLoginError::UserIdAlreadyExists(
+                    user_id.value().to_string(),
+                ));
+            }
+            result => result?,
+        };
+        self.user_identity_repository
+            .add(&UserIdentity::new(
+                identity.issuer(),
+                identity.subject(),
+                *user.uuid(),
+                Utc::now(),
+            ))
+            .await?;
+        info!(
+            "IdPのユーザーに対応するユーザーを作成しました (ユーザーID={}, サブジェクト={}, ロール={})",
+            user.id().value(),
+            identity.subject(),
+            role.as_i16()
+        );
+
+        Ok(user)
+    }
+}
@@ -6,18 +6,20 @@ use crate::internal::application::{
     session::CreateSessionUseCase,
 };
 use crate::internal::domain::repository::UserRepository;
-use crate::internal::domain::value_object::Id;
+use crate::internal::domain::value_object::{Id, LocalLoginPolicy};
 use std::sync::Arc;
 
 pub struct LoginUseCase {
     authenticate_user_use_case: Arc<AuthenticateUserUseCase>,
     create_session_use_case: Arc<CreateSessionUseCase>,
     user_repository: Arc<dyn UserRepository>,
+    local_login_policy: LocalLoginPolicy,
 }
 
 pub struct LoginCommand {
     pub user_id: Id,
     pub password: String,
+    pub user_agent: Option<String>,
 }
 
 impl LoginUseCase {
@@ -25,11 +27,13 @@ impl LoginUseCase {
         authenticate_user_use_case: Arc<AuthenticateUserUseCase>,
         create_session_use_case: Arc<CreateSessionUseCase>,
         user_repository: Arc<dyn UserRepository>,
+        local_login_policy: LocalLoginPolicy,
     ) -> Self {
         Self {
             authenticate_user_use_case,
             create_session_use_case,
             user_repository,
+            local_login_policy,
         }
     }
 
@@ -37,6 +41,12 @@ impl LoginUseCase {
         &self,
         command: LoginCommand,
     ) -> Result<(String, String, i16), AuthenticationError> {
+        // パスワードによるログインを許可したユーザーか確認
+        // ログイン失敗回数を増やさないよう、パスワードの検証より前に確認する
+        if !self.local_login_policy.allows(&command.user_id) {
+            return Err(AuthenticationError::LocalLoginNotAllowed);
+        }
+
         // 認証
         let authenticate_user_command = AuthenticateUserCommand {
             user_id: &command.user_id,
@@ -48,7 +58,13 @@ impl LoginUseCase {
             .await?;
 
         // セッション確立
-        let (session_id, csrf_token) = self.create_session_use_case.execute(user_uuid).await;
+        let (session_id, csrf_token) = self
+            .create_session_use_case
+            .execute(user_uuid, command.user_agent)
+            .await
+            .map_err(|_e| AuthenticationError::Other {
+                message: "リポジトリエラー".to_string(),
+            })?;
 
         // ユーザー情報からロールを取得
         let user = self
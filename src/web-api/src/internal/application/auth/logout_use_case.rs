@@ -1,4 +1,4 @@
-use crate::internal::application::session::DeleteSessionUseCase;
+use crate::internal::{application::session::DeleteSessionUseCase, domain::error::RepositoryError};
 use std::sync::Arc;
 
 pub struct LogoutUseCase {
@@ -12,7 +12,7 @@ impl LogoutUseCase {
         }
     }
 
-    pub async fn execute(&self, session_id: &str) {
-        self.delete_session_use_case.execute(session_id).await;
+    pub async fn execute(&self, session_id: &str) -> Result<(), RepositoryError> {
+        self.delete_session_use_case.execute(session_id).await
     }
 }
@@ -0,0 +1,38 @@
+use crate::internal::{
+    application::auth::OidcLoginError,
+    domain::{
+        entity::OidcAuthorizationRequest,
+        repository::{IdentityProviderRepository, OidcAuthorizationRequestRepository},
+    },
+};
+use std::sync::Arc;
+
+pub struct StartOidcLoginUseCase {
+    identity_provider_repository: Arc<dyn IdentityProviderRepository>,
+    authorization_request_repository: Arc<dyn OidcAuthorizationRequestRepository>,
+}
+
+impl StartOidcLoginUseCase {
+    pub fn new(
+        identity_provider_repository: Arc<dyn IdentityProviderRepository>,
+        authorization_request_repository: Arc<dyn OidcAuthorizationRequestRepository>,
+    ) -> Self {
+        Self {
+            identity_provider_repository,
+            authorization_request_repository,
+        }
+    }
+
+    /// 認可リクエストを作成し、IdPの認可エンドポイントのURLとstateを返す。
+    /// `redirect_to`はログイン後にリダイレクトするパス。
+    pub async fn execute(&self, redirect_to: &str) -> Result<(String, String), OidcLoginError> {
+        let request = OidcAuthorizationRequest::create(redirect_to);
+        let authorization_url = self
+            .identity_provider_repository
+            .authorization_url(&request)
+            .await?;
+        self.authorization_request_repository.save(&request).await?;
+
+        Ok((authorization_url, request.state().to_string()))
+    }
+}
@@ -0,0 +1,5 @@
+mod list_coercion_rules_use_case;
+pub mod replace_coercion_rules_use_case;
+
+pub use list_coercion_rules_use_case::ListCoercionRulesUseCase;
+pub use replace_coercion_rules_use_case::ReplaceCoercionRulesUseCase;
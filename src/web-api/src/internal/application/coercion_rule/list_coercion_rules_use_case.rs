@@ -0,0 +1,25 @@
+use crate::internal::domain::{
+    entity::CoercionRule, error::RepositoryError, repository::CoercionRuleRepository,
+};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+use std::sync::Arc;
+
+pub struct ListCoercionRulesUseCase {
+    repository: Arc<dyn CoercionRuleRepository>,
+}
+
+impl ListCoercionRulesUseCase {
+    pub fn new(repository: Arc<dyn CoercionRuleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, title: &AeValue) -> Result<Vec<CoercionRule>, RepositoryError> {
+        self.repository
+            .find_by_ae_title(title)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound {
+                resource: "AEタイトル".to_string(),
+                key: title.value().to_string(),
+            })
+    }
+}
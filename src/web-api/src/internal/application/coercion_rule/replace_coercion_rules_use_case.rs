@@ -0,0 +1,41 @@
+use crate::internal::domain::{
+    entity::CoercionRule, error::RepositoryError, repository::CoercionRuleRepository,
+};
+use chrono::{DateTime, Utc};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ReplaceCoercionRulesUseCase {
+    repository: Arc<dyn CoercionRuleRepository>,
+}
+
+pub struct ReplaceCoercionRulesCommand {
+    pub title: AeValue,
+    /// 適用順に並べた書き換え規則
+    pub rules: Vec<CoercionRule>,
+    pub updated_by: Uuid,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReplaceCoercionRulesUseCase {
+    pub fn new(repository: Arc<dyn CoercionRuleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: ReplaceCoercionRulesCommand,
+    ) -> Result<Vec<CoercionRule>, RepositoryError> {
+        self.repository
+            .replace(
+                &command.title,
+                &command.rules,
+                &command.updated_by,
+                &command.updated_at,
+            )
+            .await?;
+
+        Ok(command.rules)
+    }
+}
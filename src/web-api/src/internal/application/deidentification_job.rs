@@ -0,0 +1,9 @@
+mod create_deidentification_job_use_case;
+mod get_deidentification_job_use_case;
+
+pub use create_deidentification_job_use_case::{
+    CreateDeidentificationJobCommand, CreateDeidentificationJobUseCase,
+};
+pub use get_deidentification_job_use_case::{
+    GetDeidentificationJobCommand, GetDeidentificationJobUseCase,
+};
@@ -0,0 +1,92 @@
+use crate::internal::domain::{
+    entity::DeidentificationJob,
+    error::RepositoryError,
+    repository::{DeidentificationJobRepository, DicomFileRepository},
+};
+use chrono::{DateTime, Utc};
+use dicom_lib::deidentification::{DeidentificationOptions, Deidentifier};
+use std::sync::Arc;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+pub struct CreateDeidentificationJobUseCase {
+    deidentification_job_repository: Arc<dyn DeidentificationJobRepository>,
+    dicom_file_repository: Arc<dyn DicomFileRepository>,
+}
+
+pub struct CreateDeidentificationJobCommand {
+    pub study_instance_uid: String,
+    pub options: DeidentificationOptions,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CreateDeidentificationJobUseCase {
+    pub fn new(
+        deidentification_job_repository: Arc<dyn DeidentificationJobRepository>,
+        dicom_file_repository: Arc<dyn DicomFileRepository>,
+    ) -> Self {
+        Self {
+            deidentification_job_repository,
+            dicom_file_repository,
+        }
+    }
+
+    /// ジョブを登録し、バックグラウンドでエクスポートを開始する。
+    /// 登録したジョブ（実行中の状態）を返す。
+    pub async fn execute(
+        &self,
+        command: CreateDeidentificationJobCommand,
+    ) -> Result<DeidentificationJob, RepositoryError> {
+        let paths = self
+            .deidentification_job_repository
+            .find_file_paths(&command.study_instance_uid)
+            .await?;
+
+        let job = DeidentificationJob::create(
+            command.study_instance_uid,
+            command.options,
+            command.created_by,
+            command.created_at,
+        );
+        self.deidentification_job_repository.add(&job).await?;
+
+        let deidentification_job_repository = self.deidentification_job_repository.clone();
+        let dicom_file_repository = self.dicom_file_repository.clone();
+        let mut running_job = job.clone();
+        tokio::spawn(async move {
+            // UIDの置き換えを検査内で一貫させるため、検査のすべてのファイルを同じDeidentifierで匿名化する
+            // エクスポートに失敗したファイルがあっても処理を続け、失敗したファイルをジョブに記録する
+            let mut deidentifier = Deidentifier::new(running_job.options());
+            let mut exported_file_count = 0;
+            let mut failed_file_paths = Vec::new();
+            for path in paths {
+                match dicom_file_repository
+                    .export_deidentified(&path, running_job.uuid(), &mut deidentifier)
+                    .await
+                {
+                    Ok(_) => exported_file_count += 1,
+                    Err(e) => {
+                        warn!("匿名化したファイルのエクスポートに失敗しました: {e}");
+                        failed_file_paths.push(path);
+                    }
+                }
+            }
+
+            running_job.finish(exported_file_count, failed_file_paths, Utc::now());
+            match deidentification_job_repository.update(&running_job).await {
+                Ok(()) => info!(
+                    "匿名化エクスポートのジョブが終了しました (UUID={}, ファイル数={})",
+                    running_job.uuid(),
+                    running_job.exported_file_count()
+                ),
+                Err(e) => error!(
+                    "匿名化エクスポートのジョブの更新に失敗しました (UUID={}): {e}",
+                    running_job.uuid()
+                ),
+            }
+        });
+
+        Ok(job)
+    }
+}
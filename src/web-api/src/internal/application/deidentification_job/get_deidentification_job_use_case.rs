@@ -0,0 +1,32 @@
+use crate::internal::domain::{
+    entity::DeidentificationJob, error::RepositoryError, repository::DeidentificationJobRepository,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct GetDeidentificationJobUseCase {
+    repository: Arc<dyn DeidentificationJobRepository>,
+}
+
+pub struct GetDeidentificationJobCommand {
+    pub uuid: Uuid,
+}
+
+impl GetDeidentificationJobUseCase {
+    pub fn new(repository: Arc<dyn DeidentificationJobRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: GetDeidentificationJobCommand,
+    ) -> Result<DeidentificationJob, RepositoryError> {
+        self.repository
+            .find_by_uuid(&command.uuid)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound {
+                resource: "匿名化エクスポートのジョブ".to_string(),
+                key: command.uuid.to_string(),
+            })
+    }
+}
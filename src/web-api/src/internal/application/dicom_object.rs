@@ -0,0 +1,23 @@
+mod convert_sop_instance_file_use_case;
+mod read_sop_instance_data_set_use_case;
+mod read_sop_instance_file_use_case;
+mod retrieve_sop_instance_files_use_case;
+mod search_patients_use_case;
+mod search_result;
+mod search_series_use_case;
+mod search_sop_instances_use_case;
+mod search_studies_use_case;
+mod store_sop_instances_use_case;
+
+pub use convert_sop_instance_file_use_case::ConvertSopInstanceFileUseCase;
+pub use read_sop_instance_data_set_use_case::ReadSopInstanceDataSetUseCase;
+pub use read_sop_instance_file_use_case::ReadSopInstanceFileUseCase;
+pub use retrieve_sop_instance_files_use_case::{
+    RetrieveSopInstanceFilesCommand, RetrieveSopInstanceFilesUseCase,
+};
+pub use search_patients_use_case::{SearchPatientsCommand, SearchPatientsUseCase};
+pub use search_result::SearchResult;
+pub use search_series_use_case::{SearchSeriesCommand, SearchSeriesUseCase};
+pub use search_sop_instances_use_case::{SearchSopInstancesCommand, SearchSopInstancesUseCase};
+pub use search_studies_use_case::{SearchStudiesCommand, SearchStudiesUseCase};
+pub use store_sop_instances_use_case::{StoreSopInstancesCommand, StoreSopInstancesUseCase};
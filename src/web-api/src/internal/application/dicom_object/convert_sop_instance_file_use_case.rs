@@ -0,0 +1,25 @@
+use crate::internal::domain::{
+    entity::SopInstanceFile, error::RepositoryError, repository::DicomFileRepository,
+};
+use std::sync::Arc;
+
+pub struct ConvertSopInstanceFileUseCase {
+    repository: Arc<dyn DicomFileRepository>,
+}
+
+impl ConvertSopInstanceFileUseCase {
+    pub fn new(repository: Arc<dyn DicomFileRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// ファイルを読み込み、指定した転送構文に変換した内容を返す。
+    pub async fn execute(
+        &self,
+        file: &SopInstanceFile,
+        transfer_syntax_uid: &str,
+    ) -> Result<Vec<u8>, RepositoryError> {
+        self.repository
+            .read_with_transfer_syntax(file.path(), transfer_syntax_uid)
+            .await
+    }
+}
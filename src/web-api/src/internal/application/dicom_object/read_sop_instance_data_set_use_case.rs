@@ -0,0 +1,20 @@
+use crate::internal::domain::{
+    entity::SopInstanceFile, error::RepositoryError, repository::DicomFileRepository,
+};
+use dicom_lib::core::DataSet;
+use std::sync::Arc;
+
+pub struct ReadSopInstanceDataSetUseCase {
+    repository: Arc<dyn DicomFileRepository>,
+}
+
+impl ReadSopInstanceDataSetUseCase {
+    pub fn new(repository: Arc<dyn DicomFileRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// ファイルを読み込み、ファイルメタ情報を除いたデータセットを返す。
+    pub async fn execute(&self, file: &SopInstanceFile) -> Result<DataSet, RepositoryError> {
+        self.repository.read_data_set(file.path()).await
+    }
+}
@@ -0,0 +1,40 @@
+use crate::internal::domain::{
+    entity::SopInstanceFile, error::RepositoryError, repository::DicomFileRepository,
+};
+use std::sync::Arc;
+use storage::ByteStream;
+
+pub struct ReadSopInstanceFileUseCase {
+    repository: Arc<dyn DicomFileRepository>,
+}
+
+impl ReadSopInstanceFileUseCase {
+    pub fn new(repository: Arc<dyn DicomFileRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// ファイルの内容を返す。
+    ///
+    /// レスポンスのContent-Lengthは記録されたサイズから算出するため、読み込んだ内容のサイズが一致しない場合はエラーとする。
+    pub async fn execute(&self, file: &SopInstanceFile) -> Result<Vec<u8>, RepositoryError> {
+        let buf = self.repository.read(file.path()).await?;
+        if buf.len() as u64 != file.size() {
+            return Err(RepositoryError::Other {
+                message: format!(
+                    "ファイルのサイズが記録された値と一致しません (パス=\"{}\", 記録された値={}, ファイルの値={})",
+                    file.path(),
+                    file.size(),
+                    buf.len()
+                ),
+            });
+        }
+        Ok(buf)
+    }
+
+    /// ファイルの内容を読み出すストリームを返す。
+    ///
+    /// ファイルの一部のみを返す場合に使用する。ファイル全体を読み込まないため、内容はハッシュ値で検証しない。
+    pub async fn stream(&self, file: &SopInstanceFile) -> Result<ByteStream, RepositoryError> {
+        self.repository.stream(file.path()).await
+    }
+}
@@ -0,0 +1,52 @@
+use crate::internal::domain::{
+    entity::SopInstanceFile, error::RepositoryError, repository::DicomObjectRepository,
+};
+use std::sync::Arc;
+
+pub struct RetrieveSopInstanceFilesUseCase {
+    repository: Arc<dyn DicomObjectRepository>,
+}
+
+pub struct RetrieveSopInstanceFilesCommand {
+    pub study_instance_uid: String,
+    /// 省略した場合は検査に含まれるすべてのシリーズ
+    pub series_instance_uid: Option<String>,
+    /// 省略した場合はシリーズに含まれるすべてのSOPインスタンス
+    pub sop_instance_uid: Option<String>,
+}
+
+impl RetrieveSopInstanceFilesUseCase {
+    pub fn new(repository: Arc<dyn DicomObjectRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// 取得対象のSOPインスタンスのファイルを返す。
+    /// 該当するファイルが1件も存在しない場合は`RepositoryError::NotFound`を返す。
+    pub async fn execute(
+        &self,
+        command: RetrieveSopInstanceFilesCommand,
+    ) -> Result<Vec<SopInstanceFile>, RepositoryError> {
+        let files = self
+            .repository
+            .find_sop_instance_files(
+                &command.study_instance_uid,
+                command.series_instance_uid.as_deref(),
+                command.sop_instance_uid.as_deref(),
+            )
+            .await?;
+
+        if files.is_empty() {
+            let (resource, key) = match (command.series_instance_uid, command.sop_instance_uid) {
+                (_, Some(uid)) => ("SOPインスタンス", uid),
+                (Some(uid), None) => ("シリーズ", uid),
+                (None, None) => ("検査", command.study_instance_uid),
+            };
+            return Err(RepositoryError::NotFound {
+                resource: resource.to_string(),
+                key,
+            });
+        }
+
+        Ok(files)
+    }
+}
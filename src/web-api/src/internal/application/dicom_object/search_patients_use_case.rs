@@ -0,0 +1,39 @@
+use super::SearchResult;
+use crate::internal::domain::{
+    entity::Patient,
+    error::RepositoryError,
+    repository::{DicomObjectRepository, PatientSearchCondition},
+};
+use std::sync::Arc;
+
+pub struct SearchPatientsUseCase {
+    repository: Arc<dyn DicomObjectRepository>,
+}
+
+pub struct SearchPatientsCommand {
+    pub condition: PatientSearchCondition,
+    pub offset: u32,
+    /// 返す件数の上限（`None`または上限を超える場合は`MAX_SEARCH_LIMIT`件）
+    pub limit: Option<u32>,
+}
+
+impl SearchPatientsUseCase {
+    pub fn new(repository: Arc<dyn DicomObjectRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: SearchPatientsCommand,
+    ) -> Result<SearchResult<Patient>, RepositoryError> {
+        let entities = self
+            .repository
+            .search_patients(
+                &command.condition,
+                command.offset,
+                SearchResult::<Patient>::fetch_limit(command.limit),
+            )
+            .await?;
+        Ok(SearchResult::from_fetched(entities, command.limit))
+    }
+}
@@ -0,0 +1,29 @@
+/// 1回の検索で返す件数の上限
+pub const MAX_SEARCH_LIMIT: u32 = 1000;
+
+/// 検索結果
+pub struct SearchResult<T> {
+    pub items: Vec<T>,
+    /// 返した件数より後にも検索条件に一致するデータがあるかどうか
+    pub has_more: bool,
+}
+
+impl<T> SearchResult<T> {
+    /// 検索する件数を決める。
+    /// 後続のデータの有無を判定するため、返す件数より1件多く検索する。
+    pub(super) fn fetch_limit(limit: Option<u32>) -> u32 {
+        Self::effective_limit(limit) + 1
+    }
+
+    /// `fetch_limit`件まで検索した結果から検索結果を作る。
+    pub(super) fn from_fetched(mut items: Vec<T>, limit: Option<u32>) -> Self {
+        let limit = Self::effective_limit(limit) as usize;
+        let has_more = items.len() > limit;
+        items.truncate(limit);
+        Self { items, has_more }
+    }
+
+    fn effective_limit(limit: Option<u32>) -> u32 {
+        limit.map_or(MAX_SEARCH_LIMIT, |limit| limit.min(MAX_SEARCH_LIMIT))
+    }
+}
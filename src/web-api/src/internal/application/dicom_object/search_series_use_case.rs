@@ -0,0 +1,39 @@
+use super::SearchResult;
+use crate::internal::domain::{
+    entity::Series,
+    error::RepositoryError,
+    repository::{DicomObjectRepository, SeriesSearchCondition},
+};
+use std::sync::Arc;
+
+pub struct SearchSeriesUseCase {
+    repository: Arc<dyn DicomObjectRepository>,
+}
+
+pub struct SearchSeriesCommand {
+    pub condition: SeriesSearchCondition,
+    pub offset: u32,
+    /// 返す件数の上限（`None`または上限を超える場合は`MAX_SEARCH_LIMIT`件）
+    pub limit: Option<u32>,
+}
+
+impl SearchSeriesUseCase {
+    pub fn new(repository: Arc<dyn DicomObjectRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: SearchSeriesCommand,
+    ) -> Result<SearchResult<Series>, RepositoryError> {
+        let entities = self
+            .repository
+            .search_series(
+                &command.condition,
+                command.offset,
+                SearchResult::<Series>::fetch_limit(command.limit),
+            )
+            .await?;
+        Ok(SearchResult::from_fetched(entities, command.limit))
+    }
+}
@@ -0,0 +1,39 @@
+use super::SearchResult;
+use crate::internal::domain::{
+    entity::SopInstance,
+    error::RepositoryError,
+    repository::{DicomObjectRepository, SopInstanceSearchCondition},
+};
+use std::sync::Arc;
+
+pub struct SearchSopInstancesUseCase {
+    repository: Arc<dyn DicomObjectRepository>,
+}
+
+pub struct SearchSopInstancesCommand {
+    pub condition: SopInstanceSearchCondition,
+    pub offset: u32,
+    /// 返す件数の上限（`None`または上限を超える場合は`MAX_SEARCH_LIMIT`件）
+    pub limit: Option<u32>,
+}
+
+impl SearchSopInstancesUseCase {
+    pub fn new(repository: Arc<dyn DicomObjectRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: SearchSopInstancesCommand,
+    ) -> Result<SearchResult<SopInstance>, RepositoryError> {
+        let entities = self
+            .repository
+            .search_sop_instances(
+                &command.condition,
+                command.offset,
+                SearchResult::<SopInstance>::fetch_limit(command.limit),
+            )
+            .await?;
+        Ok(SearchResult::from_fetched(entities, command.limit))
+    }
+}
@@ -0,0 +1,39 @@
+use super::SearchResult;
+use crate::internal::domain::{
+    entity::Study,
+    error::RepositoryError,
+    repository::{DicomObjectRepository, StudySearchCondition},
+};
+use std::sync::Arc;
+
+pub struct SearchStudiesUseCase {
+    repository: Arc<dyn DicomObjectRepository>,
+}
+
+pub struct SearchStudiesCommand {
+    pub condition: StudySearchCondition,
+    pub offset: u32,
+    /// 返す件数の上限（`None`または上限を超える場合は`MAX_SEARCH_LIMIT`件）
+    pub limit: Option<u32>,
+}
+
+impl SearchStudiesUseCase {
+    pub fn new(repository: Arc<dyn DicomObjectRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: SearchStudiesCommand,
+    ) -> Result<SearchResult<Study>, RepositoryError> {
+        let entities = self
+            .repository
+            .search_studies(
+                &command.condition,
+                command.offset,
+                SearchResult::<Study>::fetch_limit(command.limit),
+            )
+            .await?;
+        Ok(SearchResult::from_fetched(entities, command.limit))
+    }
+}
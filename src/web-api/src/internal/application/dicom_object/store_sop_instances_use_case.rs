@@ -0,0 +1,52 @@
+use crate::internal::domain::{entity::StoreResult, repository::DicomStoreRepository};
+use std::sync::Arc;
+use tracing::error;
+use uuid::Uuid;
+
+/// 内部エラーにより保存できなかった（Processing failure）
+const PROCESSING_FAILURE: u16 = 0x0110;
+
+pub struct StoreSopInstancesUseCase {
+    repository: Arc<dyn DicomStoreRepository>,
+}
+
+pub struct StoreSopInstancesCommand {
+    /// DICOMファイル（PS3.10形式）の内容
+    pub files: Vec<Vec<u8>>,
+    /// 指定した場合は検査インスタンスUIDが一致するSOPインスタンスのみを保存する
+    pub study_instance_uid: Option<String>,
+    /// 登録者（リクエストしたユーザー）のUUID
+    pub created_by: Uuid,
+}
+
+impl StoreSopInstancesUseCase {
+    pub fn new(repository: Arc<dyn DicomStoreRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// SOPインスタンスを順に保存し、ファイルごとの保存結果を返す。
+    ///
+    /// 一部のファイルの保存中に内部エラーが発生した場合も、残りのファイルの保存を続ける。
+    pub async fn execute(&self, command: StoreSopInstancesCommand) -> Vec<StoreResult> {
+        let mut results = Vec::with_capacity(command.files.len());
+        for buf in command.files {
+            let result = match self
+                .repository
+                .store(
+                    buf,
+                    command.study_instance_uid.as_deref(),
+                    &command.created_by,
+                )
+                .await
+            {
+                Ok(val) => val,
+                Err(e) => {
+                    error!("SOPインスタンスの保存に失敗しました: {e}");
+                    StoreResult::failure("", "", PROCESSING_FAILURE)
+                }
+            };
+            results.push(result);
+        }
+        results
+    }
+}
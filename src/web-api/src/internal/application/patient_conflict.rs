@@ -0,0 +1,10 @@
+mod list_patient_conflicts_use_case;
+mod reconcile_patient_conflict_use_case;
+
+pub use list_patient_conflicts_use_case::{
+    ListPatientConflictsCommand, ListPatientConflictsUseCase,
+};
+pub use reconcile_patient_conflict_use_case::{
+    ReconcileMethod, ReconcilePatientConflictCommand, ReconcilePatientConflictError,
+    ReconcilePatientConflictUseCase,
+};
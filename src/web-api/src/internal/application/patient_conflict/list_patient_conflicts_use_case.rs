@@ -0,0 +1,26 @@
+use crate::internal::domain::{
+    entity::PatientConflict, error::RepositoryError, repository::PatientConflictRepository,
+    value_object::PatientConflictStatus,
+};
+use std::sync::Arc;
+
+pub struct ListPatientConflictsUseCase {
+    repository: Arc<dyn PatientConflictRepository>,
+}
+
+pub struct ListPatientConflictsCommand {
+    pub status: Option<PatientConflictStatus>,
+}
+
+impl ListPatientConflictsUseCase {
+    pub fn new(repository: Arc<dyn PatientConflictRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: ListPatientConflictsCommand,
+    ) -> Result<Vec<PatientConflict>, RepositoryError> {
+        self.repository.find_all(command.status).await
+    }
+}
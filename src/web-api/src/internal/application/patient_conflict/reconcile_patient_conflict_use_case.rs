@@ -0,0 +1,129 @@
+use crate::internal::domain::{
+    entity::PatientReconciliation,
+    error::RepositoryError,
+    repository::{DicomFileRepository, PatientConflictRepository},
+    value_object::{PatientConflictStatus, PatientId, ReconciliationAction},
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
+
+pub struct ReconcilePatientConflictUseCase {
+    patient_conflict_repository: Arc<dyn PatientConflictRepository>,
+    dicom_file_repository: Arc<dyn DicomFileRepository>,
+}
+
+/// 患者属性の不一致の解決方法
+pub enum ReconcileMethod {
+    /// 検査を既存の患者に統合する。
+    /// 統合先を省略した場合は、不一致が記録された患者に統合する（登録済みの患者属性を正とする）。
+    Merge {
+        target_patient_id: Option<PatientId>,
+    },
+    /// 受信した患者属性で新しい患者を登録し、検査をその患者に付け替える。
+    Split { new_patient_id: PatientId },
+}
+
+pub struct ReconcilePatientConflictCommand {
+    pub uuid: Uuid,
+    pub method: ReconcileMethod,
+    /// 保存済みファイルのPatient IDを書き換えるかどうか
+    pub rewrite_files: bool,
+    pub performed_by: Uuid,
+    pub performed_at: DateTime<Utc>,
+}
+
+impl ReconcilePatientConflictUseCase {
+    pub fn new(
+        patient_conflict_repository: Arc<dyn PatientConflictRepository>,
+        dicom_file_repository: Arc<dyn DicomFileRepository>,
+    ) -> Self {
+        Self {
+            patient_conflict_repository,
+            dicom_file_repository,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        command: ReconcilePatientConflictCommand,
+    ) -> Result<PatientReconciliation, ReconcilePatientConflictError> {
+        // 対象の不一致を取得
+        let conflict = self
+            .patient_conflict_repository
+            .find_by_uuid(&command.uuid)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound {
+                resource: "患者属性の不一致".to_string(),
+                key: command.uuid.to_string(),
+            })?;
+        if conflict.status() != PatientConflictStatus::Unresolved {
+            return Err(ReconcilePatientConflictError::AlreadyResolved);
+        }
+
+        let (action, new_patient_id) = match command.method {
+            ReconcileMethod::Merge { target_patient_id } => {
+                let target_patient_id = match target_patient_id {
+                    Some(val) => val,
+                    None => PatientId::new(conflict.patient_id())
+                        .map_err(|message| RepositoryError::Other { message })?,
+                };
+                (ReconciliationAction::Merge, target_patient_id)
+            }
+            ReconcileMethod::Split { new_patient_id } => {
+                (ReconciliationAction::Split, new_patient_id)
+            }
+        };
+
+        // 統合・分割
+        let mut reconciliation = PatientReconciliation::create(
+            *conflict.uuid(),
+            action,
+            conflict.study_instance_uid(),
+            conflict.patient_id(),
+            new_patient_id,
+            command.rewrite_files,
+            command.performed_by,
+            command.performed_at,
+        );
+        let paths = self
+            .patient_conflict_repository
+            .reconcile(&reconciliation)
+            .await?;
+
+        // 保存済みファイルのPatient IDを書き換える
+        // 書き換えに失敗したファイルがあっても統合・分割は取り消さず、失敗したファイルを監査記録に残す
+        if reconciliation.rewrite_files() && reconciliation.changes_patient_id() {
+            let mut rewritten_file_count = 0;
+            let mut failed_file_paths = Vec::new();
+            for path in paths {
+                match self
+                    .dicom_file_repository
+                    .rewrite_patient_id(&path, reconciliation.new_patient_id())
+                    .await
+                {
+                    Ok(()) => rewritten_file_count += 1,
+                    Err(e) => {
+                        warn!("患者IDの書き換えに失敗しました: {e}");
+                        failed_file_paths.push(path);
+                    }
+                }
+            }
+            reconciliation.record_file_rewrite_result(rewritten_file_count, failed_file_paths);
+            self.patient_conflict_repository
+                .update_file_rewrite_result(&reconciliation)
+                .await?;
+        }
+
+        Ok(reconciliation)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReconcilePatientConflictError {
+    #[error("患者属性の不一致は既に解決済みです")]
+    AlreadyResolved,
+    #[error("{0}")]
+    Repository(#[from] RepositoryError),
+}
@@ -0,0 +1,5 @@
+mod list_performed_procedure_steps_use_case;
+
+pub use list_performed_procedure_steps_use_case::{
+    ListPerformedProcedureStepsCommand, ListPerformedProcedureStepsUseCase,
+};
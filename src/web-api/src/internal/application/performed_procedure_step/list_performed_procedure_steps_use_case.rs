@@ -0,0 +1,29 @@
+use crate::internal::domain::{
+    entity::PerformedProcedureStep, error::RepositoryError,
+    repository::PerformedProcedureStepRepository, value_object::ProcedureStepStatus,
+};
+use std::sync::Arc;
+
+pub struct ListPerformedProcedureStepsUseCase {
+    repository: Arc<dyn PerformedProcedureStepRepository>,
+}
+
+pub struct ListPerformedProcedureStepsCommand {
+    pub study_instance_uid: Option<String>,
+    pub status: Option<ProcedureStepStatus>,
+}
+
+impl ListPerformedProcedureStepsUseCase {
+    pub fn new(repository: Arc<dyn PerformedProcedureStepRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: ListPerformedProcedureStepsCommand,
+    ) -> Result<Vec<PerformedProcedureStep>, RepositoryError> {
+        self.repository
+            .find_all(command.study_instance_uid.as_deref(), command.status)
+            .await
+    }
+}
@@ -0,0 +1,11 @@
+pub mod create_retention_rule_use_case;
+pub mod delete_retention_rule_use_case;
+mod list_retention_candidates_use_case;
+mod list_retention_rules_use_case;
+pub mod update_retention_rule_use_case;
+
+pub use create_retention_rule_use_case::CreateRetentionRuleUseCase;
+pub use delete_retention_rule_use_case::DeleteRetentionRuleUseCase;
+pub use list_retention_candidates_use_case::ListRetentionCandidatesUseCase;
+pub use list_retention_rules_use_case::ListRetentionRulesUseCase;
+pub use update_retention_rule_use_case::UpdateRetentionRuleUseCase;
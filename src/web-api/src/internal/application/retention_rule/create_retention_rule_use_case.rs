@@ -0,0 +1,42 @@
+use crate::internal::domain::{
+    entity::{RetentionLimit, RetentionRule},
+    error::RepositoryError,
+    repository::RetentionRuleRepository,
+};
+use chrono::{DateTime, Utc};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct CreateRetentionRuleUseCase {
+    repository: Arc<dyn RetentionRuleRepository>,
+}
+
+pub struct CreateRetentionRuleCommand {
+    pub name: String,
+    pub called_ae_title: Option<AeValue>,
+    pub limit: RetentionLimit,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CreateRetentionRuleUseCase {
+    pub fn new(repository: Arc<dyn RetentionRuleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: CreateRetentionRuleCommand,
+    ) -> Result<RetentionRule, RepositoryError> {
+        let entity = RetentionRule::create(
+            command.name,
+            command.called_ae_title,
+            command.limit,
+            command.created_by,
+            command.created_at,
+        );
+
+        self.repository.add(&entity).await
+    }
+}
@@ -0,0 +1,24 @@
+use crate::internal::domain::{error::RepositoryError, repository::RetentionRuleRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct DeleteRetentionRuleUseCase {
+    repository: Arc<dyn RetentionRuleRepository>,
+}
+
+pub struct DeleteRetentionRuleCommand {
+    pub uuid: Uuid,
+}
+
+impl DeleteRetentionRuleUseCase {
+    pub fn new(repository: Arc<dyn RetentionRuleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: DeleteRetentionRuleCommand,
+    ) -> Result<(), RepositoryError> {
+        self.repository.delete(&command.uuid).await
+    }
+}
@@ -0,0 +1,23 @@
+use crate::internal::domain::{
+    entity::RetentionCandidate, error::RepositoryError, repository::RetentionRuleRepository,
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+/// 保存期間の規則を評価し、削除せずに削除の対象となる検査を返す（dry-run）。
+pub struct ListRetentionCandidatesUseCase {
+    repository: Arc<dyn RetentionRuleRepository>,
+}
+
+impl ListRetentionCandidatesUseCase {
+    pub fn new(repository: Arc<dyn RetentionRuleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        now: &DateTime<Utc>,
+    ) -> Result<Vec<RetentionCandidate>, RepositoryError> {
+        self.repository.find_candidates(now).await
+    }
+}
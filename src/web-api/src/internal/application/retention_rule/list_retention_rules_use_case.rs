@@ -0,0 +1,18 @@
+use crate::internal::domain::{
+    entity::RetentionRule, error::RepositoryError, repository::RetentionRuleRepository,
+};
+use std::sync::Arc;
+
+pub struct ListRetentionRulesUseCase {
+    repository: Arc<dyn RetentionRuleRepository>,
+}
+
+impl ListRetentionRulesUseCase {
+    pub fn new(repository: Arc<dyn RetentionRuleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<RetentionRule>, RepositoryError> {
+        self.repository.find_all().await
+    }
+}
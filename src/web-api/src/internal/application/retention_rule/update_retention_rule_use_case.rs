@@ -0,0 +1,57 @@
+use crate::internal::domain::{
+    entity::{RetentionLimit, RetentionRule},
+    error::RepositoryError,
+    repository::RetentionRuleRepository,
+};
+use chrono::{DateTime, Utc};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct UpdateRetentionRuleUseCase {
+    repository: Arc<dyn RetentionRuleRepository>,
+}
+
+pub struct UpdateRetentionRuleCommand {
+    pub uuid: Uuid,
+
+    pub name: String,
+    pub called_ae_title: Option<AeValue>,
+    pub limit: RetentionLimit,
+    pub updated_by: Uuid,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UpdateRetentionRuleUseCase {
+    pub fn new(repository: Arc<dyn RetentionRuleRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: UpdateRetentionRuleCommand,
+    ) -> Result<RetentionRule, RepositoryError> {
+        // エンティティを取得
+        let mut entity = self
+            .repository
+            .find_by_uuid(&command.uuid)
+            .await?
+            .ok_or_else(|| RepositoryError::NotFound {
+                resource: "保存期間の規則".to_string(),
+                key: command.uuid.to_string(),
+            })?;
+
+        // エンティティを変更し、変更があれば保存
+        let is_changed = entity.update(
+            command.name,
+            command.called_ae_title,
+            command.limit,
+            command.updated_by,
+            command.updated_at,
+        );
+        if !is_changed {
+            return Ok(entity);
+        }
+        self.repository.update(&entity).await
+    }
+}
@@ -0,0 +1,5 @@
+mod list_role_permissions_use_case;
+pub mod update_role_permissions_use_case;
+
+pub use list_role_permissions_use_case::ListRolePermissionsUseCase;
+pub use update_role_permissions_use_case::UpdateRolePermissionsUseCase;
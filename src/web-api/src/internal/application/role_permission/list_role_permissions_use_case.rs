@@ -0,0 +1,18 @@
+use crate::internal::domain::{
+    entity::RolePermissions, error::RepositoryError, repository::RolePermissionRepository,
+};
+use std::sync::Arc;
+
+pub struct ListRolePermissionsUseCase {
+    repository: Arc<dyn RolePermissionRepository>,
+}
+
+impl ListRolePermissionsUseCase {
+    pub fn new(repository: Arc<dyn RolePermissionRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<RolePermissions>, RepositoryError> {
+        self.repository.find_all().await
+    }
+}
@@ -0,0 +1,63 @@
+use crate::internal::domain::{
+    entity::RolePermissions,
+    error::RepositoryError,
+    repository::RolePermissionRepository,
+    value_object::{Permission, Role},
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct UpdateRolePermissionsUseCase {
+    repository: Arc<dyn RolePermissionRepository>,
+}
+
+pub struct UpdateRolePermissionsCommand {
+    pub role_permissions: RolePermissions,
+    pub updated_by: Uuid,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UpdateRolePermissionsUseCase {
+    pub fn new(repository: Arc<dyn RolePermissionRepository>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        command: UpdateRolePermissionsCommand,
+    ) -> Result<RolePermissions, UpdateRolePermissionsError> {
+        // 誰も管理できなくなることを防ぐため、管理者の権限は変更できない
+        if command.role_permissions.role() == Role::Admin {
+            return Err(UpdateRolePermissionsError::Forbidden);
+        }
+
+        // ユーザーの管理の権限があれば管理者の作成や自身のロールへの権限の付与ができるため、
+        // 情シス以外のロールには付与できない
+        if command.role_permissions.role() != Role::ItStaff
+            && command.role_permissions.allows(Permission::ManageUsers)
+        {
+            return Err(UpdateRolePermissionsError::PrivilegedPermission);
+        }
+
+        self.repository
+            .replace(
+                &command.role_permissions,
+                &command.updated_by,
+                &command.updated_at,
+            )
+            .await?;
+
+        Ok(command.role_permissions)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateRolePermissionsError {
+    #[error("{0}")]
+    Repository(#[from] RepositoryError),
+    #[error("管理者の権限は変更できません")]
+    Forbidden,
+    #[error("ユーザーの管理の権限は管理者と情シス以外のロールに付与できません")]
+    PrivilegedPermission,
+}
@@ -1,8 +1,12 @@
 pub mod create_session_use_case;
 pub mod delete_session_use_case;
 pub mod extend_session_use_case;
+pub mod list_sessions_use_case;
+pub mod revoke_session_use_case;
 pub mod validate_csrf_token_use_case;
 
 pub use create_session_use_case::CreateSessionUseCase;
 pub use delete_session_use_case::DeleteSessionUseCase;
 pub use extend_session_use_case::ExtendSessionUseCase;
+pub use list_sessions_use_case::ListSessionsUseCase;
+pub use revoke_session_use_case::RevokeSessionUseCase;
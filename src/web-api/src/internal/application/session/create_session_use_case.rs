@@ -1,4 +1,6 @@
-use crate::internal::domain::{entity::Session, repository::SessionRepository};
+use crate::internal::domain::{
+    entity::Session, error::RepositoryError, repository::SessionRepository,
+};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -12,11 +14,14 @@ impl CreateSessionUseCase {
     }
 
     /// セッションを作成し、セッションIDとCSRFトークンを返す
-    pub async fn execute(&self, user_uuid: Uuid) -> (String, String) {
-        let session = Session::create(user_uuid);
-        let session_id = session.session_id().to_string();
+    pub async fn execute(
+        &self,
+        user_uuid: Uuid,
+        user_agent: Option<String>,
+    ) -> Result<(String, String), RepositoryError> {
+        let (session, session_id) = Session::create(user_uuid, user_agent);
         let csrf_token = session.csrf_token().to_string();
-        self.session_repository.save(session).await;
-        (session_id, csrf_token)
+        self.session_repository.save(session).await?;
+        Ok((session_id, csrf_token))
     }
 }
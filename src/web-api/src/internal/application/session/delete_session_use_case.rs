@@ -1,4 +1,4 @@
-use crate::internal::domain::repository::SessionRepository;
+use crate::internal::domain::{error::RepositoryError, repository::SessionRepository};
 use std::sync::Arc;
 
 pub struct DeleteSessionUseCase {
@@ -11,9 +11,9 @@ impl DeleteSessionUseCase {
     }
 
     /// セッションを削除
-    pub async fn execute(&self, session_id: &str) {
+    pub async fn execute(&self, session_id: &str) -> Result<(), RepositoryError> {
         self.session_repository
             .delete_by_session_id(session_id)
-            .await;
+            .await
     }
 }
@@ -1,10 +1,15 @@
-use crate::internal::domain::repository::SessionRepository;
+use crate::internal::domain::{
+    entity::Session, error::RepositoryError, repository::SessionRepository,
+};
 use std::sync::Arc;
 use uuid::Uuid;
 
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
 pub enum CsrfValidationError {
+    #[error("不正なCSRFトークンです")]
     InvalidToken,
+    #[error("{0}")]
+    Repository(#[from] RepositoryError),
 }
 
 pub struct ExtendSessionUseCase {
@@ -17,15 +22,22 @@ impl ExtendSessionUseCase {
     }
 
     /// セッションの有効期限を延長し、ユーザーUUIDを返す
-    pub async fn execute(&self, session_id: &str) -> Option<Uuid> {
-        // セッションを取得（期限切れは自動削除される）
-        let mut session = (self.session_repository.find_by_session_id(session_id).await)?;
+    pub async fn execute(&self, session_id: &str) -> Result<Option<Uuid>, RepositoryError> {
+        // セッションを取得（期限切れは取得されない）
+        let Some(mut session) = self
+            .session_repository
+            .find_by_session_id(session_id)
+            .await?
+        else {
+            return Ok(None);
+        };
 
-        let user_uuid = *session.user_uuid();
         session.extend();
-        self.session_repository.save(session).await;
+        if !self.extend(&session).await? {
+            return Ok(None);
+        }
 
-        Some(user_uuid)
+        Ok(Some(*session.user_uuid()))
     }
 
     /// セッションの有効期限を延長（CSRF検証付き）し、ユーザーUUIDを返す
@@ -34,8 +46,12 @@ impl ExtendSessionUseCase {
         session_id: &str,
         csrf_token: &str,
     ) -> Result<Option<Uuid>, CsrfValidationError> {
-        // セッションを取得（期限切れは自動削除される）
-        let Some(mut session) = self.session_repository.find_by_session_id(session_id).await else {
+        // セッションを取得（期限切れは取得されない）
+        let Some(mut session) = self
+            .session_repository
+            .find_by_session_id(session_id)
+            .await?
+        else {
             return Ok(None);
         };
 
@@ -44,10 +60,23 @@ impl ExtendSessionUseCase {
             return Err(CsrfValidationError::InvalidToken);
         }
 
-        let user_uuid = *session.user_uuid();
         session.extend();
-        self.session_repository.save(session).await;
+        if !self.extend(&session).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(*session.user_uuid()))
+    }
 
-        Ok(Some(user_uuid))
+    /// 延長したセッションを保存する。
+    /// 取得してから保存するまでの間にセッションが失効させられた場合は`false`を返す。
+    async fn extend(&self, session: &Session) -> Result<bool, RepositoryError> {
+        self.session_repository
+            .extend(
+                session.uuid(),
+                session.last_accessed_at(),
+                session.expires_at(),
+            )
+            .await
     }
 }
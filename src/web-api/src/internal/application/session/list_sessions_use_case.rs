@@ -0,0 +1,20 @@
+use crate::internal::domain::{
+    entity::Session, error::RepositoryError, repository::SessionRepository,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ListSessionsUseCase {
+    session_repository: Arc<dyn SessionRepository>,
+}
+
+impl ListSessionsUseCase {
+    pub fn new(session_repository: Arc<dyn SessionRepository>) -> Self {
+        Self { session_repository }
+    }
+
+    /// ユーザーの有効なセッションの一覧を返す
+    pub async fn execute(&self, user_uuid: &Uuid) -> Result<Vec<Session>, RepositoryError> {
+        self.session_repository.find_by_user_uuid(user_uuid).await
+    }
+}
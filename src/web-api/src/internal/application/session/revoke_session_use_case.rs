@@ -0,0 +1,26 @@
+use crate::internal::domain::{error::RepositoryError, repository::SessionRepository};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RevokeSessionUseCase {
+    session_repository: Arc<dyn SessionRepository>,
+}
+
+pub struct RevokeSessionCommand {
+    /// セッションを失効させるユーザー（自身のセッションのみ失効できる）
+    pub user_uuid: Uuid,
+    pub uuid: Uuid,
+}
+
+impl RevokeSessionUseCase {
+    pub fn new(session_repository: Arc<dyn SessionRepository>) -> Self {
+        Self { session_repository }
+    }
+
+    /// ユーザー自身のセッションを失効させる
+    pub async fn execute(&self, command: RevokeSessionCommand) -> Result<(), RepositoryError> {
+        self.session_repository
+            .delete_by_uuid(&command.user_uuid, &command.uuid)
+            .await
+    }
+}
@@ -18,7 +18,7 @@ impl ValidateCsrfTokenUseCase {
 
     /// CSRFトークンを検証
     pub async fn execute(&self, command: ValidateCsrfTokenCommand) -> bool {
-        if let Some(session) = self
+        if let Ok(Some(session)) = self
             .session_repository
             .find_by_session_id(&command.session_id)
             .await
@@ -1,5 +1,7 @@
 pub mod create_user_use_case;
 pub mod delete_user_use_case;
+pub mod link_user_identity_use_case;
 pub mod list_users_use_case;
 pub mod reset_login_failure_count_use_case;
+pub mod revoke_user_sessions_use_case;
 pub mod update_user_use_case;
@@ -36,10 +36,10 @@ impl CreateUserUseCase {
             return Err(CreateUserError::EmptyPassword);
         }
 
-        // 作成者の権限を確認: 情シスは管理者の作成を行えない
+        // 作成者の権限を確認: 管理者以外は管理者の作成を行えない
         match self.repository.find_by_uuid(&command.created_by).await {
             Ok(Some(actor)) => {
-                if actor.role() == Role::ItStaff && command.role == Role::Admin {
+                if actor.role() != Role::Admin && command.role == Role::Admin {
                     return Err(CreateUserError::Forbidden);
                 }
             }
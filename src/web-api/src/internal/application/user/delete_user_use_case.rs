@@ -50,10 +50,10 @@ impl DeleteUserUseCase {
             return Err(DeleteUserError::CannotDeleteSelf);
         }
 
-        // 削除者の権限を確認: 情シスは管理者を削除できない
+        // 削除者の権限を確認: 管理者以外は管理者を削除できない
         match self.user_repository.find_by_uuid(&command.deleted_by).await {
             Ok(Some(actor)) => {
-                if actor.role() == Role::ItStaff && target_user.role() == Role::Admin {
+                if actor.role() != Role::Admin && target_user.role() == Role::Admin {
                     return Err(DeleteUserError::Forbidden);
                 }
             }
@@ -74,7 +74,7 @@ impl DeleteUserUseCase {
         // 関連セッションを削除
         self.session_repository
             .delete_by_user_uuid(target_user.uuid())
-            .await;
+            .await?;
 
         // ユーザーを削除
         self.user_repository
@@ -0,0 +1,106 @@
+use crate::internal::domain::{
+    entity::UserIdentity,
+    error::{IdentityProviderError, RepositoryError},
+    repository::{IdentityProviderRepository, UserIdentityRepository, UserRepository},
+    value_object::{Id, LocalLoginPolicy, Role},
+};
+use chrono::Utc;
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+pub struct LinkUserIdentityUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    user_identity_repository: Arc<dyn UserIdentityRepository>,
+    identity_provider_repository: Arc<dyn IdentityProviderRepository>,
+    local_login_policy: LocalLoginPolicy,
+}
+
+pub struct LinkUserIdentityCommand {
+    pub target_id: Id,
+    /// IdPのユーザーのサブジェクト（IDトークンの`sub`クレーム）
+    pub subject: String,
+    pub linked_by: Uuid,
+}
+
+impl LinkUserIdentityUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        user_identity_repository: Arc<dyn UserIdentityRepository>,
+        identity_provider_repository: Arc<dyn IdentityProviderRepository>,
+        local_login_policy: LocalLoginPolicy,
+    ) -> Self {
+        Self {
+            user_repository,
+            user_identity_repository,
+            identity_provider_repository,
+            local_login_policy,
+        }
+    }
+
+    /// 既存のユーザーにIdPのユーザーを紐付け、紐付けを返す。
+    /// 紐付けたIdPのユーザーはシングルサインオンでそのユーザーとしてログインする。
+    pub async fn execute(
+        &self,
+        command: LinkUserIdentityCommand,
+    ) -> Result<UserIdentity, LinkUserIdentityError> {
+        // 対象のユーザーを取得
+        let target_user = self
+            .user_repository
+            .find_by_id(&command.target_id)
+            .await?
+            .ok_or_else(|| {
+                LinkUserIdentityError::Repository(RepositoryError::NotFound {
+                    resource: "ユーザー".to_string(),
+                    key: command.target_id.value().to_string(),
+                })
+            })?;
+
+        // 管理者以外は管理者にIdPのユーザーを紐付けられない
+        let actor = self
+            .user_repository
+            .find_by_uuid(&command.linked_by)
+            .await?
+            .expect("ユーザーがログイン済みなので存在するはず");
+        if actor.role() != Role::Admin && target_user.role() == Role::Admin {
+            return Err(LinkUserIdentityError::Forbidden);
+        }
+
+        // 緊急用のアカウントはIdPの障害時にも使用できるよう、IdPのユーザーとは紐付けない
+        if self.local_login_policy.is_reserved(target_user.id()) {
+            return Err(LinkUserIdentityError::ReservedUserId(
+                target_user.id().value().to_string(),
+            ));
+        }
+
+        let subject = command.subject.trim();
+        if subject.is_empty() {
+            return Err(LinkUserIdentityError::InvalidSubject);
+        }
+
+        let issuer = self.identity_provider_repository.issuer().await?;
+        let identity = UserIdentity::new(issuer, subject, *target_user.uuid(), Utc::now());
+        self.user_identity_repository.add(&identity).await?;
+        info!(
+            "IdPのユーザーをユーザーに紐付けました (ユーザーID={}, サブジェクト={})",
+            target_user.id().value(),
+            subject
+        );
+
+        Ok(identity)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LinkUserIdentityError {
+    #[error("{0}")]
+    Repository(#[from] RepositoryError),
+    #[error(transparent)]
+    IdentityProvider(#[from] IdentityProviderError),
+    #[error("権限がありません")]
+    Forbidden,
+    #[error("ユーザーID '{0}' は緊急用のアカウントのため、IdPのユーザーと紐付けできません")]
+    ReservedUserId(String),
+    #[error("サブジェクトを指定してください")]
+    InvalidSubject,
+}
@@ -43,13 +43,13 @@ impl ResetLoginFailureCountUseCase {
                 })
             })?;
 
-        // 情シスは管理者のログイン失敗回数をリセットできない
+        // 管理者以外は管理者のログイン失敗回数をリセットできない
         let actor = self
             .user_repository
             .find_by_uuid(&command.updated_by)
             .await?
             .expect("ユーザーがログイン済みなので存在するはず");
-        if actor.role() == Role::ItStaff && target_user.role() == Role::Admin {
+        if actor.role() != Role::Admin && target_user.role() == Role::Admin {
             return Err(ResetLoginFailureCountError::Forbidden);
         }
 
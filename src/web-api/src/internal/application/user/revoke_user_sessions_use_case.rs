@@ -0,0 +1,72 @@
+use crate::internal::domain::{
+    error::RepositoryError,
+    repository::{SessionRepository, UserRepository},
+    value_object::{Id, Role},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct RevokeUserSessionsUseCase {
+    user_repository: Arc<dyn UserRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+}
+
+pub struct RevokeUserSessionsCommand {
+    pub target_id: Id,
+    pub revoked_by: Uuid,
+}
+
+impl RevokeUserSessionsUseCase {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+    ) -> Self {
+        Self {
+            user_repository,
+            session_repository,
+        }
+    }
+
+    /// ユーザーのすべてのセッションを失効させ、失効させたセッション数を返す
+    pub async fn execute(
+        &self,
+        command: RevokeUserSessionsCommand,
+    ) -> Result<u64, RevokeUserSessionsError> {
+        // 対象のユーザーを取得
+        let target_user = self
+            .user_repository
+            .find_by_id(&command.target_id)
+            .await?
+            .ok_or_else(|| {
+                RevokeUserSessionsError::Repository(RepositoryError::NotFound {
+                    resource: "ユーザー".to_string(),
+                    key: command.target_id.value().to_string(),
+                })
+            })?;
+
+        // 管理者以外は管理者のセッションを失効させられない
+        let actor = self
+            .user_repository
+            .find_by_uuid(&command.revoked_by)
+            .await?
+            .expect("ユーザーがログイン済みなので存在するはず");
+        if actor.role() != Role::Admin && target_user.role() == Role::Admin {
+            return Err(RevokeUserSessionsError::Forbidden);
+        }
+
+        let count = self
+            .session_repository
+            .delete_by_user_uuid(target_user.uuid())
+            .await?;
+
+        Ok(count)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RevokeUserSessionsError {
+    #[error("{0}")]
+    Repository(#[from] RepositoryError),
+    #[error("権限がありません")]
+    Forbidden,
+}
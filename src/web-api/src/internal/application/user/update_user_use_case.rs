@@ -1,7 +1,7 @@
 use crate::internal::domain::{
     entity::User,
     error::RepositoryError,
-    repository::UserRepository,
+    repository::{SessionRepository, UserRepository},
     value_object::{Id, Role, UserName},
 };
 use argon2::{
@@ -14,6 +14,7 @@ use uuid::Uuid;
 
 pub struct UpdateUserUseCase {
     repository: Arc<dyn UserRepository>,
+    session_repository: Arc<dyn SessionRepository>,
 }
 
 pub struct UpdateUserCommand {
@@ -28,8 +29,14 @@ pub struct UpdateUserCommand {
 }
 
 impl UpdateUserUseCase {
-    pub fn new(repository: Arc<dyn UserRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<dyn UserRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+    ) -> Self {
+        Self {
+            repository,
+            session_repository,
+        }
     }
 
     pub async fn execute(&self, command: UpdateUserCommand) -> Result<User, UpdateUserError> {
@@ -47,8 +54,8 @@ impl UpdateUserUseCase {
         // 更新者の権限を確認
         match self.repository.find_by_uuid(&command.updated_by).await {
             Ok(Some(actor)) => {
-                // 情シスは管理者を変更できないし、誰かを管理者に昇格させることもできない
-                if actor.role() == Role::ItStaff
+                // 管理者以外は管理者を変更できないし、誰かを管理者に昇格させることもできない
+                if actor.role() != Role::Admin
                     && (entity.role() == Role::Admin || command.role == Role::Admin)
                 {
                     return Err(UpdateUserError::Forbidden);
@@ -64,6 +71,7 @@ impl UpdateUserUseCase {
         }
 
         // パスワードが指定された場合のみハッシュ化、それ以外は既存のハッシュを維持
+        let is_password_changed = command.password.is_some();
         let password_hash = match command.password {
             Some(password) => {
                 // 空文字列は許可しない
@@ -92,10 +100,20 @@ impl UpdateUserUseCase {
         if !is_changed {
             return Ok(entity);
         }
-        self.repository
+        let entity = self
+            .repository
             .update(&command.old_id, &entity)
             .await
-            .map_err(UpdateUserError::Repository)
+            .map_err(UpdateUserError::Repository)?;
+
+        // パスワードを変更した場合は、ユーザーのすべてのセッションを失効させる
+        if is_password_changed {
+            self.session_repository
+                .delete_by_user_uuid(entity.uuid())
+                .await?;
+        }
+
+        Ok(entity)
     }
 }
 
@@ -0,0 +1,5 @@
+mod issue_wado_uri_token_use_case;
+mod verify_wado_uri_token_use_case;
+
+pub use issue_wado_uri_token_use_case::{IssueWadoUriTokenCommand, IssueWadoUriTokenUseCase};
+pub use verify_wado_uri_token_use_case::VerifyWadoUriTokenUseCase;
@@ -0,0 +1,63 @@
+use crate::internal::domain::{
+    entity::WadoUriToken,
+    error::RepositoryError,
+    repository::{DicomObjectRepository, WadoUriTokenRepository},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct IssueWadoUriTokenUseCase {
+    dicom_object_repository: Arc<dyn DicomObjectRepository>,
+    wado_uri_token_repository: Arc<dyn WadoUriTokenRepository>,
+}
+
+pub struct IssueWadoUriTokenCommand {
+    pub study_instance_uid: String,
+    pub series_instance_uid: String,
+    pub sop_instance_uid: String,
+    pub issued_by: Uuid,
+}
+
+impl IssueWadoUriTokenUseCase {
+    pub fn new(
+        dicom_object_repository: Arc<dyn DicomObjectRepository>,
+        wado_uri_token_repository: Arc<dyn WadoUriTokenRepository>,
+    ) -> Self {
+        Self {
+            dicom_object_repository,
+            wado_uri_token_repository,
+        }
+    }
+
+    /// SOPインスタンスの取得を許可するトークンを発行し、署名した文字列とあわせて返す。
+    /// SOPインスタンスが存在しない場合は`RepositoryError::NotFound`を返す。
+    pub async fn execute(
+        &self,
+        command: IssueWadoUriTokenCommand,
+    ) -> Result<(WadoUriToken, String), RepositoryError> {
+        let files = self
+            .dicom_object_repository
+            .find_sop_instance_files(
+                &command.study_instance_uid,
+                Some(&command.series_instance_uid),
+                Some(&command.sop_instance_uid),
+            )
+            .await?;
+        if files.is_empty() {
+            return Err(RepositoryError::NotFound {
+                resource: "SOPインスタンス".to_string(),
+                key: command.sop_instance_uid,
+            });
+        }
+
+        let token = WadoUriToken::create(
+            command.study_instance_uid,
+            command.series_instance_uid,
+            command.sop_instance_uid,
+            command.issued_by,
+        );
+        let signed_token = self.wado_uri_token_repository.sign(&token);
+
+        Ok((token, signed_token))
+    }
+}
@@ -0,0 +1,22 @@
+use crate::internal::domain::{entity::WadoUriToken, repository::WadoUriTokenRepository};
+use std::sync::Arc;
+
+pub struct VerifyWadoUriTokenUseCase {
+    wado_uri_token_repository: Arc<dyn WadoUriTokenRepository>,
+}
+
+impl VerifyWadoUriTokenUseCase {
+    pub fn new(wado_uri_token_repository: Arc<dyn WadoUriTokenRepository>) -> Self {
+        Self {
+            wado_uri_token_repository,
+        }
+    }
+
+    /// 署名した文字列を検証し、有効期限内のトークンを返す。
+    /// 署名が不正な場合や有効期限切れの場合は`None`を返す。
+    pub fn execute(&self, signed_token: &str) -> Option<WadoUriToken> {
+        self.wado_uri_token_repository
+            .verify(signed_token)
+            .filter(|token| !token.is_expired())
+    }
+}
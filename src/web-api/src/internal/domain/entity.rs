@@ -1,9 +1,53 @@
 mod application_entity;
+mod archived_patient;
+mod archived_series;
+mod archived_sop_instance;
+mod archived_study;
+mod coercion_rule;
+mod deidentification_job;
+mod external_identity;
 mod login_failure_count;
+mod oidc_authorization_request;
+mod patient;
+mod patient_conflict;
+mod patient_reconciliation;
+mod performed_procedure_step;
+mod retention_candidate;
+mod retention_rule;
+mod role_permissions;
+mod series;
 mod session;
+mod sop_instance;
+mod sop_instance_file;
+mod store_result;
+mod study;
 mod user;
+mod user_identity;
+mod wado_uri_token;
 
 pub use application_entity::ApplicationEntity;
+pub use archived_patient::ArchivedPatient;
+pub use archived_series::ArchivedSeries;
+pub use archived_sop_instance::ArchivedSopInstance;
+pub use archived_study::ArchivedStudy;
+pub use coercion_rule::{CoercionOperation, CoercionRule};
+pub use deidentification_job::DeidentificationJob;
+pub use external_identity::ExternalIdentity;
 pub use login_failure_count::LoginFailureCount;
+pub use oidc_authorization_request::OidcAuthorizationRequest;
+pub use patient::Patient;
+pub use patient_conflict::{PatientConflict, PatientDemographics};
+pub use patient_reconciliation::PatientReconciliation;
+pub use performed_procedure_step::{PerformedProcedureStep, PerformedSeries};
+pub use retention_candidate::RetentionCandidate;
+pub use retention_rule::{RetentionLimit, RetentionRule};
+pub use role_permissions::RolePermissions;
+pub use series::Series;
 pub use session::Session;
+pub use sop_instance::SopInstance;
+pub use sop_instance_file::SopInstanceFile;
+pub use store_result::StoreResult;
+pub use study::Study;
 pub use user::User;
+pub use user_identity::UserIdentity;
+pub use wado_uri_token::WadoUriToken;
@@ -0,0 +1,63 @@
+use crate::internal::domain::entity::Patient;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// アーカイブ済みの患者
+///
+/// DICOMサーバーが受信したSOPインスタンスから登録した患者を、アーカイブの閲覧用に登録元・登録日時とともに表す。
+#[derive(Clone)]
+pub struct ArchivedPatient {
+    patient: Patient,
+    /// 患者の検査のうち最も新しい検査日
+    latest_study_date: Option<NaiveDate>,
+    /// 患者を登録したAE（STOW-RSで登録した場合はユーザー）のUUID
+    created_by: Uuid,
+    /// 患者を登録したAEのAEタイトル（AEが見つからない場合は`None`）
+    created_by_ae_title: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ArchivedPatient {
+    pub fn patient(&self) -> &Patient {
+        &self.patient
+    }
+
+    pub fn latest_study_date(&self) -> Option<&NaiveDate> {
+        self.latest_study_date.as_ref()
+    }
+
+    pub fn created_by(&self) -> &Uuid {
+        &self.created_by
+    }
+
+    pub fn created_by_ae_title(&self) -> Option<&str> {
+        self.created_by_ae_title.as_deref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    pub fn construct(
+        patient: Patient,
+        latest_study_date: Option<NaiveDate>,
+        created_by: Uuid,
+        created_by_ae_title: Option<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            patient,
+            latest_study_date,
+            created_by,
+            created_by_ae_title,
+            created_at,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::internal::domain::entity::SopInstance;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// アーカイブ済みのSOPインスタンス
+///
+/// DICOMサーバーが受信して保存したSOPインスタンスを、アーカイブの閲覧用にファイルサイズ・送信元・登録日時とともに表す。
+#[derive(Clone)]
+pub struct ArchivedSopInstance {
+    sop_instance: SopInstance,
+    /// ファイルサイズ（バイト）
+    size: i64,
+    /// SOPインスタンスを受信したAEのAEタイトル
+    called_ae_title: String,
+    version: i32,
+    /// SOPインスタンスを送信したAE（STOW-RSで登録した場合はユーザー）のUUID
+    created_by: Uuid,
+    /// SOPインスタンスを送信したAEのAEタイトル（AEが見つからない場合は`None`）
+    created_by_ae_title: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ArchivedSopInstance {
+    pub fn sop_instance(&self) -> &SopInstance {
+        &self.sop_instance
+    }
+
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    pub fn called_ae_title(&self) -> &str {
+        &self.called_ae_title
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    pub fn created_by(&self) -> &Uuid {
+        &self.created_by
+    }
+
+    pub fn created_by_ae_title(&self) -> Option<&str> {
+        self.created_by_ae_title.as_deref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        sop_instance: SopInstance,
+        size: i64,
+        called_ae_title: impl Into<String>,
+        version: i32,
+        created_by: Uuid,
+        created_by_ae_title: Option<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            sop_instance,
+            size,
+            called_ae_title: called_ae_title.into(),
+            version,
+            created_by,
+            created_by_ae_title,
+            created_at,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use crate::internal::domain::entity::Study;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// アーカイブ済みの検査
+///
+/// DICOMサーバーが受信したSOPインスタンスから登録した検査を、アーカイブの閲覧用にファイルサイズ・登録元・登録日時とともに表す。
+#[derive(Clone)]
+pub struct ArchivedStudy {
+    study: Study,
+    /// 検査に含まれるSOPインスタンスのファイルサイズの合計（バイト）
+    total_size: i64,
+    /// 検査を登録したAE（STOW-RSで登録した場合はユーザー）のUUID
+    created_by: Uuid,
+    /// 検査を登録したAEのAEタイトル（AEが見つからない場合は`None`）
+    created_by_ae_title: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ArchivedStudy {
+    pub fn study(&self) -> &Study {
+        &self.study
+    }
+
+    pub fn total_size(&self) -> i64 {
+        self.total_size
+    }
+
+    pub fn created_by(&self) -> &Uuid {
+        &self.created_by
+    }
+
+    pub fn created_by_ae_title(&self) -> Option<&str> {
+        self.created_by_ae_title.as_deref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    pub fn construct(
+        study: Study,
+        total_size: i64,
+        created_by: Uuid,
+        created_by_ae_title: Option<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            study,
+            total_size,
+            created_by,
+            created_by_ae_title,
+            created_at,
+            updated_at,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use dicom_lib::{core::Tag, dictionaries::tag_dictionary};
+use regex::Regex;
+use std::collections::HashSet;
+
+/// 書き換えの対象にできない属性 (SOP Class UID, SOP Instance UID, Original Attributes Sequence)
+const PROTECTED_TAGS: [Tag; 3] = [
+    Tag(0x0008, 0x0016),
+    Tag(0x0008, 0x0018),
+    Tag(0x0400, 0x0561),
+];
+
+/// 受信時の属性の書き換え規則
+///
+/// DICOMサーバーは、呼出元AEから受信したデータセットに対して登録順に規則を適用する。
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoercionRule {
+    tag: Tag,
+    operation: CoercionOperation,
+}
+
+/// 書き換えの処理
+#[derive(Clone, Debug, PartialEq)]
+pub enum CoercionOperation {
+    /// 値を設定する。属性が存在しない場合は追加する。
+    Set { value: String },
+    /// 他の属性の値を複写する。
+    Copy { source_tag: Tag },
+    /// 属性を削除する。
+    Delete,
+    /// 正規表現に一致する部分を置換する。
+    Replace {
+        pattern: String,
+        replacement: String,
+    },
+    /// 値が一致する場合に対応する値へ置き換える。
+    Map { mappings: Vec<(String, String)> },
+}
+
+impl CoercionRule {
+    pub fn new(tag: Tag, operation: CoercionOperation) -> Result<Self, String> {
+        validate_string_tag(tag)?;
+        if PROTECTED_TAGS.contains(&tag) {
+            return Err(format!("書き換えの対象にできない属性です (タグ={tag})"));
+        }
+
+        match &operation {
+            CoercionOperation::Copy { source_tag } => {
+                validate_string_tag(*source_tag).map_err(|e| format!("複写元の{e}"))?;
+            }
+            CoercionOperation::Replace { pattern, .. } => {
+                Regex::new(pattern)
+                    .map_err(|e| format!("正規表現が不正です (正規表現=\"{pattern}\"): {e}"))?;
+            }
+            CoercionOperation::Map { mappings } => {
+                if mappings.is_empty() {
+                    return Err("値の対応付けが空です".to_string());
+                }
+                let mut sources = HashSet::new();
+                if let Some((source, _)) = mappings.iter().find(|(s, _)| !sources.insert(s)) {
+                    return Err(format!(
+                        "対応付けの元の値が重複しています (値=\"{source}\")"
+                    ));
+                }
+            }
+            CoercionOperation::Set { .. } | CoercionOperation::Delete => {}
+        }
+
+        Ok(Self { tag, operation })
+    }
+
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    pub fn operation(&self) -> &CoercionOperation {
+        &self.operation
+    }
+}
+
+/// 文字列を値として持つ標準の属性であることを検証する。
+fn validate_string_tag(tag: Tag) -> Result<(), String> {
+    match tag_dictionary::search_vr(tag) {
+        Some(vr) if vr.is_string() => Ok(()),
+        Some(vr) => Err(format!(
+            "属性のVRが文字列ではありません (タグ={tag}, VR={})",
+            vr.as_str()
+        )),
+        None => Err(format!(
+            "標準DICOMタグ辞書に存在しない属性です (タグ={tag})"
+        )),
+    }
+}
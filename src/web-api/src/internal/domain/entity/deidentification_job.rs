@@ -0,0 +1,121 @@
+use crate::internal::domain::value_object::DeidentificationJobStatus;
+use chrono::{DateTime, Utc};
+use dicom_lib::deidentification::DeidentificationOptions;
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// 検査を匿名化してエクスポートするジョブ
+#[derive(Clone)]
+pub struct DeidentificationJob {
+    uuid: Uuid,
+    study_instance_uid: String,
+    options: DeidentificationOptions,
+    status: DeidentificationJobStatus,
+    exported_file_count: i32,
+    /// エクスポートに失敗したファイルのパス
+    failed_file_paths: Vec<String>,
+    created_by: Uuid,
+    created_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl DeidentificationJob {
+    pub fn uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn options(&self) -> DeidentificationOptions {
+        self.options
+    }
+
+    pub fn status(&self) -> DeidentificationJobStatus {
+        self.status
+    }
+
+    pub fn exported_file_count(&self) -> i32 {
+        self.exported_file_count
+    }
+
+    pub fn failed_file_paths(&self) -> &[String] {
+        &self.failed_file_paths
+    }
+
+    pub fn created_by(&self) -> &Uuid {
+        &self.created_by
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn finished_at(&self) -> Option<&DateTime<Utc>> {
+        self.finished_at.as_ref()
+    }
+
+    pub fn create(
+        study_instance_uid: impl Into<String>,
+        options: DeidentificationOptions,
+        created_by: Uuid,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        let timestamp = Timestamp::from_unix(NoContext, created_at.timestamp_millis() as u64, 0);
+
+        Self {
+            uuid: Uuid::new_v7(timestamp),
+            study_instance_uid: study_instance_uid.into(),
+            options,
+            status: DeidentificationJobStatus::Running,
+            exported_file_count: 0,
+            failed_file_paths: Vec::new(),
+            created_by,
+            created_at,
+            finished_at: None,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        uuid: Uuid,
+        study_instance_uid: impl Into<String>,
+        options: DeidentificationOptions,
+        status: DeidentificationJobStatus,
+        exported_file_count: i32,
+        failed_file_paths: Vec<String>,
+        created_by: Uuid,
+        created_at: DateTime<Utc>,
+        finished_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            uuid,
+            study_instance_uid: study_instance_uid.into(),
+            options,
+            status,
+            exported_file_count,
+            failed_file_paths,
+            created_by,
+            created_at,
+            finished_at,
+        }
+    }
+
+    /// エクスポートの結果を記録してジョブを終了する。
+    /// 1件もエクスポートできなかった場合は失敗とする。
+    pub fn finish(
+        &mut self,
+        exported_file_count: i32,
+        failed_file_paths: Vec<String>,
+        finished_at: DateTime<Utc>,
+    ) {
+        self.status = if exported_file_count == 0 && !failed_file_paths.is_empty() {
+            DeidentificationJobStatus::Failed
+        } else {
+            DeidentificationJobStatus::Completed
+        };
+        self.exported_file_count = exported_file_count;
+        self.failed_file_paths = failed_file_paths;
+        self.finished_at = Some(finished_at);
+    }
+}
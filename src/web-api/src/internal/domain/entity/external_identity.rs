@@ -0,0 +1,49 @@
+/// IdPが認証したユーザー
+///
+/// 検証済みのIDトークンのクレームから取得する。ユーザーIDと名前のクレームはIdPの設定によって含まれない場合がある。
+#[derive(Debug, Clone)]
+pub struct ExternalIdentity {
+    issuer: String,
+    subject: String,
+    user_id: Option<String>,
+    name: Option<String>,
+    groups: Vec<String>,
+}
+
+impl ExternalIdentity {
+    pub fn construct(
+        issuer: impl Into<String>,
+        subject: impl Into<String>,
+        user_id: Option<String>,
+        name: Option<String>,
+        groups: Vec<String>,
+    ) -> Self {
+        Self {
+            issuer: issuer.into(),
+            subject: subject.into(),
+            user_id,
+            name,
+            groups,
+        }
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_id.as_deref()
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn groups(&self) -> &[String] {
+        &self.groups
+    }
+}
@@ -0,0 +1,94 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64URL};
+use chrono::{DateTime, Duration, Utc};
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// OpenID Connectの認可リクエスト
+///
+/// 認可エンドポイントにリダイレクトしてから認可コードを受け取るまで保持する。
+/// stateはリクエストの偽造対策、nonceはIDトークンの再送対策、code_verifierはPKCE (RFC 7636) に用いる。
+#[derive(Debug, Clone)]
+pub struct OidcAuthorizationRequest {
+    state: String,
+    nonce: String,
+    code_verifier: String,
+    redirect_to: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl OidcAuthorizationRequest {
+    /// 認可リクエストのデフォルト有効期限（分）
+    pub const DEFAULT_EXPIRY_MINUTES: i64 = 10;
+
+    /// state、nonceおよびcode_verifierを生成して認可リクエストを作成する。
+    /// `redirect_to`はログイン後にリダイレクトするパス。
+    pub fn create(redirect_to: impl Into<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            state: Self::generate_random_string(),
+            nonce: Self::generate_random_string(),
+            code_verifier: Self::generate_random_string(),
+            redirect_to: redirect_to.into(),
+            created_at: now,
+            expires_at: now + Duration::minutes(Self::DEFAULT_EXPIRY_MINUTES),
+        }
+    }
+
+    pub fn construct(
+        state: impl Into<String>,
+        nonce: impl Into<String>,
+        code_verifier: impl Into<String>,
+        redirect_to: impl Into<String>,
+        created_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            state: state.into(),
+            nonce: nonce.into(),
+            code_verifier: code_verifier.into(),
+            redirect_to: redirect_to.into(),
+            created_at,
+            expires_at,
+        }
+    }
+
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    pub fn nonce(&self) -> &str {
+        &self.nonce
+    }
+
+    pub fn code_verifier(&self) -> &str {
+        &self.code_verifier
+    }
+
+    pub fn redirect_to(&self) -> &str {
+        &self.redirect_to
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    /// 認可リクエストが有効期限切れかどうかを判定する
+    pub fn is_expired(&self) -> bool {
+        Utc::now() >= self.expires_at
+    }
+
+    /// code_verifierから求めたcode_challenge（`S256`方式）を返す
+    pub fn code_challenge(&self) -> String {
+        BASE64URL.encode(Sha256::digest(self.code_verifier.as_bytes()))
+    }
+
+    /// 32バイトの乱数をパディングなしのBase64URLで表した文字列（43文字）を生成する
+    fn generate_random_string() -> String {
+        BASE64URL.encode(rand::rng().random::<[u8; 32]>())
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+
+/// 患者
+///
+/// DICOMサーバーが受信したSOPインスタンスから登録した患者と、その患者の検査・シリーズ・SOPインスタンスの件数を表す。
+#[derive(Clone)]
+pub struct Patient {
+    id: String,
+    name_alphabet: String,
+    name_kanji: String,
+    name_hiragana: String,
+    birth_date: Option<NaiveDate>,
+    /// ISO/IEC 5218の性別コード（0: 不明, 1: 男性, 2: 女性, 9: 適用不能）
+    sex: i16,
+    number_of_studies: i64,
+    number_of_series: i64,
+    number_of_instances: i64,
+}
+
+impl Patient {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name_alphabet(&self) -> &str {
+        &self.name_alphabet
+    }
+
+    pub fn name_kanji(&self) -> &str {
+        &self.name_kanji
+    }
+
+    pub fn name_hiragana(&self) -> &str {
+        &self.name_hiragana
+    }
+
+    pub fn birth_date(&self) -> Option<&NaiveDate> {
+        self.birth_date.as_ref()
+    }
+
+    pub fn sex(&self) -> i16 {
+        self.sex
+    }
+
+    pub fn number_of_studies(&self) -> i64 {
+        self.number_of_studies
+    }
+
+    pub fn number_of_series(&self) -> i64 {
+        self.number_of_series
+    }
+
+    pub fn number_of_instances(&self) -> i64 {
+        self.number_of_instances
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        id: impl Into<String>,
+        name_alphabet: impl Into<String>,
+        name_kanji: impl Into<String>,
+        name_hiragana: impl Into<String>,
+        birth_date: Option<NaiveDate>,
+        sex: i16,
+        number_of_studies: i64,
+        number_of_series: i64,
+        number_of_instances: i64,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name_alphabet: name_alphabet.into(),
+            name_kanji: name_kanji.into(),
+            name_hiragana: name_hiragana.into(),
+            birth_date,
+            sex,
+            number_of_studies,
+            number_of_series,
+            number_of_instances,
+        }
+    }
+}
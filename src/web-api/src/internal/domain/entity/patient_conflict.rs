@@ -0,0 +1,140 @@
+use crate::internal::domain::value_object::PatientConflictStatus;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// 患者属性の不一致
+///
+/// 登録済みの患者と同じ患者IDで、氏名・生年月日・性別が異なるSOPインスタンスをDICOMサーバーが受信した場合に記録される。
+#[derive(Clone)]
+pub struct PatientConflict {
+    uuid: Uuid,
+    patient_id: String,
+    study_instance_uid: String,
+    sop_instance_uid: String,
+    /// 登録済みの患者属性
+    registered: PatientDemographics,
+    /// 受信したSOPインスタンスの患者属性
+    received: PatientDemographics,
+    status: PatientConflictStatus,
+    resolved_by: Option<Uuid>,
+    resolved_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl PatientConflict {
+    pub fn uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    pub fn patient_id(&self) -> &str {
+        &self.patient_id
+    }
+
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn sop_instance_uid(&self) -> &str {
+        &self.sop_instance_uid
+    }
+
+    pub fn registered(&self) -> &PatientDemographics {
+        &self.registered
+    }
+
+    pub fn received(&self) -> &PatientDemographics {
+        &self.received
+    }
+
+    pub fn status(&self) -> PatientConflictStatus {
+        self.status
+    }
+
+    pub fn resolved_by(&self) -> Option<&Uuid> {
+        self.resolved_by.as_ref()
+    }
+
+    pub fn resolved_at(&self) -> Option<&DateTime<Utc>> {
+        self.resolved_at.as_ref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        uuid: Uuid,
+        patient_id: impl Into<String>,
+        study_instance_uid: impl Into<String>,
+        sop_instance_uid: impl Into<String>,
+        registered: PatientDemographics,
+        received: PatientDemographics,
+        status: PatientConflictStatus,
+        resolved_by: Option<Uuid>,
+        resolved_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            uuid,
+            patient_id: patient_id.into(),
+            study_instance_uid: study_instance_uid.into(),
+            sop_instance_uid: sop_instance_uid.into(),
+            registered,
+            received,
+            status,
+            resolved_by,
+            resolved_at,
+            created_at,
+        }
+    }
+}
+
+/// 患者属性
+#[derive(Clone)]
+pub struct PatientDemographics {
+    name_alphabet: String,
+    name_kanji: String,
+    name_hiragana: String,
+    birth_date: Option<NaiveDate>,
+    /// ISO/IEC 5218の性別コード (0: 不明, 1: 男性, 2: 女性, 9: 適用不能)
+    sex: i16,
+}
+
+impl PatientDemographics {
+    pub fn name_alphabet(&self) -> &str {
+        &self.name_alphabet
+    }
+
+    pub fn name_kanji(&self) -> &str {
+        &self.name_kanji
+    }
+
+    pub fn name_hiragana(&self) -> &str {
+        &self.name_hiragana
+    }
+
+    pub fn birth_date(&self) -> Option<&NaiveDate> {
+        self.birth_date.as_ref()
+    }
+
+    pub fn sex(&self) -> i16 {
+        self.sex
+    }
+
+    pub fn construct(
+        name_alphabet: impl Into<String>,
+        name_kanji: impl Into<String>,
+        name_hiragana: impl Into<String>,
+        birth_date: Option<NaiveDate>,
+        sex: i16,
+    ) -> Self {
+        Self {
+            name_alphabet: name_alphabet.into(),
+            name_kanji: name_kanji.into(),
+            name_hiragana: name_hiragana.into(),
+            birth_date,
+            sex,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::internal::domain::value_object::{PatientId, ReconciliationAction};
+use chrono::{DateTime, Utc};
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// 患者の統合・分割の監査記録
+#[derive(Clone)]
+pub struct PatientReconciliation {
+    uuid: Uuid,
+    patient_conflict_uuid: Uuid,
+    action: ReconciliationAction,
+    study_instance_uid: String,
+    old_patient_id: String,
+    new_patient_id: PatientId,
+    /// 保存済みファイルの患者IDを書き換えるかどうか
+    rewrite_files: bool,
+    rewritten_file_count: i32,
+    /// 書き換えに失敗したファイルのパス
+    failed_file_paths: Vec<String>,
+    performed_by: Uuid,
+    performed_at: DateTime<Utc>,
+}
+
+impl PatientReconciliation {
+    pub fn uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    pub fn patient_conflict_uuid(&self) -> &Uuid {
+        &self.patient_conflict_uuid
+    }
+
+    pub fn action(&self) -> ReconciliationAction {
+        self.action
+    }
+
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn old_patient_id(&self) -> &str {
+        &self.old_patient_id
+    }
+
+    pub fn new_patient_id(&self) -> &PatientId {
+        &self.new_patient_id
+    }
+
+    pub fn rewrite_files(&self) -> bool {
+        self.rewrite_files
+    }
+
+    pub fn rewritten_file_count(&self) -> i32 {
+        self.rewritten_file_count
+    }
+
+    pub fn failed_file_paths(&self) -> &[String] {
+        &self.failed_file_paths
+    }
+
+    pub fn performed_by(&self) -> &Uuid {
+        &self.performed_by
+    }
+
+    pub fn performed_at(&self) -> &DateTime<Utc> {
+        &self.performed_at
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        patient_conflict_uuid: Uuid,
+        action: ReconciliationAction,
+        study_instance_uid: impl Into<String>,
+        old_patient_id: impl Into<String>,
+        new_patient_id: PatientId,
+        rewrite_files: bool,
+        performed_by: Uuid,
+        performed_at: DateTime<Utc>,
+    ) -> Self {
+        let timestamp = Timestamp::from_unix(NoContext, performed_at.timestamp_millis() as u64, 0);
+
+        Self {
+            uuid: Uuid::new_v7(timestamp),
+            patient_conflict_uuid,
+            action,
+            study_instance_uid: study_instance_uid.into(),
+            old_patient_id: old_patient_id.into(),
+            new_patient_id,
+            rewrite_files,
+            rewritten_file_count: 0,
+            failed_file_paths: Vec::new(),
+            performed_by,
+            performed_at,
+        }
+    }
+
+    /// 検査の患者IDが変わるかどうか
+    pub fn changes_patient_id(&self) -> bool {
+        self.old_patient_id != self.new_patient_id.value()
+    }
+
+    /// 保存済みファイルの書き換え結果を記録する。
+    pub fn record_file_rewrite_result(
+        &mut self,
+        rewritten_file_count: i32,
+        failed_file_paths: Vec<String>,
+    ) {
+        self.rewritten_file_count = rewritten_file_count;
+        self.failed_file_paths = failed_file_paths;
+    }
+}
@@ -0,0 +1,171 @@
+use crate::internal::domain::value_object::ProcedureStepStatus;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+/// Modality Performed Procedure Step
+///
+/// モダリティからN-CREATE/N-SETで通知された検査の実施状況を表す。
+#[derive(Clone)]
+pub struct PerformedProcedureStep {
+    instance_uid: String,
+    study_instance_uid: String,
+    patient_id: String,
+    accession_number: String,
+    id: String,
+    status: ProcedureStepStatus,
+    modality: String,
+    description: String,
+    station_ae_title: String,
+    start_date: Option<NaiveDate>,
+    start_time: Option<NaiveTime>,
+    end_date: Option<NaiveDate>,
+    end_time: Option<NaiveTime>,
+    performed_series: Vec<PerformedSeries>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl PerformedProcedureStep {
+    pub fn instance_uid(&self) -> &str {
+        &self.instance_uid
+    }
+
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn patient_id(&self) -> &str {
+        &self.patient_id
+    }
+
+    pub fn accession_number(&self) -> &str {
+        &self.accession_number
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn status(&self) -> ProcedureStepStatus {
+        self.status
+    }
+
+    pub fn modality(&self) -> &str {
+        &self.modality
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn station_ae_title(&self) -> &str {
+        &self.station_ae_title
+    }
+
+    pub fn start_date(&self) -> Option<&NaiveDate> {
+        self.start_date.as_ref()
+    }
+
+    pub fn start_time(&self) -> Option<&NaiveTime> {
+        self.start_time.as_ref()
+    }
+
+    pub fn end_date(&self) -> Option<&NaiveDate> {
+        self.end_date.as_ref()
+    }
+
+    pub fn end_time(&self) -> Option<&NaiveTime> {
+        self.end_time.as_ref()
+    }
+
+    pub fn performed_series(&self) -> &[PerformedSeries] {
+        &self.performed_series
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        instance_uid: impl Into<String>,
+        study_instance_uid: impl Into<String>,
+        patient_id: impl Into<String>,
+        accession_number: impl Into<String>,
+        id: impl Into<String>,
+        status: ProcedureStepStatus,
+        modality: impl Into<String>,
+        description: impl Into<String>,
+        station_ae_title: impl Into<String>,
+        start_date: Option<NaiveDate>,
+        start_time: Option<NaiveTime>,
+        end_date: Option<NaiveDate>,
+        end_time: Option<NaiveTime>,
+        performed_series: Vec<PerformedSeries>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            instance_uid: instance_uid.into(),
+            study_instance_uid: study_instance_uid.into(),
+            patient_id: patient_id.into(),
+            accession_number: accession_number.into(),
+            id: id.into(),
+            status,
+            modality: modality.into(),
+            description: description.into(),
+            station_ae_title: station_ae_title.into(),
+            start_date,
+            start_time,
+            end_date,
+            end_time,
+            performed_series,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+/// Performed Procedure Stepで作成されたシリーズ
+#[derive(Clone)]
+pub struct PerformedSeries {
+    series_instance_uid: String,
+    description: String,
+    retrieve_ae_title: String,
+    number_of_instances: i32,
+}
+
+impl PerformedSeries {
+    pub fn series_instance_uid(&self) -> &str {
+        &self.series_instance_uid
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn retrieve_ae_title(&self) -> &str {
+        &self.retrieve_ae_title
+    }
+
+    pub fn number_of_instances(&self) -> i32 {
+        self.number_of_instances
+    }
+
+    pub fn construct(
+        series_instance_uid: impl Into<String>,
+        description: impl Into<String>,
+        retrieve_ae_title: impl Into<String>,
+        number_of_instances: i32,
+    ) -> Self {
+        Self {
+            series_instance_uid: series_instance_uid.into(),
+            description: description.into(),
+            retrieve_ae_title: retrieve_ae_title.into(),
+            number_of_instances,
+        }
+    }
+}
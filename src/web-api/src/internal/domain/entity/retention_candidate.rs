@@ -0,0 +1,74 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+/// 保存期間の規則により削除の対象となる検査
+#[derive(Clone)]
+pub struct RetentionCandidate {
+    rule_uuid: Uuid,
+    rule_name: String,
+    study_instance_uid: String,
+    patient_id: Option<String>,
+    study_date: Option<NaiveDate>,
+    /// 最後にSOPインスタンスを受信した日時
+    last_received_at: DateTime<Utc>,
+    /// SOPインスタンスの合計サイズ（バイト）
+    size: i64,
+    number_of_instances: i64,
+}
+
+impl RetentionCandidate {
+    pub fn rule_uuid(&self) -> &Uuid {
+        &self.rule_uuid
+    }
+
+    pub fn rule_name(&self) -> &str {
+        &self.rule_name
+    }
+
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn patient_id(&self) -> Option<&str> {
+        self.patient_id.as_deref()
+    }
+
+    pub fn study_date(&self) -> Option<NaiveDate> {
+        self.study_date
+    }
+
+    pub fn last_received_at(&self) -> &DateTime<Utc> {
+        &self.last_received_at
+    }
+
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    pub fn number_of_instances(&self) -> i64 {
+        self.number_of_instances
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        rule_uuid: Uuid,
+        rule_name: impl Into<String>,
+        study_instance_uid: impl Into<String>,
+        patient_id: Option<String>,
+        study_date: Option<NaiveDate>,
+        last_received_at: DateTime<Utc>,
+        size: i64,
+        number_of_instances: i64,
+    ) -> Self {
+        Self {
+            rule_uuid,
+            rule_name: rule_name.into(),
+            study_instance_uid: study_instance_uid.into(),
+            patient_id,
+            study_date,
+            last_received_at,
+            size,
+            number_of_instances,
+        }
+    }
+}
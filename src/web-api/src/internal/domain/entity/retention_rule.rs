@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+use uuid::{NoContext, Timestamp, Uuid};
+
+/// 保存期間の規則の上限
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetentionLimit {
+    /// 最後にSOPインスタンスを受信してから保存する日数
+    MaxAgeDays(i32),
+    /// 対象の検査の合計サイズの上限（バイト）
+    MaxTotalSize(i64),
+}
+
+/// 保存期間の規則
+///
+/// DICOMサーバーは定期的に規則を評価し、対象となった検査を削除する。
+#[derive(Clone)]
+pub struct RetentionRule {
+    uuid: Uuid,
+    name: String,
+    /// 対象の宛先AE（`None`の場合はすべての検査が対象）
+    called_ae_title: Option<AeValue>,
+    limit: RetentionLimit,
+    created_by: Uuid,
+    created_at: DateTime<Utc>,
+    updated_by: Uuid,
+    updated_at: DateTime<Utc>,
+}
+
+impl RetentionRule {
+    pub fn uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn called_ae_title(&self) -> Option<&AeValue> {
+        self.called_ae_title.as_ref()
+    }
+
+    pub fn limit(&self) -> RetentionLimit {
+        self.limit
+    }
+
+    pub fn created_by(&self) -> &Uuid {
+        &self.created_by
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_by(&self) -> &Uuid {
+        &self.updated_by
+    }
+
+    pub fn updated_at(&self) -> &DateTime<Utc> {
+        &self.updated_at
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        uuid: Uuid,
+        name: impl Into<String>,
+        called_ae_title: Option<AeValue>,
+        limit: RetentionLimit,
+        created_by: Uuid,
+        created_at: DateTime<Utc>,
+        updated_by: Uuid,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            uuid,
+            name: name.into(),
+            called_ae_title,
+            limit,
+            created_by,
+            created_at,
+            updated_by,
+            updated_at,
+        }
+    }
+
+    pub fn create(
+        name: impl Into<String>,
+        called_ae_title: Option<AeValue>,
+        limit: RetentionLimit,
+        created_by: Uuid,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        let timestamp = Timestamp::from_unix(NoContext, created_at.timestamp_millis() as u64, 0);
+
+        Self {
+            uuid: Uuid::new_v7(timestamp),
+            name: name.into(),
+            called_ae_title,
+            limit,
+            created_by,
+            created_at,
+            updated_by: created_by,
+            updated_at: created_at,
+        }
+    }
+
+    /// 保存期間の規則を更新する。ただし、変更があった場合のみ更新を行う。
+    ///
+    /// # Returns
+    /// 変更があった場合は`true`、変更がなかった場合は`false`を返す。
+    pub fn update(
+        &mut self,
+        name: impl Into<String>,
+        called_ae_title: Option<AeValue>,
+        limit: RetentionLimit,
+        updated_by: Uuid,
+        updated_at: DateTime<Utc>,
+    ) -> bool {
+        assert!(
+            updated_at >= self.created_at,
+            "`updated_at`は`created_at`よりも前にはできません (created_at={}, updated_at={})",
+            self.created_at,
+            updated_at,
+        );
+
+        // 変更がない場合は何もしない
+        let name = name.into();
+        if name == self.name && called_ae_title == self.called_ae_title && limit == self.limit {
+            return false;
+        }
+
+        self.name = name;
+        self.called_ae_title = called_ae_title;
+        self.limit = limit;
+        self.updated_by = updated_by;
+        self.updated_at = updated_at;
+
+        true
+    }
+}
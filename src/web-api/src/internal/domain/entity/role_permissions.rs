@@ -0,0 +1,30 @@
+use crate::internal::domain::value_object::{Permission, Role};
+
+/// ロールに許可された操作の権限
+#[derive(Debug, Clone)]
+pub struct RolePermissions {
+    role: Role,
+    permissions: Vec<Permission>,
+}
+
+impl RolePermissions {
+    /// 権限は重複を除いて値の順に並べる
+    pub fn construct(role: Role, mut permissions: Vec<Permission>) -> Self {
+        permissions.sort();
+        permissions.dedup();
+        Self { role, permissions }
+    }
+
+    pub fn role(&self) -> Role {
+        self.role
+    }
+
+    pub fn permissions(&self) -> &[Permission] {
+        &self.permissions
+    }
+
+    /// 操作が許可されているかどうかを判定する
+    pub fn allows(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+}
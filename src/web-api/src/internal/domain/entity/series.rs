@@ -0,0 +1,49 @@
+/// シリーズ
+///
+/// DICOMサーバーが受信したSOPインスタンスから登録したシリーズと、そのシリーズのSOPインスタンスの件数を表す。
+#[derive(Clone)]
+pub struct Series {
+    study_instance_uid: String,
+    instance_uid: String,
+    modality: String,
+    series_number: Option<i32>,
+    number_of_instances: i64,
+}
+
+impl Series {
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn instance_uid(&self) -> &str {
+        &self.instance_uid
+    }
+
+    pub fn modality(&self) -> &str {
+        &self.modality
+    }
+
+    pub fn series_number(&self) -> Option<i32> {
+        self.series_number
+    }
+
+    pub fn number_of_instances(&self) -> i64 {
+        self.number_of_instances
+    }
+
+    pub fn construct(
+        study_instance_uid: impl Into<String>,
+        instance_uid: impl Into<String>,
+        modality: impl Into<String>,
+        series_number: Option<i32>,
+        number_of_instances: i64,
+    ) -> Self {
+        Self {
+            study_instance_uid: study_instance_uid.into(),
+            instance_uid: instance_uid.into(),
+            modality: modality.into(),
+            series_number,
+            number_of_instances,
+        }
+    }
+}
@@ -1,12 +1,21 @@
 use chrono::{DateTime, Duration, Utc};
 use rand::Rng;
-use uuid::Uuid;
+use sha2::{Digest, Sha256};
+use uuid::{NoContext, Timestamp, Uuid};
 
+/// ログインセッション
+///
+/// セッションIDはCookieにのみ保持し、リポジトリにはSHA-256ハッシュ値のみを保存する。
+/// セッションの一覧・失効の指定には、セッションIDではなくUUIDを用いる。
 #[derive(Debug, Clone)]
 pub struct Session {
-    session_id: String,
+    uuid: Uuid,
+    session_id_hash: String,
     user_uuid: Uuid,
     csrf_token: String,
+    user_agent: Option<String>,
+    created_at: DateTime<Utc>,
+    last_accessed_at: DateTime<Utc>,
     expires_at: DateTime<Utc>,
 }
 
@@ -14,22 +23,55 @@ impl Session {
     /// セッションのデフォルト有効期限（分）
     pub const DEFAULT_EXPIRY_MINUTES: i64 = 30;
 
-    /// 新規セッションを作成する
-    pub fn create(user_uuid: Uuid) -> Self {
+    /// 新規セッションを作成し、セッションとセッションIDを返す
+    pub fn create(user_uuid: Uuid, user_agent: Option<String>) -> (Self, String) {
         let session_id = Self::generate_session_id();
         let csrf_token = Self::generate_csrf_token();
-        let expires_at = Utc::now() + Duration::minutes(Self::DEFAULT_EXPIRY_MINUTES);
+        let now = Utc::now();
+        let timestamp = Timestamp::from_unix(NoContext, now.timestamp_millis() as u64, 0);
 
-        Self {
-            session_id,
+        let session = Self {
+            uuid: Uuid::new_v7(timestamp),
+            session_id_hash: Self::hash_session_id(&session_id),
             user_uuid,
             csrf_token,
+            user_agent,
+            created_at: now,
+            last_accessed_at: now,
+            expires_at: now + Duration::minutes(Self::DEFAULT_EXPIRY_MINUTES),
+        };
+        (session, session_id)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        uuid: Uuid,
+        session_id_hash: impl Into<String>,
+        user_uuid: Uuid,
+        csrf_token: impl Into<String>,
+        user_agent: Option<String>,
+        created_at: DateTime<Utc>,
+        last_accessed_at: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            uuid,
+            session_id_hash: session_id_hash.into(),
+            user_uuid,
+            csrf_token: csrf_token.into(),
+            user_agent,
+            created_at,
+            last_accessed_at,
             expires_at,
         }
     }
 
-    pub fn session_id(&self) -> &str {
-        &self.session_id
+    pub fn uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    pub fn session_id_hash(&self) -> &str {
+        &self.session_id_hash
     }
 
     pub fn user_uuid(&self) -> &Uuid {
@@ -40,7 +82,18 @@ impl Session {
         &self.csrf_token
     }
 
-    #[allow(dead_code)]
+    pub fn user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn last_accessed_at(&self) -> &DateTime<Utc> {
+        &self.last_accessed_at
+    }
+
     pub fn expires_at(&self) -> &DateTime<Utc> {
         &self.expires_at
     }
@@ -50,6 +103,11 @@ impl Session {
         Utc::now() >= self.expires_at
     }
 
+    /// セッションIDがこのセッションのものかどうかを判定する
+    pub fn matches(&self, session_id: &str) -> bool {
+        self.session_id_hash == Self::hash_session_id(session_id)
+    }
+
     /// CSRFトークンを検証する
     pub fn validate_csrf_token(&self, token: &str) -> bool {
         self.csrf_token == token
@@ -57,7 +115,14 @@ impl Session {
 
     /// セッションの有効期限を延長する
     pub fn extend(&mut self) {
-        self.expires_at = Utc::now() + Duration::minutes(Self::DEFAULT_EXPIRY_MINUTES);
+        let now = Utc::now();
+        self.last_accessed_at = now;
+        self.expires_at = now + Duration::minutes(Self::DEFAULT_EXPIRY_MINUTES);
+    }
+
+    /// セッションIDのSHA-256ハッシュ値（16進数）を返す
+    pub fn hash_session_id(session_id: &str) -> String {
+        Self::base16_encode(&Sha256::digest(session_id.as_bytes()))
     }
 
     fn generate_session_id() -> String {
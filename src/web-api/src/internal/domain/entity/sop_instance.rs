@@ -0,0 +1,49 @@
+/// SOPインスタンス
+///
+/// DICOMサーバーが受信して保存したSOPインスタンスを表す。
+#[derive(Clone)]
+pub struct SopInstance {
+    study_instance_uid: String,
+    series_instance_uid: String,
+    class_uid: String,
+    instance_uid: String,
+    transfer_syntax_uid: String,
+}
+
+impl SopInstance {
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn series_instance_uid(&self) -> &str {
+        &self.series_instance_uid
+    }
+
+    pub fn class_uid(&self) -> &str {
+        &self.class_uid
+    }
+
+    pub fn instance_uid(&self) -> &str {
+        &self.instance_uid
+    }
+
+    pub fn transfer_syntax_uid(&self) -> &str {
+        &self.transfer_syntax_uid
+    }
+
+    pub fn construct(
+        study_instance_uid: impl Into<String>,
+        series_instance_uid: impl Into<String>,
+        class_uid: impl Into<String>,
+        instance_uid: impl Into<String>,
+        transfer_syntax_uid: impl Into<String>,
+    ) -> Self {
+        Self {
+            study_instance_uid: study_instance_uid.into(),
+            series_instance_uid: series_instance_uid.into(),
+            class_uid: class_uid.into(),
+            instance_uid: instance_uid.into(),
+            transfer_syntax_uid: transfer_syntax_uid.into(),
+        }
+    }
+}
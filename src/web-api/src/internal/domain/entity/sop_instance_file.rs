@@ -0,0 +1,57 @@
+/// SOPインスタンスのファイル
+///
+/// DICOMサーバーが保存したSOPインスタンスのファイルと、そのファイルを取得するために必要な情報を表す。
+#[derive(Clone)]
+pub struct SopInstanceFile {
+    study_instance_uid: String,
+    series_instance_uid: String,
+    instance_uid: String,
+    transfer_syntax_uid: String,
+    path: String,
+    size: u64,
+}
+
+impl SopInstanceFile {
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn series_instance_uid(&self) -> &str {
+        &self.series_instance_uid
+    }
+
+    pub fn instance_uid(&self) -> &str {
+        &self.instance_uid
+    }
+
+    pub fn transfer_syntax_uid(&self) -> &str {
+        &self.transfer_syntax_uid
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// ファイルのサイズ（バイト）
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn construct(
+        study_instance_uid: impl Into<String>,
+        series_instance_uid: impl Into<String>,
+        instance_uid: impl Into<String>,
+        transfer_syntax_uid: impl Into<String>,
+        path: impl Into<String>,
+        size: u64,
+    ) -> Self {
+        Self {
+            study_instance_uid: study_instance_uid.into(),
+            series_instance_uid: series_instance_uid.into(),
+            instance_uid: instance_uid.into(),
+            transfer_syntax_uid: transfer_syntax_uid.into(),
+            path: path.into(),
+            size,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+/// SOPインスタンスの保存結果
+///
+/// 保存結果はStorage Service Classのステータス（PS3.4 B.2.3）で表す。
+/// 受け付けたデータからUIDを取得できなかった場合、該当するUIDは空文字列とする。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreResult {
+    sop_class_uid: String,
+    sop_instance_uid: String,
+    study_instance_uid: String,
+    series_instance_uid: String,
+    status: u16,
+}
+
+impl StoreResult {
+    pub fn sop_class_uid(&self) -> &str {
+        &self.sop_class_uid
+    }
+
+    pub fn sop_instance_uid(&self) -> &str {
+        &self.sop_instance_uid
+    }
+
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn series_instance_uid(&self) -> &str {
+        &self.series_instance_uid
+    }
+
+    /// Storage Service Classのステータス
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// 保存に成功したかどうか（警告付きの成功を含む）
+    pub fn is_stored(&self) -> bool {
+        self.status == 0x0000 || self.is_warning()
+    }
+
+    /// 警告付きの成功かどうか
+    pub fn is_warning(&self) -> bool {
+        matches!(self.status, 0x0001 | 0x0107 | 0x0116 | 0xb000..=0xbfff)
+    }
+
+    pub fn construct(
+        sop_class_uid: impl Into<String>,
+        sop_instance_uid: impl Into<String>,
+        study_instance_uid: impl Into<String>,
+        series_instance_uid: impl Into<String>,
+        status: u16,
+    ) -> Self {
+        Self {
+            sop_class_uid: sop_class_uid.into(),
+            sop_instance_uid: sop_instance_uid.into(),
+            study_instance_uid: study_instance_uid.into(),
+            series_instance_uid: series_instance_uid.into(),
+            status,
+        }
+    }
+
+    /// 保存に失敗した結果を生成する。
+    pub fn failure(
+        sop_class_uid: impl Into<String>,
+        sop_instance_uid: impl Into<String>,
+        status: u16,
+    ) -> Self {
+        Self::construct(sop_class_uid, sop_instance_uid, "", "", status)
+    }
+}
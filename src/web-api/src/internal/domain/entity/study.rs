@@ -0,0 +1,126 @@
+use chrono::{NaiveDate, NaiveTime};
+
+/// 検査
+///
+/// DICOMサーバーが受信したSOPインスタンスから登録した検査と、その検査の患者・シリーズ・SOPインスタンスの情報を表す。
+/// 患者が登録されていない検査の場合、患者の属性は空文字列（性別は0）となる。
+#[derive(Clone)]
+pub struct Study {
+    instance_uid: String,
+    id: String,
+    study_date: Option<NaiveDate>,
+    study_time: Option<NaiveTime>,
+    accession_number: String,
+    patient_id: String,
+    patient_name_alphabet: String,
+    patient_name_kanji: String,
+    patient_name_hiragana: String,
+    patient_birth_date: Option<NaiveDate>,
+    /// ISO/IEC 5218の性別コード（0: 不明, 1: 男性, 2: 女性, 9: 適用不能）
+    patient_sex: i16,
+    /// 検査に含まれるシリーズのモダリティ（重複なし）
+    modalities: Vec<String>,
+    /// 検査に含まれるSOPインスタンスのSOPクラスUID（重複なし）
+    sop_class_uids: Vec<String>,
+    number_of_series: i64,
+    number_of_instances: i64,
+}
+
+impl Study {
+    pub fn instance_uid(&self) -> &str {
+        &self.instance_uid
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn study_date(&self) -> Option<&NaiveDate> {
+        self.study_date.as_ref()
+    }
+
+    pub fn study_time(&self) -> Option<&NaiveTime> {
+        self.study_time.as_ref()
+    }
+
+    pub fn accession_number(&self) -> &str {
+        &self.accession_number
+    }
+
+    pub fn patient_id(&self) -> &str {
+        &self.patient_id
+    }
+
+    pub fn patient_name_alphabet(&self) -> &str {
+        &self.patient_name_alphabet
+    }
+
+    pub fn patient_name_kanji(&self) -> &str {
+        &self.patient_name_kanji
+    }
+
+    pub fn patient_name_hiragana(&self) -> &str {
+        &self.patient_name_hiragana
+    }
+
+    pub fn patient_birth_date(&self) -> Option<&NaiveDate> {
+        self.patient_birth_date.as_ref()
+    }
+
+    pub fn patient_sex(&self) -> i16 {
+        self.patient_sex
+    }
+
+    pub fn modalities(&self) -> &[String] {
+        &self.modalities
+    }
+
+    pub fn sop_class_uids(&self) -> &[String] {
+        &self.sop_class_uids
+    }
+
+    pub fn number_of_series(&self) -> i64 {
+        self.number_of_series
+    }
+
+    pub fn number_of_instances(&self) -> i64 {
+        self.number_of_instances
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct(
+        instance_uid: impl Into<String>,
+        id: impl Into<String>,
+        study_date: Option<NaiveDate>,
+        study_time: Option<NaiveTime>,
+        accession_number: impl Into<String>,
+        patient_id: impl Into<String>,
+        patient_name_alphabet: impl Into<String>,
+        patient_name_kanji: impl Into<String>,
+        patient_name_hiragana: impl Into<String>,
+        patient_birth_date: Option<NaiveDate>,
+        patient_sex: i16,
+        modalities: Vec<String>,
+        sop_class_uids: Vec<String>,
+        number_of_series: i64,
+        number_of_instances: i64,
+    ) -> Self {
+        Self {
+            instance_uid: instance_uid.into(),
+            id: id.into(),
+            study_date,
+            study_time,
+            accession_number: accession_number.into(),
+            patient_id: patient_id.into(),
+            patient_name_alphabet: patient_name_alphabet.into(),
+            patient_name_kanji: patient_name_kanji.into(),
+            patient_name_hiragana: patient_name_hiragana.into(),
+            patient_birth_date,
+            patient_sex,
+            modalities,
+            sop_class_uids,
+            number_of_series,
+            number_of_instances,
+        }
+    }
+}
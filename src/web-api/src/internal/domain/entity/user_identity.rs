@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// IdPのユーザーとユーザーの紐付け
+///
+/// IdPのユーザーは、IDトークンの発行者 (iss) とサブジェクト (sub) の組で識別する。
+#[derive(Debug, Clone)]
+pub struct UserIdentity {
+    issuer: String,
+    subject: String,
+    user_uuid: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl UserIdentity {
+    pub fn new(
+        issuer: impl Into<String>,
+        subject: impl Into<String>,
+        user_uuid: Uuid,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            issuer: issuer.into(),
+            subject: subject.into(),
+            user_uuid,
+            created_at,
+        }
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn user_uuid(&self) -> &Uuid {
+        &self.user_uuid
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// WADO-URIの署名付きURLで取得を許可するSOPインスタンスと、その有効期限
+///
+/// 電子カルテ等に埋め込むURLで、ログインせずにSOPインスタンスを取得できるようにするために用いる。
+/// 署名付きURLでの取得は、発行したユーザーによる取得として扱う。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WadoUriToken {
+    study_instance_uid: String,
+    series_instance_uid: String,
+    sop_instance_uid: String,
+    issued_by: Uuid,
+    expires_at: DateTime<Utc>,
+}
+
+impl WadoUriToken {
+    /// 署名付きURLのデフォルト有効期限（分）
+    pub const DEFAULT_EXPIRY_MINUTES: i64 = 5;
+
+    /// 現在時刻から有効期限を設定したトークンを作成する
+    pub fn create(
+        study_instance_uid: impl Into<String>,
+        series_instance_uid: impl Into<String>,
+        sop_instance_uid: impl Into<String>,
+        issued_by: Uuid,
+    ) -> Self {
+        Self::construct(
+            study_instance_uid,
+            series_instance_uid,
+            sop_instance_uid,
+            issued_by,
+            Utc::now() + Duration::minutes(Self::DEFAULT_EXPIRY_MINUTES),
+        )
+    }
+
+    pub fn construct(
+        study_instance_uid: impl Into<String>,
+        series_instance_uid: impl Into<String>,
+        sop_instance_uid: impl Into<String>,
+        issued_by: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            study_instance_uid: study_instance_uid.into(),
+            series_instance_uid: series_instance_uid.into(),
+            sop_instance_uid: sop_instance_uid.into(),
+            issued_by,
+            expires_at,
+        }
+    }
+
+    pub fn study_instance_uid(&self) -> &str {
+        &self.study_instance_uid
+    }
+
+    pub fn series_instance_uid(&self) -> &str {
+        &self.series_instance_uid
+    }
+
+    pub fn sop_instance_uid(&self) -> &str {
+        &self.sop_instance_uid
+    }
+
+    pub fn issued_by(&self) -> &Uuid {
+        &self.issued_by
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+
+    /// トークンが有効期限切れかどうかを判定する
+    pub fn is_expired(&self) -> bool {
+        Utc::now() >= self.expires_at
+    }
+
+    /// 指定したSOPインスタンスの取得を許可するトークンかどうかを判定する
+    pub fn permits(
+        &self,
+        study_instance_uid: &str,
+        series_instance_uid: &str,
+        sop_instance_uid: &str,
+    ) -> bool {
+        self.study_instance_uid == study_instance_uid
+            && self.series_instance_uid == series_instance_uid
+            && self.sop_instance_uid == sop_instance_uid
+    }
+}
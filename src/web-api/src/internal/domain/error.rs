@@ -19,3 +19,13 @@ pub enum RepositoryError {
     #[error("{message}")]
     Other { message: String },
 }
+
+#[derive(Debug, Clone, Error)]
+pub enum IdentityProviderError {
+    /// IdPとの通信に失敗した、またはIdPの応答が不正
+    #[error("IdPとの通信に失敗しました: {message}")]
+    Unavailable { message: String },
+    /// IdPが認可コードを受け付けなかった、またはIDトークンの検証に失敗した
+    #[error("IdPによる認証に失敗しました: {message}")]
+    Unauthenticated { message: String },
+}
@@ -1,9 +1,45 @@
 mod application_entity_repository;
+mod archive_deletion_repository;
+mod archive_repository;
+mod coercion_rule_repository;
+mod deidentification_job_repository;
+mod dicom_file_repository;
+mod dicom_object_repository;
+mod dicom_store_repository;
+mod identity_provider_repository;
 mod login_failure_count_repository;
+mod oidc_authorization_request_repository;
+mod patient_conflict_repository;
+mod performed_procedure_step_repository;
+mod retention_rule_repository;
+mod role_permission_repository;
 mod session_repository;
+mod user_identity_repository;
 mod user_repository;
+mod wado_uri_token_repository;
 
 pub use application_entity_repository::ApplicationEntityRepository;
+pub use archive_deletion_repository::{ArchiveDeletionRepository, ArchiveDeletionTarget};
+pub use archive_repository::{
+    ArchiveRepository, ArchivedPatientFilter, ArchivedPatientSortKey, ArchivedStudyFilter,
+    ArchivedStudySortKey, Page,
+};
+pub use coercion_rule_repository::CoercionRuleRepository;
+pub use deidentification_job_repository::DeidentificationJobRepository;
+pub use dicom_file_repository::DicomFileRepository;
+pub use dicom_object_repository::{
+    DicomObjectRepository, PatientSearchCondition, SeriesSearchCondition,
+    SopInstanceSearchCondition, StudySearchCondition,
+};
+pub use dicom_store_repository::DicomStoreRepository;
+pub use identity_provider_repository::IdentityProviderRepository;
 pub use login_failure_count_repository::LoginFailureCountRepository;
+pub use oidc_authorization_request_repository::OidcAuthorizationRequestRepository;
+pub use patient_conflict_repository::PatientConflictRepository;
+pub use performed_procedure_step_repository::PerformedProcedureStepRepository;
+pub use retention_rule_repository::RetentionRuleRepository;
+pub use role_permission_repository::RolePermissionRepository;
 pub use session_repository::SessionRepository;
+pub use user_identity_repository::UserIdentityRepository;
 pub use user_repository::UserRepository;
+pub use wado_uri_token_repository::WadoUriTokenRepository;
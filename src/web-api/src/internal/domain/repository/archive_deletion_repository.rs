@@ -0,0 +1,34 @@
+use crate::internal::domain::error::RepositoryError;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 削除の対象
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArchiveDeletionTarget {
+    /// 検査（配下のシリーズおよびSOPインスタンスを含む）
+    Study { study_instance_uid: String },
+    /// シリーズ（配下のSOPインスタンスを含む）
+    Series {
+        study_instance_uid: String,
+        series_instance_uid: String,
+    },
+    /// SOPインスタンス
+    SopInstance {
+        study_instance_uid: String,
+        series_instance_uid: String,
+        sop_instance_uid: String,
+    },
+}
+
+#[async_trait::async_trait]
+pub trait ArchiveDeletionRepository: Send + Sync {
+    /// 検査・シリーズ・SOPインスタンスを削除済みテーブルに記録して削除し、ファイルを削除する。
+    /// SOPインスタンスがなくなったシリーズおよびシリーズがなくなった検査も削除する。
+    /// 対象が存在しない場合は`NotFound`を返す。
+    async fn delete(
+        &self,
+        target: &ArchiveDeletionTarget,
+        deleted_by: &Uuid,
+        deleted_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,154 @@
+use crate::internal::domain::{
+    entity::{ArchivedPatient, ArchivedSeries, ArchivedSopInstance, ArchivedStudy},
+    error::RepositoryError,
+    value_object::SortOrder,
+};
+use chrono::NaiveDate;
+
+/// 患者一覧の絞り込み条件
+/// 値が`None`の条件では絞り込みを行わない。
+#[derive(Default)]
+pub struct ArchivedPatientFilter {
+    /// 患者IDの前方一致
+    pub patient_id: Option<String>,
+    /// 氏名（アルファベット・漢字・ひらがなのいずれか）の部分一致
+    pub patient_name: Option<String>,
+}
+
+/// 検査一覧の絞り込み条件
+/// 値が`None`の条件では絞り込みを行わない。
+#[derive(Default)]
+pub struct ArchivedStudyFilter {
+    /// 患者IDの前方一致
+    pub patient_id: Option<String>,
+    /// 氏名（アルファベット・漢字・ひらがなのいずれか）の部分一致
+    pub patient_name: Option<String>,
+    /// 検査日の下限（この日を含む）
+    pub study_date_from: Option<NaiveDate>,
+    /// 検査日の上限（この日を含む）
+    pub study_date_to: Option<NaiveDate>,
+    /// このモダリティのシリーズを含む検査を対象とする
+    pub modality: Option<String>,
+    /// 受付番号の前方一致
+    pub accession_number: Option<String>,
+}
+
+/// 患者一覧の並べ替えの項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchivedPatientSortKey {
+    PatientId,
+    /// 氏名（ひらがな）
+    PatientName,
+    BirthDate,
+    LatestStudyDate,
+}
+
+impl ArchivedPatientSortKey {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "patientId" => Ok(Self::PatientId),
+            "patientName" => Ok(Self::PatientName),
+            "birthDate" => Ok(Self::BirthDate),
+            "latestStudyDate" => Ok(Self::LatestStudyDate),
+            _ => Err(format!("不正な並べ替えの項目です: {value}")),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PatientId => "patientId",
+            Self::PatientName => "patientName",
+            Self::BirthDate => "birthDate",
+            Self::LatestStudyDate => "latestStudyDate",
+        }
+    }
+}
+
+/// 検査一覧の並べ替えの項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchivedStudySortKey {
+    /// 検査日時
+    StudyDate,
+    PatientId,
+    /// 氏名（ひらがな）
+    PatientName,
+    AccessionNumber,
+    /// 登録日時
+    CreatedAt,
+}
+
+impl ArchivedStudySortKey {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "studyDate" => Ok(Self::StudyDate),
+            "patientId" => Ok(Self::PatientId),
+            "patientName" => Ok(Self::PatientName),
+            "accessionNumber" => Ok(Self::AccessionNumber),
+            "createdAt" => Ok(Self::CreatedAt),
+            _ => Err(format!("不正な並べ替えの項目です: {value}")),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::StudyDate => "studyDate",
+            Self::PatientId => "patientId",
+            Self::PatientName => "patientName",
+            Self::AccessionNumber => "accessionNumber",
+            Self::CreatedAt => "createdAt",
+        }
+    }
+}
+
+/// ページ単位の一覧
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// 絞り込み条件に一致する全件数
+    pub total: i64,
+}
+
+/// DICOMサーバーが登録した患者・検査・シリーズ・SOPインスタンスを閲覧するリポジトリ
+///
+/// 登録はDICOMサーバーが行うため、Web APIのリポジトリには登録処理が存在しない。
+/// 一覧の取得では、並べ替えた後に`offset`件を読み飛ばした最大`limit`件を返す。
+/// 並べ替えの項目の値がないデータは末尾とし、値が等しい場合は患者IDまたはインスタンスUIDの昇順とする。
+#[async_trait::async_trait]
+pub trait ArchiveRepository: Send + Sync {
+    /// 患者の一覧を取得する。
+    async fn find_patients(
+        &self,
+        filter: &ArchivedPatientFilter,
+        sort_key: ArchivedPatientSortKey,
+        sort_order: SortOrder,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<ArchivedPatient>, RepositoryError>;
+
+    /// 検査の一覧を取得する。
+    async fn find_studies(
+        &self,
+        filter: &ArchivedStudyFilter,
+        sort_key: ArchivedStudySortKey,
+        sort_order: SortOrder,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<ArchivedStudy>, RepositoryError>;
+
+    /// 検査インスタンスUIDで検査を取得する。
+    async fn find_study(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Option<ArchivedStudy>, RepositoryError>;
+
+    /// 検査に含まれるシリーズをシリーズ番号の昇順で取得する。
+    async fn find_series(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Vec<ArchivedSeries>, RepositoryError>;
+
+    /// シリーズに含まれるSOPインスタンスを登録日時の昇順で取得する。
+    async fn find_sop_instances(
+        &self,
+        series_instance_uid: &str,
+    ) -> Result<Vec<ArchivedSopInstance>, RepositoryError>;
+}
@@ -0,0 +1,23 @@
+use crate::internal::domain::{entity::CoercionRule, error::RepositoryError};
+use chrono::{DateTime, Utc};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait CoercionRuleRepository: Send + Sync {
+    /// 呼出元AEに対する書き換え規則を適用順に取得する。
+    /// AEが存在しない場合は`None`を返す。
+    async fn find_by_ae_title(
+        &self,
+        title: &AeValue,
+    ) -> Result<Option<Vec<CoercionRule>>, RepositoryError>;
+
+    /// 呼出元AEに対する書き換え規則を、指定した規則の並びで置き換える。
+    async fn replace(
+        &self,
+        title: &AeValue,
+        rules: &[CoercionRule],
+        updated_by: &Uuid,
+        updated_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,22 @@
+use crate::internal::domain::{entity::DeidentificationJob, error::RepositoryError};
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait DeidentificationJobRepository: Send + Sync {
+    /// 検査に属するファイル（旧版として保持しているものは含まない）のパスを取得する。
+    /// 検査が存在しない場合は`RepositoryError::NotFound`を返す。
+    async fn find_file_paths(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Vec<String>, RepositoryError>;
+
+    async fn find_by_uuid(
+        &self,
+        uuid: &Uuid,
+    ) -> Result<Option<DeidentificationJob>, RepositoryError>;
+
+    async fn add(&self, job: &DeidentificationJob) -> Result<(), RepositoryError>;
+
+    /// ジョブの状態とエクスポートの結果を更新する。
+    async fn update(&self, job: &DeidentificationJob) -> Result<(), RepositoryError>;
+}
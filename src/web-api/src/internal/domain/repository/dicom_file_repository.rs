@@ -0,0 +1,44 @@
+use crate::internal::domain::{error::RepositoryError, value_object::PatientId};
+use dicom_lib::{core::DataSet, deidentification::Deidentifier};
+use storage::ByteStream;
+use uuid::Uuid;
+
+/// DICOMサーバーが保存したDICOMファイル
+#[async_trait::async_trait]
+pub trait DicomFileRepository: Send + Sync {
+    /// ファイルを読み込み、保存時に記録したハッシュ値で検証した内容を返す。
+    async fn read(&self, path: &str) -> Result<Vec<u8>, RepositoryError>;
+
+    /// ファイルの内容を読み出すストリームを返す。
+    /// ファイルの一部のみを読み出す場合に使用し、内容はハッシュ値で検証しない。
+    async fn stream(&self, path: &str) -> Result<ByteStream, RepositoryError>;
+
+    /// ファイルを読み込み、ファイルメタ情報を除いたデータセットを返す。
+    async fn read_data_set(&self, path: &str) -> Result<DataSet, RepositoryError>;
+
+    /// ファイルを読み込み、データセットを指定した転送構文に変換した内容を返す。
+    /// 変換はImplicit VR Little EndianとExplicit VR Little Endianの間でのみ行える。
+    async fn read_with_transfer_syntax(
+        &self,
+        path: &str,
+        transfer_syntax_uid: &str,
+    ) -> Result<Vec<u8>, RepositoryError>;
+
+    /// ファイルのPatient ID (0010,0020) を書き換える。
+    async fn rewrite_patient_id(
+        &self,
+        path: &str,
+        patient_id: &PatientId,
+    ) -> Result<(), RepositoryError>;
+
+    /// ファイルを匿名化してジョブのエクスポート先に書き出し、書き出したファイルのパスを返す。
+    /// 元のファイルは変更しない。
+    ///
+    /// UIDの置き換えを検査内で一貫させるため、同じジョブのファイルには同じ`Deidentifier`を渡す。
+    async fn export_deidentified(
+        &self,
+        path: &str,
+        job_uuid: &Uuid,
+        deidentifier: &mut Deidentifier,
+    ) -> Result<String, RepositoryError>;
+}
@@ -0,0 +1,99 @@
+use crate::internal::domain::{
+    entity::{Patient, Series, SopInstance, SopInstanceFile, Study},
+    error::RepositoryError,
+    value_object::{DateRange, MatchingPattern, PersonNamePattern},
+};
+
+/// 患者の検索条件
+/// 値が`None`（または空）の条件では絞り込みを行わない。
+#[derive(Default)]
+pub struct PatientSearchCondition {
+    pub patient_id: Option<MatchingPattern>,
+    pub patient_name: Option<PersonNamePattern>,
+    pub patient_birth_date: Option<DateRange>,
+    /// ISO/IEC 5218の性別コード
+    pub patient_sex: Option<i16>,
+}
+
+/// 検査の検索条件
+/// 値が`None`（または空）の条件では絞り込みを行わない。
+#[derive(Default)]
+pub struct StudySearchCondition {
+    /// いずれかに一致する検査インスタンスUID
+    pub study_instance_uids: Vec<String>,
+    pub study_date: Option<DateRange>,
+    pub accession_number: Option<MatchingPattern>,
+    pub study_id: Option<MatchingPattern>,
+    /// いずれかのモダリティのシリーズを含む検査を対象とする
+    pub modalities_in_study: Vec<String>,
+    pub patient: PatientSearchCondition,
+}
+
+/// シリーズの検索条件
+/// 検査インスタンスUID以外は、値が`None`（または空）の条件では絞り込みを行わない。
+pub struct SeriesSearchCondition {
+    pub study_instance_uid: String,
+    /// いずれかに一致するシリーズインスタンスUID
+    pub series_instance_uids: Vec<String>,
+    pub modality: Option<MatchingPattern>,
+    pub series_number: Option<i32>,
+}
+
+/// SOPインスタンスの検索条件
+/// 検査インスタンスUIDおよびシリーズインスタンスUID以外は、値が空の条件では絞り込みを行わない。
+pub struct SopInstanceSearchCondition {
+    pub study_instance_uid: String,
+    pub series_instance_uid: String,
+    /// いずれかに一致するSOPインスタンスUID
+    pub sop_instance_uids: Vec<String>,
+    /// いずれかに一致するSOPクラスUID
+    pub sop_class_uids: Vec<String>,
+}
+
+/// DICOMサーバーが登録した患者・検査・シリーズ・SOPインスタンスを検索するリポジトリ
+///
+/// 登録はDICOMサーバーが行うため、Web APIのリポジトリには登録処理が存在しない。
+/// いずれの検索も`offset`件を読み飛ばした後の最大`limit`件を返す。
+#[async_trait::async_trait]
+pub trait DicomObjectRepository: Send + Sync {
+    /// 患者を患者IDの昇順で検索する。
+    async fn search_patients(
+        &self,
+        condition: &PatientSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Patient>, RepositoryError>;
+
+    /// 検査を検査日時の降順で検索する。
+    async fn search_studies(
+        &self,
+        condition: &StudySearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Study>, RepositoryError>;
+
+    /// 検査に含まれるシリーズをシリーズ番号の昇順で検索する。
+    async fn search_series(
+        &self,
+        condition: &SeriesSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Series>, RepositoryError>;
+
+    /// シリーズに含まれるSOPインスタンスを登録順で検索する。
+    async fn search_sop_instances(
+        &self,
+        condition: &SopInstanceSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SopInstance>, RepositoryError>;
+
+    /// 検査・シリーズ・SOPインスタンスを指定して、保存されたファイルをシリーズ番号・登録順に取得する。
+    /// シリーズまたはSOPインスタンスを省略した場合は、上位の階層に含まれるすべてのファイルを返す。
+    async fn find_sop_instance_files(
+        &self,
+        study_instance_uid: &str,
+        series_instance_uid: Option<&str>,
+        sop_instance_uid: Option<&str>,
+    ) -> Result<Vec<SopInstanceFile>, RepositoryError>;
+}
@@ -0,0 +1,19 @@
+use crate::internal::domain::{entity::StoreResult, error::RepositoryError};
+use uuid::Uuid;
+
+/// Web APIで受け付けたSOPインスタンスの保存先
+///
+/// DICOMサーバーがC-STOREで受信したSOPインスタンスと同じ手順で、ストレージへの保存とDBへの登録を行う。
+#[async_trait::async_trait]
+pub trait DicomStoreRepository: Send + Sync {
+    /// DICOMファイル（PS3.10形式）を保存し、保存結果を返す。
+    ///
+    /// `study_instance_uid`を指定した場合、検査インスタンスUIDが一致しないSOPインスタンスは保存せずに失敗とする。
+    /// 受け付けたデータが原因で保存できない場合は、失敗を表すステータスの保存結果を返す。
+    async fn store(
+        &self,
+        buf: Vec<u8>,
+        study_instance_uid: Option<&str>,
+        created_by: &Uuid,
+    ) -> Result<StoreResult, RepositoryError>;
+}
@@ -0,0 +1,24 @@
+use crate::internal::domain::{
+    entity::{ExternalIdentity, OidcAuthorizationRequest},
+    error::IdentityProviderError,
+};
+
+/// OpenID ConnectのIdP
+#[async_trait::async_trait]
+pub trait IdentityProviderRepository: Send + Sync {
+    /// IdPの発行者（IdPのユーザーとユーザーの紐付けに用いる）
+    async fn issuer(&self) -> Result<String, IdentityProviderError>;
+
+    /// 認可リクエストをIdPの認可エンドポイントに送るためのURLを返す
+    async fn authorization_url(
+        &self,
+        request: &OidcAuthorizationRequest,
+    ) -> Result<String, IdentityProviderError>;
+
+    /// 認可コードをトークンエンドポイントでIDトークンと交換し、検証したIDトークンからIdPのユーザーを返す
+    async fn exchange_code(
+        &self,
+        code: &str,
+        request: &OidcAuthorizationRequest,
+    ) -> Result<ExternalIdentity, IdentityProviderError>;
+}
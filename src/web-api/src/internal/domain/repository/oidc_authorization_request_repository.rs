@@ -0,0 +1,13 @@
+use crate::internal::domain::{entity::OidcAuthorizationRequest, error::RepositoryError};
+
+#[async_trait::async_trait]
+pub trait OidcAuthorizationRequestRepository: Send + Sync {
+    /// 認可リクエストを保存
+    async fn save(&self, request: &OidcAuthorizationRequest) -> Result<(), RepositoryError>;
+
+    /// stateに対応する認可リクエストを取り出し、再び使用できないよう削除する（期限切れの認可リクエストは取得しない）
+    async fn take(&self, state: &str) -> Result<Option<OidcAuthorizationRequest>, RepositoryError>;
+
+    /// 期限切れの認可リクエストを削除し、削除した件数を返す
+    async fn cleanup_expired_requests(&self) -> Result<u64, RepositoryError>;
+}
@@ -0,0 +1,39 @@
+use crate::internal::domain::{
+    entity::{PatientConflict, PatientReconciliation},
+    error::RepositoryError,
+    value_object::PatientConflictStatus,
+};
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait PatientConflictRepository: Send + Sync {
+    /// 患者属性の不一致の一覧を取得する。
+    /// 引数が`Some`の場合は、その値で絞り込みを行う。
+    async fn find_all(
+        &self,
+        status: Option<PatientConflictStatus>,
+    ) -> Result<Vec<PatientConflict>, RepositoryError>;
+
+    async fn find_by_uuid(&self, uuid: &Uuid) -> Result<Option<PatientConflict>, RepositoryError>;
+
+    /// 患者の統合・分割を行う。
+    ///
+    /// 以下を1つのトランザクションで行い、対象の検査に属するファイルのパスを返す。
+    /// - 分割の場合は、受信した患者属性で新しい患者を登録する
+    /// - 検査の患者IDを付け替える
+    /// - 不一致を解決済みにする
+    /// - 監査記録を登録する
+    ///
+    /// 統合先の患者が存在しない場合は`RepositoryError::NotFound`、
+    /// 分割先の患者IDが既に存在する場合は`RepositoryError::Conflict`を返す。
+    async fn reconcile(
+        &self,
+        reconciliation: &PatientReconciliation,
+    ) -> Result<Vec<String>, RepositoryError>;
+
+    /// 監査記録に保存済みファイルの書き換え結果を反映する。
+    async fn update_file_rewrite_result(
+        &self,
+        reconciliation: &PatientReconciliation,
+    ) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,14 @@
+use crate::internal::domain::{
+    entity::PerformedProcedureStep, error::RepositoryError, value_object::ProcedureStepStatus,
+};
+
+#[async_trait::async_trait]
+pub trait PerformedProcedureStepRepository: Send + Sync {
+    /// Performed Procedure Stepの一覧を取得する。
+    /// 引数が`Some`の場合は、その値で絞り込みを行う。
+    async fn find_all(
+        &self,
+        study_instance_uid: Option<&str>,
+        status: Option<ProcedureStepStatus>,
+    ) -> Result<Vec<PerformedProcedureStep>, RepositoryError>;
+}
@@ -0,0 +1,26 @@
+use crate::internal::domain::{
+    entity::{RetentionCandidate, RetentionRule},
+    error::RepositoryError,
+};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait RetentionRuleRepository: Send + Sync {
+    /// 保存期間の規則を登録順に取得する。
+    async fn find_all(&self) -> Result<Vec<RetentionRule>, RepositoryError>;
+
+    async fn find_by_uuid(&self, uuid: &Uuid) -> Result<Option<RetentionRule>, RepositoryError>;
+
+    async fn add(&self, entity: &RetentionRule) -> Result<RetentionRule, RepositoryError>;
+
+    async fn update(&self, entity: &RetentionRule) -> Result<RetentionRule, RepositoryError>;
+
+    async fn delete(&self, uuid: &Uuid) -> Result<(), RepositoryError>;
+
+    /// 保存期間の規則を評価し、`now`の時点で削除の対象となる検査を規則ごとに古い順に取得する。
+    async fn find_candidates(
+        &self,
+        now: &DateTime<Utc>,
+    ) -> Result<Vec<RetentionCandidate>, RepositoryError>;
+}
@@ -0,0 +1,22 @@
+use crate::internal::domain::{
+    entity::RolePermissions, error::RepositoryError, value_object::Role,
+};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait::async_trait]
+pub trait RolePermissionRepository: Send + Sync {
+    /// ロールに許可された権限を取得する（権限がない場合は空）
+    async fn find_by_role(&self, role: Role) -> Result<RolePermissions, RepositoryError>;
+
+    /// すべてのロールの権限をロールの値の順に取得する
+    async fn find_all(&self) -> Result<Vec<RolePermissions>, RepositoryError>;
+
+    /// ロールの権限を、指定した権限で置き換える
+    async fn replace(
+        &self,
+        role_permissions: &RolePermissions,
+        updated_by: &Uuid,
+        updated_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+}
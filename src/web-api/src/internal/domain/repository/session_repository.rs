@@ -1,20 +1,39 @@
-use crate::internal::domain::entity::Session;
+use crate::internal::domain::{entity::Session, error::RepositoryError};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 #[async_trait::async_trait]
 pub trait SessionRepository: Send + Sync {
-    /// セッションを保存（新規作成または更新）
-    async fn save(&self, session: Session);
+    /// セッションを新規作成
+    async fn save(&self, session: Session) -> Result<(), RepositoryError>;
 
-    /// セッションIDからセッション情報を取得
-    async fn find_by_session_id(&self, session_id: &str) -> Option<Session>;
+    /// セッションの最終アクセス日時と有効期限を更新し、更新したかどうかを返す。
+    /// 失効させたセッションを復活させないよう、セッションが削除済みまたは期限切れの場合は更新しない。
+    async fn extend(
+        &self,
+        uuid: &Uuid,
+        last_accessed_at: &DateTime<Utc>,
+        expires_at: &DateTime<Utc>,
+    ) -> Result<bool, RepositoryError>;
+
+    /// セッションIDからセッション情報を取得（期限切れのセッションは取得しない）
+    async fn find_by_session_id(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<Session>, RepositoryError>;
+
+    /// ユーザーの有効なセッションを最後にアクセスした日時の新しい順に取得
+    async fn find_by_user_uuid(&self, user_uuid: &Uuid) -> Result<Vec<Session>, RepositoryError>;
 
     /// セッションIDでセッションを削除
-    async fn delete_by_session_id(&self, session_id: &str);
+    async fn delete_by_session_id(&self, session_id: &str) -> Result<(), RepositoryError>;
+
+    /// ユーザーのセッションをUUIDで削除（ユーザーのセッションでない場合は`NotFound`）
+    async fn delete_by_uuid(&self, user_uuid: &Uuid, uuid: &Uuid) -> Result<(), RepositoryError>;
 
-    /// ユーザーUUIDでセッションを削除
-    async fn delete_by_user_uuid(&self, user_uuid: &Uuid);
+    /// ユーザーUUIDでセッションを削除し、削除した件数を返す
+    async fn delete_by_user_uuid(&self, user_uuid: &Uuid) -> Result<u64, RepositoryError>;
 
-    /// 期限切れセッションを削除（メモリリーク対策）
-    async fn cleanup_expired_sessions(&self);
+    /// 期限切れセッションを削除し、削除した件数を返す
+    async fn cleanup_expired_sessions(&self) -> Result<u64, RepositoryError>;
 }
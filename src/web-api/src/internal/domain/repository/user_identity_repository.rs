@@ -0,0 +1,14 @@
+use crate::internal::domain::{entity::UserIdentity, error::RepositoryError};
+
+#[async_trait::async_trait]
+pub trait UserIdentityRepository: Send + Sync {
+    /// 発行者とサブジェクトからIdPのユーザーの紐付けを取得
+    async fn find_by_subject(
+        &self,
+        issuer: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, RepositoryError>;
+
+    /// IdPのユーザーの紐付けを追加（同じIdPのユーザーが紐付け済みの場合は`Conflict`）
+    async fn add(&self, identity: &UserIdentity) -> Result<(), RepositoryError>;
+}
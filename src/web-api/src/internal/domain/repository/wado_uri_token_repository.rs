@@ -0,0 +1,13 @@
+use crate::internal::domain::entity::WadoUriToken;
+
+/// WADO-URIの署名付きURLのトークン
+///
+/// トークンはサーバーに保存せず、署名によって改ざんを検出する。
+pub trait WadoUriTokenRepository: Send + Sync {
+    /// トークンに署名し、URLに含める文字列を返す。
+    fn sign(&self, token: &WadoUriToken) -> String;
+
+    /// URLに含まれる文字列の署名を検証し、トークンを返す。
+    /// 形式や署名が不正な場合は`None`を返す。有効期限は検証しない。
+    fn verify(&self, signed_token: &str) -> Option<WadoUriToken>;
+}
@@ -1,11 +1,35 @@
+mod date_range;
+mod deidentification_job_status;
 mod host_name;
 mod id;
+mod local_login_policy;
+mod matching_pattern;
+mod patient_conflict_status;
+mod patient_id;
+mod permission;
+mod person_name_pattern;
 mod port;
+mod procedure_step_status;
+mod reconciliation_action;
 mod role;
+mod role_mapping;
+mod sort_order;
 mod user_name;
 
+pub use date_range::DateRange;
+pub use deidentification_job_status::DeidentificationJobStatus;
 pub use host_name::HostName;
 pub use id::Id;
+pub use local_login_policy::LocalLoginPolicy;
+pub use matching_pattern::MatchingPattern;
+pub use patient_conflict_status::PatientConflictStatus;
+pub use patient_id::PatientId;
+pub use permission::Permission;
+pub use person_name_pattern::PersonNamePattern;
 pub use port::Port;
+pub use procedure_step_status::ProcedureStepStatus;
+pub use reconciliation_action::ReconciliationAction;
 pub use role::Role;
+pub use role_mapping::RoleMapping;
+pub use sort_order::SortOrder;
 pub use user_name::UserName;
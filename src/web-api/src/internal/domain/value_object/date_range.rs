@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+
+/// 日付の範囲
+///
+/// DICOMの範囲照合（PS3.4 C.2.2.2.5）で指定された日付の範囲を表す。始端・終端の日付を含む。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+}
+
+impl DateRange {
+    /// DA形式の日付（`"20260201"`）または範囲（`"20260201-20260228"`, `"20260201-"`, `"-20260228"`）から生成する。
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let parse_date = |s: &str| {
+            if s.is_empty() {
+                return Ok(None);
+            }
+            if s.len() != 8 || !s.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(format!(
+                    "日付はYYYYMMDDの形式で指定する必要があります (入力文字列=\"{value}\")"
+                ));
+            }
+            NaiveDate::parse_from_str(s, "%Y%m%d")
+                .map(Some)
+                .map_err(|_| format!("存在しない日付です (入力文字列=\"{value}\")"))
+        };
+
+        let (start, end) = match value.split_once('-') {
+            Some((start, end)) => (parse_date(start)?, parse_date(end)?),
+            None => {
+                let date = parse_date(value)?;
+                (date, date)
+            }
+        };
+
+        match (start, end) {
+            (None, None) => Err(format!(
+                "日付の範囲の始端または終端を指定する必要があります (入力文字列=\"{value}\")"
+            )),
+            (Some(start), Some(end)) if start > end => Err(format!(
+                "日付の範囲の始端が終端より後になっています (入力文字列=\"{value}\")"
+            )),
+            _ => Ok(Self { start, end }),
+        }
+    }
+
+    pub fn start(&self) -> Option<&NaiveDate> {
+        self.start.as_ref()
+    }
+
+    pub fn end(&self) -> Option<&NaiveDate> {
+        self.end.as_ref()
+    }
+}
@@ -0,0 +1,25 @@
+/// 匿名化エクスポートのジョブの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeidentificationJobStatus {
+    /// 実行中
+    Running = 0,
+    /// 完了
+    Completed = 1,
+    /// 失敗
+    Failed = 2,
+}
+
+impl DeidentificationJobStatus {
+    pub fn from_i16(value: i16) -> Result<Self, String> {
+        match value {
+            0 => Ok(Self::Running),
+            1 => Ok(Self::Completed),
+            2 => Ok(Self::Failed),
+            _ => Err(format!("不正なステータスです: {value}")),
+        }
+    }
+
+    pub fn as_i16(&self) -> i16 {
+        *self as i16
+    }
+}
@@ -0,0 +1,30 @@
+use crate::internal::domain::value_object::Id;
+
+/// パスワードによるログイン（ローカルログイン）を許可するユーザー
+///
+/// シングルサインオンを必須とする場合でも、IdPの障害に備えて緊急用のアカウントにはローカルログインを許可できる。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalLoginPolicy {
+    /// 全てのユーザーに許可する
+    AllUsers,
+    /// 指定したユーザーにのみ許可する
+    Only(Vec<Id>),
+}
+
+impl LocalLoginPolicy {
+    /// ユーザーにローカルログインを許可するかどうかを判定する
+    pub fn allows(&self, id: &Id) -> bool {
+        match self {
+            Self::AllUsers => true,
+            Self::Only(ids) => ids.contains(id),
+        }
+    }
+
+    /// 緊急用のアカウントとして、ローカルログインを個別に許可したユーザーかどうかを判定する
+    pub fn is_reserved(&self, id: &Id) -> bool {
+        match self {
+            Self::AllUsers => false,
+            Self::Only(ids) => ids.contains(id),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+/// 属性の照合パターン
+///
+/// DICOMの属性照合（PS3.4 C.2.2.2）における単一値照合およびワイルドカード照合を表す。
+/// `*`は0文字以上の任意の文字列に、`?`は任意の1文字に一致する。
+/// ワイルドカードを含まない場合は値の完全一致となる。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchingPattern(String);
+
+impl MatchingPattern {
+    pub fn new(value: impl Into<String>) -> Result<Self, String> {
+        let value = value.into();
+
+        if value.is_empty() {
+            return Err("照合パターンは空にできません".to_string());
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,25 @@
+/// 患者属性の不一致の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatientConflictStatus {
+    /// 未解決
+    Unresolved = 0,
+    /// 統合済み
+    Merged = 1,
+    /// 分割済み
+    Split = 2,
+}
+
+impl PatientConflictStatus {
+    pub fn from_i16(value: i16) -> Result<Self, String> {
+        match value {
+            0 => Ok(Self::Unresolved),
+            1 => Ok(Self::Merged),
+            2 => Ok(Self::Split),
+            _ => Err(format!("不正なステータスです: {value}")),
+        }
+    }
+
+    pub fn as_i16(&self) -> i16 {
+        *self as i16
+    }
+}
@@ -0,0 +1,42 @@
+/// 患者ID
+///
+/// DICOMのPatient ID (0010,0020) に書き込むため、LOとして扱える値に制限する。
+/// DB上の長さの上限に合わせて16文字以内とし、保存済みファイルの書き換えで文字セットに依存しないよう、
+/// 使用できる文字はASCIIの印字可能文字（`\`を除く）とする。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PatientId(String);
+
+impl PatientId {
+    const MAX_CHAR_COUNT: usize = 16;
+
+    pub fn new(value: impl Into<String>) -> Result<Self, String> {
+        let value = value.into();
+
+        if value.trim_matches(' ').is_empty() {
+            return Err("患者IDは空にできません".to_string());
+        }
+
+        if value != value.trim_matches(' ') {
+            return Err("患者IDの前後に空白を含めることはできません".to_string());
+        }
+
+        if value.chars().count() > Self::MAX_CHAR_COUNT {
+            return Err(format!(
+                "患者IDは{}文字以内である必要があります (入力文字列=\"{value}\")",
+                Self::MAX_CHAR_COUNT
+            ));
+        }
+
+        if !value.chars().all(|c| c.is_ascii_graphic() || c == ' ') || value.contains('\\') {
+            return Err(format!(
+                "患者IDに使用できない文字が含まれています (入力文字列=\"{value}\")"
+            ));
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
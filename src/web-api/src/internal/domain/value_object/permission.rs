@@ -0,0 +1,52 @@
+/// 操作の権限
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Permission {
+    /// 検査の閲覧: アーカイブの閲覧、QIDO-RS、WADO-RSのメタデータ・レンダリング画像・サムネイル、MPPSの参照
+    ViewStudies = 0,
+    /// 検査のダウンロード: WADO-RSのDICOMファイル・フレーム・バルクデータ、WADO-URI
+    DownloadStudies = 1,
+    /// 検査の登録: STOW-RS
+    StoreStudies = 2,
+    /// 検査の削除: アーカイブからの削除、保存期間の規則の管理
+    DeleteStudies = 3,
+    /// AEの管理: AE、受信時の属性の書き換え規則の管理
+    ManageApplicationEntities = 4,
+    /// ユーザーの管理: ユーザー、ロールごとの権限の管理
+    ManageUsers = 5,
+    /// 匿名化エクスポート
+    ExportDeidentifiedData = 6,
+    /// 患者属性の不一致の照合
+    ReconcilePatients = 7,
+}
+
+impl Permission {
+    #[cfg(test)]
+    pub const ALL: [Self; 8] = [
+        Self::ViewStudies,
+        Self::DownloadStudies,
+        Self::StoreStudies,
+        Self::DeleteStudies,
+        Self::ManageApplicationEntities,
+        Self::ManageUsers,
+        Self::ExportDeidentifiedData,
+        Self::ReconcilePatients,
+    ];
+
+    pub fn from_i16(value: i16) -> Result<Self, String> {
+        match value {
+            0 => Ok(Self::ViewStudies),
+            1 => Ok(Self::DownloadStudies),
+            2 => Ok(Self::StoreStudies),
+            3 => Ok(Self::DeleteStudies),
+            4 => Ok(Self::ManageApplicationEntities),
+            5 => Ok(Self::ManageUsers),
+            6 => Ok(Self::ExportDeidentifiedData),
+            7 => Ok(Self::ReconcilePatients),
+            _ => Err(format!("不正な権限です: {value}")),
+        }
+    }
+
+    pub fn as_i16(&self) -> i16 {
+        *self as i16
+    }
+}
@@ -0,0 +1,79 @@
+use super::MatchingPattern;
+
+/// 患者氏名の照合パターン
+///
+/// 氏名はアルファベット・漢字・ひらがなの3つの表記で登録されている。
+/// `"YAMADA^TARO=山田^太郎=やまだ^たろう"`のように`=`で区切って指定した場合は表記ごとに照合し、
+/// 指定したすべての表記が一致する氏名を対象とする。`=`を含まない場合は、いずれかの表記が一致する氏名を対象とする。
+/// 照合は大文字・小文字を区別しない。
+///
+/// あいまい照合（fuzzy matching）では、パターンが氏名のいずれかの構成要素（姓・名など）の先頭に一致すれば対象とする。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonNamePattern {
+    alphabetic: Option<MatchingPattern>,
+    ideographic: Option<MatchingPattern>,
+    phonetic: Option<MatchingPattern>,
+    any_representation: bool,
+    fuzzy: bool,
+}
+
+impl PersonNamePattern {
+    pub fn new(value: &str, fuzzy: bool) -> Result<Self, String> {
+        let groups = value.split('=').collect::<Vec<_>>();
+        if groups.len() > 3 {
+            return Err(format!(
+                "氏名の表記は3つまでしか指定できません (入力文字列=\"{value}\")"
+            ));
+        }
+
+        let pattern = |s: &&str| {
+            (!s.is_empty())
+                .then(|| MatchingPattern::new(*s))
+                .transpose()
+        };
+        let (alphabetic, ideographic, phonetic, any_representation) = if groups.len() == 1 {
+            let p = pattern(&groups[0])?;
+            (p.clone(), p.clone(), p, true)
+        } else {
+            (
+                groups.first().map(pattern).transpose()?.flatten(),
+                groups.get(1).map(pattern).transpose()?.flatten(),
+                groups.get(2).map(pattern).transpose()?.flatten(),
+                false,
+            )
+        };
+
+        if alphabetic.is_none() && ideographic.is_none() && phonetic.is_none() {
+            return Err("氏名の照合パターンは空にできません".to_string());
+        }
+
+        Ok(Self {
+            alphabetic,
+            ideographic,
+            phonetic,
+            any_representation,
+            fuzzy,
+        })
+    }
+
+    pub fn alphabetic(&self) -> Option<&MatchingPattern> {
+        self.alphabetic.as_ref()
+    }
+
+    pub fn ideographic(&self) -> Option<&MatchingPattern> {
+        self.ideographic.as_ref()
+    }
+
+    pub fn phonetic(&self) -> Option<&MatchingPattern> {
+        self.phonetic.as_ref()
+    }
+
+    /// いずれかの表記が一致すればよいかどうか（`false`の場合は指定したすべての表記が一致する必要がある）
+    pub fn is_any_representation(&self) -> bool {
+        self.any_representation
+    }
+
+    pub fn is_fuzzy(&self) -> bool {
+        self.fuzzy
+    }
+}
@@ -0,0 +1,25 @@
+/// Performed Procedure Stepのステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcedureStepStatus {
+    /// 実施中
+    InProgress = 0,
+    /// 完了
+    Completed = 1,
+    /// 中止
+    Discontinued = 2,
+}
+
+impl ProcedureStepStatus {
+    pub fn from_i16(value: i16) -> Result<Self, String> {
+        match value {
+            0 => Ok(Self::InProgress),
+            1 => Ok(Self::Completed),
+            2 => Ok(Self::Discontinued),
+            _ => Err(format!("不正なステータスです: {value}")),
+        }
+    }
+
+    pub fn as_i16(&self) -> i16 {
+        *self as i16
+    }
+}
@@ -0,0 +1,24 @@
+use crate::internal::domain::value_object::PatientConflictStatus;
+
+/// 患者属性の不一致に対して行った照合の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconciliationAction {
+    /// 検査を既存の患者に統合する
+    Merge = 1,
+    /// 検査を新しい患者として分割する
+    Split = 2,
+}
+
+impl ReconciliationAction {
+    pub fn as_i16(&self) -> i16 {
+        *self as i16
+    }
+
+    /// 照合後の不一致の状態
+    pub fn resolved_status(&self) -> PatientConflictStatus {
+        match self {
+            Self::Merge => PatientConflictStatus::Merged,
+            Self::Split => PatientConflictStatus::Split,
+        }
+    }
+}
@@ -13,6 +13,14 @@ pub enum Role {
 }
 
 impl Role {
+    pub const ALL: [Self; 5] = [
+        Self::Admin,
+        Self::ItStaff,
+        Self::Doctor,
+        Self::Technician,
+        Self::Clerk,
+    ];
+
     pub fn from_i16(value: i16) -> Result<Self, String> {
         match value {
             0 => Ok(Self::Admin),
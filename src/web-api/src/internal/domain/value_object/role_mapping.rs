@@ -0,0 +1,56 @@
+use crate::internal::domain::value_object::Role;
+
+/// IdPのグループからロールへの対応付け
+///
+/// IdPのユーザーが複数のグループに所属し、複数のロールに対応する場合は値の小さい（権限の強い）ロールを割り当てる。
+/// いずれのグループにも対応しない場合は既定のロールを割り当て、既定のロールがない場合はロールを割り当てない。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoleMapping {
+    mappings: Vec<(String, Role)>,
+    default_role: Option<Role>,
+}
+
+impl RoleMapping {
+    pub fn new(mappings: Vec<(String, Role)>, default_role: Option<Role>) -> Self {
+        Self {
+            mappings,
+            default_role,
+        }
+    }
+
+    /// `グループ=ロールの値`をカンマ区切りで連結した文字列から作成する（例: `pacs-admins=0,radiologists=2`）
+    pub fn parse(value: &str, default_role: Option<Role>) -> Result<Self, String> {
+        let mappings = value
+            .split(',')
+            .map(str::trim)
+            .filter(|mapping| !mapping.is_empty())
+            .map(|mapping| {
+                let (group, role) = mapping.rsplit_once('=').ok_or_else(|| {
+                    format!("グループとロールの対応付けの形式が不正です: {mapping}")
+                })?;
+                let group = group.trim();
+                if group.is_empty() {
+                    return Err(format!("グループが空です: {mapping}"));
+                }
+                let role = role
+                    .trim()
+                    .parse::<i16>()
+                    .map_err(|_| format!("不正なロールです: {mapping}"))
+                    .and_then(Role::from_i16)?;
+                Ok((group.to_string(), role))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self::new(mappings, default_role))
+    }
+
+    /// IdPのユーザーが所属するグループから、割り当てるロールを返す
+    pub fn resolve(&self, groups: &[String]) -> Option<Role> {
+        self.mappings
+            .iter()
+            .filter(|(group, _)| groups.contains(group))
+            .map(|(_, role)| *role)
+            .min_by_key(Role::as_i16)
+            .or(self.default_role)
+    }
+}
@@ -0,0 +1,22 @@
+/// 一覧の並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// 昇順
+    Ascending,
+    /// 降順
+    Descending,
+}
+
+impl SortOrder {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "asc" => Ok(Self::Ascending),
+            "desc" => Ok(Self::Descending),
+            _ => Err(format!("不正な並び順です: {value}")),
+        }
+    }
+
+    pub fn is_ascending(&self) -> bool {
+        *self == Self::Ascending
+    }
+}
@@ -1,17 +1,62 @@
 mod application_entity_repository;
+mod archive_deletion_repository;
+mod archive_repository;
+mod coercion_rule_repository;
+mod deidentification_job_repository;
+mod dicom_file_repository;
+mod dicom_object_repository;
+mod dicom_store_repository;
+mod identity_provider_repository;
+mod like_pattern;
 mod login_failure_count_repository;
+mod oidc_authorization_request_repository;
+mod patient_conflict_repository;
+mod performed_procedure_step_repository;
+mod retention_rule_repository;
+mod role_permission_repository;
 mod session_repository;
+mod user_identity_repository;
 mod user_repository;
+mod wado_uri_token_repository;
 
 pub use self::{
     application_entity_repository::PostgresApplicationEntityRepository,
+    archive_deletion_repository::StorageArchiveDeletionRepository,
+    archive_repository::PostgresArchiveRepository,
+    coercion_rule_repository::PostgresCoercionRuleRepository,
+    deidentification_job_repository::PostgresDeidentificationJobRepository,
+    dicom_file_repository::StorageDicomFileRepository,
+    dicom_object_repository::PostgresDicomObjectRepository,
+    dicom_store_repository::StorageDicomStoreRepository,
+    identity_provider_repository::{OidcConfig, OidcIdentityProviderRepository},
     login_failure_count_repository::PostgresLoginFailureCountRepository,
-    session_repository::InMemorySessionRepository, user_repository::PostgresUserRepository,
+    oidc_authorization_request_repository::PostgresOidcAuthorizationRequestRepository,
+    patient_conflict_repository::PostgresPatientConflictRepository,
+    performed_procedure_step_repository::PostgresPerformedProcedureStepRepository,
+    retention_rule_repository::PostgresRetentionRuleRepository,
+    role_permission_repository::PostgresRolePermissionRepository,
+    session_repository::PostgresSessionRepository,
+    user_identity_repository::PostgresUserIdentityRepository,
+    user_repository::PostgresUserRepository,
+    wado_uri_token_repository::HmacWadoUriTokenRepository,
 };
 
 #[cfg(test)]
 pub use self::{
     application_entity_repository::TestApplicationEntityRepository,
+    archive_deletion_repository::TestArchiveDeletionRepository,
+    archive_repository::TestArchiveRepository,
+    coercion_rule_repository::TestCoercionRuleRepository,
+    deidentification_job_repository::TestDeidentificationJobRepository,
+    dicom_file_repository::TestDicomFileRepository,
+    dicom_object_repository::TestDicomObjectRepository,
+    dicom_store_repository::TestDicomStoreRepository,
     login_failure_count_repository::TestLoginFailureCountRepository,
-    session_repository::TestSessionRepository, user_repository::TestUserRepository,
+    oidc_authorization_request_repository::TestOidcAuthorizationRequestRepository,
+    patient_conflict_repository::TestPatientConflictRepository,
+    performed_procedure_step_repository::TestPerformedProcedureStepRepository,
+    retention_rule_repository::TestRetentionRuleRepository,
+    role_permission_repository::TestRolePermissionRepository,
+    session_repository::TestSessionRepository,
+    user_identity_repository::TestUserIdentityRepository, user_repository::TestUserRepository,
 };
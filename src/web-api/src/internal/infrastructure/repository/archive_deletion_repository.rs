@@ -0,0 +1,188 @@
+use crate::internal::domain::{
+    error::RepositoryError,
+    repository::{ArchiveDeletionRepository, ArchiveDeletionTarget},
+};
+use chrono::{DateTime, Utc};
+use retention::{DeletionContext, DeletionTarget};
+use sqlx::{Pool, Postgres};
+use storage::{FileSystemStorage, StorageBackend, StorageResolver};
+use tracing::warn;
+use uuid::Uuid;
+
+/// DICOMサーバーの保存期間の規則による削除と共通の削除処理（[`retention::delete`]）で、検査・シリーズ・SOPインスタンスを削除するリポジトリ
+///
+/// ゴミ箱ディレクトリを指定した場合は、削除したSOPインスタンスのファイルをゴミ箱ディレクトリに移動する。
+pub struct StorageArchiveDeletionRepository {
+    pool: Pool<Postgres>,
+    storage: StorageResolver,
+    trash: Option<FileSystemStorage>,
+}
+
+impl StorageArchiveDeletionRepository {
+    pub fn new(pool: Pool<Postgres>, storage: StorageResolver, trash_dir: Option<&str>) -> Self {
+        Self {
+            pool,
+            storage,
+            trash: trash_dir.map(FileSystemStorage::new),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ArchiveDeletionRepository for StorageArchiveDeletionRepository {
+    async fn delete(
+        &self,
+        target: &ArchiveDeletionTarget,
+        deleted_by: &Uuid,
+        deleted_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let (target, resource, key) = match target {
+            ArchiveDeletionTarget::Study { study_instance_uid } => (
+                DeletionTarget::Study {
+                    study_instance_uid: study_instance_uid.clone(),
+                },
+                "検査",
+                study_instance_uid,
+            ),
+            ArchiveDeletionTarget::Series {
+                study_instance_uid,
+                series_instance_uid,
+            } => (
+                DeletionTarget::Series {
+                    study_instance_uid: study_instance_uid.clone(),
+                    series_instance_uid: series_instance_uid.clone(),
+                },
+                "シリーズ",
+                series_instance_uid,
+            ),
+            ArchiveDeletionTarget::SopInstance {
+                study_instance_uid,
+                series_instance_uid,
+                sop_instance_uid,
+            } => (
+                DeletionTarget::SopInstance {
+                    study_instance_uid: study_instance_uid.clone(),
+                    series_instance_uid: series_instance_uid.clone(),
+                    sop_instance_uid: sop_instance_uid.clone(),
+                },
+                "SOPインスタンス",
+                sop_instance_uid,
+            ),
+        };
+
+        let context = DeletionContext {
+            db_pool: &self.pool,
+            resolver: &self.storage,
+            trash: self
+                .trash
+                .as_ref()
+                .map(|trash| trash as &dyn StorageBackend),
+            deleted_by: *deleted_by,
+            deleted_at: *deleted_at,
+            log_prefix: "[アーカイブの削除]",
+        };
+        let result = retention::delete(&context, &target)
+            .await
+            .map_err(|message| RepositoryError::Other { message })?
+            .ok_or_else(|| RepositoryError::NotFound {
+                resource: resource.to_string(),
+                key: key.to_string(),
+            })?;
+
+        // DBからは削除済みのため、ファイルの削除に失敗しても削除は成功とする
+        // 削除できなかったファイルは、DICOMサーバーの整合性の検査で未登録のファイルとして検出される
+        if !result.failed_file_uris.is_empty() {
+            warn!(
+                "削除したSOPインスタンスのファイルの一部を削除できませんでした (URI={})",
+                result.failed_file_uris.join(", ")
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use std::sync::Arc;
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestArchiveDeletionRepository {
+    /// 登録されているSOPインスタンスの検査インスタンスUID、シリーズインスタンスUID、SOPインスタンスUID
+    inner: Arc<RwLock<Vec<(String, String, String)>>>,
+}
+
+#[cfg(test)]
+impl TestArchiveDeletionRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    pub async fn add(
+        &self,
+        study_instance_uid: &str,
+        series_instance_uid: &str,
+        sop_instance_uid: &str,
+    ) {
+        self.inner.write().await.push((
+            study_instance_uid.to_string(),
+            series_instance_uid.to_string(),
+            sop_instance_uid.to_string(),
+        ));
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl ArchiveDeletionRepository for TestArchiveDeletionRepository {
+    async fn delete(
+        &self,
+        target: &ArchiveDeletionTarget,
+        _deleted_by: &Uuid,
+        _deleted_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let (study, series, sop, resource, key) = match target {
+            ArchiveDeletionTarget::Study { study_instance_uid } => {
+                (study_instance_uid, None, None, "検査", study_instance_uid)
+            }
+            ArchiveDeletionTarget::Series {
+                study_instance_uid,
+                series_instance_uid,
+            } => (
+                study_instance_uid,
+                Some(series_instance_uid),
+                None,
+                "シリーズ",
+                series_instance_uid,
+            ),
+            ArchiveDeletionTarget::SopInstance {
+                study_instance_uid,
+                series_instance_uid,
+                sop_instance_uid,
+            } => (
+                study_instance_uid,
+                Some(series_instance_uid),
+                Some(sop_instance_uid),
+                "SOPインスタンス",
+                sop_instance_uid,
+            ),
+        };
+
+        let mut inner = self.inner.write().await;
+        let len = inner.len();
+        inner.retain(|(s, se, i)| {
+            !(s == study && series.is_none_or(|v| v == se) && sop.is_none_or(|v| v == i))
+        });
+        if inner.len() == len {
+            return Err(RepositoryError::NotFound {
+                resource: resource.to_string(),
+                key: key.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,781 @@
+use super::like_pattern::{to_partial_pattern, to_prefix_pattern};
+use crate::internal::domain::{
+    entity::{
+        ArchivedPatient, ArchivedSeries, ArchivedSopInstance, ArchivedStudy, Patient, Series,
+        SopInstance, Study,
+    },
+    error::RepositoryError,
+    repository::{
+        ArchiveRepository, ArchivedPatientFilter, ArchivedPatientSortKey, ArchivedStudyFilter,
+        ArchivedStudySortKey, Page,
+    },
+    value_object::SortOrder,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(FromRow)]
+struct ArchivedPatientRecord {
+    id: String,
+    name_alphabet: String,
+    name_kanji: String,
+    name_hiragana: String,
+    birth_date: Option<NaiveDate>,
+    sex: i16,
+    number_of_studies: i64,
+    number_of_series: i64,
+    number_of_instances: i64,
+    latest_study_date: Option<NaiveDate>,
+    created_by: Uuid,
+    created_by_ae_title: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ArchivedPatientRecord> for ArchivedPatient {
+    fn from(record: ArchivedPatientRecord) -> Self {
+        ArchivedPatient::construct(
+            Patient::construct(
+                record.id,
+                record.name_alphabet,
+                record.name_kanji,
+                record.name_hiragana,
+                record.birth_date,
+                record.sex,
+                record.number_of_studies,
+                record.number_of_series,
+                record.number_of_instances,
+            ),
+            record.latest_study_date,
+            record.created_by,
+            record.created_by_ae_title,
+            record.created_at,
+            record.updated_at,
+        )
+    }
+}
+
+#[derive(FromRow)]
+struct ArchivedStudyRecord {
+    instance_uid: String,
+    id: String,
+    study_date: Option<NaiveDate>,
+    study_time: Option<NaiveTime>,
+    accession_number: String,
+    patient_id: String,
+    patient_name_alphabet: String,
+    patient_name_kanji: String,
+    patient_name_hiragana: String,
+    patient_birth_date: Option<NaiveDate>,
+    patient_sex: i16,
+    modalities: Vec<String>,
+    sop_class_uids: Vec<String>,
+    number_of_series: i64,
+    number_of_instances: i64,
+    total_size: i64,
+    created_by: Uuid,
+    created_by_ae_title: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ArchivedStudyRecord> for ArchivedStudy {
+    fn from(record: ArchivedStudyRecord) -> Self {
+        ArchivedStudy::construct(
+            Study::construct(
+                record.instance_uid,
+                record.id,
+                record.study_date,
+                record.study_time,
+                record.accession_number,
+                record.patient_id,
+                record.patient_name_alphabet,
+                record.patient_name_kanji,
+                record.patient_name_hiragana,
+                record.patient_birth_date,
+                record.patient_sex,
+                record.modalities,
+                record.sop_class_uids,
+                record.number_of_series,
+                record.number_of_instances,
+            ),
+            record.total_size,
+            record.created_by,
+            record.created_by_ae_title,
+            record.created_at,
+            record.updated_at,
+        )
+    }
+}
+
+#[derive(FromRow)]
+struct ArchivedSeriesRecord {
+    study_instance_uid: String,
+    instance_uid: String,
+    modality: String,
+    series_number: Option<i32>,
+    number_of_instances: i64,
+    total_size: i64,
+    created_by: Uuid,
+    created_by_ae_title: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ArchivedSeriesRecord> for ArchivedSeries {
+    fn from(record: ArchivedSeriesRecord) -> Self {
+        ArchivedSeries::construct(
+            Series::construct(
+                record.study_instance_uid,
+                record.instance_uid,
+                record.modality,
+                record.series_number,
+                record.number_of_instances,
+            ),
+            record.total_size,
+            record.created_by,
+            record.created_by_ae_title,
+            record.created_at,
+            record.updated_at,
+        )
+    }
+}
+
+#[derive(FromRow)]
+struct ArchivedSopInstanceRecord {
+    study_instance_uid: String,
+    series_instance_uid: String,
+    instance_uid: String,
+    class_uid: String,
+    transfer_syntax_uid: String,
+    size: i64,
+    called_ae_title: String,
+    version: i32,
+    created_by: Uuid,
+    created_by_ae_title: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ArchivedSopInstanceRecord> for ArchivedSopInstance {
+    fn from(record: ArchivedSopInstanceRecord) -> Self {
+        ArchivedSopInstance::construct(
+            SopInstance::construct(
+                record.study_instance_uid,
+                record.series_instance_uid,
+                record.class_uid,
+                record.instance_uid,
+                record.transfer_syntax_uid,
+            ),
+            record.size,
+            record.called_ae_title,
+            record.version,
+            record.created_by,
+            record.created_by_ae_title,
+            record.created_at,
+            record.updated_at,
+        )
+    }
+}
+
+pub struct PostgresArchiveRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresArchiveRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+// 氏名はDICOMの区切り文字（^）を空白に置き換えて照合する。
+// 登録したAEのAEタイトルは、削除済みのAEも含めて検索する。
+#[async_trait::async_trait]
+impl ArchiveRepository for PostgresArchiveRepository {
+    async fn find_patients(
+        &self,
+        filter: &ArchivedPatientFilter,
+        sort_key: ArchivedPatientSortKey,
+        sort_order: SortOrder,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<ArchivedPatient>, RepositoryError> {
+        let patient_id = filter.patient_id.as_deref().map(to_prefix_pattern);
+        let patient_name = filter.patient_name.as_deref().map(to_partial_pattern);
+
+        let total = sqlx::query_scalar!(
+            r#"SELECT count(*) AS "count!"
+             FROM patients p
+             WHERE ($1::text IS NULL OR p.id LIKE $1)
+               AND ($2::text IS NULL
+                    OR replace(p.name_alphabet, '^', ' ') ILIKE $2
+                    OR replace(p.name_kanji, '^', ' ') ILIKE $2
+                    OR replace(p.name_hiragana, '^', ' ') ILIKE $2)"#,
+            patient_id,
+            patient_name,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let records = sqlx::query_as!(
+            ArchivedPatientRecord,
+            r#"SELECT p.id, p.name_alphabet, p.name_kanji, p.name_hiragana, p.birth_date, p.sex,
+                    s.number_of_studies AS "number_of_studies!",
+                    (SELECT count(*) FROM studies st
+                     JOIN series se ON se.study_instance_uid = st.instance_uid
+                     WHERE st.patient_id = p.id) AS "number_of_series!",
+                    (SELECT count(*) FROM studies st
+                     JOIN series se ON se.study_instance_uid = st.instance_uid
+                     JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+                     WHERE st.patient_id = p.id) AS "number_of_instances!",
+                    s.latest_study_date AS "latest_study_date?",
+                    p.created_by,
+                    (SELECT ae.title FROM application_entities ae WHERE ae.uuid = p.created_by
+                     UNION ALL
+                     SELECT ae.title FROM application_entities_deleted ae WHERE ae.uuid = p.created_by
+                     LIMIT 1) AS "created_by_ae_title?",
+                    p.created_at, p.updated_at
+             FROM patients p
+             CROSS JOIN LATERAL (
+                 SELECT count(*) AS number_of_studies, max(st.study_date) AS latest_study_date
+                 FROM studies st WHERE st.patient_id = p.id
+             ) s
+             WHERE ($1::text IS NULL OR p.id LIKE $1)
+               AND ($2::text IS NULL
+                    OR replace(p.name_alphabet, '^', ' ') ILIKE $2
+                    OR replace(p.name_kanji, '^', ' ') ILIKE $2
+                    OR replace(p.name_hiragana, '^', ' ') ILIKE $2)
+             ORDER BY
+                 CASE WHEN $3::text = 'patientName' AND $4::bool THEN p.name_hiragana END ASC,
+                 CASE WHEN $3::text = 'patientName' AND NOT $4::bool THEN p.name_hiragana END DESC,
+                 CASE WHEN $3::text = 'birthDate' AND $4::bool THEN p.birth_date END ASC NULLS LAST,
+                 CASE WHEN $3::text = 'birthDate' AND NOT $4::bool THEN p.birth_date END DESC NULLS LAST,
+                 CASE WHEN $3::text = 'latestStudyDate' AND $4::bool THEN s.latest_study_date END ASC NULLS LAST,
+                 CASE WHEN $3::text = 'latestStudyDate' AND NOT $4::bool THEN s.latest_study_date END DESC NULLS LAST,
+                 CASE WHEN $3::text = 'patientId' AND NOT $4::bool THEN p.id END DESC,
+                 p.id
+             LIMIT $5 OFFSET $6"#,
+            patient_id,
+            patient_name,
+            sort_key.as_str(),
+            sort_order.is_ascending(),
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(Page {
+            items: records.into_iter().map(ArchivedPatient::from).collect(),
+            total,
+        })
+    }
+
+    async fn find_studies(
+        &self,
+        filter: &ArchivedStudyFilter,
+        sort_key: ArchivedStudySortKey,
+        sort_order: SortOrder,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<ArchivedStudy>, RepositoryError> {
+        let patient_id = filter.patient_id.as_deref().map(to_prefix_pattern);
+        let patient_name = filter.patient_name.as_deref().map(to_partial_pattern);
+        let accession_number = filter.accession_number.as_deref().map(to_prefix_pattern);
+
+        let total = sqlx::query_scalar!(
+            r#"SELECT count(*) AS "count!"
+             FROM studies st
+             LEFT JOIN patients p ON p.id = st.patient_id
+             WHERE ($1::text IS NULL OR p.id LIKE $1)
+               AND ($2::text IS NULL
+                    OR replace(p.name_alphabet, '^', ' ') ILIKE $2
+                    OR replace(p.name_kanji, '^', ' ') ILIKE $2
+                    OR replace(p.name_hiragana, '^', ' ') ILIKE $2)
+               AND ($3::date IS NULL OR st.study_date >= $3)
+               AND ($4::date IS NULL OR st.study_date <= $4)
+               AND ($5::text IS NULL OR EXISTS (
+                    SELECT 1 FROM series se
+                    WHERE se.study_instance_uid = st.instance_uid AND se.modality = $5))
+               AND ($6::text IS NULL OR st.accession_number LIKE $6)"#,
+            patient_id,
+            patient_name,
+            filter.study_date_from,
+            filter.study_date_to,
+            filter.modality,
+            accession_number,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let records = sqlx::query_as!(
+            ArchivedStudyRecord,
+            r#"SELECT st.instance_uid, st.id, st.study_date, st.study_time, st.accession_number,
+                    COALESCE(p.id, '') AS "patient_id!",
+                    COALESCE(p.name_alphabet, '') AS "patient_name_alphabet!",
+                    COALESCE(p.name_kanji, '') AS "patient_name_kanji!",
+                    COALESCE(p.name_hiragana, '') AS "patient_name_hiragana!",
+                    p.birth_date AS "patient_birth_date?",
+                    COALESCE(p.sex, 0::smallint) AS "patient_sex!",
+                    ARRAY(SELECT DISTINCT se.modality::text FROM series se
+                          WHERE se.study_instance_uid = st.instance_uid
+                          ORDER BY 1) AS "modalities!",
+                    ARRAY(SELECT DISTINCT i.class_uid::text FROM series se
+                          JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+                          WHERE se.study_instance_uid = st.instance_uid
+                          ORDER BY 1) AS "sop_class_uids!",
+                    (SELECT count(*) FROM series se
+                     WHERE se.study_instance_uid = st.instance_uid) AS "number_of_series!",
+                    i.number_of_instances AS "number_of_instances!",
+                    i.total_size AS "total_size!",
+                    st.created_by,
+                    (SELECT ae.title FROM application_entities ae WHERE ae.uuid = st.created_by
+                     UNION ALL
+                     SELECT ae.title FROM application_entities_deleted ae WHERE ae.uuid = st.created_by
+                     LIMIT 1) AS "created_by_ae_title?",
+                    st.created_at, st.updated_at
+             FROM studies st
+             LEFT JOIN patients p ON p.id = st.patient_id
+             CROSS JOIN LATERAL (
+                 SELECT count(*) AS number_of_instances, COALESCE(sum(i.size), 0)::bigint AS total_size
+                 FROM series se
+                 JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+                 WHERE se.study_instance_uid = st.instance_uid
+             ) i
+             WHERE ($1::text IS NULL OR p.id LIKE $1)
+               AND ($2::text IS NULL
+                    OR replace(p.name_alphabet, '^', ' ') ILIKE $2
+                    OR replace(p.name_kanji, '^', ' ') ILIKE $2
+                    OR replace(p.name_hiragana, '^', ' ') ILIKE $2)
+               AND ($3::date IS NULL OR st.study_date >= $3)
+               AND ($4::date IS NULL OR st.study_date <= $4)
+               AND ($5::text IS NULL OR EXISTS (
+                    SELECT 1 FROM series se
+                    WHERE se.study_instance_uid = st.instance_uid AND se.modality = $5))
+               AND ($6::text IS NULL OR st.accession_number LIKE $6)
+             ORDER BY
+                 CASE WHEN $7::text = 'studyDate' AND $8::bool THEN st.study_date END ASC NULLS LAST,
+                 CASE WHEN $7::text = 'studyDate' AND $8::bool THEN st.study_time END ASC NULLS LAST,
+                 CASE WHEN $7::text = 'studyDate' AND NOT $8::bool THEN st.study_date END DESC NULLS LAST,
+                 CASE WHEN $7::text = 'studyDate' AND NOT $8::bool THEN st.study_time END DESC NULLS LAST,
+                 CASE WHEN $7::text = 'patientId' AND $8::bool THEN p.id END ASC NULLS LAST,
+                 CASE WHEN $7::text = 'patientId' AND NOT $8::bool THEN p.id END DESC NULLS LAST,
+                 CASE WHEN $7::text = 'patientName' AND $8::bool THEN p.name_hiragana END ASC NULLS LAST,
+                 CASE WHEN $7::text = 'patientName' AND NOT $8::bool THEN p.name_hiragana END DESC NULLS LAST,
+                 CASE WHEN $7::text = 'accessionNumber' AND $8::bool THEN st.accession_number END ASC,
+                 CASE WHEN $7::text = 'accessionNumber' AND NOT $8::bool THEN st.accession_number END DESC,
+                 CASE WHEN $7::text = 'createdAt' AND $8::bool THEN st.created_at END ASC,
+                 CASE WHEN $7::text = 'createdAt' AND NOT $8::bool THEN st.created_at END DESC,
+                 st.instance_uid
+             LIMIT $9 OFFSET $10"#,
+            patient_id,
+            patient_name,
+            filter.study_date_from,
+            filter.study_date_to,
+            filter.modality,
+            accession_number,
+            sort_key.as_str(),
+            sort_order.is_ascending(),
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(Page {
+            items: records.into_iter().map(ArchivedStudy::from).collect(),
+            total,
+        })
+    }
+
+    async fn find_study(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Option<ArchivedStudy>, RepositoryError> {
+        let record = sqlx::query_as!(
+            ArchivedStudyRecord,
+            r#"SELECT st.instance_uid, st.id, st.study_date, st.study_time, st.accession_number,
+                    COALESCE(p.id, '') AS "patient_id!",
+                    COALESCE(p.name_alphabet, '') AS "patient_name_alphabet!",
+                    COALESCE(p.name_kanji, '') AS "patient_name_kanji!",
+                    COALESCE(p.name_hiragana, '') AS "patient_name_hiragana!",
+                    p.birth_date AS "patient_birth_date?",
+                    COALESCE(p.sex, 0::smallint) AS "patient_sex!",
+                    ARRAY(SELECT DISTINCT se.modality::text FROM series se
+                          WHERE se.study_instance_uid = st.instance_uid
+                          ORDER BY 1) AS "modalities!",
+                    ARRAY(SELECT DISTINCT i.class_uid::text FROM series se
+                          JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+                          WHERE se.study_instance_uid = st.instance_uid
+                          ORDER BY 1) AS "sop_class_uids!",
+                    (SELECT count(*) FROM series se
+                     WHERE se.study_instance_uid = st.instance_uid) AS "number_of_series!",
+                    i.number_of_instances AS "number_of_instances!",
+                    i.total_size AS "total_size!",
+                    st.created_by,
+                    (SELECT ae.title FROM application_entities ae WHERE ae.uuid = st.created_by
+                     UNION ALL
+                     SELECT ae.title FROM application_entities_deleted ae WHERE ae.uuid = st.created_by
+                     LIMIT 1) AS "created_by_ae_title?",
+                    st.created_at, st.updated_at
+             FROM studies st
+             LEFT JOIN patients p ON p.id = st.patient_id
+             CROSS JOIN LATERAL (
+                 SELECT count(*) AS number_of_instances, COALESCE(sum(i.size), 0)::bigint AS total_size
+                 FROM series se
+                 JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+                 WHERE se.study_instance_uid = st.instance_uid
+             ) i
+             WHERE st.instance_uid = $1"#,
+            study_instance_uid,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(record.map(ArchivedStudy::from))
+    }
+
+    async fn find_series(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Vec<ArchivedSeries>, RepositoryError> {
+        let records = sqlx::query_as!(
+            ArchivedSeriesRecord,
+            r#"SELECT se.study_instance_uid, se.instance_uid, se.modality, se.series_number,
+                    count(i.instance_uid) AS "number_of_instances!",
+                    COALESCE(sum(i.size), 0)::bigint AS "total_size!",
+                    se.created_by,
+                    (SELECT ae.title FROM application_entities ae WHERE ae.uuid = se.created_by
+                     UNION ALL
+                     SELECT ae.title FROM application_entities_deleted ae WHERE ae.uuid = se.created_by
+                     LIMIT 1) AS "created_by_ae_title?",
+                    se.created_at, se.updated_at
+             FROM series se
+             LEFT JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+             WHERE se.study_instance_uid = $1
+             GROUP BY se.instance_uid
+             ORDER BY se.series_number NULLS LAST, se.instance_uid"#,
+            study_instance_uid,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(records.into_iter().map(ArchivedSeries::from).collect())
+    }
+
+    async fn find_sop_instances(
+        &self,
+        series_instance_uid: &str,
+    ) -> Result<Vec<ArchivedSopInstance>, RepositoryError> {
+        let records = sqlx::query_as!(
+            ArchivedSopInstanceRecord,
+            r#"SELECT se.study_instance_uid, i.series_instance_uid, i.instance_uid, i.class_uid,
+                    i.transfer_syntax_uid, i.size, i.called_ae_title, i.version, i.created_by,
+                    (SELECT ae.title FROM application_entities ae WHERE ae.uuid = i.created_by
+                     UNION ALL
+                     SELECT ae.title FROM application_entities_deleted ae WHERE ae.uuid = i.created_by
+                     LIMIT 1) AS "created_by_ae_title?",
+                    i.created_at, i.updated_at
+             FROM sop_instances i
+             JOIN series se ON se.instance_uid = i.series_instance_uid
+             WHERE i.series_instance_uid = $1
+             ORDER BY i.created_at, i.instance_uid"#,
+            series_instance_uid,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(records.into_iter().map(ArchivedSopInstance::from).collect())
+    }
+}
+
+#[cfg(test)]
+use std::{cmp::Ordering, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+#[derive(Default)]
+struct TestArchivedObjects {
+    patients: Vec<ArchivedPatient>,
+    studies: Vec<ArchivedStudy>,
+    series: Vec<ArchivedSeries>,
+    sop_instances: Vec<ArchivedSopInstance>,
+}
+
+#[cfg(test)]
+pub struct TestArchiveRepository {
+    inner: Arc<RwLock<TestArchivedObjects>>,
+}
+
+#[cfg(test)]
+impl TestArchiveRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(TestArchivedObjects::default())),
+        }
+    }
+
+    /// テストデータを登録する。
+    /// 件数やサイズなどの集計値は登録したエンティティの値をそのまま返す。
+    pub async fn add_patient(&self, entity: &ArchivedPatient) {
+        self.inner.write().await.patients.push(entity.clone());
+    }
+
+    pub async fn add_study(&self, entity: &ArchivedStudy) {
+        self.inner.write().await.studies.push(entity.clone());
+    }
+
+    pub async fn add_series(&self, entity: &ArchivedSeries) {
+        self.inner.write().await.series.push(entity.clone());
+    }
+
+    pub async fn add_sop_instance(&self, entity: &ArchivedSopInstance) {
+        self.inner.write().await.sop_instances.push(entity.clone());
+    }
+}
+
+/// 値がないデータを末尾として、並び順に従って比較する。
+#[cfg(test)]
+fn compare_with_order<T: Ord>(a: Option<T>, b: Option<T>, sort_order: SortOrder) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) if sort_order.is_ascending() => a.cmp(&b),
+        (Some(a), Some(b)) => b.cmp(&a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+fn matches_name(name: &str, pattern: &str) -> bool {
+    name.replace('^', " ")
+        .to_lowercase()
+        .contains(&pattern.to_lowercase())
+}
+
+#[cfg(test)]
+fn paginate<T>(entities: Vec<T>, offset: u32, limit: u32) -> Page<T> {
+    Page {
+        total: entities.len() as i64,
+        items: entities
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl ArchiveRepository for TestArchiveRepository {
+    async fn find_patients(
+        &self,
+        filter: &ArchivedPatientFilter,
+        sort_key: ArchivedPatientSortKey,
+        sort_order: SortOrder,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<ArchivedPatient>, RepositoryError> {
+        let mut entities = self
+            .inner
+            .read()
+            .await
+            .patients
+            .iter()
+            .filter(|e| {
+                let e = e.patient();
+                filter
+                    .patient_id
+                    .as_ref()
+                    .is_none_or(|p| e.id().starts_with(p.as_str()))
+                    && filter.patient_name.as_ref().is_none_or(|p| {
+                        matches_name(e.name_alphabet(), p)
+                            || matches_name(e.name_kanji(), p)
+                            || matches_name(e.name_hiragana(), p)
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| {
+            let (a_patient, b_patient) = (a.patient(), b.patient());
+            match sort_key {
+                ArchivedPatientSortKey::PatientId => {
+                    compare_with_order(Some(a_patient.id()), Some(b_patient.id()), sort_order)
+                }
+                ArchivedPatientSortKey::PatientName => compare_with_order(
+                    Some(a_patient.name_hiragana()),
+                    Some(b_patient.name_hiragana()),
+                    sort_order,
+                ),
+                ArchivedPatientSortKey::BirthDate => {
+                    compare_with_order(a_patient.birth_date(), b_patient.birth_date(), sort_order)
+                }
+                ArchivedPatientSortKey::LatestStudyDate => {
+                    compare_with_order(a.latest_study_date(), b.latest_study_date(), sort_order)
+                }
+            }
+            .then_with(|| a_patient.id().cmp(b_patient.id()))
+        });
+        Ok(paginate(entities, offset, limit))
+    }
+
+    async fn find_studies(
+        &self,
+        filter: &ArchivedStudyFilter,
+        sort_key: ArchivedStudySortKey,
+        sort_order: SortOrder,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Page<ArchivedStudy>, RepositoryError> {
+        let mut entities = self
+            .inner
+            .read()
+            .await
+            .studies
+            .iter()
+            .filter(|e| {
+                let e = e.study();
+                filter
+                    .patient_id
+                    .as_ref()
+                    .is_none_or(|p| e.patient_id().starts_with(p.as_str()))
+                    && filter.patient_name.as_ref().is_none_or(|p| {
+                        matches_name(e.patient_name_alphabet(), p)
+                            || matches_name(e.patient_name_kanji(), p)
+                            || matches_name(e.patient_name_hiragana(), p)
+                    })
+                    && filter
+                        .study_date_from
+                        .is_none_or(|from| e.study_date().is_some_and(|d| *d >= from))
+                    && filter
+                        .study_date_to
+                        .is_none_or(|to| e.study_date().is_some_and(|d| *d <= to))
+                    && filter
+                        .modality
+                        .as_ref()
+                        .is_none_or(|m| e.modalities().contains(m))
+                    && filter
+                        .accession_number
+                        .as_ref()
+                        .is_none_or(|p| e.accession_number().starts_with(p.as_str()))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| {
+            let (a_study, b_study) = (a.study(), b.study());
+            match sort_key {
+                ArchivedStudySortKey::StudyDate => {
+                    compare_with_order(a_study.study_date(), b_study.study_date(), sort_order)
+                        .then_with(|| {
+                            compare_with_order(
+                                a_study.study_time(),
+                                b_study.study_time(),
+                                sort_order,
+                            )
+                        })
+                }
+                ArchivedStudySortKey::PatientId => compare_with_order(
+                    Some(a_study.patient_id()),
+                    Some(b_study.patient_id()),
+                    sort_order,
+                ),
+                ArchivedStudySortKey::PatientName => compare_with_order(
+                    Some(a_study.patient_name_hiragana()),
+                    Some(b_study.patient_name_hiragana()),
+                    sort_order,
+                ),
+                ArchivedStudySortKey::AccessionNumber => compare_with_order(
+                    Some(a_study.accession_number()),
+                    Some(b_study.accession_number()),
+                    sort_order,
+                ),
+                ArchivedStudySortKey::CreatedAt => {
+                    compare_with_order(Some(a.created_at()), Some(b.created_at()), sort_order)
+                }
+            }
+            .then_with(|| a_study.instance_uid().cmp(b_study.instance_uid()))
+        });
+        Ok(paginate(entities, offset, limit))
+    }
+
+    async fn find_study(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Option<ArchivedStudy>, RepositoryError> {
+        Ok(self
+            .inner
+            .read()
+            .await
+            .studies
+            .iter()
+            .find(|e| e.study().instance_uid() == study_instance_uid)
+            .cloned())
+    }
+
+    async fn find_series(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Vec<ArchivedSeries>, RepositoryError> {
+        let mut entities = self
+            .inner
+            .read()
+            .await
+            .series
+            .iter()
+            .filter(|e| e.series().study_instance_uid() == study_instance_uid)
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| {
+            let (a, b) = (a.series(), b.series());
+            compare_with_order(a.series_number(), b.series_number(), SortOrder::Ascending)
+                .then_with(|| a.instance_uid().cmp(b.instance_uid()))
+        });
+        Ok(entities)
+    }
+
+    async fn find_sop_instances(
+        &self,
+        series_instance_uid: &str,
+    ) -> Result<Vec<ArchivedSopInstance>, RepositoryError> {
+        let mut entities = self
+            .inner
+            .read()
+            .await
+            .sop_instances
+            .iter()
+            .filter(|e| e.sop_instance().series_instance_uid() == series_instance_uid)
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| {
+            a.created_at().cmp(b.created_at()).then_with(|| {
+                a.sop_instance()
+                    .instance_uid()
+                    .cmp(b.sop_instance().instance_uid())
+            })
+        });
+        Ok(entities)
+    }
+}
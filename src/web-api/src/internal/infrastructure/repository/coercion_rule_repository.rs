@@ -0,0 +1,269 @@
+use crate::internal::domain::{
+    entity::{CoercionOperation, CoercionRule},
+    error::RepositoryError,
+    repository::CoercionRuleRepository,
+};
+use chrono::{DateTime, Utc};
+use dicom_lib::core::{Tag, value::value_representations::ae::AeValue};
+use sqlx::{Pool, Postgres};
+use uuid::Uuid;
+
+struct CoercionRuleRecord {
+    action: i16,
+    tag: String,
+    source_tag: Option<String>,
+    pattern: String,
+    value: String,
+    map_from: Vec<String>,
+    map_to: Vec<String>,
+}
+
+impl TryFrom<CoercionRuleRecord> for CoercionRule {
+    type Error = String;
+
+    fn try_from(record: CoercionRuleRecord) -> Result<Self, Self::Error> {
+        let tag = record.tag.parse::<Tag>().map_err(|e| e.to_string())?;
+        let operation = match record.action {
+            1 => CoercionOperation::Set {
+                value: record.value,
+            },
+            2 => {
+                let source_tag = record
+                    .source_tag
+                    .ok_or("複写元の属性が存在しません")?
+                    .parse::<Tag>()
+                    .map_err(|e| e.to_string())?;
+                CoercionOperation::Copy { source_tag }
+            }
+            3 => CoercionOperation::Delete,
+            4 => CoercionOperation::Replace {
+                pattern: record.pattern,
+                replacement: record.value,
+            },
+            5 => CoercionOperation::Map {
+                mappings: record.map_from.into_iter().zip(record.map_to).collect(),
+            },
+            _ => return Err(format!("不明な処理です (action={})", record.action)),
+        };
+        CoercionRule::new(tag, operation)
+    }
+}
+
+impl From<&CoercionRule> for CoercionRuleRecord {
+    fn from(rule: &CoercionRule) -> Self {
+        let mut record = CoercionRuleRecord {
+            action: 0,
+            tag: format!("{:X}", rule.tag()),
+            source_tag: None,
+            pattern: String::new(),
+            value: String::new(),
+            map_from: Vec::new(),
+            map_to: Vec::new(),
+        };
+        match rule.operation() {
+            CoercionOperation::Set { value } => {
+                record.action = 1;
+                record.value = value.clone();
+            }
+            CoercionOperation::Copy { source_tag } => {
+                record.action = 2;
+                record.source_tag = Some(format!("{source_tag:X}"));
+            }
+            CoercionOperation::Delete => {
+                record.action = 3;
+            }
+            CoercionOperation::Replace {
+                pattern,
+                replacement,
+            } => {
+                record.action = 4;
+                record.pattern = pattern.clone();
+                record.value = replacement.clone();
+            }
+            CoercionOperation::Map { mappings } => {
+                record.action = 5;
+                (record.map_from, record.map_to) = mappings.iter().cloned().unzip();
+            }
+        }
+        record
+    }
+}
+
+pub struct PostgresCoercionRuleRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresCoercionRuleRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl CoercionRuleRepository for PostgresCoercionRuleRepository {
+    async fn find_by_ae_title(
+        &self,
+        title: &AeValue,
+    ) -> Result<Option<Vec<CoercionRule>>, RepositoryError> {
+        let application_entity_uuid = sqlx::query_scalar!(
+            "SELECT uuid FROM application_entities WHERE title = $1",
+            title.value()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+        let Some(application_entity_uuid) = application_entity_uuid else {
+            return Ok(None);
+        };
+
+        let records = sqlx::query_as!(
+            CoercionRuleRecord,
+            r#"SELECT action, tag AS "tag!", source_tag, pattern, value, map_from, map_to
+             FROM coercion_rules
+             WHERE application_entity_uuid = $1
+             ORDER BY application_order"#,
+            application_entity_uuid
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let rules = records
+            .into_iter()
+            .map(|r| {
+                r.try_into()
+                    .expect("DBレコードからエンティティへの変換は成功するはず")
+            })
+            .collect::<Vec<_>>();
+        Ok(Some(rules))
+    }
+
+    async fn replace(
+        &self,
+        title: &AeValue,
+        rules: &[CoercionRule],
+        updated_by: &Uuid,
+        updated_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let db_error = |e: sqlx::Error| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        };
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("トランザクションの開始に失敗しました: {e}"),
+            })?;
+
+        let application_entity_uuid = sqlx::query_scalar!(
+            "SELECT uuid FROM application_entities WHERE title = $1 FOR UPDATE",
+            title.value()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| RepositoryError::NotFound {
+            resource: "AEタイトル".to_string(),
+            key: title.value().to_string(),
+        })?;
+
+        // 既存の規則を削除し、指定した規則を適用順に登録する
+        sqlx::query!(
+            "DELETE FROM coercion_rules WHERE application_entity_uuid = $1",
+            application_entity_uuid
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        for (i, rule) in rules.iter().enumerate() {
+            let record = CoercionRuleRecord::from(rule);
+            sqlx::query!(
+                "INSERT INTO coercion_rules (application_entity_uuid, application_order, action, tag, source_tag, pattern, value, map_from, map_to, created_by, created_at, updated_by, updated_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $10, $11)",
+                application_entity_uuid,
+                i as i32 + 1,
+                record.action,
+                record.tag,
+                record.source_tag,
+                record.pattern,
+                record.value,
+                &record.map_from,
+                &record.map_to,
+                updated_by,
+                updated_at
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        tx.commit().await.map_err(|e| RepositoryError::Other {
+            message: format!("トランザクションのコミットに失敗しました: {e}"),
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use std::{collections::HashMap, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestCoercionRuleRepository {
+    /// AEタイトルと書き換え規則
+    inner: Arc<RwLock<HashMap<String, Vec<CoercionRule>>>>,
+}
+
+#[cfg(test)]
+impl TestCoercionRuleRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// テストデータを登録する。
+    /// 登録されていないAEタイトルは存在しないAEとして扱う。
+    pub async fn add(&self, title: &str, rules: Vec<CoercionRule>) {
+        self.inner.write().await.insert(title.to_string(), rules);
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl CoercionRuleRepository for TestCoercionRuleRepository {
+    async fn find_by_ae_title(
+        &self,
+        title: &AeValue,
+    ) -> Result<Option<Vec<CoercionRule>>, RepositoryError> {
+        Ok(self.inner.read().await.get(title.value()).cloned())
+    }
+
+    async fn replace(
+        &self,
+        title: &AeValue,
+        rules: &[CoercionRule],
+        _updated_by: &Uuid,
+        _updated_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        match self.inner.write().await.get_mut(title.value()) {
+            Some(val) => {
+                *val = rules.to_vec();
+                Ok(())
+            }
+            None => Err(RepositoryError::NotFound {
+                resource: "AEタイトル".to_string(),
+                key: title.value().to_string(),
+            }),
+        }
+    }
+}
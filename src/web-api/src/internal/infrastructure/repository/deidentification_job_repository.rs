@@ -0,0 +1,232 @@
+use crate::internal::domain::{
+    entity::DeidentificationJob, error::RepositoryError, repository::DeidentificationJobRepository,
+    value_object::DeidentificationJobStatus,
+};
+use chrono::{DateTime, Utc};
+use dicom_lib::deidentification::DeidentificationOptions;
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(FromRow)]
+struct DeidentificationJobRecord {
+    uuid: Uuid,
+    study_instance_uid: String,
+    retain_longitudinal_temporal_information: bool,
+    retain_patient_characteristics: bool,
+    clean_descriptors: bool,
+    status: i16,
+    exported_file_count: i32,
+    failed_file_paths: Vec<String>,
+    created_by: Uuid,
+    created_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<DeidentificationJobRecord> for DeidentificationJob {
+    type Error = String;
+
+    fn try_from(record: DeidentificationJobRecord) -> Result<Self, Self::Error> {
+        let status = DeidentificationJobStatus::from_i16(record.status)?;
+        Ok(DeidentificationJob::construct(
+            record.uuid,
+            record.study_instance_uid,
+            DeidentificationOptions {
+                retain_longitudinal_temporal_information: record
+                    .retain_longitudinal_temporal_information,
+                retain_patient_characteristics: record.retain_patient_characteristics,
+                clean_descriptors: record.clean_descriptors,
+            },
+            status,
+            record.exported_file_count,
+            record.failed_file_paths,
+            record.created_by,
+            record.created_at,
+            record.finished_at,
+        ))
+    }
+}
+
+pub struct PostgresDeidentificationJobRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresDeidentificationJobRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl DeidentificationJobRepository for PostgresDeidentificationJobRepository {
+    async fn find_file_paths(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Vec<String>, RepositoryError> {
+        let db_error = |e: sqlx::Error| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        };
+
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM studies WHERE instance_uid = $1) AS "exists!""#,
+            study_instance_uid,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+        if !exists {
+            return Err(RepositoryError::NotFound {
+                resource: "検査".to_string(),
+                key: study_instance_uid.to_string(),
+            });
+        }
+
+        let paths = sqlx::query_scalar!(
+            r#"SELECT i.path
+             FROM sop_instances i
+             INNER JOIN series s ON s.instance_uid = i.series_instance_uid
+             WHERE s.study_instance_uid = $1
+             ORDER BY i.path"#,
+            study_instance_uid,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        Ok(paths)
+    }
+
+    async fn find_by_uuid(
+        &self,
+        uuid: &Uuid,
+    ) -> Result<Option<DeidentificationJob>, RepositoryError> {
+        let record = sqlx::query_as!(
+            DeidentificationJobRecord,
+            "SELECT uuid, study_instance_uid, retain_longitudinal_temporal_information, retain_patient_characteristics, clean_descriptors,
+                    status, exported_file_count, failed_file_paths, created_by, created_at, finished_at
+             FROM deidentification_jobs
+             WHERE uuid = $1",
+            uuid,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let entity = record.map(|r| {
+            r.try_into()
+                .expect("DBレコードからエンティティへの変換は成功するはず")
+        });
+        Ok(entity)
+    }
+
+    async fn add(&self, job: &DeidentificationJob) -> Result<(), RepositoryError> {
+        let options = job.options();
+        sqlx::query!(
+            "INSERT INTO deidentification_jobs (uuid, study_instance_uid, retain_longitudinal_temporal_information, retain_patient_characteristics, clean_descriptors, status, created_by, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+            job.uuid(),
+            job.study_instance_uid(),
+            options.retain_longitudinal_temporal_information,
+            options.retain_patient_characteristics,
+            options.clean_descriptors,
+            job.status().as_i16(),
+            job.created_by(),
+            job.created_at(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(())
+    }
+
+    async fn update(&self, job: &DeidentificationJob) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            "UPDATE deidentification_jobs
+             SET status = $2, exported_file_count = $3, failed_file_paths = $4, finished_at = $5
+             WHERE uuid = $1",
+            job.uuid(),
+            job.status().as_i16(),
+            job.exported_file_count(),
+            job.failed_file_paths(),
+            job.finished_at(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use std::{collections::HashMap, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestDeidentificationJobRepository {
+    jobs: Arc<RwLock<HashMap<Uuid, DeidentificationJob>>>,
+    study_file_paths: Arc<RwLock<HashMap<String, Vec<String>>>>,
+}
+
+#[cfg(test)]
+impl TestDeidentificationJobRepository {
+    pub fn new() -> Self {
+        Self {
+            jobs: Arc::new(RwLock::new(HashMap::new())),
+            study_file_paths: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// 検査に属するファイルのパスを追加する。
+    pub async fn add_file_path(&self, study_instance_uid: &str, path: &str) {
+        self.study_file_paths
+            .write()
+            .await
+            .entry(study_instance_uid.to_string())
+            .or_default()
+            .push(path.to_string());
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl DeidentificationJobRepository for TestDeidentificationJobRepository {
+    async fn find_file_paths(
+        &self,
+        study_instance_uid: &str,
+    ) -> Result<Vec<String>, RepositoryError> {
+        self.study_file_paths
+            .read()
+            .await
+            .get(study_instance_uid)
+            .cloned()
+            .ok_or_else(|| RepositoryError::NotFound {
+                resource: "検査".to_string(),
+                key: study_instance_uid.to_string(),
+            })
+    }
+
+    async fn find_by_uuid(
+        &self,
+        uuid: &Uuid,
+    ) -> Result<Option<DeidentificationJob>, RepositoryError> {
+        Ok(self.jobs.read().await.get(uuid).cloned())
+    }
+
+    async fn add(&self, job: &DeidentificationJob) -> Result<(), RepositoryError> {
+        self.jobs.write().await.insert(*job.uuid(), job.clone());
+        Ok(())
+    }
+
+    async fn update(&self, job: &DeidentificationJob) -> Result<(), RepositoryError> {
+        self.jobs.write().await.insert(*job.uuid(), job.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,609 @@
+use crate::internal::domain::{
+    error::RepositoryError, repository::DicomFileRepository, value_object::PatientId,
+};
+use dicom_lib::{
+    constants::transfer_syntax_uids::{EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN},
+    core::{
+        DataSet, Encoding, Tag,
+        value::value_representations::{ae::AeValue, sh::ShValue, ui::UiValue},
+    },
+    deidentification::Deidentifier,
+    file::{File, file_meta_information::FileMetaInformation},
+};
+use ingest::{
+    JournalOperation, RewrittenFile, StorageJournal, calculate_content_hash,
+    dicom_file::{DicomFile, find_string},
+    save_rewritten_file_to_db,
+};
+use sqlx::{Pool, Postgres};
+use std::path::PathBuf;
+use storage::{ByteStream, StorageResolver, calculate_file_hash, verify_file_hash};
+use tokio::fs;
+use tracing::error;
+use uuid::Uuid;
+
+// <root>.<app>.<type>.<version>
+// root: 1.3.6.1.4.1.64183 (https://www.iana.org/assignments/enterprise-numbers/)
+// app: 1 (Oceanus)
+// type: 2 (Web API)
+// version: x (major version)
+pub(super) const IMPLEMENTATION_CLASS_UID: &str =
+    concat!("1.3.6.1.4.1.64183.1.2.", env!("CARGO_PKG_VERSION_MAJOR"));
+pub(super) const IMPLEMENTATION_VERSION_NAME: &str = concat!("OCEANUS_", env!("CARGO_PKG_VERSION")); // OCEANUS_x.y.z
+
+/// DICOMサーバーがストレージに保存したDICOMファイル
+///
+/// ファイルのパスはDICOMサーバーがDBに記録したURIをそのまま使用し、URIに対応するストレージから読み書きする。
+/// 読み込んだファイルはDICOMサーバーが保存時に記録したハッシュ値で検証し、一致しない場合はDBに記録してエラーとする。
+/// 匿名化したファイルは`{エクスポート先ディレクトリ}/{ジョブのUUID}/`に書き出す。
+pub struct StorageDicomFileRepository {
+    pool: Pool<Postgres>,
+    storage: StorageResolver,
+    export_directory: PathBuf,
+}
+
+impl StorageDicomFileRepository {
+    pub fn new(
+        pool: Pool<Postgres>,
+        storage: StorageResolver,
+        export_directory: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            pool,
+            storage,
+            export_directory: export_directory.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DicomFileRepository for StorageDicomFileRepository {
+    async fn read(&self, path: &str) -> Result<Vec<u8>, RepositoryError> {
+        let storage = self
+            .storage
+            .resolve(path)
+            .map_err(|e| RepositoryError::Other {
+                message: e.to_string(),
+            })?;
+        let buf = storage
+            .get(path)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("ファイルの読み込みに失敗しました: {e}"),
+            })?;
+
+        // 保存時に記録したハッシュ値と比較し、保存後にファイルが破損または改ざんされていないかを検証する
+        let file_hash = sqlx::query_scalar!(
+            r#"SELECT file_hash AS "file_hash!" FROM sop_instances WHERE path = $1
+             UNION ALL
+             SELECT file_hash FROM sop_instance_histories WHERE path = $1
+             LIMIT 1"#,
+            path,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?
+        .ok_or_else(|| RepositoryError::Other {
+            message: format!("ファイルのハッシュ値が記録されていません (パス=\"{path}\")"),
+        })?;
+        if let Err(e) = verify_file_hash(path, &buf, &file_hash) {
+            // Patient IDの書き換えの途中で中断したファイルは、ジャーナルに記録した書き換え後のハッシュ値と一致すれば破損とみなさない
+            // DBへの記録はDICOMサーバーの復旧処理で行う
+            let rewritten_file_hash = sqlx::query_scalar!(
+                "SELECT file_hash FROM storage_journals WHERE uri = $1 AND operation = $2 AND status = 0 ORDER BY created_at DESC LIMIT 1",
+                path,
+                JournalOperation::Rewrite as i16,
+            )
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("データベース処理でエラーが発生しました: {e}"),
+            })?;
+            if rewritten_file_hash.is_some_and(|rewritten_file_hash| {
+                verify_file_hash(path, &buf, &rewritten_file_hash).is_ok()
+            }) {
+                return Ok(buf);
+            }
+
+            sqlx::query!(
+                "UPDATE sop_instances SET file_hash_status = 2, file_hash_verified_at = now() WHERE path = $1 AND file_hash = $2",
+                path,
+                file_hash,
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("データベース処理でエラーが発生しました: {e}"),
+            })?;
+            return Err(RepositoryError::Other {
+                message: e.to_string(),
+            });
+        }
+
+        Ok(buf)
+    }
+
+    async fn stream(&self, path: &str) -> Result<ByteStream, RepositoryError> {
+        let storage = self
+            .storage
+            .resolve(path)
+            .map_err(|e| RepositoryError::Other {
+                message: e.to_string(),
+            })?;
+        storage
+            .stream(path)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("ファイルの読み込みに失敗しました: {e}"),
+            })
+    }
+
+    async fn read_data_set(&self, path: &str) -> Result<DataSet, RepositoryError> {
+        let buf = self.read(path).await?;
+        let dicom_file = DicomFile::read(&buf).map_err(|message| RepositoryError::Other {
+            message: format!("{message} (パス=\"{path}\")"),
+        })?;
+        Ok(dicom_file.data_set)
+    }
+
+    async fn read_with_transfer_syntax(
+        &self,
+        path: &str,
+        transfer_syntax_uid: &str,
+    ) -> Result<Vec<u8>, RepositoryError> {
+        let buf = self.read(path).await?;
+        convert_transfer_syntax(&buf, transfer_syntax_uid).map_err(|message| {
+            RepositoryError::Other {
+                message: format!("{message} (パス=\"{path}\")"),
+            }
+        })
+    }
+
+    async fn rewrite_patient_id(
+        &self,
+        path: &str,
+        patient_id: &PatientId,
+    ) -> Result<(), RepositoryError> {
+        let buf = self.read(path).await?;
+
+        let (meta_end, buf) =
+            replace_patient_id(&buf, patient_id).map_err(|message| RepositoryError::Other {
+                message: format!("{message} (パス=\"{path}\")"),
+            })?;
+
+        // 重複したSOPインスタンスの判定に用いるため、データセット部分のハッシュ値も再計算する
+        let content_hash = calculate_content_hash(&buf[meta_end..]);
+        let file_hash = calculate_file_hash(&buf);
+        let rewritten_file = RewrittenFile {
+            size: buf.len() as u64,
+            content_hash: &content_hash,
+            file_hash: &file_hash,
+        };
+
+        // ファイルの置き換え後にDBへの記録の前で中断しても復旧できるよう、置き換え前に書き換え後のハッシュ値をジャーナルに記録する
+        let journal = StorageJournal::begin_rewrite(&self.pool, path, &rewritten_file)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("データベース処理でエラーが発生しました: {e}"),
+            })?
+            .ok_or_else(|| RepositoryError::Other {
+                message: format!(
+                    "ファイルを参照するSOPインスタンスが存在しません (パス=\"{path}\")"
+                ),
+            })?;
+
+        // 書き込み途中で中断しても元のファイルが壊れないよう、ストレージが不可分に置き換える
+        let replaced = match self.storage.resolve(path) {
+            Ok(storage) => storage
+                .replace(path, buf)
+                .await
+                .map_err(|e| format!("ファイルの置き換えに失敗しました: {e}")),
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(message) = replaced {
+            if let Err(e) = journal.discard(&self.pool).await {
+                error!("ジャーナルの削除に失敗しました: {e}");
+            }
+            return Err(RepositoryError::Other { message });
+        }
+
+        // 以降の読み込みで検証できるよう、置き換えたファイルのサイズとハッシュ値を記録する
+        save_rewritten_file_to_db(&self.pool, &rewritten_file, &journal)
+            .await
+            .map_err(|message| RepositoryError::Other { message })?;
+
+        Ok(())
+    }
+
+    async fn export_deidentified(
+        &self,
+        path: &str,
+        job_uuid: &Uuid,
+        deidentifier: &mut Deidentifier,
+    ) -> Result<String, RepositoryError> {
+        let buf = self.read(path).await?;
+
+        let (sop_instance_uid, buf) =
+            deidentify(&buf, deidentifier).map_err(|message| RepositoryError::Other {
+                message: format!("{message} (パス=\"{path}\")"),
+            })?;
+
+        let directory = self.export_directory.join(job_uuid.to_string());
+        fs::create_dir_all(&directory)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!(
+                    "ディレクトリの作成に失敗しました (パス=\"{}\"): {e}",
+                    directory.display()
+                ),
+            })?;
+        let export_path = directory.join(format!("{sop_instance_uid}.dcm"));
+        let export_path = export_path.to_string_lossy().to_string();
+        fs::write(&export_path, buf)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("ファイルの書き込みに失敗しました (パス=\"{export_path}\"): {e}"),
+            })?;
+
+        Ok(export_path)
+    }
+}
+
+/// DICOMファイルのデータセットを指定した転送構文に変換したバイト列を返す。
+///
+/// ファイルメタ情報は変換後の転送構文で作り直し、SOPクラスUID・SOPインスタンスUID・送信元AEタイトルを引き継ぐ。
+fn convert_transfer_syntax(buf: &[u8], transfer_syntax_uid: &str) -> Result<Vec<u8>, String> {
+    let encoding = to_native_encoding(transfer_syntax_uid).ok_or_else(|| {
+        format!("変換先に対応していない転送構文です (転送構文UID=\"{transfer_syntax_uid}\")")
+    })?;
+
+    let dicom_file = DicomFile::read(buf)?;
+    let source_transfer_syntax_uid = dicom_file.transfer_syntax_uid;
+    if to_native_encoding(&source_transfer_syntax_uid).is_none() {
+        return Err(format!(
+            "変換元に対応していない転送構文です (転送構文UID=\"{source_transfer_syntax_uid}\")"
+        ));
+    }
+    let data_set = dicom_file
+        .data_set
+        .convert_encoding(encoding)
+        .map_err(|e| e.to_string())?;
+
+    let sop_class_uid = dicom_file
+        .sop_class_uid
+        .or_else(|| find_string(&data_set, Tag(0x0008, 0x0016)))
+        .ok_or_else(|| "SOP Class UIDが存在しません".to_string())?;
+    let sop_instance_uid = dicom_file
+        .sop_instance_uid
+        .or_else(|| find_string(&data_set, Tag(0x0008, 0x0018)))
+        .ok_or_else(|| "SOP Instance UIDが存在しません".to_string())?;
+    let source_application_entity_title = dicom_file
+        .source_ae_title
+        .and_then(|title| AeValue::from_string(&title).ok());
+
+    let meta_information = FileMetaInformation::new(
+        UiValue::from_string(&sop_class_uid)
+            .map_err(|e| format!("不正なSOP Class UIDです: {e}"))?,
+        UiValue::from_string(&sop_instance_uid)
+            .map_err(|e| format!("不正なSOP Instance UIDです: {e}"))?,
+        UiValue::from_string(transfer_syntax_uid).unwrap(),
+        UiValue::from_string(IMPLEMENTATION_CLASS_UID).unwrap(),
+        Some(ShValue::from_string(IMPLEMENTATION_VERSION_NAME).unwrap()),
+        source_application_entity_title,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    Ok(File::new(meta_information, data_set).into())
+}
+
+/// ネイティブ形式（非圧縮・非カプセル化）のリトルエンディアンの転送構文に対応するエンコーディングを返す。
+fn to_native_encoding(transfer_syntax_uid: &str) -> Option<Encoding> {
+    match transfer_syntax_uid {
+        IMPLICIT_VR_LITTLE_ENDIAN => Some(Encoding::ImplicitVrLittleEndian),
+        EXPLICIT_VR_LITTLE_ENDIAN => Some(Encoding::ExplicitVrLittleEndian),
+        _ => None,
+    }
+}
+
+/// DICOMファイルのPatient IDを置き換え、ファイルメタ情報の末尾の位置と置き換えたバイト列を返す。
+fn replace_patient_id(buf: &[u8], patient_id: &PatientId) -> Result<(usize, Vec<u8>), String> {
+    let DicomFile {
+        meta_end,
+        mut data_set,
+        ..
+    } = DicomFile::read(buf)?;
+
+    // データセットのPatient IDを置き換える
+    let mut value_field = patient_id.value().as_bytes().to_vec();
+    if !value_field.len().is_multiple_of(2) {
+        value_field.push(b' ');
+    }
+    if !data_set.replace_value_field(Tag(0x0010, 0x0020), value_field) {
+        return Err("Patient IDが存在しません".to_string());
+    }
+
+    let mut new_buf = buf[..meta_end].to_vec();
+    new_buf.append(&mut data_set.into());
+    Ok((meta_end, new_buf))
+}
+
+/// DICOMファイルを匿名化し、置き換え後のSOP Instance UIDとバイト列を返す。
+///
+/// ファイルメタ情報は匿名化後のデータセットに合わせて作り直し、送信元等の情報は引き継がない。
+fn deidentify(buf: &[u8], deidentifier: &mut Deidentifier) -> Result<(String, Vec<u8>), String> {
+    let dicom_file = DicomFile::read(buf)?;
+    let transfer_syntax_uid = dicom_file.transfer_syntax_uid;
+
+    let data_set = deidentifier.deidentify(&dicom_file.data_set);
+    let sop_class_uid = find_string(&data_set, Tag(0x0008, 0x0016))
+        .ok_or_else(|| "SOP Class UIDが存在しません".to_string())?;
+    let sop_instance_uid = find_string(&data_set, Tag(0x0008, 0x0018))
+        .ok_or_else(|| "SOP Instance UIDが存在しません".to_string())?;
+
+    let meta_information = FileMetaInformation::new(
+        UiValue::from_string(&sop_class_uid)
+            .map_err(|e| format!("不正なSOP Class UIDです: {e}"))?,
+        UiValue::from_string(&sop_instance_uid).unwrap(),
+        UiValue::from_string(&transfer_syntax_uid)
+            .map_err(|e| format!("不正な転送構文UIDです: {e}"))?,
+        UiValue::from_string(IMPLEMENTATION_CLASS_UID).unwrap(),
+        Some(ShValue::from_string(IMPLEMENTATION_VERSION_NAME).unwrap()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+
+    Ok((
+        sop_instance_uid,
+        File::new(meta_information, data_set).into(),
+    ))
+}
+
+#[cfg(test)]
+use std::{collections::HashMap, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestDicomFileRepository {
+    /// ファイルのパスと書き込まれている患者ID
+    inner: Arc<RwLock<HashMap<String, String>>>,
+    /// ファイルのパスとファイルの内容
+    files: Arc<RwLock<HashMap<String, Vec<u8>>>>,
+}
+
+#[cfg(test)]
+impl TestDicomFileRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(HashMap::new())),
+            files: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// 読み込み用のファイルの内容を登録する。
+    /// 登録されていないパスのファイルは読み込みに失敗する。
+    pub async fn add_file(&self, path: &str, buf: Vec<u8>) {
+        self.files.write().await.insert(path.to_string(), buf);
+    }
+
+    /// テストデータを登録する。
+    /// 登録されていないパスのファイルは書き換えに失敗する。
+    pub async fn add(&self, path: &str, patient_id: &str) {
+        self.inner
+            .write()
+            .await
+            .insert(path.to_string(), patient_id.to_string());
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl DicomFileRepository for TestDicomFileRepository {
+    async fn read(&self, path: &str) -> Result<Vec<u8>, RepositoryError> {
+        self.files
+            .read()
+            .await
+            .get(path)
+            .cloned()
+            .ok_or_else(|| RepositoryError::Other {
+                message: format!("ファイルの読み込みに失敗しました (パス=\"{path}\")"),
+            })
+    }
+
+    async fn stream(&self, path: &str) -> Result<ByteStream, RepositoryError> {
+        let buf = self.read(path).await?;
+        Ok(Box::pin(std::io::Cursor::new(buf)))
+    }
+
+    async fn read_data_set(&self, path: &str) -> Result<DataSet, RepositoryError> {
+        let buf = self.read(path).await?;
+        let dicom_file = DicomFile::read(&buf).map_err(|message| RepositoryError::Other {
+            message: format!("{message} (パス=\"{path}\")"),
+        })?;
+        Ok(dicom_file.data_set)
+    }
+
+    async fn read_with_transfer_syntax(
+        &self,
+        path: &str,
+        transfer_syntax_uid: &str,
+    ) -> Result<Vec<u8>, RepositoryError> {
+        let buf = self.read(path).await?;
+        convert_transfer_syntax(&buf, transfer_syntax_uid).map_err(|message| {
+            RepositoryError::Other {
+                message: format!("{message} (パス=\"{path}\")"),
+            }
+        })
+    }
+
+    async fn rewrite_patient_id(
+        &self,
+        path: &str,
+        patient_id: &PatientId,
+    ) -> Result<(), RepositoryError> {
+        match self.inner.write().await.get_mut(path) {
+            Some(val) => {
+                *val = patient_id.value().to_string();
+                Ok(())
+            }
+            None => Err(RepositoryError::Other {
+                message: format!("ファイルの読み込みに失敗しました (パス=\"{path}\")"),
+            }),
+        }
+    }
+
+    async fn export_deidentified(
+        &self,
+        path: &str,
+        job_uuid: &Uuid,
+        deidentifier: &mut Deidentifier,
+    ) -> Result<String, RepositoryError> {
+        if !self.inner.read().await.contains_key(path) {
+            return Err(RepositoryError::Other {
+                message: format!("ファイルの読み込みに失敗しました (パス=\"{path}\")"),
+            });
+        }
+
+        // 匿名化後のSOP Instance UIDの代わりに、パスを置き換えたUIDをファイル名にする
+        let sop_instance_uid = deidentifier.remap_uid(path);
+        Ok(format!(
+            "/var/lib/oceanus/export/{job_uuid}/{sop_instance_uid}.dcm"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_replace_patient_id() {
+        // Arrange
+        let buf = fs::read("../../data/dicom/GENECG").await.unwrap();
+        let patient_id = PatientId::new("P000001").unwrap();
+
+        // Act
+        let (meta_end, actual) = replace_patient_id(&buf, &patient_id).unwrap();
+
+        // Assert
+        // ファイルメタ情報は変わらない
+        assert_eq!(meta_end, DicomFile::read(&buf).unwrap().meta_end);
+        // 元のPatient ID (18バイト) が8バイトの値に置き換わる
+        assert_eq!(actual.len(), buf.len() - 10);
+        assert_eq!(&actual[..0x0000055a], &buf[..0x0000055a]);
+        assert_eq!(
+            &actual[0x0000055a..0x0000055a + 16],
+            b"\x10\x00\x20\x00LO\x08\x00P000001 "
+        );
+        assert_eq!(&actual[0x0000055a + 16..], &buf[0x0000055a + 26..]);
+    }
+
+    #[tokio::test]
+    async fn test_deidentify() {
+        // Arrange
+        let buf = fs::read("../../data/dicom/GENECG").await.unwrap();
+        let mut deidentifier = Deidentifier::new(Default::default());
+
+        // Act
+        let (sop_instance_uid, actual) = deidentify(&buf, &mut deidentifier).unwrap();
+
+        // Assert
+        // SOP Instance UIDは置き換えたUIDになり、ファイルメタ情報にも反映される
+        assert_eq!(
+            sop_instance_uid,
+            deidentifier.remap_uid("1.3.12.2.1107.5.4.5.999999.30000009060216012356200000013")
+        );
+        let DicomFile {
+            meta_end,
+            sop_instance_uid: meta_sop_instance_uid,
+            transfer_syntax_uid,
+            data_set,
+            ..
+        } = DicomFile::read(&actual).unwrap();
+        assert_eq!(meta_sop_instance_uid.unwrap(), sop_instance_uid);
+        assert_eq!(transfer_syntax_uid, "1.2.840.10008.1.2.1");
+        assert!(
+            actual[..meta_end]
+                .windows(IMPLEMENTATION_CLASS_UID.len())
+                .any(|window| window == IMPLEMENTATION_CLASS_UID.as_bytes())
+        );
+        assert_eq!(
+            find_string(&data_set, Tag(0x0008, 0x0018)).unwrap(),
+            sop_instance_uid
+        );
+        // 患者を識別する情報は取り除かれる
+        assert_eq!(find_string(&data_set, Tag(0x0010, 0x0010)).unwrap(), "");
+        assert_eq!(find_string(&data_set, Tag(0x0010, 0x0020)).unwrap(), "");
+        assert_eq!(find_string(&data_set, Tag(0x0012, 0x0062)).unwrap(), "YES");
+    }
+
+    #[tokio::test]
+    async fn test_convert_transfer_syntax() {
+        // Arrange
+        let buf = fs::read("../../data/dicom/GENECG").await.unwrap();
+
+        // Act
+        let implicit = convert_transfer_syntax(&buf, IMPLICIT_VR_LITTLE_ENDIAN).unwrap();
+        let explicit = convert_transfer_syntax(&implicit, EXPLICIT_VR_LITTLE_ENDIAN).unwrap();
+        let unsupported = convert_transfer_syntax(&buf, "1.2.840.10008.1.2.4.50");
+
+        // Assert
+        // ファイルメタ情報の転送構文UIDが変換後の値になり、SOPインスタンスUIDは引き継ぐ
+        let DicomFile {
+            sop_instance_uid,
+            transfer_syntax_uid,
+            data_set,
+            ..
+        } = DicomFile::read(&implicit).unwrap();
+        assert_eq!(transfer_syntax_uid, IMPLICIT_VR_LITTLE_ENDIAN);
+        assert_eq!(
+            sop_instance_uid.unwrap(),
+            "1.3.12.2.1107.5.4.5.999999.30000009060216012356200000013"
+        );
+        assert_eq!(data_set.encoding(), Encoding::ImplicitVrLittleEndian);
+        assert!(data_set.into_iter().all(|e| e.vr().is_none()));
+        assert_eq!(
+            find_string(&data_set, Tag(0x0010, 0x0020)).unwrap(),
+            find_string(
+                &DicomFile::read(&buf).unwrap().data_set,
+                Tag(0x0010, 0x0020)
+            )
+            .unwrap()
+        );
+
+        // 明示的VRに戻すと、データセットは元のファイルと一致する
+        let original_meta_end = DicomFile::read(&buf).unwrap().meta_end;
+        let meta_end = DicomFile::read(&explicit).unwrap().meta_end;
+        assert_eq!(&explicit[meta_end..], &buf[original_meta_end..]);
+
+        assert!(unsupported.is_err());
+    }
+}
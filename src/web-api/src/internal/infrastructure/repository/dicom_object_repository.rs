@@ -0,0 +1,897 @@
+use super::like_pattern::to_like_pattern;
+#[cfg(test)]
+use crate::internal::domain::value_object::DateRange;
+use crate::internal::domain::{
+    entity::{Patient, Series, SopInstance, SopInstanceFile, Study},
+    error::RepositoryError,
+    repository::{
+        DicomObjectRepository, PatientSearchCondition, SeriesSearchCondition,
+        SopInstanceSearchCondition, StudySearchCondition,
+    },
+    value_object::{MatchingPattern, PersonNamePattern},
+};
+use chrono::{NaiveDate, NaiveTime};
+use sqlx::{FromRow, Pool, Postgres};
+
+#[derive(FromRow)]
+struct PatientRecord {
+    id: String,
+    name_alphabet: String,
+    name_kanji: String,
+    name_hiragana: String,
+    birth_date: Option<NaiveDate>,
+    sex: i16,
+    number_of_studies: i64,
+    number_of_series: i64,
+    number_of_instances: i64,
+}
+
+impl From<PatientRecord> for Patient {
+    fn from(record: PatientRecord) -> Self {
+        Patient::construct(
+            record.id,
+            record.name_alphabet,
+            record.name_kanji,
+            record.name_hiragana,
+            record.birth_date,
+            record.sex,
+            record.number_of_studies,
+            record.number_of_series,
+            record.number_of_instances,
+        )
+    }
+}
+
+#[derive(FromRow)]
+struct StudyRecord {
+    instance_uid: String,
+    id: String,
+    study_date: Option<NaiveDate>,
+    study_time: Option<NaiveTime>,
+    accession_number: String,
+    patient_id: String,
+    patient_name_alphabet: String,
+    patient_name_kanji: String,
+    patient_name_hiragana: String,
+    patient_birth_date: Option<NaiveDate>,
+    patient_sex: i16,
+    modalities: Vec<String>,
+    sop_class_uids: Vec<String>,
+    number_of_series: i64,
+    number_of_instances: i64,
+}
+
+impl From<StudyRecord> for Study {
+    fn from(record: StudyRecord) -> Self {
+        Study::construct(
+            record.instance_uid,
+            record.id,
+            record.study_date,
+            record.study_time,
+            record.accession_number,
+            record.patient_id,
+            record.patient_name_alphabet,
+            record.patient_name_kanji,
+            record.patient_name_hiragana,
+            record.patient_birth_date,
+            record.patient_sex,
+            record.modalities,
+            record.sop_class_uids,
+            record.number_of_series,
+            record.number_of_instances,
+        )
+    }
+}
+
+#[derive(FromRow)]
+struct SeriesRecord {
+    study_instance_uid: String,
+    instance_uid: String,
+    modality: String,
+    series_number: Option<i32>,
+    number_of_instances: i64,
+}
+
+impl From<SeriesRecord> for Series {
+    fn from(record: SeriesRecord) -> Self {
+        Series::construct(
+            record.study_instance_uid,
+            record.instance_uid,
+            record.modality,
+            record.series_number,
+            record.number_of_instances,
+        )
+    }
+}
+
+#[derive(FromRow)]
+struct SopInstanceRecord {
+    study_instance_uid: String,
+    series_instance_uid: String,
+    class_uid: String,
+    instance_uid: String,
+    transfer_syntax_uid: String,
+}
+
+impl From<SopInstanceRecord> for SopInstance {
+    fn from(record: SopInstanceRecord) -> Self {
+        SopInstance::construct(
+            record.study_instance_uid,
+            record.series_instance_uid,
+            record.class_uid,
+            record.instance_uid,
+            record.transfer_syntax_uid,
+        )
+    }
+}
+
+#[derive(FromRow)]
+struct SopInstanceFileRecord {
+    study_instance_uid: String,
+    series_instance_uid: String,
+    instance_uid: String,
+    transfer_syntax_uid: String,
+    path: String,
+    size: i64,
+}
+
+impl From<SopInstanceFileRecord> for SopInstanceFile {
+    fn from(record: SopInstanceFileRecord) -> Self {
+        SopInstanceFile::construct(
+            record.study_instance_uid,
+            record.series_instance_uid,
+            record.instance_uid,
+            record.transfer_syntax_uid,
+            record.path,
+            record.size as u64,
+        )
+    }
+}
+
+/// 氏名の照合パターンをSQLのパラメータに変換する。
+///
+/// 戻り値は、アルファベット・漢字・ひらがなの各表記に対するILIKE演算子のパターンと、いずれかの表記の一致でよいかどうか。
+/// 氏名の構成要素の先頭での一致を判定できるよう、パターンは`'^' || 氏名`に対して照合する形式とする。
+fn to_person_name_params(
+    pattern: Option<&PersonNamePattern>,
+) -> (Option<String>, Option<String>, Option<String>, bool) {
+    let Some(pattern) = pattern else {
+        return (None, None, None, false);
+    };
+
+    let to_param = |p: Option<&MatchingPattern>| {
+        p.map(|p| {
+            if pattern.is_fuzzy() {
+                format!("%^{}%", to_like_pattern(p))
+            } else {
+                format!("^{}", to_like_pattern(p))
+            }
+        })
+    };
+    (
+        to_param(pattern.alphabetic()),
+        to_param(pattern.ideographic()),
+        to_param(pattern.phonetic()),
+        pattern.is_any_representation(),
+    )
+}
+
+pub struct PostgresDicomObjectRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresDicomObjectRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl DicomObjectRepository for PostgresDicomObjectRepository {
+    async fn search_patients(
+        &self,
+        condition: &PatientSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Patient>, RepositoryError> {
+        let (name_alphabet, name_kanji, name_hiragana, name_any_representation) =
+            to_person_name_params(condition.patient_name.as_ref());
+        let records = sqlx::query_as!(
+            PatientRecord,
+            r#"SELECT p.id, p.name_alphabet, p.name_kanji, p.name_hiragana, p.birth_date, p.sex,
+                    (SELECT count(*) FROM studies st WHERE st.patient_id = p.id) AS "number_of_studies!",
+                    (SELECT count(*) FROM studies st
+                     JOIN series se ON se.study_instance_uid = st.instance_uid
+                     WHERE st.patient_id = p.id) AS "number_of_series!",
+                    (SELECT count(*) FROM studies st
+                     JOIN series se ON se.study_instance_uid = st.instance_uid
+                     JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+                     WHERE st.patient_id = p.id) AS "number_of_instances!"
+             FROM patients p
+             WHERE ($1::text IS NULL OR p.id LIKE $1)
+               AND (CASE WHEN $5::bool
+                    THEN ('^' || p.name_alphabet) ILIKE $2 OR ('^' || p.name_kanji) ILIKE $3 OR ('^' || p.name_hiragana) ILIKE $4
+                    ELSE ($2::text IS NULL OR ('^' || p.name_alphabet) ILIKE $2)
+                     AND ($3::text IS NULL OR ('^' || p.name_kanji) ILIKE $3)
+                     AND ($4::text IS NULL OR ('^' || p.name_hiragana) ILIKE $4)
+                    END)
+               AND ($6::date IS NULL OR p.birth_date >= $6)
+               AND ($7::date IS NULL OR p.birth_date <= $7)
+               AND ($8::smallint IS NULL OR p.sex = $8)
+             ORDER BY p.id
+             LIMIT $9 OFFSET $10"#,
+            condition.patient_id.as_ref().map(to_like_pattern),
+            name_alphabet,
+            name_kanji,
+            name_hiragana,
+            name_any_representation,
+            condition.patient_birth_date.and_then(|r| r.start().copied()),
+            condition.patient_birth_date.and_then(|r| r.end().copied()),
+            condition.patient_sex,
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(records.into_iter().map(Patient::from).collect())
+    }
+
+    async fn search_studies(
+        &self,
+        condition: &StudySearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Study>, RepositoryError> {
+        let patient = &condition.patient;
+        let (name_alphabet, name_kanji, name_hiragana, name_any_representation) =
+            to_person_name_params(patient.patient_name.as_ref());
+        let records = sqlx::query_as!(
+            StudyRecord,
+            r#"SELECT st.instance_uid, st.id, st.study_date, st.study_time, st.accession_number,
+                    COALESCE(p.id, '') AS "patient_id!",
+                    COALESCE(p.name_alphabet, '') AS "patient_name_alphabet!",
+                    COALESCE(p.name_kanji, '') AS "patient_name_kanji!",
+                    COALESCE(p.name_hiragana, '') AS "patient_name_hiragana!",
+                    p.birth_date AS "patient_birth_date?",
+                    COALESCE(p.sex, 0::smallint) AS "patient_sex!",
+                    ARRAY(SELECT DISTINCT se.modality::text FROM series se
+                          WHERE se.study_instance_uid = st.instance_uid
+                          ORDER BY 1) AS "modalities!",
+                    ARRAY(SELECT DISTINCT i.class_uid::text FROM series se
+                          JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+                          WHERE se.study_instance_uid = st.instance_uid
+                          ORDER BY 1) AS "sop_class_uids!",
+                    (SELECT count(*) FROM series se
+                     WHERE se.study_instance_uid = st.instance_uid) AS "number_of_series!",
+                    (SELECT count(*) FROM series se
+                     JOIN sop_instances i ON i.series_instance_uid = se.instance_uid
+                     WHERE se.study_instance_uid = st.instance_uid) AS "number_of_instances!"
+             FROM studies st
+             LEFT JOIN patients p ON p.id = st.patient_id
+             WHERE (cardinality($1::text[]) = 0 OR st.instance_uid = ANY($1))
+               AND ($2::date IS NULL OR st.study_date >= $2)
+               AND ($3::date IS NULL OR st.study_date <= $3)
+               AND ($4::text IS NULL OR st.accession_number LIKE $4)
+               AND ($5::text IS NULL OR st.id LIKE $5)
+               AND (cardinality($6::text[]) = 0 OR EXISTS (
+                    SELECT 1 FROM series se
+                    WHERE se.study_instance_uid = st.instance_uid AND se.modality = ANY($6)))
+               AND ($7::text IS NULL OR p.id LIKE $7)
+               AND (CASE WHEN $11::bool
+                    THEN ('^' || p.name_alphabet) ILIKE $8 OR ('^' || p.name_kanji) ILIKE $9 OR ('^' || p.name_hiragana) ILIKE $10
+                    ELSE ($8::text IS NULL OR ('^' || p.name_alphabet) ILIKE $8)
+                     AND ($9::text IS NULL OR ('^' || p.name_kanji) ILIKE $9)
+                     AND ($10::text IS NULL OR ('^' || p.name_hiragana) ILIKE $10)
+                    END)
+               AND ($12::date IS NULL OR p.birth_date >= $12)
+               AND ($13::date IS NULL OR p.birth_date <= $13)
+               AND ($14::smallint IS NULL OR p.sex = $14)
+             ORDER BY st.study_date DESC NULLS LAST, st.study_time DESC NULLS LAST, st.instance_uid
+             LIMIT $15 OFFSET $16"#,
+            &condition.study_instance_uids,
+            condition.study_date.and_then(|r| r.start().copied()),
+            condition.study_date.and_then(|r| r.end().copied()),
+            condition.accession_number.as_ref().map(to_like_pattern),
+            condition.study_id.as_ref().map(to_like_pattern),
+            &condition.modalities_in_study,
+            patient.patient_id.as_ref().map(to_like_pattern),
+            name_alphabet,
+            name_kanji,
+            name_hiragana,
+            name_any_representation,
+            patient.patient_birth_date.and_then(|r| r.start().copied()),
+            patient.patient_birth_date.and_then(|r| r.end().copied()),
+            patient.patient_sex,
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(records.into_iter().map(Study::from).collect())
+    }
+
+    async fn search_series(
+        &self,
+        condition: &SeriesSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Series>, RepositoryError> {
+        let records = sqlx::query_as!(
+            SeriesRecord,
+            r#"SELECT se.study_instance_uid, se.instance_uid, se.modality, se.series_number,
+                    (SELECT count(*) FROM sop_instances i
+                     WHERE i.series_instance_uid = se.instance_uid) AS "number_of_instances!"
+             FROM series se
+             WHERE se.study_instance_uid = $1
+               AND (cardinality($2::text[]) = 0 OR se.instance_uid = ANY($2))
+               AND ($3::text IS NULL OR se.modality LIKE $3)
+               AND ($4::integer IS NULL OR se.series_number = $4)
+             ORDER BY se.series_number NULLS LAST, se.instance_uid
+             LIMIT $5 OFFSET $6"#,
+            condition.study_instance_uid,
+            &condition.series_instance_uids,
+            condition.modality.as_ref().map(to_like_pattern),
+            condition.series_number,
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(records.into_iter().map(Series::from).collect())
+    }
+
+    async fn search_sop_instances(
+        &self,
+        condition: &SopInstanceSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SopInstance>, RepositoryError> {
+        let records = sqlx::query_as!(
+            SopInstanceRecord,
+            r#"SELECT se.study_instance_uid, i.series_instance_uid, i.class_uid, i.instance_uid,
+                    i.transfer_syntax_uid
+             FROM sop_instances i
+             JOIN series se ON se.instance_uid = i.series_instance_uid
+             WHERE se.study_instance_uid = $1
+               AND i.series_instance_uid = $2
+               AND (cardinality($3::text[]) = 0 OR i.instance_uid = ANY($3))
+               AND (cardinality($4::text[]) = 0 OR i.class_uid = ANY($4))
+             ORDER BY i.created_at, i.instance_uid
+             LIMIT $5 OFFSET $6"#,
+            condition.study_instance_uid,
+            condition.series_instance_uid,
+            &condition.sop_instance_uids,
+            &condition.sop_class_uids,
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(records.into_iter().map(SopInstance::from).collect())
+    }
+
+    async fn find_sop_instance_files(
+        &self,
+        study_instance_uid: &str,
+        series_instance_uid: Option<&str>,
+        sop_instance_uid: Option<&str>,
+    ) -> Result<Vec<SopInstanceFile>, RepositoryError> {
+        let records = sqlx::query_as!(
+            SopInstanceFileRecord,
+            r#"SELECT se.study_instance_uid, i.series_instance_uid, i.instance_uid,
+                    i.transfer_syntax_uid, i.path, i.size
+             FROM sop_instances i
+             JOIN series se ON se.instance_uid = i.series_instance_uid
+             WHERE se.study_instance_uid = $1
+               AND ($2::text IS NULL OR i.series_instance_uid = $2)
+               AND ($3::text IS NULL OR i.instance_uid = $3)
+             ORDER BY se.series_number NULLS LAST, se.instance_uid, i.created_at, i.instance_uid"#,
+            study_instance_uid,
+            series_instance_uid,
+            sop_instance_uid,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(records.into_iter().map(SopInstanceFile::from).collect())
+    }
+}
+
+#[cfg(test)]
+use std::sync::Arc;
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+#[derive(Default)]
+struct TestDicomObjects {
+    patients: Vec<Patient>,
+    studies: Vec<Study>,
+    series: Vec<Series>,
+    sop_instances: Vec<SopInstance>,
+    sop_instance_files: Vec<SopInstanceFile>,
+}
+
+#[cfg(test)]
+pub struct TestDicomObjectRepository {
+    inner: Arc<RwLock<TestDicomObjects>>,
+}
+
+#[cfg(test)]
+impl TestDicomObjectRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(TestDicomObjects::default())),
+        }
+    }
+
+    /// テストデータを登録する。
+    /// 件数などの集計値は登録したエンティティの値をそのまま返す。
+    pub async fn add_patient(&self, entity: &Patient) {
+        self.inner.write().await.patients.push(entity.clone());
+    }
+
+    pub async fn add_study(&self, entity: &Study) {
+        self.inner.write().await.studies.push(entity.clone());
+    }
+
+    pub async fn add_series(&self, entity: &Series) {
+        self.inner.write().await.series.push(entity.clone());
+    }
+
+    pub async fn add_sop_instance(&self, entity: &SopInstance) {
+        self.inner.write().await.sop_instances.push(entity.clone());
+    }
+
+    pub async fn add_sop_instance_file(&self, entity: &SopInstanceFile) {
+        self.inner
+            .write()
+            .await
+            .sop_instance_files
+            .push(entity.clone());
+    }
+}
+
+#[cfg(test)]
+fn paginate<T>(entities: Vec<T>, offset: u32, limit: u32) -> Vec<T> {
+    entities
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .collect()
+}
+
+/// `value LIKE pattern`を評価する（エスケープ文字は`\`）。
+///
+/// [`PostgresDicomObjectRepository`]と同じ照合結果になるよう、SQLに渡すパラメータをそのまま評価する。
+#[cfg(test)]
+fn like(value: &str, pattern: &str) -> bool {
+    // パターンを`None`（`%`）、`Some(None)`（`_`）、`Some(Some(c))`（文字`c`）に分解する
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '%' => None,
+            '_' => Some(None),
+            '\\' => Some(chars.next()),
+            c => Some(Some(c)),
+        });
+    }
+    let value = value.chars().collect::<Vec<_>>();
+
+    // `%`が直前に現れた位置と、その`%`に一致させた値の位置を記録して、一致しなかった場合にやり直す
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while v < value.len() {
+        match tokens.get(p) {
+            Some(Some(None)) => {
+                p += 1;
+                v += 1;
+            }
+            Some(Some(Some(c))) if *c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            Some(None) => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            _ => match backtrack {
+                Some((percent_p, percent_v)) => {
+                    p = percent_p + 1;
+                    v = percent_v + 1;
+                    backtrack = Some((percent_p, percent_v + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[p..].iter().all(Option::is_none)
+}
+
+/// `value ILIKE pattern`を評価する。
+#[cfg(test)]
+fn ilike(value: &str, pattern: &str) -> bool {
+    like(&value.to_lowercase(), &pattern.to_lowercase())
+}
+
+/// [`PostgresDicomObjectRepository`]の氏名の条件を評価する。
+#[cfg(test)]
+fn person_name_matches(
+    pattern: &PersonNamePattern,
+    alphabetic: &str,
+    ideographic: &str,
+    phonetic: &str,
+) -> bool {
+    let (name_alphabet, name_kanji, name_hiragana, any_representation) =
+        to_person_name_params(Some(pattern));
+    let mut results = [
+        (name_alphabet, alphabetic),
+        (name_kanji, ideographic),
+        (name_hiragana, phonetic),
+    ]
+    .into_iter()
+    .filter_map(|(param, name)| param.map(|param| ilike(&format!("^{name}"), &param)));
+
+    if any_representation {
+        results.any(|matched| matched)
+    } else {
+        results.all(|matched| matched)
+    }
+}
+
+#[cfg(test)]
+fn in_date_range(range: &DateRange, date: &NaiveDate) -> bool {
+    range.start().is_none_or(|start| start <= date) && range.end().is_none_or(|end| date <= end)
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl DicomObjectRepository for TestDicomObjectRepository {
+    async fn search_patients(
+        &self,
+        condition: &PatientSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Patient>, RepositoryError> {
+        let mut entities = self
+            .inner
+            .read()
+            .await
+            .patients
+            .iter()
+            .filter(|e| {
+                condition
+                    .patient_id
+                    .as_ref()
+                    .is_none_or(|p| like(e.id(), &to_like_pattern(p)))
+                    && condition.patient_name.as_ref().is_none_or(|p| {
+                        person_name_matches(p, e.name_alphabet(), e.name_kanji(), e.name_hiragana())
+                    })
+                    && condition
+                        .patient_birth_date
+                        .is_none_or(|r| e.birth_date().is_some_and(|d| in_date_range(&r, d)))
+                    && condition.patient_sex.is_none_or(|sex| e.sex() == sex)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| a.id().cmp(b.id()));
+        Ok(paginate(entities, offset, limit))
+    }
+
+    async fn search_studies(
+        &self,
+        condition: &StudySearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Study>, RepositoryError> {
+        let patient = &condition.patient;
+        let mut entities = self
+            .inner
+            .read()
+            .await
+            .studies
+            .iter()
+            .filter(|e| {
+                (condition.study_instance_uids.is_empty()
+                    || condition
+                        .study_instance_uids
+                        .iter()
+                        .any(|uid| uid == e.instance_uid()))
+                    && condition
+                        .study_date
+                        .is_none_or(|r| e.study_date().is_some_and(|d| in_date_range(&r, d)))
+                    && condition
+                        .accession_number
+                        .as_ref()
+                        .is_none_or(|p| like(e.accession_number(), &to_like_pattern(p)))
+                    && condition
+                        .study_id
+                        .as_ref()
+                        .is_none_or(|p| like(e.id(), &to_like_pattern(p)))
+                    && (condition.modalities_in_study.is_empty()
+                        || e.modalities()
+                            .iter()
+                            .any(|m| condition.modalities_in_study.contains(m)))
+                    && patient
+                        .patient_id
+                        .as_ref()
+                        .is_none_or(|p| like(e.patient_id(), &to_like_pattern(p)))
+                    && patient.patient_name.as_ref().is_none_or(|p| {
+                        person_name_matches(
+                            p,
+                            e.patient_name_alphabet(),
+                            e.patient_name_kanji(),
+                            e.patient_name_hiragana(),
+                        )
+                    })
+                    && patient.patient_birth_date.is_none_or(|r| {
+                        e.patient_birth_date().is_some_and(|d| in_date_range(&r, d))
+                    })
+                    && patient.patient_sex.is_none_or(|sex| e.patient_sex() == sex)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| {
+            (b.study_date(), b.study_time())
+                .cmp(&(a.study_date(), a.study_time()))
+                .then_with(|| a.instance_uid().cmp(b.instance_uid()))
+        });
+        Ok(paginate(entities, offset, limit))
+    }
+
+    async fn search_series(
+        &self,
+        condition: &SeriesSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Series>, RepositoryError> {
+        let mut entities = self
+            .inner
+            .read()
+            .await
+            .series
+            .iter()
+            .filter(|e| {
+                e.study_instance_uid() == condition.study_instance_uid
+                    && (condition.series_instance_uids.is_empty()
+                        || condition
+                            .series_instance_uids
+                            .iter()
+                            .any(|uid| uid == e.instance_uid()))
+                    && condition
+                        .modality
+                        .as_ref()
+                        .is_none_or(|p| like(e.modality(), &to_like_pattern(p)))
+                    && condition
+                        .series_number
+                        .is_none_or(|n| e.series_number() == Some(n))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| {
+            (
+                a.series_number().is_none(),
+                a.series_number(),
+                a.instance_uid(),
+            )
+                .cmp(&(
+                    b.series_number().is_none(),
+                    b.series_number(),
+                    b.instance_uid(),
+                ))
+        });
+        Ok(paginate(entities, offset, limit))
+    }
+
+    async fn search_sop_instances(
+        &self,
+        condition: &SopInstanceSearchCondition,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<SopInstance>, RepositoryError> {
+        let entities = self
+            .inner
+            .read()
+            .await
+            .sop_instances
+            .iter()
+            .filter(|e| {
+                e.study_instance_uid() == condition.study_instance_uid
+                    && e.series_instance_uid() == condition.series_instance_uid
+                    && (condition.sop_instance_uids.is_empty()
+                        || condition
+                            .sop_instance_uids
+                            .iter()
+                            .any(|uid| uid == e.instance_uid()))
+                    && (condition.sop_class_uids.is_empty()
+                        || condition
+                            .sop_class_uids
+                            .iter()
+                            .any(|uid| uid == e.class_uid()))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        Ok(paginate(entities, offset, limit))
+    }
+
+    async fn find_sop_instance_files(
+        &self,
+        study_instance_uid: &str,
+        series_instance_uid: Option<&str>,
+        sop_instance_uid: Option<&str>,
+    ) -> Result<Vec<SopInstanceFile>, RepositoryError> {
+        Ok(self
+            .inner
+            .read()
+            .await
+            .sop_instance_files
+            .iter()
+            .filter(|e| {
+                e.study_instance_uid() == study_instance_uid
+                    && series_instance_uid.is_none_or(|uid| e.series_instance_uid() == uid)
+                    && sop_instance_uid.is_none_or(|uid| e.instance_uid() == uid)
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_person_name_params() {
+        // 正常系: 表記を区切らない場合はすべての表記に同じパターンを指定し、いずれかの一致でよい
+        {
+            // Arrange
+            let pattern = PersonNamePattern::new("YAMADA*", false).unwrap();
+
+            // Act
+            let actual = to_person_name_params(Some(&pattern));
+
+            // Assert
+            assert_eq!(
+                actual,
+                (
+                    Some("^YAMADA%".to_string()),
+                    Some("^YAMADA%".to_string()),
+                    Some("^YAMADA%".to_string()),
+                    true
+                )
+            );
+        }
+
+        // 正常系: 表記を区切った場合は指定した表記のみを照合し、すべての一致が必要
+        {
+            // Arrange
+            let pattern = PersonNamePattern::new("=山田?太郎", false).unwrap();
+
+            // Act
+            let actual = to_person_name_params(Some(&pattern));
+
+            // Assert
+            assert_eq!(actual, (None, Some("^山田_太郎".to_string()), None, false));
+        }
+
+        // 正常系: あいまい照合では構成要素の先頭からの前方一致とし、LIKE演算子の特殊文字はエスケープする
+        {
+            // Arrange
+            let pattern = PersonNamePattern::new("TA%RO", true).unwrap();
+
+            // Act
+            let actual = to_person_name_params(Some(&pattern));
+
+            // Assert
+            assert_eq!(actual.0, Some(r"%^TA\%RO%".to_string()));
+        }
+
+        // 正常系: 条件を指定しない場合
+        {
+            // Act
+            let actual = to_person_name_params(None);
+
+            // Assert
+            assert_eq!(actual, (None, None, None, false));
+        }
+    }
+
+    #[test]
+    fn test_like() {
+        // 正常系: ワイルドカードを変換したパターンで照合する
+        {
+            let pattern = to_like_pattern(&MatchingPattern::new("CT*").unwrap());
+            assert!(like("CT", &pattern));
+            assert!(like("CTA", &pattern));
+            assert!(!like("MR", &pattern));
+
+            let pattern = to_like_pattern(&MatchingPattern::new("P?1*9").unwrap());
+            assert!(like("PA1239", &pattern));
+            assert!(like("PA19", &pattern));
+            assert!(!like("P19", &pattern));
+            assert!(!like("PA1", &pattern));
+        }
+
+        // 正常系: エスケープしたLIKE演算子の特殊文字は文字そのものと照合する
+        {
+            let pattern = to_like_pattern(&MatchingPattern::new(r"100%_\").unwrap());
+            assert!(like(r"100%_\", &pattern));
+            assert!(!like(r"100%%\", &pattern));
+            assert!(!like(r"1000_\", &pattern));
+        }
+
+        // 正常系: ILIKE演算子は大文字・小文字を区別しない
+        {
+            let pattern = to_like_pattern(&MatchingPattern::new("Yamada*").unwrap());
+            assert!(!like("YAMADA^TARO", &pattern));
+            assert!(ilike("YAMADA^TARO", &pattern));
+        }
+    }
+
+    #[test]
+    fn test_person_name_matches() {
+        // 正常系: 表記を区切らない場合はいずれかの表記が一致すればよい
+        {
+            let pattern = PersonNamePattern::new("山田*", false).unwrap();
+            assert!(person_name_matches(
+                &pattern,
+                "YAMADA^TARO",
+                "山田^太郎",
+                "やまだ^たろう"
+            ));
+            assert!(!person_name_matches(
+                &pattern,
+                "YAMADA^TARO",
+                "山本^太郎",
+                "やまだ^たろう"
+            ));
+        }
+
+        // 正常系: 表記を区切った場合は指定したすべての表記が一致する必要がある
+        {
+            let pattern = PersonNamePattern::new("yamada*=山田*", false).unwrap();
+            assert!(person_name_matches(
+                &pattern,
+                "YAMADA^TARO",
+                "山田^太郎",
+                ""
+            ));
+            assert!(!person_name_matches(
+                &pattern,
+                "YAMADA^TARO",
+                "山本^太郎",
+                ""
+            ));
+        }
+
+        // 正常系: あいまい照合では構成要素の先頭に一致すればよい
+        {
+            let pattern = PersonNamePattern::new("TARO", true).unwrap();
+            assert!(person_name_matches(&pattern, "YAMADA^TARO", "", ""));
+            assert!(!person_name_matches(&pattern, "YAMADA^KENTARO", "", ""));
+
+            let pattern = PersonNamePattern::new("TARO", false).unwrap();
+            assert!(!person_name_matches(&pattern, "YAMADA^TARO", "", ""));
+        }
+    }
+}
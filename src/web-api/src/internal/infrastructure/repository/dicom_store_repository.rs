@@ -0,0 +1,274 @@
+use super::dicom_file_repository::{IMPLEMENTATION_CLASS_UID, IMPLEMENTATION_VERSION_NAME};
+use crate::internal::domain::{
+    entity::StoreResult, error::RepositoryError, repository::DicomStoreRepository,
+};
+use dicom_lib::{
+    core::{
+        DataSet, Tag,
+        value::value_representations::{ae::AeValue, sh::ShValue, ui::UiValue},
+    },
+    file::file_meta_information::FileMetaInformation,
+};
+use ingest::{
+    DuplicatePolicy, StoreContext,
+    dicom_file::{DicomFile, find_string},
+};
+use sqlx::{Pool, Postgres};
+use storage::StorageConfig;
+use tracing::warn;
+use uuid::Uuid;
+
+/// データセットを解釈できない（Cannot understand）
+const CANNOT_UNDERSTAND: u16 = 0xc000;
+/// 検査インスタンスUIDがリクエストのURLで指定した値と一致しない（実装固有のステータス）
+const STUDY_INSTANCE_UID_MISMATCH: u16 = 0xa911;
+
+/// DICOMサーバーと共通の保存処理（[`ingest::store`]）でSOPインスタンスを保存するリポジトリ
+///
+/// 起動時に指定したAEタイトルを宛先AEとして保存する。
+/// AEタイトルが`local_application_entities`テーブルに存在する場合はその保存先ディレクトリと重複の処理方針を、
+/// 存在しない場合はDICOMサーバーの既定の設定（データディレクトリ直下の`dicom`ディレクトリと起動時に指定した処理方針）を使用する。
+pub struct StorageDicomStoreRepository {
+    pool: Pool<Postgres>,
+    storage: StorageConfig,
+    ae_title: String,
+    default_duplicate_policy: DuplicatePolicy,
+}
+
+impl StorageDicomStoreRepository {
+    pub fn new(
+        pool: Pool<Postgres>,
+        storage: StorageConfig,
+        ae_title: impl Into<String>,
+        default_duplicate_policy: DuplicatePolicy,
+    ) -> Self {
+        Self {
+            pool,
+            storage,
+            ae_title: ae_title.into(),
+            default_duplicate_policy,
+        }
+    }
+
+    /// 宛先AEの保存先ディレクトリと重複したSOPインスタンスの処理方針を取得する。
+    async fn find_local_application_entity(
+        &self,
+    ) -> Result<(String, DuplicatePolicy), RepositoryError> {
+        let record = sqlx::query!(
+            "SELECT storage_dir, duplicate_policy FROM local_application_entities WHERE title = $1",
+            self.ae_title
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("宛先AEの取得に失敗しました: {e}"),
+        })?;
+
+        match record {
+            Some(record) => Ok((
+                record.storage_dir,
+                DuplicatePolicy::try_from(record.duplicate_policy)
+                    .map_err(|message| RepositoryError::Other { message })?,
+            )),
+            None => Ok(("dicom".to_string(), self.default_duplicate_policy)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DicomStoreRepository for StorageDicomStoreRepository {
+    async fn store(
+        &self,
+        buf: Vec<u8>,
+        study_instance_uid: Option<&str>,
+        created_by: &Uuid,
+    ) -> Result<StoreResult, RepositoryError> {
+        let received = match ReceivedFile::read(&buf, study_instance_uid) {
+            Ok(val) => val,
+            Err(result) => return Ok(result),
+        };
+
+        let (storage_dir, duplicate_policy) = self.find_local_application_entity().await?;
+        let storage = self.storage.open(&storage_dir);
+        let file_meta_info = FileMetaInformation::new(
+            UiValue::from_string(&received.sop_class_uid).unwrap(),
+            UiValue::from_string(&received.sop_instance_uid).unwrap(),
+            UiValue::from_string(&received.transfer_syntax_uid).unwrap(),
+            UiValue::from_string(IMPLEMENTATION_CLASS_UID).unwrap(),
+            Some(ShValue::from_string(IMPLEMENTATION_VERSION_NAME).unwrap()),
+            None,
+            None,
+            AeValue::from_string(&self.ae_title).ok(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let context = StoreContext {
+            db_pool: &self.pool,
+            storage: storage.as_ref(),
+            called_ae_title: &self.ae_title,
+            calling_ae_title: "",
+            created_by: *created_by,
+            duplicate_policy,
+            coercion_rules: &[],
+            log_prefix: "STOW-RS",
+        };
+        let status = ingest::store(
+            &context,
+            file_meta_info,
+            received.data_set,
+            &received.transfer_syntax_uid,
+            &received.sop_instance_uid,
+        )
+        .await
+        .map_err(|message| RepositoryError::Other { message })?;
+
+        Ok(StoreResult::construct(
+            received.sop_class_uid,
+            received.sop_instance_uid,
+            received.study_instance_uid,
+            received.series_instance_uid,
+            status.into(),
+        ))
+    }
+}
+
+/// 受け付けたDICOMファイル
+struct ReceivedFile {
+    sop_class_uid: String,
+    sop_instance_uid: String,
+    study_instance_uid: String,
+    series_instance_uid: String,
+    transfer_syntax_uid: String,
+    data_set: DataSet,
+}
+
+impl ReceivedFile {
+    /// DICOMファイルを読み込む。
+    ///
+    /// 読み込めない場合や、検査インスタンスUIDが`study_instance_uid`と一致しない場合は、失敗を表す保存結果を返す。
+    fn read(buf: &[u8], study_instance_uid: Option<&str>) -> Result<Self, StoreResult> {
+        let dicom_file = DicomFile::read(buf).map_err(|e| {
+            warn!("STOW-RS - DICOMファイルの読み込みに失敗しました: {e}");
+            StoreResult::failure("", "", CANNOT_UNDERSTAND)
+        })?;
+
+        // ファイルメタ情報のSOPクラスUIDとSOPインスタンスUIDを保存結果に用いる
+        // ファイルメタ情報に含まれない場合はデータセットの値を用いる
+        let DicomFile {
+            sop_class_uid,
+            sop_instance_uid,
+            transfer_syntax_uid,
+            data_set,
+            ..
+        } = dicom_file;
+        let sop_class_uid = sop_class_uid
+            .or_else(|| find_string(&data_set, Tag(0x0008, 0x0016)))
+            .unwrap_or_default();
+        let sop_instance_uid = sop_instance_uid
+            .or_else(|| find_string(&data_set, Tag(0x0008, 0x0018)))
+            .unwrap_or_default();
+        if [&sop_class_uid, &sop_instance_uid, &transfer_syntax_uid]
+            .iter()
+            .any(|uid| uid.is_empty() || UiValue::from_string(uid).is_err())
+        {
+            warn!(
+                "STOW-RS - ファイルメタ情報のUIDが不正です (SOPクラスUID=\"{sop_class_uid}\", SOPインスタンスUID=\"{sop_instance_uid}\", 転送構文UID=\"{transfer_syntax_uid}\")"
+            );
+            return Err(StoreResult::failure(
+                sop_class_uid,
+                sop_instance_uid,
+                CANNOT_UNDERSTAND,
+            ));
+        }
+
+        let actual_study_instance_uid =
+            find_string(&data_set, Tag(0x0020, 0x000d)).unwrap_or_default();
+        if let Some(study_instance_uid) = study_instance_uid
+            && actual_study_instance_uid != study_instance_uid
+        {
+            warn!(
+                "STOW-RS - 検査インスタンスUIDが指定した値と一致しないため保存しませんでした (SOPインスタンスUID=\"{sop_instance_uid}\", 指定した値=\"{study_instance_uid}\", 検査インスタンスUID=\"{actual_study_instance_uid}\")"
+            );
+            return Err(StoreResult::failure(
+                sop_class_uid,
+                sop_instance_uid,
+                STUDY_INSTANCE_UID_MISMATCH,
+            ));
+        }
+
+        Ok(Self {
+            sop_class_uid,
+            sop_instance_uid,
+            study_instance_uid: actual_study_instance_uid,
+            series_instance_uid: find_string(&data_set, Tag(0x0020, 0x000e)).unwrap_or_default(),
+            transfer_syntax_uid,
+            data_set,
+        })
+    }
+}
+
+#[cfg(test)]
+use std::{collections::HashSet, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestDicomStoreRepository {
+    /// 保存したSOPインスタンスのUID
+    inner: Arc<RwLock<HashSet<String>>>,
+}
+
+#[cfg(test)]
+impl TestDicomStoreRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(HashSet::new())),
+        }
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl DicomStoreRepository for TestDicomStoreRepository {
+    async fn store(
+        &self,
+        buf: Vec<u8>,
+        study_instance_uid: Option<&str>,
+        _created_by: &Uuid,
+    ) -> Result<StoreResult, RepositoryError> {
+        let received = match ReceivedFile::read(&buf, study_instance_uid) {
+            Ok(val) => val,
+            Err(result) => return Ok(result),
+        };
+
+        // 保存済みのSOPインスタンスは、内容が同一のSOPインスタンスを保存済みの警告とする
+        let status = if self
+            .inner
+            .write()
+            .await
+            .insert(received.sop_instance_uid.clone())
+        {
+            0x0000
+        } else {
+            0xb010
+        };
+
+        Ok(StoreResult::construct(
+            received.sop_class_uid,
+            received.sop_instance_uid,
+            received.study_instance_uid,
+            received.series_instance_uid,
+            status,
+        ))
+    }
+}
@@ -0,0 +1,413 @@
+use crate::internal::domain::{
+    entity::{ExternalIdentity, OidcAuthorizationRequest},
+    error::IdentityProviderError,
+    repository::IdentityProviderRepository,
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64URL};
+use chrono::Utc;
+use rsa::{BigUint, Pkcs1v15Sign, RsaPublicKey};
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use tokio::sync::RwLock;
+use url::{Url, form_urlencoded};
+
+/// IDトークンの有効期限の検証で許容する時刻のずれ（秒）
+const CLOCK_SKEW_SECONDS: i64 = 60;
+
+/// IdPへのリクエストのタイムアウト
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OpenID ConnectのIdPとクライアントの設定
+#[derive(Debug, Clone)]
+pub struct OidcConfig {
+    /// 発行者URL（`{発行者URL}/.well-known/openid-configuration`からIdPの設定を取得する）
+    pub issuer_url: String,
+    /// クライアントID
+    pub client_id: String,
+    /// クライアントシークレット（パブリッククライアントの場合は`None`）
+    pub client_secret: Option<String>,
+    /// IdPに登録したリダイレクトURI
+    pub redirect_uri: String,
+    /// 要求するスコープ（空白区切り）
+    pub scopes: String,
+    /// ユーザーIDを含むクレーム
+    pub user_id_claim: String,
+    /// 名前を含むクレーム
+    pub name_claim: String,
+    /// 所属するグループを含むクレーム
+    pub groups_claim: String,
+}
+
+/// IdPの設定 (OpenID Connect Discovery 1.0)
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+/// IDトークンの署名の検証に用いる公開鍵 (RFC 7517)
+#[derive(Debug, Clone, Deserialize)]
+struct JsonWebKey {
+    kty: String,
+    kid: Option<String>,
+    #[serde(rename = "use")]
+    key_use: Option<String>,
+    n: Option<String>,
+    e: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonWebKeySet {
+    keys: Vec<JsonWebKey>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JwtHeader {
+    alg: String,
+    kid: Option<String>,
+}
+
+/// OpenID ConnectのIdP
+///
+/// 認可コードフローとPKCEで認証し、トークンエンドポイントから受け取ったIDトークンのRS256の署名とクレームを検証する。
+/// IdPの設定と公開鍵は初回の使用時に取得してキャッシュし、IDトークンの鍵IDに対応する公開鍵がない場合は公開鍵を取得し直す。
+pub struct OidcIdentityProviderRepository {
+    config: OidcConfig,
+    client: reqwest::Client,
+    metadata: RwLock<Option<ProviderMetadata>>,
+    keys: RwLock<Vec<JsonWebKey>>,
+}
+
+impl OidcIdentityProviderRepository {
+    pub fn new(config: OidcConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .expect("HTTPクライアントの初期化は成功するはず");
+
+        Self {
+            config,
+            client,
+            metadata: RwLock::new(None),
+            keys: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// IdPの設定を取得する
+    async fn metadata(&self) -> Result<ProviderMetadata, IdentityProviderError> {
+        if let Some(metadata) = self.metadata.read().await.as_ref() {
+            return Ok(metadata.clone());
+        }
+
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            self.config.issuer_url.trim_end_matches('/')
+        );
+        let metadata: ProviderMetadata = self.get_json(&url).await?;
+        // 発行者URLが異なるIdPの設定は使用しない (OpenID Connect Discovery 1.0 4.3)
+        if metadata.issuer.trim_end_matches('/') != self.config.issuer_url.trim_end_matches('/') {
+            return Err(IdentityProviderError::Unavailable {
+                message: format!(
+                    "IdPの設定の発行者URLが一致しません (設定={}, IdP={})",
+                    self.config.issuer_url, metadata.issuer
+                ),
+            });
+        }
+
+        *self.metadata.write().await = Some(metadata.clone());
+        Ok(metadata)
+    }
+
+    /// 鍵IDに対応する公開鍵を取得する
+    async fn verifying_key(
+        &self,
+        jwks_uri: &str,
+        kid: Option<&str>,
+    ) -> Result<RsaPublicKey, IdentityProviderError> {
+        if let Some(key) = Self::find_key(&self.keys.read().await, kid) {
+            return Self::to_public_key(&key);
+        }
+
+        // IdPが鍵を更新した可能性があるため、公開鍵を取得し直す
+        let key_set: JsonWebKeySet = self.get_json(jwks_uri).await?;
+        let key = Self::find_key(&key_set.keys, kid);
+        *self.keys.write().await = key_set.keys;
+
+        match key {
+            Some(key) => Self::to_public_key(&key),
+            None => Err(IdentityProviderError::Unauthenticated {
+                message: format!(
+                    "IDトークンの署名の検証に用いる公開鍵が見つかりません (鍵ID={})",
+                    kid.unwrap_or("なし")
+                ),
+            }),
+        }
+    }
+
+    /// 署名用のRSAの公開鍵から、鍵IDが一致するものを探す。
+    /// 鍵IDが指定されていない場合は、公開鍵が1つだけのときにその公開鍵を返す。
+    fn find_key(keys: &[JsonWebKey], kid: Option<&str>) -> Option<JsonWebKey> {
+        let mut candidates = keys
+            .iter()
+            .filter(|key| key.kty == "RSA" && key.key_use.as_deref().is_none_or(|u| u == "sig"));
+        match kid {
+            Some(kid) => candidates
+                .find(|key| key.kid.as_deref() == Some(kid))
+                .cloned(),
+            None => {
+                let key = candidates.next()?;
+                candidates.next().is_none().then(|| key.clone())
+            }
+        }
+    }
+
+    fn to_public_key(key: &JsonWebKey) -> Result<RsaPublicKey, IdentityProviderError> {
+        let decode = |value: Option<&String>| {
+            value
+                .and_then(|value| BASE64URL.decode(value).ok())
+                .map(|bytes| BigUint::from_bytes_be(&bytes))
+        };
+        let (Some(n), Some(e)) = (decode(key.n.as_ref()), decode(key.e.as_ref())) else {
+            return Err(IdentityProviderError::Unavailable {
+                message: "IdPの公開鍵の形式が不正です".to_string(),
+            });
+        };
+        RsaPublicKey::new(n, e).map_err(|e| IdentityProviderError::Unavailable {
+            message: format!("IdPの公開鍵の形式が不正です: {e}"),
+        })
+    }
+
+    /// IDトークンの署名とクレームを検証し、クレームを返す (OpenID Connect Core 1.0 3.1.3.7)
+    async fn verify_id_token(
+        &self,
+        id_token: &str,
+        metadata: &ProviderMetadata,
+        nonce: &str,
+    ) -> Result<serde_json::Map<String, Value>, IdentityProviderError> {
+        let unauthenticated = |message: &str| IdentityProviderError::Unauthenticated {
+            message: message.to_string(),
+        };
+
+        let [encoded_header, encoded_payload, encoded_signature] =
+            id_token.split('.').collect::<Vec<_>>()[..]
+        else {
+            return Err(unauthenticated("IDトークンの形式が不正です"));
+        };
+        let header: JwtHeader = BASE64URL
+            .decode(encoded_header)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| unauthenticated("IDトークンのヘッダーの形式が不正です"))?;
+        let claims: serde_json::Map<String, Value> = BASE64URL
+            .decode(encoded_payload)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| unauthenticated("IDトークンのペイロードの形式が不正です"))?;
+        let signature = BASE64URL
+            .decode(encoded_signature)
+            .map_err(|_| unauthenticated("IDトークンの署名の形式が不正です"))?;
+
+        // 署名
+        if header.alg != "RS256" {
+            return Err(IdentityProviderError::Unauthenticated {
+                message: format!(
+                    "IDトークンの署名アルゴリズムに対応していません: {}",
+                    header.alg
+                ),
+            });
+        }
+        let key = self
+            .verifying_key(&metadata.jwks_uri, header.kid.as_deref())
+            .await?;
+        let hashed = Sha256::digest(format!("{encoded_header}.{encoded_payload}").as_bytes());
+        key.verify(Pkcs1v15Sign::new::<Sha256>(), &hashed, &signature)
+            .map_err(|_| unauthenticated("IDトークンの署名が不正です"))?;
+
+        // 発行者
+        if claims.get("iss").and_then(Value::as_str) != Some(metadata.issuer.as_str()) {
+            return Err(unauthenticated("IDトークンの発行者が一致しません"));
+        }
+
+        // 対象者
+        let client_id = self.config.client_id.as_str();
+        let audience_matches = match claims.get("aud") {
+            Some(Value::String(aud)) => aud == client_id,
+            Some(Value::Array(auds)) => auds.iter().any(|aud| aud.as_str() == Some(client_id)),
+            _ => false,
+        };
+        if !audience_matches {
+            return Err(unauthenticated("IDトークンの対象者が一致しません"));
+        }
+        if let Some(azp) = claims.get("azp").and_then(Value::as_str)
+            && azp != client_id
+        {
+            return Err(unauthenticated(
+                "IDトークンの認可された対象者が一致しません",
+            ));
+        }
+
+        // 有効期限
+        let expires_at = claims
+            .get("exp")
+            .and_then(Value::as_i64)
+            .ok_or_else(|| unauthenticated("IDトークンに有効期限がありません"))?;
+        if expires_at + CLOCK_SKEW_SECONDS <= Utc::now().timestamp() {
+            return Err(unauthenticated("IDトークンの有効期限が切れています"));
+        }
+
+        // nonce
+        if claims.get("nonce").and_then(Value::as_str) != Some(nonce) {
+            return Err(unauthenticated("IDトークンのnonceが一致しません"));
+        }
+
+        Ok(claims)
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, IdentityProviderError> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| IdentityProviderError::Unavailable {
+                message: format!("{url} の取得に失敗しました: {e}"),
+            })?;
+        response
+            .json()
+            .await
+            .map_err(|e| IdentityProviderError::Unavailable {
+                message: format!("{url} の形式が不正です: {e}"),
+            })
+    }
+}
+
+#[async_trait::async_trait]
+impl IdentityProviderRepository for OidcIdentityProviderRepository {
+    async fn issuer(&self) -> Result<String, IdentityProviderError> {
+        Ok(self.metadata().await?.issuer)
+    }
+
+    async fn authorization_url(
+        &self,
+        request: &OidcAuthorizationRequest,
+    ) -> Result<String, IdentityProviderError> {
+        let metadata = self.metadata().await?;
+        let url = Url::parse_with_params(
+            &metadata.authorization_endpoint,
+            [
+                ("response_type", "code"),
+                ("client_id", &self.config.client_id),
+                ("redirect_uri", &self.config.redirect_uri),
+                ("scope", &self.config.scopes),
+                ("state", request.state()),
+                ("nonce", request.nonce()),
+                ("code_challenge", &request.code_challenge()),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map_err(|e| IdentityProviderError::Unavailable {
+            message: format!("IdPの認可エンドポイントのURLが不正です: {e}"),
+        })?;
+
+        Ok(url.to_string())
+    }
+
+    async fn exchange_code(
+        &self,
+        code: &str,
+        request: &OidcAuthorizationRequest,
+    ) -> Result<ExternalIdentity, IdentityProviderError> {
+        let metadata = self.metadata().await?;
+
+        // 認可コードをIDトークンと交換する
+        let mut token_request = self.client.post(&metadata.token_endpoint).form(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &self.config.redirect_uri),
+            ("client_id", &self.config.client_id),
+            ("code_verifier", request.code_verifier()),
+        ]);
+        if let Some(client_secret) = &self.config.client_secret {
+            // client_secret_basic: クライアントIDとシークレットはURLエンコードしてから連結する (RFC 6749 2.3.1)
+            token_request = token_request.basic_auth(
+                form_urlencoded::byte_serialize(self.config.client_id.as_bytes())
+                    .collect::<String>(),
+                Some(form_urlencoded::byte_serialize(client_secret.as_bytes()).collect::<String>()),
+            );
+        }
+        let response =
+            token_request
+                .send()
+                .await
+                .map_err(|e| IdentityProviderError::Unavailable {
+                    message: format!("トークンエンドポイントへのリクエストに失敗しました: {e}"),
+                })?;
+        let status = response.status();
+        if status.is_client_error() {
+            // 認可コードの期限切れや再使用、code_verifierの不一致など
+            let body = response.text().await.unwrap_or_default();
+            return Err(IdentityProviderError::Unauthenticated {
+                message: format!(
+                    "トークンエンドポイントが認可コードを受け付けませんでした (ステータスコード={status}): {body}"
+                ),
+            });
+        }
+        if !status.is_success() {
+            return Err(IdentityProviderError::Unavailable {
+                message: format!(
+                    "トークンエンドポイントがエラーを返しました (ステータスコード={status})"
+                ),
+            });
+        }
+        let token_response: TokenResponse =
+            response
+                .json()
+                .await
+                .map_err(|e| IdentityProviderError::Unavailable {
+                    message: format!("トークンエンドポイントの応答の形式が不正です: {e}"),
+                })?;
+
+        // IDトークンを検証する
+        let claims = self
+            .verify_id_token(&token_response.id_token, &metadata, request.nonce())
+            .await?;
+
+        let string_claim = |name: &str| {
+            claims
+                .get(name)
+                .and_then(Value::as_str)
+                .map(|value| value.to_string())
+        };
+        let subject =
+            string_claim("sub").ok_or_else(|| IdentityProviderError::Unauthenticated {
+                message: "IDトークンにサブジェクトがありません".to_string(),
+            })?;
+        // グループは単一の文字列または文字列の配列で表される
+        let groups = match claims.get(&self.config.groups_claim) {
+            Some(Value::String(group)) => vec![group.clone()],
+            Some(Value::Array(groups)) => groups
+                .iter()
+                .filter_map(|group| group.as_str().map(|group| group.to_string()))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Ok(ExternalIdentity::construct(
+            metadata.issuer,
+            subject,
+            string_claim(&self.config.user_id_claim),
+            string_claim(&self.config.name_claim),
+            groups,
+        ))
+    }
+}
@@ -0,0 +1,69 @@
+use crate::internal::domain::value_object::MatchingPattern;
+
+/// 照合パターンをLIKE演算子のパターンに変換する。
+pub fn to_like_pattern(pattern: &MatchingPattern) -> String {
+    let mut like_pattern = String::with_capacity(pattern.value().len());
+    for c in pattern.value().chars() {
+        match c {
+            '*' => like_pattern.push('%'),
+            '?' => like_pattern.push('_'),
+            c => push_escaped(&mut like_pattern, c),
+        }
+    }
+    like_pattern
+}
+
+/// 前方一致のLIKE演算子のパターンに変換する。
+pub fn to_prefix_pattern(value: &str) -> String {
+    format!("{}%", escape_like(value))
+}
+
+/// 部分一致のLIKE演算子のパターンに変換する。
+pub fn to_partial_pattern(value: &str) -> String {
+    format!("%{}%", escape_like(value))
+}
+
+/// LIKE演算子の特殊文字をエスケープする。
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        push_escaped(&mut escaped, c);
+    }
+    escaped
+}
+
+fn push_escaped(buf: &mut String, c: char) {
+    if matches!(c, '\\' | '%' | '_') {
+        buf.push('\\');
+    }
+    buf.push(c);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_like_pattern() {
+        // Arrange
+        let pattern = MatchingPattern::new(r"A*B?C%D_E\F").unwrap();
+
+        // Act
+        let actual = to_like_pattern(&pattern);
+
+        // Assert
+        assert_eq!(actual, r"A%B_C\%D\_E\\F");
+    }
+
+    #[test]
+    fn test_to_prefix_pattern() {
+        assert_eq!(to_prefix_pattern("P*1"), "P*1%");
+        assert_eq!(to_prefix_pattern(r"P%1_\"), r"P\%1\_\\%");
+    }
+
+    #[test]
+    fn test_to_partial_pattern() {
+        assert_eq!(to_partial_pattern("YAMADA"), "%YAMADA%");
+        assert_eq!(to_partial_pattern("100%"), r"%100\%%");
+    }
+}
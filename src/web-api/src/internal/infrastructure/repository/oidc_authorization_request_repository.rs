@@ -0,0 +1,146 @@
+use crate::internal::domain::{
+    entity::OidcAuthorizationRequest, error::RepositoryError,
+    repository::OidcAuthorizationRequestRepository,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+
+#[derive(FromRow)]
+struct OidcAuthorizationRequestRecord {
+    state: String,
+    nonce: String,
+    code_verifier: String,
+    redirect_to: String,
+    created_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<OidcAuthorizationRequestRecord> for OidcAuthorizationRequest {
+    fn from(record: OidcAuthorizationRequestRecord) -> Self {
+        OidcAuthorizationRequest::construct(
+            record.state,
+            record.nonce,
+            record.code_verifier,
+            record.redirect_to,
+            record.created_at,
+            record.expires_at,
+        )
+    }
+}
+
+/// 認可リクエストをDBに保存するリポジトリ
+///
+/// 認可エンドポイントへのリダイレクトと認可コードの受け取りを異なるWeb APIが処理しても、認可リクエストを共有できる。
+pub struct PostgresOidcAuthorizationRequestRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresOidcAuthorizationRequestRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl OidcAuthorizationRequestRepository for PostgresOidcAuthorizationRequestRepository {
+    async fn save(&self, request: &OidcAuthorizationRequest) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            "INSERT INTO oidc_authorization_requests (state, nonce, code_verifier, redirect_to, created_at, expires_at)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+            request.state(),
+            request.nonce(),
+            request.code_verifier(),
+            request.redirect_to(),
+            request.created_at(),
+            request.expires_at()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(())
+    }
+
+    async fn take(&self, state: &str) -> Result<Option<OidcAuthorizationRequest>, RepositoryError> {
+        // 削除と取得を1つのクエリで行い、同じ認可リクエストを並行して使用できないようにする
+        let record = sqlx::query_as!(
+            OidcAuthorizationRequestRecord,
+            "DELETE FROM oidc_authorization_requests
+             WHERE state = $1
+             RETURNING state, nonce, code_verifier, redirect_to, created_at, expires_at",
+            state
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(record
+            .map(OidcAuthorizationRequest::from)
+            .filter(|request| !request.is_expired()))
+    }
+
+    async fn cleanup_expired_requests(&self) -> Result<u64, RepositoryError> {
+        let rows_affected =
+            sqlx::query!("DELETE FROM oidc_authorization_requests WHERE expires_at <= now()")
+                .execute(&self.pool)
+                .await
+                .map_err(|e| RepositoryError::Other {
+                    message: format!("データベース処理でエラーが発生しました: {e}"),
+                })?
+                .rows_affected();
+
+        Ok(rows_affected)
+    }
+}
+
+#[cfg(test)]
+use std::{collections::HashMap, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestOidcAuthorizationRequestRepository {
+    /// stateをキーとする認可リクエスト
+    inner: Arc<RwLock<HashMap<String, OidcAuthorizationRequest>>>,
+}
+
+#[cfg(test)]
+impl TestOidcAuthorizationRequestRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl OidcAuthorizationRequestRepository for TestOidcAuthorizationRequestRepository {
+    async fn save(&self, request: &OidcAuthorizationRequest) -> Result<(), RepositoryError> {
+        self.inner
+            .write()
+            .await
+            .insert(request.state().to_string(), request.clone());
+        Ok(())
+    }
+
+    async fn take(&self, state: &str) -> Result<Option<OidcAuthorizationRequest>, RepositoryError> {
+        Ok(self
+            .inner
+            .write()
+            .await
+            .remove(state)
+            .filter(|request| !request.is_expired()))
+    }
+
+    async fn cleanup_expired_requests(&self) -> Result<u64, RepositoryError> {
+        let mut requests = self.inner.write().await;
+        let len = requests.len();
+        requests.retain(|_, request| !request.is_expired());
+        Ok((len - requests.len()) as u64)
+    }
+}
@@ -0,0 +1,451 @@
+use crate::internal::domain::{
+    entity::{PatientConflict, PatientDemographics, PatientReconciliation},
+    error::RepositoryError,
+    repository::PatientConflictRepository,
+    value_object::{PatientConflictStatus, ReconciliationAction},
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(FromRow)]
+struct PatientConflictRecord {
+    uuid: Uuid,
+    patient_id: String,
+    study_instance_uid: String,
+    sop_instance_uid: String,
+    registered_name_alphabet: String,
+    registered_name_kanji: String,
+    registered_name_hiragana: String,
+    registered_birth_date: Option<NaiveDate>,
+    registered_sex: i16,
+    name_alphabet: String,
+    name_kanji: String,
+    name_hiragana: String,
+    birth_date: Option<NaiveDate>,
+    sex: i16,
+    status: i16,
+    resolved_by: Option<Uuid>,
+    resolved_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<PatientConflictRecord> for PatientConflict {
+    type Error = String;
+
+    fn try_from(record: PatientConflictRecord) -> Result<Self, Self::Error> {
+        let status = PatientConflictStatus::from_i16(record.status)?;
+        Ok(PatientConflict::construct(
+            record.uuid,
+            record.patient_id,
+            record.study_instance_uid,
+            record.sop_instance_uid,
+            PatientDemographics::construct(
+                record.registered_name_alphabet,
+                record.registered_name_kanji,
+                record.registered_name_hiragana,
+                record.registered_birth_date,
+                record.registered_sex,
+            ),
+            PatientDemographics::construct(
+                record.name_alphabet,
+                record.name_kanji,
+                record.name_hiragana,
+                record.birth_date,
+                record.sex,
+            ),
+            status,
+            record.resolved_by,
+            record.resolved_at,
+            record.created_at,
+        ))
+    }
+}
+
+pub struct PostgresPatientConflictRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresPatientConflictRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl PatientConflictRepository for PostgresPatientConflictRepository {
+    async fn find_all(
+        &self,
+        status: Option<PatientConflictStatus>,
+    ) -> Result<Vec<PatientConflict>, RepositoryError> {
+        let records = sqlx::query_as!(
+            PatientConflictRecord,
+            r#"SELECT c.uuid, c.patient_id, c.study_instance_uid, c.sop_instance_uid,
+                    p.name_alphabet AS registered_name_alphabet, p.name_kanji AS registered_name_kanji, p.name_hiragana AS registered_name_hiragana,
+                    p.birth_date AS registered_birth_date, p.sex AS registered_sex,
+                    c.name_alphabet, c.name_kanji, c.name_hiragana, c.birth_date, c.sex,
+                    c.status, c.resolved_by, c.resolved_at, c.created_at
+             FROM patient_conflicts c
+             INNER JOIN patients p ON p.id = c.patient_id
+             WHERE ($1::smallint IS NULL OR c.status = $1)
+             ORDER BY c.created_at DESC"#,
+            status.map(|s| s.as_i16()),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let entities = records
+            .into_iter()
+            .map(|r| {
+                r.try_into()
+                    .expect("DBレコードからエンティティへの変換は成功するはず")
+            })
+            .collect::<Vec<_>>();
+        Ok(entities)
+    }
+
+    async fn find_by_uuid(&self, uuid: &Uuid) -> Result<Option<PatientConflict>, RepositoryError> {
+        let record = sqlx::query_as!(
+            PatientConflictRecord,
+            r#"SELECT c.uuid, c.patient_id, c.study_instance_uid, c.sop_instance_uid,
+                    p.name_alphabet AS registered_name_alphabet, p.name_kanji AS registered_name_kanji, p.name_hiragana AS registered_name_hiragana,
+                    p.birth_date AS registered_birth_date, p.sex AS registered_sex,
+                    c.name_alphabet, c.name_kanji, c.name_hiragana, c.birth_date, c.sex,
+                    c.status, c.resolved_by, c.resolved_at, c.created_at
+             FROM patient_conflicts c
+             INNER JOIN patients p ON p.id = c.patient_id
+             WHERE c.uuid = $1"#,
+            uuid,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let entity = record.map(|r| {
+            r.try_into()
+                .expect("DBレコードからエンティティへの変換は成功するはず")
+        });
+        Ok(entity)
+    }
+
+    async fn reconcile(
+        &self,
+        reconciliation: &PatientReconciliation,
+    ) -> Result<Vec<String>, RepositoryError> {
+        let db_error = |e: sqlx::Error| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        };
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("トランザクションの開始に失敗しました: {e}"),
+            })?;
+
+        // 不一致を解決済みにする（並行して解決された場合は対象が存在しない）
+        let rows_affected = sqlx::query!(
+            "UPDATE patient_conflicts
+             SET status = $2, resolved_by = $3, resolved_at = $4
+             WHERE uuid = $1 AND status = 0",
+            reconciliation.patient_conflict_uuid(),
+            reconciliation.action().resolved_status().as_i16(),
+            reconciliation.performed_by(),
+            reconciliation.performed_at(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .rows_affected();
+        if rows_affected == 0 {
+            return Err(RepositoryError::NotFound {
+                resource: "未解決の患者属性の不一致".to_string(),
+                key: reconciliation.patient_conflict_uuid().to_string(),
+            });
+        }
+
+        match reconciliation.action() {
+            ReconciliationAction::Merge => {
+                // 統合先の患者が存在することを確認する
+                let exists = sqlx::query_scalar!(
+                    r#"SELECT EXISTS(SELECT 1 FROM patients WHERE id = $1) AS "exists!""#,
+                    reconciliation.new_patient_id().value(),
+                )
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(db_error)?;
+                if !exists {
+                    return Err(RepositoryError::NotFound {
+                        resource: "患者".to_string(),
+                        key: reconciliation.new_patient_id().value().to_string(),
+                    });
+                }
+            }
+            ReconciliationAction::Split => {
+                // 受信した患者属性で新しい患者を登録する
+                let rows_affected = sqlx::query!(
+                    "INSERT INTO patients (id, name_alphabet, name_kanji, name_hiragana, birth_date, sex, created_by, created_at, updated_by, updated_at)
+                     SELECT $2, name_alphabet, name_kanji, name_hiragana, birth_date, sex, $3, $4, $3, $4
+                     FROM patient_conflicts
+                     WHERE uuid = $1
+                     ON CONFLICT (id) DO NOTHING",
+                    reconciliation.patient_conflict_uuid(),
+                    reconciliation.new_patient_id().value(),
+                    reconciliation.performed_by(),
+                    reconciliation.performed_at(),
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?
+                .rows_affected();
+                if rows_affected == 0 {
+                    return Err(RepositoryError::Conflict {
+                        resource: "患者".to_string(),
+                        field: "ID".to_string(),
+                        value: reconciliation.new_patient_id().value().to_string(),
+                    });
+                }
+            }
+        }
+
+        // 検査の患者IDを付け替える
+        sqlx::query!(
+            "UPDATE studies
+             SET patient_id = $2, updated_by = $3, updated_at = $4
+             WHERE instance_uid = $1 AND patient_id <> $2",
+            reconciliation.study_instance_uid(),
+            reconciliation.new_patient_id().value(),
+            reconciliation.performed_by(),
+            reconciliation.performed_at(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        // 監査記録を登録する
+        sqlx::query!(
+            "INSERT INTO patient_reconciliation_logs (uuid, patient_conflict_uuid, action, study_instance_uid, old_patient_id, new_patient_id, rewrite_files, performed_by, performed_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+            reconciliation.uuid(),
+            reconciliation.patient_conflict_uuid(),
+            reconciliation.action().as_i16(),
+            reconciliation.study_instance_uid(),
+            reconciliation.old_patient_id(),
+            reconciliation.new_patient_id().value(),
+            reconciliation.rewrite_files(),
+            reconciliation.performed_by(),
+            reconciliation.performed_at(),
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        // 検査に属するファイル（旧版として保持しているものを含む）のパスを取得する
+        let paths = sqlx::query_scalar!(
+            r#"SELECT i.path AS "path!"
+             FROM sop_instances i
+             INNER JOIN series s ON s.instance_uid = i.series_instance_uid
+             WHERE s.study_instance_uid = $1
+             UNION
+             SELECT h.path AS "path!"
+             FROM sop_instance_histories h
+             INNER JOIN series s ON s.instance_uid = h.series_instance_uid
+             WHERE s.study_instance_uid = $1
+             ORDER BY 1"#,
+            reconciliation.study_instance_uid(),
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        tx.commit().await.map_err(|e| RepositoryError::Other {
+            message: format!("トランザクションのコミットに失敗しました: {e}"),
+        })?;
+
+        Ok(paths)
+    }
+
+    async fn update_file_rewrite_result(
+        &self,
+        reconciliation: &PatientReconciliation,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            "UPDATE patient_reconciliation_logs
+             SET rewritten_file_count = $2, failed_file_paths = $3
+             WHERE uuid = $1",
+            reconciliation.uuid(),
+            reconciliation.rewritten_file_count(),
+            reconciliation.failed_file_paths(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestPatientConflictRepository {
+    conflicts: Arc<RwLock<HashMap<Uuid, PatientConflict>>>,
+    patient_ids: Arc<RwLock<HashSet<String>>>,
+    study_file_paths: Arc<RwLock<HashMap<String, Vec<String>>>>,
+    reconciliations: Arc<RwLock<HashMap<Uuid, PatientReconciliation>>>,
+}
+
+#[cfg(test)]
+impl TestPatientConflictRepository {
+    pub fn new() -> Self {
+        Self {
+            conflicts: Arc::new(RwLock::new(HashMap::new())),
+            patient_ids: Arc::new(RwLock::new(HashSet::new())),
+            study_file_paths: Arc::new(RwLock::new(HashMap::new())),
+            reconciliations: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// テストデータを登録する。
+    /// 患者属性の不一致はDICOMサーバーが登録するため、Web APIのリポジトリには登録処理が存在しない。
+    pub async fn add(&self, entity: &PatientConflict) {
+        self.patient_ids
+            .write()
+            .await
+            .insert(entity.patient_id().to_string());
+        self.conflicts
+            .write()
+            .await
+            .insert(*entity.uuid(), entity.clone());
+    }
+
+    /// 登録済みの患者IDを追加する。
+    pub async fn add_patient_id(&self, patient_id: &str) {
+        self.patient_ids
+            .write()
+            .await
+            .insert(patient_id.to_string());
+    }
+
+    /// 検査に属するファイルのパスを追加する。
+    pub async fn add_file_path(&self, study_instance_uid: &str, path: &str) {
+        self.study_file_paths
+            .write()
+            .await
+            .entry(study_instance_uid.to_string())
+            .or_default()
+            .push(path.to_string());
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl PatientConflictRepository for TestPatientConflictRepository {
+    async fn find_all(
+        &self,
+        status: Option<PatientConflictStatus>,
+    ) -> Result<Vec<PatientConflict>, RepositoryError> {
+        let mut entities = self
+            .conflicts
+            .read()
+            .await
+            .values()
+            .filter(|e| status.is_none_or(|status| e.status() == status))
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| b.created_at().cmp(a.created_at()));
+        Ok(entities)
+    }
+
+    async fn find_by_uuid(&self, uuid: &Uuid) -> Result<Option<PatientConflict>, RepositoryError> {
+        Ok(self.conflicts.read().await.get(uuid).cloned())
+    }
+
+    async fn reconcile(
+        &self,
+        reconciliation: &PatientReconciliation,
+    ) -> Result<Vec<String>, RepositoryError> {
+        let mut conflicts = self.conflicts.write().await;
+        let mut patient_ids = self.patient_ids.write().await;
+
+        let conflict = conflicts
+            .get_mut(reconciliation.patient_conflict_uuid())
+            .filter(|c| c.status() == PatientConflictStatus::Unresolved)
+            .ok_or_else(|| RepositoryError::NotFound {
+                resource: "未解決の患者属性の不一致".to_string(),
+                key: reconciliation.patient_conflict_uuid().to_string(),
+            })?;
+
+        let new_patient_id = reconciliation.new_patient_id().value().to_string();
+        match reconciliation.action() {
+            ReconciliationAction::Merge => {
+                if !patient_ids.contains(&new_patient_id) {
+                    return Err(RepositoryError::NotFound {
+                        resource: "患者".to_string(),
+                        key: new_patient_id,
+                    });
+                }
+            }
+            ReconciliationAction::Split => {
+                if !patient_ids.insert(new_patient_id.clone()) {
+                    return Err(RepositoryError::Conflict {
+                        resource: "患者".to_string(),
+                        field: "ID".to_string(),
+                        value: new_patient_id,
+                    });
+                }
+            }
+        }
+
+        *conflict = PatientConflict::construct(
+            *conflict.uuid(),
+            conflict.patient_id(),
+            conflict.study_instance_uid(),
+            conflict.sop_instance_uid(),
+            conflict.registered().clone(),
+            conflict.received().clone(),
+            reconciliation.action().resolved_status(),
+            Some(*reconciliation.performed_by()),
+            Some(*reconciliation.performed_at()),
+            *conflict.created_at(),
+        );
+        self.reconciliations
+            .write()
+            .await
+            .insert(*reconciliation.uuid(), reconciliation.clone());
+
+        let paths = self
+            .study_file_paths
+            .read()
+            .await
+            .get(reconciliation.study_instance_uid())
+            .cloned()
+            .unwrap_or_default();
+        Ok(paths)
+    }
+
+    async fn update_file_rewrite_result(
+        &self,
+        reconciliation: &PatientReconciliation,
+    ) -> Result<(), RepositoryError> {
+        self.reconciliations
+            .write()
+            .await
+            .insert(*reconciliation.uuid(), reconciliation.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use crate::internal::domain::{
+    entity::{PerformedProcedureStep, PerformedSeries},
+    error::RepositoryError,
+    repository::PerformedProcedureStepRepository,
+    value_object::ProcedureStepStatus,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use std::collections::HashMap;
+
+#[derive(FromRow)]
+struct PerformedProcedureStepRecord {
+    instance_uid: String,
+    study_instance_uid: String,
+    patient_id: String,
+    accession_number: String,
+    id: String,
+    status: i16,
+    modality: String,
+    description: String,
+    station_ae_title: String,
+    start_date: Option<NaiveDate>,
+    start_time: Option<NaiveTime>,
+    end_date: Option<NaiveDate>,
+    end_time: Option<NaiveTime>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(FromRow)]
+struct PerformedSeriesRecord {
+    performed_procedure_step_instance_uid: String,
+    series_instance_uid: String,
+    description: String,
+    retrieve_ae_title: String,
+    number_of_instances: i32,
+}
+
+impl From<PerformedSeriesRecord> for PerformedSeries {
+    fn from(record: PerformedSeriesRecord) -> Self {
+        PerformedSeries::construct(
+            record.series_instance_uid,
+            record.description,
+            record.retrieve_ae_title,
+            record.number_of_instances,
+        )
+    }
+}
+
+impl TryFrom<(PerformedProcedureStepRecord, Vec<PerformedSeries>)> for PerformedProcedureStep {
+    type Error = String;
+
+    fn try_from(
+        (record, performed_series): (PerformedProcedureStepRecord, Vec<PerformedSeries>),
+    ) -> Result<Self, Self::Error> {
+        let status = ProcedureStepStatus::from_i16(record.status)?;
+        Ok(PerformedProcedureStep::construct(
+            record.instance_uid,
+            record.study_instance_uid,
+            record.patient_id,
+            record.accession_number,
+            record.id,
+            status,
+            record.modality,
+            record.description,
+            record.station_ae_title,
+            record.start_date,
+            record.start_time,
+            record.end_date,
+            record.end_time,
+            performed_series,
+            record.created_at,
+            record.updated_at,
+        ))
+    }
+}
+
+pub struct PostgresPerformedProcedureStepRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresPerformedProcedureStepRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl PerformedProcedureStepRepository for PostgresPerformedProcedureStepRepository {
+    async fn find_all(
+        &self,
+        study_instance_uid: Option<&str>,
+        status: Option<ProcedureStepStatus>,
+    ) -> Result<Vec<PerformedProcedureStep>, RepositoryError> {
+        let records = sqlx::query_as!(
+            PerformedProcedureStepRecord,
+            "SELECT instance_uid, study_instance_uid, patient_id, accession_number, id, status, modality, description, station_ae_title, start_date, start_time, end_date, end_time, created_at, updated_at
+             FROM performed_procedure_steps
+             WHERE ($1::text IS NULL OR study_instance_uid = $1)
+               AND ($2::smallint IS NULL OR status = $2)
+             ORDER BY created_at DESC",
+            study_instance_uid,
+            status.map(|s| s.as_i16()),
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let instance_uids = records
+            .iter()
+            .map(|r| r.instance_uid.clone())
+            .collect::<Vec<_>>();
+        let series_records = sqlx::query_as!(
+            PerformedSeriesRecord,
+            "SELECT performed_procedure_step_instance_uid, series_instance_uid, description, retrieve_ae_title, number_of_instances
+             FROM performed_procedure_step_series
+             WHERE performed_procedure_step_instance_uid = ANY($1)
+             ORDER BY created_at",
+            &instance_uids,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let mut instance_uid_to_series: HashMap<String, Vec<PerformedSeries>> = HashMap::new();
+        for record in series_records {
+            instance_uid_to_series
+                .entry(record.performed_procedure_step_instance_uid.clone())
+                .or_default()
+                .push(record.into());
+        }
+
+        let entities = records
+            .into_iter()
+            .map(|r| {
+                let performed_series = instance_uid_to_series
+                    .remove(&r.instance_uid)
+                    .unwrap_or_default();
+                (r, performed_series)
+                    .try_into()
+                    .expect("DBレコードからエンティティへの変換は成功するはず")
+            })
+            .collect::<Vec<_>>();
+        Ok(entities)
+    }
+}
+
+#[cfg(test)]
+use std::sync::Arc;
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestPerformedProcedureStepRepository {
+    inner: Arc<RwLock<HashMap<String, PerformedProcedureStep>>>,
+}
+
+#[cfg(test)]
+impl TestPerformedProcedureStepRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// テストデータを登録する。
+    /// Performed Procedure StepはDICOMサーバーが登録するため、Web APIのリポジトリには登録処理が存在しない。
+    pub async fn add(&self, entity: &PerformedProcedureStep) {
+        self.inner
+            .write()
+            .await
+            .insert(entity.instance_uid().to_string(), entity.clone());
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl PerformedProcedureStepRepository for TestPerformedProcedureStepRepository {
+    async fn find_all(
+        &self,
+        study_instance_uid: Option<&str>,
+        status: Option<ProcedureStepStatus>,
+    ) -> Result<Vec<PerformedProcedureStep>, RepositoryError> {
+        let mut entities = self
+            .inner
+            .read()
+            .await
+            .values()
+            .filter(|e| study_instance_uid.is_none_or(|uid| e.study_instance_uid() == uid))
+            .filter(|e| status.is_none_or(|status| e.status() == status))
+            .cloned()
+            .collect::<Vec<_>>();
+        entities.sort_by(|a, b| b.created_at().cmp(a.created_at()));
+        Ok(entities)
+    }
+}
@@ -0,0 +1,304 @@
+use crate::internal::domain::{
+    entity::{RetentionCandidate, RetentionLimit, RetentionRule},
+    error::RepositoryError,
+    repository::RetentionRuleRepository,
+};
+use chrono::{DateTime, Utc};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(FromRow)]
+struct RetentionRuleRecord {
+    uuid: Uuid,
+    name: String,
+    called_ae_title: Option<String>,
+    max_age_days: Option<i32>,
+    max_total_size: Option<i64>,
+    created_by: Uuid,
+    created_at: DateTime<Utc>,
+    updated_by: Uuid,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<RetentionRuleRecord> for RetentionRule {
+    type Error = String;
+
+    fn try_from(record: RetentionRuleRecord) -> Result<Self, Self::Error> {
+        let called_ae_title = record
+            .called_ae_title
+            .map(|title| AeValue::from_string(&title))
+            .transpose()
+            .map_err(|e| format!("AEタイトルが不正です: {e}"))?;
+        let limit = match (record.max_age_days, record.max_total_size) {
+            (Some(days), None) => RetentionLimit::MaxAgeDays(days),
+            (None, Some(size)) => RetentionLimit::MaxTotalSize(size),
+            _ => return Err("保存期間の規則の上限が不正です".to_string()),
+        };
+        Ok(RetentionRule::construct(
+            record.uuid,
+            record.name,
+            called_ae_title,
+            limit,
+            record.created_by,
+            record.created_at,
+            record.updated_by,
+            record.updated_at,
+        ))
+    }
+}
+
+/// 上限を`max_age_days`および`max_total_size`の値に変換する。
+fn limit_to_columns(limit: RetentionLimit) -> (Option<i32>, Option<i64>) {
+    match limit {
+        RetentionLimit::MaxAgeDays(days) => (Some(days), None),
+        RetentionLimit::MaxTotalSize(size) => (None, Some(size)),
+    }
+}
+
+pub struct PostgresRetentionRuleRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresRetentionRuleRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl RetentionRuleRepository for PostgresRetentionRuleRepository {
+    async fn find_all(&self) -> Result<Vec<RetentionRule>, RepositoryError> {
+        let records = sqlx::query_as!(
+            RetentionRuleRecord,
+            "SELECT uuid, name, called_ae_title, max_age_days, max_total_size, created_by, created_at, updated_by, updated_at
+             FROM retention_rules
+             ORDER BY created_at, uuid",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let entities = records
+            .into_iter()
+            .map(|r| {
+                r.try_into()
+                    .expect("DBレコードからエンティティへの変換は成功するはず")
+            })
+            .collect::<Vec<_>>();
+        Ok(entities)
+    }
+
+    async fn find_by_uuid(&self, uuid: &Uuid) -> Result<Option<RetentionRule>, RepositoryError> {
+        let record = sqlx::query_as!(
+            RetentionRuleRecord,
+            "SELECT uuid, name, called_ae_title, max_age_days, max_total_size, created_by, created_at, updated_by, updated_at
+             FROM retention_rules
+             WHERE uuid = $1",
+            uuid
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(record.map(|record| {
+            record
+                .try_into()
+                .expect("DBレコードからエンティティへの変換は成功するはず")
+        }))
+    }
+
+    async fn add(&self, entity: &RetentionRule) -> Result<RetentionRule, RepositoryError> {
+        let (max_age_days, max_total_size) = limit_to_columns(entity.limit());
+        let record = sqlx::query_as!(
+            RetentionRuleRecord,
+            "INSERT INTO retention_rules (uuid, name, called_ae_title, max_age_days, max_total_size, created_by, created_at, updated_by, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING uuid, name, called_ae_title, max_age_days, max_total_size, created_by, created_at, updated_by, updated_at",
+            entity.uuid(),
+            entity.name(),
+            entity.called_ae_title().map(|title| title.value()),
+            max_age_days,
+            max_total_size,
+            entity.created_by(),
+            entity.created_at(),
+            entity.updated_by(),
+            entity.updated_at()
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let entity = record
+            .try_into()
+            .expect("DBレコードからエンティティへの変換は成功するはず");
+        Ok(entity)
+    }
+
+    async fn update(&self, entity: &RetentionRule) -> Result<RetentionRule, RepositoryError> {
+        let (max_age_days, max_total_size) = limit_to_columns(entity.limit());
+        let record = sqlx::query_as!(
+            RetentionRuleRecord,
+            "UPDATE retention_rules
+             SET name = $1, called_ae_title = $2, max_age_days = $3, max_total_size = $4, updated_by = $5, updated_at = $6
+             WHERE uuid = $7
+             RETURNING uuid, name, called_ae_title, max_age_days, max_total_size, created_by, created_at, updated_by, updated_at",
+            entity.name(),
+            entity.called_ae_title().map(|title| title.value()),
+            max_age_days,
+            max_total_size,
+            entity.updated_by(),
+            entity.updated_at(),
+            entity.uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        match record {
+            Some(record) => {
+                let entity = record
+                    .try_into()
+                    .expect("DBレコードからエンティティへの変換は成功するはず");
+                Ok(entity)
+            }
+            None => Err(RepositoryError::NotFound {
+                resource: "保存期間の規則".to_string(),
+                key: entity.uuid().to_string(),
+            }),
+        }
+    }
+
+    async fn delete(&self, uuid: &Uuid) -> Result<(), RepositoryError> {
+        let rows_affected = sqlx::query!("DELETE FROM retention_rules WHERE uuid = $1", uuid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("データベース処理でエラーが発生しました: {e}"),
+            })?
+            .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(RepositoryError::NotFound {
+                resource: "保存期間の規則".to_string(),
+                key: uuid.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn find_candidates(
+        &self,
+        now: &DateTime<Utc>,
+    ) -> Result<Vec<RetentionCandidate>, RepositoryError> {
+        let candidates = retention::find_candidates(&self.pool, *now)
+            .await
+            .map_err(|message| RepositoryError::Other { message })?;
+
+        Ok(candidates
+            .into_iter()
+            .map(|candidate| {
+                RetentionCandidate::construct(
+                    candidate.rule_uuid,
+                    candidate.rule_name,
+                    candidate.study.study_instance_uid,
+                    candidate.study.patient_id,
+                    candidate.study.study_date,
+                    candidate.study.last_received_at,
+                    candidate.study.size,
+                    candidate.study.sop_instance_count,
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+use std::sync::Arc;
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestRetentionRuleRepository {
+    inner: Arc<RwLock<Vec<RetentionRule>>>,
+    /// 規則の評価結果として返す検査
+    candidates: Arc<RwLock<Vec<RetentionCandidate>>>,
+}
+
+#[cfg(test)]
+impl TestRetentionRuleRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Vec::new())),
+            candidates: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    pub async fn add_candidate(&self, candidate: RetentionCandidate) {
+        self.candidates.write().await.push(candidate);
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl RetentionRuleRepository for TestRetentionRuleRepository {
+    async fn find_all(&self) -> Result<Vec<RetentionRule>, RepositoryError> {
+        Ok(self.inner.read().await.clone())
+    }
+
+    async fn find_by_uuid(&self, uuid: &Uuid) -> Result<Option<RetentionRule>, RepositoryError> {
+        Ok(self
+            .inner
+            .read()
+            .await
+            .iter()
+            .find(|e| e.uuid() == uuid)
+            .cloned())
+    }
+
+    async fn add(&self, entity: &RetentionRule) -> Result<RetentionRule, RepositoryError> {
+        self.inner.write().await.push(entity.clone());
+        Ok(entity.clone())
+    }
+
+    async fn update(&self, entity: &RetentionRule) -> Result<RetentionRule, RepositoryError> {
+        let mut inner = self.inner.write().await;
+        let Some(existing) = inner.iter_mut().find(|e| e.uuid() == entity.uuid()) else {
+            return Err(RepositoryError::NotFound {
+                resource: "保存期間の規則".to_string(),
+                key: entity.uuid().to_string(),
+            });
+        };
+        *existing = entity.clone();
+        Ok(entity.clone())
+    }
+
+    async fn delete(&self, uuid: &Uuid) -> Result<(), RepositoryError> {
+        let mut inner = self.inner.write().await;
+        let len = inner.len();
+        inner.retain(|e| e.uuid() != uuid);
+        if inner.len() == len {
+            return Err(RepositoryError::NotFound {
+                resource: "保存期間の規則".to_string(),
+                key: uuid.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn find_candidates(
+        &self,
+        _now: &DateTime<Utc>,
+    ) -> Result<Vec<RetentionCandidate>, RepositoryError> {
+        Ok(self.candidates.read().await.clone())
+    }
+}
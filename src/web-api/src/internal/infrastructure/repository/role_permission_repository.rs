@@ -0,0 +1,200 @@
+use crate::internal::domain::{
+    entity::RolePermissions,
+    error::RepositoryError,
+    repository::RolePermissionRepository,
+    value_object::{Permission, Role},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(FromRow)]
+struct RolePermissionRecord {
+    role: i16,
+    permission: i16,
+}
+
+pub struct PostgresRolePermissionRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresRolePermissionRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl RolePermissionRepository for PostgresRolePermissionRepository {
+    async fn find_by_role(&self, role: Role) -> Result<RolePermissions, RepositoryError> {
+        let permissions = sqlx::query_scalar!(
+            "SELECT permission FROM role_permissions WHERE role = $1 ORDER BY permission",
+            role.as_i16()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        let permissions = permissions
+            .into_iter()
+            .map(|permission| {
+                Permission::from_i16(permission)
+                    .expect("DBレコードからエンティティへの変換は成功するはず")
+            })
+            .collect();
+        Ok(RolePermissions::construct(role, permissions))
+    }
+
+    async fn find_all(&self) -> Result<Vec<RolePermissions>, RepositoryError> {
+        let records = sqlx::query_as!(
+            RolePermissionRecord,
+            "SELECT role, permission FROM role_permissions ORDER BY role, permission"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        // 権限が1つもないロールも含めて返す
+        let entities = Role::ALL
+            .into_iter()
+            .map(|role| {
+                let permissions = records
+                    .iter()
+                    .filter(|r| r.role == role.as_i16())
+                    .map(|r| {
+                        Permission::from_i16(r.permission)
+                            .expect("DBレコードからエンティティへの変換は成功するはず")
+                    })
+                    .collect();
+                RolePermissions::construct(role, permissions)
+            })
+            .collect();
+        Ok(entities)
+    }
+
+    async fn replace(
+        &self,
+        role_permissions: &RolePermissions,
+        updated_by: &Uuid,
+        updated_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let db_error = |e: sqlx::Error| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        };
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("トランザクションの開始に失敗しました: {e}"),
+            })?;
+
+        // 既存の権限を削除し、指定した権限を登録する
+        let role = role_permissions.role().as_i16();
+        sqlx::query!("DELETE FROM role_permissions WHERE role = $1", role)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+
+        for permission in role_permissions.permissions() {
+            sqlx::query!(
+                "INSERT INTO role_permissions (role, permission, created_by, created_at)
+                 VALUES ($1, $2, $3, $4)",
+                role,
+                permission.as_i16(),
+                updated_by,
+                updated_at
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+        }
+
+        tx.commit().await.map_err(|e| RepositoryError::Other {
+            message: format!("トランザクションのコミットに失敗しました: {e}"),
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use std::{collections::HashMap, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestRolePermissionRepository {
+    inner: Arc<RwLock<HashMap<i16, Vec<Permission>>>>,
+}
+
+#[cfg(test)]
+impl TestRolePermissionRepository {
+    /// 権限の既定値（db/init/090-seed.sql）を登録した状態で作成する
+    pub fn new() -> Self {
+        use Permission::*;
+
+        let inner = HashMap::from([
+            (Role::Admin.as_i16(), Permission::ALL.to_vec()),
+            (Role::ItStaff.as_i16(), Permission::ALL.to_vec()),
+            (
+                Role::Doctor.as_i16(),
+                vec![ViewStudies, DownloadStudies, StoreStudies],
+            ),
+            (
+                Role::Technician.as_i16(),
+                vec![ViewStudies, DownloadStudies, StoreStudies],
+            ),
+            (Role::Clerk.as_i16(), vec![ViewStudies]),
+        ]);
+        Self {
+            inner: Arc::new(RwLock::new(inner)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl RolePermissionRepository for TestRolePermissionRepository {
+    async fn find_by_role(&self, role: Role) -> Result<RolePermissions, RepositoryError> {
+        let permissions = self
+            .inner
+            .read()
+            .await
+            .get(&role.as_i16())
+            .cloned()
+            .unwrap_or_default();
+        Ok(RolePermissions::construct(role, permissions))
+    }
+
+    async fn find_all(&self) -> Result<Vec<RolePermissions>, RepositoryError> {
+        let inner = self.inner.read().await;
+        Ok(Role::ALL
+            .into_iter()
+            .map(|role| {
+                RolePermissions::construct(
+                    role,
+                    inner.get(&role.as_i16()).cloned().unwrap_or_default(),
+                )
+            })
+            .collect())
+    }
+
+    async fn replace(
+        &self,
+        role_permissions: &RolePermissions,
+        _updated_by: &Uuid,
+        _updated_at: &DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        self.inner.write().await.insert(
+            role_permissions.role().as_i16(),
+            role_permissions.permissions().to_vec(),
+        );
+        Ok(())
+    }
+}
@@ -1,63 +1,211 @@
-use crate::internal::domain::{entity::Session, repository::SessionRepository};
-use std::{collections::HashMap, sync::Arc};
-use tokio::sync::RwLock;
+use crate::internal::domain::{
+    entity::Session, error::RepositoryError, repository::SessionRepository,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Pool, Postgres};
 use uuid::Uuid;
 
-pub struct InMemorySessionRepository {
-    sessions: Arc<RwLock<HashMap<String, Session>>>,
+#[derive(FromRow)]
+struct SessionRecord {
+    uuid: Uuid,
+    session_id_hash: String,
+    user_uuid: Uuid,
+    csrf_token: String,
+    user_agent: Option<String>,
+    created_at: DateTime<Utc>,
+    last_accessed_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
 }
 
-impl InMemorySessionRepository {
-    pub fn new() -> Self {
-        Self {
-            sessions: Arc::new(RwLock::new(HashMap::new())),
-        }
+impl From<SessionRecord> for Session {
+    fn from(record: SessionRecord) -> Self {
+        Session::construct(
+            record.uuid,
+            record.session_id_hash,
+            record.user_uuid,
+            record.csrf_token,
+            record.user_agent,
+            record.created_at,
+            record.last_accessed_at,
+            record.expires_at,
+        )
+    }
+}
+
+/// セッションをDBに保存するリポジトリ
+///
+/// Web APIを再起動してもセッションが維持され、複数のWeb APIでセッションを共有できる。
+pub struct PostgresSessionRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
     }
 }
 
 #[async_trait::async_trait]
-impl SessionRepository for InMemorySessionRepository {
-    async fn save(&self, session: Session) {
-        self.sessions
-            .write()
-            .await
-            .insert(session.session_id().to_string(), session);
+impl SessionRepository for PostgresSessionRepository {
+    async fn save(&self, session: Session) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            "INSERT INTO sessions (uuid, session_id_hash, user_uuid, csrf_token, user_agent, created_at, last_accessed_at, expires_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+            session.uuid(),
+            session.session_id_hash(),
+            session.user_uuid(),
+            session.csrf_token(),
+            session.user_agent(),
+            session.created_at(),
+            session.last_accessed_at(),
+            session.expires_at()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(())
     }
 
-    async fn find_by_session_id(&self, session_id: &str) -> Option<Session> {
-        let (session, is_expired) = {
-            let sessions = self.sessions.read().await;
-            match sessions.get(session_id) {
-                Some(session) => (Some(session.clone()), session.is_expired()),
-                None => (None, false),
-            }
-        };
+    async fn extend(
+        &self,
+        uuid: &Uuid,
+        last_accessed_at: &DateTime<Utc>,
+        expires_at: &DateTime<Utc>,
+    ) -> Result<bool, RepositoryError> {
+        let rows_affected = sqlx::query!(
+            "UPDATE sessions SET last_accessed_at = $2, expires_at = $3
+             WHERE uuid = $1 AND expires_at > now()",
+            uuid,
+            last_accessed_at,
+            expires_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?
+        .rows_affected();
 
-        if is_expired {
-            self.sessions.write().await.remove(session_id);
-            return None;
-        }
+        Ok(rows_affected > 0)
+    }
 
-        session
+    async fn find_by_session_id(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<Session>, RepositoryError> {
+        let record = sqlx::query_as!(
+            SessionRecord,
+            "SELECT uuid, session_id_hash, user_uuid, csrf_token, user_agent, created_at, last_accessed_at, expires_at
+             FROM sessions
+             WHERE session_id_hash = $1",
+            Session::hash_session_id(session_id)
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(record
+            .map(Session::from)
+            .filter(|session| !session.is_expired()))
     }
 
-    async fn delete_by_session_id(&self, session_id: &str) {
-        self.sessions.write().await.remove(session_id);
+    async fn find_by_user_uuid(&self, user_uuid: &Uuid) -> Result<Vec<Session>, RepositoryError> {
+        let records = sqlx::query_as!(
+            SessionRecord,
+            "SELECT uuid, session_id_hash, user_uuid, csrf_token, user_agent, created_at, last_accessed_at, expires_at
+             FROM sessions
+             WHERE user_uuid = $1
+             ORDER BY last_accessed_at DESC, uuid",
+            user_uuid
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(records
+            .into_iter()
+            .map(Session::from)
+            .filter(|session| !session.is_expired())
+            .collect())
     }
 
-    async fn delete_by_user_uuid(&self, user_uuid: &Uuid) {
-        let mut sessions = self.sessions.write().await;
-        sessions.retain(|_, session| session.user_uuid() != user_uuid);
+    async fn delete_by_session_id(&self, session_id: &str) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            "DELETE FROM sessions WHERE session_id_hash = $1",
+            Session::hash_session_id(session_id)
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(())
     }
 
-    async fn cleanup_expired_sessions(&self) {
-        let mut sessions = self.sessions.write().await;
-        sessions.retain(|_, session| !session.is_expired());
+    async fn delete_by_uuid(&self, user_uuid: &Uuid, uuid: &Uuid) -> Result<(), RepositoryError> {
+        let rows_affected = sqlx::query!(
+            "DELETE FROM sessions WHERE uuid = $1 AND user_uuid = $2",
+            uuid,
+            user_uuid
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(RepositoryError::NotFound {
+                resource: "セッション".to_string(),
+                key: uuid.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn delete_by_user_uuid(&self, user_uuid: &Uuid) -> Result<u64, RepositoryError> {
+        let rows_affected = sqlx::query!("DELETE FROM sessions WHERE user_uuid = $1", user_uuid)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("データベース処理でエラーが発生しました: {e}"),
+            })?
+            .rows_affected();
+
+        Ok(rows_affected)
+    }
+
+    async fn cleanup_expired_sessions(&self) -> Result<u64, RepositoryError> {
+        let rows_affected = sqlx::query!("DELETE FROM sessions WHERE expires_at <= now()")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Other {
+                message: format!("データベース処理でエラーが発生しました: {e}"),
+            })?
+            .rows_affected();
+
+        Ok(rows_affected)
     }
 }
 
+#[cfg(test)]
+use std::{collections::HashMap, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
 #[cfg(test)]
 pub struct TestSessionRepository {
+    /// セッションIDのハッシュ値をキーとするセッション
     inner: Arc<RwLock<HashMap<String, Session>>>,
 }
 
@@ -73,41 +221,102 @@ impl TestSessionRepository {
 #[cfg(test)]
 #[async_trait::async_trait]
 impl SessionRepository for TestSessionRepository {
-    async fn save(&self, session: Session) {
+    async fn save(&self, session: Session) -> Result<(), RepositoryError> {
         self.inner
             .write()
             .await
-            .insert(session.session_id().to_string(), session);
+            .insert(session.session_id_hash().to_string(), session);
+        Ok(())
     }
 
-    async fn find_by_session_id(&self, session_id: &str) -> Option<Session> {
-        let (session, is_expired) = {
-            let sessions = self.inner.read().await;
-            match sessions.get(session_id) {
-                Some(session) => (Some(session.clone()), session.is_expired()),
-                None => (None, false),
-            }
+    async fn extend(
+        &self,
+        uuid: &Uuid,
+        last_accessed_at: &DateTime<Utc>,
+        expires_at: &DateTime<Utc>,
+    ) -> Result<bool, RepositoryError> {
+        let mut sessions = self.inner.write().await;
+        let Some(session) = sessions
+            .values_mut()
+            .find(|session| session.uuid() == uuid && !session.is_expired())
+        else {
+            return Ok(false);
         };
+        *session = Session::construct(
+            *session.uuid(),
+            session.session_id_hash().to_string(),
+            *session.user_uuid(),
+            session.csrf_token().to_string(),
+            session.user_agent().map(|v| v.to_string()),
+            *session.created_at(),
+            *last_accessed_at,
+            *expires_at,
+        );
+        Ok(true)
+    }
 
-        if is_expired {
-            self.inner.write().await.remove(session_id);
-            return None;
-        }
+    async fn find_by_session_id(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<Session>, RepositoryError> {
+        Ok(self
+            .inner
+            .read()
+            .await
+            .get(&Session::hash_session_id(session_id))
+            .filter(|session| !session.is_expired())
+            .cloned())
+    }
+
+    async fn find_by_user_uuid(&self, user_uuid: &Uuid) -> Result<Vec<Session>, RepositoryError> {
+        let mut sessions = self
+            .inner
+            .read()
+            .await
+            .values()
+            .filter(|session| session.user_uuid() == user_uuid && !session.is_expired())
+            .cloned()
+            .collect::<Vec<_>>();
+        sessions.sort_by(|a, b| {
+            b.last_accessed_at()
+                .cmp(a.last_accessed_at())
+                .then_with(|| a.uuid().cmp(b.uuid()))
+        });
+        Ok(sessions)
+    }
 
-        session
+    async fn delete_by_session_id(&self, session_id: &str) -> Result<(), RepositoryError> {
+        self.inner
+            .write()
+            .await
+            .remove(&Session::hash_session_id(session_id));
+        Ok(())
     }
 
-    async fn delete_by_session_id(&self, session_id: &str) {
-        self.inner.write().await.remove(session_id);
+    async fn delete_by_uuid(&self, user_uuid: &Uuid, uuid: &Uuid) -> Result<(), RepositoryError> {
+        let mut sessions = self.inner.write().await;
+        let len = sessions.len();
+        sessions.retain(|_, session| !(session.uuid() == uuid && session.user_uuid() == user_uuid));
+        if sessions.len() == len {
+            return Err(RepositoryError::NotFound {
+                resource: "セッション".to_string(),
+                key: uuid.to_string(),
+            });
+        }
+        Ok(())
     }
 
-    async fn delete_by_user_uuid(&self, user_uuid: &Uuid) {
+    async fn delete_by_user_uuid(&self, user_uuid: &Uuid) -> Result<u64, RepositoryError> {
         let mut sessions = self.inner.write().await;
+        let len = sessions.len();
         sessions.retain(|_, session| session.user_uuid() != user_uuid);
+        Ok((len - sessions.len()) as u64)
     }
 
-    async fn cleanup_expired_sessions(&self) {
+    async fn cleanup_expired_sessions(&self) -> Result<u64, RepositoryError> {
         let mut sessions = self.inner.write().await;
+        let len = sessions.len();
         sessions.retain(|_, session| !session.is_expired());
+        Ok((len - sessions.len()) as u64)
     }
 }
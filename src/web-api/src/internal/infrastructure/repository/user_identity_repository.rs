@@ -0,0 +1,143 @@
+use crate::internal::domain::{
+    entity::UserIdentity, error::RepositoryError, repository::UserIdentityRepository,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Pool, Postgres};
+use uuid::Uuid;
+
+#[derive(FromRow)]
+struct UserIdentityRecord {
+    issuer: String,
+    subject: String,
+    user_uuid: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+impl From<UserIdentityRecord> for UserIdentity {
+    fn from(record: UserIdentityRecord) -> Self {
+        UserIdentity::new(
+            record.issuer,
+            record.subject,
+            record.user_uuid,
+            record.created_at,
+        )
+    }
+}
+
+pub struct PostgresUserIdentityRepository {
+    pool: Pool<Postgres>,
+}
+
+impl PostgresUserIdentityRepository {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl UserIdentityRepository for PostgresUserIdentityRepository {
+    async fn find_by_subject(
+        &self,
+        issuer: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, RepositoryError> {
+        let record = sqlx::query_as!(
+            UserIdentityRecord,
+            "SELECT issuer, subject, user_uuid, created_at
+             FROM user_identities
+             WHERE issuer = $1 AND subject = $2",
+            issuer,
+            subject
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Other {
+            message: format!("データベース処理でエラーが発生しました: {e}"),
+        })?;
+
+        Ok(record.map(UserIdentity::from))
+    }
+
+    async fn add(&self, identity: &UserIdentity) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            "INSERT INTO user_identities (issuer, subject, user_uuid, created_at)
+             VALUES ($1, $2, $3, $4)",
+            identity.issuer(),
+            identity.subject(),
+            identity.user_uuid(),
+            identity.created_at()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if let Some(db_err) = e.as_database_error()
+                && db_err.is_unique_violation()
+            {
+                return RepositoryError::Conflict {
+                    resource: "IdPのユーザー".to_string(),
+                    field: "サブジェクト".to_string(),
+                    value: identity.subject().to_string(),
+                };
+            }
+            RepositoryError::Other {
+                message: format!("データベース処理でエラーが発生しました: {e}"),
+            }
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use std::{collections::HashMap, sync::Arc};
+#[cfg(test)]
+use tokio::sync::RwLock;
+
+#[cfg(test)]
+pub struct TestUserIdentityRepository {
+    /// 発行者とサブジェクトの組をキーとする紐付け
+    inner: Arc<RwLock<HashMap<(String, String), UserIdentity>>>,
+}
+
+#[cfg(test)]
+impl TestUserIdentityRepository {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+}
+
+#[cfg(test)]
+#[async_trait::async_trait]
+impl UserIdentityRepository for TestUserIdentityRepository {
+    async fn find_by_subject(
+        &self,
+        issuer: &str,
+        subject: &str,
+    ) -> Result<Option<UserIdentity>, RepositoryError> {
+        Ok(self
+            .inner
+            .read()
+            .await
+            .get(&(issuer.to_string(), subject.to_string()))
+            .cloned())
+    }
+
+    async fn add(&self, identity: &UserIdentity) -> Result<(), RepositoryError> {
+        let key = (
+            identity.issuer().to_string(),
+            identity.subject().to_string(),
+        );
+        let mut identities = self.inner.write().await;
+        if identities.contains_key(&key) {
+            return Err(RepositoryError::Conflict {
+                resource: "IdPのユーザー".to_string(),
+                field: "サブジェクト".to_string(),
+                value: identity.subject().to_string(),
+            });
+        }
+        identities.insert(key, identity.clone());
+        Ok(())
+    }
+}
@@ -327,7 +327,7 @@ impl UserRepository for TestUserRepository {
             .values()
             .cloned()
             .collect::<Vec<User>>();
-        entities.sort_by(|a, b| b.created_at().cmp(&a.created_at()));
+        entities.sort_by(|a, b| b.created_at().cmp(a.created_at()));
         Ok(entities)
     }
 
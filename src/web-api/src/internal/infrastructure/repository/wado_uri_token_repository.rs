@@ -0,0 +1,137 @@
+use crate::internal::domain::{entity::WadoUriToken, repository::WadoUriTokenRepository};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64URL};
+use chrono::DateTime;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+/// HMAC-SHA256で署名したWADO-URIのトークン
+///
+/// トークンは`{ペイロード}.{署名}`の形式とし、いずれもパディングなしのBase64URLで表す。
+/// ペイロードは検査・シリーズ・SOPインスタンスのUID、発行したユーザーのUUID、有効期限（UNIX時間）を改行で連結したものとする。
+/// 秘密鍵が異なるサーバーで署名したトークンは検証に失敗する。
+pub struct HmacWadoUriTokenRepository {
+    secret: Vec<u8>,
+}
+
+impl HmacWadoUriTokenRepository {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            secret: secret.into(),
+        }
+    }
+
+    fn mac(&self, payload: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret)
+            .expect("HMACは任意の長さの鍵を受け付けるはず");
+        mac.update(payload);
+        mac
+    }
+}
+
+impl WadoUriTokenRepository for HmacWadoUriTokenRepository {
+    fn sign(&self, token: &WadoUriToken) -> String {
+        let payload = format!(
+            "{}\n{}\n{}\n{}\n{}",
+            token.study_instance_uid(),
+            token.series_instance_uid(),
+            token.sop_instance_uid(),
+            token.issued_by(),
+            token.expires_at().timestamp()
+        );
+        let signature = self.mac(payload.as_bytes()).finalize().into_bytes();
+
+        format!(
+            "{}.{}",
+            BASE64URL.encode(payload),
+            BASE64URL.encode(signature)
+        )
+    }
+
+    fn verify(&self, signed_token: &str) -> Option<WadoUriToken> {
+        let (payload, signature) = signed_token.split_once('.')?;
+        let payload = BASE64URL.decode(payload).ok()?;
+        let signature = BASE64URL.decode(signature).ok()?;
+        // 比較に要する時間から署名を推測されないよう、定数時間で比較する
+        self.mac(&payload).verify_slice(&signature).ok()?;
+
+        let payload = String::from_utf8(payload).ok()?;
+        let fields = payload.split('\n').collect::<Vec<_>>();
+        let [
+            study_instance_uid,
+            series_instance_uid,
+            sop_instance_uid,
+            issued_by,
+            expires_at,
+        ] = fields[..]
+        else {
+            return None;
+        };
+
+        Some(WadoUriToken::construct(
+            study_instance_uid,
+            series_instance_uid,
+            sop_instance_uid,
+            Uuid::parse_str(issued_by).ok()?,
+            DateTime::from_timestamp(expires_at.parse().ok()?, 0)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn token() -> WadoUriToken {
+        WadoUriToken::construct(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+            Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 1, 0, 5, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        // Arrange
+        let repository = HmacWadoUriTokenRepository::new("secret");
+
+        // Act
+        let signed_token = repository.sign(&token());
+        let actual = repository.verify(&signed_token);
+
+        // Assert
+        // URLにそのまま含められる文字のみで構成される
+        assert!(
+            signed_token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        );
+        assert_eq!(actual, Some(token()));
+    }
+
+    #[test]
+    fn test_verify_tampered_token() {
+        // Arrange
+        let repository = HmacWadoUriTokenRepository::new("secret");
+        let signed_token = repository.sign(&token());
+        let (_, signature) = signed_token.split_once('.').unwrap();
+        // 別のSOPインスタンスのペイロードに元の署名を付け替える
+        let tampered_payload = BASE64URL.encode(format!(
+            "1.2.392.200036.9116.2.6.1.48.1000\n1.2.392.200036.9116.2.6.1.48.1000.1\n1.2.392.200036.9116.2.6.1.48.1000.1.2\n49223a37-7e58-717c-b222-754550659249\n{}",
+            token().expires_at().timestamp()
+        ));
+
+        // Act
+        let tampered = repository.verify(&format!("{tampered_payload}.{signature}"));
+        let other_secret = HmacWadoUriTokenRepository::new("other secret").verify(&signed_token);
+        let malformed = repository.verify("malformed");
+
+        // Assert
+        assert_eq!(tampered, None);
+        assert_eq!(other_secret, None);
+        assert_eq!(malformed, None);
+    }
+}
@@ -34,6 +34,10 @@ pub enum PresentationError {
     #[error("{0}")]
     Forbidden(String),
     #[error("{0}")]
+    NotAcceptable(String),
+    #[error("{0}")]
+    UnsupportedMediaType(String),
+    #[error("{0}")]
     InternalServerError(String),
 }
 
@@ -47,6 +51,8 @@ impl IntoResponse for PresentationError {
             PresentationError::UnprocessableContent(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg),
             PresentationError::InternalServerError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             PresentationError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
+            PresentationError::NotAcceptable(msg) => (StatusCode::NOT_ACCEPTABLE, msg),
+            PresentationError::UnsupportedMediaType(msg) => (StatusCode::UNSUPPORTED_MEDIA_TYPE, msg),
         };
 
         let body = ErrorResponseBody {
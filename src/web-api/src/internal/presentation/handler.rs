@@ -1,4 +1,13 @@
 pub mod application_entity;
+pub mod archive;
 pub mod auth;
+pub mod coercion_rule;
+pub mod deidentification_job;
+pub mod dicom_web;
 pub mod health;
+pub mod patient_conflict;
+pub mod performed_procedure_step;
+pub mod retention_rule;
+pub mod role_permission;
+pub mod session;
 pub mod user;
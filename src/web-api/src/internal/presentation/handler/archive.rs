@@ -0,0 +1,338 @@
+pub mod delete_archived_series;
+pub mod delete_archived_sop_instance;
+pub mod delete_archived_study;
+pub mod get_archived_study;
+pub mod list_archived_patients;
+pub mod list_archived_sop_instances;
+pub mod list_archived_studies;
+
+pub use self::{
+    delete_archived_series::delete_archived_series,
+    delete_archived_sop_instance::delete_archived_sop_instance,
+    delete_archived_study::delete_archived_study, get_archived_study::get_archived_study,
+    list_archived_patients::list_archived_patients,
+    list_archived_sop_instances::list_archived_sop_instances,
+    list_archived_studies::list_archived_studies,
+};
+
+use crate::internal::{domain::value_object::SortOrder, presentation::error::PresentationError};
+
+/// 一覧で取得する件数の既定値
+const DEFAULT_LIMIT: u32 = 50;
+/// 一覧で取得する件数の上限
+const MAX_LIMIT: u32 = 200;
+
+/// 一覧で取得する件数を検証する。
+fn validate_limit(limit: Option<u32>) -> Result<u32, PresentationError> {
+    match limit {
+        None => Ok(DEFAULT_LIMIT),
+        Some(limit) if (1..=MAX_LIMIT).contains(&limit) => Ok(limit),
+        Some(limit) => Err(PresentationError::UnprocessableContent(format!(
+            "無効な取得件数: {limit}（1〜{MAX_LIMIT}の範囲で指定してください）"
+        ))),
+    }
+}
+
+/// 並び順を検証する。指定がない場合は`default`とする。
+fn validate_sort_order(
+    order: Option<&str>,
+    default: SortOrder,
+) -> Result<SortOrder, PresentationError> {
+    order
+        .map(SortOrder::parse)
+        .transpose()
+        .map(|order| order.unwrap_or(default))
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効な並び順: {e}")))
+}
+
+#[cfg(test)]
+pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
+    use crate::{
+        internal::{
+            domain::{
+                entity::{
+                    ArchivedPatient, ArchivedSeries, ArchivedSopInstance, ArchivedStudy, Patient,
+                    Series, SopInstance, Study, User,
+                },
+                repository::UserRepository,
+                value_object::{Id, Role, UserName},
+            },
+            infrastructure::repository::{
+                TestArchiveDeletionRepository, TestArchiveRepository, TestUserRepository,
+            },
+        },
+        startup,
+    };
+    use chrono::{DateTime, NaiveDate, NaiveTime};
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    // CT01から受信したデータと、ユーザーがSTOW-RSで登録したデータ
+    let ae_uuid = Uuid::parse_str("019c0a1b-2c3d-7e4f-8a5b-6c7d8e9f0a1b").unwrap();
+    let user_uuid = Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap();
+
+    let user_repository = Arc::new(TestUserRepository::new());
+    user_repository.add(&User::construct(
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        Id::new("admin").unwrap(),
+        UserName::new("管理者 太郎").unwrap(),
+        Role::Admin,
+        "$argon2id$v=19$m=19456,t=2,p=1$Zf/xy2I09QAEAvKnXga60w$arwk9jM50i/6RAjgZ2+N6fiRq0WWJFX3GmngTw+n34Y",
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        user_uuid,
+        Id::new("technician").unwrap(),
+        UserName::new("技師 太郎").unwrap(),
+        Role::Technician,
+        "$argon2id$v=19$m=19456,t=2,p=1$HLCrMDHifn55j/Kq5M6t0g$lIVsN8r8+osWzQmU6n5khyRZk8TNeB9/qn4NeULwVfI",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+    )).await.unwrap();
+
+    let archive_repository = Arc::new(TestArchiveRepository::new());
+    archive_repository
+        .add_patient(&ArchivedPatient::construct(
+            Patient::construct(
+                "P000001",
+                "YAMADA^TARO",
+                "山田^太郎",
+                "やまだ^たろう",
+                Some(NaiveDate::from_ymd_opt(1980, 1, 1).unwrap()),
+                1,
+                2,
+                3,
+                4,
+            ),
+            Some(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
+            ae_uuid,
+            Some("CT01".to_string()),
+            DateTime::from_str("2026-01-10T10:00:00.123+09:00").unwrap(),
+            DateTime::from_str("2026-02-01T09:00:01.123+09:00").unwrap(),
+        ))
+        .await;
+    archive_repository
+        .add_patient(&ArchivedPatient::construct(
+            Patient::construct(
+                "P000002",
+                "SATO^HANAKO",
+                "佐藤^花子",
+                "さとう^はなこ",
+                Some(NaiveDate::from_ymd_opt(1975, 5, 5).unwrap()),
+                2,
+                1,
+                1,
+                1,
+            ),
+            Some(NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()),
+            user_uuid,
+            None,
+            DateTime::from_str("2026-01-15T14:00:00.456+09:00").unwrap(),
+            DateTime::from_str("2026-01-15T14:00:00.456+09:00").unwrap(),
+        ))
+        .await;
+    archive_repository
+        .add_patient(&ArchivedPatient::construct(
+            Patient::construct(
+                "P000003",
+                "ITO^JIRO",
+                "伊藤^次郎",
+                "いとう^じろう",
+                None,
+                0,
+                0,
+                0,
+                0,
+            ),
+            None,
+            ae_uuid,
+            Some("CT01".to_string()),
+            DateTime::from_str("2026-01-20T08:00:00.789+09:00").unwrap(),
+            DateTime::from_str("2026-01-20T08:00:00.789+09:00").unwrap(),
+        ))
+        .await;
+
+    archive_repository
+        .add_study(&ArchivedStudy::construct(
+            Study::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                "S0001",
+                Some(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
+                Some(NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+                "A0001",
+                "P000001",
+                "YAMADA^TARO",
+                "山田^太郎",
+                "やまだ^たろう",
+                Some(NaiveDate::from_ymd_opt(1980, 1, 1).unwrap()),
+                1,
+                vec!["CT".to_string()],
+                vec!["1.2.840.10008.5.1.4.1.1.2".to_string()],
+                2,
+                3,
+            ),
+            1_572_864,
+            ae_uuid,
+            Some("CT01".to_string()),
+            DateTime::from_str("2026-02-01T09:00:01.123+09:00").unwrap(),
+            DateTime::from_str("2026-02-01T09:05:00.000+09:00").unwrap(),
+        ))
+        .await;
+    archive_repository
+        .add_study(&ArchivedStudy::construct(
+            Study::construct(
+                "1.2.392.200036.9116.2.6.1.48.2000",
+                "S0002",
+                Some(NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()),
+                Some(NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+                "A0002",
+                "P000001",
+                "YAMADA^TARO",
+                "山田^太郎",
+                "やまだ^たろう",
+                Some(NaiveDate::from_ymd_opt(1980, 1, 1).unwrap()),
+                1,
+                vec!["MR".to_string()],
+                vec!["1.2.840.10008.5.1.4.1.1.4".to_string()],
+                1,
+                1,
+            ),
+            524_288,
+            ae_uuid,
+            Some("CT01".to_string()),
+            DateTime::from_str("2026-01-10T10:00:00.123+09:00").unwrap(),
+            DateTime::from_str("2026-01-10T10:00:00.123+09:00").unwrap(),
+        ))
+        .await;
+    archive_repository
+        .add_study(&ArchivedStudy::construct(
+            Study::construct(
+                "1.2.392.200036.9116.2.6.1.48.3000",
+                "S0003",
+                Some(NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()),
+                None,
+                "B0001",
+                "P000002",
+                "SATO^HANAKO",
+                "佐藤^花子",
+                "さとう^はなこ",
+                Some(NaiveDate::from_ymd_opt(1975, 5, 5).unwrap()),
+                2,
+                vec!["CR".to_string()],
+                vec!["1.2.840.10008.5.1.4.1.1.1".to_string()],
+                1,
+                1,
+            ),
+            262_144,
+            user_uuid,
+            None,
+            DateTime::from_str("2026-01-15T14:00:00.456+09:00").unwrap(),
+            DateTime::from_str("2026-01-15T14:00:00.456+09:00").unwrap(),
+        ))
+        .await;
+
+    archive_repository
+        .add_series(&ArchivedSeries::construct(
+            Series::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                "1.2.392.200036.9116.2.6.1.48.1000.2",
+                "CT",
+                Some(2),
+                1,
+            ),
+            524_288,
+            ae_uuid,
+            Some("CT01".to_string()),
+            DateTime::from_str("2026-02-01T09:03:00.000+09:00").unwrap(),
+            DateTime::from_str("2026-02-01T09:03:00.000+09:00").unwrap(),
+        ))
+        .await;
+    archive_repository
+        .add_series(&ArchivedSeries::construct(
+            Series::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                "1.2.392.200036.9116.2.6.1.48.1000.1",
+                "CT",
+                Some(1),
+                2,
+            ),
+            1_048_576,
+            ae_uuid,
+            Some("CT01".to_string()),
+            DateTime::from_str("2026-02-01T09:00:01.123+09:00").unwrap(),
+            DateTime::from_str("2026-02-01T09:00:02.000+09:00").unwrap(),
+        ))
+        .await;
+
+    archive_repository
+        .add_sop_instance(&ArchivedSopInstance::construct(
+            SopInstance::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                "1.2.392.200036.9116.2.6.1.48.1000.1",
+                "1.2.840.10008.5.1.4.1.1.2",
+                "1.2.392.200036.9116.2.6.1.48.1000.1.2",
+                "1.2.840.10008.1.2.1",
+            ),
+            524_288,
+            "OCEANUS",
+            1,
+            ae_uuid,
+            Some("CT01".to_string()),
+            DateTime::from_str("2026-02-01T09:00:02.000+09:00").unwrap(),
+            DateTime::from_str("2026-02-01T09:00:02.000+09:00").unwrap(),
+        ))
+        .await;
+    archive_repository
+        .add_sop_instance(&ArchivedSopInstance::construct(
+            SopInstance::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                "1.2.392.200036.9116.2.6.1.48.1000.1",
+                "1.2.840.10008.5.1.4.1.1.2",
+                "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+                "1.2.840.10008.1.2.1",
+            ),
+            524_288,
+            "OCEANUS",
+            2,
+            ae_uuid,
+            Some("CT01".to_string()),
+            DateTime::from_str("2026-02-01T09:00:01.123+09:00").unwrap(),
+            DateTime::from_str("2026-02-01T09:10:00.000+09:00").unwrap(),
+        ))
+        .await;
+
+    let archive_deletion_repository = Arc::new(TestArchiveDeletionRepository::new());
+    archive_deletion_repository
+        .add(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+        )
+        .await;
+    archive_deletion_repository
+        .add(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "1.2.392.200036.9116.2.6.1.48.1000.1.2",
+        )
+        .await;
+    archive_deletion_repository
+        .add(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.2",
+            "1.2.392.200036.9116.2.6.1.48.1000.2.1",
+        )
+        .await;
+
+    let mut repos = startup::Repos::new_for_test();
+    repos.user_repository = user_repository;
+    repos.archive_repository = archive_repository;
+    repos.archive_deletion_repository = archive_deletion_repository;
+
+    repos
+}
@@ -0,0 +1,149 @@
+use crate::{
+    internal::{
+        application::archive::DeleteArchivedObjectCommand,
+        domain::repository::ArchiveDeletionTarget,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::Utc;
+
+#[utoipa::path(
+    delete,
+    path = "/archive/studies/{study_instance_uid}/series/{series_instance_uid}",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID")
+    ),
+    responses(
+        (status = 204, description = "シリーズの削除に成功"),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "シリーズが見つからない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "archive"
+)]
+pub async fn delete_archived_series(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path((study_instance_uid, series_instance_uid)): Path<(String, String)>,
+) -> Result<StatusCode, PresentationError> {
+    let command = DeleteArchivedObjectCommand {
+        target: ArchiveDeletionTarget::Series {
+            study_instance_uid,
+            series_instance_uid,
+        },
+        deleted_by: user.uuid(),
+        deleted_at: Utc::now(),
+    };
+    state
+        .delete_archived_object_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+
+    async fn delete(router: &Router, user_id: &str, uri: &str) -> StatusCode {
+        let (session_id, csrf_token) = test_helpers::login(router, user_id, "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+        router.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn 管理者はシリーズを削除できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let status = delete(
+            &router,
+            "admin",
+            "/archive/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        // 他のシリーズは削除されていないことの確認
+        let status = delete(
+            &router,
+            "admin",
+            "/archive/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.2",
+        )
+        .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn 別の検査のシリーズを指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let status = delete(
+            &router,
+            "admin",
+            "/archive/studies/1.2.392.200036.9116.2.6.1.48.2000/series/1.2.392.200036.9116.2.6.1.48.1000.1",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 技師はシリーズを削除できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let status = delete(
+            &router,
+            "technician",
+            "/archive/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,142 @@
+mod response_body;
+
+pub use self::response_body::{GetArchivedStudyResponseBody, GetArchivedStudyResponseBodySeries};
+
+use crate::{
+    internal::{
+        application::archive::GetArchivedStudyCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+
+#[utoipa::path(
+    get,
+    path = "/archive/studies/{study_instance_uid}",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID")
+    ),
+    responses(
+        (status = 200, description = "検査の取得に成功", body = GetArchivedStudyResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "検査が見つからない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "archive"
+)]
+pub async fn get_archived_study(
+    State(state): State<AppState>,
+    Path(study_instance_uid): Path<String>,
+) -> Result<Json<GetArchivedStudyResponseBody>, PresentationError> {
+    let command = GetArchivedStudyCommand { study_instance_uid };
+    let detail = state
+        .get_archived_study_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(GetArchivedStudyResponseBody::from(detail)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn get(router: &Router, uri: &str) -> (StatusCode, Value) {
+        let (session_id, csrf_token) =
+            test_helpers::login(router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは検査とシリーズの一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, body) = get(
+            &router,
+            "/archive/studies/1.2.392.200036.9116.2.6.1.48.1000",
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::OK);
+
+        // レスポンスボディの確認
+        assert_eq!(
+            body["studyInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000"
+        );
+        assert_eq!(body["patientId"], "P000001");
+        assert_eq!(body["numberOfSeries"], 2);
+        assert_eq!(body["numberOfInstances"], 3);
+        assert_eq!(body["totalSize"], 1_572_864);
+        assert_eq!(body["createdByAeTitle"], "CT01");
+
+        // シリーズはシリーズ番号の昇順
+        let series = body["series"].as_array().unwrap();
+        assert_eq!(series.len(), 2);
+        assert_eq!(
+            series[0]["seriesInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000.1"
+        );
+        assert_eq!(series[0]["modality"], "CT");
+        assert_eq!(series[0]["seriesNumber"], 1);
+        assert_eq!(series[0]["numberOfInstances"], 2);
+        assert_eq!(series[0]["totalSize"], 1_048_576);
+        assert_eq!(series[0]["createdByAeTitle"], "CT01");
+        assert_eq!(
+            series[1]["seriesInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000.2"
+        );
+    }
+
+    #[tokio::test]
+    async fn 存在しない検査を指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = get(&router, "/archive/studies/1.2.3.4.5").await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
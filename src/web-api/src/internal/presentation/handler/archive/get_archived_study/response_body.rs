@@ -0,0 +1,103 @@
+use crate::internal::{application::archive::ArchivedStudyDetail, domain::entity::ArchivedSeries};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetArchivedStudyResponseBody {
+    pub study_instance_uid: String,
+    pub study_id: String,
+    pub study_date: Option<NaiveDate>,
+    pub study_time: Option<NaiveTime>,
+    pub accession_number: String,
+    pub patient_id: String,
+    pub patient_name_alphabet: String,
+    pub patient_name_kanji: String,
+    pub patient_name_hiragana: String,
+    pub patient_birth_date: Option<NaiveDate>,
+    /// ISO/IEC 5218の性別コード（0: 不明, 1: 男性, 2: 女性, 9: 適用不能）
+    pub patient_sex: i16,
+    pub modalities: Vec<String>,
+    pub number_of_series: i64,
+    pub number_of_instances: i64,
+    /// SOPインスタンスのファイルサイズの合計（バイト）
+    pub total_size: i64,
+    /// 検査を登録したAE（STOW-RSで登録した場合はユーザー）のUUID
+    pub created_by: String,
+    /// 検査を登録したAEのAEタイトル
+    pub created_by_ae_title: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// シリーズ番号の昇順
+    pub series: Vec<GetArchivedStudyResponseBodySeries>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetArchivedStudyResponseBodySeries {
+    pub study_instance_uid: String,
+    pub series_instance_uid: String,
+    pub modality: String,
+    pub series_number: Option<i32>,
+    pub number_of_instances: i64,
+    /// SOPインスタンスのファイルサイズの合計（バイト）
+    pub total_size: i64,
+    /// シリーズを登録したAE（STOW-RSで登録した場合はユーザー）のUUID
+    pub created_by: String,
+    /// シリーズを登録したAEのAEタイトル
+    pub created_by_ae_title: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ArchivedStudyDetail> for GetArchivedStudyResponseBody {
+    fn from(detail: ArchivedStudyDetail) -> Self {
+        let archived_study = detail.study;
+        let study = archived_study.study();
+        Self {
+            study_instance_uid: study.instance_uid().to_string(),
+            study_id: study.id().to_string(),
+            study_date: study.study_date().copied(),
+            study_time: study.study_time().copied(),
+            accession_number: study.accession_number().to_string(),
+            patient_id: study.patient_id().to_string(),
+            patient_name_alphabet: study.patient_name_alphabet().to_string(),
+            patient_name_kanji: study.patient_name_kanji().to_string(),
+            patient_name_hiragana: study.patient_name_hiragana().to_string(),
+            patient_birth_date: study.patient_birth_date().copied(),
+            patient_sex: study.patient_sex(),
+            modalities: study.modalities().to_vec(),
+            number_of_series: study.number_of_series(),
+            number_of_instances: study.number_of_instances(),
+            total_size: archived_study.total_size(),
+            created_by: archived_study.created_by().to_string(),
+            created_by_ae_title: archived_study.created_by_ae_title().map(str::to_string),
+            created_at: *archived_study.created_at(),
+            updated_at: *archived_study.updated_at(),
+            series: detail
+                .series
+                .into_iter()
+                .map(GetArchivedStudyResponseBodySeries::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<ArchivedSeries> for GetArchivedStudyResponseBodySeries {
+    fn from(entity: ArchivedSeries) -> Self {
+        let series = entity.series();
+        Self {
+            study_instance_uid: series.study_instance_uid().to_string(),
+            series_instance_uid: series.instance_uid().to_string(),
+            modality: series.modality().to_string(),
+            series_number: series.series_number(),
+            number_of_instances: series.number_of_instances(),
+            total_size: entity.total_size(),
+            created_by: entity.created_by().to_string(),
+            created_by_ae_title: entity.created_by_ae_title().map(str::to_string),
+            created_at: *entity.created_at(),
+            updated_at: *entity.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,246 @@
+mod query_params;
+mod response_body;
+
+pub use self::{
+    query_params::ListArchivedPatientsQueryParams,
+    response_body::{ListArchivedPatientsResponseBody, ListArchivedPatientsResponseBodyItem},
+};
+
+use super::{validate_limit, validate_sort_order};
+use crate::{
+    internal::{
+        application::archive::ListArchivedPatientsCommand,
+        domain::{
+            repository::{ArchivedPatientFilter, ArchivedPatientSortKey},
+            value_object::SortOrder,
+        },
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+
+#[utoipa::path(
+    get,
+    path = "/archive/patients",
+    params(ListArchivedPatientsQueryParams),
+    responses(
+        (status = 200, description = "患者一覧の取得に成功", body = ListArchivedPatientsResponseBody),
+        (status = 400, description = "クエリパラメータの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "archive"
+)]
+pub async fn list_archived_patients(
+    State(state): State<AppState>,
+    Query(query_params): Query<ListArchivedPatientsQueryParams>,
+) -> Result<Json<ListArchivedPatientsResponseBody>, PresentationError> {
+    // バリデーション
+    let sort_key = query_params
+        .sort
+        .as_deref()
+        .map(ArchivedPatientSortKey::parse)
+        .transpose()
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効な並べ替えの項目: {e}")))?
+        .unwrap_or(ArchivedPatientSortKey::PatientId);
+    let sort_order = validate_sort_order(query_params.order.as_deref(), SortOrder::Ascending)?;
+    let limit = validate_limit(query_params.limit)?;
+
+    let command = ListArchivedPatientsCommand {
+        filter: ArchivedPatientFilter {
+            patient_id: query_params.patient_id.filter(|v| !v.is_empty()),
+            patient_name: query_params.patient_name.filter(|v| !v.is_empty()),
+        },
+        sort_key,
+        sort_order,
+        offset: query_params.offset.unwrap_or(0),
+        limit,
+    };
+    let page = state
+        .list_archived_patients_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(ListArchivedPatientsResponseBody::from(page)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn get(router: &Router, uri: &str) -> (StatusCode, Value) {
+        let (session_id, csrf_token) =
+            test_helpers::login(router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは患者一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, body) = get(&router, "/archive/patients").await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::OK);
+
+        // レスポンスボディの確認（患者IDの昇順）
+        assert_eq!(body["total"], 3);
+        let patients = body["items"].as_array().unwrap();
+        let ids = patients.iter().map(|p| &p["id"]).collect::<Vec<_>>();
+        assert_eq!(ids, ["P000001", "P000002", "P000003"]);
+
+        let patient = &patients[0];
+        assert_eq!(patient["nameAlphabet"], "YAMADA^TARO");
+        assert_eq!(patient["nameKanji"], "山田^太郎");
+        assert_eq!(patient["nameHiragana"], "やまだ^たろう");
+        assert_eq!(patient["birthDate"], "1980-01-01");
+        assert_eq!(patient["sex"], 1);
+        assert_eq!(patient["numberOfStudies"], 2);
+        assert_eq!(patient["latestStudyDate"], "2026-02-01");
+        assert_eq!(patient["createdBy"], "019c0a1b-2c3d-7e4f-8a5b-6c7d8e9f0a1b");
+        assert_eq!(patient["createdByAeTitle"], "CT01");
+
+        // STOW-RSで登録した患者はAEタイトルがない
+        assert_eq!(patients[1]["createdByAeTitle"], Value::Null);
+    }
+
+    #[tokio::test]
+    async fn 氏名と患者IDで絞り込みができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (kanji_status, kanji_body) =
+            get(&router, "/archive/patients?patientName=%E5%B1%B1%E7%94%B0").await;
+        let (alphabet_status, alphabet_body) =
+            get(&router, "/archive/patients?patientName=hanako").await;
+        let (id_status, id_body) = get(&router, "/archive/patients?patientId=P00000").await;
+
+        // Assert
+        // 漢字の部分一致（山田）
+        assert_eq!(kanji_status, StatusCode::OK);
+        assert_eq!(kanji_body["total"], 1);
+        assert_eq!(kanji_body["items"][0]["id"], "P000001");
+
+        // アルファベットの部分一致（大文字と小文字を区別しない）
+        assert_eq!(alphabet_status, StatusCode::OK);
+        assert_eq!(alphabet_body["total"], 1);
+        assert_eq!(alphabet_body["items"][0]["id"], "P000002");
+
+        // 患者IDの前方一致
+        assert_eq!(id_status, StatusCode::OK);
+        assert_eq!(id_body["total"], 3);
+    }
+
+    #[tokio::test]
+    async fn 並べ替えとページングができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (sorted_status, sorted_body) =
+            get(&router, "/archive/patients?sort=latestStudyDate&order=desc").await;
+        let (paged_status, paged_body) =
+            get(&router, "/archive/patients?sort=birthDate&offset=1&limit=1").await;
+
+        // Assert
+        // 最新の検査日の降順（検査がない患者は末尾）
+        assert_eq!(sorted_status, StatusCode::OK);
+        let ids = sorted_body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| &p["id"])
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["P000001", "P000002", "P000003"]);
+
+        // 生年月日の昇順の2件目（全件数はページングの影響を受けない）
+        assert_eq!(paged_status, StatusCode::OK);
+        assert_eq!(paged_body["total"], 3);
+        let items = paged_body["items"].as_array().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0]["id"], "P000001");
+    }
+
+    #[tokio::test]
+    async fn 不正な並べ替えの項目や取得件数を指定すると422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (sort_status, _) = get(&router, "/archive/patients?sort=sex").await;
+        let (order_status, _) = get(&router, "/archive/patients?order=up").await;
+        let (zero_limit_status, _) = get(&router, "/archive/patients?limit=0").await;
+        let (large_limit_status, _) = get(&router, "/archive/patients?limit=201").await;
+
+        // Assert
+        assert_eq!(sort_status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(order_status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(zero_limit_status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(large_limit_status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn ログインしていない場合は401エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/archive/patients")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct ListArchivedPatientsQueryParams {
+    /// 患者IDで絞り込む（前方一致）
+    pub patient_id: Option<String>,
+    /// 氏名で絞り込む（アルファベット・漢字・ひらがなのいずれかに部分一致）
+    pub patient_name: Option<String>,
+    /// 並べ替えの項目 (patientId, patientName, birthDate, latestStudyDate)。既定値はpatientId
+    pub sort: Option<String>,
+    /// 並び順 (asc, desc)。既定値はasc
+    pub order: Option<String>,
+    /// 読み飛ばす件数。既定値は0
+    pub offset: Option<u32>,
+    /// 取得する件数 (1〜200)。既定値は50
+    pub limit: Option<u32>,
+}
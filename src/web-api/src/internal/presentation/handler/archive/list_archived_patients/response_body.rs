@@ -0,0 +1,65 @@
+use crate::internal::domain::{entity::ArchivedPatient, repository::Page};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListArchivedPatientsResponseBody {
+    /// 絞り込み条件に一致する全件数
+    pub total: i64,
+    pub items: Vec<ListArchivedPatientsResponseBodyItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListArchivedPatientsResponseBodyItem {
+    pub id: String,
+    pub name_alphabet: String,
+    pub name_kanji: String,
+    pub name_hiragana: String,
+    pub birth_date: Option<NaiveDate>,
+    /// ISO/IEC 5218の性別コード（0: 不明, 1: 男性, 2: 女性, 9: 適用不能）
+    pub sex: i16,
+    pub number_of_studies: i64,
+    pub latest_study_date: Option<NaiveDate>,
+    /// 患者を登録したAE（STOW-RSで登録した場合はユーザー）のUUID
+    pub created_by: String,
+    /// 患者を登録したAEのAEタイトル
+    pub created_by_ae_title: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Page<ArchivedPatient>> for ListArchivedPatientsResponseBody {
+    fn from(page: Page<ArchivedPatient>) -> Self {
+        Self {
+            total: page.total,
+            items: page
+                .items
+                .into_iter()
+                .map(ListArchivedPatientsResponseBodyItem::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<ArchivedPatient> for ListArchivedPatientsResponseBodyItem {
+    fn from(entity: ArchivedPatient) -> Self {
+        let patient = entity.patient();
+        Self {
+            id: patient.id().to_string(),
+            name_alphabet: patient.name_alphabet().to_string(),
+            name_kanji: patient.name_kanji().to_string(),
+            name_hiragana: patient.name_hiragana().to_string(),
+            birth_date: patient.birth_date().copied(),
+            sex: patient.sex(),
+            number_of_studies: patient.number_of_studies(),
+            latest_study_date: entity.latest_study_date().copied(),
+            created_by: entity.created_by().to_string(),
+            created_by_ae_title: entity.created_by_ae_title().map(str::to_string),
+            created_at: *entity.created_at(),
+            updated_at: *entity.updated_at(),
+        }
+    }
+}
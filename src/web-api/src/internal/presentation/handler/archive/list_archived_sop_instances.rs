@@ -0,0 +1,151 @@
+mod response_body;
+
+pub use self::response_body::ListArchivedSopInstancesResponseBodyItem;
+
+use crate::{
+    internal::{
+        application::archive::ListArchivedSopInstancesCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+
+#[utoipa::path(
+    get,
+    path = "/archive/studies/{study_instance_uid}/series/{series_instance_uid}/instances",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID")
+    ),
+    responses(
+        (status = 200, description = "SOPインスタンス一覧の取得に成功", body = Vec<ListArchivedSopInstancesResponseBodyItem>),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "シリーズが見つからない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "archive"
+)]
+pub async fn list_archived_sop_instances(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid)): Path<(String, String)>,
+) -> Result<Json<Vec<ListArchivedSopInstancesResponseBodyItem>>, PresentationError> {
+    let command = ListArchivedSopInstancesCommand {
+        study_instance_uid,
+        series_instance_uid,
+    };
+    let response_body = state
+        .list_archived_sop_instances_use_case
+        .execute(command)
+        .await
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(ListArchivedSopInstancesResponseBodyItem::from)
+                .collect()
+        })
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn get(router: &Router, uri: &str) -> (StatusCode, Value) {
+        let (session_id, csrf_token) =
+            test_helpers::login(router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーはシリーズのSOPインスタンス一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, body) = get(
+            &router,
+            "/archive/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances",
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::OK);
+
+        // レスポンスボディの確認（登録日時の昇順）
+        let instances = body.as_array().unwrap();
+        assert_eq!(instances.len(), 2);
+        let instance = &instances[0];
+        assert_eq!(
+            instance["sopInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1"
+        );
+        assert_eq!(instance["sopClassUid"], "1.2.840.10008.5.1.4.1.1.2");
+        assert_eq!(instance["transferSyntaxUid"], "1.2.840.10008.1.2.1");
+        assert_eq!(instance["size"], 524_288);
+        assert_eq!(instance["calledAeTitle"], "OCEANUS");
+        assert_eq!(instance["version"], 2);
+        assert_eq!(
+            instance["createdBy"],
+            "019c0a1b-2c3d-7e4f-8a5b-6c7d8e9f0a1b"
+        );
+        assert_eq!(instance["createdByAeTitle"], "CT01");
+        assert_eq!(
+            instances[1]["sopInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000.1.2"
+        );
+    }
+
+    #[tokio::test]
+    async fn 検査に含まれないシリーズを指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = get(
+            &router,
+            "/archive/studies/1.2.392.200036.9116.2.6.1.48.2000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances",
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
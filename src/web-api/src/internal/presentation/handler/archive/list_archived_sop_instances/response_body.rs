@@ -0,0 +1,43 @@
+use crate::internal::domain::entity::ArchivedSopInstance;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListArchivedSopInstancesResponseBodyItem {
+    pub series_instance_uid: String,
+    pub sop_instance_uid: String,
+    pub sop_class_uid: String,
+    pub transfer_syntax_uid: String,
+    /// ファイルサイズ（バイト）
+    pub size: i64,
+    /// SOPインスタンスを受信したAEのAEタイトル
+    pub called_ae_title: String,
+    pub version: i32,
+    /// SOPインスタンスを送信したAE（STOW-RSで登録した場合はユーザー）のUUID
+    pub created_by: String,
+    /// SOPインスタンスを送信したAEのAEタイトル
+    pub created_by_ae_title: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ArchivedSopInstance> for ListArchivedSopInstancesResponseBodyItem {
+    fn from(entity: ArchivedSopInstance) -> Self {
+        let sop_instance = entity.sop_instance();
+        Self {
+            series_instance_uid: sop_instance.series_instance_uid().to_string(),
+            sop_instance_uid: sop_instance.instance_uid().to_string(),
+            sop_class_uid: sop_instance.class_uid().to_string(),
+            transfer_syntax_uid: sop_instance.transfer_syntax_uid().to_string(),
+            size: entity.size(),
+            called_ae_title: entity.called_ae_title().to_string(),
+            version: entity.version(),
+            created_by: entity.created_by().to_string(),
+            created_by_ae_title: entity.created_by_ae_title().map(str::to_string),
+            created_at: *entity.created_at(),
+            updated_at: *entity.updated_at(),
+        }
+    }
+}
@@ -0,0 +1,258 @@
+mod query_params;
+mod response_body;
+
+pub use self::{
+    query_params::ListArchivedStudiesQueryParams,
+    response_body::{ListArchivedStudiesResponseBody, ListArchivedStudiesResponseBodyItem},
+};
+
+use super::{validate_limit, validate_sort_order};
+use crate::{
+    internal::{
+        application::archive::ListArchivedStudiesCommand,
+        domain::{
+            repository::{ArchivedStudyFilter, ArchivedStudySortKey},
+            value_object::SortOrder,
+        },
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+
+#[utoipa::path(
+    get,
+    path = "/archive/studies",
+    params(ListArchivedStudiesQueryParams),
+    responses(
+        (status = 200, description = "検査一覧の取得に成功", body = ListArchivedStudiesResponseBody),
+        (status = 400, description = "クエリパラメータの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "archive"
+)]
+pub async fn list_archived_studies(
+    State(state): State<AppState>,
+    Query(query_params): Query<ListArchivedStudiesQueryParams>,
+) -> Result<Json<ListArchivedStudiesResponseBody>, PresentationError> {
+    // バリデーション
+    let sort_key = query_params
+        .sort
+        .as_deref()
+        .map(ArchivedStudySortKey::parse)
+        .transpose()
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効な並べ替えの項目: {e}")))?
+        .unwrap_or(ArchivedStudySortKey::StudyDate);
+    let sort_order = validate_sort_order(query_params.order.as_deref(), SortOrder::Descending)?;
+    let limit = validate_limit(query_params.limit)?;
+    if let (Some(from), Some(to)) = (query_params.study_date_from, query_params.study_date_to)
+        && from > to
+    {
+        return Err(PresentationError::UnprocessableContent(format!(
+            "無効な検査日の範囲: {from}〜{to}"
+        )));
+    }
+
+    let command = ListArchivedStudiesCommand {
+        filter: ArchivedStudyFilter {
+            patient_id: query_params.patient_id.filter(|v| !v.is_empty()),
+            patient_name: query_params.patient_name.filter(|v| !v.is_empty()),
+            study_date_from: query_params.study_date_from,
+            study_date_to: query_params.study_date_to,
+            modality: query_params.modality.filter(|v| !v.is_empty()),
+            accession_number: query_params.accession_number.filter(|v| !v.is_empty()),
+        },
+        sort_key,
+        sort_order,
+        offset: query_params.offset.unwrap_or(0),
+        limit,
+    };
+    let page = state
+        .list_archived_studies_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(ListArchivedStudiesResponseBody::from(page)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn get(router: &Router, uri: &str) -> (StatusCode, Value) {
+        let (session_id, csrf_token) =
+            test_helpers::login(router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    fn study_instance_uids(body: &Value) -> Vec<&str> {
+        body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["studyInstanceUid"].as_str().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは検査一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, body) = get(&router, "/archive/studies").await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::OK);
+
+        // レスポンスボディの確認（検査日時の降順）
+        assert_eq!(body["total"], 3);
+        assert_eq!(
+            study_instance_uids(&body),
+            [
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                "1.2.392.200036.9116.2.6.1.48.3000",
+                "1.2.392.200036.9116.2.6.1.48.2000",
+            ]
+        );
+
+        let study = &body["items"][0];
+        assert_eq!(study["studyId"], "S0001");
+        assert_eq!(study["studyDate"], "2026-02-01");
+        assert_eq!(study["studyTime"], "09:00:00");
+        assert_eq!(study["accessionNumber"], "A0001");
+        assert_eq!(study["patientId"], "P000001");
+        assert_eq!(study["patientNameKanji"], "山田^太郎");
+        assert_eq!(study["modalities"], serde_json::json!(["CT"]));
+        assert_eq!(study["numberOfSeries"], 2);
+        assert_eq!(study["numberOfInstances"], 3);
+        assert_eq!(study["totalSize"], 1_572_864);
+        assert_eq!(study["createdByAeTitle"], "CT01");
+    }
+
+    #[tokio::test]
+    async fn 検査日の範囲とモダリティと受付番号で絞り込みができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (date_status, date_body) = get(
+            &router,
+            "/archive/studies?studyDateFrom=2026-01-10&studyDateTo=2026-01-31",
+        )
+        .await;
+        let (modality_status, modality_body) = get(&router, "/archive/studies?modality=MR").await;
+        let (accession_status, accession_body) = get(
+            &router,
+            "/archive/studies?accessionNumber=A&patientName=%E5%B1%B1%E7%94%B0",
+        )
+        .await;
+
+        // Assert
+        // 検査日の範囲（両端を含む）
+        assert_eq!(date_status, StatusCode::OK);
+        assert_eq!(date_body["total"], 2);
+        assert_eq!(
+            study_instance_uids(&date_body),
+            [
+                "1.2.392.200036.9116.2.6.1.48.3000",
+                "1.2.392.200036.9116.2.6.1.48.2000",
+            ]
+        );
+
+        // モダリティ
+        assert_eq!(modality_status, StatusCode::OK);
+        assert_eq!(
+            study_instance_uids(&modality_body),
+            ["1.2.392.200036.9116.2.6.1.48.2000"]
+        );
+
+        // 受付番号の前方一致と氏名の部分一致
+        assert_eq!(accession_status, StatusCode::OK);
+        assert_eq!(accession_body["total"], 2);
+    }
+
+    #[tokio::test]
+    async fn 並べ替えとページングができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, body) = get(
+            &router,
+            "/archive/studies?sort=accessionNumber&order=asc&offset=1&limit=2",
+        )
+        .await;
+
+        // Assert
+        // 受付番号の昇順の2件目から2件
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total"], 3);
+        assert_eq!(
+            study_instance_uids(&body),
+            [
+                "1.2.392.200036.9116.2.6.1.48.2000",
+                "1.2.392.200036.9116.2.6.1.48.3000",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn 不正な検索条件を指定すると422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (sort_status, _) = get(&router, "/archive/studies?sort=modality").await;
+        let (range_status, _) = get(
+            &router,
+            "/archive/studies?studyDateFrom=2026-02-01&studyDateTo=2026-01-01",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(sort_status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(range_status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
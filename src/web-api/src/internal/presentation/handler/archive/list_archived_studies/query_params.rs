@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct ListArchivedStudiesQueryParams {
+    /// 患者IDで絞り込む（前方一致）
+    pub patient_id: Option<String>,
+    /// 氏名で絞り込む（アルファベット・漢字・ひらがなのいずれかに部分一致）
+    pub patient_name: Option<String>,
+    /// 検査日の下限で絞り込む（この日を含む）
+    pub study_date_from: Option<NaiveDate>,
+    /// 検査日の上限で絞り込む（この日を含む）
+    pub study_date_to: Option<NaiveDate>,
+    /// モダリティで絞り込む（このモダリティのシリーズを含む検査）
+    pub modality: Option<String>,
+    /// 受付番号で絞り込む（前方一致）
+    pub accession_number: Option<String>,
+    /// 並べ替えの項目 (studyDate, patientId, patientName, accessionNumber, createdAt)。既定値はstudyDate
+    pub sort: Option<String>,
+    /// 並び順 (asc, desc)。既定値はdesc
+    pub order: Option<String>,
+    /// 読み飛ばす件数。既定値は0
+    pub offset: Option<u32>,
+    /// 取得する件数 (1〜200)。既定値は50
+    pub limit: Option<u32>,
+}
@@ -1,8 +1,12 @@
 pub mod login;
 pub mod logout;
 pub mod me;
+pub mod oidc_callback;
+pub mod oidc_login;
 
-pub use self::{login::login, logout::logout, me::me};
+pub use self::{
+    login::login, logout::logout, me::me, oidc_callback::oidc_callback, oidc_login::oidc_login,
+};
 
 #[cfg(test)]
 pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
@@ -39,3 +43,46 @@ pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
 
     repos
 }
+
+/// テスト用のIdPを起動し、シングルサインオンを有効にしたテストデータを準備する。
+/// 管理者 (admin) は緊急用のアカウントとしてパスワードによるログインを許可する。
+#[cfg(test)]
+pub(crate) async fn prepare_oidc_test_data() -> (
+    crate::startup::Repos,
+    crate::internal::presentation::util::mock_identity_provider::MockIdentityProvider,
+) {
+    use crate::internal::{
+        domain::{
+            entity::User,
+            value_object::{Id, LocalLoginPolicy, Role, RoleMapping, UserName},
+        },
+        infrastructure::repository::OidcIdentityProviderRepository,
+        presentation::util::mock_identity_provider::MockIdentityProvider,
+    };
+    use chrono::DateTime;
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    let mut repos = prepare_test_data().await;
+    repos.user_repository.add(&User::construct(
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        Id::new("admin").unwrap(),
+        UserName::new("管理者 太郎").unwrap(),
+        Role::Admin,
+        "$argon2id$v=19$m=19456,t=2,p=1$Zf/xy2I09QAEAvKnXga60w$arwk9jM50i/6RAjgZ2+N6fiRq0WWJFX3GmngTw+n34Y",
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+    )).await.unwrap();
+
+    let identity_provider = MockIdentityProvider::start().await;
+    repos.identity_provider_repository = Some(Arc::new(OidcIdentityProviderRepository::new(
+        identity_provider.config(),
+    )));
+    repos.role_mapping =
+        RoleMapping::parse("pacs-admins=0,radiologists=2,technologists=3", None).unwrap();
+    repos.local_login_policy = LocalLoginPolicy::Only(vec![Id::new("admin").unwrap()]);
+
+    (repos, identity_provider)
+}
@@ -14,7 +14,11 @@ use crate::{
     },
     startup::AppState,
 };
-use axum::{Json, extract::State};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, header::USER_AGENT},
+};
 use tower_cookies::Cookies;
 
 #[utoipa::path(
@@ -25,7 +29,7 @@ use tower_cookies::Cookies;
         (status = 200, description = "ログインに成功", body = LoginResponseBody),
         (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
         (status = 401, description = "認証に失敗", body = ErrorResponseBody),
-        (status = 403, description = "アカウントがロックされている", body = ErrorResponseBody),
+        (status = 403, description = "アカウントがロックされているか、パスワードによるログインが許可されていない", body = ErrorResponseBody),
         (status = 422, description = "バリデーション失敗", body = ErrorResponseBody),
     ),
     tag = "auth"
@@ -33,6 +37,7 @@ use tower_cookies::Cookies;
 pub async fn login(
     State(state): State<AppState>,
     cookies: Cookies,
+    headers: HeaderMap,
     Json(request_body): Json<LoginRequestBody>,
 ) -> Result<Json<LoginResponseBody>, PresentationError> {
     // バリデーション
@@ -43,6 +48,11 @@ pub async fn login(
     let command = LoginCommand {
         user_id: user_id.clone(),
         password: request_body.password,
+        // セッション一覧で端末を見分けられるよう、User-Agentを記録する
+        user_agent: headers
+            .get(USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string()),
     };
     let (session_id, csrf_token, role) =
         state
@@ -53,7 +63,9 @@ pub async fn login(
                 AuthenticationError::InvalidCredentials => {
                     PresentationError::Unauthorized(e.to_string())
                 }
-                AuthenticationError::Locked => PresentationError::Forbidden(e.to_string()),
+                AuthenticationError::Locked | AuthenticationError::LocalLoginNotAllowed => {
+                    PresentationError::Forbidden(e.to_string())
+                }
                 AuthenticationError::Other { .. } => {
                     PresentationError::InternalServerError(e.to_string())
                 }
@@ -73,7 +85,7 @@ pub async fn login(
 #[allow(non_snake_case)]
 #[cfg(test)]
 mod tests {
-    use super::super::prepare_test_data;
+    use super::super::{prepare_oidc_test_data, prepare_test_data};
     use crate::startup;
     use axum::{
         body::{self, Body},
@@ -100,6 +112,7 @@ mod tests {
             .method("POST")
             .uri("/login")
             .header("content-type", "application/json")
+            .header("user-agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
             .body(Body::from(serde_json::to_string(&body).unwrap()))
             .unwrap();
 
@@ -141,10 +154,15 @@ mod tests {
         let session = repos
             .session_repository
             .find_by_session_id(&session_id)
-            .await;
+            .await
+            .unwrap();
         assert!(session.is_some());
         let session = session.unwrap();
         assert_eq!(session.csrf_token(), body["csrfToken"].as_str().unwrap());
+        assert_eq!(
+            session.user_agent(),
+            Some("Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
+        );
     }
 
     #[tokio::test]
@@ -351,4 +369,77 @@ mod tests {
         assert!(body["detail"].is_string());
         assert!(!body["detail"].as_str().unwrap().is_empty());
     }
+
+    #[tokio::test]
+    async fn パスワードによるログインが許可されていないユーザーは403エラーになる() {
+        // Arrange
+        let (repos, _identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let body = json!({
+            "userId": "doctor",
+            "password": "Password#1234"
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/login")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(response.headers().get("set-cookie").is_none());
+
+        // ログイン失敗回数が増えていないことを確認
+        let user_uuid = Uuid::parse_str("492236d4-2f18-76ab-a82f-84e29fcf92f8").unwrap();
+        assert_eq!(
+            repos
+                .login_failure_count_repository
+                .find_by_user_uuid(&user_uuid)
+                .await
+                .unwrap()
+                .map_or(0, |c| c.failure_count()),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn シングルサインオンが有効でも緊急用のアカウントはパスワードでログインできる() {
+        // Arrange
+        let (repos, _identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let body = json!({
+            "userId": "admin",
+            "password": "Password#1234"
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/login")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(
+            response
+                .headers()
+                .get("set-cookie")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .starts_with("session_id=")
+        );
+    }
 }
@@ -36,7 +36,11 @@ pub async fn logout(
         ))?;
 
     // ログアウト処理
-    state.logout_use_case.execute(&session_id).await;
+    state
+        .logout_use_case
+        .execute(&session_id)
+        .await
+        .map_err(|e| PresentationError::InternalServerError(e.to_string()))?;
 
     // Cookieを削除
     cookies.remove(CookieHelper::delete_session_cookie());
@@ -65,10 +69,9 @@ mod tests {
 
         // 事前にセッションを作成してリポジトリに保存
         let user_uuid = Uuid::parse_str("492236d4-2f18-76ab-a82f-84e29fcf92f8").unwrap();
-        let session = Session::create(user_uuid);
-        let session_id = session.session_id().to_string();
+        let (session, session_id) = Session::create(user_uuid, None);
         let csrf_token = session.csrf_token().to_string();
-        repos.session_repository.save(session).await;
+        repos.session_repository.save(session).await.unwrap();
 
         let request = Request::builder()
             .method("POST")
@@ -89,7 +92,8 @@ mod tests {
         let session = repos
             .session_repository
             .find_by_session_id(&session_id)
-            .await;
+            .await
+            .unwrap();
         assert!(session.is_none());
     }
 }
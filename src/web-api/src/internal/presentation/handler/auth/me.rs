@@ -5,7 +5,7 @@ pub use self::response_body::MeResponseBody;
 use crate::internal::{
     domain::{
         entity::Session,
-        repository::{SessionRepository, UserRepository},
+        repository::{RolePermissionRepository, SessionRepository, UserRepository},
     },
     presentation::{
         error::{ErrorResponseBody, PresentationError},
@@ -33,6 +33,7 @@ pub async fn me(
     cookies: Cookies,
     session_repository: Arc<dyn SessionRepository>,
     user_repository: Arc<dyn UserRepository>,
+    role_permission_repository: Arc<dyn RolePermissionRepository>,
 ) -> Result<Json<MeResponseBody>, PresentationError> {
     // CookieからセッションIDを取得
     let session_id = cookies
@@ -46,6 +47,7 @@ pub async fn me(
     let mut session = session_repository
         .find_by_session_id(&session_id)
         .await
+        .map_err(|e| PresentationError::InternalServerError(e.to_string()))?
         .ok_or(PresentationError::Unauthorized(
             "認証されていません".to_string(),
         ))?;
@@ -63,9 +65,18 @@ pub async fn me(
             "認証されていません".to_string(),
         ))?;
 
+    // ロールの権限を取得
+    let role_permissions = role_permission_repository
+        .find_by_role(user.role())
+        .await
+        .map_err(|e| PresentationError::InternalServerError(e.to_string()))?;
+
     // セッションを延長
     session.extend();
-    session_repository.save(session).await;
+    session_repository
+        .save(session)
+        .await
+        .map_err(|e| PresentationError::InternalServerError(e.to_string()))?;
 
     // Cookieの有効期限も更新
     let cookie = CookieHelper::create_session_cookie(session_id, Session::DEFAULT_EXPIRY_MINUTES);
@@ -75,6 +86,11 @@ pub async fn me(
         user_id: user.id().value().to_string(),
         csrf_token,
         role: user.role().as_i16(),
+        permissions: role_permissions
+            .permissions()
+            .iter()
+            .map(|p| p.as_i16())
+            .collect(),
     }))
 }
 
@@ -129,12 +145,14 @@ mod tests {
         assert_eq!(body["userId"], "doctor");
         assert_eq!(body["csrfToken"], csrf_token);
         assert_eq!(body["role"], 2); // Doctor
+        assert_eq!(body["permissions"], serde_json::json!([0, 1, 2]));
 
         // リポジトリに反映されていることの確認（セッションが延長されたことの確認）
         let updated_session = repos
             .session_repository
             .find_by_session_id(&session_id)
             .await
+            .unwrap()
             .unwrap();
         assert_eq!(
             updated_session.user_uuid().to_string(),
@@ -7,4 +7,6 @@ pub struct MeResponseBody {
     pub user_id: String,
     pub csrf_token: String,
     pub role: i16,
+    /// ロールに許可された操作の権限
+    pub permissions: Vec<i16>,
 }
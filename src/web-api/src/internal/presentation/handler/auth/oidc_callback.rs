@@ -0,0 +1,645 @@
+mod query_params;
+
+pub use self::query_params::OidcCallbackQueryParams;
+
+use crate::{
+    internal::{
+        application::auth::{CompleteOidcLoginCommand, OidcLoginError},
+        domain::{entity::Session, error::IdentityProviderError},
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            util::CookieHelper,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, header::USER_AGENT},
+    response::Redirect,
+};
+use tower_cookies::Cookies;
+
+#[utoipa::path(
+    get,
+    path = "/oidc/callback",
+    params(OidcCallbackQueryParams),
+    responses(
+        (status = 303, description = "ログインに成功し、ログイン開始時に指定したパスにリダイレクト"),
+        (status = 400, description = "認可リクエストが見つからないか期限が切れている", body = ErrorResponseBody),
+        (status = 401, description = "IdPによる認証に失敗", body = ErrorResponseBody),
+        (status = 403, description = "IdPのユーザーにロールを割り当てられない", body = ErrorResponseBody),
+        (status = 404, description = "シングルサインオンが設定されていない", body = ErrorResponseBody),
+        (status = 409, description = "IdPのユーザーが紐付けられておらず、IdPのユーザーのIDのユーザーが既に存在する", body = ErrorResponseBody),
+        (status = 500, description = "IdPとの通信に失敗", body = ErrorResponseBody),
+    ),
+    tag = "auth"
+)]
+pub async fn oidc_callback(
+    State(state): State<AppState>,
+    cookies: Cookies,
+    headers: HeaderMap,
+    Query(query): Query<OidcCallbackQueryParams>,
+) -> Result<Redirect, PresentationError> {
+    let use_case = state.complete_oidc_login_use_case.as_ref().ok_or_else(|| {
+        PresentationError::NotFound("シングルサインオンは設定されていません".to_string())
+    })?;
+
+    // stateのCookieは一度しか使用しないため、結果にかかわらず削除する
+    let cookie_state = cookies
+        .get(CookieHelper::OIDC_STATE_COOKIE_NAME)
+        .map(|c| c.value().to_string());
+    cookies.remove(CookieHelper::delete_oidc_state_cookie());
+
+    // IdPが認証を拒否した場合（ユーザーが同意しなかった場合など）
+    if let Some(error) = query.error {
+        let description = query
+            .error_description
+            .map(|description| format!(" ({description})"))
+            .unwrap_or_default();
+        return Err(PresentationError::Unauthorized(format!(
+            "IdPによる認証に失敗しました: {error}{description}"
+        )));
+    }
+
+    // バリデーション
+    let (Some(code), Some(oidc_state)) = (query.code, query.state) else {
+        return Err(PresentationError::BadRequest(
+            "認可コードとstateを指定してください".to_string(),
+        ));
+    };
+    // ログインを開始したブラウザからのリクエストであることを確認する
+    if cookie_state.as_deref() != Some(oidc_state.as_str()) {
+        return Err(PresentationError::BadRequest(
+            "stateが一致しません。もう一度ログインしてください".to_string(),
+        ));
+    }
+
+    // ログイン処理
+    let command = CompleteOidcLoginCommand {
+        state: oidc_state,
+        code,
+        // セッション一覧で端末を見分けられるよう、User-Agentを記録する
+        user_agent: headers
+            .get(USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string()),
+    };
+    let result = use_case.execute(command).await.map_err(|e| match e {
+        OidcLoginError::InvalidState => PresentationError::BadRequest(e.to_string()),
+        OidcLoginError::IdentityProvider(IdentityProviderError::Unauthenticated { .. }) => {
+            PresentationError::Unauthorized(e.to_string())
+        }
+        OidcLoginError::IdentityProvider(IdentityProviderError::Unavailable { .. }) => {
+            PresentationError::InternalServerError(e.to_string())
+        }
+        OidcLoginError::InvalidClaim(_) | OidcLoginError::RoleNotAssigned => {
+            PresentationError::Forbidden(e.to_string())
+        }
+        OidcLoginError::UserIdAlreadyExists(_) => PresentationError::Conflict(e.to_string()),
+        OidcLoginError::Repository(e) => PresentationError::from(e),
+    })?;
+
+    // Cookie設定
+    let cookie =
+        CookieHelper::create_session_cookie(result.session_id, Session::DEFAULT_EXPIRY_MINUTES);
+    cookies.add(cookie);
+
+    Ok(Redirect::to(&result.redirect_to))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_oidc_test_data;
+    use crate::{
+        internal::domain::{
+            entity::UserIdentity,
+            value_object::{Id, LocalLoginPolicy, Role},
+        },
+        startup,
+    };
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode, response::Response},
+    };
+    use chrono::Utc;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    /// ログインを開始し、IdPの認可エンドポイントのURLを返す
+    async fn start_login(router: &Router, redirect_to: &str) -> String {
+        let request = Request::builder()
+            .method("GET")
+            .uri(format!("/oidc/login?redirectTo={redirect_to}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+        response
+            .headers()
+            .get("location")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// IdPからのリダイレクトを模したリクエストを作成する
+    fn callback_request(code: &str, state: &str, cookie_state: &str) -> Request<Body> {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/oidc/callback?code={code}&state={state}"))
+            .header("cookie", format!("oidc_state={cookie_state}"))
+            .header("user-agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64)")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    fn session_id(response: &Response<Body>) -> Option<String> {
+        response
+            .headers()
+            .get_all("set-cookie")
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .find_map(|v| v.strip_prefix("session_id="))
+            .map(|v| v.split(';').next().unwrap().to_string())
+    }
+
+    #[tokio::test]
+    async fn 初めてログインしたIdPのユーザーが作成されログインできる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let authorization_url = start_login(&router, "%2F").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({
+                "sub": "idp-0001",
+                "preferred_username": "radiologist",
+                "name": "放射線科 花子",
+                "groups": ["technologists", "radiologists", "nurses"],
+            }),
+        );
+
+        // Act
+        let response = router
+            .clone()
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+
+        // Assert
+        // ステータスコードとリダイレクト先の確認
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers().get("location").unwrap(), "/");
+
+        // stateのCookieが削除されていることの確認
+        assert!(
+            response
+                .headers()
+                .get_all("set-cookie")
+                .iter()
+                .any(|v| v.to_str().unwrap().starts_with("oidc_state=;"))
+        );
+
+        // ユーザーが作成されていることの確認（最も権限の大きいロールが割り当てられる）
+        let user = repos
+            .user_repository
+            .find_by_id(&Id::new("radiologist").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.name().value(), "放射線科 花子");
+        assert_eq!(user.role(), Role::Doctor);
+
+        // IdPのユーザーが紐付けられていることの確認
+        let user_identity = repos
+            .user_identity_repository
+            .find_by_subject(identity_provider.issuer(), "idp-0001")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user_identity.user_uuid(), user.uuid());
+
+        // 確立したセッションでAPIを呼び出せることの確認
+        let session_id = session_id(&response).unwrap();
+        let request = Request::builder()
+            .method("GET")
+            .uri("/me")
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["userId"], "radiologist");
+        assert_eq!(body["role"], 2); // Doctor
+    }
+
+    #[tokio::test]
+    async fn ログインのたびにIdPのグループに合わせてロールが変更される() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let authorization_url = start_login(&router, "%2F").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({
+                "sub": "idp-0001",
+                "preferred_username": "radiologist",
+                "groups": ["radiologists"],
+            }),
+        );
+        let response = router
+            .clone()
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+        // 2回目のログインではユーザーIDのクレームが変わっても、サブジェクトで同じユーザーと判断する
+        let authorization_url = start_login(&router, "%2F").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({
+                "sub": "idp-0001",
+                "preferred_username": "renamed",
+                "groups": ["technologists"],
+            }),
+        );
+
+        // Act
+        let response = router
+            .clone()
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+        let user = repos
+            .user_repository
+            .find_by_id(&Id::new("radiologist").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.role(), Role::Technician);
+        assert_eq!(
+            user.updated_by().to_string(),
+            "00000000-0000-7000-8000-000000000000"
+        );
+        assert!(
+            repos
+                .user_repository
+                .find_by_id(&Id::new("renamed").unwrap())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn 同じIDのユーザーがいてもIdPのユーザーは紐付けられず409エラーになる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let authorization_url = start_login(&router, "%2F").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({
+                "sub": "idp-0002",
+                "preferred_username": "doctor",
+                "groups": ["pacs-admins"],
+            }),
+        );
+
+        // Act
+        let response = router
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert!(session_id(&response).is_none());
+
+        // 既存のユーザーが変更されていないことの確認
+        let user = repos
+            .user_repository
+            .find_by_id(&Id::new("doctor").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.role(), Role::Doctor);
+        assert!(
+            repos
+                .user_identity_repository
+                .find_by_subject(identity_provider.issuer(), "idp-0002")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn IdPのユーザーIDがadminでも管理者のアカウントでログインできない() {
+        // Arrange
+        let (mut repos, identity_provider) = prepare_oidc_test_data().await;
+        // 緊急用のアカウントを指定していない場合
+        repos.local_login_policy = LocalLoginPolicy::AllUsers;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let authorization_url = start_login(&router, "%2F").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({
+                "sub": "idp-0003",
+                "preferred_username": "admin",
+                "groups": ["technologists"],
+            }),
+        );
+
+        // Act
+        let response = router
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert!(session_id(&response).is_none());
+
+        // 管理者のアカウントが変更されず、IdPのユーザーが紐付けられていないことの確認
+        let user = repos
+            .user_repository
+            .find_by_id(&Id::new("admin").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.role(), Role::Admin);
+        assert!(
+            repos
+                .user_identity_repository
+                .find_by_subject(identity_provider.issuer(), "idp-0003")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn 管理者が紐付けたIdPのユーザーは既存のユーザーとしてログインできる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let doctor_uuid = Uuid::parse_str("492236d4-2f18-76ab-a82f-84e29fcf92f8").unwrap();
+        repos
+            .user_identity_repository
+            .add(&UserIdentity::new(
+                identity_provider.issuer(),
+                "idp-0002",
+                doctor_uuid,
+                Utc::now(),
+            ))
+            .await
+            .unwrap();
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // ユーザーIDのクレームが異なっても、サブジェクトで紐付けたユーザーとしてログインする
+        let authorization_url = start_login(&router, "%2F").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({
+                "sub": "idp-0002",
+                "preferred_username": "taro.doctor",
+                "groups": ["radiologists"],
+            }),
+        );
+
+        // Act
+        let response = router
+            .clone()
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+        let session = repos
+            .session_repository
+            .find_by_session_id(&session_id(&response).unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(*session.user_uuid(), doctor_uuid);
+        assert!(
+            repos
+                .user_repository
+                .find_by_id(&Id::new("taro.doctor").unwrap())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn ロールに対応するグループに所属していない場合は403エラーになる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let authorization_url = start_login(&router, "%2F").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({
+                "sub": "idp-0004",
+                "preferred_username": "nurse",
+                "groups": ["nurses"],
+            }),
+        );
+
+        // Act
+        let response = router
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(
+            repos
+                .user_repository
+                .find_by_id(&Id::new("nurse").unwrap())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn stateがCookieと一致しない場合は400エラーになる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let authorization_url = start_login(&router, "%2F").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({ "sub": "idp-0001", "preferred_username": "radiologist", "groups": ["radiologists"] }),
+        );
+
+        // Act
+        // 攻撃者が開始したログインのコールバックを、別のブラウザで開かせた場合
+        let response = router
+            .oneshot(callback_request(&code, &oidc_state, "another-state"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(session_id(&response).is_none());
+    }
+
+    #[tokio::test]
+    async fn 使用済みのstateで再度ログインすると400エラーになる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let authorization_url = start_login(&router, "%2F").await;
+        let claims = json!({ "sub": "idp-0001", "preferred_username": "radiologist", "groups": ["radiologists"] });
+        let (code, oidc_state) = identity_provider.authorize(&authorization_url, claims.clone());
+        let response = router
+            .clone()
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+        let (code, oidc_state) = identity_provider.authorize(&authorization_url, claims);
+
+        // Act
+        let response = router
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn IDトークンの検証に失敗した場合は401エラーになる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let claims = [
+            // 対象者が異なる
+            json!({ "sub": "idp-0001", "preferred_username": "radiologist", "groups": ["radiologists"], "aud": "another-client" }),
+            // 有効期限切れ
+            json!({ "sub": "idp-0001", "preferred_username": "radiologist", "groups": ["radiologists"], "exp": Utc::now().timestamp() - 3600 }),
+            // nonceが異なる
+            json!({ "sub": "idp-0001", "preferred_username": "radiologist", "groups": ["radiologists"], "nonce": "another-nonce" }),
+            // 発行者が異なる
+            json!({ "sub": "idp-0001", "preferred_username": "radiologist", "groups": ["radiologists"], "iss": "https://evil.example.com" }),
+        ];
+
+        for claims in claims {
+            let authorization_url = start_login(&router, "%2F").await;
+            let (code, oidc_state) = identity_provider.authorize(&authorization_url, claims);
+
+            // Act
+            let response = router
+                .clone()
+                .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+                .await
+                .unwrap();
+
+            // Assert
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert!(session_id(&response).is_none());
+        }
+        assert!(
+            repos
+                .user_repository
+                .find_by_id(&Id::new("radiologist").unwrap())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn IdPが認証を拒否した場合は401エラーになる() {
+        // Arrange
+        let (repos, _identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/oidc/callback?error=access_denied&error_description=User%20denied&state=abc")
+            .header("cookie", "oidc_state=abc")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert!(body["detail"].as_str().unwrap().contains("access_denied"));
+    }
+
+    #[tokio::test]
+    async fn ログイン開始時に指定したパスにリダイレクトされる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let authorization_url = start_login(&router, "%2Fstudies%3Fpage%3D2").await;
+        let (code, oidc_state) = identity_provider.authorize(
+            &authorization_url,
+            json!({ "sub": "idp-0001", "preferred_username": "radiologist", "groups": ["radiologists"] }),
+        );
+
+        // Act
+        let response = router
+            .oneshot(callback_request(&code, &oidc_state, &oidc_state))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(
+            response.headers().get("location").unwrap(),
+            "/studies?page=2"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// IdPの認可エンドポイントからのリダイレクトで渡されるパラメータ
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OidcCallbackQueryParams {
+    /// 認可コード
+    pub code: Option<String>,
+    /// 認可リクエストのstate
+    pub state: Option<String>,
+    /// IdPが認証を拒否した場合のエラーコード
+    pub error: Option<String>,
+    /// IdPが認証を拒否した場合のエラーの説明
+    pub error_description: Option<String>,
+}
@@ -0,0 +1,211 @@
+mod query_params;
+
+pub use self::query_params::OidcLoginQueryParams;
+
+use crate::{
+    internal::{
+        application::auth::OidcLoginError,
+        domain::{entity::OidcAuthorizationRequest, error::IdentityProviderError},
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            util::CookieHelper,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    extract::{Query, State},
+    response::Redirect,
+};
+use tower_cookies::Cookies;
+
+#[utoipa::path(
+    get,
+    path = "/oidc/login",
+    params(OidcLoginQueryParams),
+    responses(
+        (status = 303, description = "IdPの認可エンドポイントにリダイレクト"),
+        (status = 404, description = "シングルサインオンが設定されていない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーション失敗", body = ErrorResponseBody),
+        (status = 500, description = "IdPとの通信に失敗", body = ErrorResponseBody),
+    ),
+    tag = "auth"
+)]
+pub async fn oidc_login(
+    State(state): State<AppState>,
+    cookies: Cookies,
+    Query(query): Query<OidcLoginQueryParams>,
+) -> Result<Redirect, PresentationError> {
+    let use_case = state.start_oidc_login_use_case.as_ref().ok_or_else(|| {
+        PresentationError::NotFound("シングルサインオンは設定されていません".to_string())
+    })?;
+
+    // バリデーション
+    // 他のサイトへのリダイレクトに悪用されないよう、ログイン後のリダイレクト先は同じオリジンのパスに限る
+    let redirect_to = query.redirect_to.unwrap_or_else(|| "/".to_string());
+    if !redirect_to.starts_with('/') || redirect_to.starts_with("//") || redirect_to.contains('\\')
+    {
+        return Err(PresentationError::UnprocessableContent(format!(
+            "ログイン後のリダイレクト先には\"/\"で始まるパスを指定してください: {redirect_to}"
+        )));
+    }
+
+    // 認可リクエストを作成
+    let (authorization_url, oidc_state) =
+        use_case.execute(&redirect_to).await.map_err(|e| match e {
+            OidcLoginError::IdentityProvider(IdentityProviderError::Unauthenticated { .. }) => {
+                PresentationError::Unauthorized(e.to_string())
+            }
+            OidcLoginError::Repository(e) => PresentationError::from(e),
+            _ => PresentationError::InternalServerError(e.to_string()),
+        })?;
+
+    // IdPからのリダイレクトがログインを開始したブラウザからのものか検証できるよう、stateをCookieに保持する
+    cookies.add(CookieHelper::create_oidc_state_cookie(
+        oidc_state,
+        OidcAuthorizationRequest::DEFAULT_EXPIRY_MINUTES,
+    ));
+
+    Ok(Redirect::to(&authorization_url))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::{prepare_oidc_test_data, prepare_test_data};
+    use crate::startup;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64URL};
+    use sha2::{Digest, Sha256};
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+    use url::Url;
+
+    #[tokio::test]
+    async fn シングルサインオンが設定されていない場合は404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/oidc/login")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn IdPの認可エンドポイントにPKCEのパラメータを付けてリダイレクトされる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/oidc/login?redirectTo=%2Fstudies%3Fpage%3D2")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+
+        // リダイレクト先の確認
+        let location = response
+            .headers()
+            .get("location")
+            .unwrap()
+            .to_str()
+            .unwrap();
+        let url = Url::parse(location).unwrap();
+        assert_eq!(
+            url.as_str().split('?').next().unwrap(),
+            format!("{}/authorize", identity_provider.issuer())
+        );
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(params["response_type"], "code");
+        assert_eq!(params["client_id"], "oceanus");
+        assert_eq!(params["redirect_uri"], "http://localhost/oidc/callback");
+        assert_eq!(params["scope"], "openid profile");
+        assert_eq!(params["code_challenge_method"], "S256");
+
+        // stateがCookieに保持されていることの確認
+        let set_cookie = response
+            .headers()
+            .get("set-cookie")
+            .unwrap()
+            .to_str()
+            .unwrap();
+        assert!(set_cookie.starts_with(&format!("oidc_state={};", params["state"])));
+        assert!(set_cookie.contains("HttpOnly"));
+        assert!(set_cookie.contains("SameSite=Lax"));
+
+        // 認可リクエストがリポジトリに保存されていることの確認
+        let authorization_request = repos
+            .oidc_authorization_request_repository
+            .take(&params["state"])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(authorization_request.redirect_to(), "/studies?page=2");
+        assert_eq!(authorization_request.nonce(), params["nonce"]);
+        assert_eq!(
+            BASE64URL.encode(Sha256::digest(
+                authorization_request.code_verifier().as_bytes()
+            )),
+            params["code_challenge"]
+        );
+    }
+
+    #[tokio::test]
+    async fn ログイン後のリダイレクト先が他のサイトの場合は422エラーになる() {
+        // Arrange
+        let (repos, _identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = |redirect_to: &str| {
+            Request::builder()
+                .method("GET")
+                .uri(format!("/oidc/login?redirectTo={redirect_to}"))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        // Act
+        let response1 = router
+            .clone()
+            .oneshot(request("https%3A%2F%2Fevil.example.com%2F"))
+            .await
+            .unwrap();
+        let response2 = router
+            .clone()
+            .oneshot(request("%2F%2Fevil.example.com%2F"))
+            .await
+            .unwrap();
+        let response3 = router
+            .clone()
+            .oneshot(request("%2F%5Cevil.example.com%2F"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response1.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response2.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response3.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
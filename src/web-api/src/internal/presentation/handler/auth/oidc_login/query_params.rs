@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct OidcLoginQueryParams {
+    /// ログイン後にリダイレクトするパス（"/"で始まるパス）。既定値は"/"
+    pub redirect_to: Option<String>,
+}
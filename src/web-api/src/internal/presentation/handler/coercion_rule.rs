@@ -0,0 +1,94 @@
+pub mod list_coercion_rules;
+pub mod replace_coercion_rules;
+
+pub use self::{
+    list_coercion_rules::list_coercion_rules, replace_coercion_rules::replace_coercion_rules,
+};
+
+#[cfg(test)]
+pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
+    use crate::{
+        internal::{
+            domain::{
+                entity::{CoercionOperation, CoercionRule, User},
+                repository::UserRepository,
+                value_object::{Id, Role, UserName},
+            },
+            infrastructure::repository::{TestCoercionRuleRepository, TestUserRepository},
+        },
+        startup,
+    };
+    use chrono::DateTime;
+    use dicom_lib::core::Tag;
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    let user_repository = Arc::new(TestUserRepository::new());
+    user_repository.add(&User::construct(
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        Id::new("admin").unwrap(),
+        UserName::new("管理者 太郎").unwrap(),
+        Role::Admin,
+        "$argon2id$v=19$m=19456,t=2,p=1$Zf/xy2I09QAEAvKnXga60w$arwk9jM50i/6RAjgZ2+N6fiRq0WWJFX3GmngTw+n34Y",
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        Uuid::parse_str("4922356e-d6a0-7083-8e18-93b7a023c328").unwrap(),
+        Id::new("it").unwrap(),
+        UserName::new("情シス 太郎").unwrap(),
+        Role::ItStaff,
+        "$argon2id$v=19$m=19456,t=2,p=1$20Tk1g6xZ9BdBDcrKqWy1A$//ZKdw5sFbvtSwtbgnBapb3u1r112qUBz6QVG3JuzzU",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap(),
+        Id::new("technician").unwrap(),
+        UserName::new("技師 太郎").unwrap(),
+        Role::Technician,
+        "$argon2id$v=19$m=19456,t=2,p=1$HLCrMDHifn55j/Kq5M6t0g$lIVsN8r8+osWzQmU6n5khyRZk8TNeB9/qn4NeULwVfI",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+    )).await.unwrap();
+
+    let coercion_rule_repository = Arc::new(TestCoercionRuleRepository::new());
+    coercion_rule_repository
+        .add(
+            "DCMTK",
+            vec![
+                CoercionRule::new(
+                    Tag(0x0010, 0x0020),
+                    CoercionOperation::Replace {
+                        pattern: "^HOSP-".to_string(),
+                        replacement: String::new(),
+                    },
+                )
+                .unwrap(),
+                CoercionRule::new(
+                    Tag(0x0008, 0x0080),
+                    CoercionOperation::Map {
+                        mappings: vec![
+                            ("OCEANUS HOSP".to_string(), "Oceanus Hospital".to_string()),
+                            ("oceanus".to_string(), "Oceanus Hospital".to_string()),
+                        ],
+                    },
+                )
+                .unwrap(),
+            ],
+        )
+        .await;
+    coercion_rule_repository.add("OsiriX", Vec::new()).await;
+
+    let mut repos = startup::Repos::new_for_test();
+    repos.user_repository = user_repository;
+    repos.coercion_rule_repository = coercion_rule_repository;
+
+    repos
+}
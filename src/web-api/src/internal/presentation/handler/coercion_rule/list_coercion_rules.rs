@@ -0,0 +1,211 @@
+mod response_body;
+
+pub use self::response_body::{
+    ListCoercionRulesResponseBodyItem, ListCoercionRulesResponseBodyMapping,
+};
+
+use crate::{
+    internal::presentation::error::{ErrorResponseBody, PresentationError},
+    startup::AppState,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+
+#[utoipa::path(
+    get,
+    path = "/application-entities/{ae_title}/coercion-rules",
+    params(
+        ("ae_title" = String, Path, description = "呼出元AEのAE Title")
+    ),
+    responses(
+        (status = 200, description = "書き換え規則一覧の取得に成功", body = Vec<ListCoercionRulesResponseBodyItem>),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "権限がない", body = ErrorResponseBody),
+        (status = 404, description = "対象のAEが見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーション失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "application-entities"
+)]
+pub async fn list_coercion_rules(
+    State(state): State<AppState>,
+    Path(ae_title): Path<String>,
+) -> Result<Json<Vec<ListCoercionRulesResponseBodyItem>>, PresentationError> {
+    // バリデーション
+    let title = AeValue::from_string(&ae_title).map_err(|e| {
+        PresentationError::UnprocessableContent(format!("AEタイトルが不正です: {e}"))
+    })?;
+
+    let response_body = state
+        .list_coercion_rules_use_case
+        .execute(&title)
+        .await
+        .map(|rules| {
+            rules
+                .into_iter()
+                .map(ListCoercionRulesResponseBodyItem::from)
+                .collect()
+        })
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn 管理者は書き換え規則一覧を適用順に取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/application-entities/DCMTK/coercion-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body,
+            json!([
+                {
+                    "tag": "00100020",
+                    "action": "replace",
+                    "value": null,
+                    "sourceTag": null,
+                    "pattern": "^HOSP-",
+                    "replacement": "",
+                    "mappings": null,
+                },
+                {
+                    "tag": "00080080",
+                    "action": "map",
+                    "value": null,
+                    "sourceTag": null,
+                    "pattern": null,
+                    "replacement": null,
+                    "mappings": [
+                        { "from": "OCEANUS HOSP", "to": "Oceanus Hospital" },
+                        { "from": "oceanus", "to": "Oceanus Hospital" },
+                    ],
+                },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn 情シスは書き換え規則一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/application-entities/OsiriX/coercion-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認（規則が登録されていないAEは空配列）
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body, json!([]));
+    }
+
+    #[tokio::test]
+    async fn 存在しないAEの書き換え規則一覧を取得しようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/application-entities/HOROS/coercion-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 管理者でも情シスでもないユーザーが書き換え規則一覧を取得しようとすると403エラーになる()
+    {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/application-entities/DCMTK/coercion-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
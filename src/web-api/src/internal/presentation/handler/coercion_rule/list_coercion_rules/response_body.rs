@@ -0,0 +1,76 @@
+use crate::internal::domain::entity::{CoercionOperation, CoercionRule};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListCoercionRulesResponseBodyItem {
+    /// 書き換える属性のタグ（"00100020"の形式）
+    pub tag: String,
+    /// 処理（"set", "copy", "delete", "replace", "map"のいずれか）
+    pub action: String,
+    /// 設定する値（"set"の場合のみ）
+    pub value: Option<String>,
+    /// 複写元の属性のタグ（"copy"の場合のみ）
+    pub source_tag: Option<String>,
+    /// 正規表現（"replace"の場合のみ）
+    pub pattern: Option<String>,
+    /// 置換後の文字列（"replace"の場合のみ）
+    pub replacement: Option<String>,
+    /// 値の対応付け（"map"の場合のみ）
+    pub mappings: Option<Vec<ListCoercionRulesResponseBodyMapping>>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ListCoercionRulesResponseBodyMapping {
+    pub from: String,
+    pub to: String,
+}
+
+impl From<CoercionRule> for ListCoercionRulesResponseBodyItem {
+    fn from(rule: CoercionRule) -> Self {
+        let mut item = Self {
+            tag: format!("{:X}", rule.tag()),
+            action: String::new(),
+            value: None,
+            source_tag: None,
+            pattern: None,
+            replacement: None,
+            mappings: None,
+        };
+        match rule.operation() {
+            CoercionOperation::Set { value } => {
+                item.action = "set".to_string();
+                item.value = Some(value.clone());
+            }
+            CoercionOperation::Copy { source_tag } => {
+                item.action = "copy".to_string();
+                item.source_tag = Some(format!("{source_tag:X}"));
+            }
+            CoercionOperation::Delete => {
+                item.action = "delete".to_string();
+            }
+            CoercionOperation::Replace {
+                pattern,
+                replacement,
+            } => {
+                item.action = "replace".to_string();
+                item.pattern = Some(pattern.clone());
+                item.replacement = Some(replacement.clone());
+            }
+            CoercionOperation::Map { mappings } => {
+                item.action = "map".to_string();
+                item.mappings = Some(
+                    mappings
+                        .iter()
+                        .map(|(from, to)| ListCoercionRulesResponseBodyMapping {
+                            from: from.clone(),
+                            to: to.clone(),
+                        })
+                        .collect(),
+                );
+            }
+        }
+        item
+    }
+}
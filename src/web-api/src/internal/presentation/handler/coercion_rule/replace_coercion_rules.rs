@@ -0,0 +1,391 @@
+mod request_body;
+mod response_body;
+
+pub use self::{
+    request_body::{
+        ReplaceCoercionRulesRequestBody, ReplaceCoercionRulesRequestBodyMapping,
+        ReplaceCoercionRulesRequestBodyRule,
+    },
+    response_body::{
+        ReplaceCoercionRulesResponseBodyItem, ReplaceCoercionRulesResponseBodyMapping,
+    },
+};
+
+use crate::{
+    internal::{
+        application::coercion_rule::replace_coercion_rules_use_case::ReplaceCoercionRulesCommand,
+        domain::entity::{CoercionOperation, CoercionRule},
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use chrono::Utc;
+use dicom_lib::core::{Tag, value::value_representations::ae::AeValue};
+
+#[utoipa::path(
+    put,
+    path = "/application-entities/{ae_title}/coercion-rules",
+    request_body = ReplaceCoercionRulesRequestBody,
+    params(
+        ("ae_title" = String, Path, description = "呼出元AEのAE Title")
+    ),
+    responses(
+        (status = 200, description = "書き換え規則の置き換えに成功", body = Vec<ReplaceCoercionRulesResponseBodyItem>),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "対象のAEが見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーション失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "application-entities"
+)]
+pub async fn replace_coercion_rules(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(ae_title): Path<String>,
+    Json(request_body): Json<ReplaceCoercionRulesRequestBody>,
+) -> Result<Json<Vec<ReplaceCoercionRulesResponseBodyItem>>, PresentationError> {
+    // バリデーション
+    let title = AeValue::from_string(&ae_title).map_err(|e| {
+        PresentationError::UnprocessableContent(format!("AEタイトルが不正です: {e}"))
+    })?;
+    let rules = request_body
+        .rules
+        .into_iter()
+        .enumerate()
+        .map(|(i, rule)| {
+            to_coercion_rule(rule).map_err(|e| {
+                PresentationError::UnprocessableContent(format!(
+                    "{}番目の書き換え規則が不正です: {e}",
+                    i + 1
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // 置き換え処理
+    let command = ReplaceCoercionRulesCommand {
+        title,
+        rules,
+        updated_by: user.uuid(),
+        updated_at: Utc::now(),
+    };
+    let rules = state
+        .replace_coercion_rules_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    let response_body = rules
+        .into_iter()
+        .map(ReplaceCoercionRulesResponseBodyItem::from)
+        .collect();
+
+    Ok(Json(response_body))
+}
+
+/// リクエストボディの書き換え規則をエンティティに変換する。
+fn to_coercion_rule(rule: ReplaceCoercionRulesRequestBodyRule) -> Result<CoercionRule, String> {
+    let tag = parse_tag(&rule.tag)?;
+    let operation = match rule.action.as_str() {
+        "set" => CoercionOperation::Set {
+            value: rule.value.ok_or("valueが指定されていません")?,
+        },
+        "copy" => CoercionOperation::Copy {
+            source_tag: parse_tag(
+                rule.source_tag
+                    .as_deref()
+                    .ok_or("sourceTagが指定されていません")?,
+            )?,
+        },
+        "delete" => CoercionOperation::Delete,
+        "replace" => CoercionOperation::Replace {
+            pattern: rule.pattern.ok_or("patternが指定されていません")?,
+            replacement: rule.replacement.ok_or("replacementが指定されていません")?,
+        },
+        "map" => CoercionOperation::Map {
+            mappings: rule
+                .mappings
+                .ok_or("mappingsが指定されていません")?
+                .into_iter()
+                .map(|mapping| (mapping.from, mapping.to))
+                .collect(),
+        },
+        action => return Err(format!("不明な処理です (action=\"{action}\")")),
+    };
+
+    CoercionRule::new(tag, operation)
+}
+
+fn parse_tag(tag: &str) -> Result<Tag, String> {
+    tag.parse::<Tag>()
+        .map_err(|e| format!("タグが不正です (タグ=\"{tag}\"): {e}"))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{
+        internal::{
+            domain::entity::{CoercionOperation, CoercionRule},
+            presentation::util::test_helpers,
+        },
+        startup,
+    };
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use dicom_lib::core::{Tag, value::value_representations::ae::AeValue};
+    use futures::future::join_all;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn 管理者は書き換え規則を置き換えられる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let body = json!({
+            "rules": [
+                { "tag": "00080080", "action": "set", "value": "Oceanus Hospital" },
+                { "tag": "00080090", "action": "delete" },
+                { "tag": "00100021", "action": "copy", "sourceTag": "00080080" },
+                { "tag": "00100020", "action": "replace", "pattern": "^(\\d+)-\\d+$", "replacement": "$1" },
+                { "tag": "00080060", "action": "map", "mappings": [{ "from": "PX", "to": "DX" }] },
+            ]
+        });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/application-entities/DCMTK/coercion-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let actions = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|rule| rule["action"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(actions, ["set", "delete", "copy", "replace", "map"]);
+        assert_eq!(body[2]["sourceTag"], "00080080");
+        assert_eq!(body[3]["replacement"], "$1");
+
+        // リポジトリの確認
+        let stored = repos
+            .coercion_rule_repository
+            .find_by_ae_title(&AeValue::from_string("DCMTK").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            stored,
+            vec![
+                CoercionRule::new(
+                    Tag(0x0008, 0x0080),
+                    CoercionOperation::Set {
+                        value: "Oceanus Hospital".to_string()
+                    }
+                )
+                .unwrap(),
+                CoercionRule::new(Tag(0x0008, 0x0090), CoercionOperation::Delete).unwrap(),
+                CoercionRule::new(
+                    Tag(0x0010, 0x0021),
+                    CoercionOperation::Copy {
+                        source_tag: Tag(0x0008, 0x0080)
+                    }
+                )
+                .unwrap(),
+                CoercionRule::new(
+                    Tag(0x0010, 0x0020),
+                    CoercionOperation::Replace {
+                        pattern: "^(\\d+)-\\d+$".to_string(),
+                        replacement: "$1".to_string()
+                    }
+                )
+                .unwrap(),
+                CoercionRule::new(
+                    Tag(0x0008, 0x0060),
+                    CoercionOperation::Map {
+                        mappings: vec![("PX".to_string(), "DX".to_string())]
+                    }
+                )
+                .unwrap(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn 情シスは空の規則で書き換え規則をすべて削除できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({ "rules": [] });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/application-entities/DCMTK/coercion-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // リポジトリの確認
+        let stored = repos
+            .coercion_rule_repository
+            .find_by_ae_title(&AeValue::from_string("DCMTK").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.is_empty());
+    }
+
+    #[tokio::test]
+    async fn 存在しないAEの書き換え規則を置き換えようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let body = json!({ "rules": [{ "tag": "00080090", "action": "delete" }] });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/application-entities/HOROS/coercion-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 管理者でも情シスでもないユーザーが書き換え規則を置き換えようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let body = json!({ "rules": [] });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/application-entities/DCMTK/coercion-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // リポジトリの確認（変更されていない）
+        let stored = repos
+            .coercion_rule_repository
+            .find_by_ae_title(&AeValue::from_string("DCMTK").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn リクエストボディのバリデーション違反の場合に422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let bodies = [
+            // 不明な処理
+            json!({ "rules": [{ "tag": "00080080", "action": "upper" }] }),
+            // タグの形式が不正
+            json!({ "rules": [{ "tag": "(0008,0080)", "action": "delete" }] }),
+            // 文字列を値として持たない属性 (Rows)
+            json!({ "rules": [{ "tag": "00280010", "action": "delete" }] }),
+            // 書き換えが禁止されている属性 (SOP Instance UID)
+            json!({ "rules": [{ "tag": "00080018", "action": "set", "value": "1.2.3" }] }),
+            // 複写元の属性が指定されていない
+            json!({ "rules": [{ "tag": "00100021", "action": "copy" }] }),
+            // 正規表現が不正
+            json!({ "rules": [{ "tag": "00100020", "action": "replace", "pattern": "(", "replacement": "" }] }),
+            // 対応付けが空
+            json!({ "rules": [{ "tag": "00080060", "action": "map", "mappings": [] }] }),
+            // 設定する値が指定されていない
+            json!({ "rules": [{ "tag": "00080080", "action": "set" }] }),
+        ];
+        let requests = bodies.iter().map(|body| {
+            Request::builder()
+                .method("PUT")
+                .uri("/application-entities/DCMTK/coercion-rules")
+                .header("content-type", "application/json")
+                .header("cookie", format!("session_id={session_id}"))
+                .header("x-csrf-token", &csrf_token)
+                .body(Body::from(serde_json::to_string(&body).unwrap()))
+                .unwrap()
+        });
+
+        // Act
+        let responses = join_all(requests.map(|req| router.clone().oneshot(req))).await;
+
+        // Assert
+        responses.into_iter().for_each(|res| {
+            // ステータスコードの確認
+            assert_eq!(res.unwrap().status(), StatusCode::UNPROCESSABLE_ENTITY);
+        });
+    }
+}
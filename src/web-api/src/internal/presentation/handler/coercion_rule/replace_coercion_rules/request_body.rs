@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct ReplaceCoercionRulesRequestBody {
+    /// 適用順に並べた書き換え規則（空の場合はすべての規則を削除する）
+    pub rules: Vec<ReplaceCoercionRulesRequestBodyRule>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceCoercionRulesRequestBodyRule {
+    /// 書き換える属性のタグ（"00100020"の形式）
+    pub tag: String,
+    /// 処理（"set", "copy", "delete", "replace", "map"のいずれか）
+    pub action: String,
+    /// 設定する値（"set"の場合に必須）
+    pub value: Option<String>,
+    /// 複写元の属性のタグ（"copy"の場合に必須）
+    pub source_tag: Option<String>,
+    /// 正規表現（"replace"の場合に必須）
+    pub pattern: Option<String>,
+    /// 置換後の文字列（"replace"の場合に必須。`$1`等でキャプチャグループを参照できる）
+    pub replacement: Option<String>,
+    /// 値の対応付け（"map"の場合に必須）
+    pub mappings: Option<Vec<ReplaceCoercionRulesRequestBodyMapping>>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct ReplaceCoercionRulesRequestBodyMapping {
+    pub from: String,
+    pub to: String,
+}
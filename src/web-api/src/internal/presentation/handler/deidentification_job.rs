@@ -0,0 +1,96 @@
+pub mod create_deidentification_job;
+pub mod get_deidentification_job;
+
+pub use self::{
+    create_deidentification_job::create_deidentification_job,
+    get_deidentification_job::get_deidentification_job,
+};
+
+#[cfg(test)]
+pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
+    use crate::{
+        internal::{
+            domain::{
+                entity::{DeidentificationJob, User},
+                repository::{DeidentificationJobRepository, UserRepository},
+                value_object::{DeidentificationJobStatus, Id, Role, UserName},
+            },
+            infrastructure::repository::{
+                TestDeidentificationJobRepository, TestDicomFileRepository, TestUserRepository,
+            },
+        },
+        startup,
+    };
+    use chrono::DateTime;
+    use dicom_lib::deidentification::DeidentificationOptions;
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    let user_repository = Arc::new(TestUserRepository::new());
+    user_repository.add(&User::construct(
+        Uuid::parse_str("4922356e-d6a0-7083-8e18-93b7a023c328").unwrap(),
+        Id::new("it").unwrap(),
+        UserName::new("情シス 太郎").unwrap(),
+        Role::ItStaff,
+        "$argon2id$v=19$m=19456,t=2,p=1$20Tk1g6xZ9BdBDcrKqWy1A$//ZKdw5sFbvtSwtbgnBapb3u1r112qUBz6QVG3JuzzU",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap(),
+        Id::new("technician").unwrap(),
+        UserName::new("技師 太郎").unwrap(),
+        Role::Technician,
+        "$argon2id$v=19$m=19456,t=2,p=1$HLCrMDHifn55j/Kq5M6t0g$lIVsN8r8+osWzQmU6n5khyRZk8TNeB9/qn4NeULwVfI",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+    )).await.unwrap();
+
+    let deidentification_job_repository = Arc::new(TestDeidentificationJobRepository::new());
+    deidentification_job_repository
+        .add_file_path(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "/var/lib/oceanus/dicom/success/1.dcm",
+        )
+        .await;
+    deidentification_job_repository
+        .add_file_path(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "/var/lib/oceanus/dicom/success/2.dcm",
+        )
+        .await;
+    deidentification_job_repository
+        .add(&DeidentificationJob::construct(
+            Uuid::parse_str("019c2b3c-4d5e-7f60-8b7c-8d9e0f1a2b3c").unwrap(),
+            "1.2.392.200036.9116.2.6.1.48.2000",
+            DeidentificationOptions {
+                retain_longitudinal_temporal_information: true,
+                retain_patient_characteristics: false,
+                clean_descriptors: true,
+            },
+            DeidentificationJobStatus::Completed,
+            3,
+            vec!["/var/lib/oceanus/dicom/success/3.dcm".to_string()],
+            Uuid::parse_str("4922356e-d6a0-7083-8e18-93b7a023c328").unwrap(),
+            DateTime::from_str("2026-02-03T09:00:00.123+09:00").unwrap(),
+            Some(DateTime::from_str("2026-02-03T09:00:05.456+09:00").unwrap()),
+        ))
+        .await
+        .unwrap();
+
+    let dicom_file_repository = Arc::new(TestDicomFileRepository::new());
+    dicom_file_repository
+        .add("/var/lib/oceanus/dicom/success/1.dcm", "P000001")
+        .await;
+
+    let mut repos = startup::Repos::new_for_test();
+    repos.user_repository = user_repository;
+    repos.deidentification_job_repository = deidentification_job_repository;
+    repos.dicom_file_repository = dicom_file_repository;
+
+    repos
+}
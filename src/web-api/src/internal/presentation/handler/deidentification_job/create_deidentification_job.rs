@@ -0,0 +1,268 @@
+mod request_body;
+mod response_body;
+
+pub use self::{
+    request_body::CreateDeidentificationJobRequestBody,
+    response_body::CreateDeidentificationJobResponseBody,
+};
+
+use crate::{
+    internal::{
+        application::deidentification_job::CreateDeidentificationJobCommand,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::Utc;
+use dicom_lib::{
+    core::value::value_representations::ui::UiValue, deidentification::DeidentificationOptions,
+};
+
+#[utoipa::path(
+    post,
+    path = "/studies/{study_instance_uid}/deidentification-jobs",
+    request_body = CreateDeidentificationJobRequestBody,
+    params(
+        ("study_instance_uid" = String, Path, description = "Study Instance UID")
+    ),
+    responses(
+        (status = 202, description = "匿名化エクスポートのジョブを開始", body = CreateDeidentificationJobResponseBody),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "検査が見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "deidentification-jobs"
+)]
+pub async fn create_deidentification_job(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(study_instance_uid): Path<String>,
+    Json(request_body): Json<CreateDeidentificationJobRequestBody>,
+) -> Result<(StatusCode, Json<CreateDeidentificationJobResponseBody>), PresentationError> {
+    // バリデーション
+    UiValue::from_string(&study_instance_uid).map_err(|e| {
+        PresentationError::UnprocessableContent(format!("無効なStudy Instance UID: {e}"))
+    })?;
+
+    // ジョブの登録（エクスポートはバックグラウンドで行う）
+    let command = CreateDeidentificationJobCommand {
+        study_instance_uid,
+        options: DeidentificationOptions {
+            retain_longitudinal_temporal_information: request_body
+                .retain_longitudinal_temporal_information,
+            retain_patient_characteristics: request_body.retain_patient_characteristics,
+            clean_descriptors: request_body.clean_descriptors,
+        },
+        created_by: user.uuid(),
+        created_at: Utc::now(),
+    };
+    let entity = state
+        .create_deidentification_job_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    let response_body = CreateDeidentificationJobResponseBody::from(entity);
+
+    Ok((StatusCode::ACCEPTED, Json(response_body)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{
+        internal::{
+            domain::value_object::DeidentificationJobStatus, presentation::util::test_helpers,
+        },
+        startup,
+    };
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::{Value, json};
+    use std::time::Duration;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn 情シスは検査の匿名化エクスポートを開始できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "retainLongitudinalTemporalInformation": true,
+            "retainPatientCharacteristics": false,
+            "cleanDescriptors": true,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/studies/1.2.392.200036.9116.2.6.1.48.1000/deidentification-jobs")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+        // レスポンスボディの確認（実行中の状態で返る）
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body["studyInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000"
+        );
+        assert_eq!(body["retainLongitudinalTemporalInformation"], true);
+        assert_eq!(body["retainPatientCharacteristics"], false);
+        assert_eq!(body["cleanDescriptors"], true);
+        assert_eq!(body["status"], 0);
+        assert_eq!(body["exportedFileCount"], 0);
+        assert_eq!(body["failedFilePaths"], json!([]));
+        assert_eq!(body["createdBy"], "4922356e-d6a0-7083-8e18-93b7a023c328");
+        assert_eq!(body["finishedAt"], Value::Null);
+
+        // バックグラウンドのエクスポートが終了するまで待つ
+        let uuid = Uuid::parse_str(body["uuid"].as_str().unwrap()).unwrap();
+        let mut stored = None;
+        for _ in 0..100 {
+            let job = repos
+                .deidentification_job_repository
+                .find_by_uuid(&uuid)
+                .await
+                .unwrap()
+                .unwrap();
+            if job.status() != DeidentificationJobStatus::Running {
+                stored = Some(job);
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        // エクスポートに失敗したファイルは記録される
+        let stored = stored.expect("ジョブが終了するはず");
+        assert_eq!(stored.status(), DeidentificationJobStatus::Completed);
+        assert_eq!(stored.exported_file_count(), 1);
+        assert_eq!(
+            stored.failed_file_paths(),
+            ["/var/lib/oceanus/dicom/success/2.dcm"]
+        );
+        assert!(stored.finished_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn 存在しない検査を指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "retainLongitudinalTemporalInformation": false,
+            "retainPatientCharacteristics": false,
+            "cleanDescriptors": false,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/studies/1.2.392.200036.9116.2.6.1.48.9999/deidentification-jobs")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 管理者や情シスでないユーザーがエクスポートを開始しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let body = json!({
+            "retainLongitudinalTemporalInformation": false,
+            "retainPatientCharacteristics": false,
+            "cleanDescriptors": false,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/studies/1.2.392.200036.9116.2.6.1.48.1000/deidentification-jobs")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn 不正なStudy_Instance_UIDを指定すると422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "retainLongitudinalTemporalInformation": false,
+            "retainPatientCharacteristics": false,
+            "cleanDescriptors": false,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/studies/1.2.abc/deidentification-jobs")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
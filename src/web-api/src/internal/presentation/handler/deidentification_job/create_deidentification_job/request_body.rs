@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDeidentificationJobRequestBody {
+    /// 日付・時刻を保持するかどうか (Retain Longitudinal Temporal Information with Full Dates Option)
+    pub retain_longitudinal_temporal_information: bool,
+    /// 患者の性別・年齢・身長・体重等を保持するかどうか (Retain Patient Characteristics Option)
+    pub retain_patient_characteristics: bool,
+    /// 記述子から識別情報を取り除いて保持するかどうか (Clean Descriptors Option)
+    pub clean_descriptors: bool,
+}
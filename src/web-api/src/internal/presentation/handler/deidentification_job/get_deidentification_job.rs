@@ -0,0 +1,185 @@
+mod response_body;
+
+pub use self::response_body::GetDeidentificationJobResponseBody;
+
+use crate::{
+    internal::{
+        application::deidentification_job::GetDeidentificationJobCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "/deidentification-jobs/{uuid}",
+    params(
+        ("uuid" = String, Path, description = "匿名化エクスポートのジョブのUUID")
+    ),
+    responses(
+        (status = 200, description = "ジョブの取得に成功", body = GetDeidentificationJobResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "権限がない", body = ErrorResponseBody),
+        (status = 404, description = "ジョブが見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "deidentification-jobs"
+)]
+pub async fn get_deidentification_job(
+    State(state): State<AppState>,
+    Path(uuid): Path<String>,
+) -> Result<Json<GetDeidentificationJobResponseBody>, PresentationError> {
+    // バリデーション
+    let uuid = Uuid::parse_str(&uuid)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なUUID: {e}")))?;
+
+    let command = GetDeidentificationJobCommand { uuid };
+    let entity = state
+        .get_deidentification_job_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(GetDeidentificationJobResponseBody::from(entity)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn 情シスは匿名化エクスポートのジョブを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/deidentification-jobs/019c2b3c-4d5e-7f60-8b7c-8d9e0f1a2b3c")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["uuid"], "019c2b3c-4d5e-7f60-8b7c-8d9e0f1a2b3c");
+        assert_eq!(
+            body["studyInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.2000"
+        );
+        assert_eq!(body["retainLongitudinalTemporalInformation"], true);
+        assert_eq!(body["retainPatientCharacteristics"], false);
+        assert_eq!(body["cleanDescriptors"], true);
+        assert_eq!(body["status"], 1);
+        assert_eq!(body["exportedFileCount"], 3);
+        assert_eq!(
+            body["failedFilePaths"],
+            json!(["/var/lib/oceanus/dicom/success/3.dcm"])
+        );
+        assert_eq!(body["createdBy"], "4922356e-d6a0-7083-8e18-93b7a023c328");
+        assert_eq!(body["createdAt"], "2026-02-03T00:00:00.123Z");
+        assert_eq!(body["finishedAt"], "2026-02-03T00:00:05.456Z");
+    }
+
+    #[tokio::test]
+    async fn 存在しないジョブを取得しようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/deidentification-jobs/019c2b3c-4d5e-7f60-8b7c-8d9e0f1a2b3d")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 管理者や情シスでないユーザーがジョブを取得しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/deidentification-jobs/019c2b3c-4d5e-7f60-8b7c-8d9e0f1a2b3c")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn 不正なUUIDを指定すると422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/deidentification-jobs/invalid-uuid")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
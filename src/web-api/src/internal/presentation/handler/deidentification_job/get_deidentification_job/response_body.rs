@@ -0,0 +1,41 @@
+use crate::internal::domain::entity::DeidentificationJob;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDeidentificationJobResponseBody {
+    pub uuid: String,
+    pub study_instance_uid: String,
+    pub retain_longitudinal_temporal_information: bool,
+    pub retain_patient_characteristics: bool,
+    pub clean_descriptors: bool,
+    /// 0: 実行中, 1: 完了, 2: 失敗
+    pub status: i16,
+    pub exported_file_count: i32,
+    pub failed_file_paths: Vec<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl From<DeidentificationJob> for GetDeidentificationJobResponseBody {
+    fn from(entity: DeidentificationJob) -> Self {
+        let options = entity.options();
+        Self {
+            uuid: entity.uuid().to_string(),
+            study_instance_uid: entity.study_instance_uid().to_string(),
+            retain_longitudinal_temporal_information: options
+                .retain_longitudinal_temporal_information,
+            retain_patient_characteristics: options.retain_patient_characteristics,
+            clean_descriptors: options.clean_descriptors,
+            status: entity.status().as_i16(),
+            exported_file_count: entity.exported_file_count(),
+            failed_file_paths: entity.failed_file_paths().to_vec(),
+            created_by: entity.created_by().to_string(),
+            created_at: *entity.created_at(),
+            finished_at: entity.finished_at().copied(),
+        }
+    }
+}
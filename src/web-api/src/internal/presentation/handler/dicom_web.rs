@@ -0,0 +1,357 @@
+mod attribute;
+mod dicom_json;
+mod multipart;
+mod search_query;
+
+pub mod retrieve_bulk_data;
+pub mod retrieve_frames;
+pub mod retrieve_instances;
+pub mod retrieve_metadata;
+pub mod retrieve_rendered;
+pub mod search_instances;
+pub mod search_patients;
+pub mod search_series;
+pub mod search_studies;
+pub mod store_instances;
+pub mod wado_uri;
+
+pub use self::{
+    dicom_json::{DicomJsonAttribute, DicomJsonDataSet},
+    retrieve_bulk_data::retrieve_bulk_data,
+    retrieve_frames::retrieve_frames,
+    retrieve_instances::{retrieve_instance, retrieve_series, retrieve_study},
+    retrieve_metadata::{
+        retrieve_instance_metadata, retrieve_series_metadata, retrieve_study_metadata,
+    },
+    retrieve_rendered::{
+        retrieve_instance_thumbnail, retrieve_rendered_frames, retrieve_rendered_instance,
+        retrieve_series_thumbnail, retrieve_study_thumbnail,
+    },
+    search_instances::search_instances,
+    search_patients::search_patients,
+    search_series::search_series,
+    search_studies::search_studies,
+    store_instances::{MAX_STORE_REQUEST_SIZE, store_instances, store_study_instances},
+    wado_uri::{create_wado_uri_token, retrieve_wado_uri_object},
+};
+
+#[cfg(test)]
+pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
+    use crate::{
+        internal::{
+            domain::{
+                entity::{Patient, Series, SopInstance, SopInstanceFile, Study, User},
+                repository::UserRepository,
+                value_object::{Id, Role, UserName},
+            },
+            infrastructure::repository::{
+                TestDicomFileRepository, TestDicomObjectRepository, TestUserRepository,
+            },
+        },
+        startup,
+    };
+    use chrono::{DateTime, NaiveDate, NaiveTime};
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    let user_repository = Arc::new(TestUserRepository::new());
+    user_repository.add(&User::construct(
+        Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap(),
+        Id::new("technician").unwrap(),
+        UserName::new("技師 太郎").unwrap(),
+        Role::Technician,
+        "$argon2id$v=19$m=19456,t=2,p=1$HLCrMDHifn55j/Kq5M6t0g$lIVsN8r8+osWzQmU6n5khyRZk8TNeB9/qn4NeULwVfI",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+    )).await.unwrap();
+
+    let dicom_object_repository = Arc::new(TestDicomObjectRepository::new());
+    dicom_object_repository
+        .add_patient(&Patient::construct(
+            "P000001",
+            "YAMADA^TARO",
+            "山田^太郎",
+            "やまだ^たろう",
+            Some(NaiveDate::from_ymd_opt(1980, 4, 1).unwrap()),
+            1,
+            2,
+            3,
+            4,
+        ))
+        .await;
+    dicom_object_repository
+        .add_patient(&Patient::construct(
+            "P000002",
+            "SUZUKI^HANAKO",
+            "鈴木^花子",
+            "すずき^はなこ",
+            Some(NaiveDate::from_ymd_opt(1975, 12, 24).unwrap()),
+            2,
+            1,
+            1,
+            1,
+        ))
+        .await;
+    dicom_object_repository
+        .add_study(&Study::construct(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "S0001",
+            Some(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
+            Some(NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+            "A0001",
+            "P000001",
+            "YAMADA^TARO",
+            "山田^太郎",
+            "やまだ^たろう",
+            Some(NaiveDate::from_ymd_opt(1980, 4, 1).unwrap()),
+            1,
+            vec!["CT".to_string(), "SR".to_string()],
+            vec![
+                "1.2.840.10008.5.1.4.1.1.2".to_string(),
+                "1.2.840.10008.5.1.4.1.1.88.11".to_string(),
+            ],
+            2,
+            3,
+        ))
+        .await;
+    dicom_object_repository
+        .add_study(&Study::construct(
+            "1.2.392.200036.9116.2.6.1.48.2000",
+            "S0002",
+            Some(NaiveDate::from_ymd_opt(2026, 2, 20).unwrap()),
+            Some(NaiveTime::from_hms_micro_opt(10, 30, 15, 250000).unwrap()),
+            "A0002",
+            "P000001",
+            "YAMADA^TARO",
+            "山田^太郎",
+            "やまだ^たろう",
+            Some(NaiveDate::from_ymd_opt(1980, 4, 1).unwrap()),
+            1,
+            vec!["MR".to_string()],
+            vec!["1.2.840.10008.5.1.4.1.1.4".to_string()],
+            1,
+            1,
+        ))
+        .await;
+    dicom_object_repository
+        .add_study(&Study::construct(
+            "1.2.392.200036.9116.2.6.1.48.3000",
+            "S0003",
+            Some(NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()),
+            None,
+            "",
+            "P000002",
+            "SUZUKI^HANAKO",
+            "鈴木^花子",
+            "すずき^はなこ",
+            Some(NaiveDate::from_ymd_opt(1975, 12, 24).unwrap()),
+            2,
+            vec!["CR".to_string()],
+            vec!["1.2.840.10008.5.1.4.1.1.1".to_string()],
+            1,
+            1,
+        ))
+        .await;
+    dicom_object_repository
+        .add_series(&Series::construct(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.2",
+            "SR",
+            None,
+            1,
+        ))
+        .await;
+    dicom_object_repository
+        .add_series(&Series::construct(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "CT",
+            Some(1),
+            2,
+        ))
+        .await;
+    dicom_object_repository
+        .add_series(&Series::construct(
+            "1.2.392.200036.9116.2.6.1.48.2000",
+            "1.2.392.200036.9116.2.6.1.48.2000.1",
+            "MR",
+            Some(1),
+            1,
+        ))
+        .await;
+    for (instance_uid, transfer_syntax_uid) in [
+        (
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+            "1.2.840.10008.1.2.1",
+        ),
+        (
+            "1.2.392.200036.9116.2.6.1.48.1000.1.2",
+            "1.2.840.10008.1.2.4.70",
+        ),
+    ] {
+        dicom_object_repository
+            .add_sop_instance(&SopInstance::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                "1.2.392.200036.9116.2.6.1.48.1000.1",
+                "1.2.840.10008.5.1.4.1.1.2",
+                instance_uid,
+                transfer_syntax_uid,
+            ))
+            .await;
+    }
+    dicom_object_repository
+        .add_sop_instance(&SopInstance::construct(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.2",
+            "1.2.840.10008.5.1.4.1.1.88.11",
+            "1.2.392.200036.9116.2.6.1.48.1000.2.1",
+            "1.2.840.10008.1.2.1",
+        ))
+        .await;
+
+    // SOPインスタンスのファイル
+    let dicom_file_repository = Arc::new(TestDicomFileRepository::new());
+    for (series_instance_uid, instance_uid, transfer_syntax_uid, buf) in [
+        (
+            "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+            "1.2.840.10008.1.2.1",
+            test_ct_file(
+                "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+                "1.2.840.10008.1.2.1",
+                &test_element(0x7fe0, 0x0010, b"OW", &(0..16).collect::<Vec<u8>>()),
+            ),
+        ),
+        (
+            "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "1.2.392.200036.9116.2.6.1.48.1000.1.2",
+            "1.2.840.10008.1.2.4.70",
+            test_ct_file(
+                "1.2.392.200036.9116.2.6.1.48.1000.1.2",
+                "1.2.840.10008.1.2.4.70",
+                &test_encapsulated_pixel_data(&[
+                    b"\xff\xd8FRAME1\xff\xd9".as_slice(),
+                    b"\xff\xd8FRAME2\xff\xd9".as_slice(),
+                ]),
+            ),
+        ),
+        (
+            "1.2.392.200036.9116.2.6.1.48.1000.2",
+            "1.2.392.200036.9116.2.6.1.48.1000.2.1",
+            "1.2.840.10008.1.2.1",
+            std::fs::read("../../data/dicom/GENECG").unwrap(),
+        ),
+    ] {
+        let path = format!("file:///var/lib/oceanus/dicom/{instance_uid}.dcm");
+        dicom_object_repository
+            .add_sop_instance_file(&SopInstanceFile::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                series_instance_uid,
+                instance_uid,
+                transfer_syntax_uid,
+                &path,
+                buf.len() as u64,
+            ))
+            .await;
+        dicom_file_repository.add_file(&path, buf).await;
+    }
+
+    let mut repos = startup::Repos::new_for_test();
+    repos.user_repository = user_repository;
+    repos.dicom_object_repository = dicom_object_repository;
+    repos.dicom_file_repository = dicom_file_repository;
+
+    repos
+}
+
+/// 明示的VRリトルエンディアンのデータ要素を生成する。
+#[cfg(test)]
+fn test_element(group: u16, element: u16, vr: &[u8; 2], value: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&group.to_le_bytes());
+    buf.extend_from_slice(&element.to_le_bytes());
+    buf.extend_from_slice(vr);
+    if matches!(vr, b"OB" | b"OW" | b"SQ" | b"UN" | b"UT") {
+        buf.extend_from_slice(&[0, 0]);
+        buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    } else {
+        buf.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    }
+    buf.extend_from_slice(value);
+    buf
+}
+
+/// 値を偶数長に埋めたデータ要素を生成する（UIはNULL、それ以外は空白で埋める）。
+#[cfg(test)]
+fn test_padded_element(group: u16, element: u16, vr: &[u8; 2], value: &str) -> Vec<u8> {
+    let mut value = value.as_bytes().to_vec();
+    if value.len() % 2 == 1 {
+        value.push(if vr == b"UI" { 0 } else { b' ' });
+    }
+    test_element(group, element, vr, &value)
+}
+
+/// フラグメントに分割したカプセル化ピクセルデータの要素を生成する（Basic Offset Tableは空とする）。
+#[cfg(test)]
+fn test_encapsulated_pixel_data(fragments: &[&[u8]]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&[0xe0, 0x7f, 0x10, 0x00]);
+    buf.extend_from_slice(b"OB");
+    buf.extend_from_slice(&[0, 0, 0xff, 0xff, 0xff, 0xff]);
+    let item = |buf: &mut Vec<u8>, value: &[u8]| {
+        buf.extend_from_slice(&[0xfe, 0xff, 0x00, 0xe0]);
+        buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        buf.extend_from_slice(value);
+    };
+    item(&mut buf, &[]);
+    for fragment in fragments {
+        item(&mut buf, fragment);
+    }
+    buf.extend_from_slice(&[0xfe, 0xff, 0xdd, 0xe0, 0, 0, 0, 0]);
+    buf
+}
+
+/// 2行×2列・16ビット・2フレームのCT画像のDICOMファイル（PS3.10）を生成する。
+#[cfg(test)]
+fn test_ct_file(instance_uid: &str, transfer_syntax_uid: &str, pixel_data: &[u8]) -> Vec<u8> {
+    let mut meta = Vec::new();
+    meta.extend(test_element(0x0002, 0x0001, b"OB", &[0, 1]));
+    meta.extend(test_padded_element(
+        0x0002,
+        0x0010,
+        b"UI",
+        transfer_syntax_uid,
+    ));
+
+    let mut buf = vec![0; 128];
+    buf.extend_from_slice(b"DICM");
+    buf.extend(test_element(
+        0x0002,
+        0x0000,
+        b"UL",
+        &(meta.len() as u32).to_le_bytes(),
+    ));
+    buf.extend(meta);
+    for element in [
+        test_padded_element(0x0008, 0x0005, b"CS", "ISO_IR 192"),
+        test_padded_element(0x0008, 0x0016, b"UI", "1.2.840.10008.5.1.4.1.1.2"),
+        test_padded_element(0x0008, 0x0018, b"UI", instance_uid),
+        test_padded_element(0x0008, 0x0060, b"CS", "CT"),
+        test_padded_element(0x0010, 0x0010, b"PN", "YAMADA^TARO=山田^太郎=やまだ^たろう"),
+        test_padded_element(0x0010, 0x0020, b"LO", "P000001"),
+        test_padded_element(0x0020, 0x000d, b"UI", "1.2.392.200036.9116.2.6.1.48.1000"),
+        test_padded_element(0x0020, 0x000e, b"UI", "1.2.392.200036.9116.2.6.1.48.1000.1"),
+        test_element(0x0028, 0x0002, b"US", &1u16.to_le_bytes()),
+        test_padded_element(0x0028, 0x0004, b"CS", "MONOCHROME2"),
+        test_padded_element(0x0028, 0x0008, b"IS", "2"),
+        test_element(0x0028, 0x0010, b"US", &2u16.to_le_bytes()),
+        test_element(0x0028, 0x0011, b"US", &2u16.to_le_bytes()),
+        test_element(0x0028, 0x0100, b"US", &16u16.to_le_bytes()),
+        pixel_data.to_vec(),
+    ] {
+        buf.extend(element);
+    }
+    buf
+}
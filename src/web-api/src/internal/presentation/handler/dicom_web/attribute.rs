@@ -0,0 +1,124 @@
+use dicom_lib::core::Tag;
+
+/// DICOMwebのAPIで扱う属性
+#[derive(Debug, PartialEq, Eq)]
+pub struct Attribute {
+    /// 属性のキーワード（例: `"PatientName"`）
+    pub keyword: &'static str,
+    pub tag: Tag,
+    pub vr: &'static str,
+}
+
+impl Attribute {
+    /// DICOM JSONモデルにおけるタグの表記（例: `"00100010"`）
+    pub fn json_key(&self) -> String {
+        format!("{:X}", self.tag)
+    }
+
+    /// キーワードまたはタグの表記（`"00100010"`）から属性を探す。
+    pub fn find(key: &str) -> Option<&'static Attribute> {
+        let tag = key.parse::<Tag>().ok();
+        ALL_ATTRIBUTES
+            .iter()
+            .copied()
+            .find(|a| a.keyword == key || Some(a.tag) == tag)
+    }
+}
+
+macro_rules! attributes {
+    ($($name:ident = ($keyword:literal, $group:literal, $element:literal, $vr:literal),)*) => {
+        $(
+            pub const $name: Attribute = Attribute {
+                keyword: $keyword,
+                tag: Tag($group, $element),
+                vr: $vr,
+            };
+        )*
+
+        const ALL_ATTRIBUTES: &[&Attribute] = &[$(&$name),*];
+    };
+}
+
+attributes! {
+    SOP_CLASS_UID = ("SOPClassUID", 0x0008, 0x0016, "UI"),
+    SOP_INSTANCE_UID = ("SOPInstanceUID", 0x0008, 0x0018, "UI"),
+    STUDY_DATE = ("StudyDate", 0x0008, 0x0020, "DA"),
+    STUDY_TIME = ("StudyTime", 0x0008, 0x0030, "TM"),
+    ACCESSION_NUMBER = ("AccessionNumber", 0x0008, 0x0050, "SH"),
+    INSTANCE_AVAILABILITY = ("InstanceAvailability", 0x0008, 0x0056, "CS"),
+    MODALITY = ("Modality", 0x0008, 0x0060, "CS"),
+    MODALITIES_IN_STUDY = ("ModalitiesInStudy", 0x0008, 0x0061, "CS"),
+    SOP_CLASSES_IN_STUDY = ("SOPClassesInStudy", 0x0008, 0x0062, "UI"),
+    AVAILABLE_TRANSFER_SYNTAX_UID = ("AvailableTransferSyntaxUID", 0x0008, 0x3002, "UI"),
+    PATIENT_NAME = ("PatientName", 0x0010, 0x0010, "PN"),
+    PATIENT_ID = ("PatientID", 0x0010, 0x0020, "LO"),
+    PATIENT_BIRTH_DATE = ("PatientBirthDate", 0x0010, 0x0030, "DA"),
+    PATIENT_SEX = ("PatientSex", 0x0010, 0x0040, "CS"),
+    STUDY_INSTANCE_UID = ("StudyInstanceUID", 0x0020, 0x000D, "UI"),
+    SERIES_INSTANCE_UID = ("SeriesInstanceUID", 0x0020, 0x000E, "UI"),
+    STUDY_ID = ("StudyID", 0x0020, 0x0010, "SH"),
+    SERIES_NUMBER = ("SeriesNumber", 0x0020, 0x0011, "IS"),
+    NUMBER_OF_PATIENT_RELATED_STUDIES = ("NumberOfPatientRelatedStudies", 0x0020, 0x1200, "IS"),
+    NUMBER_OF_PATIENT_RELATED_SERIES = ("NumberOfPatientRelatedSeries", 0x0020, 0x1202, "IS"),
+    NUMBER_OF_PATIENT_RELATED_INSTANCES = ("NumberOfPatientRelatedInstances", 0x0020, 0x1204, "IS"),
+    NUMBER_OF_STUDY_RELATED_SERIES = ("NumberOfStudyRelatedSeries", 0x0020, 0x1206, "IS"),
+    NUMBER_OF_STUDY_RELATED_INSTANCES = ("NumberOfStudyRelatedInstances", 0x0020, 0x1208, "IS"),
+    NUMBER_OF_SERIES_RELATED_INSTANCES = ("NumberOfSeriesRelatedInstances", 0x0020, 0x1209, "IS"),
+}
+
+/// STOW-RSの応答（PS3.18 10.5.3）でのみ使用する属性（検索のキーには指定できない）
+pub const RETRIEVE_URL: Attribute = Attribute {
+    keyword: "RetrieveURL",
+    tag: Tag(0x0008, 0x1190),
+    vr: "UR",
+};
+pub const REFERENCED_SOP_CLASS_UID: Attribute = Attribute {
+    keyword: "ReferencedSOPClassUID",
+    tag: Tag(0x0008, 0x1150),
+    vr: "UI",
+};
+pub const REFERENCED_SOP_INSTANCE_UID: Attribute = Attribute {
+    keyword: "ReferencedSOPInstanceUID",
+    tag: Tag(0x0008, 0x1155),
+    vr: "UI",
+};
+pub const WARNING_REASON: Attribute = Attribute {
+    keyword: "WarningReason",
+    tag: Tag(0x0008, 0x1196),
+    vr: "US",
+};
+pub const FAILURE_REASON: Attribute = Attribute {
+    keyword: "FailureReason",
+    tag: Tag(0x0008, 0x1197),
+    vr: "US",
+};
+pub const FAILED_SOP_SEQUENCE: Attribute = Attribute {
+    keyword: "FailedSOPSequence",
+    tag: Tag(0x0008, 0x1198),
+    vr: "SQ",
+};
+pub const REFERENCED_SOP_SEQUENCE: Attribute = Attribute {
+    keyword: "ReferencedSOPSequence",
+    tag: Tag(0x0008, 0x1199),
+    vr: "SQ",
+};
+
+/// ISO/IEC 5218の性別コードをPatient's Sex (0010,0040) の値に変換する。
+pub fn sex_to_code(sex: i16) -> &'static str {
+    match sex {
+        1 => "M",
+        2 => "F",
+        9 => "O",
+        _ => "",
+    }
+}
+
+/// Patient's Sex (0010,0040) の値をISO/IEC 5218の性別コードに変換する。
+pub fn sex_from_code(code: &str) -> Option<i16> {
+    match code {
+        "M" => Some(1),
+        "F" => Some(2),
+        "O" => Some(9),
+        _ => None,
+    }
+}
@@ -0,0 +1,363 @@
+use super::attribute::Attribute;
+use axum::{
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use chrono::{NaiveDate, NaiveTime, Timelike};
+use dicom_lib::{
+    core::{
+        DataSet, Tag,
+        data_element::Vr,
+        value::{SpecificCharacterSet, generate_person_name_strings_lossy, generate_string_lossy},
+    },
+    dictionaries::tag_dictionary,
+};
+use serde::Serialize;
+use serde_json::{Map, Number, Value, json};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+pub const DICOM_JSON_CONTENT_TYPE: &str = "application/dicom+json";
+
+/// DICOM JSONモデル（PS3.18 F.2）の属性
+#[derive(Serialize, ToSchema)]
+pub struct DicomJsonAttribute {
+    /// 値表現（例: `"PN"`）
+    pub vr: &'static str,
+    /// 属性の値（値が空の場合は省略）
+    #[serde(rename = "Value", skip_serializing_if = "Vec::is_empty")]
+    #[schema(value_type = Vec<Object>)]
+    pub value: Vec<Value>,
+    /// バイナリ値を取得するURI（メタデータの取得時のみ）
+    #[serde(rename = "BulkDataURI", skip_serializing_if = "Option::is_none")]
+    pub bulk_data_uri: Option<String>,
+    /// Base64で符号化したバイナリ値（メタデータの取得時のみ）
+    #[serde(rename = "InlineBinary", skip_serializing_if = "Option::is_none")]
+    pub inline_binary: Option<String>,
+}
+
+const SPECIFIC_CHARACTER_SET: Tag = Tag(0x0008, 0x0005);
+/// 値長さが不定であることを表す値（カプセル化されたPixel Dataなど）
+const UNDEFINED_LENGTH: u32 = 0xffffffff;
+
+/// DICOM JSONモデル（PS3.18 F.2）のデータセット
+///
+/// キーは属性のタグ（例: `"0020000D"`）で、タグの昇順に出力する。
+#[derive(Serialize, ToSchema, Default)]
+#[serde(transparent)]
+pub struct DicomJsonDataSet(BTreeMap<String, DicomJsonAttribute>);
+
+impl DicomJsonDataSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn insert(&mut self, attribute: &Attribute, value: Vec<Value>) {
+        self.0.insert(
+            attribute.json_key(),
+            DicomJsonAttribute {
+                vr: attribute.vr,
+                value,
+                bulk_data_uri: None,
+                inline_binary: None,
+            },
+        );
+    }
+
+    /// 文字列の属性を追加する。空文字列の場合は値のない属性となる。
+    pub fn insert_string(&mut self, attribute: &Attribute, value: &str) {
+        let value = if value.is_empty() {
+            vec![]
+        } else {
+            vec![Value::from(value)]
+        };
+        self.insert(attribute, value);
+    }
+
+    /// 複数の値を持つ文字列の属性を追加する。
+    pub fn insert_strings(&mut self, attribute: &Attribute, values: &[String]) {
+        self.insert(
+            attribute,
+            values.iter().map(|v| Value::from(v.as_str())).collect(),
+        );
+    }
+
+    /// 数値の属性（IS）を追加する。
+    pub fn insert_number(&mut self, attribute: &Attribute, value: Option<i64>) {
+        self.insert(attribute, value.map(Value::from).into_iter().collect());
+    }
+
+    /// 符号なし16ビット整数の属性（US）を追加する。
+    pub fn insert_unsigned_short(&mut self, attribute: &Attribute, value: u16) {
+        self.insert(attribute, vec![Value::from(value)]);
+    }
+
+    /// シーケンスの属性（SQ）を追加する。
+    pub fn insert_sequence(&mut self, attribute: &Attribute, items: Vec<DicomJsonDataSet>) {
+        self.insert(
+            attribute,
+            items
+                .into_iter()
+                .map(|item| Value::Object(item.0.into_iter().map(|(k, v)| (k, json!(v))).collect()))
+                .collect(),
+        );
+    }
+
+    /// 日付の属性（DA）を`YYYYMMDD`の形式で追加する。
+    pub fn insert_date(&mut self, attribute: &Attribute, value: Option<&NaiveDate>) {
+        self.insert(
+            attribute,
+            value
+                .map(|d| Value::from(d.format("%Y%m%d").to_string()))
+                .into_iter()
+                .collect(),
+        );
+    }
+
+    /// 時刻の属性（TM）を`HHMMSS`（秒未満がある場合は`HHMMSS.FFFFFF`）の形式で追加する。
+    pub fn insert_time(&mut self, attribute: &Attribute, value: Option<&NaiveTime>) {
+        self.insert(
+            attribute,
+            value
+                .map(|t| {
+                    let format = if t.nanosecond() == 0 {
+                        "%H%M%S"
+                    } else {
+                        "%H%M%S%.6f"
+                    };
+                    Value::from(t.format(format).to_string())
+                })
+                .into_iter()
+                .collect(),
+        );
+    }
+
+    /// 人名の属性（PN）をアルファベット・漢字・ひらがなの表記から追加する。空の表記は省略する。
+    pub fn insert_person_name(
+        &mut self,
+        attribute: &Attribute,
+        alphabetic: &str,
+        ideographic: &str,
+        phonetic: &str,
+    ) {
+        let mut name = Map::new();
+        for (key, value) in [
+            ("Alphabetic", alphabetic),
+            ("Ideographic", ideographic),
+            ("Phonetic", phonetic),
+        ] {
+            if !value.is_empty() {
+                name.insert(key.to_string(), json!(value));
+            }
+        }
+        let value = if name.is_empty() {
+            vec![]
+        } else {
+            vec![Value::Object(name)]
+        };
+        self.insert(attribute, value);
+    }
+}
+
+impl DicomJsonDataSet {
+    /// データセットのすべての属性をDICOM JSONモデルに変換する。
+    ///
+    /// 最上位のバイナリ値（OB, OWなど）の属性は`bulk_data_uri`で生成したURIをBulkDataURIとして出力し、
+    /// シーケンスのアイテムに含まれるバイナリ値はInlineBinaryとして出力する。
+    /// 文字列はSpecific Character Set (0008,0005) に従って復号する。
+    pub fn from_data_set(data_set: &DataSet, bulk_data_uri: impl Fn(Tag) -> String) -> Self {
+        Self::convert(data_set, SpecificCharacterSet::None, Some(&bulk_data_uri))
+    }
+
+    fn convert(
+        data_set: &DataSet,
+        inherited_char_set: SpecificCharacterSet,
+        bulk_data_uri: Option<&dyn Fn(Tag) -> String>,
+    ) -> Self {
+        // アイテムにSpecific Character Setが存在しない場合は、上位のデータセットの文字セットを引き継ぐ
+        let char_set = data_set
+            .find_index(SPECIFIC_CHARACTER_SET)
+            .and_then(|i| SpecificCharacterSet::try_from(data_set[i].value_field()).ok())
+            .unwrap_or(inherited_char_set);
+
+        let mut json = Self::new();
+        for index in (0..data_set.len()).filter(|&i| data_set.get_parent_index(i).is_none()) {
+            let element = &data_set[index];
+            let tag = element.tag();
+            if tag.element() == 0x0000 || tag.group() == 0xfffe {
+                // グループ長および区切り要素は出力しない
+                continue;
+            }
+
+            let vr = element
+                .vr()
+                .or_else(|| tag_dictionary::search_vr(tag))
+                .unwrap_or(Vr::Un);
+            let bytes = element.value_field();
+            let mut attribute = DicomJsonAttribute {
+                vr: vr.as_str(),
+                value: Vec::new(),
+                bulk_data_uri: None,
+                inline_binary: None,
+            };
+            match vr {
+                Vr::Sq => {
+                    attribute.value = data_set
+                        .items(index)
+                        .iter()
+                        .map(|item| {
+                            serde_json::to_value(Self::convert(item, char_set, None))
+                                .expect("DICOM JSONのデータセットのシリアライズは成功するはず")
+                        })
+                        .collect();
+                }
+                Vr::Ob | Vr::Od | Vr::Of | Vr::Ol | Vr::Ov | Vr::Ow | Vr::Un => {
+                    if !bytes.is_empty() || element.value_length() == UNDEFINED_LENGTH {
+                        match bulk_data_uri {
+                            Some(bulk_data_uri) => {
+                                attribute.bulk_data_uri = Some(bulk_data_uri(tag))
+                            }
+                            None => attribute.inline_binary = Some(BASE64.encode(bytes)),
+                        }
+                    }
+                }
+                Vr::Pn => {
+                    attribute.value = generate_person_name_strings_lossy(bytes, char_set)
+                        .iter()
+                        .map(|s| person_name_value(s.trim_end_matches([' ', '\0'])))
+                        .collect();
+                }
+                Vr::Us => attribute.value = numbers(bytes, 2, |b| u16::from_le_bytes([b[0], b[1]])),
+                Vr::Ss => attribute.value = numbers(bytes, 2, |b| i16::from_le_bytes([b[0], b[1]])),
+                Vr::Ul => {
+                    attribute.value =
+                        numbers(bytes, 4, |b| u32::from_le_bytes(b.try_into().unwrap()))
+                }
+                Vr::Sl => {
+                    attribute.value =
+                        numbers(bytes, 4, |b| i32::from_le_bytes(b.try_into().unwrap()))
+                }
+                Vr::Uv => {
+                    attribute.value =
+                        numbers(bytes, 8, |b| u64::from_le_bytes(b.try_into().unwrap()))
+                }
+                Vr::Sv => {
+                    attribute.value =
+                        numbers(bytes, 8, |b| i64::from_le_bytes(b.try_into().unwrap()))
+                }
+                Vr::Fl => {
+                    attribute.value = numbers(bytes, 4, |b| {
+                        float_value(f32::from_le_bytes(b.try_into().unwrap()) as f64)
+                    })
+                }
+                Vr::Fd => {
+                    attribute.value = numbers(bytes, 8, |b| {
+                        float_value(f64::from_le_bytes(b.try_into().unwrap()))
+                    })
+                }
+                Vr::At => {
+                    attribute.value = bytes
+                        .chunks_exact(4)
+                        .map(|b| {
+                            let tag = Tag(
+                                u16::from_le_bytes([b[0], b[1]]),
+                                u16::from_le_bytes([b[2], b[3]]),
+                            );
+                            Value::from(format!("{tag:X}"))
+                        })
+                        .collect();
+                }
+                _ => {
+                    let value = generate_string_lossy(bytes, char_set);
+                    attribute.value = string_values(&value, vr);
+                }
+            }
+            json.0.insert(format!("{tag:X}"), attribute);
+        }
+        json
+    }
+}
+
+/// 文字列の値表現の値をDICOM JSONモデルの値に変換する。空の値は`null`とする。
+fn string_values(value: &str, vr: Vr) -> Vec<Value> {
+    // LT, ST, UTは複数の値を持たず、先頭の空白は意味を持つ
+    let values = match vr {
+        Vr::Lt | Vr::St | Vr::Ut => vec![value.trim_end_matches([' ', '\0'])],
+        _ => value
+            .split('\\')
+            .map(|v| v.trim_matches([' ', '\0']))
+            .collect(),
+    };
+    if values.iter().all(|v| v.is_empty()) {
+        return Vec::new();
+    }
+
+    values
+        .into_iter()
+        .map(|v| match (v.is_empty(), vr) {
+            (true, _) => Value::Null,
+            (false, Vr::Is) => v
+                .parse::<i64>()
+                .map_or_else(|_| Value::from(v), Value::from),
+            (false, Vr::Ds) => v
+                .parse::<f64>()
+                .ok()
+                .and_then(Number::from_f64)
+                .map_or_else(|| Value::from(v), Value::Number),
+            (false, _) => Value::from(v),
+        })
+        .collect()
+}
+
+/// 人名の値（`=`で区切られた構成要素グループ）をDICOM JSONモデルの人名に変換する。
+fn person_name_value(value: &str) -> Value {
+    let mut name = Map::new();
+    for (key, group) in ["Alphabetic", "Ideographic", "Phonetic"]
+        .into_iter()
+        .zip(value.split('='))
+    {
+        if !group.is_empty() {
+            name.insert(key.to_string(), json!(group));
+        }
+    }
+    if name.is_empty() {
+        Value::Null
+    } else {
+        Value::Object(name)
+    }
+}
+
+/// 数値の値表現の値フィールドを、値ごとのバイト列に分割して変換する。
+fn numbers<T: Into<Value>>(bytes: &[u8], size: usize, f: impl Fn(&[u8]) -> T) -> Vec<Value> {
+    bytes.chunks_exact(size).map(|b| f(b).into()).collect()
+}
+
+/// 浮動小数点数をDICOM JSONモデルの値に変換する。JSONで表せない値（NaN, 無限大）は文字列とする。
+fn float_value(value: f64) -> Value {
+    Number::from_f64(value).map_or_else(|| Value::from(value.to_string()), Value::Number)
+}
+
+/// `application/dicom+json`のレスポンス
+///
+/// `warnings`はWarningヘッダー（RFC 7234）の警告文として出力する。
+pub struct DicomJsonResponse {
+    pub data_sets: Vec<DicomJsonDataSet>,
+    pub warnings: Vec<String>,
+}
+
+impl IntoResponse for DicomJsonResponse {
+    fn into_response(self) -> Response {
+        let body = serde_json::to_vec(&self.data_sets)
+            .expect("DICOM JSONのデータセットのシリアライズは成功するはず");
+        let mut response =
+            ([(header::CONTENT_TYPE, DICOM_JSON_CONTENT_TYPE)], body).into_response();
+        for warning in self.warnings {
+            // 警告文はASCII文字のみで構成するため、ヘッダー値への変換は成功するはず
+            if let Ok(value) = HeaderValue::from_str(&format!("299 - \"{warning}\"")) {
+                response.headers_mut().append(header::WARNING, value);
+            }
+        }
+        response
+    }
+}
@@ -0,0 +1,371 @@
+use crate::internal::presentation::error::PresentationError;
+use axum::{
+    body::Body,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use futures::{
+    Stream, StreamExt, TryStreamExt,
+    future::BoxFuture,
+    stream::{self, BoxStream},
+};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use storage::ByteStream;
+use tokio::io::{self, AsyncReadExt};
+use tracing::error;
+
+/// ストリームから一度に読み出すサイズ
+const CHUNK_SIZE: u64 = 64 * 1024;
+
+/// 送信時に読み込むパートの内容
+pub type LoadPart =
+    Box<dyn FnOnce() -> BoxFuture<'static, Result<Vec<u8>, PresentationError>> + Send>;
+
+/// 送信時にパートの内容を読み出すストリームを開く
+pub type OpenPart =
+    Box<dyn FnOnce() -> BoxFuture<'static, Result<ByteStream, PresentationError>> + Send>;
+
+/// パートの内容
+pub enum PartContent {
+    /// 内容が確定しているパート
+    Bytes(Vec<u8>),
+    /// 送信時に読み込むパート
+    ///
+    /// Content-Lengthを送信前に確定させるため、読み込む内容のサイズ`len`を事前に指定する。
+    /// パート全体を送信する場合は`load`で内容を読み込み、パートの一部のみを送信する場合は`open`で開いたストリームから範囲内の部分のみを読み出す。
+    /// 読み込んだ内容のサイズが異なる場合は送信を中断する。
+    Deferred {
+        len: u64,
+        load: LoadPart,
+        open: OpenPart,
+    },
+}
+
+impl PartContent {
+    fn len(&self) -> u64 {
+        match self {
+            Self::Bytes(buf) => buf.len() as u64,
+            Self::Deferred { len, .. } => *len,
+        }
+    }
+}
+
+/// `multipart/related`（RFC 2387）のレスポンス
+///
+/// パートの内容は送信時に順に読み込み、レスポンス全体をメモリに保持しない。
+/// Rangeヘッダー（単一の範囲のみ）を指定した場合は、マルチパートのボディ全体のうち指定した範囲を返す。
+/// 範囲がパートの一部のみと重なる場合は、そのパートの内容をストリームから読み出し、パート全体をメモリに読み込まない。
+pub struct MultipartRelated {
+    media_type: String,
+    boundary: String,
+    parts: Vec<(String, PartContent)>,
+}
+
+impl MultipartRelated {
+    /// `media_type`はルートのパートのメディアタイプ（`type`パラメーター）を表す。
+    ///
+    /// 範囲を指定したリクエストの間で同じボディを返すよう、境界文字列は`key`から決定的に生成する。
+    pub fn new(media_type: impl Into<String>, key: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        Self {
+            media_type: media_type.into(),
+            boundary: format!("oceanus-{:016x}", hasher.finish()),
+            parts: Vec::new(),
+        }
+    }
+
+    /// パートを追加する。`content_type`はパートのContent-Typeヘッダーの値を表す。
+    pub fn push(&mut self, content_type: impl Into<String>, content: PartContent) {
+        self.parts.push((content_type.into(), content));
+    }
+
+    /// リクエストヘッダーのRangeに応じたレスポンスを返す。
+    pub fn into_response(self, request_headers: &HeaderMap) -> Response {
+        let content_type = format!(
+            "multipart/related; type=\"{}\"; boundary={}",
+            self.media_type, self.boundary
+        );
+
+        // ボディを境界・ヘッダー等の固定部分とパートの内容に分割する
+        let mut segments = Vec::with_capacity(self.parts.len() * 3 + 1);
+        for (part_content_type, content) in self.parts {
+            segments.push(PartContent::Bytes(
+                format!(
+                    "--{}\r\nContent-Type: {part_content_type}\r\nContent-Length: {}\r\n\r\n",
+                    self.boundary,
+                    content.len()
+                )
+                .into_bytes(),
+            ));
+            segments.push(content);
+            segments.push(PartContent::Bytes(b"\r\n".to_vec()));
+        }
+        segments.push(PartContent::Bytes(
+            format!("--{}--\r\n", self.boundary).into_bytes(),
+        ));
+        let total = segments.iter().map(PartContent::len).sum::<u64>();
+
+        let range = request_headers
+            .get(header::RANGE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| parse_range(v, total));
+        let (status, start, end) = match range {
+            Some(Ok(Some((start, end)))) => (StatusCode::PARTIAL_CONTENT, start, end),
+            Some(Err(())) => {
+                return (
+                    StatusCode::RANGE_NOT_SATISFIABLE,
+                    [
+                        (header::CONTENT_RANGE, format!("bytes */{total}")),
+                        (header::ACCEPT_RANGES, "bytes".to_string()),
+                    ],
+                )
+                    .into_response();
+            }
+            _ => (StatusCode::OK, 0, total),
+        };
+
+        // 範囲と重なるセグメントのみを、範囲内の部分に切り詰めて送信する
+        let mut slices = Vec::new();
+        let mut position = 0;
+        for segment in segments {
+            let len = segment.len();
+            let (segment_start, segment_end) = (position, position + len);
+            position = segment_end;
+            if segment_end <= start || end <= segment_start || len == 0 {
+                continue;
+            }
+            let from = start.max(segment_start) - segment_start;
+            let to = end.min(segment_end) - segment_start;
+            slices.push((segment, from, to));
+        }
+        let body = stream::iter(slices)
+            .flat_map(|(segment, from, to)| -> BoxStream<'static, _> {
+                let len = segment.len();
+                match segment {
+                    PartContent::Bytes(buf) => {
+                        let buf = if from == 0 && to == len {
+                            buf
+                        } else {
+                            buf[from as usize..to as usize].to_vec()
+                        };
+                        stream::once(async move { Ok(buf) }).boxed()
+                    }
+                    PartContent::Deferred { load, .. } if from == 0 && to == len => {
+                        stream::once(async move {
+                            let buf = load().await?;
+                            if buf.len() as u64 != len {
+                                return Err(PresentationError::InternalServerError(format!(
+                                    "パートのサイズが事前に算出した値と一致しません (算出した値={len}, 実際の値={})",
+                                    buf.len()
+                                )));
+                            }
+                            Ok(buf)
+                        })
+                        .boxed()
+                    }
+                    PartContent::Deferred { open, .. } => {
+                        read_range(open, from, to).boxed()
+                    }
+                }
+            })
+            .inspect_err(|e| error!("マルチパートのレスポンスの送信を中断しました: {e}"));
+
+        let mut response = (status, Body::from_stream(body)).into_response();
+        let headers = response.headers_mut();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_str(&content_type).expect("Content-Typeはヘッダー値として有効なはず"),
+        );
+        headers.insert(header::CONTENT_LENGTH, HeaderValue::from(end - start));
+        headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+        if status == StatusCode::PARTIAL_CONTENT {
+            headers.insert(
+                header::CONTENT_RANGE,
+                HeaderValue::from_str(&format!("bytes {start}-{}/{total}", end - 1))
+                    .expect("Content-Rangeはヘッダー値として有効なはず"),
+            );
+        }
+        response
+    }
+}
+
+/// パートの内容のうち`from`から`to`（`to`の位置は含まない）までを、`open`で開いたストリームから順に読み出す。
+fn read_range(
+    open: OpenPart,
+    from: u64,
+    to: u64,
+) -> impl Stream<Item = Result<Vec<u8>, PresentationError>> {
+    stream::once(async move {
+        let mut reader = open().await?;
+        // 範囲の始端までを読み飛ばす
+        let skipped = io::copy(&mut (&mut reader).take(from), &mut io::sink())
+            .await
+            .map_err(read_error)?;
+        if skipped != from {
+            return Err(size_error());
+        }
+
+        Ok(stream::try_unfold(
+            (reader, to - from),
+            |(mut reader, remaining)| async move {
+                if remaining == 0 {
+                    return Ok(None);
+                }
+                let mut buf = vec![0; remaining.min(CHUNK_SIZE) as usize];
+                let n = reader.read(&mut buf).await.map_err(read_error)?;
+                if n == 0 {
+                    return Err(size_error());
+                }
+                buf.truncate(n);
+                Ok(Some((buf, (reader, remaining - n as u64))))
+            },
+        ))
+    })
+    .try_flatten()
+}
+
+fn read_error(e: io::Error) -> PresentationError {
+    PresentationError::InternalServerError(format!("パートの読み出しに失敗しました: {e}"))
+}
+
+fn size_error() -> PresentationError {
+    PresentationError::InternalServerError(
+        "パートのサイズが事前に算出した値より小さくなっています".to_string(),
+    )
+}
+
+/// Rangeヘッダーの値（RFC 9110 14.2）を解析し、範囲の始端と終端（終端の位置は含まない）を返す。
+///
+/// 単一の範囲のみに対応し、形式が不正な場合や複数の範囲を指定した場合は`Ok(None)`（範囲の指定を無視する）を返す。
+/// 範囲がボディと重ならない場合は`Err(())`を返す。
+fn parse_range(value: &str, total: u64) -> Result<Option<(u64, u64)>, ()> {
+    let Some(spec) = value.trim().strip_prefix("bytes=") else {
+        return Ok(None);
+    };
+    if spec.contains(',') {
+        return Ok(None);
+    }
+    let Some((first, last)) = spec.trim().split_once('-') else {
+        return Ok(None);
+    };
+
+    match (first.parse::<u64>(), last.parse::<u64>()) {
+        // bytes=-N: 末尾のNバイト
+        (Err(_), Ok(suffix)) if first.is_empty() => {
+            if suffix == 0 || total == 0 {
+                return Err(());
+            }
+            Ok(Some((total - suffix.min(total), total)))
+        }
+        // bytes=N-: Nバイト目以降
+        (Ok(first), Err(_)) if last.is_empty() => {
+            if first >= total {
+                return Err(());
+            }
+            Ok(Some((first, total)))
+        }
+        // bytes=N-M: Nバイト目からMバイト目まで
+        (Ok(first), Ok(last)) if first <= last => {
+            if first >= total {
+                return Err(());
+            }
+            Ok(Some((first, (last + 1).min(total))))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// `multipart/related`（RFC 2387）のリクエストボディのパート
+pub struct Part {
+    /// パートのContent-Typeヘッダーの値（省略した場合は`None`）
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// `multipart/related`のリクエストのContent-Typeヘッダーの値を解析し、`type`パラメーターと境界文字列を返す。
+///
+/// `multipart/related`でない場合や境界文字列が指定されていない場合は`None`を返す。
+pub fn parse_content_type(value: &str) -> Option<(Option<String>, String)> {
+    let mut params = value.split(';');
+    if !params
+        .next()?
+        .trim()
+        .eq_ignore_ascii_case("multipart/related")
+    {
+        return None;
+    }
+
+    let (mut media_type, mut boundary) = (None, None);
+    for param in params {
+        let Some((name, value)) = param.split_once('=') else {
+            continue;
+        };
+        let value = value.trim().trim_matches('"').to_string();
+        match name.trim().to_ascii_lowercase().as_str() {
+            "type" => media_type = Some(value),
+            "boundary" => boundary = Some(value),
+            _ => {}
+        }
+    }
+
+    Some((media_type, boundary.filter(|b| !b.is_empty())?))
+}
+
+/// `multipart/related`のリクエストボディをパートに分割する。
+///
+/// 最初の境界より前（プリアンブル）と終端の境界より後（エピローグ）は無視する。
+pub fn parse_body(body: &[u8], boundary: &str) -> Result<Vec<Part>, String> {
+    let delimiter = format!("--{boundary}").into_bytes();
+    let Some(mut position) = find(body, &delimiter, 0) else {
+        return Err("マルチパートの境界が見つかりません".to_string());
+    };
+
+    let mut parts = Vec::new();
+    loop {
+        position += delimiter.len();
+        if body[position..].starts_with(b"--") {
+            return Ok(parts);
+        }
+        // 境界の行末（空白とCRLF）を読み飛ばす
+        let Some(line_end) = find(body, b"\r\n", position) else {
+            break;
+        };
+        let part_start = line_end + 2;
+        let close_delimiter = [b"\r\n".as_slice(), &delimiter].concat();
+        let Some(part_end) = find(body, &close_delimiter, part_start) else {
+            break;
+        };
+
+        let part = &body[part_start..part_end];
+        let (headers, content) = if part.starts_with(b"\r\n") {
+            (&part[..0], &part[2..])
+        } else {
+            match find(part, b"\r\n\r\n", 0) {
+                Some(i) => (&part[..i], &part[i + 4..]),
+                None => return Err("パートのヘッダーが途中で終わっています".to_string()),
+            }
+        };
+        let content_type = String::from_utf8_lossy(headers)
+            .split("\r\n")
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-type"))
+            .map(|(_, value)| value.trim().to_string());
+        parts.push(Part {
+            content_type,
+            body: content.to_vec(),
+        });
+
+        position = part_end + 2;
+    }
+
+    Err("マルチパートの終端の境界が見つかりません".to_string())
+}
+
+/// `buf`の`from`以降で`pattern`が最初に現れる位置を返す。
+fn find(buf: &[u8], pattern: &[u8], from: usize) -> Option<usize> {
+    buf.get(from..)?
+        .windows(pattern.len())
+        .position(|w| w == pattern)
+        .map(|i| from + i)
+}
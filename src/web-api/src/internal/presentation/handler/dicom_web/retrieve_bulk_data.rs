@@ -0,0 +1,267 @@
+use super::{
+    multipart::{MultipartRelated, PartContent},
+    retrieve_frames::{frame_media_type, from_pixel_data_error},
+};
+use crate::{
+    internal::{
+        application::dicom_object::RetrieveSopInstanceFilesCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::Response,
+};
+use dicom_lib::{
+    core::{Tag, data_element::Vr},
+    dictionaries::tag_dictionary,
+    pixel_data::PixelData,
+};
+use std::str::FromStr;
+
+const PIXEL_DATA: Tag = Tag(0x7fe0, 0x0010);
+
+/// 値長さが不定であることを表す値
+const UNDEFINED_LENGTH: u32 = 0xffffffff;
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/bulkdata/{tag}",
+    description = "WADO-RSのバルクデータの取得 (Retrieve Bulkdata)。\
+メタデータの`BulkDataURI`が示すバイナリ値の属性の値を`multipart/related`で返す。\
+カプセル化されたピクセルデータはフレームごとのパートに分割し、転送構文に対応するメディアタイプ（`image/jpeg`等）で返す。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("sop_instance_uid" = String, Path, description = "SOPインスタンスUID"),
+        ("tag" = String, Path, description = "属性のタグ（例: `7FE00010`）"),
+    ),
+    responses(
+        (status = 200, description = "バルクデータの取得に成功", content_type = "multipart/related"),
+        (status = 400, description = "タグの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンスまたはバルクデータが見つからない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_bulk_data(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid, sop_instance_uid, tag)): Path<(
+        String,
+        String,
+        String,
+        String,
+    )>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let tag = Tag::from_str(&tag).map_err(|_| {
+        PresentationError::BadRequest(format!(
+            "タグは16進数8桁で指定してください (入力文字列=\"{tag}\")"
+        ))
+    })?;
+
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: Some(sop_instance_uid),
+    };
+    let files = state
+        .retrieve_sop_instance_files_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+    let file = &files[0];
+    let data_set = state
+        .read_sop_instance_data_set_use_case
+        .execute(file)
+        .await
+        .map_err(PresentationError::from)?;
+
+    // メタデータで`BulkDataURI`を返す最上位のバイナリ値の属性のみを対象とする
+    let not_found =
+        || PresentationError::NotFound(format!("バルクデータ {tag:X} が見つかりません"));
+    let index = data_set.find_index(tag).ok_or_else(not_found)?;
+    let element = &data_set[index];
+    let vr = element
+        .vr()
+        .or_else(|| tag_dictionary::search_vr(tag))
+        .unwrap_or(Vr::Un);
+    if !matches!(
+        vr,
+        Vr::Ob | Vr::Od | Vr::Of | Vr::Ol | Vr::Ov | Vr::Ow | Vr::Un
+    ) {
+        return Err(not_found());
+    }
+
+    let key = (file.instance_uid(), file.size(), format!("{tag:X}"));
+    let response = if tag == PIXEL_DATA && element.value_length() == UNDEFINED_LENGTH {
+        let pixel_data = PixelData::from_data_set(&data_set).map_err(from_pixel_data_error)?;
+        let media_type = frame_media_type(file.transfer_syntax_uid(), true);
+        let mut multipart = MultipartRelated::new(media_type, key);
+        for number in 1..=pixel_data.number_of_frames() {
+            let frame = pixel_data
+                .frame(number)
+                .expect("フレーム数の範囲内のフレームは存在するはず");
+            multipart.push(
+                format!(
+                    "{media_type}; transfer-syntax={}",
+                    file.transfer_syntax_uid()
+                ),
+                PartContent::Bytes(frame.to_vec()),
+            );
+        }
+        multipart
+    } else {
+        let mut multipart = MultipartRelated::new("application/octet-stream", key);
+        multipart.push(
+            "application/octet-stream",
+            PartContent::Bytes(element.value_field().to_vec()),
+        );
+        multipart
+    };
+
+    Ok(response.into_response(&headers))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use tower::ServiceExt;
+
+    const INSTANCE_URI: &str = "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances";
+
+    async fn get(router: Router, uri: &str) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    async fn body_bytes(response: Response) -> Vec<u8> {
+        body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap()
+            .to_vec()
+    }
+
+    /// Content-Typeの境界文字列を返す。
+    fn boundary(response: &Response) -> String {
+        let content_type = response.headers()["content-type"].to_str().unwrap();
+        content_type.split_once("boundary=").unwrap().1.to_string()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは非圧縮のピクセルデータのバルクデータを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/bulkdata/7FE00010"),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスボディの確認（値全体を1つのパートで返す）
+        let boundary = boundary(&response);
+        let mut expected = format!(
+            "--{boundary}\r\nContent-Type: application/octet-stream\r\nContent-Length: 16\r\n\r\n"
+        )
+        .into_bytes();
+        expected.extend(0..16);
+        expected.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+        assert_eq!(body_bytes(response).await, expected);
+    }
+
+    #[tokio::test]
+    async fn カプセル化されたピクセルデータのバルクデータはフレームごとに返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.2/bulkdata/7FE00010"),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスボディの確認
+        let boundary = boundary(&response);
+        let mut expected = Vec::new();
+        for frame in [b"\xff\xd8FRAME1\xff\xd9", b"\xff\xd8FRAME2\xff\xd9"] {
+            expected.extend_from_slice(
+                format!(
+                    "--{boundary}\r\nContent-Type: image/jpeg; transfer-syntax=1.2.840.10008.1.2.4.70\r\nContent-Length: 10\r\n\r\n"
+                )
+                .as_bytes(),
+            );
+            expected.extend_from_slice(frame);
+            expected.extend_from_slice(b"\r\n");
+        }
+        expected.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+        assert_eq!(body_bytes(response).await, expected);
+    }
+
+    #[tokio::test]
+    async fn バイナリ値でない属性を指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/bulkdata/00100010"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 不正なタグを指定すると400エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/bulkdata/PIXEL"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
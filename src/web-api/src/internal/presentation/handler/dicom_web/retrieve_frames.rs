@@ -0,0 +1,266 @@
+use super::multipart::{MultipartRelated, PartContent};
+use crate::{
+    internal::{
+        application::dicom_object::RetrieveSopInstanceFilesCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::Response,
+};
+use dicom_lib::{
+    constants::transfer_syntax_uids::{
+        JPEG_BASELINE_8_BIT, JPEG_EXTENDED_12_BIT, JPEG_LOSSLESS, JPEG_LOSSLESS_SV1, JPEG2000,
+        JPEG2000_LOSSLESS, JPEG2000_MC, JPEG2000_MC_LOSSLESS, JPEGLS_LOSSLESS,
+        JPEGLS_NEAR_LOSSLESS, RLE_LOSSLESS,
+    },
+    pixel_data::{PixelData, PixelDataError},
+};
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/frames/{frame_list}",
+    description = "WADO-RSのフレームの取得 (Retrieve Frames)。\
+SOPインスタンスのピクセルデータのうち、指定したフレームを`multipart/related`で返す。\
+ピクセルデータは保存時の転送構文のまま返し、圧縮されている場合は転送構文に対応するメディアタイプ（`image/jpeg`等）とする。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("sop_instance_uid" = String, Path, description = "SOPインスタンスUID"),
+        ("frame_list" = String, Path, description = "フレーム番号（1始まり、カンマ区切りで複数指定可）"),
+    ),
+    responses(
+        (status = 200, description = "フレームの取得に成功", content_type = "multipart/related"),
+        (status = 400, description = "フレーム番号の形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンス、ピクセルデータまたはフレームが見つからない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_frames(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid, sop_instance_uid, frame_list)): Path<(
+        String,
+        String,
+        String,
+        String,
+    )>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let frame_numbers = frame_list
+        .split(',')
+        .map(|s| match s.trim().parse::<usize>() {
+            Ok(number) if number >= 1 => Ok(number),
+            _ => Err(PresentationError::BadRequest(format!(
+                "フレーム番号は1以上の整数で指定してください (入力文字列=\"{s}\")"
+            ))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: Some(sop_instance_uid),
+    };
+    let files = state
+        .retrieve_sop_instance_files_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+    let file = &files[0];
+    let data_set = state
+        .read_sop_instance_data_set_use_case
+        .execute(file)
+        .await
+        .map_err(PresentationError::from)?;
+    let pixel_data = PixelData::from_data_set(&data_set).map_err(from_pixel_data_error)?;
+
+    let media_type = frame_media_type(file.transfer_syntax_uid(), pixel_data.is_encapsulated());
+    let mut multipart = MultipartRelated::new(
+        media_type,
+        (file.instance_uid(), file.size(), &frame_numbers),
+    );
+    for number in frame_numbers {
+        let frame = pixel_data.frame(number).ok_or_else(|| {
+            PresentationError::NotFound(format!("フレーム {number} が見つかりません"))
+        })?;
+        multipart.push(
+            format!(
+                "{media_type}; transfer-syntax={}",
+                file.transfer_syntax_uid()
+            ),
+            PartContent::Bytes(frame.to_vec()),
+        );
+    }
+
+    Ok(multipart.into_response(&headers))
+}
+
+/// フレームのメディアタイプを返す。
+/// 非圧縮のピクセルデータおよびメディアタイプが定められていない転送構文は`application/octet-stream`とする。
+pub(super) fn frame_media_type(transfer_syntax_uid: &str, encapsulated: bool) -> &'static str {
+    if !encapsulated {
+        return "application/octet-stream";
+    }
+    match transfer_syntax_uid {
+        JPEG_BASELINE_8_BIT | JPEG_EXTENDED_12_BIT | JPEG_LOSSLESS | JPEG_LOSSLESS_SV1 => {
+            "image/jpeg"
+        }
+        JPEGLS_LOSSLESS | JPEGLS_NEAR_LOSSLESS => "image/jls",
+        JPEG2000_LOSSLESS | JPEG2000 => "image/jp2",
+        JPEG2000_MC_LOSSLESS | JPEG2000_MC => "image/jpx",
+        RLE_LOSSLESS => "image/dicom-rle",
+        _ => "application/octet-stream",
+    }
+}
+
+pub(super) fn from_pixel_data_error(e: PixelDataError) -> PresentationError {
+    match e {
+        PixelDataError::NotFound => PresentationError::NotFound(e.to_string()),
+        _ => PresentationError::InternalServerError(e.to_string()),
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use tower::ServiceExt;
+
+    const INSTANCE_URI: &str = "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances";
+
+    async fn get(router: Router, uri: &str) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    async fn body_bytes(response: Response) -> Vec<u8> {
+        body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap()
+            .to_vec()
+    }
+
+    /// Content-Typeの境界文字列を返す。
+    fn boundary(response: &Response) -> String {
+        let content_type = response.headers()["content-type"].to_str().unwrap();
+        content_type.split_once("boundary=").unwrap().1.to_string()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは非圧縮のピクセルデータのフレームを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/frames/2,1"),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスボディの確認（指定した順にフレームを返す）
+        let boundary = boundary(&response);
+        let mut expected = Vec::new();
+        for frame in [8u8..16, 0..8] {
+            expected.extend_from_slice(
+                format!(
+                    "--{boundary}\r\nContent-Type: application/octet-stream; transfer-syntax=1.2.840.10008.1.2.1\r\nContent-Length: 8\r\n\r\n"
+                )
+                .as_bytes(),
+            );
+            expected.extend(frame);
+            expected.extend_from_slice(b"\r\n");
+        }
+        expected.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+        assert_eq!(body_bytes(response).await, expected);
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは圧縮されたピクセルデータのフレームを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.2/frames/2"),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスボディの確認（転送構文に対応するメディアタイプで返す）
+        let boundary = boundary(&response);
+        let mut expected = format!(
+            "--{boundary}\r\nContent-Type: image/jpeg; transfer-syntax=1.2.840.10008.1.2.4.70\r\nContent-Length: 10\r\n\r\n"
+        )
+        .into_bytes();
+        expected.extend_from_slice(b"\xff\xd8FRAME2\xff\xd9\r\n");
+        expected.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+        assert_eq!(body_bytes(response).await, expected);
+    }
+
+    #[tokio::test]
+    async fn 存在しないフレームを指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/frames/3"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 不正なフレーム番号を指定すると400エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/frames/0"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
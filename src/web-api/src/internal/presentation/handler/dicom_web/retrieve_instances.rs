@@ -0,0 +1,359 @@
+use super::multipart::{MultipartRelated, PartContent};
+use crate::{
+    internal::{
+        application::dicom_object::RetrieveSopInstanceFilesCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::Response,
+};
+
+const DICOM_MEDIA_TYPE: &str = "application/dicom";
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}",
+    description = "WADO-RSの検査の取得 (Retrieve Study)。\
+検査に含まれるすべてのSOPインスタンスのDICOMファイル（PS3.10）を`multipart/related`で返す。\
+ファイルは保存時の転送構文のまま返す。Rangeヘッダーでボディの一部を取得できる。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+    ),
+    responses(
+        (status = 200, description = "検査の取得に成功", content_type = "multipart/related; type=\"application/dicom\""),
+        (status = 206, description = "Rangeヘッダーで指定した範囲の取得に成功", content_type = "multipart/related; type=\"application/dicom\""),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "検査が見つからない", body = ErrorResponseBody),
+        (status = 416, description = "Rangeヘッダーで指定した範囲がボディと重ならない"),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_study(
+    State(state): State<AppState>,
+    Path(study_instance_uid): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: None,
+        sop_instance_uid: None,
+    };
+    retrieve(state, command, &headers).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}",
+    description = "WADO-RSのシリーズの取得 (Retrieve Series)。\
+シリーズに含まれるすべてのSOPインスタンスのDICOMファイル（PS3.10）を`multipart/related`で返す。\
+ファイルは保存時の転送構文のまま返す。Rangeヘッダーでボディの一部を取得できる。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+    ),
+    responses(
+        (status = 200, description = "シリーズの取得に成功", content_type = "multipart/related; type=\"application/dicom\""),
+        (status = 206, description = "Rangeヘッダーで指定した範囲の取得に成功", content_type = "multipart/related; type=\"application/dicom\""),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "シリーズが見つからない", body = ErrorResponseBody),
+        (status = 416, description = "Rangeヘッダーで指定した範囲がボディと重ならない"),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_series(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: None,
+    };
+    retrieve(state, command, &headers).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}",
+    description = "WADO-RSのSOPインスタンスの取得 (Retrieve Instance)。\
+SOPインスタンスのDICOMファイル（PS3.10）を`multipart/related`で返す。\
+ファイルは保存時の転送構文のまま返す。Rangeヘッダーでボディの一部を取得できる。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("sop_instance_uid" = String, Path, description = "SOPインスタンスUID"),
+    ),
+    responses(
+        (status = 200, description = "SOPインスタンスの取得に成功", content_type = "multipart/related; type=\"application/dicom\""),
+        (status = 206, description = "Rangeヘッダーで指定した範囲の取得に成功", content_type = "multipart/related; type=\"application/dicom\""),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンスが見つからない", body = ErrorResponseBody),
+        (status = 416, description = "Rangeヘッダーで指定した範囲がボディと重ならない"),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_instance(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid, sop_instance_uid)): Path<(
+        String,
+        String,
+        String,
+    )>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: Some(sop_instance_uid),
+    };
+    retrieve(state, command, &headers).await
+}
+
+/// 取得対象のSOPインスタンスのファイルを、1ファイルを1パートとする`multipart/related`で返す。
+/// ファイルはパートを送信する時点で1件ずつ読み込む。
+/// ファイル全体を送信する場合は読み込んだ内容をハッシュ値で検証し、Rangeヘッダーでファイルの一部のみを送信する場合は検証せずにストリームから読み出す。
+async fn retrieve(
+    state: AppState,
+    command: RetrieveSopInstanceFilesCommand,
+    headers: &HeaderMap,
+) -> Result<Response, PresentationError> {
+    let files = state
+        .retrieve_sop_instance_files_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    let key = files
+        .iter()
+        .map(|f| (f.instance_uid().to_string(), f.size()))
+        .collect::<Vec<_>>();
+    let mut multipart = MultipartRelated::new(DICOM_MEDIA_TYPE, key);
+    for file in files {
+        let use_case = state.read_sop_instance_file_use_case.clone();
+        multipart.push(
+            format!(
+                "{DICOM_MEDIA_TYPE}; transfer-syntax={}",
+                file.transfer_syntax_uid()
+            ),
+            PartContent::Deferred {
+                len: file.size(),
+                load: Box::new({
+                    let (use_case, file) = (use_case.clone(), file.clone());
+                    move || {
+                        Box::pin(async move {
+                            use_case
+                                .execute(&file)
+                                .await
+                                .map_err(PresentationError::from)
+                        })
+                    }
+                }),
+                open: Box::new(move || {
+                    Box::pin(async move {
+                        use_case
+                            .stream(&file)
+                            .await
+                            .map_err(PresentationError::from)
+                    })
+                }),
+            },
+        );
+    }
+
+    Ok(multipart.into_response(headers))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use tower::ServiceExt;
+
+    async fn get(router: Router, uri: &str, range: Option<&str>) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let mut request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"));
+        if let Some(range) = range {
+            request = request.header("range", range);
+        }
+        router
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn body_bytes(response: Response) -> Vec<u8> {
+        body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap()
+            .to_vec()
+    }
+
+    /// Content-Typeの境界文字列を返す。
+    fn boundary(response: &Response) -> String {
+        let content_type = response.headers()["content-type"].to_str().unwrap();
+        content_type.split_once("boundary=").unwrap().1.to_string()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーはシリーズのDICOMファイルを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1",
+            None,
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスヘッダーの確認
+        let content_type = response.headers()["content-type"].to_str().unwrap();
+        assert!(
+            content_type.starts_with("multipart/related; type=\"application/dicom\"; boundary=")
+        );
+        assert_eq!(response.headers()["accept-ranges"], "bytes");
+        let content_length = response.headers()["content-length"]
+            .to_str()
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        // レスポンスボディの確認（SOPインスタンスごとに保存時の転送構文のまま返す）
+        let boundary = boundary(&response);
+        let body = body_bytes(response).await;
+        assert_eq!(body.len(), content_length);
+        let headers = String::from_utf8_lossy(&body)
+            .split(&format!("--{boundary}"))
+            .filter_map(|part| {
+                part.split_once("\r\n\r\n")
+                    .map(|(h, _)| h.trim().to_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(headers.len(), 2);
+        assert!(headers[0].starts_with(
+            "Content-Type: application/dicom; transfer-syntax=1.2.840.10008.1.2.1\r\n"
+        ));
+        assert!(headers[1].starts_with(
+            "Content-Type: application/dicom; transfer-syntax=1.2.840.10008.1.2.4.70\r\n"
+        ));
+        assert!(body.ends_with(format!("--{boundary}--\r\n").as_bytes()));
+    }
+
+    #[tokio::test]
+    async fn Rangeヘッダーを指定するとボディの一部を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let uri = "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances/1.2.392.200036.9116.2.6.1.48.1000.1.1";
+        let full = body_bytes(get(router.clone(), uri, None).await).await;
+
+        // Act
+        let response = get(router, uri, Some("bytes=100-199")).await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        // レスポンスヘッダーの確認
+        assert_eq!(
+            response.headers()["content-range"],
+            format!("bytes 100-199/{}", full.len()).as_str()
+        );
+        assert_eq!(response.headers()["content-length"], "100");
+        // レスポンスボディの確認（範囲を指定しない場合と同じボディの一部を返す）
+        assert_eq!(body_bytes(response).await, full[100..200]);
+    }
+
+    #[tokio::test]
+    async fn Rangeヘッダーでファイルの途中から始まる範囲を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let uri =
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1";
+        let full = body_bytes(get(router.clone(), uri, None).await).await;
+
+        // Act
+        let response = get(router, uri, Some("bytes=-200")).await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        // レスポンスヘッダーの確認
+        assert_eq!(
+            response.headers()["content-range"],
+            format!(
+                "bytes {}-{}/{}",
+                full.len() - 200,
+                full.len() - 1,
+                full.len()
+            )
+            .as_str()
+        );
+        // レスポンスボディの確認（最後のファイルの末尾と終端の境界を返す）
+        assert_eq!(body_bytes(response).await, full[full.len() - 200..]);
+    }
+
+    #[tokio::test]
+    async fn ボディと重ならない範囲を指定すると416エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000",
+            Some("bytes=100000000-"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    }
+
+    #[tokio::test]
+    async fn 存在しない検査を指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, "/studies/1.2.3.4", None).await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,286 @@
+use super::dicom_json::{DicomJsonDataSet, DicomJsonResponse};
+use crate::{
+    internal::{
+        application::dicom_object::RetrieveSopInstanceFilesCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::extract::{Path, State};
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/metadata",
+    description = "WADO-RSの検査のメタデータの取得 (Retrieve Study Metadata)。\
+検査に含まれるすべてのSOPインスタンスの属性をDICOM JSONモデルで返す。\
+ピクセルデータ等のバイナリ値の属性は`BulkDataURI`（バルクデータの取得先）として返す。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+    ),
+    responses(
+        (status = 200, description = "検査のメタデータの取得に成功", content_type = "application/dicom+json", body = Vec<DicomJsonDataSet>),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "検査が見つからない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_study_metadata(
+    State(state): State<AppState>,
+    Path(study_instance_uid): Path<String>,
+) -> Result<DicomJsonResponse, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: None,
+        sop_instance_uid: None,
+    };
+    retrieve_metadata(state, command).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/metadata",
+    description = "WADO-RSのシリーズのメタデータの取得 (Retrieve Series Metadata)。\
+シリーズに含まれるすべてのSOPインスタンスの属性をDICOM JSONモデルで返す。\
+ピクセルデータ等のバイナリ値の属性は`BulkDataURI`（バルクデータの取得先）として返す。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+    ),
+    responses(
+        (status = 200, description = "シリーズのメタデータの取得に成功", content_type = "application/dicom+json", body = Vec<DicomJsonDataSet>),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "シリーズが見つからない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_series_metadata(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid)): Path<(String, String)>,
+) -> Result<DicomJsonResponse, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: None,
+    };
+    retrieve_metadata(state, command).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/metadata",
+    description = "WADO-RSのSOPインスタンスのメタデータの取得 (Retrieve Instance Metadata)。\
+SOPインスタンスの属性をDICOM JSONモデルで返す。\
+ピクセルデータ等のバイナリ値の属性は`BulkDataURI`（バルクデータの取得先）として返す。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("sop_instance_uid" = String, Path, description = "SOPインスタンスUID"),
+    ),
+    responses(
+        (status = 200, description = "SOPインスタンスのメタデータの取得に成功", content_type = "application/dicom+json", body = Vec<DicomJsonDataSet>),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンスが見つからない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_instance_metadata(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid, sop_instance_uid)): Path<(
+        String,
+        String,
+        String,
+    )>,
+) -> Result<DicomJsonResponse, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: Some(sop_instance_uid),
+    };
+    retrieve_metadata(state, command).await
+}
+
+async fn retrieve_metadata(
+    state: AppState,
+    command: RetrieveSopInstanceFilesCommand,
+) -> Result<DicomJsonResponse, PresentationError> {
+    let files = state
+        .retrieve_sop_instance_files_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    let mut data_sets = Vec::with_capacity(files.len());
+    for file in files {
+        let data_set = state
+            .read_sop_instance_data_set_use_case
+            .execute(&file)
+            .await
+            .map_err(PresentationError::from)?;
+        data_sets.push(DicomJsonDataSet::from_data_set(&data_set, |tag| {
+            format!(
+                "/studies/{}/series/{}/instances/{}/bulkdata/{tag:X}",
+                file.study_instance_uid(),
+                file.series_instance_uid(),
+                file.instance_uid()
+            )
+        }));
+    }
+
+    Ok(DicomJsonResponse {
+        data_sets,
+        warnings: Vec::new(),
+    })
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    async fn get(router: Router, uri: &str) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    async fn body_json(response: Response) -> Value {
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーはSOPインスタンスのメタデータを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances/1.2.392.200036.9116.2.6.1.48.1000.1.1/metadata",
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "application/dicom+json");
+        // レスポンスボディの確認
+        let body = body_json(response).await;
+        let data_sets = body.as_array().unwrap();
+        assert_eq!(data_sets.len(), 1);
+        assert_eq!(
+            data_sets[0]["00080018"],
+            json!({ "vr": "UI", "Value": ["1.2.392.200036.9116.2.6.1.48.1000.1.1"] })
+        );
+        // 特定文字集合に従って氏名を復号し、表記ごとに分けて返す
+        assert_eq!(
+            data_sets[0]["00100010"],
+            json!({
+                "vr": "PN",
+                "Value": [{
+                    "Alphabetic": "YAMADA^TARO",
+                    "Ideographic": "山田^太郎",
+                    "Phonetic": "やまだ^たろう"
+                }]
+            })
+        );
+        assert_eq!(
+            data_sets[0]["00280010"],
+            json!({ "vr": "US", "Value": [2] })
+        );
+        assert_eq!(
+            data_sets[0]["00280008"],
+            json!({ "vr": "IS", "Value": [2] })
+        );
+        // バイナリ値の属性はバルクデータの取得先を返す
+        assert_eq!(
+            data_sets[0]["7FE00010"],
+            json!({
+                "vr": "OW",
+                "BulkDataURI": "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances/1.2.392.200036.9116.2.6.1.48.1000.1.1/bulkdata/7FE00010"
+            })
+        );
+        // ファイルメタ情報は含まない
+        assert!(data_sets[0].get("00020010").is_none());
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは検査のメタデータを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/metadata",
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスボディの確認（シリーズ番号の昇順、シリーズ番号がないシリーズは最後）
+        let body = body_json(response).await;
+        let instance_uids = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|data_set| data_set["00080018"]["Value"][0].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            instance_uids,
+            vec![
+                "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+                "1.2.392.200036.9116.2.6.1.48.1000.1.2",
+                "1.3.12.2.1107.5.4.5.999999.30000009060216012356200000013",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn 存在しないシリーズを指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.3.4/metadata",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,751 @@
+use super::retrieve_frames::from_pixel_data_error;
+use crate::{
+    internal::{
+        application::dicom_object::RetrieveSopInstanceFilesCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, header},
+    response::{IntoResponse, Response},
+};
+use dicom_lib::{
+    core::DataSet,
+    rendering::{RenderedImage, RenderingError, VoiLutFunction, Window},
+};
+use std::collections::HashMap;
+
+/// `quality`を省略した場合のJPEGの品質
+const DEFAULT_QUALITY: u8 = 90;
+
+/// `viewport`を省略した場合のサムネイルの幅と高さの上限
+const THUMBNAIL_SIZE: u32 = 128;
+
+/// `viewport`で指定できる幅と高さの上限
+const MAX_VIEWPORT_SIZE: u32 = 4096;
+
+/// レンダリングした画像のメディアタイプ
+#[derive(Clone, Copy)]
+enum RenderedMediaType {
+    Jpeg,
+    Png,
+}
+
+impl RenderedMediaType {
+    /// Acceptヘッダーの候補から、最初に返せるメディアタイプを選ぶ。
+    /// Acceptヘッダーがない場合や`image/*`、`*/*`の場合は`image/jpeg`とする。
+    fn from_headers(headers: &HeaderMap) -> Result<Self, PresentationError> {
+        let Some(accept) = headers
+            .get(header::ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .filter(|v| !v.trim().is_empty())
+        else {
+            return Ok(Self::Jpeg);
+        };
+
+        accept
+            .split(',')
+            .find_map(
+                |media_type| match media_type.split(';').next().unwrap_or_default().trim() {
+                    "image/jpeg" | "image/*" | "*/*" => Some(Self::Jpeg),
+                    "image/png" => Some(Self::Png),
+                    _ => None,
+                },
+            )
+            .ok_or_else(|| {
+                PresentationError::NotAcceptable(format!(
+                    "image/jpegおよびimage/png以外のメディアタイプには対応していません (Accept=\"{accept}\")"
+                ))
+            })
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+        }
+    }
+}
+
+/// レンダリングのクエリパラメータ（PS3.18 8.3.5.1）
+struct RenderingParams {
+    window: Option<Window>,
+    viewport: Option<(u32, u32)>,
+    quality: u8,
+}
+
+impl RenderingParams {
+    fn parse(params: &HashMap<String, String>) -> Result<Self, PresentationError> {
+        let param = |key: &str| params.get(key).map(|v| v.trim()).filter(|v| !v.is_empty());
+
+        let window = param("window")
+            .map(|value| {
+                let invalid = || {
+                    PresentationError::BadRequest(format!(
+                        "windowは\"center,width,function\"の形式で指定してください (入力文字列=\"{value}\")"
+                    ))
+                };
+                let values = value.split(',').map(str::trim).collect::<Vec<_>>();
+                let (center, width, function) = match values.as_slice() {
+                    [center, width] => (center, width, "linear"),
+                    [center, width, function] => (center, width, *function),
+                    _ => return Err(invalid()),
+                };
+                let window = Window {
+                    center: center.parse().map_err(|_| invalid())?,
+                    width: width.parse().map_err(|_| invalid())?,
+                    function: VoiLutFunction::from_defined_term(
+                        &function.to_ascii_uppercase().replace('-', "_"),
+                    )
+                    .ok_or_else(invalid)?,
+                };
+                if !window.is_valid() {
+                    return Err(invalid());
+                }
+                Ok(window)
+            })
+            .transpose()?;
+
+        let viewport = param("viewport")
+            .map(|value| {
+                let invalid = || {
+                    PresentationError::BadRequest(format!(
+                        "viewportは1以上{MAX_VIEWPORT_SIZE}以下の\"幅,高さ\"の形式で指定してください (入力文字列=\"{value}\")"
+                    ))
+                };
+                let (width, height) = value.split_once(',').ok_or_else(invalid)?;
+                let size = |s: &str| {
+                    s.trim()
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|n| (1..=MAX_VIEWPORT_SIZE).contains(n))
+                        .ok_or_else(invalid)
+                };
+                Ok::<_, PresentationError>((size(width)?, size(height)?))
+            })
+            .transpose()?;
+
+        let quality = param("quality")
+            .map(|value| {
+                value
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|n| (1..=100).contains(n))
+                    .ok_or_else(|| {
+                        PresentationError::BadRequest(format!(
+                            "qualityは1以上100以下の整数で指定してください (入力文字列=\"{value}\")"
+                        ))
+                    })
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_QUALITY);
+
+        Ok(Self {
+            window,
+            viewport,
+            quality,
+        })
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/rendered",
+    description = "WADO-RSのレンダリングされたインスタンスの取得 (Retrieve Rendered Instance)。\
+SOPインスタンスの最初のフレームを表示用の画像にレンダリングし、Acceptヘッダーに従って`image/jpeg`（既定）または`image/png`で返す。\
+非圧縮のピクセルデータのみに対応する。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("sop_instance_uid" = String, Path, description = "SOPインスタンスUID"),
+        ("window" = Option<String>, Query, description = "ウィンドウ（`center,width,function`。functionは`linear`、`linear-exact`、`sigmoid`のいずれかで省略時は`linear`）。省略時はデータセットのWindow Center/WidthまたはVOI LUTを適用する"),
+        ("viewport" = Option<String>, Query, description = "縦横比を保って収める幅と高さ（`width,height`）"),
+        ("quality" = Option<u8>, Query, description = "JPEGの品質（1〜100、省略時は90）"),
+    ),
+    responses(
+        (status = 200, description = "レンダリングされたインスタンスの取得に成功", content_type = "image/jpeg"),
+        (status = 400, description = "クエリパラメータが無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンスまたはピクセルデータが見つからない", body = ErrorResponseBody),
+        (status = 406, description = "指定したメディアタイプで返せないか、レンダリングに対応していない画像", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_rendered_instance(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid, sop_instance_uid)): Path<(
+        String,
+        String,
+        String,
+    )>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let media_type = RenderedMediaType::from_headers(&headers)?;
+    let params = RenderingParams::parse(&params)?;
+
+    let data_set = read_instance_data_set(
+        &state,
+        study_instance_uid,
+        series_instance_uid,
+        sop_instance_uid,
+    )
+    .await?;
+
+    render(&data_set, 1, &params, None, media_type)
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/frames/{frame_list}/rendered",
+    description = "WADO-RSのレンダリングされたフレームの取得 (Retrieve Rendered Frames)。\
+指定したフレームを表示用の画像にレンダリングし、Acceptヘッダーに従って`image/jpeg`（既定）または`image/png`で返す。\
+フレーム番号は1つのみ指定できる。非圧縮のピクセルデータのみに対応する。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("sop_instance_uid" = String, Path, description = "SOPインスタンスUID"),
+        ("frame_list" = String, Path, description = "フレーム番号（1始まり）"),
+        ("window" = Option<String>, Query, description = "ウィンドウ（`center,width,function`。functionは`linear`、`linear-exact`、`sigmoid`のいずれかで省略時は`linear`）。省略時はデータセットのWindow Center/WidthまたはVOI LUTを適用する"),
+        ("viewport" = Option<String>, Query, description = "縦横比を保って収める幅と高さ（`width,height`）"),
+        ("quality" = Option<u8>, Query, description = "JPEGの品質（1〜100、省略時は90）"),
+    ),
+    responses(
+        (status = 200, description = "レンダリングされたフレームの取得に成功", content_type = "image/jpeg"),
+        (status = 400, description = "フレーム番号またはクエリパラメータが無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンス、ピクセルデータまたはフレームが見つからない", body = ErrorResponseBody),
+        (status = 406, description = "指定したメディアタイプで返せないか、レンダリングに対応していない画像", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_rendered_frames(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid, sop_instance_uid, frame_list)): Path<(
+        String,
+        String,
+        String,
+        String,
+    )>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let frame_number = match frame_list.trim().parse::<usize>() {
+        Ok(number) if number >= 1 => number,
+        _ => {
+            return Err(PresentationError::BadRequest(format!(
+                "フレーム番号は1以上の整数を1つだけ指定してください (入力文字列=\"{frame_list}\")"
+            )));
+        }
+    };
+    let media_type = RenderedMediaType::from_headers(&headers)?;
+    let params = RenderingParams::parse(&params)?;
+
+    let data_set = read_instance_data_set(
+        &state,
+        study_instance_uid,
+        series_instance_uid,
+        sop_instance_uid,
+    )
+    .await?;
+
+    render(&data_set, frame_number, &params, None, media_type)
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/thumbnail",
+    description = "WADO-RSのインスタンスのサムネイルの取得 (Retrieve Instance Thumbnail)。\
+SOPインスタンスの最初のフレームを、`viewport`を省略した場合は128×128に収まるようにレンダリングして返す。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("sop_instance_uid" = String, Path, description = "SOPインスタンスUID"),
+        ("viewport" = Option<String>, Query, description = "縦横比を保って収める幅と高さ（`width,height`、省略時は`128,128`）"),
+        ("quality" = Option<u8>, Query, description = "JPEGの品質（1〜100、省略時は90）"),
+    ),
+    responses(
+        (status = 200, description = "サムネイルの取得に成功", content_type = "image/jpeg"),
+        (status = 400, description = "クエリパラメータが無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンスまたはピクセルデータが見つからない", body = ErrorResponseBody),
+        (status = 406, description = "指定したメディアタイプで返せないか、レンダリングに対応していない画像", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_instance_thumbnail(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid, sop_instance_uid)): Path<(
+        String,
+        String,
+        String,
+    )>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let media_type = RenderedMediaType::from_headers(&headers)?;
+    // サムネイルはデータセットの表示条件でレンダリングする
+    let params = RenderingParams {
+        window: None,
+        ..RenderingParams::parse(&params)?
+    };
+
+    let data_set = read_instance_data_set(
+        &state,
+        study_instance_uid,
+        series_instance_uid,
+        sop_instance_uid,
+    )
+    .await?;
+
+    render(
+        &data_set,
+        1,
+        &params,
+        Some((THUMBNAIL_SIZE, THUMBNAIL_SIZE)),
+        media_type,
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/thumbnail",
+    description = "WADO-RSの検査のサムネイルの取得 (Retrieve Study Thumbnail)。\
+検査に含まれるSOPインスタンスのうち、レンダリングできる最初の画像のサムネイルを返す。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("viewport" = Option<String>, Query, description = "縦横比を保って収める幅と高さ（`width,height`、省略時は`128,128`）"),
+        ("quality" = Option<u8>, Query, description = "JPEGの品質（1〜100、省略時は90）"),
+    ),
+    responses(
+        (status = 200, description = "サムネイルの取得に成功", content_type = "image/jpeg"),
+        (status = 400, description = "クエリパラメータが無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "検査またはレンダリングできる画像が見つからない", body = ErrorResponseBody),
+        (status = 406, description = "指定したメディアタイプで返せない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_study_thumbnail(
+    State(state): State<AppState>,
+    Path(study_instance_uid): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: None,
+        sop_instance_uid: None,
+    };
+    retrieve_representative_thumbnail(&state, command, &params, &headers).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/thumbnail",
+    description = "WADO-RSのシリーズのサムネイルの取得 (Retrieve Series Thumbnail)。\
+シリーズに含まれるSOPインスタンスのうち、レンダリングできる最初の画像のサムネイルを返す。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("viewport" = Option<String>, Query, description = "縦横比を保って収める幅と高さ（`width,height`、省略時は`128,128`）"),
+        ("quality" = Option<u8>, Query, description = "JPEGの品質（1〜100、省略時は90）"),
+    ),
+    responses(
+        (status = 200, description = "サムネイルの取得に成功", content_type = "image/jpeg"),
+        (status = 400, description = "クエリパラメータが無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "シリーズまたはレンダリングできる画像が見つからない", body = ErrorResponseBody),
+        (status = 406, description = "指定したメディアタイプで返せない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_series_thumbnail(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid)): Path<(String, String)>,
+    Query(params): Query<HashMap<String, String>>,
+    headers: HeaderMap,
+) -> Result<Response, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: None,
+    };
+    retrieve_representative_thumbnail(&state, command, &params, &headers).await
+}
+
+/// 検査またはシリーズに含まれるSOPインスタンスを順に読み込み、最初にレンダリングできた画像のサムネイルを返す。
+async fn retrieve_representative_thumbnail(
+    state: &AppState,
+    command: RetrieveSopInstanceFilesCommand,
+    params: &HashMap<String, String>,
+    headers: &HeaderMap,
+) -> Result<Response, PresentationError> {
+    let media_type = RenderedMediaType::from_headers(headers)?;
+    let params = RenderingParams::parse(params)?;
+
+    let files = state
+        .retrieve_sop_instance_files_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+    for file in &files {
+        let data_set = state
+            .read_sop_instance_data_set_use_case
+            .execute(file)
+            .await
+            .map_err(PresentationError::from)?;
+        // ピクセルデータを含まないSOPインスタンスや、圧縮された画像は読み飛ばす
+        if let Ok(image) = RenderedImage::render(&data_set, 1, None) {
+            return encode(
+                image,
+                &params,
+                Some((THUMBNAIL_SIZE, THUMBNAIL_SIZE)),
+                media_type,
+            );
+        }
+    }
+
+    Err(PresentationError::NotFound(
+        "サムネイルを作成できる画像が見つかりません".to_string(),
+    ))
+}
+
+async fn read_instance_data_set(
+    state: &AppState,
+    study_instance_uid: String,
+    series_instance_uid: String,
+    sop_instance_uid: String,
+) -> Result<DataSet, PresentationError> {
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: Some(sop_instance_uid),
+    };
+    let files = state
+        .retrieve_sop_instance_files_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+    state
+        .read_sop_instance_data_set_use_case
+        .execute(&files[0])
+        .await
+        .map_err(PresentationError::from)
+}
+
+fn render(
+    data_set: &DataSet,
+    frame_number: usize,
+    params: &RenderingParams,
+    default_viewport: Option<(u32, u32)>,
+    media_type: RenderedMediaType,
+) -> Result<Response, PresentationError> {
+    let image = RenderedImage::render(data_set, frame_number, params.window)
+        .map_err(from_rendering_error)?;
+    encode(image, params, default_viewport, media_type)
+}
+
+fn encode(
+    image: RenderedImage,
+    params: &RenderingParams,
+    default_viewport: Option<(u32, u32)>,
+    media_type: RenderedMediaType,
+) -> Result<Response, PresentationError> {
+    let image = match params.viewport.or(default_viewport) {
+        Some((width, height)) => image.fit_to(width, height),
+        None => image,
+    };
+    let buf = match media_type {
+        RenderedMediaType::Jpeg => image.encode_jpeg(params.quality),
+        RenderedMediaType::Png => image.encode_png(),
+    }
+    .map_err(from_rendering_error)?;
+
+    Ok(([(header::CONTENT_TYPE, media_type.as_str())], buf).into_response())
+}
+
+fn from_rendering_error(e: RenderingError) -> PresentationError {
+    match e {
+        RenderingError::PixelData(e) => from_pixel_data_error(e),
+        RenderingError::FrameNotFound(_) => PresentationError::NotFound(e.to_string()),
+        RenderingError::Encapsulated
+        | RenderingError::UnsupportedPhotometricInterpretation(_)
+        | RenderingError::UnsupportedBitsAllocated(_) => {
+            PresentationError::NotAcceptable(e.to_string())
+        }
+        RenderingError::InvalidAttribute(_) | RenderingError::Encode(_) => {
+            PresentationError::InternalServerError(e.to_string())
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use tower::ServiceExt;
+
+    const STUDY_URI: &str = "/studies/1.2.392.200036.9116.2.6.1.48.1000";
+    const INSTANCE_URI: &str = "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances";
+
+    async fn get(router: Router, uri: &str, accept: Option<&str>) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let mut builder = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"));
+        if let Some(accept) = accept {
+            builder = builder.header("accept", accept);
+        }
+        router
+            .oneshot(builder.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn body_bytes(response: Response) -> Vec<u8> {
+        body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap()
+            .to_vec()
+    }
+
+    /// PNGのIHDRチャンクから幅と高さを返す。
+    fn png_size(png: &[u8]) -> (u32, u32) {
+        (
+            u32::from_be_bytes(png[16..20].try_into().unwrap()),
+            u32::from_be_bytes(png[20..24].try_into().unwrap()),
+        )
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーはレンダリングされたインスタンスをJPEGで取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/rendered?quality=80"),
+            None,
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // Content-Typeの確認
+        assert_eq!(response.headers()["content-type"], "image/jpeg");
+        // レスポンスボディの確認
+        let body = body_bytes(response).await;
+        assert!(body.starts_with(&[0xff, 0xd8]));
+    }
+
+    #[tokio::test]
+    async fn ウィンドウとビューポートを指定してフレームをPNGで取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!(
+                "{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/frames/2/rendered?window=2500,1000,linear-exact&viewport=8,8"
+            ),
+            Some("image/png"),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // Content-Typeの確認
+        assert_eq!(response.headers()["content-type"], "image/png");
+        // レスポンスボディの確認（2×2の画像を8×8に拡大する）
+        let body = body_bytes(response).await;
+        assert!(body.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert_eq!(png_size(&body), (8, 8));
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーはインスタンスのサムネイルを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/thumbnail"),
+            Some("image/png"),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスボディの確認（viewportを省略した場合は128×128に収める）
+        let body = body_bytes(response).await;
+        assert_eq!(png_size(&body), (128, 128));
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは検査のサムネイルを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{STUDY_URI}/thumbnail?viewport=64,32"),
+            Some("image/webp, image/png;q=0.9, */*;q=0.8"),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // Content-Typeの確認
+        assert_eq!(response.headers()["content-type"], "image/png");
+        // レスポンスボディの確認
+        let body = body_bytes(response).await;
+        assert_eq!(png_size(&body), (32, 32));
+    }
+
+    #[tokio::test]
+    async fn 画像を含まないシリーズのサムネイルは404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{STUDY_URI}/series/1.2.392.200036.9116.2.6.1.48.1000.2/thumbnail"),
+            None,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 圧縮されたピクセルデータのレンダリングは406エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.2/rendered"),
+            None,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    }
+
+    #[tokio::test]
+    async fn 対応していないメディアタイプを指定すると406エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/rendered"),
+            Some("image/gif"),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    }
+
+    #[tokio::test]
+    async fn 不正なクエリパラメータを指定すると400エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+
+        for query in [
+            "window=40",
+            "window=40,0",
+            "window=40,400,cubic",
+            "viewport=0,128",
+            "viewport=128",
+            "quality=101",
+        ] {
+            let state = startup::make_state(&repos);
+            let router = startup::make_router(state, &repos);
+
+            // Act
+            let response = get(
+                router,
+                &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/rendered?{query}"),
+                None,
+            )
+            .await;
+
+            // Assert
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{query}");
+        }
+    }
+
+    #[tokio::test]
+    async fn 複数のフレーム番号を指定すると400エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            &format!("{INSTANCE_URI}/1.2.392.200036.9116.2.6.1.48.1000.1.1/frames/1,2/rendered"),
+            None,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
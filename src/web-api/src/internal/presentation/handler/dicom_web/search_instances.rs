@@ -0,0 +1,196 @@
+mod response_body;
+
+use super::{
+    attribute::{SOP_CLASS_UID, SOP_INSTANCE_UID},
+    dicom_json::{DicomJsonDataSet, DicomJsonResponse},
+    search_query::SearchQuery,
+};
+use crate::{
+    internal::{
+        application::dicom_object::SearchSopInstancesCommand,
+        domain::repository::SopInstanceSearchCondition,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::extract::{Path, Query, State};
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series/{series_instance_uid}/instances",
+    description = "QIDO-RSのシリーズに含まれるSOPインスタンスの検索 (Search for Study's Series' Instances)。\
+照合キーは属性のキーワードまたはタグ（例: `00080018`）で指定する。\
+対応していない属性の照合キーは無視し、Warningヘッダーで通知する。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("series_instance_uid" = String, Path, description = "シリーズインスタンスUID"),
+        ("SOPInstanceUID" = Option<String>, Query, description = "SOPインスタンスUID（カンマ区切りで複数指定可）"),
+        ("SOPClassUID" = Option<String>, Query, description = "SOPクラスUID（カンマ区切りで複数指定可）"),
+        ("includefield" = Option<String>, Query, description = "追加で返す属性（カンマ区切りで複数指定可、`all`ですべての属性）"),
+        ("limit" = Option<u32>, Query, description = "返す件数の上限（最大1000件）"),
+        ("offset" = Option<u32>, Query, description = "読み飛ばす件数"),
+    ),
+    responses(
+        (status = 200, description = "SOPインスタンスの検索に成功", content_type = "application/dicom+json", body = Vec<DicomJsonDataSet>),
+        (status = 400, description = "クエリパラメータの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn search_instances(
+    State(state): State<AppState>,
+    Path((study_instance_uid, series_instance_uid)): Path<(String, String)>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<DicomJsonResponse, PresentationError> {
+    let query = SearchQuery::parse(params, &[&SOP_INSTANCE_UID, &SOP_CLASS_UID])?;
+
+    let command = SearchSopInstancesCommand {
+        condition: SopInstanceSearchCondition {
+            study_instance_uid,
+            series_instance_uid,
+            sop_instance_uids: query.values(&SOP_INSTANCE_UID),
+            sop_class_uids: query.values(&SOP_CLASS_UID),
+        },
+        offset: query.offset(),
+        limit: query.limit(),
+    };
+    let result = state
+        .search_sop_instances_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(DicomJsonResponse {
+        data_sets: result
+            .items
+            .iter()
+            .map(|entity| response_body::to_data_set(entity, &query))
+            .collect(),
+        warnings: query.warnings(result.has_more),
+    })
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    async fn get(router: Router, uri: &str) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    async fn body_json(response: Response) -> Value {
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーはシリーズに含まれるSOPインスタンスを検索できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances",
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let body = body_json(response).await;
+        assert_eq!(
+            body,
+            json!([
+                {
+                    "00080016": { "vr": "UI", "Value": ["1.2.840.10008.5.1.4.1.1.2"] },
+                    "00080018": { "vr": "UI", "Value": ["1.2.392.200036.9116.2.6.1.48.1000.1.1"] },
+                    "00080056": { "vr": "CS", "Value": ["ONLINE"] },
+                },
+                {
+                    "00080016": { "vr": "UI", "Value": ["1.2.840.10008.5.1.4.1.1.2"] },
+                    "00080018": { "vr": "UI", "Value": ["1.2.392.200036.9116.2.6.1.48.1000.1.2"] },
+                    "00080056": { "vr": "CS", "Value": ["ONLINE"] },
+                },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn SOPインスタンスUIDで絞り込みができincludefieldにallを指定するとすべての属性を返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances?SOPInstanceUID=1.2.392.200036.9116.2.6.1.48.1000.1.2,1.2.3&includefield=all",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body_json(response).await;
+        let instances = body.as_array().unwrap();
+        assert_eq!(instances.len(), 1);
+        assert_eq!(
+            instances[0]["00083002"],
+            json!({ "vr": "UI", "Value": ["1.2.840.10008.1.2.4.70"] })
+        );
+        assert_eq!(
+            instances[0]["0020000D"]["Value"][0],
+            "1.2.392.200036.9116.2.6.1.48.1000"
+        );
+        assert_eq!(
+            instances[0]["0020000E"]["Value"][0],
+            "1.2.392.200036.9116.2.6.1.48.1000.1"
+        );
+    }
+
+    #[tokio::test]
+    async fn 検査に含まれないシリーズを指定すると空の配列を返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.2000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await, json!([]));
+    }
+}
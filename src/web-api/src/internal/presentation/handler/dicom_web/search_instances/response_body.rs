@@ -0,0 +1,31 @@
+use crate::internal::{
+    domain::entity::SopInstance,
+    presentation::handler::dicom_web::{
+        attribute::{
+            AVAILABLE_TRANSFER_SYNTAX_UID, INSTANCE_AVAILABILITY, SERIES_INSTANCE_UID,
+            SOP_CLASS_UID, SOP_INSTANCE_UID, STUDY_INSTANCE_UID,
+        },
+        dicom_json::DicomJsonDataSet,
+        search_query::SearchQuery,
+    },
+};
+
+/// SOPインスタンスをDICOM JSONのデータセットに変換する。
+/// Available Transfer Syntax UID (0008,3002)、Study Instance UID (0020,000D) および
+/// Series Instance UID (0020,000E) は`includefield`で指定された場合のみ返す。
+pub fn to_data_set(entity: &SopInstance, query: &SearchQuery) -> DicomJsonDataSet {
+    let mut data_set = DicomJsonDataSet::new();
+    data_set.insert_string(&SOP_CLASS_UID, entity.class_uid());
+    data_set.insert_string(&SOP_INSTANCE_UID, entity.instance_uid());
+    data_set.insert_string(&INSTANCE_AVAILABILITY, "ONLINE");
+    if query.includes(&AVAILABLE_TRANSFER_SYNTAX_UID) {
+        data_set.insert_string(&AVAILABLE_TRANSFER_SYNTAX_UID, entity.transfer_syntax_uid());
+    }
+    if query.includes(&STUDY_INSTANCE_UID) {
+        data_set.insert_string(&STUDY_INSTANCE_UID, entity.study_instance_uid());
+    }
+    if query.includes(&SERIES_INSTANCE_UID) {
+        data_set.insert_string(&SERIES_INSTANCE_UID, entity.series_instance_uid());
+    }
+    data_set
+}
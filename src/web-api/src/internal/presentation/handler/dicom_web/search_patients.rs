@@ -0,0 +1,226 @@
+mod response_body;
+
+use super::{
+    attribute::{PATIENT_BIRTH_DATE, PATIENT_ID, PATIENT_NAME, PATIENT_SEX},
+    dicom_json::{DicomJsonDataSet, DicomJsonResponse},
+    search_query::SearchQuery,
+};
+use crate::{
+    internal::{
+        application::dicom_object::SearchPatientsCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::extract::{Query, State};
+
+#[utoipa::path(
+    get,
+    path = "/patients",
+    description = "QIDO-RSの患者の検索 (Search for Patients)。\
+照合キーは属性のキーワードまたはタグ（例: `00100020`）で指定する。\
+対応していない属性の照合キーは無視し、Warningヘッダーで通知する。",
+    params(
+        ("PatientID" = Option<String>, Query, description = "患者ID（ワイルドカード`*`, `?`を使用可）"),
+        ("PatientName" = Option<String>, Query, description = "患者氏名（ワイルドカード`*`, `?`を使用可、大文字・小文字を区別しない）"),
+        ("PatientBirthDate" = Option<String>, Query, description = "生年月日（YYYYMMDD、範囲はYYYYMMDD-YYYYMMDD）"),
+        ("PatientSex" = Option<String>, Query, description = "性別（M, F, O）"),
+        ("includefield" = Option<String>, Query, description = "追加で返す属性（カンマ区切りで複数指定可、`all`ですべての属性）"),
+        ("fuzzymatching" = Option<bool>, Query, description = "患者氏名をあいまい照合するかどうか"),
+        ("limit" = Option<u32>, Query, description = "返す件数の上限（最大1000件）"),
+        ("offset" = Option<u32>, Query, description = "読み飛ばす件数"),
+    ),
+    responses(
+        (status = 200, description = "患者の検索に成功", content_type = "application/dicom+json", body = Vec<DicomJsonDataSet>),
+        (status = 400, description = "クエリパラメータの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn search_patients(
+    State(state): State<AppState>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<DicomJsonResponse, PresentationError> {
+    let query = SearchQuery::parse(
+        params,
+        &[
+            &PATIENT_ID,
+            &PATIENT_NAME,
+            &PATIENT_BIRTH_DATE,
+            &PATIENT_SEX,
+        ],
+    )?;
+
+    let command = SearchPatientsCommand {
+        condition: query.patient_condition()?,
+        offset: query.offset(),
+        limit: query.limit(),
+    };
+    let result = state
+        .search_patients_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(DicomJsonResponse {
+        data_sets: result
+            .items
+            .iter()
+            .map(response_body::to_data_set)
+            .collect(),
+        warnings: query.warnings(result.has_more),
+    })
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode, header},
+        response::Response,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    async fn get(router: Router, uri: &str) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    async fn patient_ids(response: Response) -> Vec<String> {
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        body.as_array()
+            .unwrap()
+            .iter()
+            .map(|data_set| {
+                data_set["00100020"]["Value"][0]
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは患者を検索できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, "/patients").await;
+
+        // Assert
+        // ステータスコードとContent-Typeの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/dicom+json"
+        );
+
+        // レスポンスボディの確認（患者IDの昇順）
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let patients = body.as_array().unwrap();
+        assert_eq!(patients.len(), 2);
+
+        let patient = &patients[1];
+        assert_eq!(
+            patient["00100010"],
+            json!({
+                "vr": "PN",
+                "Value": [{
+                    "Alphabetic": "SUZUKI^HANAKO",
+                    "Ideographic": "鈴木^花子",
+                    "Phonetic": "すずき^はなこ",
+                }],
+            })
+        );
+        assert_eq!(
+            patient["00100020"],
+            json!({ "vr": "LO", "Value": ["P000002"] })
+        );
+        assert_eq!(
+            patient["00100030"],
+            json!({ "vr": "DA", "Value": ["19751224"] })
+        );
+        assert_eq!(patient["00100040"], json!({ "vr": "CS", "Value": ["F"] }));
+        assert_eq!(patient["00201200"], json!({ "vr": "IS", "Value": [1] }));
+        assert_eq!(patient["00201202"], json!({ "vr": "IS", "Value": [1] }));
+        assert_eq!(patient["00201204"], json!({ "vr": "IS", "Value": [1] }));
+    }
+
+    #[tokio::test]
+    async fn 患者IDと生年月日の範囲と性別で絞り込みができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let id_response = get(router.clone(), "/patients?PatientID=P00000?").await;
+        let birth_date_response = get(router.clone(), "/patients?PatientBirthDate=-19791231").await;
+        let sex_response = get(router, "/patients?00100040=M").await;
+
+        // Assert
+        assert_eq!(patient_ids(id_response).await, vec!["P000001", "P000002"]);
+        assert_eq!(patient_ids(birth_date_response).await, vec!["P000002"]);
+        assert_eq!(patient_ids(sex_response).await, vec!["P000001"]);
+    }
+
+    #[tokio::test]
+    async fn ワイルドカードの照合では大文字と小文字以外は完全一致する() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let partial_response = get(router.clone(), "/patients?PatientName=yamada").await;
+        let exact_response = get(router, "/patients?PatientName=yamada%5Etaro").await; // "yamada^taro"
+
+        // Assert
+        assert!(patient_ids(partial_response).await.is_empty());
+        assert_eq!(patient_ids(exact_response).await, vec!["P000001"]);
+    }
+
+    #[tokio::test]
+    async fn ログインしていない場合は401エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/patients")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::internal::{
+    domain::entity::Patient,
+    presentation::handler::dicom_web::{
+        attribute::{
+            NUMBER_OF_PATIENT_RELATED_INSTANCES, NUMBER_OF_PATIENT_RELATED_SERIES,
+            NUMBER_OF_PATIENT_RELATED_STUDIES, PATIENT_BIRTH_DATE, PATIENT_ID, PATIENT_NAME,
+            PATIENT_SEX, sex_to_code,
+        },
+        dicom_json::DicomJsonDataSet,
+    },
+};
+
+/// 患者をDICOM JSONのデータセットに変換する。
+pub fn to_data_set(entity: &Patient) -> DicomJsonDataSet {
+    let mut data_set = DicomJsonDataSet::new();
+    data_set.insert_person_name(
+        &PATIENT_NAME,
+        entity.name_alphabet(),
+        entity.name_kanji(),
+        entity.name_hiragana(),
+    );
+    data_set.insert_string(&PATIENT_ID, entity.id());
+    data_set.insert_date(&PATIENT_BIRTH_DATE, entity.birth_date());
+    data_set.insert_string(&PATIENT_SEX, sex_to_code(entity.sex()));
+    data_set.insert_number(
+        &NUMBER_OF_PATIENT_RELATED_STUDIES,
+        Some(entity.number_of_studies()),
+    );
+    data_set.insert_number(
+        &NUMBER_OF_PATIENT_RELATED_SERIES,
+        Some(entity.number_of_series()),
+    );
+    data_set.insert_number(
+        &NUMBER_OF_PATIENT_RELATED_INSTANCES,
+        Some(entity.number_of_instances()),
+    );
+    data_set
+}
@@ -0,0 +1,233 @@
+use super::attribute::{
+    Attribute, PATIENT_BIRTH_DATE, PATIENT_ID, PATIENT_NAME, PATIENT_SEX, sex_from_code,
+};
+use crate::internal::{
+    domain::{
+        repository::PatientSearchCondition,
+        value_object::{DateRange, MatchingPattern, PersonNamePattern},
+    },
+    presentation::error::PresentationError,
+};
+
+/// QIDO-RSの検索クエリ（PS3.18 8.3.4）
+///
+/// 照合キーは属性のキーワードまたはタグ（例: `PatientID=P000001`, `00100020=P000001`）で指定する。
+/// 値が空の照合キーは絞り込みを行わず、その属性を返すことだけを指定する（universal matching）。
+/// 対応していない属性の照合キーは無視し、Warningヘッダーで通知する。
+pub struct SearchQuery {
+    matching_keys: Vec<(&'static Attribute, String)>,
+    include_all: bool,
+    include_fields: Vec<&'static Attribute>,
+    fuzzy_matching: bool,
+    limit: Option<u32>,
+    offset: u32,
+    unsupported_keys: Vec<String>,
+}
+
+impl SearchQuery {
+    /// クエリパラメータを解析する。
+    /// `matching_attributes`は照合キーとして指定できる属性。
+    pub fn parse(
+        params: Vec<(String, String)>,
+        matching_attributes: &[&Attribute],
+    ) -> Result<Self, PresentationError> {
+        let mut query = Self {
+            matching_keys: vec![],
+            include_all: false,
+            include_fields: vec![],
+            fuzzy_matching: false,
+            limit: None,
+            offset: 0,
+            unsupported_keys: vec![],
+        };
+
+        for (key, value) in params {
+            match key.as_str() {
+                "includefield" => {
+                    for field in value.split(',').filter(|f| !f.is_empty()) {
+                        if field == "all" {
+                            query.include_all = true;
+                        } else if let Some(attribute) = Attribute::find(field) {
+                            query.include_fields.push(attribute);
+                        } else {
+                            query.unsupported_keys.push(field.to_string());
+                        }
+                    }
+                }
+                "fuzzymatching" => {
+                    query.fuzzy_matching = value.parse().map_err(|_| {
+                        PresentationError::BadRequest(format!(
+                            "fuzzymatchingにはtrueまたはfalseを指定する必要があります (入力文字列=\"{value}\")"
+                        ))
+                    })?;
+                }
+                "limit" => {
+                    query.limit = Some(value.parse().map_err(|_| {
+                        PresentationError::BadRequest(format!(
+                            "limitには0以上の整数を指定する必要があります (入力文字列=\"{value}\")"
+                        ))
+                    })?);
+                }
+                "offset" => {
+                    query.offset = value.parse().map_err(|_| {
+                        PresentationError::BadRequest(format!(
+                            "offsetには0以上の整数を指定する必要があります (入力文字列=\"{value}\")"
+                        ))
+                    })?;
+                }
+                _ => match Attribute::find(&key).filter(|a| matching_attributes.contains(a)) {
+                    Some(attribute) => {
+                        if query.matching_keys.iter().any(|(a, _)| *a == attribute) {
+                            return Err(PresentationError::BadRequest(format!(
+                                "同じ属性の照合キーを複数指定することはできません (属性=\"{}\")",
+                                attribute.keyword
+                            )));
+                        }
+                        query.matching_keys.push((attribute, value));
+                    }
+                    None => query.unsupported_keys.push(key),
+                },
+            }
+        }
+
+        Ok(query)
+    }
+
+    pub fn limit(&self) -> Option<u32> {
+        self.limit
+    }
+
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// 属性を返すかどうか（照合キーまたは`includefield`で指定されたかどうか）
+    pub fn includes(&self, attribute: &Attribute) -> bool {
+        self.include_all
+            || self.include_fields.contains(&attribute)
+            || self.matching_keys.iter().any(|(a, _)| *a == attribute)
+    }
+
+    /// 照合キーの値を返す。照合キーが指定されていないか、値が空の場合は`None`を返す。
+    fn value(&self, attribute: &Attribute) -> Option<&str> {
+        self.matching_keys
+            .iter()
+            .find(|(a, _)| *a == attribute)
+            .map(|(_, v)| v.as_str())
+            .filter(|v| !v.is_empty())
+    }
+
+    /// 単一値照合またはワイルドカード照合の照合キーを返す。
+    pub fn pattern(
+        &self,
+        attribute: &Attribute,
+    ) -> Result<Option<MatchingPattern>, PresentationError> {
+        self.value(attribute)
+            .map(MatchingPattern::new)
+            .transpose()
+            .map_err(|e| invalid_value(attribute, e))
+    }
+
+    /// 範囲照合の照合キーを返す。
+    pub fn date_range(
+        &self,
+        attribute: &Attribute,
+    ) -> Result<Option<DateRange>, PresentationError> {
+        self.value(attribute)
+            .map(DateRange::parse)
+            .transpose()
+            .map_err(|e| invalid_value(attribute, e))
+    }
+
+    /// 人名の照合キーを返す。`fuzzymatching=true`の場合はあいまい照合となる。
+    pub fn person_name(
+        &self,
+        attribute: &Attribute,
+    ) -> Result<Option<PersonNamePattern>, PresentationError> {
+        self.value(attribute)
+            .map(|v| PersonNamePattern::new(v, self.fuzzy_matching))
+            .transpose()
+            .map_err(|e| invalid_value(attribute, e))
+    }
+
+    /// 整数の照合キーを返す。
+    pub fn integer(&self, attribute: &Attribute) -> Result<Option<i32>, PresentationError> {
+        self.value(attribute)
+            .map(|v| v.parse())
+            .transpose()
+            .map_err(|_| invalid_value(attribute, "整数を指定する必要があります".to_string()))
+    }
+
+    /// リスト照合の照合キーを返す。値はカンマまたは`\`で区切って複数指定できる。
+    pub fn values(&self, attribute: &Attribute) -> Vec<String> {
+        self.value(attribute)
+            .map(|v| {
+                v.split([',', '\\'])
+                    .filter(|v| !v.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 患者の属性の照合キーから患者の検索条件を作る。
+    pub fn patient_condition(&self) -> Result<PatientSearchCondition, PresentationError> {
+        let patient_sex = self
+            .value(&PATIENT_SEX)
+            .map(|v| {
+                sex_from_code(v).ok_or_else(|| {
+                    invalid_value(
+                        &PATIENT_SEX,
+                        "M, F, Oのいずれかを指定する必要があります".to_string(),
+                    )
+                })
+            })
+            .transpose()?;
+
+        Ok(PatientSearchCondition {
+            patient_id: self.pattern(&PATIENT_ID)?,
+            patient_name: self.person_name(&PATIENT_NAME)?,
+            patient_birth_date: self.date_range(&PATIENT_BIRTH_DATE)?,
+            patient_sex,
+        })
+    }
+
+    /// Warningヘッダーの警告文を返す。
+    /// `has_more`は返した件数より後にも検索条件に一致するデータがあるかどうか。
+    pub fn warnings(&self, has_more: bool) -> Vec<String> {
+        let mut warnings = vec![];
+        if !self.unsupported_keys.is_empty() {
+            // ヘッダー値に使用できない文字を含むキーは置き換える
+            let keys = self
+                .unsupported_keys
+                .iter()
+                .map(|key| {
+                    key.chars()
+                        .map(|c| {
+                            if c.is_ascii_alphanumeric() || c == '.' {
+                                c
+                            } else {
+                                '?'
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+            warnings.push(format!(
+                "The following attributes are not supported and have been ignored: {}",
+                keys.join(", ")
+            ));
+        }
+        if has_more {
+            warnings.push("There are additional results that can be requested".to_string());
+        }
+        warnings
+    }
+}
+
+fn invalid_value(attribute: &Attribute, message: String) -> PresentationError {
+    PresentationError::BadRequest(format!(
+        "{}の照合キーが不正です: {message}",
+        attribute.keyword
+    ))
+}
@@ -0,0 +1,209 @@
+mod response_body;
+
+use super::{
+    attribute::{MODALITY, SERIES_INSTANCE_UID, SERIES_NUMBER},
+    dicom_json::{DicomJsonDataSet, DicomJsonResponse},
+    search_query::SearchQuery,
+};
+use crate::{
+    internal::{
+        application::dicom_object::SearchSeriesCommand,
+        domain::repository::SeriesSearchCondition,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::extract::{Path, Query, State};
+
+#[utoipa::path(
+    get,
+    path = "/studies/{study_instance_uid}/series",
+    description = "QIDO-RSの検査に含まれるシリーズの検索 (Search for Study's Series)。\
+照合キーは属性のキーワードまたはタグ（例: `00080060`）で指定する。\
+対応していない属性の照合キーは無視し、Warningヘッダーで通知する。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+        ("SeriesInstanceUID" = Option<String>, Query, description = "シリーズインスタンスUID（カンマ区切りで複数指定可）"),
+        ("Modality" = Option<String>, Query, description = "モダリティ（ワイルドカード`*`, `?`を使用可）"),
+        ("SeriesNumber" = Option<i32>, Query, description = "シリーズ番号"),
+        ("includefield" = Option<String>, Query, description = "追加で返す属性（カンマ区切りで複数指定可、`all`ですべての属性）"),
+        ("limit" = Option<u32>, Query, description = "返す件数の上限（最大1000件）"),
+        ("offset" = Option<u32>, Query, description = "読み飛ばす件数"),
+    ),
+    responses(
+        (status = 200, description = "シリーズの検索に成功", content_type = "application/dicom+json", body = Vec<DicomJsonDataSet>),
+        (status = 400, description = "クエリパラメータの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn search_series(
+    State(state): State<AppState>,
+    Path(study_instance_uid): Path<String>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<DicomJsonResponse, PresentationError> {
+    let query = SearchQuery::parse(params, &[&SERIES_INSTANCE_UID, &MODALITY, &SERIES_NUMBER])?;
+
+    let command = SearchSeriesCommand {
+        condition: SeriesSearchCondition {
+            study_instance_uid,
+            series_instance_uids: query.values(&SERIES_INSTANCE_UID),
+            modality: query.pattern(&MODALITY)?,
+            series_number: query.integer(&SERIES_NUMBER)?,
+        },
+        offset: query.offset(),
+        limit: query.limit(),
+    };
+    let result = state
+        .search_series_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(DicomJsonResponse {
+        data_sets: result
+            .items
+            .iter()
+            .map(|entity| response_body::to_data_set(entity, &query))
+            .collect(),
+        warnings: query.warnings(result.has_more),
+    })
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    async fn get(router: Router, uri: &str) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    async fn body_json(response: Response) -> Value {
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは検査に含まれるシリーズを検索できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, "/studies/1.2.392.200036.9116.2.6.1.48.1000/series").await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認（シリーズ番号の昇順、シリーズ番号がないシリーズは最後）
+        let body = body_json(response).await;
+        let series = body.as_array().unwrap();
+        assert_eq!(series.len(), 2);
+        assert_eq!(
+            series[0]["0020000E"],
+            json!({ "vr": "UI", "Value": ["1.2.392.200036.9116.2.6.1.48.1000.1"] })
+        );
+        assert_eq!(
+            series[0]["00080060"],
+            json!({ "vr": "CS", "Value": ["CT"] })
+        );
+        assert_eq!(series[0]["00200011"], json!({ "vr": "IS", "Value": [1] }));
+        assert_eq!(series[0]["00201209"], json!({ "vr": "IS", "Value": [2] }));
+        assert_eq!(
+            series[0]["00080056"],
+            json!({ "vr": "CS", "Value": ["ONLINE"] })
+        );
+        assert!(series[0].get("0020000D").is_none());
+        assert_eq!(
+            series[1]["0020000E"]["Value"][0],
+            "1.2.392.200036.9116.2.6.1.48.1000.2"
+        );
+        assert_eq!(series[1]["00200011"], json!({ "vr": "IS" }));
+    }
+
+    #[tokio::test]
+    async fn モダリティで絞り込みができincludefieldで検査インスタンスUIDを返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/series?Modality=S*&includefield=StudyInstanceUID",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = body_json(response).await;
+        let series = body.as_array().unwrap();
+        assert_eq!(series.len(), 1);
+        assert_eq!(
+            series[0]["0020000E"]["Value"][0],
+            "1.2.392.200036.9116.2.6.1.48.1000.2"
+        );
+        assert_eq!(
+            series[0]["0020000D"],
+            json!({ "vr": "UI", "Value": ["1.2.392.200036.9116.2.6.1.48.1000"] })
+        );
+    }
+
+    #[tokio::test]
+    async fn 存在しない検査を指定すると空の配列を返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, "/studies/1.2.3.4/series").await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body_json(response).await, json!([]));
+    }
+
+    #[tokio::test]
+    async fn 不正なシリーズ番号を指定すると400エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies/1.2.392.200036.9116.2.6.1.48.1000/series?SeriesNumber=one",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
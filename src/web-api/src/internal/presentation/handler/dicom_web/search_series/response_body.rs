@@ -0,0 +1,29 @@
+use crate::internal::{
+    domain::entity::Series,
+    presentation::handler::dicom_web::{
+        attribute::{
+            INSTANCE_AVAILABILITY, MODALITY, NUMBER_OF_SERIES_RELATED_INSTANCES,
+            SERIES_INSTANCE_UID, SERIES_NUMBER, STUDY_INSTANCE_UID,
+        },
+        dicom_json::DicomJsonDataSet,
+        search_query::SearchQuery,
+    },
+};
+
+/// シリーズをDICOM JSONのデータセットに変換する。
+/// Study Instance UID (0020,000D) は`includefield`で指定された場合のみ返す。
+pub fn to_data_set(entity: &Series, query: &SearchQuery) -> DicomJsonDataSet {
+    let mut data_set = DicomJsonDataSet::new();
+    data_set.insert_string(&INSTANCE_AVAILABILITY, "ONLINE");
+    data_set.insert_string(&MODALITY, entity.modality());
+    if query.includes(&STUDY_INSTANCE_UID) {
+        data_set.insert_string(&STUDY_INSTANCE_UID, entity.study_instance_uid());
+    }
+    data_set.insert_string(&SERIES_INSTANCE_UID, entity.instance_uid());
+    data_set.insert_number(&SERIES_NUMBER, entity.series_number().map(i64::from));
+    data_set.insert_number(
+        &NUMBER_OF_SERIES_RELATED_INSTANCES,
+        Some(entity.number_of_instances()),
+    );
+    data_set
+}
@@ -0,0 +1,444 @@
+mod response_body;
+
+use super::{
+    attribute::{
+        ACCESSION_NUMBER, MODALITIES_IN_STUDY, PATIENT_BIRTH_DATE, PATIENT_ID, PATIENT_NAME,
+        PATIENT_SEX, STUDY_DATE, STUDY_ID, STUDY_INSTANCE_UID,
+    },
+    dicom_json::{DicomJsonDataSet, DicomJsonResponse},
+    search_query::SearchQuery,
+};
+use crate::{
+    internal::{
+        application::dicom_object::SearchStudiesCommand,
+        domain::repository::StudySearchCondition,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::extract::{Query, State};
+
+#[utoipa::path(
+    get,
+    path = "/studies",
+    description = "QIDO-RSの検査の検索 (Search for Studies)。\
+照合キーは属性のキーワードまたはタグ（例: `00100020`）で指定する。\
+対応していない属性の照合キーは無視し、Warningヘッダーで通知する。",
+    params(
+        ("StudyInstanceUID" = Option<String>, Query, description = "検査インスタンスUID（カンマ区切りで複数指定可）"),
+        ("StudyDate" = Option<String>, Query, description = "検査日（YYYYMMDD、範囲はYYYYMMDD-YYYYMMDD）"),
+        ("AccessionNumber" = Option<String>, Query, description = "受付番号（ワイルドカード`*`, `?`を使用可）"),
+        ("StudyID" = Option<String>, Query, description = "検査ID（ワイルドカード`*`, `?`を使用可）"),
+        ("ModalitiesInStudy" = Option<String>, Query, description = "検査に含まれるモダリティ（カンマ区切りで複数指定可）"),
+        ("PatientID" = Option<String>, Query, description = "患者ID（ワイルドカード`*`, `?`を使用可）"),
+        ("PatientName" = Option<String>, Query, description = "患者氏名（ワイルドカード`*`, `?`を使用可、大文字・小文字を区別しない）"),
+        ("PatientBirthDate" = Option<String>, Query, description = "患者の生年月日（YYYYMMDD、範囲はYYYYMMDD-YYYYMMDD）"),
+        ("PatientSex" = Option<String>, Query, description = "患者の性別（M, F, O）"),
+        ("includefield" = Option<String>, Query, description = "追加で返す属性（カンマ区切りで複数指定可、`all`ですべての属性）"),
+        ("fuzzymatching" = Option<bool>, Query, description = "患者氏名をあいまい照合するかどうか"),
+        ("limit" = Option<u32>, Query, description = "返す件数の上限（最大1000件）"),
+        ("offset" = Option<u32>, Query, description = "読み飛ばす件数"),
+    ),
+    responses(
+        (status = 200, description = "検査の検索に成功", content_type = "application/dicom+json", body = Vec<DicomJsonDataSet>),
+        (status = 400, description = "クエリパラメータの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn search_studies(
+    State(state): State<AppState>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<DicomJsonResponse, PresentationError> {
+    let query = SearchQuery::parse(
+        params,
+        &[
+            &STUDY_INSTANCE_UID,
+            &STUDY_DATE,
+            &ACCESSION_NUMBER,
+            &STUDY_ID,
+            &MODALITIES_IN_STUDY,
+            &PATIENT_ID,
+            &PATIENT_NAME,
+            &PATIENT_BIRTH_DATE,
+            &PATIENT_SEX,
+        ],
+    )?;
+
+    let command = SearchStudiesCommand {
+        condition: StudySearchCondition {
+            study_instance_uids: query.values(&STUDY_INSTANCE_UID),
+            study_date: query.date_range(&STUDY_DATE)?,
+            accession_number: query.pattern(&ACCESSION_NUMBER)?,
+            study_id: query.pattern(&STUDY_ID)?,
+            modalities_in_study: query.values(&MODALITIES_IN_STUDY),
+            patient: query.patient_condition()?,
+        },
+        offset: query.offset(),
+        limit: query.limit(),
+    };
+    let result = state
+        .search_studies_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(DicomJsonResponse {
+        data_sets: result
+            .items
+            .iter()
+            .map(|entity| response_body::to_data_set(entity, &query))
+            .collect(),
+        warnings: query.warnings(result.has_more),
+    })
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode, header},
+        response::Response,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    async fn get(router: Router, uri: &str) -> Response {
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap()
+    }
+
+    async fn study_instance_uids(response: Response) -> Vec<String> {
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        body.as_array()
+            .unwrap()
+            .iter()
+            .map(|data_set| {
+                data_set["0020000D"]["Value"][0]
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーは検査を検索できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, "/studies").await;
+
+        // Assert
+        // ステータスコードとContent-Typeの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/dicom+json"
+        );
+        assert!(response.headers().get(header::WARNING).is_none());
+
+        // レスポンスボディの確認（検査日時の降順）
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let studies = body.as_array().unwrap();
+        assert_eq!(studies.len(), 3);
+        assert_eq!(
+            studies[0]["0020000D"]["Value"][0],
+            "1.2.392.200036.9116.2.6.1.48.2000"
+        );
+        assert_eq!(studies[0]["00080030"]["Value"][0], "103015.250000");
+        assert_eq!(
+            studies[2]["0020000D"]["Value"][0],
+            "1.2.392.200036.9116.2.6.1.48.3000"
+        );
+        assert_eq!(studies[2]["00080050"], json!({ "vr": "SH" }));
+        assert_eq!(studies[2]["00080030"], json!({ "vr": "TM" }));
+
+        let study = &studies[1];
+        assert_eq!(
+            study["0020000D"],
+            json!({ "vr": "UI", "Value": ["1.2.392.200036.9116.2.6.1.48.1000"] })
+        );
+        assert_eq!(
+            study["00080020"],
+            json!({ "vr": "DA", "Value": ["20260201"] })
+        );
+        assert_eq!(
+            study["00080030"],
+            json!({ "vr": "TM", "Value": ["090000"] })
+        );
+        assert_eq!(study["00080050"], json!({ "vr": "SH", "Value": ["A0001"] }));
+        assert_eq!(
+            study["00080056"],
+            json!({ "vr": "CS", "Value": ["ONLINE"] })
+        );
+        assert_eq!(
+            study["00080061"],
+            json!({ "vr": "CS", "Value": ["CT", "SR"] })
+        );
+        assert_eq!(
+            study["00100010"],
+            json!({
+                "vr": "PN",
+                "Value": [{
+                    "Alphabetic": "YAMADA^TARO",
+                    "Ideographic": "山田^太郎",
+                    "Phonetic": "やまだ^たろう",
+                }],
+            })
+        );
+        assert_eq!(
+            study["00100020"],
+            json!({ "vr": "LO", "Value": ["P000001"] })
+        );
+        assert_eq!(
+            study["00100030"],
+            json!({ "vr": "DA", "Value": ["19800401"] })
+        );
+        assert_eq!(study["00100040"], json!({ "vr": "CS", "Value": ["M"] }));
+        assert_eq!(study["00200010"], json!({ "vr": "SH", "Value": ["S0001"] }));
+        assert_eq!(study["00201206"], json!({ "vr": "IS", "Value": [2] }));
+        assert_eq!(study["00201208"], json!({ "vr": "IS", "Value": [3] }));
+        // includefieldで指定していない属性は返さない
+        assert!(study.get("00080062").is_none());
+    }
+
+    #[tokio::test]
+    async fn 患者氏名のワイルドカードで大文字と小文字を区別せずに絞り込みができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, "/studies?PatientName=suzuki*").await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            study_instance_uids(response).await,
+            vec!["1.2.392.200036.9116.2.6.1.48.3000"]
+        );
+    }
+
+    #[tokio::test]
+    async fn 漢字の表記を指定して患者氏名で絞り込みができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, "/studies?PatientName=%3D%E5%B1%B1%E7%94%B0*").await; // "=山田*"
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            study_instance_uids(response).await,
+            vec![
+                "1.2.392.200036.9116.2.6.1.48.2000",
+                "1.2.392.200036.9116.2.6.1.48.1000"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn あいまい照合では患者氏名の構成要素の先頭で一致する検査を返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let fuzzy_response = get(
+            router.clone(),
+            "/studies?PatientName=hana&fuzzymatching=true",
+        )
+        .await;
+        let literal_response = get(router, "/studies?PatientName=hana").await;
+
+        // Assert
+        assert_eq!(fuzzy_response.status(), StatusCode::OK);
+        assert_eq!(
+            study_instance_uids(fuzzy_response).await,
+            vec!["1.2.392.200036.9116.2.6.1.48.3000"]
+        );
+        assert_eq!(literal_response.status(), StatusCode::OK);
+        assert!(study_instance_uids(literal_response).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn 検査日の範囲とモダリティで絞り込みができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let date_response = get(router.clone(), "/studies?StudyDate=20260201-").await;
+        let modality_response = get(router, "/studies?00080061=CR,MR").await;
+
+        // Assert
+        assert_eq!(date_response.status(), StatusCode::OK);
+        assert_eq!(
+            study_instance_uids(date_response).await,
+            vec![
+                "1.2.392.200036.9116.2.6.1.48.2000",
+                "1.2.392.200036.9116.2.6.1.48.1000"
+            ]
+        );
+        assert_eq!(modality_response.status(), StatusCode::OK);
+        assert_eq!(
+            study_instance_uids(modality_response).await,
+            vec![
+                "1.2.392.200036.9116.2.6.1.48.2000",
+                "1.2.392.200036.9116.2.6.1.48.3000"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn includefieldで指定した属性と値が空の照合キーの属性を返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(
+            router,
+            "/studies?StudyInstanceUID=1.2.392.200036.9116.2.6.1.48.1000&includefield=00080062&PatientSex=",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let studies = body.as_array().unwrap();
+        assert_eq!(studies.len(), 1);
+        assert_eq!(
+            studies[0]["00080062"],
+            json!({
+                "vr": "UI",
+                "Value": ["1.2.840.10008.5.1.4.1.1.2", "1.2.840.10008.5.1.4.1.1.88.11"],
+            })
+        );
+        assert_eq!(studies[0]["00100040"]["Value"][0], "M");
+    }
+
+    #[tokio::test]
+    async fn limitとoffsetで取得範囲を指定でき後続がある場合は警告を返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router.clone(), "/studies?limit=1&offset=1").await;
+        let last_response = get(router, "/studies?limit=1&offset=2").await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::WARNING],
+            "299 - \"There are additional results that can be requested\""
+        );
+        assert_eq!(
+            study_instance_uids(response).await,
+            vec!["1.2.392.200036.9116.2.6.1.48.1000"]
+        );
+        assert_eq!(last_response.status(), StatusCode::OK);
+        assert!(last_response.headers().get(header::WARNING).is_none());
+        assert_eq!(
+            study_instance_uids(last_response).await,
+            vec!["1.2.392.200036.9116.2.6.1.48.3000"]
+        );
+    }
+
+    #[tokio::test]
+    async fn 対応していない属性の照合キーは無視して警告を返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, "/studies?ReferringPhysicianName=DOE*&00080060=CT").await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::WARNING],
+            "299 - \"The following attributes are not supported and have been ignored: ReferringPhysicianName, 00080060\""
+        );
+        assert_eq!(study_instance_uids(response).await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn 不正な照合キーを指定すると400エラーになる() {
+        for uri in [
+            "/studies?StudyDate=20260231",
+            "/studies?StudyDate=20260301-20260201",
+            "/studies?PatientSex=X",
+            "/studies?limit=-1",
+            "/studies?fuzzymatching=yes",
+        ] {
+            // Arrange
+            let repos = prepare_test_data().await;
+            let state = startup::make_state(&repos);
+            let router = startup::make_router(state, &repos);
+
+            // Act
+            let response = get(router, uri).await;
+
+            // Assert
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn ログインしていない場合は401エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/studies")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::internal::{
+    domain::entity::Study,
+    presentation::handler::dicom_web::{
+        attribute::{
+            ACCESSION_NUMBER, INSTANCE_AVAILABILITY, MODALITIES_IN_STUDY,
+            NUMBER_OF_STUDY_RELATED_INSTANCES, NUMBER_OF_STUDY_RELATED_SERIES, PATIENT_BIRTH_DATE,
+            PATIENT_ID, PATIENT_NAME, PATIENT_SEX, SOP_CLASSES_IN_STUDY, STUDY_DATE, STUDY_ID,
+            STUDY_INSTANCE_UID, STUDY_TIME, sex_to_code,
+        },
+        dicom_json::DicomJsonDataSet,
+        search_query::SearchQuery,
+    },
+};
+
+/// 検査をDICOM JSONのデータセットに変換する。
+/// SOP Classes in Study (0008,0062) は`includefield`で指定された場合のみ返す。
+pub fn to_data_set(entity: &Study, query: &SearchQuery) -> DicomJsonDataSet {
+    let mut data_set = DicomJsonDataSet::new();
+    data_set.insert_date(&STUDY_DATE, entity.study_date());
+    data_set.insert_time(&STUDY_TIME, entity.study_time());
+    data_set.insert_string(&ACCESSION_NUMBER, entity.accession_number());
+    data_set.insert_string(&INSTANCE_AVAILABILITY, "ONLINE");
+    data_set.insert_strings(&MODALITIES_IN_STUDY, entity.modalities());
+    if query.includes(&SOP_CLASSES_IN_STUDY) {
+        data_set.insert_strings(&SOP_CLASSES_IN_STUDY, entity.sop_class_uids());
+    }
+    data_set.insert_person_name(
+        &PATIENT_NAME,
+        entity.patient_name_alphabet(),
+        entity.patient_name_kanji(),
+        entity.patient_name_hiragana(),
+    );
+    data_set.insert_string(&PATIENT_ID, entity.patient_id());
+    data_set.insert_date(&PATIENT_BIRTH_DATE, entity.patient_birth_date());
+    data_set.insert_string(&PATIENT_SEX, sex_to_code(entity.patient_sex()));
+    data_set.insert_string(&STUDY_INSTANCE_UID, entity.instance_uid());
+    data_set.insert_string(&STUDY_ID, entity.id());
+    data_set.insert_number(
+        &NUMBER_OF_STUDY_RELATED_SERIES,
+        Some(entity.number_of_series()),
+    );
+    data_set.insert_number(
+        &NUMBER_OF_STUDY_RELATED_INSTANCES,
+        Some(entity.number_of_instances()),
+    );
+    data_set
+}
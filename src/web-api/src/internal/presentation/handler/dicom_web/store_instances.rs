@@ -0,0 +1,473 @@
+use super::{
+    attribute::{
+        FAILED_SOP_SEQUENCE, FAILURE_REASON, REFERENCED_SOP_CLASS_UID, REFERENCED_SOP_INSTANCE_UID,
+        REFERENCED_SOP_SEQUENCE, RETRIEVE_URL, WARNING_REASON,
+    },
+    dicom_json::{DICOM_JSON_CONTENT_TYPE, DicomJsonDataSet},
+    multipart,
+};
+use crate::{
+    internal::{
+        application::dicom_object::StoreSopInstancesCommand,
+        domain::entity::StoreResult,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    body::Bytes,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use dicom_lib::core::value::value_representations::ui::UiValue;
+
+const DICOM_MEDIA_TYPE: &str = "application/dicom";
+
+/// STOW-RSのリクエストボディの最大サイズ（1 GiB）
+pub const MAX_STORE_REQUEST_SIZE: usize = 1024 * 1024 * 1024;
+
+#[utoipa::path(
+    post,
+    path = "/studies",
+    description = "STOW-RSのSOPインスタンスの保存 (Store Instances)。\
+`multipart/related; type=\"application/dicom\"`の各パートをDICOMファイル（PS3.10）として、DICOMサーバーのC-STOREと同じ手順で保存する。\
+SOPインスタンスごとの保存結果をDICOM JSONのデータセット（PS3.18 10.5.3）で返す。",
+    request_body(content = Vec<u8>, content_type = "multipart/related; type=\"application/dicom\""),
+    responses(
+        (status = 200, description = "すべてのSOPインスタンスの保存に成功", content_type = "application/dicom+json", body = DicomJsonDataSet),
+        (status = 202, description = "一部のSOPインスタンスの保存に失敗したか、警告付きで保存", content_type = "application/dicom+json", body = DicomJsonDataSet),
+        (status = 400, description = "リクエストボディの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効", body = ErrorResponseBody),
+        (status = 409, description = "すべてのSOPインスタンスの保存に失敗", content_type = "application/dicom+json", body = DicomJsonDataSet),
+        (status = 413, description = "リクエストボディが大きすぎる"),
+        (status = 415, description = "`multipart/related; type=\"application/dicom\"`以外のリクエスト", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn store_instances(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, PresentationError> {
+    store(state, user, None, &headers, &body).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/studies/{study_instance_uid}",
+    description = "STOW-RSの検査へのSOPインスタンスの保存 (Store Instances)。\
+`multipart/related; type=\"application/dicom\"`の各パートをDICOMファイル（PS3.10）として、DICOMサーバーのC-STOREと同じ手順で保存する。\
+検査インスタンスUIDが一致しないSOPインスタンスは保存せず、失敗（FailureReason=0xA911）とする。\
+SOPインスタンスごとの保存結果をDICOM JSONのデータセット（PS3.18 10.5.3）で返す。",
+    params(
+        ("study_instance_uid" = String, Path, description = "検査インスタンスUID"),
+    ),
+    request_body(content = Vec<u8>, content_type = "multipart/related; type=\"application/dicom\""),
+    responses(
+        (status = 200, description = "すべてのSOPインスタンスの保存に成功", content_type = "application/dicom+json", body = DicomJsonDataSet),
+        (status = 202, description = "一部のSOPインスタンスの保存に失敗したか、警告付きで保存", content_type = "application/dicom+json", body = DicomJsonDataSet),
+        (status = 400, description = "リクエストボディの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効", body = ErrorResponseBody),
+        (status = 409, description = "すべてのSOPインスタンスの保存に失敗", content_type = "application/dicom+json", body = DicomJsonDataSet),
+        (status = 413, description = "リクエストボディが大きすぎる"),
+        (status = 415, description = "`multipart/related; type=\"application/dicom\"`以外のリクエスト", body = ErrorResponseBody),
+        (status = 422, description = "検査インスタンスUIDが無効", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn store_study_instances(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(study_instance_uid): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, PresentationError> {
+    // バリデーション
+    UiValue::from_string(&study_instance_uid).map_err(|e| {
+        PresentationError::UnprocessableContent(format!("無効なStudy Instance UID: {e}"))
+    })?;
+
+    store(state, user, Some(study_instance_uid), &headers, &body).await
+}
+
+/// リクエストボディの各パートをSOPインスタンスとして保存し、保存結果を返す。
+async fn store(
+    state: AppState,
+    user: AuthenticatedUser,
+    study_instance_uid: Option<String>,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<Response, PresentationError> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let Some((media_type, boundary)) = multipart::parse_content_type(content_type) else {
+        return Err(PresentationError::UnsupportedMediaType(format!(
+            "multipart/relatedのリクエストのみ受け付けます (Content-Type=\"{content_type}\")"
+        )));
+    };
+    if !media_type.is_some_and(|t| is_dicom_media_type(&t)) {
+        return Err(PresentationError::UnsupportedMediaType(format!(
+            "typeパラメーターが{DICOM_MEDIA_TYPE}のリクエストのみ受け付けます (Content-Type=\"{content_type}\")"
+        )));
+    }
+
+    let parts = multipart::parse_body(body, &boundary).map_err(PresentationError::BadRequest)?;
+    if parts.is_empty() {
+        return Err(PresentationError::BadRequest(
+            "保存するSOPインスタンスが含まれていません".to_string(),
+        ));
+    }
+    if let Some(part_content_type) = parts
+        .iter()
+        .filter_map(|part| part.content_type.as_deref())
+        .find(|t| !is_dicom_media_type(t))
+    {
+        return Err(PresentationError::UnsupportedMediaType(format!(
+            "{DICOM_MEDIA_TYPE}以外のパートは受け付けません (Content-Type=\"{part_content_type}\")"
+        )));
+    }
+
+    let command = StoreSopInstancesCommand {
+        files: parts.into_iter().map(|part| part.body).collect(),
+        study_instance_uid,
+        created_by: user.uuid(),
+    };
+    let results = state.store_sop_instances_use_case.execute(command).await;
+
+    let status = if results.iter().all(|r| r.status() == 0x0000) {
+        StatusCode::OK
+    } else if results.iter().any(StoreResult::is_stored) {
+        StatusCode::ACCEPTED
+    } else {
+        StatusCode::CONFLICT
+    };
+
+    Ok((
+        status,
+        [(header::CONTENT_TYPE, DICOM_JSON_CONTENT_TYPE)],
+        Json(response_data_set(&results)),
+    )
+        .into_response())
+}
+
+/// メディアタイプが`application/dicom`かどうか（パラメーターは無視する）
+fn is_dicom_media_type(value: &str) -> bool {
+    value
+        .split(';')
+        .next()
+        .is_some_and(|t| t.trim().eq_ignore_ascii_case(DICOM_MEDIA_TYPE))
+}
+
+/// 保存結果からSTOW-RSのレスポンスのデータセット（PS3.18 10.5.3）を生成する。
+///
+/// 保存したSOPインスタンスがすべて同じ検査に含まれる場合は、検査の取得URLをRetrieve URLとする。
+fn response_data_set(results: &[StoreResult]) -> DicomJsonDataSet {
+    let (stored, failed): (Vec<_>, Vec<_>) = results.iter().partition(|r| r.is_stored());
+
+    let mut data_set = DicomJsonDataSet::new();
+    if let Some(first) = stored.first()
+        && stored
+            .iter()
+            .all(|r| r.study_instance_uid() == first.study_instance_uid())
+    {
+        data_set.insert_string(
+            &RETRIEVE_URL,
+            &format!("/studies/{}", first.study_instance_uid()),
+        );
+    }
+    if !failed.is_empty() {
+        let items = failed
+            .iter()
+            .map(|r| {
+                let mut item = referenced_sop(r);
+                item.insert_unsigned_short(&FAILURE_REASON, r.status());
+                item
+            })
+            .collect();
+        data_set.insert_sequence(&FAILED_SOP_SEQUENCE, items);
+    }
+    if !stored.is_empty() {
+        let items = stored
+            .iter()
+            .map(|r| {
+                let mut item = referenced_sop(r);
+                item.insert_string(
+                    &RETRIEVE_URL,
+                    &format!(
+                        "/studies/{}/series/{}/instances/{}",
+                        r.study_instance_uid(),
+                        r.series_instance_uid(),
+                        r.sop_instance_uid()
+                    ),
+                );
+                if r.is_warning() {
+                    item.insert_unsigned_short(&WARNING_REASON, r.status());
+                }
+                item
+            })
+            .collect();
+        data_set.insert_sequence(&REFERENCED_SOP_SEQUENCE, items);
+    }
+    data_set
+}
+
+/// 保存結果のSOPクラスUIDとSOPインスタンスUIDを持つシーケンスの項目を生成する。
+fn referenced_sop(result: &StoreResult) -> DicomJsonDataSet {
+    let mut item = DicomJsonDataSet::new();
+    item.insert_string(&REFERENCED_SOP_CLASS_UID, result.sop_class_uid());
+    item.insert_string(&REFERENCED_SOP_INSTANCE_UID, result.sop_instance_uid());
+    item
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::{prepare_test_data, test_ct_file, test_encapsulated_pixel_data};
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    const BOUNDARY: &str = "stow-test-boundary";
+    const CONTENT_TYPE: &str =
+        "multipart/related; type=\"application/dicom\"; boundary=stow-test-boundary";
+
+    async fn post(router: Router, uri: &str, content_type: &str, body: Vec<u8>) -> Response {
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("cookie", format!("session_id={session_id}"))
+                    .header("x-csrf-token", csrf_token)
+                    .header("content-type", content_type)
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    /// パートを`multipart/related`のボディに組み立てる。
+    fn multipart_body(parts: &[Vec<u8>]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for part in parts {
+            body.extend_from_slice(
+                format!("--{BOUNDARY}\r\nContent-Type: application/dicom\r\n\r\n").as_bytes(),
+            );
+            body.extend_from_slice(part);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    fn ct_file(instance_uid: &str) -> Vec<u8> {
+        test_ct_file(
+            instance_uid,
+            "1.2.840.10008.1.2.4.70",
+            &test_encapsulated_pixel_data(&[b"\xff\xd8FRAME1\xff\xd9", b"\xff\xd8FRAME2\xff\xd9"]),
+        )
+    }
+
+    async fn body_json(response: Response) -> Value {
+        let body = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーはDICOMファイルを保存できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let body = multipart_body(&[
+            ct_file("1.2.392.200036.9116.2.6.1.48.1000.1.11"),
+            ct_file("1.2.392.200036.9116.2.6.1.48.1000.1.12"),
+        ]);
+
+        // Act
+        let response = post(router, "/studies", CONTENT_TYPE, body).await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスヘッダーの確認
+        assert_eq!(response.headers()["content-type"], "application/dicom+json");
+        // レスポンスボディの確認
+        let body = body_json(response).await;
+        assert_eq!(
+            body["00081190"],
+            json!({"vr": "UR", "Value": ["/studies/1.2.392.200036.9116.2.6.1.48.1000"]})
+        );
+        assert!(body.get("00081198").is_none());
+        let items = body["00081199"]["Value"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(
+            items[0],
+            json!({
+                "00081150": {"vr": "UI", "Value": ["1.2.840.10008.5.1.4.1.1.2"]},
+                "00081155": {"vr": "UI", "Value": ["1.2.392.200036.9116.2.6.1.48.1000.1.11"]},
+                "00081190": {"vr": "UR", "Value": ["/studies/1.2.392.200036.9116.2.6.1.48.1000/series/1.2.392.200036.9116.2.6.1.48.1000.1/instances/1.2.392.200036.9116.2.6.1.48.1000.1.11"]},
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn 保存に失敗したSOPインスタンスがある場合は202を返す() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let body = multipart_body(&[
+            ct_file("1.2.392.200036.9116.2.6.1.48.1000.1.11"),
+            b"not a dicom file".to_vec(),
+            ct_file("1.2.392.200036.9116.2.6.1.48.1000.1.11"),
+        ]);
+
+        // Act
+        let response = post(router, "/studies", CONTENT_TYPE, body).await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        // レスポンスボディの確認
+        let body = body_json(response).await;
+        // 解釈できないファイルは失敗（0xC000）とする
+        let failed = body["00081198"]["Value"].as_array().unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(
+            failed[0]["00081197"],
+            json!({"vr": "US", "Value": [0xc000]})
+        );
+        // 保存済みのSOPインスタンスは警告付きの成功とする
+        let stored = body["00081199"]["Value"].as_array().unwrap();
+        assert_eq!(stored.len(), 2);
+        assert!(stored[0].get("00081196").is_none());
+        assert_eq!(
+            stored[1]["00081196"],
+            json!({"vr": "US", "Value": [0xb010]})
+        );
+    }
+
+    #[tokio::test]
+    async fn 検査インスタンスUIDが一致しないSOPインスタンスは保存できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let body = multipart_body(&[ct_file("1.2.392.200036.9116.2.6.1.48.1000.1.11")]);
+
+        // Act
+        let response = post(router, "/studies/1.2.3.4", CONTENT_TYPE, body).await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        // レスポンスボディの確認
+        let body = body_json(response).await;
+        assert!(body.get("00081190").is_none());
+        assert!(body.get("00081199").is_none());
+        assert_eq!(
+            body["00081198"]["Value"][0],
+            json!({
+                "00081150": {"vr": "UI", "Value": ["1.2.840.10008.5.1.4.1.1.2"]},
+                "00081155": {"vr": "UI", "Value": ["1.2.392.200036.9116.2.6.1.48.1000.1.11"]},
+                "00081197": {"vr": "US", "Value": [0xa911]},
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn typeがapplication_dicom以外のリクエストは415エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let body = multipart_body(&[ct_file("1.2.392.200036.9116.2.6.1.48.1000.1.11")]);
+
+        // Act
+        let response = post(
+            router,
+            "/studies",
+            "multipart/related; type=\"application/dicom+json\"; boundary=stow-test-boundary",
+            body,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn 終端の境界がないリクエストは400エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let mut body =
+            format!("--{BOUNDARY}\r\nContent-Type: application/dicom\r\n\r\n").into_bytes();
+        body.extend(ct_file("1.2.392.200036.9116.2.6.1.48.1000.1.11"));
+
+        // Act
+        let response = post(router, "/studies", CONTENT_TYPE, body).await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn CSRFトークンがない場合は403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+
+        // Act
+        let response = router
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/studies")
+                    .header("cookie", format!("session_id={session_id}"))
+                    .header("content-type", CONTENT_TYPE)
+                    .body(Body::from(multipart_body(&[ct_file(
+                        "1.2.392.200036.9116.2.6.1.48.1000.1.11",
+                    )])))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
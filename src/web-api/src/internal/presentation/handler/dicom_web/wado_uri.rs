@@ -0,0 +1,530 @@
+use crate::{
+    internal::{
+        application::{
+            dicom_object::RetrieveSopInstanceFilesCommand, wado_uri_token::IssueWadoUriTokenCommand,
+        },
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    extract::{Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use dicom_lib::{
+    constants::transfer_syntax_uids::{EXPLICIT_VR_LITTLE_ENDIAN, IMPLICIT_VR_LITTLE_ENDIAN},
+    core::value::value_representations::ui::UiValue,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+const DICOM_MEDIA_TYPE: &str = "application/dicom";
+
+/// 相互に変換できる転送構文
+const CONVERTIBLE_TRANSFER_SYNTAX_UIDS: [&str; 2] =
+    [IMPLICIT_VR_LITTLE_ENDIAN, EXPLICIT_VR_LITTLE_ENDIAN];
+
+#[utoipa::path(
+    get,
+    path = "/wado",
+    description = "WADO-URIのDICOMオブジェクトの取得 (PS3.18 9)。\
+SOPインスタンスのDICOMファイル（PS3.10）を`application/dicom`で返す。\
+`transferSyntax`を指定した場合は、Implicit VR Little EndianとExplicit VR Little Endianの間で転送構文を変換して返す。\
+セッションのほか、`POST /wado/tokens`で発行した署名付きURLのトークン（`token`）で認証できる。",
+    params(
+        ("requestType" = String, Query, description = "`WADO`固定"),
+        ("studyUID" = String, Query, description = "検査インスタンスUID"),
+        ("seriesUID" = String, Query, description = "シリーズインスタンスUID"),
+        ("objectUID" = String, Query, description = "SOPインスタンスUID"),
+        ("contentType" = Option<String>, Query, description = "メディアタイプ（`application/dicom`のみ対応。省略時は`application/dicom`）"),
+        ("transferSyntax" = Option<String>, Query, description = "転送構文UID（省略時は保存時の転送構文）"),
+        ("token" = Option<String>, Query, description = "署名付きURLのトークン"),
+    ),
+    responses(
+        (status = 200, description = "DICOMオブジェクトの取得に成功", content_type = "application/dicom"),
+        (status = 400, description = "クエリパラメータが無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている、または署名付きURLが無効か期限が切れている", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンスが見つからない", body = ErrorResponseBody),
+        (status = 406, description = "指定したメディアタイプまたは転送構文で返せない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn retrieve_wado_uri_object(
+    State(state): State<AppState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Response, PresentationError> {
+    let param = |key: &str| params.get(key).map(|v| v.trim()).filter(|v| !v.is_empty());
+
+    // バリデーション
+    if param("requestType") != Some("WADO") {
+        return Err(PresentationError::BadRequest(
+            "requestTypeにはWADOを指定してください".to_string(),
+        ));
+    }
+    let uid = |key: &str| {
+        param(key)
+            .map(str::to_string)
+            .ok_or_else(|| PresentationError::BadRequest(format!("{key}を指定してください")))
+    };
+    let study_instance_uid = uid("studyUID")?;
+    let series_instance_uid = uid("seriesUID")?;
+    let sop_instance_uid = uid("objectUID")?;
+    if let Some(content_type) = param("contentType")
+        && !accepts_dicom(content_type)
+    {
+        return Err(PresentationError::NotAcceptable(format!(
+            "{DICOM_MEDIA_TYPE}以外のメディアタイプには対応していません (contentType=\"{content_type}\")"
+        )));
+    }
+
+    let command = RetrieveSopInstanceFilesCommand {
+        study_instance_uid,
+        series_instance_uid: Some(series_instance_uid),
+        sop_instance_uid: Some(sop_instance_uid),
+    };
+    let files = state
+        .retrieve_sop_instance_files_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+    let file = &files[0];
+
+    let buf = match param("transferSyntax") {
+        Some(transfer_syntax_uid) if transfer_syntax_uid != file.transfer_syntax_uid() => {
+            if !CONVERTIBLE_TRANSFER_SYNTAX_UIDS.contains(&transfer_syntax_uid)
+                || !CONVERTIBLE_TRANSFER_SYNTAX_UIDS.contains(&file.transfer_syntax_uid())
+            {
+                return Err(PresentationError::NotAcceptable(format!(
+                    "転送構文の変換に対応していません (保存時の転送構文UID=\"{}\", 指定した転送構文UID=\"{transfer_syntax_uid}\")",
+                    file.transfer_syntax_uid()
+                )));
+            }
+            state
+                .convert_sop_instance_file_use_case
+                .execute(file, transfer_syntax_uid)
+                .await
+        }
+        _ => state.read_sop_instance_file_use_case.execute(file).await,
+    }
+    .map_err(PresentationError::from)?;
+
+    Ok(([(header::CONTENT_TYPE, DICOM_MEDIA_TYPE)], buf).into_response())
+}
+
+/// `contentType`の候補（カンマ区切り）に`application/dicom`を返せるものが含まれるかどうかを判定する。
+fn accepts_dicom(content_type: &str) -> bool {
+    content_type.split(',').any(|media_type| {
+        let media_type = media_type.split(';').next().unwrap_or_default().trim();
+        matches!(media_type, DICOM_MEDIA_TYPE | "application/*" | "*/*")
+    })
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWadoUriTokenRequestBody {
+    pub study_instance_uid: String,
+    pub series_instance_uid: String,
+    pub sop_instance_uid: String,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWadoUriTokenResponseBody {
+    /// 署名付きURLのトークン
+    pub token: String,
+    /// 署名付きURL（APIのベースURLからの相対パス）
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/wado/tokens",
+    description = "WADO-URIの署名付きURLの発行。\
+ログインせずにSOPインスタンスを取得できる、有効期限付きのURLを発行する。\
+署名付きURLでの取得は、発行したユーザーによる取得として扱う。",
+    request_body = CreateWadoUriTokenRequestBody,
+    responses(
+        (status = 201, description = "署名付きURLの発行に成功", body = CreateWadoUriTokenResponseBody),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効", body = ErrorResponseBody),
+        (status = 404, description = "SOPインスタンスが見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "dicom-web"
+)]
+pub async fn create_wado_uri_token(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Json(request_body): Json<CreateWadoUriTokenRequestBody>,
+) -> Result<(StatusCode, Json<CreateWadoUriTokenResponseBody>), PresentationError> {
+    // バリデーション
+    for uid in [
+        &request_body.study_instance_uid,
+        &request_body.series_instance_uid,
+        &request_body.sop_instance_uid,
+    ] {
+        UiValue::from_string(uid)
+            .map_err(|e| PresentationError::UnprocessableContent(format!("無効なUID: {e}")))?;
+    }
+
+    let command = IssueWadoUriTokenCommand {
+        study_instance_uid: request_body.study_instance_uid,
+        series_instance_uid: request_body.series_instance_uid,
+        sop_instance_uid: request_body.sop_instance_uid,
+        issued_by: user.uuid(),
+    };
+    let (token, signed_token) = state
+        .issue_wado_uri_token_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    // UIDとトークンはURLで使用できる文字のみで構成されるため、エンコードは不要
+    let url = format!(
+        "/wado?requestType=WADO&studyUID={}&seriesUID={}&objectUID={}&contentType={}&token={signed_token}",
+        token.study_instance_uid(),
+        token.series_instance_uid(),
+        token.sop_instance_uid(),
+        DICOM_MEDIA_TYPE.replace('/', "%2F"),
+    );
+    let response_body = CreateWadoUriTokenResponseBody {
+        token: signed_token,
+        url,
+        expires_at: *token.expires_at(),
+    };
+
+    Ok((StatusCode::CREATED, Json(response_body)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{
+        internal::{domain::entity::WadoUriToken, presentation::util::test_helpers},
+        startup,
+    };
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+        response::Response,
+    };
+    use chrono::{Duration, Utc};
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    const INSTANCE_QUERY: &str = "requestType=WADO&studyUID=1.2.392.200036.9116.2.6.1.48.1000&seriesUID=1.2.392.200036.9116.2.6.1.48.1000.1&objectUID=1.2.392.200036.9116.2.6.1.48.1000.1.1";
+
+    async fn get(router: Router, uri: &str, session_id: Option<&str>) -> Response {
+        let mut request = Request::builder().method("GET").uri(uri);
+        if let Some(session_id) = session_id {
+            request = request.header("cookie", format!("session_id={session_id}"));
+        }
+        router
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn body_bytes(response: Response) -> Vec<u8> {
+        body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap()
+            .to_vec()
+    }
+
+    /// ファイルメタ情報の転送構文UIDを返す。
+    fn transfer_syntax_uid(buf: &[u8]) -> String {
+        let position = buf
+            .windows(6)
+            .position(|w| w == b"\x02\x00\x10\x00UI")
+            .unwrap();
+        let len = u16::from_le_bytes([buf[position + 6], buf[position + 7]]) as usize;
+        String::from_utf8_lossy(&buf[position + 8..position + 8 + len])
+            .trim_end_matches('\0')
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn ログインユーザーはWADO_URIでDICOMファイルを取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+
+        // Act
+        let response = get(
+            router,
+            &format!("/wado?{INSTANCE_QUERY}&contentType=application%2Fdicom"),
+            Some(&session_id),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスヘッダーの確認
+        assert_eq!(response.headers()["content-type"], "application/dicom");
+        // レスポンスボディの確認（保存時の転送構文のまま返す）
+        let body = body_bytes(response).await;
+        assert_eq!(&body[128..132], b"DICM");
+        assert_eq!(transfer_syntax_uid(&body), "1.2.840.10008.1.2.1");
+    }
+
+    #[tokio::test]
+    async fn 転送構文を指定するとImplicit_VR_Little_Endianに変換して取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+
+        // Act
+        let response = get(
+            router,
+            &format!("/wado?{INSTANCE_QUERY}&transferSyntax=1.2.840.10008.1.2"),
+            Some(&session_id),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+        // レスポンスボディの確認（ファイルメタ情報の転送構文UIDが変換後の値になる）
+        let body = body_bytes(response).await;
+        assert_eq!(transfer_syntax_uid(&body), "1.2.840.10008.1.2");
+        // 暗黙的VRではデータ要素にVRが含まれない
+        assert!(body.ends_with(&[
+            0xe0, 0x7f, 0x10, 0x00, 16, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+            15
+        ]));
+    }
+
+    #[tokio::test]
+    async fn 変換に対応していない転送構文やメディアタイプを指定すると406エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+
+        // Act
+        let compressed_target = get(
+            router.clone(),
+            &format!("/wado?{INSTANCE_QUERY}&transferSyntax=1.2.840.10008.1.2.4.50"),
+            Some(&session_id),
+        )
+        .await;
+        let compressed_source = get(
+            router.clone(),
+            "/wado?requestType=WADO&studyUID=1.2.392.200036.9116.2.6.1.48.1000&seriesUID=1.2.392.200036.9116.2.6.1.48.1000.1&objectUID=1.2.392.200036.9116.2.6.1.48.1000.1.2&transferSyntax=1.2.840.10008.1.2.1",
+            Some(&session_id),
+        )
+        .await;
+        let jpeg = get(
+            router,
+            &format!("/wado?{INSTANCE_QUERY}&contentType=image%2Fjpeg"),
+            Some(&session_id),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(compressed_target.status(), StatusCode::NOT_ACCEPTABLE);
+        assert_eq!(compressed_source.status(), StatusCode::NOT_ACCEPTABLE);
+        assert_eq!(jpeg.status(), StatusCode::NOT_ACCEPTABLE);
+    }
+
+    #[tokio::test]
+    async fn 必須のクエリパラメータがない場合は400エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+
+        // Act
+        let without_request_type = get(
+            router.clone(),
+            &format!("/wado?{}", INSTANCE_QUERY.replace("requestType=WADO&", "")),
+            Some(&session_id),
+        )
+        .await;
+        let without_object_uid = get(
+            router,
+            "/wado?requestType=WADO&studyUID=1.2.392.200036.9116.2.6.1.48.1000&seriesUID=1.2.392.200036.9116.2.6.1.48.1000.1",
+            Some(&session_id),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(without_request_type.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(without_object_uid.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn 存在しないSOPインスタンスを指定すると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+
+        // Act
+        let response = get(
+            router,
+            &format!("/wado?{INSTANCE_QUERY}9"),
+            Some(&session_id),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 発行した署名付きURLではログインせずに取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let body = json!({
+            "studyInstanceUid": "1.2.392.200036.9116.2.6.1.48.1000",
+            "seriesInstanceUid": "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "sopInstanceUid": "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/wado/tokens")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let issued = router.clone().oneshot(request).await.unwrap();
+        let issued_status = issued.status();
+        let issued_body: Value = serde_json::from_slice(&body_bytes(issued).await).unwrap();
+        let url = issued_body["url"].as_str().unwrap();
+        let response = get(router, url, None).await;
+
+        // Assert
+        // 署名付きURLの発行の確認
+        assert_eq!(issued_status, StatusCode::CREATED);
+        assert!(url.starts_with(&format!(
+            "/wado?{INSTANCE_QUERY}&contentType=application%2Fdicom&token="
+        )));
+        assert!(url.ends_with(issued_body["token"].as_str().unwrap()));
+        // 署名付きURLでの取得の確認
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "application/dicom");
+    }
+
+    #[tokio::test]
+    async fn 存在しないSOPインスタンスの署名付きURLは発行できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let body = json!({
+            "studyInstanceUid": "1.2.392.200036.9116.2.6.1.48.1000",
+            "seriesInstanceUid": "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "sopInstanceUid": "1.2.392.200036.9116.2.6.1.48.1000.1.9",
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/wado/tokens")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn ログインしておらず署名付きURLでもない場合は401エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let response = get(router, &format!("/wado?{INSTANCE_QUERY}"), None).await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn 期限切れや別のSOPインスタンスの署名付きURLでは取得できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let issued_by = Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap();
+        let expired_token = repos
+            .wado_uri_token_repository
+            .sign(&WadoUriToken::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000",
+                "1.2.392.200036.9116.2.6.1.48.1000.1",
+                "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+                issued_by,
+                Utc::now() - Duration::seconds(1),
+            ));
+        let other_instance_token = repos.wado_uri_token_repository.sign(&WadoUriToken::create(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.1",
+            "1.2.392.200036.9116.2.6.1.48.1000.1.2",
+            issued_by,
+        ));
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let expired = get(
+            router.clone(),
+            &format!("/wado?{INSTANCE_QUERY}&token={expired_token}"),
+            None,
+        )
+        .await;
+        let other_instance = get(
+            router.clone(),
+            &format!("/wado?{INSTANCE_QUERY}&token={other_instance_token}"),
+            None,
+        )
+        .await;
+        let malformed = get(router, &format!("/wado?{INSTANCE_QUERY}&token=abc"), None).await;
+
+        // Assert
+        assert_eq!(expired.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(other_instance.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(malformed.status(), StatusCode::UNAUTHORIZED);
+    }
+}
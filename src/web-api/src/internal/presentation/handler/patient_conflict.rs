@@ -0,0 +1,125 @@
+pub mod list_patient_conflicts;
+pub mod merge_patient_conflict;
+pub mod split_patient_conflict;
+
+pub use self::{
+    list_patient_conflicts::list_patient_conflicts, merge_patient_conflict::merge_patient_conflict,
+    split_patient_conflict::split_patient_conflict,
+};
+
+#[cfg(test)]
+pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
+    use crate::{
+        internal::{
+            domain::{
+                entity::{PatientConflict, PatientDemographics, User},
+                repository::UserRepository,
+                value_object::{Id, PatientConflictStatus, Role, UserName},
+            },
+            infrastructure::repository::{
+                TestDicomFileRepository, TestPatientConflictRepository, TestUserRepository,
+            },
+        },
+        startup,
+    };
+    use chrono::{DateTime, NaiveDate};
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    let user_repository = Arc::new(TestUserRepository::new());
+    user_repository.add(&User::construct(
+        Uuid::parse_str("4922356e-d6a0-7083-8e18-93b7a023c328").unwrap(),
+        Id::new("it").unwrap(),
+        UserName::new("情シス 太郎").unwrap(),
+        Role::ItStaff,
+        "$argon2id$v=19$m=19456,t=2,p=1$20Tk1g6xZ9BdBDcrKqWy1A$//ZKdw5sFbvtSwtbgnBapb3u1r112qUBz6QVG3JuzzU",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap(),
+        Id::new("technician").unwrap(),
+        UserName::new("技師 太郎").unwrap(),
+        Role::Technician,
+        "$argon2id$v=19$m=19456,t=2,p=1$HLCrMDHifn55j/Kq5M6t0g$lIVsN8r8+osWzQmU6n5khyRZk8TNeB9/qn4NeULwVfI",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+    )).await.unwrap();
+
+    let patient_conflict_repository = Arc::new(TestPatientConflictRepository::new());
+    patient_conflict_repository
+        .add(&PatientConflict::construct(
+            Uuid::parse_str("019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b").unwrap(),
+            "P000001",
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1",
+            PatientDemographics::construct(
+                "Yamada^Taro",
+                "山田^太郎",
+                "やまだ^たろう",
+                Some(NaiveDate::from_ymd_opt(1980, 4, 1).unwrap()),
+                1,
+            ),
+            PatientDemographics::construct(
+                "Yamada^Hanako",
+                "山田^花子",
+                "やまだ^はなこ",
+                Some(NaiveDate::from_ymd_opt(1985, 10, 10).unwrap()),
+                2,
+            ),
+            PatientConflictStatus::Unresolved,
+            None,
+            None,
+            DateTime::from_str("2026-02-01T09:00:01.123+09:00").unwrap(),
+        ))
+        .await;
+    patient_conflict_repository
+        .add(&PatientConflict::construct(
+            Uuid::parse_str("019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2c").unwrap(),
+            "P000002",
+            "1.2.392.200036.9116.2.6.1.48.2000",
+            "1.2.392.200036.9116.2.6.1.48.2000.1.1",
+            PatientDemographics::construct(
+                "Suzuki^Ichiro",
+                "鈴木^一郎",
+                "すずき^いちろう",
+                Some(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()),
+                1,
+            ),
+            PatientDemographics::construct("Suzuki^Ichiro", "", "", None, 1),
+            PatientConflictStatus::Merged,
+            Some(Uuid::parse_str("4922356e-d6a0-7083-8e18-93b7a023c328").unwrap()),
+            Some(DateTime::from_str("2026-02-02T10:00:00.000+09:00").unwrap()),
+            DateTime::from_str("2026-02-01T10:30:00.789+09:00").unwrap(),
+        ))
+        .await;
+    patient_conflict_repository.add_patient_id("P000003").await;
+    patient_conflict_repository
+        .add_file_path(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "/var/lib/oceanus/dicom/success/1.dcm",
+        )
+        .await;
+    patient_conflict_repository
+        .add_file_path(
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "/var/lib/oceanus/dicom/success/2.dcm",
+        )
+        .await;
+
+    let dicom_file_repository = Arc::new(TestDicomFileRepository::new());
+    dicom_file_repository
+        .add("/var/lib/oceanus/dicom/success/1.dcm", "P000001")
+        .await;
+
+    let mut repos = startup::Repos::new_for_test();
+    repos.user_repository = user_repository;
+    repos.patient_conflict_repository = patient_conflict_repository;
+    repos.dicom_file_repository = dicom_file_repository;
+
+    repos
+}
@@ -0,0 +1,224 @@
+mod query_params;
+mod response_body;
+
+pub use self::{
+    query_params::ListPatientConflictsQueryParams,
+    response_body::{
+        ListPatientConflictsResponseBodyDemographics, ListPatientConflictsResponseBodyItem,
+    },
+};
+
+use crate::{
+    internal::{
+        application::patient_conflict::ListPatientConflictsCommand,
+        domain::value_object::PatientConflictStatus,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+
+#[utoipa::path(
+    get,
+    path = "/patient-conflicts",
+    params(ListPatientConflictsQueryParams),
+    responses(
+        (status = 200, description = "患者属性の不一致一覧の取得に成功", body = Vec<ListPatientConflictsResponseBodyItem>),
+        (status = 400, description = "クエリパラメータの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "権限がない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "patient-conflicts"
+)]
+pub async fn list_patient_conflicts(
+    State(state): State<AppState>,
+    Query(query_params): Query<ListPatientConflictsQueryParams>,
+) -> Result<Json<Vec<ListPatientConflictsResponseBodyItem>>, PresentationError> {
+    // バリデーション
+    let status = query_params
+        .status
+        .map(PatientConflictStatus::from_i16)
+        .transpose()
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なステータス: {e}")))?;
+
+    let command = ListPatientConflictsCommand { status };
+    let response_body = state
+        .list_patient_conflicts_use_case
+        .execute(command)
+        .await
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(ListPatientConflictsResponseBodyItem::from)
+                .collect()
+        })
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn 情シスは患者属性の不一致一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/patient-conflicts")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認（作成日時の降順）
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let conflicts = body.as_array().unwrap();
+        assert_eq!(conflicts.len(), 2);
+
+        let conflict = &conflicts[0];
+        assert_eq!(conflict["uuid"], "019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2c");
+        assert_eq!(conflict["status"], 1);
+        assert_eq!(
+            conflict["resolvedBy"],
+            "4922356e-d6a0-7083-8e18-93b7a023c328"
+        );
+        assert_eq!(conflict["received"]["birthDate"], Value::Null);
+
+        let conflict = &conflicts[1];
+        assert_eq!(conflict["uuid"], "019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b");
+        assert_eq!(conflict["patientId"], "P000001");
+        assert_eq!(
+            conflict["studyInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000"
+        );
+        assert_eq!(
+            conflict["sopInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000.1.1"
+        );
+        assert_eq!(conflict["registered"]["nameAlphabet"], "Yamada^Taro");
+        assert_eq!(conflict["registered"]["birthDate"], "1980-04-01");
+        assert_eq!(conflict["registered"]["sex"], 1);
+        assert_eq!(conflict["received"]["nameAlphabet"], "Yamada^Hanako");
+        assert_eq!(conflict["received"]["nameKanji"], "山田^花子");
+        assert_eq!(conflict["received"]["nameHiragana"], "やまだ^はなこ");
+        assert_eq!(conflict["received"]["birthDate"], "1985-10-10");
+        assert_eq!(conflict["received"]["sex"], 2);
+        assert_eq!(conflict["status"], 0);
+        assert_eq!(conflict["resolvedBy"], Value::Null);
+        assert_eq!(conflict["resolvedAt"], Value::Null);
+    }
+
+    #[tokio::test]
+    async fn ステータスで絞り込みができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/patient-conflicts?status=0")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let conflicts = body.as_array().unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0]["uuid"], "019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b");
+    }
+
+    #[tokio::test]
+    async fn 管理者や情シスでないユーザーが一覧を取得しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/patient-conflicts")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn 不正なステータスを指定すると422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/patient-conflicts?status=3")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
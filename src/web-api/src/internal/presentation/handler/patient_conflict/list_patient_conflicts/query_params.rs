@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct ListPatientConflictsQueryParams {
+    /// ステータスで絞り込む (0: 未解決, 1: 統合済み, 2: 分割済み)
+    pub status: Option<i16>,
+}
@@ -0,0 +1,62 @@
+use crate::internal::domain::entity::{PatientConflict, PatientDemographics};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPatientConflictsResponseBodyItem {
+    pub uuid: String,
+    pub patient_id: String,
+    pub study_instance_uid: String,
+    pub sop_instance_uid: String,
+    /// 登録済みの患者属性
+    pub registered: ListPatientConflictsResponseBodyDemographics,
+    /// 受信したSOPインスタンスの患者属性
+    pub received: ListPatientConflictsResponseBodyDemographics,
+    /// 0: 未解決, 1: 統合済み, 2: 分割済み
+    pub status: i16,
+    pub resolved_by: Option<String>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPatientConflictsResponseBodyDemographics {
+    pub name_alphabet: String,
+    pub name_kanji: String,
+    pub name_hiragana: String,
+    pub birth_date: Option<NaiveDate>,
+    /// 0: 不明, 1: 男性, 2: 女性, 9: 適用不能
+    pub sex: i16,
+}
+
+impl From<PatientConflict> for ListPatientConflictsResponseBodyItem {
+    fn from(entity: PatientConflict) -> Self {
+        Self {
+            uuid: entity.uuid().to_string(),
+            patient_id: entity.patient_id().to_string(),
+            study_instance_uid: entity.study_instance_uid().to_string(),
+            sop_instance_uid: entity.sop_instance_uid().to_string(),
+            registered: entity.registered().into(),
+            received: entity.received().into(),
+            status: entity.status().as_i16(),
+            resolved_by: entity.resolved_by().map(|u| u.to_string()),
+            resolved_at: entity.resolved_at().copied(),
+            created_at: *entity.created_at(),
+        }
+    }
+}
+
+impl From<&PatientDemographics> for ListPatientConflictsResponseBodyDemographics {
+    fn from(demographics: &PatientDemographics) -> Self {
+        Self {
+            name_alphabet: demographics.name_alphabet().to_string(),
+            name_kanji: demographics.name_kanji().to_string(),
+            name_hiragana: demographics.name_hiragana().to_string(),
+            birth_date: demographics.birth_date().copied(),
+            sex: demographics.sex(),
+        }
+    }
+}
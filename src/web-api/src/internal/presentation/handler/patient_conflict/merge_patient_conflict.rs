@@ -0,0 +1,350 @@
+mod request_body;
+mod response_body;
+
+pub use self::{
+    request_body::MergePatientConflictRequestBody, response_body::MergePatientConflictResponseBody,
+};
+
+use crate::{
+    internal::{
+        application::patient_conflict::{
+            ReconcileMethod, ReconcilePatientConflictCommand, ReconcilePatientConflictError,
+        },
+        domain::value_object::PatientId,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/patient-conflicts/{uuid}/merge",
+    request_body = MergePatientConflictRequestBody,
+    params(
+        ("uuid" = String, Path, description = "患者属性の不一致のUUID")
+    ),
+    responses(
+        (status = 200, description = "患者の統合に成功", body = MergePatientConflictResponseBody),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "対象の不一致または統合先の患者が見つからない", body = ErrorResponseBody),
+        (status = 409, description = "対象の不一致が既に解決済み", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "patient-conflicts"
+)]
+pub async fn merge_patient_conflict(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(uuid): Path<String>,
+    Json(request_body): Json<MergePatientConflictRequestBody>,
+) -> Result<Json<MergePatientConflictResponseBody>, PresentationError> {
+    // バリデーション
+    let uuid = Uuid::parse_str(&uuid)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なUUID: {e}")))?;
+    let target_patient_id = request_body
+        .target_patient_id
+        .map(PatientId::new)
+        .transpose()
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効な患者ID: {e}")))?;
+
+    // 統合処理
+    let command = ReconcilePatientConflictCommand {
+        uuid,
+        method: ReconcileMethod::Merge { target_patient_id },
+        rewrite_files: request_body.rewrite_files,
+        performed_by: user.uuid(),
+        performed_at: Utc::now(),
+    };
+    let entity = state
+        .reconcile_patient_conflict_use_case
+        .execute(command)
+        .await
+        .map_err(|e| match e {
+            ReconcilePatientConflictError::AlreadyResolved => {
+                PresentationError::Conflict(e.to_string())
+            }
+            ReconcilePatientConflictError::Repository(repo_err) => {
+                PresentationError::from(repo_err)
+            }
+        })?;
+
+    let response_body = MergePatientConflictResponseBody::from(entity);
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use futures::future::join_all;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn 情シスは検査を既存の患者に統合できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "targetPatientId": "P000003",
+            "rewriteFiles": true,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/merge")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body["patientConflictUuid"],
+            "019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b"
+        );
+        assert_eq!(body["action"], 1);
+        assert_eq!(
+            body["studyInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000"
+        );
+        assert_eq!(body["oldPatientId"], "P000001");
+        assert_eq!(body["newPatientId"], "P000003");
+        assert_eq!(body["rewriteFiles"], true);
+        // 書き換えに失敗したファイルは記録される
+        assert_eq!(body["rewrittenFileCount"], 1);
+        assert_eq!(
+            body["failedFilePaths"],
+            json!(["/var/lib/oceanus/dicom/success/2.dcm"])
+        );
+        assert_eq!(body["performedBy"], "4922356e-d6a0-7083-8e18-93b7a023c328");
+
+        // リポジトリの確認
+        let stored = repos
+            .patient_conflict_repository
+            .find_by_uuid(&Uuid::parse_str("019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status().as_i16(), 1);
+        assert_eq!(
+            stored.resolved_by().unwrap().to_string(),
+            "4922356e-d6a0-7083-8e18-93b7a023c328"
+        );
+    }
+
+    #[tokio::test]
+    async fn 統合先を省略すると不一致が記録された患者に統合されファイルは書き換えない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "rewriteFiles": true,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/merge")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認（患者IDが変わらないため書き換えは行われない）
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["oldPatientId"], "P000001");
+        assert_eq!(body["newPatientId"], "P000001");
+        assert_eq!(body["rewrittenFileCount"], 0);
+        assert_eq!(body["failedFilePaths"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn 存在しない患者に統合しようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "targetPatientId": "P999999",
+            "rewriteFiles": false,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/merge")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // 不一致が未解決のままであることの確認
+        let stored = repos
+            .patient_conflict_repository
+            .find_by_uuid(&Uuid::parse_str("019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status().as_i16(), 0);
+    }
+
+    #[tokio::test]
+    async fn 解決済みの不一致を統合しようとすると409エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "rewriteFiles": false,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2c/merge")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn 管理者や情シスでないユーザーが統合しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let body = json!({
+            "rewriteFiles": false,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/merge")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn バリデーション違反の場合に422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let cases = [
+            (
+                // UUIDが不正
+                "/patient-conflicts/invalid-uuid/merge",
+                json!({ "rewriteFiles": false }),
+            ),
+            (
+                // 患者IDが長すぎる(17文字)
+                "/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/merge",
+                json!({ "targetPatientId": "12345678901234567", "rewriteFiles": false }),
+            ),
+            (
+                // 患者IDにバックスラッシュが含まれる
+                "/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/merge",
+                json!({ "targetPatientId": "P00\\0001", "rewriteFiles": false }),
+            ),
+        ];
+        let requests = cases.iter().map(|(uri, body)| {
+            Request::builder()
+                .method("POST")
+                .uri(*uri)
+                .header("content-type", "application/json")
+                .header("cookie", format!("session_id={session_id}"))
+                .header("x-csrf-token", &csrf_token)
+                .body(Body::from(serde_json::to_string(&body).unwrap()))
+                .unwrap()
+        });
+
+        // Act
+        let responses = join_all(requests.map(|req| router.clone().oneshot(req))).await;
+
+        // Assert
+        responses.into_iter().for_each(|res| {
+            // ステータスコードの確認
+            assert_eq!(res.unwrap().status(), StatusCode::UNPROCESSABLE_ENTITY);
+        });
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MergePatientConflictRequestBody {
+    /// 統合先の患者ID（省略した場合は不一致が記録された患者に統合する）
+    pub target_patient_id: Option<String>,
+    /// 保存済みファイルのPatient IDを書き換えるかどうか
+    pub rewrite_files: bool,
+}
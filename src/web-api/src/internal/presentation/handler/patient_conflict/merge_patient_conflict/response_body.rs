@@ -0,0 +1,39 @@
+use crate::internal::domain::entity::PatientReconciliation;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MergePatientConflictResponseBody {
+    pub uuid: String,
+    pub patient_conflict_uuid: String,
+    /// 1: 統合, 2: 分割
+    pub action: i16,
+    pub study_instance_uid: String,
+    pub old_patient_id: String,
+    pub new_patient_id: String,
+    pub rewrite_files: bool,
+    pub rewritten_file_count: i32,
+    pub failed_file_paths: Vec<String>,
+    pub performed_by: String,
+    pub performed_at: DateTime<Utc>,
+}
+
+impl From<PatientReconciliation> for MergePatientConflictResponseBody {
+    fn from(entity: PatientReconciliation) -> Self {
+        Self {
+            uuid: entity.uuid().to_string(),
+            patient_conflict_uuid: entity.patient_conflict_uuid().to_string(),
+            action: entity.action().as_i16(),
+            study_instance_uid: entity.study_instance_uid().to_string(),
+            old_patient_id: entity.old_patient_id().to_string(),
+            new_patient_id: entity.new_patient_id().value().to_string(),
+            rewrite_files: entity.rewrite_files(),
+            rewritten_file_count: entity.rewritten_file_count(),
+            failed_file_paths: entity.failed_file_paths().to_vec(),
+            performed_by: entity.performed_by().to_string(),
+            performed_at: *entity.performed_at(),
+        }
+    }
+}
@@ -0,0 +1,261 @@
+mod request_body;
+mod response_body;
+
+pub use self::{
+    request_body::SplitPatientConflictRequestBody, response_body::SplitPatientConflictResponseBody,
+};
+
+use crate::{
+    internal::{
+        application::patient_conflict::{
+            ReconcileMethod, ReconcilePatientConflictCommand, ReconcilePatientConflictError,
+        },
+        domain::value_object::PatientId,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/patient-conflicts/{uuid}/split",
+    request_body = SplitPatientConflictRequestBody,
+    params(
+        ("uuid" = String, Path, description = "患者属性の不一致のUUID")
+    ),
+    responses(
+        (status = 200, description = "患者の分割に成功", body = SplitPatientConflictResponseBody),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "対象の不一致が見つからない", body = ErrorResponseBody),
+        (status = 409, description = "対象の不一致が既に解決済み、または患者IDが既に存在する", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "patient-conflicts"
+)]
+pub async fn split_patient_conflict(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(uuid): Path<String>,
+    Json(request_body): Json<SplitPatientConflictRequestBody>,
+) -> Result<Json<SplitPatientConflictResponseBody>, PresentationError> {
+    // バリデーション
+    let uuid = Uuid::parse_str(&uuid)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なUUID: {e}")))?;
+    let new_patient_id = PatientId::new(request_body.new_patient_id)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効な患者ID: {e}")))?;
+
+    // 分割処理
+    let command = ReconcilePatientConflictCommand {
+        uuid,
+        method: ReconcileMethod::Split { new_patient_id },
+        rewrite_files: request_body.rewrite_files,
+        performed_by: user.uuid(),
+        performed_at: Utc::now(),
+    };
+    let entity = state
+        .reconcile_patient_conflict_use_case
+        .execute(command)
+        .await
+        .map_err(|e| match e {
+            ReconcilePatientConflictError::AlreadyResolved => {
+                PresentationError::Conflict(e.to_string())
+            }
+            ReconcilePatientConflictError::Repository(repo_err) => {
+                PresentationError::from(repo_err)
+            }
+        })?;
+
+    let response_body = SplitPatientConflictResponseBody::from(entity);
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use futures::future::join_all;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn 情シスは検査を新しい患者に分割できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "newPatientId": "P000004",
+            "rewriteFiles": false,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/split")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認（ファイルの書き換えを指定していないため書き換えは行われない）
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["action"], 2);
+        assert_eq!(body["oldPatientId"], "P000001");
+        assert_eq!(body["newPatientId"], "P000004");
+        assert_eq!(body["rewriteFiles"], false);
+        assert_eq!(body["rewrittenFileCount"], 0);
+        assert_eq!(body["failedFilePaths"], json!([]));
+
+        // リポジトリの確認
+        let stored = repos
+            .patient_conflict_repository
+            .find_by_uuid(&Uuid::parse_str("019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status().as_i16(), 2);
+    }
+
+    #[tokio::test]
+    async fn 既に存在する患者IDに分割しようとすると409エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "newPatientId": "P000003",
+            "rewriteFiles": true,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/split")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        // 不一致が未解決のままであることの確認
+        let stored = repos
+            .patient_conflict_repository
+            .find_by_uuid(&Uuid::parse_str("019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status().as_i16(), 0);
+    }
+
+    #[tokio::test]
+    async fn 存在しない不一致を分割しようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let body = json!({
+            "newPatientId": "P000004",
+            "rewriteFiles": false,
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2d/split")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn リクエストボディのバリデーション違反の場合に422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let bodies = [
+            json!({ // 患者IDが空文字
+                "newPatientId": "",
+                "rewriteFiles": false,
+            }),
+            json!({ // 患者IDの前後に空白が含まれる
+                "newPatientId": " P000004",
+                "rewriteFiles": false,
+            }),
+            json!({ // 患者IDにASCII以外の文字が含まれる
+                "newPatientId": "患者0004",
+                "rewriteFiles": false,
+            }),
+        ];
+        let requests = bodies.iter().map(|body| {
+            Request::builder()
+                .method("POST")
+                .uri("/patient-conflicts/019c1a2b-3c4d-7e5f-8a6b-7c8d9e0f1a2b/split")
+                .header("content-type", "application/json")
+                .header("cookie", format!("session_id={session_id}"))
+                .header("x-csrf-token", &csrf_token)
+                .body(Body::from(serde_json::to_string(&body).unwrap()))
+                .unwrap()
+        });
+
+        // Act
+        let responses = join_all(requests.map(|req| router.clone().oneshot(req))).await;
+
+        // Assert
+        responses.into_iter().for_each(|res| {
+            // ステータスコードの確認
+            assert_eq!(res.unwrap().status(), StatusCode::UNPROCESSABLE_ENTITY);
+        });
+    }
+}
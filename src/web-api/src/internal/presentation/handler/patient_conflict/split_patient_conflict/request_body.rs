@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitPatientConflictRequestBody {
+    /// 新しく登録する患者の患者ID
+    pub new_patient_id: String,
+    /// 保存済みファイルのPatient IDを書き換えるかどうか
+    pub rewrite_files: bool,
+}
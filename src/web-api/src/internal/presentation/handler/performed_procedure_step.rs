@@ -0,0 +1,88 @@
+pub mod list_performed_procedure_steps;
+
+pub use self::list_performed_procedure_steps::list_performed_procedure_steps;
+
+#[cfg(test)]
+pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
+    use crate::{
+        internal::{
+            domain::{
+                entity::{PerformedProcedureStep, PerformedSeries, User},
+                repository::UserRepository,
+                value_object::{Id, ProcedureStepStatus, Role, UserName},
+            },
+            infrastructure::repository::{
+                TestPerformedProcedureStepRepository, TestUserRepository,
+            },
+        },
+        startup,
+    };
+    use chrono::{DateTime, NaiveDate, NaiveTime};
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    let user_repository = Arc::new(TestUserRepository::new());
+    user_repository.add(&User::construct(
+        Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap(),
+        Id::new("technician").unwrap(),
+        UserName::new("技師 太郎").unwrap(),
+        Role::Technician,
+        "$argon2id$v=19$m=19456,t=2,p=1$HLCrMDHifn55j/Kq5M6t0g$lIVsN8r8+osWzQmU6n5khyRZk8TNeB9/qn4NeULwVfI",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+    )).await.unwrap();
+    let performed_procedure_step_repository = Arc::new(TestPerformedProcedureStepRepository::new());
+    performed_procedure_step_repository
+        .add(&PerformedProcedureStep::construct(
+            "1.2.392.200036.9116.2.6.1.48.1",
+            "1.2.392.200036.9116.2.6.1.48.1000",
+            "P000001",
+            "A0001",
+            "PPS0001",
+            ProcedureStepStatus::Completed,
+            "CT",
+            "胸部単純CT",
+            "CT01",
+            Some(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
+            Some(NaiveTime::from_hms_opt(9, 0, 0).unwrap()),
+            Some(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
+            Some(NaiveTime::from_hms_opt(9, 15, 30).unwrap()),
+            vec![PerformedSeries::construct(
+                "1.2.392.200036.9116.2.6.1.48.1000.1",
+                "Chest 5mm",
+                "OCEANUS",
+                120,
+            )],
+            DateTime::from_str("2026-02-01T09:00:01.123+09:00").unwrap(),
+            DateTime::from_str("2026-02-01T09:15:31.456+09:00").unwrap(),
+        ))
+        .await;
+    performed_procedure_step_repository
+        .add(&PerformedProcedureStep::construct(
+            "1.2.392.200036.9116.2.6.1.48.2",
+            "1.2.392.200036.9116.2.6.1.48.2000",
+            "P000002",
+            "A0002",
+            "PPS0002",
+            ProcedureStepStatus::InProgress,
+            "MR",
+            "頭部MRI",
+            "MR01",
+            Some(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap()),
+            Some(NaiveTime::from_hms_opt(10, 30, 0).unwrap()),
+            None,
+            None,
+            vec![],
+            DateTime::from_str("2026-02-01T10:30:00.789+09:00").unwrap(),
+            DateTime::from_str("2026-02-01T10:30:00.789+09:00").unwrap(),
+        ))
+        .await;
+
+    let mut repos = startup::Repos::new_for_test();
+    repos.user_repository = user_repository;
+    repos.performed_procedure_step_repository = performed_procedure_step_repository;
+
+    repos
+}
@@ -0,0 +1,222 @@
+mod query_params;
+mod response_body;
+
+pub use self::{
+    query_params::ListPerformedProcedureStepsQueryParams,
+    response_body::{
+        ListPerformedProcedureStepsResponseBodyItem,
+        ListPerformedProcedureStepsResponseBodyPerformedSeries,
+    },
+};
+
+use crate::{
+    internal::{
+        application::performed_procedure_step::ListPerformedProcedureStepsCommand,
+        domain::value_object::ProcedureStepStatus,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+
+#[utoipa::path(
+    get,
+    path = "/performed-procedure-steps",
+    params(ListPerformedProcedureStepsQueryParams),
+    responses(
+        (status = 200, description = "Performed Procedure Step一覧の取得に成功", body = Vec<ListPerformedProcedureStepsResponseBodyItem>),
+        (status = 400, description = "クエリパラメータの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "performed-procedure-steps"
+)]
+pub async fn list_performed_procedure_steps(
+    State(state): State<AppState>,
+    Query(query_params): Query<ListPerformedProcedureStepsQueryParams>,
+) -> Result<Json<Vec<ListPerformedProcedureStepsResponseBodyItem>>, PresentationError> {
+    // バリデーション
+    let status = query_params
+        .status
+        .map(ProcedureStepStatus::from_i16)
+        .transpose()
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なステータス: {e}")))?;
+
+    let command = ListPerformedProcedureStepsCommand {
+        study_instance_uid: query_params.study_instance_uid,
+        status,
+    };
+    let response_body = state
+        .list_performed_procedure_steps_use_case
+        .execute(command)
+        .await
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(ListPerformedProcedureStepsResponseBodyItem::from)
+                .collect()
+        })
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn ログインユーザーはPerformed_Procedure_Step一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/performed-procedure-steps")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認（作成日時の降順）
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let steps = body.as_array().unwrap();
+        assert_eq!(steps.len(), 2);
+
+        let step = &steps[0];
+        assert_eq!(step["instanceUid"], "1.2.392.200036.9116.2.6.1.48.2");
+        assert_eq!(step["status"], 0);
+        assert_eq!(step["endDate"], Value::Null);
+        assert_eq!(step["performedSeries"].as_array().unwrap().len(), 0);
+
+        let step = &steps[1];
+        assert_eq!(step["instanceUid"], "1.2.392.200036.9116.2.6.1.48.1");
+        assert_eq!(
+            step["studyInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000"
+        );
+        assert_eq!(step["patientId"], "P000001");
+        assert_eq!(step["accessionNumber"], "A0001");
+        assert_eq!(step["status"], 1);
+        assert_eq!(step["modality"], "CT");
+        assert_eq!(step["startDate"], "2026-02-01");
+        assert_eq!(step["startTime"], "09:00:00");
+        assert_eq!(step["endDate"], "2026-02-01");
+        assert_eq!(step["endTime"], "09:15:30");
+        let performed_series = step["performedSeries"].as_array().unwrap();
+        assert_eq!(performed_series.len(), 1);
+        assert_eq!(
+            performed_series[0]["seriesInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.1000.1"
+        );
+        assert_eq!(performed_series[0]["numberOfInstances"], 120);
+    }
+
+    #[tokio::test]
+    async fn 検査インスタンスUIDとステータスで絞り込みができる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/performed-procedure-steps?studyInstanceUid=1.2.392.200036.9116.2.6.1.48.1000&status=1")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let steps = body.as_array().unwrap();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0]["instanceUid"], "1.2.392.200036.9116.2.6.1.48.1");
+    }
+
+    #[tokio::test]
+    async fn 不正なステータスを指定すると422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/performed-procedure-steps?status=3")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn ログインしていない場合は401エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/performed-procedure-steps")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
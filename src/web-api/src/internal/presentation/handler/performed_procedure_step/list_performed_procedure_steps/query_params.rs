@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+#[into_params(parameter_in = Query)]
+pub struct ListPerformedProcedureStepsQueryParams {
+    /// 検査インスタンスUIDで絞り込む
+    pub study_instance_uid: Option<String>,
+    /// ステータスで絞り込む (0: IN PROGRESS, 1: COMPLETED, 2: DISCONTINUED)
+    pub status: Option<i16>,
+}
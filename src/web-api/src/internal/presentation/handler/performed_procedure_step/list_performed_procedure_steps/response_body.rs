@@ -0,0 +1,73 @@
+use crate::internal::domain::entity::{PerformedProcedureStep, PerformedSeries};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPerformedProcedureStepsResponseBodyItem {
+    pub instance_uid: String,
+    pub study_instance_uid: String,
+    pub patient_id: String,
+    pub accession_number: String,
+    pub id: String,
+    /// 0: IN PROGRESS, 1: COMPLETED, 2: DISCONTINUED
+    pub status: i16,
+    pub modality: String,
+    pub description: String,
+    pub station_ae_title: String,
+    pub start_date: Option<NaiveDate>,
+    pub start_time: Option<NaiveTime>,
+    pub end_date: Option<NaiveDate>,
+    pub end_time: Option<NaiveTime>,
+    pub performed_series: Vec<ListPerformedProcedureStepsResponseBodyPerformedSeries>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListPerformedProcedureStepsResponseBodyPerformedSeries {
+    pub series_instance_uid: String,
+    pub description: String,
+    pub retrieve_ae_title: String,
+    pub number_of_instances: i32,
+}
+
+impl From<PerformedProcedureStep> for ListPerformedProcedureStepsResponseBodyItem {
+    fn from(entity: PerformedProcedureStep) -> Self {
+        Self {
+            instance_uid: entity.instance_uid().to_string(),
+            study_instance_uid: entity.study_instance_uid().to_string(),
+            patient_id: entity.patient_id().to_string(),
+            accession_number: entity.accession_number().to_string(),
+            id: entity.id().to_string(),
+            status: entity.status().as_i16(),
+            modality: entity.modality().to_string(),
+            description: entity.description().to_string(),
+            station_ae_title: entity.station_ae_title().to_string(),
+            start_date: entity.start_date().copied(),
+            start_time: entity.start_time().copied(),
+            end_date: entity.end_date().copied(),
+            end_time: entity.end_time().copied(),
+            performed_series: entity
+                .performed_series()
+                .iter()
+                .map(ListPerformedProcedureStepsResponseBodyPerformedSeries::from)
+                .collect(),
+            created_at: *entity.created_at(),
+            updated_at: *entity.updated_at(),
+        }
+    }
+}
+
+impl From<&PerformedSeries> for ListPerformedProcedureStepsResponseBodyPerformedSeries {
+    fn from(series: &PerformedSeries) -> Self {
+        Self {
+            series_instance_uid: series.series_instance_uid().to_string(),
+            description: series.description().to_string(),
+            retrieve_ae_title: series.retrieve_ae_title().to_string(),
+            number_of_instances: series.number_of_instances(),
+        }
+    }
+}
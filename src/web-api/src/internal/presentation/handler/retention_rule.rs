@@ -0,0 +1,155 @@
+pub mod create_retention_rule;
+pub mod delete_retention_rule;
+pub mod list_retention_candidates;
+pub mod list_retention_rules;
+pub mod update_retention_rule;
+
+pub use self::{
+    create_retention_rule::create_retention_rule, delete_retention_rule::delete_retention_rule,
+    list_retention_candidates::list_retention_candidates,
+    list_retention_rules::list_retention_rules, update_retention_rule::update_retention_rule,
+};
+
+use crate::internal::{domain::entity::RetentionLimit, presentation::error::PresentationError};
+use dicom_lib::core::value::value_representations::ae::AeValue;
+
+/// 規則の名前を検証する。
+fn validate_name(name: &str) -> Result<String, PresentationError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(PresentationError::UnprocessableContent(
+            "規則の名前を指定してください".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// 宛先AEタイトルを検証する。空文字列の場合はすべての検査を対象とする（`None`）。
+fn validate_called_ae_title(
+    called_ae_title: Option<&str>,
+) -> Result<Option<AeValue>, PresentationError> {
+    called_ae_title
+        .filter(|title| !title.trim().is_empty())
+        .map(AeValue::from_string)
+        .transpose()
+        .map_err(|e| PresentationError::UnprocessableContent(format!("AEタイトルが不正です: {e}")))
+}
+
+/// 上限を検証する。保存する日数と合計サイズの上限のどちらか一方のみを指定する必要がある。
+fn validate_limit(
+    max_age_days: Option<i32>,
+    max_total_size: Option<i64>,
+) -> Result<RetentionLimit, PresentationError> {
+    match (max_age_days, max_total_size) {
+        (Some(days), None) if days >= 1 => Ok(RetentionLimit::MaxAgeDays(days)),
+        (Some(days), None) => Err(PresentationError::UnprocessableContent(format!(
+            "無効な保存日数: {days}（1以上を指定してください）"
+        ))),
+        (None, Some(size)) if size >= 1 => Ok(RetentionLimit::MaxTotalSize(size)),
+        (None, Some(size)) => Err(PresentationError::UnprocessableContent(format!(
+            "無効な合計サイズの上限: {size}（1以上を指定してください）"
+        ))),
+        _ => Err(PresentationError::UnprocessableContent(
+            "保存日数と合計サイズの上限のどちらか一方のみを指定してください".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
+    use crate::{
+        internal::{
+            domain::{
+                entity::{RetentionCandidate, RetentionRule, User},
+                repository::{RetentionRuleRepository, UserRepository},
+                value_object::{Id, Role, UserName},
+            },
+            infrastructure::repository::{TestRetentionRuleRepository, TestUserRepository},
+        },
+        startup,
+    };
+    use chrono::{DateTime, NaiveDate};
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    let user_repository = Arc::new(TestUserRepository::new());
+    user_repository.add(&User::construct(
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        Id::new("admin").unwrap(),
+        UserName::new("管理者 太郎").unwrap(),
+        Role::Admin,
+        "$argon2id$v=19$m=19456,t=2,p=1$Zf/xy2I09QAEAvKnXga60w$arwk9jM50i/6RAjgZ2+N6fiRq0WWJFX3GmngTw+n34Y",
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        Uuid::parse_str("4922356e-d6a0-7083-8e18-93b7a023c328").unwrap(),
+        Id::new("it").unwrap(),
+        UserName::new("情シス 太郎").unwrap(),
+        Role::ItStaff,
+        "$argon2id$v=19$m=19456,t=2,p=1$20Tk1g6xZ9BdBDcrKqWy1A$//ZKdw5sFbvtSwtbgnBapb3u1r112qUBz6QVG3JuzzU",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap(),
+        Id::new("technician").unwrap(),
+        UserName::new("技師 太郎").unwrap(),
+        Role::Technician,
+        "$argon2id$v=19$m=19456,t=2,p=1$HLCrMDHifn55j/Kq5M6t0g$lIVsN8r8+osWzQmU6n5khyRZk8TNeB9/qn4NeULwVfI",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:26:54.695+09:00").unwrap(),
+    )).await.unwrap();
+
+    let retention_rule_repository = Arc::new(TestRetentionRuleRepository::new());
+    retention_rule_repository
+        .add(&RetentionRule::construct(
+            Uuid::parse_str("019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f").unwrap(),
+            "研究用は90日",
+            Some(AeValue::from_string("RESEARCH").unwrap()),
+            RetentionLimit::MaxAgeDays(90),
+            Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+            DateTime::from_str("2026-02-10T10:00:00.000+09:00").unwrap(),
+            Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+            DateTime::from_str("2026-02-10T10:00:00.000+09:00").unwrap(),
+        ))
+        .await
+        .unwrap();
+    retention_rule_repository
+        .add(&RetentionRule::construct(
+            Uuid::parse_str("019c4a2f-0c21-7d4e-8f90-1a2b3c4d5e6f").unwrap(),
+            "全体で2TiB",
+            None,
+            RetentionLimit::MaxTotalSize(2_199_023_255_552),
+            Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+            DateTime::from_str("2026-02-10T10:05:00.000+09:00").unwrap(),
+            Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+            DateTime::from_str("2026-02-10T10:05:00.000+09:00").unwrap(),
+        ))
+        .await
+        .unwrap();
+    retention_rule_repository
+        .add_candidate(RetentionCandidate::construct(
+            Uuid::parse_str("019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f").unwrap(),
+            "研究用は90日",
+            "1.2.392.200036.9116.2.6.1.48.3000",
+            Some("R000001".to_string()),
+            Some(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap()),
+            DateTime::from_str("2026-01-05T11:00:00.000+09:00").unwrap(),
+            3_145_728,
+            6,
+        ))
+        .await;
+
+    let mut repos = startup::Repos::new_for_test();
+    repos.user_repository = user_repository;
+    repos.retention_rule_repository = retention_rule_repository;
+
+    repos
+}
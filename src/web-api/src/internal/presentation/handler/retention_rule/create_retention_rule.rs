@@ -0,0 +1,273 @@
+mod request_body;
+mod response_body;
+
+pub use self::{
+    request_body::CreateRetentionRuleRequestBody, response_body::CreateRetentionRuleResponseBody,
+};
+
+use super::{validate_called_ae_title, validate_limit, validate_name};
+use crate::{
+    internal::{
+        application::retention_rule::create_retention_rule_use_case::CreateRetentionRuleCommand,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{Extension, Json, extract::State};
+use chrono::Utc;
+
+#[utoipa::path(
+    post,
+    path = "/retention-rules",
+    request_body = CreateRetentionRuleRequestBody,
+    responses(
+        (status = 200, description = "保存期間の規則の作成に成功", body = CreateRetentionRuleResponseBody),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーション失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "retention-rules"
+)]
+pub async fn create_retention_rule(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Json(request_body): Json<CreateRetentionRuleRequestBody>,
+) -> Result<Json<CreateRetentionRuleResponseBody>, PresentationError> {
+    // バリデーション
+    let name = validate_name(&request_body.name)?;
+    let called_ae_title = validate_called_ae_title(request_body.called_ae_title.as_deref())?;
+    let limit = validate_limit(request_body.max_age_days, request_body.max_total_size)?;
+
+    // 登録処理
+    let command = CreateRetentionRuleCommand {
+        name,
+        called_ae_title,
+        limit,
+        created_by: user.uuid(),
+        created_at: Utc::now(),
+    };
+    let entity = state
+        .create_retention_rule_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(CreateRetentionRuleResponseBody::from(entity)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    async fn post(router: &Router, user_id: &str, body: Value) -> (StatusCode, Value) {
+        let (session_id, csrf_token) = test_helpers::login(router, user_id, "Password#1234").await;
+        let request = Request::builder()
+            .method("POST")
+            .uri("/retention-rules")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn 管理者は保存期間の規則を作成できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, body) = post(
+            &router,
+            "admin",
+            json!({
+                "name": "検診は5年",
+                "calledAeTitle": "KENSHIN",
+                "maxAgeDays": 1826,
+            }),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::OK);
+
+        // レスポンスボディの確認
+        assert_eq!(body["name"], "検診は5年");
+        assert_eq!(body["calledAeTitle"], "KENSHIN");
+        assert_eq!(body["maxAgeDays"], 1826);
+        assert_eq!(body["maxTotalSize"], Value::Null);
+
+        // リポジトリ内に正しく保存されていることの確認
+        let uuid = Uuid::parse_str(body["uuid"].as_str().unwrap()).unwrap();
+        let stored = repos
+            .retention_rule_repository
+            .find_by_uuid(&uuid)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.name(), "検診は5年");
+        assert_eq!(
+            stored.created_by(),
+            &Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn 宛先AEタイトルを省略するとすべての検査が対象の規則になる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, body) = post(
+            &router,
+            "it",
+            json!({
+                "name": "全体で1TiB",
+                "maxTotalSize": 1_099_511_627_776_i64,
+            }),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["calledAeTitle"], Value::Null);
+        assert_eq!(body["maxAgeDays"], Value::Null);
+        assert_eq!(body["maxTotalSize"], 1_099_511_627_776_i64);
+    }
+
+    #[tokio::test]
+    async fn 上限を両方指定すると422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = post(
+            &router,
+            "admin",
+            json!({
+                "name": "両方",
+                "maxAgeDays": 90,
+                "maxTotalSize": 1024,
+            }),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn 上限を指定しないと422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = post(&router, "admin", json!({ "name": "上限なし" })).await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn 保存日数が0の場合に422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = post(&router, "admin", json!({ "name": "即時", "maxAgeDays": 0 })).await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn 名前が空の場合に422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = post(&router, "admin", json!({ "name": " ", "maxAgeDays": 90 })).await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn 不正なAEタイトルの場合に422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        // AEタイトルは16文字以内である必要がある。17文字のタイトルを指定する。
+        let (status, _) = post(
+            &router,
+            "admin",
+            json!({ "name": "長い", "calledAeTitle": "A".repeat(17), "maxAgeDays": 90 }),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn 技師は保存期間の規則を作成できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = post(
+            &router,
+            "technician",
+            json!({ "name": "技師", "maxAgeDays": 90 }),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+}
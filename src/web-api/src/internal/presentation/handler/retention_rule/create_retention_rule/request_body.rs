@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRetentionRuleRequestBody {
+    pub name: String,
+    /// 対象の宛先AEタイトル（省略した場合はすべての検査が対象）
+    pub called_ae_title: Option<String>,
+    /// 最後にSOPインスタンスを受信してから保存する日数
+    pub max_age_days: Option<i32>,
+    /// 対象の検査の合計サイズの上限（バイト）
+    pub max_total_size: Option<i64>,
+}
@@ -0,0 +1,141 @@
+use crate::{
+    internal::{
+        application::retention_rule::delete_retention_rule_use_case::DeleteRetentionRuleCommand,
+        presentation::error::{ErrorResponseBody, PresentationError},
+    },
+    startup::AppState,
+};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/retention-rules/{uuid}",
+    params(
+        ("uuid" = String, Path, description = "保存期間の規則のUUID")
+    ),
+    responses(
+        (status = 204, description = "保存期間の規則の削除に成功"),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "保存期間の規則が見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーション失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "retention-rules"
+)]
+pub async fn delete_retention_rule(
+    State(state): State<AppState>,
+    Path(uuid): Path<String>,
+) -> Result<StatusCode, PresentationError> {
+    // バリデーション
+    let uuid = Uuid::parse_str(&uuid)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なUUID: {e}")))?;
+
+    // 削除処理
+    state
+        .delete_retention_rule_use_case
+        .execute(DeleteRetentionRuleCommand { uuid })
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    async fn delete(router: &Router, user_id: &str, uri: &str) -> StatusCode {
+        let (session_id, csrf_token) = test_helpers::login(router, user_id, "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri(uri)
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+        router.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn 管理者は保存期間の規則を削除できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let status = delete(
+            &router,
+            "admin",
+            "/retention-rules/019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f",
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        // リポジトリから削除されていることの確認
+        let stored = repos
+            .retention_rule_repository
+            .find_by_uuid(&Uuid::parse_str("019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f").unwrap())
+            .await
+            .unwrap();
+        assert!(stored.is_none());
+    }
+
+    #[tokio::test]
+    async fn 存在しない保存期間の規則を削除しようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let status = delete(
+            &router,
+            "admin",
+            "/retention-rules/019c4a30-0000-7000-8000-000000000000",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 技師は保存期間の規則を削除できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let status = delete(
+            &router,
+            "technician",
+            "/retention-rules/019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+}
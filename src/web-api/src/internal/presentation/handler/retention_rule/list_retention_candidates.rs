@@ -0,0 +1,123 @@
+mod response_body;
+
+pub use self::response_body::ListRetentionCandidatesResponseBodyItem;
+
+use crate::{
+    internal::presentation::error::{ErrorResponseBody, PresentationError},
+    startup::AppState,
+};
+use axum::{Json, extract::State};
+use chrono::Utc;
+
+/// 保存期間の規則を評価し、削除の対象となる検査を削除せずに返す（dry-run）。
+#[utoipa::path(
+    get,
+    path = "/retention-rules/candidates",
+    responses(
+        (status = 200, description = "削除の対象となる検査の取得に成功", body = Vec<ListRetentionCandidatesResponseBodyItem>),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "権限がない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "retention-rules"
+)]
+pub async fn list_retention_candidates(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ListRetentionCandidatesResponseBodyItem>>, PresentationError> {
+    let response_body = state
+        .list_retention_candidates_use_case
+        .execute(&Utc::now())
+        .await
+        .map(|candidates| {
+            candidates
+                .into_iter()
+                .map(ListRetentionCandidatesResponseBodyItem::from)
+                .collect()
+        })
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn 管理者は削除の対象となる検査を確認できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, _) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/retention-rules/candidates")
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let candidates = body.as_array().unwrap();
+        assert_eq!(candidates.len(), 1);
+
+        let candidate = &candidates[0];
+        assert_eq!(
+            candidate["ruleUuid"],
+            "019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f"
+        );
+        assert_eq!(candidate["ruleName"], "研究用は90日");
+        assert_eq!(
+            candidate["studyInstanceUid"],
+            "1.2.392.200036.9116.2.6.1.48.3000"
+        );
+        assert_eq!(candidate["patientId"], "R000001");
+        assert_eq!(candidate["studyDate"], "2026-01-05");
+        assert_eq!(candidate["size"], 3_145_728);
+        assert_eq!(candidate["numberOfInstances"], 6);
+    }
+
+    #[tokio::test]
+    async fn 技師は削除の対象となる検査を確認できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/retention-rules/candidates")
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
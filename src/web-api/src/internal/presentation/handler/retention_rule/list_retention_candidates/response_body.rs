@@ -0,0 +1,35 @@
+use crate::internal::domain::entity::RetentionCandidate;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRetentionCandidatesResponseBodyItem {
+    /// 検査が対象となった規則のUUID
+    pub rule_uuid: String,
+    pub rule_name: String,
+    pub study_instance_uid: String,
+    pub patient_id: Option<String>,
+    pub study_date: Option<NaiveDate>,
+    /// 最後にSOPインスタンスを受信した日時
+    pub last_received_at: DateTime<Utc>,
+    /// SOPインスタンスの合計サイズ（バイト）
+    pub size: i64,
+    pub number_of_instances: i64,
+}
+
+impl From<RetentionCandidate> for ListRetentionCandidatesResponseBodyItem {
+    fn from(entity: RetentionCandidate) -> Self {
+        Self {
+            rule_uuid: entity.rule_uuid().to_string(),
+            rule_name: entity.rule_name().to_string(),
+            study_instance_uid: entity.study_instance_uid().to_string(),
+            patient_id: entity.patient_id().map(str::to_string),
+            study_date: entity.study_date(),
+            last_received_at: *entity.last_received_at(),
+            size: entity.size(),
+            number_of_instances: entity.number_of_instances(),
+        }
+    }
+}
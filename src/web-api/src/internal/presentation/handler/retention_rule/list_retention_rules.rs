@@ -0,0 +1,117 @@
+mod response_body;
+
+pub use self::response_body::ListRetentionRulesResponseBodyItem;
+
+use crate::{
+    internal::presentation::error::{ErrorResponseBody, PresentationError},
+    startup::AppState,
+};
+use axum::{Json, extract::State};
+
+#[utoipa::path(
+    get,
+    path = "/retention-rules",
+    responses(
+        (status = 200, description = "保存期間の規則の一覧の取得に成功", body = Vec<ListRetentionRulesResponseBodyItem>),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "権限がない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "retention-rules"
+)]
+pub async fn list_retention_rules(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ListRetentionRulesResponseBodyItem>>, PresentationError> {
+    let response_body = state
+        .list_retention_rules_use_case
+        .execute()
+        .await
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(ListRetentionRulesResponseBodyItem::from)
+                .collect()
+        })
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn 管理者は保存期間の規則の一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, _) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/retention-rules")
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let rules = body.as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+
+        assert_eq!(rules[0]["uuid"], "019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f");
+        assert_eq!(rules[0]["name"], "研究用は90日");
+        assert_eq!(rules[0]["calledAeTitle"], "RESEARCH");
+        assert_eq!(rules[0]["maxAgeDays"], 90);
+        assert_eq!(rules[0]["maxTotalSize"], Value::Null);
+
+        assert_eq!(rules[1]["name"], "全体で2TiB");
+        assert_eq!(rules[1]["calledAeTitle"], Value::Null);
+        assert_eq!(rules[1]["maxAgeDays"], Value::Null);
+        assert_eq!(rules[1]["maxTotalSize"], 2_199_023_255_552_i64);
+    }
+
+    #[tokio::test]
+    async fn 技師は保存期間の規則の一覧を取得できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/retention-rules")
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::internal::domain::entity::{RetentionLimit, RetentionRule};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRetentionRulesResponseBodyItem {
+    pub uuid: String,
+    pub name: String,
+    pub called_ae_title: Option<String>,
+    pub max_age_days: Option<i32>,
+    pub max_total_size: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<RetentionRule> for ListRetentionRulesResponseBodyItem {
+    fn from(entity: RetentionRule) -> Self {
+        let (max_age_days, max_total_size) = match entity.limit() {
+            RetentionLimit::MaxAgeDays(days) => (Some(days), None),
+            RetentionLimit::MaxTotalSize(size) => (None, Some(size)),
+        };
+        Self {
+            uuid: entity.uuid().to_string(),
+            name: entity.name().to_string(),
+            called_ae_title: entity
+                .called_ae_title()
+                .map(|title| title.value().to_string()),
+            max_age_days,
+            max_total_size,
+            created_at: *entity.created_at(),
+            updated_at: *entity.updated_at(),
+        }
+    }
+}
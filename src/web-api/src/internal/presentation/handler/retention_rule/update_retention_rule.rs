@@ -0,0 +1,219 @@
+mod request_body;
+mod response_body;
+
+pub use self::{
+    request_body::UpdateRetentionRuleRequestBody, response_body::UpdateRetentionRuleResponseBody,
+};
+
+use super::{validate_called_ae_title, validate_limit, validate_name};
+use crate::{
+    internal::{
+        application::retention_rule::update_retention_rule_use_case::UpdateRetentionRuleCommand,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+#[utoipa::path(
+    put,
+    path = "/retention-rules/{uuid}",
+    request_body = UpdateRetentionRuleRequestBody,
+    params(
+        ("uuid" = String, Path, description = "保存期間の規則のUUID")
+    ),
+    responses(
+        (status = 200, description = "保存期間の規則の更新に成功", body = UpdateRetentionRuleResponseBody),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "保存期間の規則が見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーション失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "retention-rules"
+)]
+pub async fn update_retention_rule(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(uuid): Path<String>,
+    Json(request_body): Json<UpdateRetentionRuleRequestBody>,
+) -> Result<Json<UpdateRetentionRuleResponseBody>, PresentationError> {
+    // バリデーション
+    let uuid = Uuid::parse_str(&uuid)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なUUID: {e}")))?;
+    let name = validate_name(&request_body.name)?;
+    let called_ae_title = validate_called_ae_title(request_body.called_ae_title.as_deref())?;
+    let limit = validate_limit(request_body.max_age_days, request_body.max_total_size)?;
+
+    // 更新処理
+    let command = UpdateRetentionRuleCommand {
+        uuid,
+        name,
+        called_ae_title,
+        limit,
+        updated_by: user.uuid(),
+        updated_at: Utc::now(),
+    };
+    let entity = state
+        .update_retention_rule_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(UpdateRetentionRuleResponseBody::from(entity)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{
+        internal::{domain::entity::RetentionLimit, presentation::util::test_helpers},
+        startup,
+    };
+    use axum::{
+        Router,
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    async fn put(router: &Router, user_id: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let (session_id, csrf_token) = test_helpers::login(router, user_id, "Password#1234").await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn 管理者は保存期間の規則を更新できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, body) = put(
+            &router,
+            "admin",
+            "/retention-rules/019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f",
+            json!({
+                "name": "研究用は180日",
+                "calledAeTitle": "RESEARCH",
+                "maxAgeDays": 180,
+            }),
+        )
+        .await;
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(status, StatusCode::OK);
+
+        // レスポンスボディの確認
+        assert_eq!(body["uuid"], "019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f");
+        assert_eq!(body["name"], "研究用は180日");
+        assert_eq!(body["maxAgeDays"], 180);
+
+        // リポジトリ内に正しく保存されていることの確認
+        let stored = repos
+            .retention_rule_repository
+            .find_by_uuid(&Uuid::parse_str("019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.name(), "研究用は180日");
+        assert_eq!(stored.limit(), RetentionLimit::MaxAgeDays(180));
+        assert_eq!(
+            stored.updated_by(),
+            &Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn 存在しない保存期間の規則を更新しようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = put(
+            &router,
+            "admin",
+            "/retention-rules/019c4a30-0000-7000-8000-000000000000",
+            json!({ "name": "なし", "maxAgeDays": 90 }),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn 不正なUUIDの場合に422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = put(
+            &router,
+            "admin",
+            "/retention-rules/invalid",
+            json!({ "name": "なし", "maxAgeDays": 90 }),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn 技師は保存期間の規則を更新できない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // Act
+        let (status, _) = put(
+            &router,
+            "technician",
+            "/retention-rules/019c4a2e-5b10-7c3d-9e8f-0a1b2c3d4e5f",
+            json!({ "name": "技師", "maxAgeDays": 1 }),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,64 @@
+pub mod list_role_permissions;
+pub mod update_role_permissions;
+
+pub use self::{
+    list_role_permissions::list_role_permissions, update_role_permissions::update_role_permissions,
+};
+
+#[cfg(test)]
+pub(crate) async fn prepare_test_data() -> crate::startup::Repos {
+    use crate::{
+        internal::{
+            domain::{
+                entity::User,
+                repository::UserRepository,
+                value_object::{Id, Role, UserName},
+            },
+            infrastructure::repository::TestUserRepository,
+        },
+        startup,
+    };
+    use chrono::DateTime;
+    use std::{str::FromStr, sync::Arc};
+    use uuid::Uuid;
+
+    let user_repository = Arc::new(TestUserRepository::new());
+    user_repository.add(&User::construct(
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        Id::new("admin").unwrap(),
+        UserName::new("管理者 太郎").unwrap(),
+        Role::Admin,
+        "$argon2id$v=19$m=19456,t=2,p=1$Zf/xy2I09QAEAvKnXga60w$arwk9jM50i/6RAjgZ2+N6fiRq0WWJFX3GmngTw+n34Y",
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+        Uuid::parse_str("00000000-0000-7000-8000-000000000000").unwrap(),
+        DateTime::from_str("2026-01-20T23:10:24.332+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        Uuid::parse_str("4922356e-d6a0-7083-8e18-93b7a023c328").unwrap(),
+        Id::new("it").unwrap(),
+        UserName::new("情シス 太郎").unwrap(),
+        Role::ItStaff,
+        "$argon2id$v=19$m=19456,t=2,p=1$20Tk1g6xZ9BdBDcrKqWy1A$//ZKdw5sFbvtSwtbgnBapb3u1r112qUBz6QVG3JuzzU",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:34.436+09:00").unwrap(),
+    )).await.unwrap();
+    user_repository.add(&User::construct(
+        Uuid::parse_str("492236d4-2f18-76ab-a82f-84e29fcf92f8").unwrap(),
+        Id::new("doctor").unwrap(),
+        UserName::new("医師 太郎").unwrap(),
+        Role::Doctor,
+        "$argon2id$v=19$m=19456,t=2,p=1$1E/vEPPwrHBsW1fLuzdUVQ$1sAIm/nnFMIyc1IBuKW8+6KcdyHtdzjHCv7ae8lG6sA",
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:57.855+09:00").unwrap(),
+        Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+        DateTime::from_str("2026-01-24T22:25:57.855+09:00").unwrap(),
+    )).await.unwrap();
+
+    let mut repos = startup::Repos::new_for_test();
+    repos.user_repository = user_repository;
+
+    repos
+}
@@ -0,0 +1,113 @@
+mod response_body;
+
+pub use response_body::ListRolePermissionsResponseBodyItem;
+
+use crate::{
+    internal::presentation::error::{ErrorResponseBody, PresentationError},
+    startup::AppState,
+};
+use axum::{Json, extract::State};
+
+#[utoipa::path(
+    get,
+    path = "/role-permissions",
+    responses(
+        (status = 200, description = "ロールごとの権限の一覧の取得に成功", body = Vec<ListRolePermissionsResponseBodyItem>),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "権限がない", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "role-permissions"
+)]
+pub async fn list_role_permissions(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ListRolePermissionsResponseBodyItem>>, PresentationError> {
+    let response_body = state
+        .list_role_permissions_use_case
+        .execute()
+        .await
+        .map(|entities| {
+            entities
+                .into_iter()
+                .map(ListRolePermissionsResponseBodyItem::from)
+                .collect()
+        })
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(response_body))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn 管理者はロールごとの権限の一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, _) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/role-permissions")
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            body,
+            json!([
+                { "role": 0, "permissions": [0, 1, 2, 3, 4, 5, 6, 7] },
+                { "role": 1, "permissions": [0, 1, 2, 3, 4, 5, 6, 7] },
+                { "role": 2, "permissions": [0, 1, 2] },
+                { "role": 3, "permissions": [0, 1, 2] },
+                { "role": 4, "permissions": [0] },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn ユーザーの管理の権限がない場合は403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, _) = test_helpers::login(&router, "doctor", "Password#1234").await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/role-permissions")
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,18 @@
+use crate::internal::domain::entity::RolePermissions;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+pub struct ListRolePermissionsResponseBodyItem {
+    pub role: i16,
+    pub permissions: Vec<i16>,
+}
+
+impl From<RolePermissions> for ListRolePermissionsResponseBodyItem {
+    fn from(entity: RolePermissions) -> Self {
+        Self {
+            role: entity.role().as_i16(),
+            permissions: entity.permissions().iter().map(|p| p.as_i16()).collect(),
+        }
+    }
+}
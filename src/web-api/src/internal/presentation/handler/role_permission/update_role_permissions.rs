@@ -0,0 +1,358 @@
+mod request_body;
+mod response_body;
+
+pub use self::{
+    request_body::UpdateRolePermissionsRequestBody,
+    response_body::UpdateRolePermissionsResponseBody,
+};
+
+use crate::{
+    internal::{
+        application::role_permission::update_role_permissions_use_case::{
+            UpdateRolePermissionsCommand, UpdateRolePermissionsError,
+        },
+        domain::{
+            entity::RolePermissions,
+            value_object::{Permission, Role},
+        },
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use chrono::Utc;
+
+#[utoipa::path(
+    put,
+    path = "/role-permissions/{role}",
+    request_body = UpdateRolePermissionsRequestBody,
+    params(
+        ("role" = i16, Path, description = "ロール")
+    ),
+    responses(
+        (status = 200, description = "ロールの権限の更新に成功", body = UpdateRolePermissionsResponseBody),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効、権限がない、管理者の権限を変更しようとした、またはユーザーの管理の権限を管理者と情シス以外に付与しようとした", body = ErrorResponseBody),
+        (status = 422, description = "バリデーション失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "role-permissions"
+)]
+pub async fn update_role_permissions(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(role): Path<String>,
+    Json(request_body): Json<UpdateRolePermissionsRequestBody>,
+) -> Result<Json<UpdateRolePermissionsResponseBody>, PresentationError> {
+    // バリデーション
+    let role = role
+        .parse::<i16>()
+        .map_err(|e| e.to_string())
+        .and_then(Role::from_i16)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なロール: {e}")))?;
+    let permissions = request_body
+        .permissions
+        .into_iter()
+        .map(Permission::from_i16)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効な権限: {e}")))?;
+
+    // 更新処理
+    let command = UpdateRolePermissionsCommand {
+        role_permissions: RolePermissions::construct(role, permissions),
+        updated_by: user.uuid(),
+        updated_at: Utc::now(),
+    };
+    let role_permissions = state
+        .update_role_permissions_use_case
+        .execute(command)
+        .await
+        .map_err(|e| match e {
+            UpdateRolePermissionsError::Repository(repo_err) => PresentationError::from(repo_err),
+            UpdateRolePermissionsError::Forbidden
+            | UpdateRolePermissionsError::PrivilegedPermission => {
+                PresentationError::Forbidden(e.to_string())
+            }
+        })?;
+
+    Ok(Json(UpdateRolePermissionsResponseBody::from(
+        role_permissions,
+    )))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{
+        internal::{
+            domain::value_object::{Permission, Role},
+            presentation::util::test_helpers,
+        },
+        startup,
+    };
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use futures::future::join_all;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn 管理者はロールの権限を更新できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let body = json!({ "permissions": [6, 0, 1, 0] }); // 順不同・重複あり
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/role-permissions/2")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body, json!({ "role": 2, "permissions": [0, 1, 6] }));
+
+        // リポジトリに反映されていることの確認
+        let role_permissions = repos
+            .role_permission_repository
+            .find_by_role(Role::Doctor)
+            .await
+            .unwrap();
+        assert_eq!(
+            role_permissions.permissions(),
+            [
+                Permission::ViewStudies,
+                Permission::DownloadStudies,
+                Permission::ExportDeidentifiedData
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn 権限を変更すると以降のリクエストに反映される() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let (doctor_session_id, _) = test_helpers::login(&router, "doctor", "Password#1234").await;
+
+        // 医師から検査の閲覧の権限を取り消す
+        let body = json!({ "permissions": [1, 2] });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/role-permissions/2")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/archive/studies")
+            .header("cookie", format!("session_id={doctor_session_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn 管理者の権限を変更しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let body = json!({ "permissions": [] });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/role-permissions/0")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // 管理者の権限が変更されていないことの確認
+        let role_permissions = repos
+            .role_permission_repository
+            .find_by_role(Role::Admin)
+            .await
+            .unwrap();
+        assert_eq!(role_permissions.permissions(), Permission::ALL);
+    }
+
+    #[tokio::test]
+    async fn ユーザーの管理の権限がない場合は403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "doctor", "Password#1234").await;
+        let body = json!({ "permissions": [0, 1, 2, 3, 4, 5, 6, 7] });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/role-permissions/2")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn バリデーション違反の場合に422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let requests = [
+            ("/role-permissions/5", json!({ "permissions": [] })), // 不正なロール
+            ("/role-permissions/doctor", json!({ "permissions": [] })), // 数値でないロール
+            ("/role-permissions/2", json!({ "permissions": [8] })), // 不正な権限
+        ]
+        .into_iter()
+        .map(|(uri, body)| {
+            Request::builder()
+                .method("PUT")
+                .uri(uri)
+                .header("content-type", "application/json")
+                .header("cookie", format!("session_id={session_id}"))
+                .header("x-csrf-token", &csrf_token)
+                .body(Body::from(serde_json::to_string(&body).unwrap()))
+                .unwrap()
+        });
+
+        // Act
+        let responses = join_all(requests.map(|request| router.clone().oneshot(request))).await;
+
+        // Assert
+        for response in responses {
+            assert_eq!(response.unwrap().status(), StatusCode::UNPROCESSABLE_ENTITY);
+        }
+    }
+
+    #[tokio::test]
+    async fn ユーザーの管理の権限を管理者と情シス以外のロールに付与しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let requests = [2, 3, 4].map(|role| {
+            Request::builder()
+                .method("PUT")
+                .uri(format!("/role-permissions/{role}"))
+                .header("content-type", "application/json")
+                .header("cookie", format!("session_id={session_id}"))
+                .header("x-csrf-token", &csrf_token)
+                .body(Body::from(json!({ "permissions": [0, 5] }).to_string()))
+                .unwrap()
+        });
+
+        // Act
+        let responses = join_all(requests.map(|req| router.clone().oneshot(req))).await;
+
+        // Assert
+        responses.into_iter().for_each(|res| {
+            assert_eq!(res.unwrap().status(), StatusCode::FORBIDDEN);
+        });
+
+        // 権限が変更されていないことの確認
+        for role in [Role::Doctor, Role::Technician, Role::Clerk] {
+            let role_permissions = repos
+                .role_permission_repository
+                .find_by_role(role)
+                .await
+                .unwrap();
+            assert!(!role_permissions.allows(Permission::ManageUsers));
+        }
+    }
+
+    #[tokio::test]
+    async fn 情シスのロールにはユーザーの管理の権限を付与できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/role-permissions/1")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(json!({ "permissions": [0, 5] }).to_string()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let role_permissions = repos
+            .role_permission_repository
+            .find_by_role(Role::ItStaff)
+            .await
+            .unwrap();
+        assert_eq!(
+            role_permissions.permissions(),
+            [Permission::ViewStudies, Permission::ManageUsers]
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct UpdateRolePermissionsRequestBody {
+    /// ロールに許可する操作の権限（空の場合はすべての権限を取り消す）
+    pub permissions: Vec<i16>,
+}
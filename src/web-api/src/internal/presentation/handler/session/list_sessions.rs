@@ -0,0 +1,126 @@
+mod response_body;
+
+pub use response_body::ListSessionsResponseBodyItem;
+
+use crate::{
+    internal::presentation::{
+        error::{ErrorResponseBody, PresentationError},
+        middleware::AuthenticatedUser,
+        util::CookieHelper,
+    },
+    startup::AppState,
+};
+use axum::{Extension, Json, extract::State};
+use tower_cookies::Cookies;
+
+#[utoipa::path(
+    get,
+    path = "/sessions",
+    responses(
+        (status = 200, description = "ログイン中のユーザーのセッションの一覧の取得に成功", body = Vec<ListSessionsResponseBodyItem>),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = [])
+    ),
+    tag = "sessions"
+)]
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    cookies: Cookies,
+) -> Result<Json<Vec<ListSessionsResponseBodyItem>>, PresentationError> {
+    // 認証ミドルウェアを通過しているのでセッションIDは存在する
+    let session_id = cookies
+        .get(CookieHelper::SESSION_COOKIE_NAME)
+        .map(|c| c.value().to_string())
+        .unwrap_or_default();
+
+    let sessions = state
+        .list_sessions_use_case
+        .execute(&user.uuid())
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(Json(
+        sessions
+            .iter()
+            .map(|session| ListSessionsResponseBodyItem::new(session, &session_id))
+            .collect(),
+    ))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{internal::presentation::util::test_helpers, startup};
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn ログイン中のユーザーのセッションの一覧を取得できる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // 2つの端末からログインする
+        let (_, _) = test_helpers::login(&router, "doctor", "Password#1234").await;
+        let (session_id, _) = test_helpers::login(&router, "doctor", "Password#1234").await;
+        // 他のユーザーのセッションは含まれない
+        let (_, _) = test_helpers::login(&router, "technician", "Password#1234").await;
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/sessions")
+            .header("cookie", format!("session_id={session_id}"))
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        let sessions = body.as_array().unwrap();
+        assert_eq!(sessions.len(), 2);
+        // 最後にアクセスしたセッション（リクエストに使用したセッション）が先頭
+        assert_eq!(sessions[0]["current"], true);
+        assert_eq!(sessions[1]["current"], false);
+        assert!(!sessions[0]["uuid"].as_str().unwrap().is_empty());
+        assert!(sessions[0]["createdAt"].is_string());
+        assert!(sessions[0]["lastAccessedAt"].is_string());
+        assert!(sessions[0]["expiresAt"].is_string());
+    }
+
+    #[tokio::test]
+    async fn ログインしていない場合は401エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/sessions")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::internal::domain::entity::Session;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSessionsResponseBodyItem {
+    pub uuid: String,
+    pub user_agent: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_accessed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// リクエストに使用しているセッションかどうか
+    pub current: bool,
+}
+
+impl ListSessionsResponseBodyItem {
+    pub fn new(session: &Session, current_session_id: &str) -> Self {
+        Self {
+            uuid: session.uuid().to_string(),
+            user_agent: session.user_agent().map(|v| v.to_string()),
+            created_at: *session.created_at(),
+            last_accessed_at: *session.last_accessed_at(),
+            expires_at: *session.expires_at(),
+            current: session.matches(current_session_id),
+        }
+    }
+}
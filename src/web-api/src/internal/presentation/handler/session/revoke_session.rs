@@ -0,0 +1,181 @@
+use crate::{
+    internal::{
+        application::session::revoke_session_use_case::RevokeSessionCommand,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/sessions/{uuid}",
+    params(
+        ("uuid" = String, Path, description = "セッションのUUID")
+    ),
+    responses(
+        (status = 204, description = "セッションの失効に成功"),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効", body = ErrorResponseBody),
+        (status = 404, description = "対象のセッションが見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "sessions"
+)]
+pub async fn revoke_session(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(uuid): Path<String>,
+) -> Result<StatusCode, PresentationError> {
+    // バリデーション
+    let uuid = Uuid::parse_str(&uuid)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なUUID: {e}")))?;
+
+    // 失効処理（自身のセッションのみ）
+    let command = RevokeSessionCommand {
+        user_uuid: user.uuid(),
+        uuid,
+    };
+    state
+        .revoke_session_use_case
+        .execute(command)
+        .await
+        .map_err(PresentationError::from)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{
+        internal::{domain::entity::Session, presentation::util::test_helpers},
+        startup,
+    };
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn 自身の他の端末のセッションを失効させられる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // 他の端末のセッション
+        let user_uuid = Uuid::parse_str("492236d4-2f18-76ab-a82f-84e29fcf92f8").unwrap();
+        let (other_session, other_session_id) = Session::create(user_uuid, None);
+        let other_session_uuid = *other_session.uuid();
+        repos.session_repository.save(other_session).await.unwrap();
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "doctor", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri(format!("/sessions/{other_session_uuid}"))
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        // 他の端末のセッションだけが削除されていることの確認
+        let other_session = repos
+            .session_repository
+            .find_by_session_id(&other_session_id)
+            .await
+            .unwrap();
+        assert!(other_session.is_none());
+        let session = repos
+            .session_repository
+            .find_by_session_id(&session_id)
+            .await
+            .unwrap();
+        assert!(session.is_some());
+    }
+
+    #[tokio::test]
+    async fn 他のユーザーのセッションを失効させようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        // 技師のセッション
+        let technician_uuid = Uuid::parse_str("49223a37-7e58-717c-b222-754550659249").unwrap();
+        let (other_session, other_session_id) = Session::create(technician_uuid, None);
+        let other_session_uuid = *other_session.uuid();
+        repos.session_repository.save(other_session).await.unwrap();
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "doctor", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri(format!("/sessions/{other_session_uuid}"))
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // 技師のセッションが削除されていないことの確認
+        let other_session = repos
+            .session_repository
+            .find_by_session_id(&other_session_id)
+            .await
+            .unwrap();
+        assert!(other_session.is_some());
+    }
+
+    #[tokio::test]
+    async fn パスパラメータのUUIDが不正な場合は422エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "doctor", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri("/sessions/invalid")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -1,12 +1,15 @@
 pub mod create_user;
 pub mod delete_user;
+pub mod link_user_identity;
 pub mod list_users;
 pub mod reset_login_failure_count;
+pub mod revoke_user_sessions;
 pub mod update_user;
 
 pub use self::{
-    create_user::create_user, delete_user::delete_user, list_users::list_users,
-    reset_login_failure_count::reset_login_failure_count, update_user::update_user,
+    create_user::create_user, delete_user::delete_user, link_user_identity::link_user_identity,
+    list_users::list_users, reset_login_failure_count::reset_login_failure_count,
+    revoke_user_sessions::revoke_user_sessions, update_user::update_user,
 };
 
 #[cfg(test)]
@@ -81,7 +81,10 @@ mod tests {
     use super::super::prepare_test_data;
     use crate::{
         internal::{
-            domain::value_object::{Id, Role},
+            domain::{
+                entity::RolePermissions,
+                value_object::{Id, Permission, Role},
+            },
             presentation::util::test_helpers,
         },
         startup,
@@ -431,4 +434,54 @@ mod tests {
             assert_eq!(res.unwrap().status(), StatusCode::UNPROCESSABLE_ENTITY);
         });
     }
+
+    #[tokio::test]
+    async fn ユーザーの管理の権限を持つ医師が管理者を作成しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        // 以前の設定などにより、医師のロールにユーザーの管理の権限が付与されている場合
+        repos
+            .role_permission_repository
+            .replace(
+                &RolePermissions::construct(
+                    Role::Doctor,
+                    vec![Permission::ViewStudies, Permission::ManageUsers],
+                ),
+                &Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+                &Utc::now(),
+            )
+            .await
+            .unwrap();
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "doctor", "Password#1234").await;
+        let body = json!({
+            "id": "john",
+            "name": "John Doe",
+            "role": 0, // Admin
+            "password": "Password#1234",
+        });
+        let request = Request::builder()
+            .method("POST")
+            .uri("/users")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let user = repos
+            .user_repository
+            .find_by_id(&Id::new("john").unwrap())
+            .await
+            .unwrap();
+        assert!(user.is_none());
+    }
 }
@@ -72,14 +72,22 @@ pub async fn delete_user(
 mod tests {
     use super::super::prepare_test_data;
     use crate::{
-        internal::{domain::value_object::Id, presentation::util::test_helpers},
+        internal::{
+            domain::{
+                entity::RolePermissions,
+                value_object::{Id, Permission, Role},
+            },
+            presentation::util::test_helpers,
+        },
         startup,
     };
     use axum::{
         body::Body,
         http::{Request, StatusCode},
     };
+    use chrono::Utc;
     use tower::ServiceExt;
+    use uuid::Uuid;
 
     #[tokio::test]
     async fn 管理者は他のユーザーを削除できる() {
@@ -88,6 +96,7 @@ mod tests {
         let state = startup::make_state(&repos);
         let router = startup::make_router(state, &repos);
 
+        let (doctor_session_id, _) = test_helpers::login(&router, "doctor", "Password#1234").await;
         let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
         let request = Request::builder()
             .method("DELETE")
@@ -111,6 +120,14 @@ mod tests {
             .await
             .unwrap();
         assert!(user.is_none());
+
+        // 削除したユーザーのセッションが削除されていることの確認
+        let session = repos
+            .session_repository
+            .find_by_session_id(&doctor_session_id)
+            .await
+            .unwrap();
+        assert!(session.is_none());
     }
 
     #[tokio::test]
@@ -289,4 +306,47 @@ mod tests {
             .unwrap();
         assert!(user.is_some());
     }
+
+    #[tokio::test]
+    async fn ユーザーの管理の権限を持つ医師が管理者を削除しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        // 以前の設定などにより、医師のロールにユーザーの管理の権限が付与されている場合
+        repos
+            .role_permission_repository
+            .replace(
+                &RolePermissions::construct(
+                    Role::Doctor,
+                    vec![Permission::ViewStudies, Permission::ManageUsers],
+                ),
+                &Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+                &Utc::now(),
+            )
+            .await
+            .unwrap();
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "doctor", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri("/users/admin")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let user = repos
+            .user_repository
+            .find_by_id(&Id::new("admin").unwrap())
+            .await
+            .unwrap();
+        assert!(user.is_some());
+    }
 }
@@ -0,0 +1,267 @@
+mod request_body;
+mod response_body;
+
+pub use self::{
+    request_body::LinkUserIdentityRequestBody, response_body::LinkUserIdentityResponseBody,
+};
+
+use crate::{
+    internal::{
+        application::user::link_user_identity_use_case::{
+            LinkUserIdentityCommand, LinkUserIdentityError,
+        },
+        domain::value_object::Id,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+
+#[utoipa::path(
+    post,
+    path = "/users/{id}/identities",
+    params(
+        ("id" = String, Path, description = "ユーザーID")
+    ),
+    request_body = LinkUserIdentityRequestBody,
+    responses(
+        (status = 200, description = "IdPのユーザーの紐付けに成功", body = LinkUserIdentityResponseBody),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "対象のユーザーが見つからないか、シングルサインオンが設定されていない", body = ErrorResponseBody),
+        (status = 409, description = "IdPのユーザーが既に紐付けられているか、対象のユーザーが緊急用のアカウント", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+        (status = 500, description = "IdPとの通信に失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "users"
+)]
+pub async fn link_user_identity(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    Json(request_body): Json<LinkUserIdentityRequestBody>,
+) -> Result<Json<LinkUserIdentityResponseBody>, PresentationError> {
+    let use_case = state.link_user_identity_use_case.as_ref().ok_or_else(|| {
+        PresentationError::NotFound("シングルサインオンは設定されていません".to_string())
+    })?;
+
+    // バリデーション
+    let id = Id::new(id)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なID: {e}")))?;
+
+    // 紐付け処理
+    let command = LinkUserIdentityCommand {
+        target_id: id,
+        subject: request_body.subject,
+        linked_by: user.uuid(),
+    };
+    let identity = use_case.execute(command).await.map_err(|e| match e {
+        LinkUserIdentityError::Repository(e) => PresentationError::from(e),
+        LinkUserIdentityError::IdentityProvider(_) => {
+            PresentationError::InternalServerError(e.to_string())
+        }
+        LinkUserIdentityError::Forbidden => PresentationError::Forbidden(e.to_string()),
+        LinkUserIdentityError::ReservedUserId(_) => PresentationError::Conflict(e.to_string()),
+        LinkUserIdentityError::InvalidSubject => {
+            PresentationError::UnprocessableContent(e.to_string())
+        }
+    })?;
+
+    Ok(Json(LinkUserIdentityResponseBody::from(identity)))
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{
+        internal::{
+            domain::value_object::{Id, LocalLoginPolicy},
+            infrastructure::repository::OidcIdentityProviderRepository,
+            presentation::util::{mock_identity_provider::MockIdentityProvider, test_helpers},
+        },
+        startup,
+    };
+    use axum::{
+        body::{self, Body},
+        http::{Request, StatusCode},
+    };
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    /// シングルサインオンを有効にしたテストデータを準備する
+    async fn prepare_oidc_test_data() -> (startup::Repos, MockIdentityProvider) {
+        let mut repos = prepare_test_data().await;
+        let identity_provider = MockIdentityProvider::start().await;
+        repos.identity_provider_repository = Some(Arc::new(OidcIdentityProviderRepository::new(
+            identity_provider.config(),
+        )));
+        repos.local_login_policy = LocalLoginPolicy::Only(vec![Id::new("admin").unwrap()]);
+
+        (repos, identity_provider)
+    }
+
+    fn link_request(id: &str, subject: &str, session_id: &str, csrf_token: &str) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/users/{id}/identities"))
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", csrf_token)
+            .body(Body::from(json!({ "subject": subject }).to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn 管理者は既存のユーザーにIdPのユーザーを紐付けられる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+
+        // Act
+        let response = router
+            .oneshot(link_request("doctor", "idp-0002", &session_id, &csrf_token))
+            .await
+            .unwrap();
+
+        // Assert
+        // ステータスコードの確認
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // レスポンスボディの確認
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["issuer"], identity_provider.issuer());
+        assert_eq!(body["subject"], "idp-0002");
+
+        // リポジトリに反映されていることの確認
+        let user_identity = repos
+            .user_identity_repository
+            .find_by_subject(identity_provider.issuer(), "idp-0002")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            *user_identity.user_uuid(),
+            Uuid::parse_str("492236d4-2f18-76ab-a82f-84e29fcf92f8").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn 既に紐付けられているIdPのユーザーを紐付けると409エラーになる() {
+        // Arrange
+        let (repos, _identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let response = router
+            .clone()
+            .oneshot(link_request("doctor", "idp-0002", &session_id, &csrf_token))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Act
+        let response = router
+            .oneshot(link_request("it", "idp-0002", &session_id, &csrf_token))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn 緊急用のアカウントにIdPのユーザーを紐付けると409エラーになる() {
+        // Arrange
+        let (repos, identity_provider) = prepare_oidc_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+
+        // Act
+        let response = router
+            .oneshot(link_request("admin", "idp-0003", &session_id, &csrf_token))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert!(
+            repos
+                .user_identity_repository
+                .find_by_subject(identity_provider.issuer(), "idp-0003")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn 情シスは管理者にIdPのユーザーを紐付けられない() {
+        // Arrange
+        let (mut repos, identity_provider) = prepare_oidc_test_data().await;
+        // 緊急用のアカウントでない管理者
+        repos.local_login_policy = LocalLoginPolicy::AllUsers;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+
+        // Act
+        let response = router
+            .oneshot(link_request("admin", "idp-0003", &session_id, &csrf_token))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(
+            repos
+                .user_identity_repository
+                .find_by_subject(identity_provider.issuer(), "idp-0003")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn シングルサインオンが設定されていない場合は404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+
+        // Act
+        let response = router
+            .oneshot(link_request("doctor", "idp-0002", &session_id, &csrf_token))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkUserIdentityRequestBody {
+    /// IdPのユーザーのサブジェクト（IDトークンの`sub`クレーム）
+    pub subject: String,
+}
@@ -0,0 +1,22 @@
+use crate::internal::domain::entity::UserIdentity;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkUserIdentityResponseBody {
+    pub issuer: String,
+    pub subject: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<UserIdentity> for LinkUserIdentityResponseBody {
+    fn from(identity: UserIdentity) -> Self {
+        Self {
+            issuer: identity.issuer().into(),
+            subject: identity.subject().into(),
+            created_at: *identity.created_at(),
+        }
+    }
+}
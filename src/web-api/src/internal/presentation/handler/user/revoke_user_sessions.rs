@@ -0,0 +1,242 @@
+use crate::{
+    internal::{
+        application::user::revoke_user_sessions_use_case::{
+            RevokeUserSessionsCommand, RevokeUserSessionsError,
+        },
+        domain::value_object::Id,
+        presentation::{
+            error::{ErrorResponseBody, PresentationError},
+            middleware::AuthenticatedUser,
+        },
+    },
+    startup::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/users/{id}/sessions",
+    params(
+        ("id" = String, Path, description = "ユーザーID")
+    ),
+    responses(
+        (status = 204, description = "ユーザーのすべてのセッションの失効に成功"),
+        (status = 400, description = "リクエストの形式が無効", body = ErrorResponseBody),
+        (status = 401, description = "セッションが確立されていないか期限が切れている", body = ErrorResponseBody),
+        (status = 403, description = "CSRFトークンが無効または権限がない", body = ErrorResponseBody),
+        (status = 404, description = "対象のユーザーが見つからない", body = ErrorResponseBody),
+        (status = 422, description = "バリデーションに失敗", body = ErrorResponseBody),
+    ),
+    security(
+        ("session_cookie" = []),
+        ("csrf_token" = [])
+    ),
+    tag = "users"
+)]
+pub async fn revoke_user_sessions(
+    State(state): State<AppState>,
+    Extension(user): Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, PresentationError> {
+    // バリデーション
+    let id = Id::new(id)
+        .map_err(|e| PresentationError::UnprocessableContent(format!("無効なID: {e}")))?;
+
+    // 失効処理
+    let command = RevokeUserSessionsCommand {
+        target_id: id,
+        revoked_by: user.uuid(),
+    };
+    state
+        .revoke_user_sessions_use_case
+        .execute(command)
+        .await
+        .map_err(|e| match e {
+            RevokeUserSessionsError::Repository(repo_err) => PresentationError::from(repo_err),
+            RevokeUserSessionsError::Forbidden => PresentationError::Forbidden(e.to_string()),
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::super::prepare_test_data;
+    use crate::{
+        internal::{domain::entity::Session, presentation::util::test_helpers},
+        startup,
+    };
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn 管理者はユーザーのすべてのセッションを失効させられる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let user_uuid = Uuid::parse_str("492236d4-2f18-76ab-a82f-84e29fcf92f8").unwrap();
+        let (session1, session_id1) = Session::create(user_uuid, None);
+        let (session2, session_id2) = Session::create(user_uuid, None);
+        repos.session_repository.save(session1).await.unwrap();
+        repos.session_repository.save(session2).await.unwrap();
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri("/users/doctor/sessions")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        // 対象ユーザーのセッションがすべて削除されていることの確認
+        for session_id in [session_id1, session_id2] {
+            let session = repos
+                .session_repository
+                .find_by_session_id(&session_id)
+                .await
+                .unwrap();
+            assert!(session.is_none());
+        }
+        // 操作したユーザーのセッションは削除されていないことの確認
+        let session = repos
+            .session_repository
+            .find_by_session_id(&session_id)
+            .await
+            .unwrap();
+        assert!(session.is_some());
+    }
+
+    #[tokio::test]
+    async fn 情シスはユーザーのすべてのセッションを失効させられる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let user_uuid = Uuid::parse_str("492236d4-2f18-76ab-a82f-84e29fcf92f8").unwrap();
+        let (session, target_session_id) = Session::create(user_uuid, None);
+        repos.session_repository.save(session).await.unwrap();
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri("/users/doctor/sessions")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let session = repos
+            .session_repository
+            .find_by_session_id(&target_session_id)
+            .await
+            .unwrap();
+        assert!(session.is_none());
+    }
+
+    #[tokio::test]
+    async fn 管理者でも情シスでもないユーザーが失効させようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "technician", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri("/users/doctor/sessions")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn 情シスが管理者ユーザーのセッションを失効させようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let admin_uuid = Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap();
+        let (session, admin_session_id) = Session::create(admin_uuid, None);
+        repos.session_repository.save(session).await.unwrap();
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "it", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri("/users/admin/sessions")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // 管理者のセッションが削除されていないことの確認
+        let session = repos
+            .session_repository
+            .find_by_session_id(&admin_session_id)
+            .await
+            .unwrap();
+        assert!(session.is_some());
+    }
+
+    #[tokio::test]
+    async fn 存在しないユーザーのセッションを失効させようとすると404エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let request = Request::builder()
+            .method("DELETE")
+            .uri("/users/notfound/sessions")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -91,7 +91,13 @@ pub async fn update_user(
 mod tests {
     use super::super::prepare_test_data;
     use crate::{
-        internal::{domain::value_object::Id, presentation::util::test_helpers},
+        internal::{
+            domain::{
+                entity::RolePermissions,
+                value_object::{Id, Permission, Role},
+            },
+            presentation::util::test_helpers,
+        },
         startup,
     };
     use axum::{
@@ -319,6 +325,83 @@ mod tests {
         assert!((now - *user.updated_at()).num_seconds().abs() < 10);
     }
 
+    #[tokio::test]
+    async fn パスワードを変更するとユーザーのすべてのセッションが失効する() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (doctor_session_id, _) = test_helpers::login(&router, "doctor", "Password#1234").await;
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let body = json!({
+            "id": "doctor",
+            "name": "医師 太郎",
+            "role": 2,
+            "password": "NewPassword#5678" // パスワードを変更
+        });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/users/doctor")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // パスワードを変更したユーザーのセッションが削除されていることの確認
+        let session = repos
+            .session_repository
+            .find_by_session_id(&doctor_session_id)
+            .await
+            .unwrap();
+        assert!(session.is_none());
+    }
+
+    #[tokio::test]
+    async fn パスワードを変更しない場合はセッションは失効しない() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (doctor_session_id, _) = test_helpers::login(&router, "doctor", "Password#1234").await;
+        let (session_id, csrf_token) = test_helpers::login(&router, "admin", "Password#1234").await;
+        let body = json!({
+            "id": "doctor",
+            "name": "John Doe",
+            "role": 2,
+            "password": null // パスワードは変更しない
+        });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/users/doctor")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let session = repos
+            .session_repository
+            .find_by_session_id(&doctor_session_id)
+            .await
+            .unwrap();
+        assert!(session.is_some());
+    }
+
     #[tokio::test]
     async fn 情シスはユーザー名とロールを変更できる() {
         // Arrange
@@ -840,4 +923,55 @@ mod tests {
             assert_eq!(res.unwrap().status(), StatusCode::UNPROCESSABLE_ENTITY);
         });
     }
+
+    #[tokio::test]
+    async fn ユーザーの管理の権限を持つ医師が自身を管理者に昇格しようとすると403エラーになる() {
+        // Arrange
+        let repos = prepare_test_data().await;
+        // 以前の設定などにより、医師のロールにユーザーの管理の権限が付与されている場合
+        repos
+            .role_permission_repository
+            .replace(
+                &RolePermissions::construct(
+                    Role::Doctor,
+                    vec![Permission::ViewStudies, Permission::ManageUsers],
+                ),
+                &Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+                &Utc::now(),
+            )
+            .await
+            .unwrap();
+        let state = startup::make_state(&repos);
+        let router = startup::make_router(state, &repos);
+
+        let (session_id, csrf_token) =
+            test_helpers::login(&router, "doctor", "Password#1234").await;
+        let body = json!({
+            "id": "doctor",
+            "name": "医師 太郎",
+            "role": 0, // Admin
+            "password": null
+        });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/users/doctor")
+            .header("content-type", "application/json")
+            .header("cookie", format!("session_id={session_id}"))
+            .header("x-csrf-token", &csrf_token)
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = router.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let user = repos
+            .user_repository
+            .find_by_id(&Id::new("doctor").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.role(), Role::Doctor);
+    }
 }
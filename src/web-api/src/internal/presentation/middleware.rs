@@ -1,5 +1,7 @@
-pub mod role_check;
+pub mod permission_check;
 pub mod session_auth;
+pub mod wado_uri_auth;
 
-pub use role_check::require_admin_or_it;
+pub use permission_check::require_permission;
 pub use session_auth::{AuthenticatedUser, session_auth_middleware};
+pub use wado_uri_auth::wado_uri_auth_middleware;
@@ -0,0 +1,266 @@
+use crate::internal::{
+    domain::{
+        repository::{RolePermissionRepository, UserRepository},
+        value_object::Permission,
+    },
+    presentation::{error::PresentationError, middleware::session_auth::AuthenticatedUser},
+};
+use axum::{
+    body::Body, extract::Request, middleware::Next, response::IntoResponse, response::Response,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// ユーザーのロールに操作の権限が許可されていなければ403を返すミドルウェア関数
+pub async fn require_permission(
+    permission: Permission,
+    request: Request<Body>,
+    next: Next,
+    user_repository: Arc<dyn UserRepository>,
+    role_permission_repository: Arc<dyn RolePermissionRepository>,
+) -> Response {
+    // 認証済みユーザー情報を取得
+    let user_uuid: Uuid = match request.extensions().get::<AuthenticatedUser>() {
+        Some(auth) => auth.uuid(),
+        None => {
+            return PresentationError::Unauthorized("認証が必要です".to_string()).into_response();
+        }
+    };
+
+    // ユーザーを取得
+    let user = match user_repository.find_by_uuid(&user_uuid).await {
+        Ok(Some(user)) => user,
+        Ok(None) => {
+            return PresentationError::Unauthorized("ユーザーが見つかりません".to_string())
+                .into_response();
+        }
+        Err(e) => {
+            return PresentationError::InternalServerError(format!(
+                "データベース処理でエラーが発生しました: {e}"
+            ))
+            .into_response();
+        }
+    };
+
+    // ロールの権限を取得
+    match role_permission_repository.find_by_role(user.role()).await {
+        Ok(role_permissions) if role_permissions.allows(permission) => next.run(request).await,
+        Ok(_) => PresentationError::Forbidden("この操作を行う権限がありません".to_string())
+            .into_response(),
+        Err(e) => PresentationError::InternalServerError(format!(
+            "データベース処理でエラーが発生しました: {e}"
+        ))
+        .into_response(),
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::require_permission;
+    use crate::internal::{
+        domain::{
+            entity::{RolePermissions, User},
+            repository::{RolePermissionRepository, UserRepository},
+            value_object::{Id, Permission, Role, UserName},
+        },
+        infrastructure::repository::{TestRolePermissionRepository, TestUserRepository},
+        presentation::middleware::AuthenticatedUser,
+    };
+    use axum::{
+        Extension, Router,
+        body::Body,
+        http::{Request, StatusCode},
+        routing::get,
+    };
+    use chrono::Utc;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    const USER_UUID: &str = "492236d4-2f18-76ab-a82f-84e29fcf92f8";
+
+    async fn prepare_user_repository(role: Role) -> Arc<dyn UserRepository> {
+        let user_repository = Arc::new(TestUserRepository::new());
+        user_repository
+            .add(&User::construct(
+                Uuid::parse_str(USER_UUID).unwrap(),
+                Id::new("user").unwrap(),
+                UserName::new("利用者 太郎").unwrap(),
+                role,
+                "$argon2id$v=19$m=19456,t=2,p=1$1E/vEPPwrHBsW1fLuzdUVQ$1sAIm/nnFMIyc1IBuKW8+6KcdyHtdzjHCv7ae8lG6sA",
+                Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+                Utc::now(),
+                Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+                Utc::now(),
+            ))
+            .await
+            .unwrap();
+        user_repository
+    }
+
+    /// 権限の検査を適用したルートにリクエストし、ステータスコードを返す
+    async fn request(
+        permission: Permission,
+        authenticated_user: Option<AuthenticatedUser>,
+        user_repository: Arc<dyn UserRepository>,
+        role_permission_repository: Arc<dyn RolePermissionRepository>,
+    ) -> StatusCode {
+        let router = Router::new()
+            .route("/", get(|| async { StatusCode::OK }))
+            .layer(axum::middleware::from_fn(move |request, next| {
+                require_permission(
+                    permission,
+                    request,
+                    next,
+                    user_repository.clone(),
+                    role_permission_repository.clone(),
+                )
+            }));
+        let router = match authenticated_user {
+            Some(user) => router.layer(Extension(user)),
+            None => router,
+        };
+
+        let request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        router.oneshot(request).await.unwrap().status()
+    }
+
+    /// 既定の設定で、ロールに許可された操作だけが許可されることを確認する
+    async fn assert_default_permissions(role: Role, allowed: &[Permission]) {
+        let user_repository = prepare_user_repository(role).await;
+        let role_permission_repository = Arc::new(TestRolePermissionRepository::new());
+
+        for permission in Permission::ALL {
+            let status = request(
+                permission,
+                Some(AuthenticatedUser(Uuid::parse_str(USER_UUID).unwrap())),
+                user_repository.clone(),
+                role_permission_repository.clone(),
+            )
+            .await;
+
+            let expected = if allowed.contains(&permission) {
+                StatusCode::OK
+            } else {
+                StatusCode::FORBIDDEN
+            };
+            assert_eq!(status, expected, "ロール={role:?}, 権限={permission:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn 管理者はすべての操作を許可される() {
+        assert_default_permissions(Role::Admin, &Permission::ALL).await;
+    }
+
+    #[tokio::test]
+    async fn 情シスはすべての操作を許可される() {
+        assert_default_permissions(Role::ItStaff, &Permission::ALL).await;
+    }
+
+    #[tokio::test]
+    async fn 医師は検査の閲覧とダウンロードと登録を許可される() {
+        assert_default_permissions(
+            Role::Doctor,
+            &[
+                Permission::ViewStudies,
+                Permission::DownloadStudies,
+                Permission::StoreStudies,
+            ],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn 技師は検査の閲覧とダウンロードと登録を許可される() {
+        assert_default_permissions(
+            Role::Technician,
+            &[
+                Permission::ViewStudies,
+                Permission::DownloadStudies,
+                Permission::StoreStudies,
+            ],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn 事務員は検査の閲覧だけを許可される() {
+        assert_default_permissions(Role::Clerk, &[Permission::ViewStudies]).await;
+    }
+
+    #[tokio::test]
+    async fn ロールの権限を変更すると許可される操作が変わる() {
+        // Arrange
+        let user_repository = prepare_user_repository(Role::Clerk).await;
+        let role_permission_repository = Arc::new(TestRolePermissionRepository::new());
+        role_permission_repository
+            .replace(
+                &RolePermissions::construct(Role::Clerk, vec![Permission::ExportDeidentifiedData]),
+                &Uuid::parse_str("019bdbbe-0dcc-7474-8b43-95b89ca8b4fd").unwrap(),
+                &Utc::now(),
+            )
+            .await
+            .unwrap();
+        let authenticated_user = AuthenticatedUser(Uuid::parse_str(USER_UUID).unwrap());
+
+        // Act
+        let view_status = request(
+            Permission::ViewStudies,
+            Some(authenticated_user),
+            user_repository.clone(),
+            role_permission_repository.clone(),
+        )
+        .await;
+        let export_status = request(
+            Permission::ExportDeidentifiedData,
+            Some(authenticated_user),
+            user_repository,
+            role_permission_repository,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(view_status, StatusCode::FORBIDDEN);
+        assert_eq!(export_status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn 認証されていない場合は401エラーになる() {
+        // Arrange
+        let user_repository = prepare_user_repository(Role::Admin).await;
+        let role_permission_repository = Arc::new(TestRolePermissionRepository::new());
+
+        // Act
+        let status = request(
+            Permission::ViewStudies,
+            None,
+            user_repository,
+            role_permission_repository,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn 存在しないユーザーの場合は401エラーになる() {
+        // Arrange
+        let user_repository = prepare_user_repository(Role::Admin).await;
+        let role_permission_repository = Arc::new(TestRolePermissionRepository::new());
+
+        // Act
+        let status = request(
+            Permission::ViewStudies,
+            Some(AuthenticatedUser(Uuid::nil())),
+            user_repository,
+            role_permission_repository,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::internal::{
-    application::session::ExtendSessionUseCase,
+    application::session::{ExtendSessionUseCase, extend_session_use_case::CsrfValidationError},
     domain::entity::Session,
     presentation::{error::PresentationError, util::CookieHelper},
 };
@@ -91,31 +91,192 @@ pub async fn session_auth_middleware(
                 PresentationError::Unauthorized("セッションが期限切れか見つかりません".to_string())
                     .into_response()
             }
-            Err(_) => {
+            Err(CsrfValidationError::InvalidToken) => {
                 // CSRFトークンが不正
                 PresentationError::Forbidden("不正なCSRFトークンです".to_string()).into_response()
             }
+            Err(CsrfValidationError::Repository(e)) => {
+                PresentationError::InternalServerError(e.to_string()).into_response()
+            }
         }
     } else {
         // GETなどの参照系リクエストではCSRFトークン不要
         // セッションを検証し延長する
-        if let Some(user_uuid) = extend_session_use_case.execute(&session_id).await {
-            // セッション有効期限を延長したので、Cookieも更新
-            let cookie =
-                CookieHelper::create_session_cookie(session_id, Session::DEFAULT_EXPIRY_MINUTES);
-            cookies.add(cookie);
-
-            // 認証済みユーザー情報をリクエストに追加
-            let mut request = request;
-            request
-                .extensions_mut()
-                .insert(AuthenticatedUser(user_uuid));
-
-            next.run(request).await
-        } else {
-            // セッションが見つからないか期限切れ
-            PresentationError::Unauthorized("セッションが期限切れか見つかりません".to_string())
-                .into_response()
+        match extend_session_use_case.execute(&session_id).await {
+            Ok(Some(user_uuid)) => {
+                // セッション有効期限を延長したので、Cookieも更新
+                let cookie = CookieHelper::create_session_cookie(
+                    session_id,
+                    Session::DEFAULT_EXPIRY_MINUTES,
+                );
+                cookies.add(cookie);
+
+                // 認証済みユーザー情報をリクエストに追加
+                let mut request = request;
+                request
+                    .extensions_mut()
+                    .insert(AuthenticatedUser(user_uuid));
+
+                next.run(request).await
+            }
+            Ok(None) => {
+                // セッションが見つからないか期限切れ
+                PresentationError::Unauthorized("セッションが期限切れか見つかりません".to_string())
+                    .into_response()
+            }
+            Err(e) => PresentationError::InternalServerError(e.to_string()).into_response(),
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::session_auth_middleware;
+    use crate::internal::{
+        application::session::ExtendSessionUseCase,
+        domain::{entity::Session, error::RepositoryError, repository::SessionRepository},
+        infrastructure::repository::TestSessionRepository,
+        presentation::util::CookieHelper,
+    };
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, StatusCode, header},
+        routing::get,
+    };
+    use chrono::{DateTime, Utc};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+    use tower_cookies::{CookieManagerLayer, Cookies};
+    use uuid::Uuid;
+
+    const USER_UUID: &str = "492236d4-2f18-76ab-a82f-84e29fcf92f8";
+
+    /// セッションを取得した直後に、別のリクエストでそのセッションが失効させられた状況を再現するリポジトリ
+    struct RevokedAfterFindSessionRepository {
+        inner: TestSessionRepository,
+    }
+
+    #[async_trait::async_trait]
+    impl SessionRepository for RevokedAfterFindSessionRepository {
+        async fn save(&self, session: Session) -> Result<(), RepositoryError> {
+            self.inner.save(session).await
+        }
+
+        async fn extend(
+            &self,
+            uuid: &Uuid,
+            last_accessed_at: &DateTime<Utc>,
+            expires_at: &DateTime<Utc>,
+        ) -> Result<bool, RepositoryError> {
+            self.inner.extend(uuid, last_accessed_at, expires_at).await
+        }
+
+        async fn find_by_session_id(
+            &self,
+            session_id: &str,
+        ) -> Result<Option<Session>, RepositoryError> {
+            let session = self.inner.find_by_session_id(session_id).await?;
+            self.inner.delete_by_session_id(session_id).await?;
+            Ok(session)
+        }
+
+        async fn find_by_user_uuid(
+            &self,
+            user_uuid: &Uuid,
+        ) -> Result<Vec<Session>, RepositoryError> {
+            self.inner.find_by_user_uuid(user_uuid).await
+        }
+
+        async fn delete_by_session_id(&self, session_id: &str) -> Result<(), RepositoryError> {
+            self.inner.delete_by_session_id(session_id).await
+        }
+
+        async fn delete_by_uuid(
+            &self,
+            user_uuid: &Uuid,
+            uuid: &Uuid,
+        ) -> Result<(), RepositoryError> {
+            self.inner.delete_by_uuid(user_uuid, uuid).await
+        }
+
+        async fn delete_by_user_uuid(&self, user_uuid: &Uuid) -> Result<u64, RepositoryError> {
+            self.inner.delete_by_user_uuid(user_uuid).await
         }
+
+        async fn cleanup_expired_sessions(&self) -> Result<u64, RepositoryError> {
+            self.inner.cleanup_expired_sessions().await
+        }
+    }
+
+    /// セッション認証を適用したルートにセッションIDを付けてリクエストし、ステータスコードを返す
+    async fn request(
+        session_repository: Arc<dyn SessionRepository>,
+        session_id: &str,
+    ) -> StatusCode {
+        let extend_session_use_case = Arc::new(ExtendSessionUseCase::new(session_repository));
+        let router = Router::new()
+            .route("/", get(|| async { StatusCode::OK }))
+            .layer(axum::middleware::from_fn(
+                move |cookies: Cookies, request, next| {
+                    session_auth_middleware(cookies, extend_session_use_case.clone(), request, next)
+                },
+            ))
+            .layer(CookieManagerLayer::new());
+
+        let request = Request::builder()
+            .uri("/")
+            .header(
+                header::COOKIE,
+                format!("{}={session_id}", CookieHelper::SESSION_COOKIE_NAME),
+            )
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn 有効なセッションでリクエストすると有効期限が延長される() {
+        // Arrange
+        let session_repository = Arc::new(TestSessionRepository::new());
+        let (session, session_id) = Session::create(Uuid::parse_str(USER_UUID).unwrap(), None);
+        let expires_at = *session.expires_at();
+        session_repository.save(session).await.unwrap();
+
+        // Act
+        let status = request(session_repository.clone(), &session_id).await;
+
+        // Assert
+        assert_eq!(status, StatusCode::OK);
+        let session = session_repository
+            .find_by_session_id(&session_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(*session.expires_at() >= expires_at);
+    }
+
+    #[tokio::test]
+    async fn 延長する前に失効させられたセッションは復活しない() {
+        // Arrange
+        let inner = TestSessionRepository::new();
+        let (session, session_id) = Session::create(Uuid::parse_str(USER_UUID).unwrap(), None);
+        inner.save(session).await.unwrap();
+        let session_repository = Arc::new(RevokedAfterFindSessionRepository { inner });
+
+        // Act
+        let status = request(session_repository.clone(), &session_id).await;
+
+        // Assert
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert!(
+            session_repository
+                .inner
+                .find_by_session_id(&session_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
     }
 }
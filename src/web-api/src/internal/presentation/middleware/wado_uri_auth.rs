@@ -0,0 +1,48 @@
+use super::session_auth::{AuthenticatedUser, session_auth_middleware};
+use crate::internal::{
+    application::{session::ExtendSessionUseCase, wado_uri_token::VerifyWadoUriTokenUseCase},
+    presentation::error::PresentationError,
+};
+use axum::{
+    body::Body,
+    extract::{Query, Request},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::{collections::HashMap, sync::Arc};
+use tower_cookies::Cookies;
+
+/// WADO-URIの認証ミドルウェア
+///
+/// クエリパラメータ`token`が指定されている場合は署名付きURLのトークンを検証し、
+/// 指定されていない場合はセッションで認証する。
+pub async fn wado_uri_auth_middleware(
+    cookies: Cookies,
+    extend_session_use_case: Arc<ExtendSessionUseCase>,
+    verify_wado_uri_token_use_case: Arc<VerifyWadoUriTokenUseCase>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let query = Query::<HashMap<String, String>>::try_from_uri(request.uri())
+        .map(|Query(query)| query)
+        .unwrap_or_default();
+    let Some(signed_token) = query.get("token") else {
+        return session_auth_middleware(cookies, extend_session_use_case, request, next).await;
+    };
+
+    // トークンは取得対象のSOPインスタンスを指定したURLでのみ有効とする
+    let param = |key: &str| query.get(key).map(String::as_str).unwrap_or_default();
+    match verify_wado_uri_token_use_case.execute(signed_token) {
+        Some(token) if token.permits(param("studyUID"), param("seriesUID"), param("objectUID")) => {
+            // 署名付きURLでの取得は、発行したユーザーによる取得として扱う
+            let mut request = request;
+            request
+                .extensions_mut()
+                .insert(AuthenticatedUser(*token.issued_by()));
+
+            next.run(request).await
+        }
+        _ => PresentationError::Unauthorized("署名付きURLが無効か期限が切れています".to_string())
+            .into_response(),
+    }
+}
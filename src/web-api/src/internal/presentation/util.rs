@@ -1,5 +1,7 @@
 mod cookie_helper;
 
+#[cfg(test)]
+pub mod mock_identity_provider;
 #[cfg(test)]
 pub mod test_helpers;
 
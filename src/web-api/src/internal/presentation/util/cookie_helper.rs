@@ -5,6 +5,7 @@ pub struct CookieHelper;
 
 impl CookieHelper {
     pub const SESSION_COOKIE_NAME: &'static str = "session_id";
+    pub const OIDC_STATE_COOKIE_NAME: &'static str = "oidc_state";
 
     pub fn create_session_cookie(
         session_id: impl Into<String>,
@@ -27,4 +28,28 @@ impl CookieHelper {
         cookie.set_secure(false);
         cookie
     }
+
+    /// ログインを開始したブラウザを識別するため、認可リクエストのstateを保持するCookieを作成する。
+    /// IdPからのリダイレクト（他サイトからの遷移）でも送信されるよう、SameSite=Laxとする。
+    pub fn create_oidc_state_cookie(
+        state: impl Into<String>,
+        max_age_minutes: i64,
+    ) -> Cookie<'static> {
+        let mut cookie = Cookie::new(Self::OIDC_STATE_COOKIE_NAME, state.into());
+        cookie.set_path("/");
+        cookie.set_http_only(true);
+        cookie.set_same_site(SameSite::Lax);
+        cookie.set_secure(false); // TODO: 本番環境のHTTPS化に伴い`true`に設定する
+        cookie.set_max_age(Duration::minutes(max_age_minutes));
+        cookie
+    }
+
+    pub fn delete_oidc_state_cookie() -> Cookie<'static> {
+        let mut cookie = Cookie::from(Self::OIDC_STATE_COOKIE_NAME);
+        cookie.set_path("/");
+        cookie.set_http_only(true);
+        cookie.set_same_site(SameSite::Lax);
+        cookie.set_secure(false);
+        cookie
+    }
 }
@@ -2,13 +2,20 @@ mod args;
 mod internal;
 mod startup;
 
-use self::args::Args;
+use self::{
+    args::Args,
+    internal::{
+        domain::{repository::IdentityProviderRepository, value_object::LocalLoginPolicy},
+        infrastructure::repository::OidcIdentityProviderRepository,
+    },
+};
 use clap::Parser;
 use dotenvy::dotenv;
 use sqlx::postgres::PgPoolOptions;
-use std::{io::IsTerminal, net::Ipv4Addr, process::exit};
+use std::{io::IsTerminal, net::Ipv4Addr, process::exit, sync::Arc};
+use storage::StorageResolver;
 use tokio::net::TcpListener;
-use tracing::{debug, error, info, level_filters::LevelFilter};
+use tracing::{debug, error, info, level_filters::LevelFilter, warn};
 use tracing_subscriber::fmt::time::LocalTime;
 
 // Swagger UI関連
@@ -26,15 +33,64 @@ use utoipa::{
         internal::presentation::handler::auth::login::login,
         internal::presentation::handler::auth::logout::logout,
         internal::presentation::handler::auth::me::me,
+        internal::presentation::handler::auth::oidc_login::oidc_login,
+        internal::presentation::handler::auth::oidc_callback::oidc_callback,
         internal::presentation::handler::user::create_user::create_user,
         internal::presentation::handler::user::list_users::list_users,
         internal::presentation::handler::user::update_user::update_user,
         internal::presentation::handler::user::delete_user::delete_user,
         internal::presentation::handler::user::reset_login_failure_count::reset_login_failure_count,
+        internal::presentation::handler::user::revoke_user_sessions::revoke_user_sessions,
+        internal::presentation::handler::user::link_user_identity::link_user_identity,
+        internal::presentation::handler::role_permission::list_role_permissions::list_role_permissions,
+        internal::presentation::handler::role_permission::update_role_permissions::update_role_permissions,
+        internal::presentation::handler::session::list_sessions::list_sessions,
+        internal::presentation::handler::session::revoke_session::revoke_session,
         internal::presentation::handler::application_entity::create_application_entity::create_application_entity,
         internal::presentation::handler::application_entity::list_application_entities::list_application_entities,
         internal::presentation::handler::application_entity::update_application_entity::update_application_entity,
         internal::presentation::handler::application_entity::delete_application_entity::delete_application_entity,
+        internal::presentation::handler::coercion_rule::list_coercion_rules::list_coercion_rules,
+        internal::presentation::handler::coercion_rule::replace_coercion_rules::replace_coercion_rules,
+        internal::presentation::handler::performed_procedure_step::list_performed_procedure_steps::list_performed_procedure_steps,
+        internal::presentation::handler::archive::list_archived_patients::list_archived_patients,
+        internal::presentation::handler::archive::list_archived_studies::list_archived_studies,
+        internal::presentation::handler::archive::get_archived_study::get_archived_study,
+        internal::presentation::handler::archive::list_archived_sop_instances::list_archived_sop_instances,
+        internal::presentation::handler::archive::delete_archived_study::delete_archived_study,
+        internal::presentation::handler::archive::delete_archived_series::delete_archived_series,
+        internal::presentation::handler::archive::delete_archived_sop_instance::delete_archived_sop_instance,
+        internal::presentation::handler::retention_rule::list_retention_rules::list_retention_rules,
+        internal::presentation::handler::retention_rule::create_retention_rule::create_retention_rule,
+        internal::presentation::handler::retention_rule::update_retention_rule::update_retention_rule,
+        internal::presentation::handler::retention_rule::delete_retention_rule::delete_retention_rule,
+        internal::presentation::handler::retention_rule::list_retention_candidates::list_retention_candidates,
+        internal::presentation::handler::patient_conflict::list_patient_conflicts::list_patient_conflicts,
+        internal::presentation::handler::patient_conflict::merge_patient_conflict::merge_patient_conflict,
+        internal::presentation::handler::patient_conflict::split_patient_conflict::split_patient_conflict,
+        internal::presentation::handler::deidentification_job::create_deidentification_job::create_deidentification_job,
+        internal::presentation::handler::deidentification_job::get_deidentification_job::get_deidentification_job,
+        internal::presentation::handler::dicom_web::search_patients::search_patients,
+        internal::presentation::handler::dicom_web::search_studies::search_studies,
+        internal::presentation::handler::dicom_web::search_series::search_series,
+        internal::presentation::handler::dicom_web::search_instances::search_instances,
+        internal::presentation::handler::dicom_web::retrieve_instances::retrieve_study,
+        internal::presentation::handler::dicom_web::retrieve_instances::retrieve_series,
+        internal::presentation::handler::dicom_web::retrieve_instances::retrieve_instance,
+        internal::presentation::handler::dicom_web::retrieve_metadata::retrieve_study_metadata,
+        internal::presentation::handler::dicom_web::retrieve_metadata::retrieve_series_metadata,
+        internal::presentation::handler::dicom_web::retrieve_metadata::retrieve_instance_metadata,
+        internal::presentation::handler::dicom_web::retrieve_frames::retrieve_frames,
+        internal::presentation::handler::dicom_web::retrieve_bulk_data::retrieve_bulk_data,
+        internal::presentation::handler::dicom_web::retrieve_rendered::retrieve_rendered_instance,
+        internal::presentation::handler::dicom_web::retrieve_rendered::retrieve_rendered_frames,
+        internal::presentation::handler::dicom_web::retrieve_rendered::retrieve_study_thumbnail,
+        internal::presentation::handler::dicom_web::retrieve_rendered::retrieve_series_thumbnail,
+        internal::presentation::handler::dicom_web::retrieve_rendered::retrieve_instance_thumbnail,
+        internal::presentation::handler::dicom_web::store_instances::store_instances,
+        internal::presentation::handler::dicom_web::store_instances::store_study_instances,
+        internal::presentation::handler::dicom_web::wado_uri::retrieve_wado_uri_object,
+        internal::presentation::handler::dicom_web::wado_uri::create_wado_uri_token,
     ),
     components(schemas(
         internal::presentation::error::ErrorResponseBody,
@@ -46,17 +102,66 @@ use utoipa::{
         internal::presentation::handler::user::list_users::ListUsersResponseBodyItem,
         internal::presentation::handler::user::update_user::UpdateUserRequestBody,
         internal::presentation::handler::user::update_user::UpdateUserResponseBody,
+        internal::presentation::handler::user::link_user_identity::LinkUserIdentityRequestBody,
+        internal::presentation::handler::user::link_user_identity::LinkUserIdentityResponseBody,
+        internal::presentation::handler::role_permission::list_role_permissions::ListRolePermissionsResponseBodyItem,
+        internal::presentation::handler::role_permission::update_role_permissions::UpdateRolePermissionsRequestBody,
+        internal::presentation::handler::role_permission::update_role_permissions::UpdateRolePermissionsResponseBody,
+        internal::presentation::handler::session::list_sessions::ListSessionsResponseBodyItem,
         internal::presentation::handler::application_entity::create_application_entity::CreateApplicationEntityRequestBody,
         internal::presentation::handler::application_entity::create_application_entity::CreateApplicationEntityResponseBody,
         internal::presentation::handler::application_entity::list_application_entities::ListApplicationEntitiesResponseBodyItem,
         internal::presentation::handler::application_entity::update_application_entity::UpdateApplicationEntityRequestBody,
         internal::presentation::handler::application_entity::update_application_entity::UpdateApplicationEntityResponseBody,
+        internal::presentation::handler::coercion_rule::list_coercion_rules::ListCoercionRulesResponseBodyItem,
+        internal::presentation::handler::coercion_rule::list_coercion_rules::ListCoercionRulesResponseBodyMapping,
+        internal::presentation::handler::coercion_rule::replace_coercion_rules::ReplaceCoercionRulesRequestBody,
+        internal::presentation::handler::coercion_rule::replace_coercion_rules::ReplaceCoercionRulesRequestBodyRule,
+        internal::presentation::handler::coercion_rule::replace_coercion_rules::ReplaceCoercionRulesRequestBodyMapping,
+        internal::presentation::handler::coercion_rule::replace_coercion_rules::ReplaceCoercionRulesResponseBodyItem,
+        internal::presentation::handler::coercion_rule::replace_coercion_rules::ReplaceCoercionRulesResponseBodyMapping,
+        internal::presentation::handler::performed_procedure_step::list_performed_procedure_steps::ListPerformedProcedureStepsResponseBodyItem,
+        internal::presentation::handler::performed_procedure_step::list_performed_procedure_steps::ListPerformedProcedureStepsResponseBodyPerformedSeries,
+        internal::presentation::handler::archive::list_archived_patients::ListArchivedPatientsResponseBody,
+        internal::presentation::handler::archive::list_archived_patients::ListArchivedPatientsResponseBodyItem,
+        internal::presentation::handler::archive::list_archived_studies::ListArchivedStudiesResponseBody,
+        internal::presentation::handler::archive::list_archived_studies::ListArchivedStudiesResponseBodyItem,
+        internal::presentation::handler::archive::get_archived_study::GetArchivedStudyResponseBody,
+        internal::presentation::handler::archive::get_archived_study::GetArchivedStudyResponseBodySeries,
+        internal::presentation::handler::archive::list_archived_sop_instances::ListArchivedSopInstancesResponseBodyItem,
+        internal::presentation::handler::retention_rule::list_retention_rules::ListRetentionRulesResponseBodyItem,
+        internal::presentation::handler::retention_rule::create_retention_rule::CreateRetentionRuleRequestBody,
+        internal::presentation::handler::retention_rule::create_retention_rule::CreateRetentionRuleResponseBody,
+        internal::presentation::handler::retention_rule::update_retention_rule::UpdateRetentionRuleRequestBody,
+        internal::presentation::handler::retention_rule::update_retention_rule::UpdateRetentionRuleResponseBody,
+        internal::presentation::handler::retention_rule::list_retention_candidates::ListRetentionCandidatesResponseBodyItem,
+        internal::presentation::handler::patient_conflict::list_patient_conflicts::ListPatientConflictsResponseBodyItem,
+        internal::presentation::handler::patient_conflict::list_patient_conflicts::ListPatientConflictsResponseBodyDemographics,
+        internal::presentation::handler::patient_conflict::merge_patient_conflict::MergePatientConflictRequestBody,
+        internal::presentation::handler::patient_conflict::merge_patient_conflict::MergePatientConflictResponseBody,
+        internal::presentation::handler::patient_conflict::split_patient_conflict::SplitPatientConflictRequestBody,
+        internal::presentation::handler::patient_conflict::split_patient_conflict::SplitPatientConflictResponseBody,
+        internal::presentation::handler::deidentification_job::create_deidentification_job::CreateDeidentificationJobRequestBody,
+        internal::presentation::handler::deidentification_job::create_deidentification_job::CreateDeidentificationJobResponseBody,
+        internal::presentation::handler::deidentification_job::get_deidentification_job::GetDeidentificationJobResponseBody,
+        internal::presentation::handler::dicom_web::DicomJsonDataSet,
+        internal::presentation::handler::dicom_web::DicomJsonAttribute,
+        internal::presentation::handler::dicom_web::wado_uri::CreateWadoUriTokenRequestBody,
+        internal::presentation::handler::dicom_web::wado_uri::CreateWadoUriTokenResponseBody,
     )),
     tags(
         (name = "health", description = "ヘルスチェックAPI"),
         (name = "auth", description = "認証API"),
         (name = "users", description = "ユーザー管理API"),
-        (name = "application-entities", description = "Application Entity管理API")
+        (name = "role-permissions", description = "ロールごとの権限の管理API"),
+        (name = "sessions", description = "ログイン中のユーザーのセッションの管理API"),
+        (name = "application-entities", description = "Application Entity管理API"),
+        (name = "performed-procedure-steps", description = "Performed Procedure Step参照API"),
+        (name = "archive", description = "アーカイブ（患者・検査・シリーズ・SOPインスタンス）の閲覧・削除API"),
+        (name = "retention-rules", description = "保存期間の規則の管理API"),
+        (name = "patient-conflicts", description = "患者属性の不一致の照合API"),
+        (name = "deidentification-jobs", description = "検査の匿名化エクスポートAPI"),
+        (name = "dicom-web", description = "DICOMweb API (QIDO-RS, WADO-RS, STOW-RS, WADO-URI)")
     ),
     modifiers(&SecurityAddon),
     info(
@@ -123,17 +228,98 @@ async fn main() {
         }
     };
 
+    // STOW-RSで受け付けたSOPインスタンスの保存先
+    let storage_config = match args.storage_config() {
+        Ok(val) => val,
+        Err(e) => {
+            error!("{e}");
+            exit(1);
+        }
+    };
+
+    // WADO-URIの署名付きURLの秘密鍵
+    let wado_uri_secret = match args.wado_uri_secret() {
+        Some(secret) => secret.to_vec(),
+        None => {
+            warn!(
+                "WADO_URI_SECRETが指定されていないため秘密鍵を生成しました。再起動すると発行済みの署名付きURLは無効になります"
+            );
+            rand::random::<[u8; 32]>().to_vec()
+        }
+    };
+
+    // シングルサインオン (OpenID Connect) の設定
+    let (oidc_config, role_mapping, local_login_policy) = match (
+        args.oidc_config(),
+        args.role_mapping(),
+        args.local_login_policy(),
+    ) {
+        (Ok(oidc_config), Ok(role_mapping), Ok(local_login_policy)) => {
+            (oidc_config, role_mapping, local_login_policy)
+        }
+        (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
+            error!("{e}");
+            exit(1);
+        }
+    };
+    if oidc_config.is_some() && local_login_policy == LocalLoginPolicy::AllUsers {
+        warn!(
+            "シングルサインオンが有効ですが、LOCAL_LOGIN_USERSが指定されていないため全ユーザーにパスワードによるログインを許可します"
+        );
+    }
+    let identity_provider_repository = oidc_config.map(|config| {
+        info!(
+            "シングルサインオンを有効にしました (発行者URL={})",
+            config.issuer_url
+        );
+        Arc::new(OidcIdentityProviderRepository::new(config)) as Arc<dyn IdentityProviderRepository>
+    });
+
     // リポジトリの初期化
-    let repos = startup::Repos::new(pool);
+    let repos = startup::Repos::new(
+        pool,
+        StorageResolver::new(args.s3_config()),
+        &args.export_directory,
+        storage_config,
+        &args.ae_title,
+        args.duplicate_policy,
+        &wado_uri_secret,
+        StorageResolver::new(args.s3_config()),
+        args.trash_dir(),
+        identity_provider_repository,
+        role_mapping,
+        local_login_policy,
+    );
 
-    // セッションクリーンアップの定期実行ジョブ（メモリ内セッションの期限切れ削除）
+    // セッションクリーンアップの定期実行ジョブ（期限切れセッションの削除）
+    // 複数のWeb APIを起動している場合はそれぞれで実行されるが、削除は冪等なので問題ない
     {
         let session_repo = repos.session_repository.clone();
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
             loop {
                 interval.tick().await;
-                session_repo.cleanup_expired_sessions().await;
+                match session_repo.cleanup_expired_sessions().await {
+                    Ok(0) => {}
+                    Ok(count) => debug!("期限切れのセッションを削除しました (件数={count})"),
+                    Err(e) => warn!("期限切れのセッションの削除に失敗しました: {e}"),
+                }
+            }
+        });
+    }
+
+    // 期限切れの認可リクエスト（シングルサインオンを開始して完了しなかったもの）の定期削除
+    {
+        let authorization_request_repo = repos.oidc_authorization_request_repository.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+            loop {
+                interval.tick().await;
+                match authorization_request_repo.cleanup_expired_requests().await {
+                    Ok(0) => {}
+                    Ok(count) => debug!("期限切れの認可リクエストを削除しました (件数={count})"),
+                    Err(e) => warn!("期限切れの認可リクエストの削除に失敗しました: {e}"),
+                }
             }
         });
     }
@@ -4,66 +4,216 @@ use crate::internal::{
             CreateApplicationEntityUseCase, DeleteApplicationEntityUseCase,
             ListApplicationEntitiesUseCase, UpdateApplicationEntityUseCase,
         },
-        auth::{AuthenticateUserUseCase, LoginUseCase, LogoutUseCase},
-        session::{CreateSessionUseCase, DeleteSessionUseCase, ExtendSessionUseCase},
+        archive::{
+            DeleteArchivedObjectUseCase, GetArchivedStudyUseCase, ListArchivedPatientsUseCase,
+            ListArchivedSopInstancesUseCase, ListArchivedStudiesUseCase,
+        },
+        auth::{
+            AuthenticateUserUseCase, CompleteOidcLoginUseCase, LoginUseCase, LogoutUseCase,
+            StartOidcLoginUseCase,
+        },
+        coercion_rule::{ListCoercionRulesUseCase, ReplaceCoercionRulesUseCase},
+        deidentification_job::{CreateDeidentificationJobUseCase, GetDeidentificationJobUseCase},
+        dicom_object::{
+            ConvertSopInstanceFileUseCase, ReadSopInstanceDataSetUseCase,
+            ReadSopInstanceFileUseCase, RetrieveSopInstanceFilesUseCase, SearchPatientsUseCase,
+            SearchSeriesUseCase, SearchSopInstancesUseCase, SearchStudiesUseCase,
+            StoreSopInstancesUseCase,
+        },
+        patient_conflict::{ListPatientConflictsUseCase, ReconcilePatientConflictUseCase},
+        performed_procedure_step::ListPerformedProcedureStepsUseCase,
+        retention_rule::{
+            CreateRetentionRuleUseCase, DeleteRetentionRuleUseCase, ListRetentionCandidatesUseCase,
+            ListRetentionRulesUseCase, UpdateRetentionRuleUseCase,
+        },
+        role_permission::{ListRolePermissionsUseCase, UpdateRolePermissionsUseCase},
+        session::{
+            CreateSessionUseCase, DeleteSessionUseCase, ExtendSessionUseCase, ListSessionsUseCase,
+            RevokeSessionUseCase,
+        },
         user::{
             create_user_use_case::CreateUserUseCase, delete_user_use_case::DeleteUserUseCase,
+            link_user_identity_use_case::LinkUserIdentityUseCase,
             list_users_use_case::ListUsersUseCase,
             reset_login_failure_count_use_case::ResetLoginFailureCountUseCase,
+            revoke_user_sessions_use_case::RevokeUserSessionsUseCase,
             update_user_use_case::UpdateUserUseCase,
         },
+        wado_uri_token::{IssueWadoUriTokenUseCase, VerifyWadoUriTokenUseCase},
     },
-    domain::repository::{
-        ApplicationEntityRepository, LoginFailureCountRepository, SessionRepository, UserRepository,
+    domain::{
+        repository::{
+            ApplicationEntityRepository, ArchiveDeletionRepository, ArchiveRepository,
+            CoercionRuleRepository, DeidentificationJobRepository, DicomFileRepository,
+            DicomObjectRepository, DicomStoreRepository, IdentityProviderRepository,
+            LoginFailureCountRepository, OidcAuthorizationRequestRepository,
+            PatientConflictRepository, PerformedProcedureStepRepository, RetentionRuleRepository,
+            RolePermissionRepository, SessionRepository, UserIdentityRepository, UserRepository,
+            WadoUriTokenRepository,
+        },
+        value_object::{LocalLoginPolicy, Permission, RoleMapping},
     },
     infrastructure::repository::{
-        InMemorySessionRepository, PostgresApplicationEntityRepository,
-        PostgresLoginFailureCountRepository, PostgresUserRepository,
+        HmacWadoUriTokenRepository, PostgresApplicationEntityRepository, PostgresArchiveRepository,
+        PostgresCoercionRuleRepository, PostgresDeidentificationJobRepository,
+        PostgresDicomObjectRepository, PostgresLoginFailureCountRepository,
+        PostgresOidcAuthorizationRequestRepository, PostgresPatientConflictRepository,
+        PostgresPerformedProcedureStepRepository, PostgresRetentionRuleRepository,
+        PostgresRolePermissionRepository, PostgresSessionRepository,
+        PostgresUserIdentityRepository, PostgresUserRepository, StorageArchiveDeletionRepository,
+        StorageDicomFileRepository, StorageDicomStoreRepository,
     },
     presentation::{self, handler},
 };
 use axum::{
     Router,
+    extract::DefaultBodyLimit,
     routing::{delete, get, post, put},
 };
+use ingest::DuplicatePolicy;
 use sqlx::{Pool, Postgres};
 use std::sync::Arc;
+use storage::{StorageConfig, StorageResolver};
 use tower_cookies::CookieManagerLayer;
 use tower_http::trace::TraceLayer;
 
 pub struct Repos {
     pub application_entity_repository: Arc<dyn ApplicationEntityRepository>,
+    pub coercion_rule_repository: Arc<dyn CoercionRuleRepository>,
     pub user_repository: Arc<dyn UserRepository>,
     pub login_failure_count_repository: Arc<dyn LoginFailureCountRepository>,
+    pub role_permission_repository: Arc<dyn RolePermissionRepository>,
     pub session_repository: Arc<dyn SessionRepository>,
+    /// シングルサインオンを設定しない場合は`None`
+    pub identity_provider_repository: Option<Arc<dyn IdentityProviderRepository>>,
+    pub oidc_authorization_request_repository: Arc<dyn OidcAuthorizationRequestRepository>,
+    pub user_identity_repository: Arc<dyn UserIdentityRepository>,
+    /// IdPのグループからロールへの対応付け
+    pub role_mapping: RoleMapping,
+    /// パスワードによるログインを許可するユーザー
+    pub local_login_policy: LocalLoginPolicy,
+    pub performed_procedure_step_repository: Arc<dyn PerformedProcedureStepRepository>,
+    pub patient_conflict_repository: Arc<dyn PatientConflictRepository>,
+    pub deidentification_job_repository: Arc<dyn DeidentificationJobRepository>,
+    pub dicom_file_repository: Arc<dyn DicomFileRepository>,
+    pub dicom_object_repository: Arc<dyn DicomObjectRepository>,
+    pub archive_repository: Arc<dyn ArchiveRepository>,
+    pub archive_deletion_repository: Arc<dyn ArchiveDeletionRepository>,
+    pub retention_rule_repository: Arc<dyn RetentionRuleRepository>,
+    pub dicom_store_repository: Arc<dyn DicomStoreRepository>,
+    pub wado_uri_token_repository: Arc<dyn WadoUriTokenRepository>,
 }
 
 impl Repos {
-    pub fn new(pool: Pool<Postgres>) -> Self {
+    /// `storage`は保存済みのファイルの読み書きに、`store_storage`はSTOW-RSで受け付けたSOPインスタンスの保存に使用する。
+    /// `wado_uri_secret`はWADO-URIの署名付きURLの署名に使用する。
+    /// `deletion_storage`は検査等の削除時のファイルの削除に使用し、`trash_dir`を指定した場合は削除したファイルをゴミ箱ディレクトリに移動する。
+    /// `identity_provider_repository`を指定した場合はシングルサインオンを有効にする。
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        pool: Pool<Postgres>,
+        storage: StorageResolver,
+        export_directory: &str,
+        store_storage: StorageConfig,
+        ae_title: &str,
+        default_duplicate_policy: DuplicatePolicy,
+        wado_uri_secret: &[u8],
+        deletion_storage: StorageResolver,
+        trash_dir: Option<&str>,
+        identity_provider_repository: Option<Arc<dyn IdentityProviderRepository>>,
+        role_mapping: RoleMapping,
+        local_login_policy: LocalLoginPolicy,
+    ) -> Self {
         Self {
             application_entity_repository: Arc::new(PostgresApplicationEntityRepository::new(
                 pool.clone(),
             )),
+            coercion_rule_repository: Arc::new(PostgresCoercionRuleRepository::new(pool.clone())),
             user_repository: Arc::new(PostgresUserRepository::new(pool.clone())),
             login_failure_count_repository: Arc::new(PostgresLoginFailureCountRepository::new(
                 pool.clone(),
             )),
-            session_repository: Arc::new(InMemorySessionRepository::new()),
+            role_permission_repository: Arc::new(PostgresRolePermissionRepository::new(
+                pool.clone(),
+            )),
+            session_repository: Arc::new(PostgresSessionRepository::new(pool.clone())),
+            identity_provider_repository,
+            oidc_authorization_request_repository: Arc::new(
+                PostgresOidcAuthorizationRequestRepository::new(pool.clone()),
+            ),
+            user_identity_repository: Arc::new(PostgresUserIdentityRepository::new(pool.clone())),
+            role_mapping,
+            local_login_policy,
+            performed_procedure_step_repository: Arc::new(
+                PostgresPerformedProcedureStepRepository::new(pool.clone()),
+            ),
+            patient_conflict_repository: Arc::new(PostgresPatientConflictRepository::new(
+                pool.clone(),
+            )),
+            deidentification_job_repository: Arc::new(PostgresDeidentificationJobRepository::new(
+                pool.clone(),
+            )),
+            dicom_file_repository: Arc::new(StorageDicomFileRepository::new(
+                pool.clone(),
+                storage,
+                export_directory,
+            )),
+            dicom_object_repository: Arc::new(PostgresDicomObjectRepository::new(pool.clone())),
+            archive_repository: Arc::new(PostgresArchiveRepository::new(pool.clone())),
+            archive_deletion_repository: Arc::new(StorageArchiveDeletionRepository::new(
+                pool.clone(),
+                deletion_storage,
+                trash_dir,
+            )),
+            retention_rule_repository: Arc::new(PostgresRetentionRuleRepository::new(pool.clone())),
+            dicom_store_repository: Arc::new(StorageDicomStoreRepository::new(
+                pool.clone(),
+                store_storage,
+                ae_title,
+                default_duplicate_policy,
+            )),
+            wado_uri_token_repository: Arc::new(HmacWadoUriTokenRepository::new(wado_uri_secret)),
         }
     }
 
     #[cfg(test)]
     pub fn new_for_test() -> Self {
         use crate::internal::infrastructure::repository::{
-            TestApplicationEntityRepository, TestLoginFailureCountRepository,
-            TestSessionRepository, TestUserRepository,
+            TestApplicationEntityRepository, TestArchiveDeletionRepository, TestArchiveRepository,
+            TestCoercionRuleRepository, TestDeidentificationJobRepository, TestDicomFileRepository,
+            TestDicomObjectRepository, TestDicomStoreRepository, TestLoginFailureCountRepository,
+            TestOidcAuthorizationRequestRepository, TestPatientConflictRepository,
+            TestPerformedProcedureStepRepository, TestRetentionRuleRepository,
+            TestRolePermissionRepository, TestSessionRepository, TestUserIdentityRepository,
+            TestUserRepository,
         };
 
         Self {
             application_entity_repository: Arc::new(TestApplicationEntityRepository::new()),
+            coercion_rule_repository: Arc::new(TestCoercionRuleRepository::new()),
             user_repository: Arc::new(TestUserRepository::new()),
             login_failure_count_repository: Arc::new(TestLoginFailureCountRepository::new()),
+            role_permission_repository: Arc::new(TestRolePermissionRepository::new()),
             session_repository: Arc::new(TestSessionRepository::new()),
+            identity_provider_repository: None,
+            oidc_authorization_request_repository: Arc::new(
+                TestOidcAuthorizationRequestRepository::new(),
+            ),
+            user_identity_repository: Arc::new(TestUserIdentityRepository::new()),
+            role_mapping: RoleMapping::default(),
+            local_login_policy: LocalLoginPolicy::AllUsers,
+            performed_procedure_step_repository: Arc::new(
+                TestPerformedProcedureStepRepository::new(),
+            ),
+            patient_conflict_repository: Arc::new(TestPatientConflictRepository::new()),
+            deidentification_job_repository: Arc::new(TestDeidentificationJobRepository::new()),
+            dicom_file_repository: Arc::new(TestDicomFileRepository::new()),
+            dicom_object_repository: Arc::new(TestDicomObjectRepository::new()),
+            archive_repository: Arc::new(TestArchiveRepository::new()),
+            archive_deletion_repository: Arc::new(TestArchiveDeletionRepository::new()),
+            retention_rule_repository: Arc::new(TestRetentionRuleRepository::new()),
+            dicom_store_repository: Arc::new(TestDicomStoreRepository::new()),
+            wado_uri_token_repository: Arc::new(HmacWadoUriTokenRepository::new("test")),
         }
     }
 }
@@ -74,14 +224,53 @@ pub struct AppState {
     pub list_application_entities_use_case: Arc<ListApplicationEntitiesUseCase>,
     pub update_application_entity_use_case: Arc<UpdateApplicationEntityUseCase>,
     pub delete_application_entity_use_case: Arc<DeleteApplicationEntityUseCase>,
+    pub list_coercion_rules_use_case: Arc<ListCoercionRulesUseCase>,
+    pub replace_coercion_rules_use_case: Arc<ReplaceCoercionRulesUseCase>,
     pub create_user_use_case: Arc<CreateUserUseCase>,
     pub list_users_use_case: Arc<ListUsersUseCase>,
     pub update_user_use_case: Arc<UpdateUserUseCase>,
     pub delete_user_use_case: Arc<DeleteUserUseCase>,
     pub reset_login_failure_count_use_case: Arc<ResetLoginFailureCountUseCase>,
+    pub revoke_user_sessions_use_case: Arc<RevokeUserSessionsUseCase>,
+    /// シングルサインオンを設定しない場合は`None`
+    pub link_user_identity_use_case: Option<Arc<LinkUserIdentityUseCase>>,
+    pub list_role_permissions_use_case: Arc<ListRolePermissionsUseCase>,
+    pub update_role_permissions_use_case: Arc<UpdateRolePermissionsUseCase>,
     pub login_use_case: Arc<LoginUseCase>,
     pub logout_use_case: Arc<LogoutUseCase>,
+    /// シングルサインオンを設定しない場合は`None`
+    pub start_oidc_login_use_case: Option<Arc<StartOidcLoginUseCase>>,
+    /// シングルサインオンを設定しない場合は`None`
+    pub complete_oidc_login_use_case: Option<Arc<CompleteOidcLoginUseCase>>,
     pub extend_session_use_case: Arc<ExtendSessionUseCase>,
+    pub list_sessions_use_case: Arc<ListSessionsUseCase>,
+    pub revoke_session_use_case: Arc<RevokeSessionUseCase>,
+    pub list_performed_procedure_steps_use_case: Arc<ListPerformedProcedureStepsUseCase>,
+    pub list_patient_conflicts_use_case: Arc<ListPatientConflictsUseCase>,
+    pub reconcile_patient_conflict_use_case: Arc<ReconcilePatientConflictUseCase>,
+    pub create_deidentification_job_use_case: Arc<CreateDeidentificationJobUseCase>,
+    pub get_deidentification_job_use_case: Arc<GetDeidentificationJobUseCase>,
+    pub search_patients_use_case: Arc<SearchPatientsUseCase>,
+    pub search_studies_use_case: Arc<SearchStudiesUseCase>,
+    pub search_series_use_case: Arc<SearchSeriesUseCase>,
+    pub search_sop_instances_use_case: Arc<SearchSopInstancesUseCase>,
+    pub retrieve_sop_instance_files_use_case: Arc<RetrieveSopInstanceFilesUseCase>,
+    pub read_sop_instance_file_use_case: Arc<ReadSopInstanceFileUseCase>,
+    pub read_sop_instance_data_set_use_case: Arc<ReadSopInstanceDataSetUseCase>,
+    pub convert_sop_instance_file_use_case: Arc<ConvertSopInstanceFileUseCase>,
+    pub store_sop_instances_use_case: Arc<StoreSopInstancesUseCase>,
+    pub list_archived_patients_use_case: Arc<ListArchivedPatientsUseCase>,
+    pub list_archived_studies_use_case: Arc<ListArchivedStudiesUseCase>,
+    pub get_archived_study_use_case: Arc<GetArchivedStudyUseCase>,
+    pub list_archived_sop_instances_use_case: Arc<ListArchivedSopInstancesUseCase>,
+    pub delete_archived_object_use_case: Arc<DeleteArchivedObjectUseCase>,
+    pub create_retention_rule_use_case: Arc<CreateRetentionRuleUseCase>,
+    pub list_retention_rules_use_case: Arc<ListRetentionRulesUseCase>,
+    pub update_retention_rule_use_case: Arc<UpdateRetentionRuleUseCase>,
+    pub delete_retention_rule_use_case: Arc<DeleteRetentionRuleUseCase>,
+    pub list_retention_candidates_use_case: Arc<ListRetentionCandidatesUseCase>,
+    pub issue_wado_uri_token_use_case: Arc<IssueWadoUriTokenUseCase>,
+    pub verify_wado_uri_token_use_case: Arc<VerifyWadoUriTokenUseCase>,
 }
 
 pub fn make_state(repos: &Repos) -> AppState {
@@ -98,12 +287,22 @@ pub fn make_state(repos: &Repos) -> AppState {
         repos.application_entity_repository.clone(),
     ));
 
+    let list_coercion_rules_use_case = Arc::new(ListCoercionRulesUseCase::new(
+        repos.coercion_rule_repository.clone(),
+    ));
+    let replace_coercion_rules_use_case = Arc::new(ReplaceCoercionRulesUseCase::new(
+        repos.coercion_rule_repository.clone(),
+    ));
+
     let create_user_use_case = Arc::new(CreateUserUseCase::new(repos.user_repository.clone()));
     let list_users_use_case = Arc::new(ListUsersUseCase::new(
         repos.user_repository.clone(),
         repos.login_failure_count_repository.clone(),
     ));
-    let update_user_use_case = Arc::new(UpdateUserUseCase::new(repos.user_repository.clone()));
+    let update_user_use_case = Arc::new(UpdateUserUseCase::new(
+        repos.user_repository.clone(),
+        repos.session_repository.clone(),
+    ));
     let delete_user_use_case = Arc::new(DeleteUserUseCase::new(
         repos.user_repository.clone(),
         repos.login_failure_count_repository.clone(),
@@ -113,6 +312,17 @@ pub fn make_state(repos: &Repos) -> AppState {
         repos.user_repository.clone(),
         repos.login_failure_count_repository.clone(),
     ));
+    let revoke_user_sessions_use_case = Arc::new(RevokeUserSessionsUseCase::new(
+        repos.user_repository.clone(),
+        repos.session_repository.clone(),
+    ));
+
+    let list_role_permissions_use_case = Arc::new(ListRolePermissionsUseCase::new(
+        repos.role_permission_repository.clone(),
+    ));
+    let update_role_permissions_use_case = Arc::new(UpdateRolePermissionsUseCase::new(
+        repos.role_permission_repository.clone(),
+    ));
 
     let authenticate_user_use_case = Arc::new(AuthenticateUserUseCase::new(
         repos.user_repository.clone(),
@@ -122,57 +332,415 @@ pub fn make_state(repos: &Repos) -> AppState {
         Arc::new(CreateSessionUseCase::new(repos.session_repository.clone()));
     let login_use_case = Arc::new(LoginUseCase::new(
         authenticate_user_use_case,
-        create_session_use_case,
+        create_session_use_case.clone(),
         repos.user_repository.clone(),
+        repos.local_login_policy.clone(),
     ));
     let logout_use_case = Arc::new(LogoutUseCase::new(Arc::new(DeleteSessionUseCase::new(
         repos.session_repository.clone(),
     ))));
 
+    let start_oidc_login_use_case =
+        repos
+            .identity_provider_repository
+            .as_ref()
+            .map(|identity_provider_repository| {
+                Arc::new(StartOidcLoginUseCase::new(
+                    identity_provider_repository.clone(),
+                    repos.oidc_authorization_request_repository.clone(),
+                ))
+            });
+    let complete_oidc_login_use_case =
+        repos
+            .identity_provider_repository
+            .as_ref()
+            .map(|identity_provider_repository| {
+                Arc::new(CompleteOidcLoginUseCase::new(
+                    identity_provider_repository.clone(),
+                    repos.oidc_authorization_request_repository.clone(),
+                    repos.user_repository.clone(),
+                    repos.user_identity_repository.clone(),
+                    create_session_use_case.clone(),
+                    repos.role_mapping.clone(),
+                ))
+            });
+    let link_user_identity_use_case =
+        repos
+            .identity_provider_repository
+            .as_ref()
+            .map(|identity_provider_repository| {
+                Arc::new(LinkUserIdentityUseCase::new(
+                    repos.user_repository.clone(),
+                    repos.user_identity_repository.clone(),
+                    identity_provider_repository.clone(),
+                    repos.local_login_policy.clone(),
+                ))
+            });
+
     let extend_session_use_case =
         Arc::new(ExtendSessionUseCase::new(repos.session_repository.clone()));
+    let list_sessions_use_case =
+        Arc::new(ListSessionsUseCase::new(repos.session_repository.clone()));
+    let revoke_session_use_case =
+        Arc::new(RevokeSessionUseCase::new(repos.session_repository.clone()));
+
+    let list_performed_procedure_steps_use_case = Arc::new(
+        ListPerformedProcedureStepsUseCase::new(repos.performed_procedure_step_repository.clone()),
+    );
+
+    let list_patient_conflicts_use_case = Arc::new(ListPatientConflictsUseCase::new(
+        repos.patient_conflict_repository.clone(),
+    ));
+    let reconcile_patient_conflict_use_case = Arc::new(ReconcilePatientConflictUseCase::new(
+        repos.patient_conflict_repository.clone(),
+        repos.dicom_file_repository.clone(),
+    ));
+
+    let create_deidentification_job_use_case = Arc::new(CreateDeidentificationJobUseCase::new(
+        repos.deidentification_job_repository.clone(),
+        repos.dicom_file_repository.clone(),
+    ));
+    let get_deidentification_job_use_case = Arc::new(GetDeidentificationJobUseCase::new(
+        repos.deidentification_job_repository.clone(),
+    ));
+
+    let search_patients_use_case = Arc::new(SearchPatientsUseCase::new(
+        repos.dicom_object_repository.clone(),
+    ));
+    let search_studies_use_case = Arc::new(SearchStudiesUseCase::new(
+        repos.dicom_object_repository.clone(),
+    ));
+    let search_series_use_case = Arc::new(SearchSeriesUseCase::new(
+        repos.dicom_object_repository.clone(),
+    ));
+    let search_sop_instances_use_case = Arc::new(SearchSopInstancesUseCase::new(
+        repos.dicom_object_repository.clone(),
+    ));
+    let retrieve_sop_instance_files_use_case = Arc::new(RetrieveSopInstanceFilesUseCase::new(
+        repos.dicom_object_repository.clone(),
+    ));
+    let read_sop_instance_file_use_case = Arc::new(ReadSopInstanceFileUseCase::new(
+        repos.dicom_file_repository.clone(),
+    ));
+    let read_sop_instance_data_set_use_case = Arc::new(ReadSopInstanceDataSetUseCase::new(
+        repos.dicom_file_repository.clone(),
+    ));
+    let convert_sop_instance_file_use_case = Arc::new(ConvertSopInstanceFileUseCase::new(
+        repos.dicom_file_repository.clone(),
+    ));
+    let store_sop_instances_use_case = Arc::new(StoreSopInstancesUseCase::new(
+        repos.dicom_store_repository.clone(),
+    ));
+
+    let list_archived_patients_use_case = Arc::new(ListArchivedPatientsUseCase::new(
+        repos.archive_repository.clone(),
+    ));
+    let list_archived_studies_use_case = Arc::new(ListArchivedStudiesUseCase::new(
+        repos.archive_repository.clone(),
+    ));
+    let get_archived_study_use_case = Arc::new(GetArchivedStudyUseCase::new(
+        repos.archive_repository.clone(),
+    ));
+    let list_archived_sop_instances_use_case = Arc::new(ListArchivedSopInstancesUseCase::new(
+        repos.archive_repository.clone(),
+    ));
+    let delete_archived_object_use_case = Arc::new(DeleteArchivedObjectUseCase::new(
+        repos.archive_deletion_repository.clone(),
+    ));
+
+    let create_retention_rule_use_case = Arc::new(CreateRetentionRuleUseCase::new(
+        repos.retention_rule_repository.clone(),
+    ));
+    let list_retention_rules_use_case = Arc::new(ListRetentionRulesUseCase::new(
+        repos.retention_rule_repository.clone(),
+    ));
+    let update_retention_rule_use_case = Arc::new(UpdateRetentionRuleUseCase::new(
+        repos.retention_rule_repository.clone(),
+    ));
+    let delete_retention_rule_use_case = Arc::new(DeleteRetentionRuleUseCase::new(
+        repos.retention_rule_repository.clone(),
+    ));
+    let list_retention_candidates_use_case = Arc::new(ListRetentionCandidatesUseCase::new(
+        repos.retention_rule_repository.clone(),
+    ));
+
+    let issue_wado_uri_token_use_case = Arc::new(IssueWadoUriTokenUseCase::new(
+        repos.dicom_object_repository.clone(),
+        repos.wado_uri_token_repository.clone(),
+    ));
+    let verify_wado_uri_token_use_case = Arc::new(VerifyWadoUriTokenUseCase::new(
+        repos.wado_uri_token_repository.clone(),
+    ));
 
     AppState {
         create_application_entity_use_case,
         list_application_entities_use_case,
         update_application_entity_use_case,
         delete_application_entity_use_case,
+        list_coercion_rules_use_case,
+        replace_coercion_rules_use_case,
         create_user_use_case,
         list_users_use_case,
         update_user_use_case,
         delete_user_use_case,
         reset_login_failure_count_use_case,
+        revoke_user_sessions_use_case,
+        link_user_identity_use_case,
+        list_role_permissions_use_case,
+        update_role_permissions_use_case,
         login_use_case,
         logout_use_case,
+        start_oidc_login_use_case,
+        complete_oidc_login_use_case,
         extend_session_use_case,
+        list_sessions_use_case,
+        revoke_session_use_case,
+        list_performed_procedure_steps_use_case,
+        list_patient_conflicts_use_case,
+        reconcile_patient_conflict_use_case,
+        create_deidentification_job_use_case,
+        get_deidentification_job_use_case,
+        search_patients_use_case,
+        search_studies_use_case,
+        search_series_use_case,
+        search_sop_instances_use_case,
+        retrieve_sop_instance_files_use_case,
+        read_sop_instance_file_use_case,
+        read_sop_instance_data_set_use_case,
+        convert_sop_instance_file_use_case,
+        store_sop_instances_use_case,
+        list_archived_patients_use_case,
+        list_archived_studies_use_case,
+        get_archived_study_use_case,
+        list_archived_sop_instances_use_case,
+        delete_archived_object_use_case,
+        create_retention_rule_use_case,
+        list_retention_rules_use_case,
+        update_retention_rule_use_case,
+        delete_retention_rule_use_case,
+        list_retention_candidates_use_case,
+        issue_wado_uri_token_use_case,
+        verify_wado_uri_token_use_case,
     }
 }
 
 pub fn make_router(state: AppState, repos: &Repos) -> Router {
     let session_repository_for_me = repos.session_repository.clone();
     let user_repository_for_me = repos.user_repository.clone();
-
-    let user_repository = repos.user_repository.clone();
+    let role_permission_repository_for_me = repos.role_permission_repository.clone();
 
     let extend_session_use_case = state.extend_session_use_case.clone();
+    let extend_session_use_case_for_wado_uri = state.extend_session_use_case.clone();
+    let verify_wado_uri_token_use_case = state.verify_wado_uri_token_use_case.clone();
+
+    // ユーザーのロールに操作の権限が許可されているかを検査するミドルウェア
+    let require = |permission: Permission| {
+        let user_repository = repos.user_repository.clone();
+        let role_permission_repository = repos.role_permission_repository.clone();
+        axum::middleware::from_fn(move |request, next| {
+            presentation::middleware::require_permission(
+                permission,
+                request,
+                next,
+                user_repository.clone(),
+                role_permission_repository.clone(),
+            )
+        })
+    };
 
     Router::new()
         // 認証不要なエンドポイント
         .route("/health", get(handler::health::respond_if_healthy))
         .route("/login", post(handler::auth::login))
+        // シングルサインオン (OpenID Connect)
+        .route("/oidc/login", get(handler::auth::oidc_login))
+        .route("/oidc/callback", get(handler::auth::oidc_callback))
         .route(
             "/me",
             get(move |cookies| {
-                handler::auth::me(cookies, session_repository_for_me, user_repository_for_me)
+                handler::auth::me(
+                    cookies,
+                    session_repository_for_me,
+                    user_repository_for_me,
+                    role_permission_repository_for_me,
+                )
             }),
         )
+        // セッションまたは署名付きURLで認証するエンドポイント
+        .route(
+            "/wado",
+            get(handler::dicom_web::retrieve_wado_uri_object)
+                .route_layer(require(Permission::DownloadStudies))
+                .route_layer(axum::middleware::from_fn(move |cookies, request, next| {
+                    presentation::middleware::wado_uri_auth_middleware(
+                        cookies,
+                        extend_session_use_case_for_wado_uri.clone(),
+                        verify_wado_uri_token_use_case.clone(),
+                        request,
+                        next,
+                    )
+                })),
+        )
         // 認証が必要なエンドポイントにミドルウェアを適用
         .merge({
-            // 認証は必要だが、管理者権限は不要なルート
-            let public_auth_router = Router::new().route("/logout", post(handler::auth::logout));
+            // 認証は必要だが、権限は不要なルート
+            let self_service_router = Router::new()
+                .route("/logout", post(handler::auth::logout))
+                // 自身のセッションの一覧・失効
+                .route("/sessions", get(handler::session::list_sessions))
+                .route("/sessions/{uuid}", delete(handler::session::revoke_session));
+
+            // 検査の閲覧の権限が必要なルート
+            let view_studies_router = Router::new()
+                .route(
+                    "/performed-procedure-steps",
+                    get(handler::performed_procedure_step::list_performed_procedure_steps),
+                )
+                // アーカイブの閲覧
+                .route(
+                    "/archive/patients",
+                    get(handler::archive::list_archived_patients),
+                )
+                .route("/archive/studies", get(handler::archive::list_archived_studies))
+                .route(
+                    "/archive/studies/{study_instance_uid}",
+                    get(handler::archive::get_archived_study),
+                )
+                .route(
+                    "/archive/studies/{study_instance_uid}/series/{series_instance_uid}/instances",
+                    get(handler::archive::list_archived_sop_instances),
+                )
+                // DICOMweb (QIDO-RS)
+                .route("/patients", get(handler::dicom_web::search_patients))
+                .route("/studies", get(handler::dicom_web::search_studies))
+                .route(
+                    "/studies/{study_instance_uid}/series",
+                    get(handler::dicom_web::search_series),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/instances",
+                    get(handler::dicom_web::search_instances),
+                )
+                // DICOMweb (WADO-RS) のメタデータ・レンダリング画像・サムネイル
+                .route(
+                    "/studies/{study_instance_uid}/metadata",
+                    get(handler::dicom_web::retrieve_study_metadata),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/metadata",
+                    get(handler::dicom_web::retrieve_series_metadata),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/metadata",
+                    get(handler::dicom_web::retrieve_instance_metadata),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/rendered",
+                    get(handler::dicom_web::retrieve_rendered_instance),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/frames/{frame_list}/rendered",
+                    get(handler::dicom_web::retrieve_rendered_frames),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/thumbnail",
+                    get(handler::dicom_web::retrieve_study_thumbnail),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/thumbnail",
+                    get(handler::dicom_web::retrieve_series_thumbnail),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/thumbnail",
+                    get(handler::dicom_web::retrieve_instance_thumbnail),
+                )
+                .layer(require(Permission::ViewStudies));
+
+            // 検査のダウンロードの権限が必要なルート
+            let download_studies_router = Router::new()
+                // DICOMweb (WADO-RS)
+                .route(
+                    "/studies/{study_instance_uid}",
+                    get(handler::dicom_web::retrieve_study),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}",
+                    get(handler::dicom_web::retrieve_series),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}",
+                    get(handler::dicom_web::retrieve_instance),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/frames/{frame_list}",
+                    get(handler::dicom_web::retrieve_frames),
+                )
+                .route(
+                    "/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}/bulkdata/{tag}",
+                    get(handler::dicom_web::retrieve_bulk_data),
+                )
+                // DICOMweb (WADO-URI)
+                .route(
+                    "/wado/tokens",
+                    post(handler::dicom_web::create_wado_uri_token),
+                )
+                .layer(require(Permission::DownloadStudies));
+
+            // 検査の登録の権限が必要なルート
+            let store_studies_router = Router::new()
+                // DICOMweb (STOW-RS)
+                .route(
+                    "/studies",
+                    post(handler::dicom_web::store_instances).layer(DefaultBodyLimit::max(
+                        handler::dicom_web::MAX_STORE_REQUEST_SIZE,
+                    )),
+                )
+                .route(
+                    "/studies/{study_instance_uid}",
+                    post(handler::dicom_web::store_study_instances).layer(DefaultBodyLimit::max(
+                        handler::dicom_web::MAX_STORE_REQUEST_SIZE,
+                    )),
+                )
+                .layer(require(Permission::StoreStudies));
 
-            // 管理者または情シス権限が必要なルート
-            let admin_router = Router::new()
+            // 検査の削除の権限が必要なルート
+            let delete_studies_router = Router::new()
+                .route(
+                    "/archive/studies/{study_instance_uid}",
+                    delete(handler::archive::delete_archived_study),
+                )
+                .route(
+                    "/archive/studies/{study_instance_uid}/series/{series_instance_uid}",
+                    delete(handler::archive::delete_archived_series),
+                )
+                .route(
+                    "/archive/studies/{study_instance_uid}/series/{series_instance_uid}/instances/{sop_instance_uid}",
+                    delete(handler::archive::delete_archived_sop_instance),
+                )
+                .route(
+                    "/retention-rules",
+                    post(handler::retention_rule::create_retention_rule),
+                )
+                .route(
+                    "/retention-rules",
+                    get(handler::retention_rule::list_retention_rules),
+                )
+                .route(
+                    "/retention-rules/candidates",
+                    get(handler::retention_rule::list_retention_candidates),
+                )
+                .route(
+                    "/retention-rules/{uuid}",
+                    put(handler::retention_rule::update_retention_rule),
+                )
+                .route(
+                    "/retention-rules/{uuid}",
+                    delete(handler::retention_rule::delete_retention_rule),
+                )
+                .layer(require(Permission::DeleteStudies));
+
+            // AEの管理の権限が必要なルート
+            let manage_application_entities_router = Router::new()
                 .route(
                     "/application-entities",
                     post(handler::application_entity::create_application_entity),
@@ -189,6 +757,18 @@ pub fn make_router(state: AppState, repos: &Repos) -> Router {
                     "/application-entities/{ae_title}",
                     delete(handler::application_entity::delete_application_entity),
                 )
+                .route(
+                    "/application-entities/{ae_title}/coercion-rules",
+                    get(handler::coercion_rule::list_coercion_rules),
+                )
+                .route(
+                    "/application-entities/{ae_title}/coercion-rules",
+                    put(handler::coercion_rule::replace_coercion_rules),
+                )
+                .layer(require(Permission::ManageApplicationEntities));
+
+            // ユーザーの管理の権限が必要なルート
+            let manage_users_router = Router::new()
                 .route("/users", post(handler::user::create_user))
                 .route("/users", get(handler::user::list_users))
                 .route("/users/{id}", put(handler::user::update_user))
@@ -197,18 +777,62 @@ pub fn make_router(state: AppState, repos: &Repos) -> Router {
                     "/users/{id}/login-failure-count",
                     delete(handler::user::reset_login_failure_count),
                 )
-                // 管理者チェックミドルウェアを適用
-                .layer(axum::middleware::from_fn(move |request, next| {
-                    presentation::middleware::require_admin_or_it(
-                        request,
-                        next,
-                        user_repository.clone(),
-                    )
-                }));
+                .route(
+                    "/users/{id}/sessions",
+                    delete(handler::user::revoke_user_sessions),
+                )
+                .route(
+                    "/users/{id}/identities",
+                    post(handler::user::link_user_identity),
+                )
+                .route(
+                    "/role-permissions",
+                    get(handler::role_permission::list_role_permissions),
+                )
+                .route(
+                    "/role-permissions/{role}",
+                    put(handler::role_permission::update_role_permissions),
+                )
+                .layer(require(Permission::ManageUsers));
+
+            // 匿名化エクスポートの権限が必要なルート
+            let export_deidentified_data_router = Router::new()
+                .route(
+                    "/studies/{study_instance_uid}/deidentification-jobs",
+                    post(handler::deidentification_job::create_deidentification_job),
+                )
+                .route(
+                    "/deidentification-jobs/{uuid}",
+                    get(handler::deidentification_job::get_deidentification_job),
+                )
+                .layer(require(Permission::ExportDeidentifiedData));
+
+            // 患者属性の不一致の照合の権限が必要なルート
+            let reconcile_patients_router = Router::new()
+                .route(
+                    "/patient-conflicts",
+                    get(handler::patient_conflict::list_patient_conflicts),
+                )
+                .route(
+                    "/patient-conflicts/{uuid}/merge",
+                    post(handler::patient_conflict::merge_patient_conflict),
+                )
+                .route(
+                    "/patient-conflicts/{uuid}/split",
+                    post(handler::patient_conflict::split_patient_conflict),
+                )
+                .layer(require(Permission::ReconcilePatients));
 
             // まとめてマージし、セッション認証ミドルウェアを適用
-            public_auth_router
-                .merge(admin_router)
+            self_service_router
+                .merge(view_studies_router)
+                .merge(download_studies_router)
+                .merge(store_studies_router)
+                .merge(delete_studies_router)
+                .merge(manage_application_entities_router)
+                .merge(manage_users_router)
+                .merge(export_deidentified_data_router)
+                .merge(reconcile_patients_router)
                 .route_layer(axum::middleware::from_fn(move |cookies, request, next| {
                     presentation::middleware::session_auth_middleware(
                         cookies,